@@ -4,9 +4,13 @@ extern crate bencher;
 use bencher::Bencher;
 use std::collections::HashMap;
 use whatlang::dev::{
-    FilterList, LowercaseText, alphabet_cyrillic_calculate_scores, alphabet_latin_calculate_scores,
+    AlphabetBuffers, FilterList, alphabet_cyrillic_calculate_scores,
+    alphabet_latin_calculate_scores,
+};
+use whatlang::{
+    Detector, DetectorScratch, Lang, PreparedText, Scorer, Script, Text, TrigramScorer, detect,
+    detect_script,
 };
-use whatlang::{detect, detect_script};
 
 fn bench_detect(bench: &mut Bencher) {
     let example_data = include_str!("../tests/examples.json");
@@ -19,6 +23,56 @@ fn bench_detect(bench: &mut Bencher) {
     })
 }
 
+fn bench_detect_with_scratch(bench: &mut Bencher) {
+    let example_data = include_str!("../tests/examples.json");
+    let examples: HashMap<String, String> = serde_json::from_str(example_data).unwrap();
+    let detector = Detector::new();
+    let mut scratch = DetectorScratch::new();
+
+    bench.iter(|| {
+        for text in examples.values() {
+            detector.detect_with(&mut scratch, text);
+        }
+    })
+}
+
+fn bench_detect_prepared_with_3_detectors(bench: &mut Bencher) {
+    let example_data = include_str!("../tests/examples.json");
+    let examples: HashMap<String, String> = serde_json::from_str(example_data).unwrap();
+    let detectors = [
+        Detector::new(),
+        Detector::with_allowlist(vec![Lang::Eng, Lang::Deu, Lang::Rus]),
+        Detector::with_denylist(vec![Lang::Eng]),
+    ];
+
+    bench.iter(|| {
+        for text in examples.values() {
+            let prepared = PreparedText::new(text);
+            for detector in &detectors {
+                detector.detect_prepared(&prepared);
+            }
+        }
+    })
+}
+
+fn bench_detect_with_3_detectors(bench: &mut Bencher) {
+    let example_data = include_str!("../tests/examples.json");
+    let examples: HashMap<String, String> = serde_json::from_str(example_data).unwrap();
+    let detectors = [
+        Detector::new(),
+        Detector::with_allowlist(vec![Lang::Eng, Lang::Deu, Lang::Rus]),
+        Detector::with_denylist(vec![Lang::Eng]),
+    ];
+
+    bench.iter(|| {
+        for text in examples.values() {
+            for detector in &detectors {
+                detector.detect(text);
+            }
+        }
+    })
+}
+
 fn bench_detect_script(bench: &mut Bencher) {
     let example_data = include_str!("../tests/examples.json");
     let examples: HashMap<String, String> = serde_json::from_str(example_data).unwrap();
@@ -30,31 +84,75 @@ fn bench_detect_script(bench: &mut Bencher) {
     })
 }
 
+fn bench_detect_ascii(bench: &mut Bencher) {
+    let text = "There is no reason not to learn Esperanto. It is one of the easiest languages to learn, and it will help you to make friends all over the world. Where is the library? The library is over there, on the second floor.";
+    let detector = Detector::new();
+    let mut scratch = DetectorScratch::new();
+
+    bench.iter(|| {
+        detector.detect_with(&mut scratch, text);
+    })
+}
+
 fn bench_alphabet_latin_calculate_scores(bench: &mut Bencher) {
     let text = "Ich sehe auf die Uhr. Es ist kurz vor Mittag, und da heute Sonnabend ist, mache ich Schluß. Por ke lingvo internacia povu bone kaj regule progresadi kaj por ke ĝi havu plenan certecon, ke ĝi neniam disfalos kaj ia facilanima paŝo de ĝiaj amikoj estontaj ne detruos la laborojn de ĝiaj amikoj estintaj, - estas plej necesa antaŭ ĉio unu kondiĉo: la ezistado de klare difinita, neniam tuŝebla kaj neniam ŝangebla Fundamento de la lingvo.";
-    let lowercase_text = LowercaseText::new(text);
     let filter = FilterList::All;
+    let mut buffers = AlphabetBuffers::default();
 
     bench.iter(|| {
-        alphabet_latin_calculate_scores(&lowercase_text, &filter);
+        alphabet_latin_calculate_scores(&Text::new(text), &filter, &mut buffers);
     })
 }
 
 fn bench_alphabet_cyrillic_calculate_scores(bench: &mut Bencher) {
     let text = "Творець есперанто Людвік Заменгоф назвав свою мову просто Lingvo internacia «міжнародна мова». Оскільки на той час у Європі популярною була інша штучна мова — волапюк, прихильники есперанто часто казали «мова доктора Есперанто». Згодом це формулювання скоротилося до «мова Есперанто», а врешті-решт залишилося одне лише слово «Esperanto», яке есперантською пишуть з великої літери, аби його можна було відрізнити від слова «людина, яка сподівається»";
-    let lowercase_text = LowercaseText::new(text);
+    let filter = FilterList::All;
+    let mut buffers = AlphabetBuffers::default();
+
+    bench.iter(|| {
+        alphabet_cyrillic_calculate_scores(&Text::new(text), &filter, &mut buffers);
+    })
+}
+
+fn bench_trigram_latin_scores(bench: &mut Bencher) {
+    let text = "Ich sehe auf die Uhr. Es ist kurz vor Mittag, und da heute Sonnabend ist, mache ich Schluß. Por ke lingvo internacia povu bone kaj regule progresadi kaj por ke ĝi havu plenan certecon, ke ĝi neniam disfalos kaj ia facilanima paŝo de ĝiaj amikoj estontaj ne detruos la laborojn de ĝiaj amikoj estintaj, - estas plej necesa antaŭ ĉio unu kondiĉo: la ezistado de klare difinita, neniam tuŝebla kaj neniam ŝangebla Fundamento de la lingvo.";
+    let filter = FilterList::All;
+
+    bench.iter(|| {
+        TrigramScorer.score(&Text::new(text), Script::Latin, &filter);
+    })
+}
+
+fn bench_trigram_latin_scores_short_text(bench: &mut Bencher) {
+    let text = "Ich sehe auf die Uhr";
+    let filter = FilterList::All;
+
+    bench.iter(|| {
+        TrigramScorer.score(&Text::new(text), Script::Latin, &filter);
+    })
+}
+
+fn bench_trigram_cyrillic_scores(bench: &mut Bencher) {
+    let text = "Творець есперанто Людвік Заменгоф назвав свою мову просто Lingvo internacia «міжнародна мова». Оскільки на той час у Європі популярною була інша штучна мова — волапюк, прихильники есперанто часто казали «мова доктора Есперанто».";
     let filter = FilterList::All;
 
     bench.iter(|| {
-        alphabet_cyrillic_calculate_scores(&lowercase_text, &filter);
+        TrigramScorer.score(&Text::new(text), Script::Cyrillic, &filter);
     })
 }
 
 benchmark_group!(
     benches,
     bench_detect,
+    bench_detect_with_scratch,
+    bench_detect_with_3_detectors,
+    bench_detect_prepared_with_3_detectors,
     bench_detect_script,
+    bench_detect_ascii,
     bench_alphabet_latin_calculate_scores,
     bench_alphabet_cyrillic_calculate_scores,
+    bench_trigram_latin_scores,
+    bench_trigram_latin_scores_short_text,
+    bench_trigram_cyrillic_scores,
 );
 benchmark_main!(benches);
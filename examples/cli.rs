@@ -1,9 +1,30 @@
 extern crate whatlang;
 
+use std::env;
+use std::fs;
 use std::io;
-use whatlang::detect;
+use std::path::Path;
+use std::process;
+use whatlang::{Catalog, CatalogFormat, CatalogValidator, Lang, detect};
+
+const USAGE: &str = "Usage:
+    cli
+        Detect the language of a text read from stdin.
+    cli check-catalog [--lang <locale>] [--min-confidence <0..1>] <file>...
+        Report translations of PO, XLIFF and JSON catalogs, which are not in the language
+        of the catalog. The language is declared by the catalog, given by --lang or taken
+        from the file name (e.g. de.json, messages.de-AT.json, locales/de/common.json).";
 
 fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        None => detect_stdin(),
+        Some("check-catalog") => check_catalogs(&args[1..]),
+        Some(_) => exit_with_usage(),
+    }
+}
+
+fn detect_stdin() {
     let mut text = String::new();
     println!("Please enter a text:");
     io::stdin()
@@ -17,3 +38,120 @@ fn main() {
         println!("Cannot recognize a language :(");
     }
 }
+
+fn check_catalogs(args: &[String]) {
+    let mut lang = None;
+    let mut validator = CatalogValidator::new();
+    let mut paths = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--lang" => {
+                let locale = args.next().unwrap_or_else(|| exit_with_usage());
+                let parsed = Lang::from_locale(locale)
+                    .unwrap_or_else(|| exit_with_error(&format!("Unknown locale: {}", locale)));
+                lang = Some(parsed);
+            }
+            "--min-confidence" => {
+                let value = args.next().unwrap_or_else(|| exit_with_usage());
+                let confidence = value.parse().unwrap_or_else(|_| exit_with_usage());
+                validator = validator.set_min_confidence(confidence);
+            }
+            _ if arg.starts_with("--") => exit_with_usage(),
+            _ => paths.push(Path::new(arg)),
+        }
+    }
+    if paths.is_empty() {
+        exit_with_usage();
+    }
+
+    let mut mismatch_count = 0;
+    for path in paths {
+        let format = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(CatalogFormat::from_extension)
+            .unwrap_or_else(|| {
+                exit_with_error(&format!("{}: unknown catalog format", path.display()))
+            });
+        let text = fs::read_to_string(path)
+            .unwrap_or_else(|err| exit_with_error(&format!("{}: {}", path.display(), err)));
+        let catalog = Catalog::parse(&text, format)
+            .unwrap_or_else(|err| exit_with_error(&format!("{}: {}", path.display(), err)));
+        let Some(target) = lang.or(catalog.lang()).or_else(|| lang_from_path(path)) else {
+            exit_with_error(&format!(
+                "{}: the language is unknown, use --lang",
+                path.display()
+            ));
+        };
+
+        for mismatch in validator.validate(&catalog, target) {
+            let info = mismatch.info();
+            println!(
+                "{}:{}: {}: expected {}, detected {} ({:.2}): {}",
+                path.display(),
+                mismatch.entry().line(),
+                mismatch.entry().key().replace('\u{4}', "|"),
+                target.eng_name(),
+                info.lang().eng_name(),
+                info.confidence(),
+                mismatch
+                    .text()
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            );
+            mismatch_count += 1;
+        }
+    }
+    if mismatch_count > 0 {
+        process::exit(1);
+    }
+}
+
+// A locale in the file name or the name of the directory, e.g. `de.json`, `messages.de_AT.po`
+// or `locales/de/common.json`. The file name is split on dots only, so a region subtag
+// (`AT` of `de_AT`) is never taken for a language.
+fn lang_from_path(path: &Path) -> Option<Lang> {
+    let stem = path.file_stem()?.to_str()?;
+    stem.split('.')
+        .rev()
+        .find_map(Lang::from_locale)
+        .or_else(|| {
+            let dir = path.parent()?.file_name()?.to_str()?;
+            Lang::from_locale(dir)
+        })
+}
+
+fn exit_with_usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn exit_with_error(message: &str) -> ! {
+    eprintln!("{}", message);
+    process::exit(2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lang_from_path() {
+        let cases = [
+            ("de.json", Some(Lang::Deu)),
+            ("messages.de-AT.json", Some(Lang::Deu)),
+            ("messages.fr_CA.po", Some(Lang::Fra)),
+            ("messages.es_AR.po", Some(Lang::Spa)),
+            ("messages.nl_BE.po", Some(Lang::Nld)),
+            ("pt_BR.xlf", Some(Lang::Por)),
+            ("locales/de/common.json", Some(Lang::Deu)),
+            ("locales/fr_CA/common.json", Some(Lang::Fra)),
+            ("locales/common.json", None),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(lang_from_path(Path::new(path)), expected, "{path}");
+        }
+    }
+}
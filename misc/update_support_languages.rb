@@ -23,13 +23,14 @@ IGNORE_LANGS = {
 }
 
 class Lang
-  attr_reader :code, :eng_name, :name, :native_speakers, :script, :trigrams
+  attr_reader :code, :eng_name, :name, :native_speakers, :iso_639_1, :script, :trigrams
 
-  def initialize(code, eng_name, name, script, trigrams, native_speakers = nil)
+  def initialize(code, eng_name, name, script, trigrams, native_speakers = nil, iso_639_1 = nil)
     @code = code || raise("Missing code")
     @eng_name = eng_name || raise("Missing eng_name")
     @name = name || eng_name || raise("Missing name")
     @native_speakers = native_speakers
+    @iso_639_1 = iso_639_1 || raise("Missing iso_639_1")
   end
 
   def self.load
@@ -37,7 +38,7 @@ class Lang
     rows = CSV.read(LIST_FILE, headers: true).each
     rows.each do |row|
       if !langs.any? { |l| l.code == row["code"] }
-        langs << Lang.new(row["code"], row["eng_name"], row["name"], "", [], row["native_speakers"])
+        langs << Lang.new(row["code"], row["eng_name"], row["name"], "", [], row["native_speakers"], row["iso_639_1"])
       end
     end
 
@@ -1,107 +1,232 @@
 //! It's a hard-core optimized implementation of a relatively simple algorithm.
 //! The explanation of the algorithm can be found in the parent module [crate::alphabets].
 
-use super::RawOutcome;
-use crate::core::{FilterList, LowercaseText};
-use crate::utils::is_stop_char;
+use super::{AlphabetBuffers, RawOutcome};
+use crate::core::{CharCounts, FilterList};
+use crate::lang::{LANGS_COUNT, SUPPORTED_LANGS};
 use crate::{Lang, Script};
-use std::cmp::Reverse;
-use std::collections::HashMap;
-use std::sync::LazyLock;
-
-/// Inverted map binding a character to a set of languages.
-pub fn build_inverted_map(alphabets: &[(Lang, &str)]) -> (Vec<char>, Vec<Vec<Lang>>) {
-    let mut map = HashMap::new();
-
-    for (lang, alphabet) in alphabets {
-        for c in alphabet.chars() {
-            let entry = map.entry(c).or_insert_with(Vec::new);
-            entry.push(*lang);
-        }
-    }
-
-    let mut char_lang: Vec<_> = map.into_iter().collect();
-
-    char_lang.sort_unstable_by_key(|(c, _)| *c);
+use core::cmp::Reverse;
+
+/// Inverted map binding a character to a set of languages, generated from the alphabets of
+/// a script, see [tables](super::tables).
+#[derive(Debug)]
+pub struct AlphabetMap {
+    /// Characters of all the alphabets, in ascending order.
+    pub chars: &'static [char],
+    /// Languages of every character, as a set of bits `1 << lang as usize`.
+    /// It includes the languages excluded by cargo features, see [SUPPORTED_LANGS].
+    pub lang_sets: &'static [u128],
+    /// Languages of every character with the relative frequency of the character in every of
+    /// them, see [frequency](super::frequency). Only the languages included by cargo features.
+    pub lang_weights: &'static [&'static [(Lang, u16)]],
+}
 
-    let mut chars = Vec::with_capacity(char_lang.len());
-    let mut langs = Vec::with_capacity(char_lang.len());
-    for (ch, languages) in char_lang {
-        chars.push(ch);
-        langs.push(languages);
+impl AlphabetMap {
+    /// Position of the character in the map.
+    #[inline]
+    pub fn position(&self, ch: char) -> Option<usize> {
+        self.chars.binary_search(&ch).ok()
     }
-
-    (chars, langs)
 }
 
 pub fn generic_alphabet_calculate_scores(
     script: Script,
-    lang_map: &LazyLock<(Vec<char>, Vec<Vec<Lang>>)>,
-    text: &LowercaseText,
+    lang_map: &AlphabetMap,
+    char_counts: &CharCounts,
     filter_list: &FilterList,
-) -> RawOutcome {
-    let (chars, langs) = &**lang_map;
+    buffers: &mut AlphabetBuffers,
+) {
+    let AlphabetBuffers {
+        char_counts: char_scores,
+        outcome,
+    } = buffers;
     let script_langs = script.langs();
 
     // score of each character.
-    let mut char_scores = vec![0; chars.len()];
-    let mut max_raw_score = 0;
-    // iterate over the text and scores characters.
-    for ch in text.chars() {
-        if is_stop_char(ch) {
-            continue;
-        }
-
-        max_raw_score += 1;
-
-        if let Ok(position) = chars.binary_search(&ch) {
+    char_scores.clear();
+    char_scores.resize(lang_map.chars.len(), 0);
+    let max_raw_score = char_counts.total();
+    // iterate over the characters of the text and score them.
+    for (ch, count) in char_counts.iter() {
+        if let Some(position) = lang_map.position(ch) {
             // add 2 and remove max_raw_score at the end,
             // to keep the score interval of -max_raw_score..max_raw_score
-            char_scores[position] += 2;
+            char_scores[position] += 2 * count;
         }
     }
 
     // score of each lang.
-    let mut lang_scores = vec![0; Lang::all().len()];
+    let mut lang_scores = [0; LANGS_COUNT];
     let mut common_score: usize = 0;
     // iterate over scored characters to compute language's scores.
-    for (position, char_score) in char_scores.into_iter().enumerate() {
+    for (position, &char_score) in char_scores.iter().enumerate() {
         if char_score > 0 {
-            let languages = &langs[position];
+            let mut lang_set = lang_map.lang_sets[position] & SUPPORTED_LANGS;
             // if current character is common to all Languages, increment a common score
             // instead of iterating over all Languages scores.
-            if languages.len() == script_langs.len() {
+            if lang_set.count_ones() as usize == script_langs.len() {
                 common_score += char_score;
             } else {
-                for &lang in languages {
-                    lang_scores[lang as usize] += char_score;
+                while lang_set != 0 {
+                    lang_scores[lang_set.trailing_zeros() as usize] += char_score;
+                    lang_set &= lang_set - 1;
                 }
             }
         }
     }
 
+    let RawOutcome {
+        count,
+        raw_scores,
+        scores,
+    } = outcome;
+    *count = max_raw_score;
+
     // remap languages with theirs scores.
-    let mut raw_scores: Vec<(Lang, usize)> = script_langs
-        .iter()
-        .filter(|&&l| filter_list.is_allowed(l))
-        .map(|&l| {
-            let score = (lang_scores[l as usize] + common_score).saturating_sub(max_raw_score);
-            (l, score)
-        })
-        .collect();
+    raw_scores.clear();
+    raw_scores.extend(
+        script_langs
+            .iter()
+            .filter(|&&l| filter_list.is_allowed(l))
+            .map(|&l| {
+                let score = (lang_scores[l as usize] + common_score).saturating_sub(max_raw_score);
+                (l, score)
+            }),
+    );
 
     raw_scores.sort_unstable_by_key(|(_, score)| Reverse(*score));
 
-    let mut normalized_scores = vec![];
-
+    scores.clear();
     for &(lang, raw_score) in raw_scores.iter() {
         let normalized_score = raw_score as f64 / max_raw_score as f64;
-        normalized_scores.push((lang, normalized_score));
+        scores.push((lang, normalized_score));
     }
+}
 
-    RawOutcome {
-        count: max_raw_score,
-        raw_scores,
-        scores: normalized_scores,
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabets::frequency::build_frequency_inverted_map;
+    use crate::alphabets::{cyrillic, latin};
+    use crate::trigrams::{CYRILLIC_LANGS, LATIN_LANGS, LangProfileList};
+    use crate::utils::is_stop_char;
+    use core::fmt::Write;
+    use unicode_normalization::char::is_combining_mark;
+
+    const TABLES_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/alphabets/tables.rs");
+
+    fn render_map(
+        out: &mut String,
+        name: &str,
+        alphabets: &[(Lang, &str)],
+        profiles: LangProfileList,
+    ) {
+        let (chars, lang_weights) = build_frequency_inverted_map(alphabets, profiles);
+
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "pub(super) static {name}: AlphabetMap = AlphabetMap {{"
+        )
+        .unwrap();
+        writeln!(out, "    chars: &[").unwrap();
+        for row in chars.chunks(16) {
+            let row: Vec<String> = row.iter().map(|ch| format!("{ch:?}")).collect();
+            writeln!(out, "        {},", row.join(", ")).unwrap();
+        }
+        writeln!(out, "    ],").unwrap();
+        writeln!(out, "    lang_sets: &[").unwrap();
+        for weights in &lang_weights {
+            let lang_set = weights
+                .iter()
+                .fold(0u128, |set, &(lang, _)| set | 1 << lang as usize);
+            writeln!(out, "        {lang_set:#034x},").unwrap();
+        }
+        writeln!(out, "    ],").unwrap();
+        writeln!(out, "    lang_weights: &[").unwrap();
+        for weights in &lang_weights {
+            let weights: Vec<String> = weights
+                .iter()
+                .map(|&(lang, weight)| {
+                    let code = lang.code();
+                    format!("#[cfg(feature = \"lang-{code}\")] (Lang::{lang:?}, {weight})")
+                })
+                .collect();
+            writeln!(out, "        &[{}],", weights.join(", ")).unwrap();
+        }
+        writeln!(out, "    ],").unwrap();
+        writeln!(out, "}};").unwrap();
+    }
+
+    fn render_tables() -> String {
+        let mut out = String::new();
+        writeln!(out, "// Generated from the alphabets of src/alphabets/latin.rs and src/alphabets/cyrillic.rs").unwrap();
+        writeln!(out, "// and the trigram profiles of the languages.").unwrap();
+        writeln!(out, "// Do not edit it manually, see `test_tables_are_up_to_date` in src/alphabets/common.rs.").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "use super::common::AlphabetMap;").unwrap();
+        writeln!(
+            out,
+            "// Unused when all the languages of the tables are excluded by cargo features."
+        )
+        .unwrap();
+        writeln!(out, "#[allow(unused_imports)]").unwrap();
+        writeln!(out, "use crate::Lang;").unwrap();
+        render_map(&mut out, "LATIN", latin::LATIN_ALPHABETS, LATIN_LANGS);
+        render_map(
+            &mut out,
+            "CYRILLIC",
+            cyrillic::CYRILLIC_ALPHABETS,
+            CYRILLIC_LANGS,
+        );
+        out
+    }
+
+    // Run with `WHATLANG_UPDATE_TABLES=1` to regenerate the tables, e.g. after a change of
+    // an alphabet.
+    #[test]
+    fn test_tables_are_up_to_date() {
+        // The tables are generated from the profiles of all the languages.
+        if !cfg!(feature = "all-langs") {
+            return;
+        }
+        let tables = render_tables();
+        if std::env::var_os("WHATLANG_UPDATE_TABLES").is_some() {
+            std::fs::write(TABLES_PATH, &tables).unwrap();
+        }
+        let actual = std::fs::read_to_string(TABLES_PATH).unwrap();
+        assert!(actual == tables, "{TABLES_PATH} is outdated");
+    }
+
+    #[test]
+    fn test_alphabets_have_no_stop_chars() {
+        for (lang, alphabet) in latin::LATIN_ALPHABETS
+            .iter()
+            .chain(cyrillic::CYRILLIC_ALPHABETS)
+        {
+            assert!(!alphabet.chars().any(is_stop_char), "{lang:?}");
+        }
+    }
+
+    // Texts are normalized to NFC and lowercased before the alphabet method,
+    // e.g. a stray combining dot would never match.
+    #[test]
+    fn test_alphabets_have_only_lowercase_letters() {
+        for (lang, alphabet) in latin::LATIN_ALPHABETS
+            .iter()
+            .chain(cyrillic::CYRILLIC_ALPHABETS)
+        {
+            for ch in alphabet.chars() {
+                assert!(!is_combining_mark(ch), "{lang:?} {ch:?}");
+                assert!(ch.to_lowercase().eq([ch]), "{lang:?} {ch:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_lang_sets_are_indexed_by_lang() {
+        for (i, &lang) in Lang::all().iter().enumerate() {
+            assert_eq!(lang as usize, i);
+        }
     }
 }
@@ -1,9 +1,10 @@
-use super::RawOutcome;
-use super::common::{build_inverted_map, generic_alphabet_calculate_scores};
-use crate::core::{FilterList, LowercaseText};
+use super::common::generic_alphabet_calculate_scores;
+use super::foreign_words::generic_tolerant_alphabet_calculate_scores;
+use super::frequency::generic_frequency_alphabet_calculate_scores;
+use super::tables;
+use super::{AlphabetBuffers, RawOutcome};
+use crate::core::{AlphabetMode, FilterList, Text};
 use crate::{Lang, Script};
-use std::sync::LazyLock;
-
 const BUL: &str = "абвгдежзийклмнопрстуфхцчшщъьюя";
 const RUS: &str = "абвгдежзийклмнопрстуфхцчшщъыьэюяё";
 const UKR: &str = "абвгдежзийклмнопрстуфхцчшщьюяєіїґ";
@@ -11,7 +12,9 @@ const BEL: &str = "абвгдежзйклмнопрстуфхцчшыьэюяё
 const SRP: &str = "абвгдежзиклмнопрстуфхцчшђјљњћџ";
 const MKD: &str = "абвгдежзиклмнопрстуфхцчшѓѕјљњќџ";
 
-const CYRILLIC_ALPHABETS: &[(Lang, &str)] = &[
+// Source of the generated tables, see `test_tables_are_up_to_date` in src/alphabets/common.rs.
+#[cfg_attr(not(test), allow(dead_code))]
+pub(super) const CYRILLIC_ALPHABETS: &[(Lang, &str)] = &[
     (Lang::Bul, BUL),
     (Lang::Rus, RUS),
     (Lang::Ukr, UKR),
@@ -20,17 +23,51 @@ const CYRILLIC_ALPHABETS: &[(Lang, &str)] = &[
     (Lang::Mkd, MKD),
 ];
 
-/// Inverted map binding a character to a set of languages.
-static CYRILLIC_ALPHABET_LANG_MAP: LazyLock<(Vec<char>, Vec<Vec<Lang>>)> =
-    LazyLock::new(|| build_inverted_map(CYRILLIC_ALPHABETS));
-
-pub fn alphabet_calculate_scores(text: &LowercaseText, filter_list: &FilterList) -> RawOutcome {
+pub fn alphabet_calculate_scores<'a>(
+    text: &Text,
+    filter_list: &FilterList,
+    buffers: &'a mut AlphabetBuffers,
+) -> &'a RawOutcome {
     generic_alphabet_calculate_scores(
         Script::Cyrillic,
-        &CYRILLIC_ALPHABET_LANG_MAP,
+        &tables::CYRILLIC,
+        &text.char_counts(),
+        filter_list,
+        buffers,
+    );
+    &buffers.outcome
+}
+
+pub fn frequency_alphabet_calculate_scores<'a>(
+    text: &Text,
+    filter_list: &FilterList,
+    buffers: &'a mut AlphabetBuffers,
+) -> &'a RawOutcome {
+    generic_frequency_alphabet_calculate_scores(
+        Script::Cyrillic,
+        &tables::CYRILLIC,
+        &text.char_counts(),
+        filter_list,
+        buffers,
+    );
+    &buffers.outcome
+}
+
+pub fn tolerant_alphabet_calculate_scores<'a>(
+    text: &Text,
+    filter_list: &FilterList,
+    mode: AlphabetMode,
+    buffers: &'a mut AlphabetBuffers,
+) -> &'a RawOutcome {
+    generic_tolerant_alphabet_calculate_scores(
+        Script::Cyrillic,
+        &tables::CYRILLIC,
         text,
         filter_list,
-    )
+        mode,
+        buffers,
+    );
+    &buffers.outcome
 }
 
 #[cfg(test)]
@@ -43,19 +80,20 @@ mod tests {
 
     #[test]
     fn test_when_ukrainian_specific_chars_given() {
-        let text = LowercaseText::new("Дуже цікаво");
+        let text = Text::new("Дуже цікаво");
+        let mut buffers = AlphabetBuffers::default();
         let RawOutcome {
             count,
             raw_scores,
             scores,
-        } = alphabet_calculate_scores(&text, &FilterList::default());
+        } = alphabet_calculate_scores(&text, &FilterList::default(), &mut buffers);
 
-        assert_eq!(count, 10);
+        assert_eq!(*count, 10);
 
-        assert_eq!(fetch(&Lang::Ukr, &raw_scores), 10);
-        assert_eq!(fetch(&Lang::Rus, &raw_scores), 8);
+        assert_eq!(fetch(&Lang::Ukr, raw_scores), 10);
+        assert_eq!(fetch(&Lang::Rus, raw_scores), 8);
 
-        assert_eq!(fetch(&Lang::Ukr, &scores), 1.0);
-        assert_eq!(fetch(&Lang::Rus, &scores), 0.8);
+        assert_eq!(fetch(&Lang::Ukr, scores), 1.0);
+        assert_eq!(fetch(&Lang::Rus, scores), 0.8);
     }
 }
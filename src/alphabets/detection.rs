@@ -1,20 +1,27 @@
-use super::RawOutcome;
+use super::{AlphabetBuffers, RawOutcome};
 use super::{cyrillic, latin};
 use crate::Lang;
-use crate::core::{FilterList, Info, InternalQuery, LowercaseText, calculate_confidence};
+use crate::core::{AlphabetMode, FilterList, Info, InternalQuery, Text, calculate_confidence};
+use crate::scripts::grouping::MultiLangScript;
 
-pub fn detect(iquery: &InternalQuery) -> Option<Info> {
-    let raw_outcome = raw_detect(iquery);
-    let RawOutcome { count, scores, .. } = raw_outcome;
+pub fn detect(iquery: &InternalQuery, buffers: &mut AlphabetBuffers) -> Option<Info> {
+    let RawOutcome { count, scores, .. } = calculate_scores_into(
+        iquery.text,
+        iquery.filter_list,
+        iquery.multi_lang_script,
+        iquery.alphabet_mode,
+        iquery.tolerate_foreign_words,
+        buffers,
+    );
 
-    let mut normalized_scores_iter = scores.into_iter();
+    let mut normalized_scores_iter = scores.iter();
     let opt_lang_score1 = normalized_scores_iter.next();
     let opt_lang_score2 = normalized_scores_iter.next();
 
-    opt_lang_score1.map(|(lang1, score1)| {
+    opt_lang_score1.map(|&(lang1, score1)| {
         let script = iquery.multi_lang_script.to_script();
-        let confidence = if let Some((_, score2)) = opt_lang_score2 {
-            calculate_confidence(score1, score2, count)
+        let confidence = if let Some(&(_, score2)) = opt_lang_score2 {
+            calculate_confidence(score1, score2, *count)
         } else {
             1.0
         };
@@ -22,35 +29,90 @@ pub fn detect(iquery: &InternalQuery) -> Option<Info> {
     })
 }
 
+#[cfg(feature = "dev")]
 pub fn raw_detect(iquery: &InternalQuery) -> RawOutcome {
-    use crate::scripts::grouping::MultiLangScript as MLS;
+    let mut buffers = AlphabetBuffers::default();
+    calculate_scores_into(
+        iquery.text,
+        iquery.filter_list,
+        iquery.multi_lang_script,
+        iquery.alphabet_mode,
+        iquery.tolerate_foreign_words,
+        &mut buffers,
+    );
+    buffers.outcome
+}
+
+/// Calculate scores reusing the buffers, the outcome is kept in the buffers.
+pub fn calculate_scores_into<'a>(
+    text: &Text,
+    filter_list: &FilterList,
+    multi_lang_script: MultiLangScript,
+    mode: AlphabetMode,
+    tolerate_foreign_words: bool,
+    buffers: &'a mut AlphabetBuffers,
+) -> &'a RawOutcome {
+    use AlphabetMode as AM;
+    use MultiLangScript as MLS;
 
-    let text: &LowercaseText = &iquery.text.lowercase();
-    let filter_list: &FilterList = iquery.filter_list;
-    match iquery.multi_lang_script {
-        MLS::Cyrillic => cyrillic::alphabet_calculate_scores(text, filter_list),
-        MLS::Latin => latin::alphabet_calculate_scores(text, filter_list),
+    if tolerate_foreign_words {
+        match multi_lang_script {
+            MLS::Cyrillic => {
+                return cyrillic::tolerant_alphabet_calculate_scores(
+                    text,
+                    filter_list,
+                    mode,
+                    buffers,
+                );
+            }
+            MLS::Latin => {
+                return latin::tolerant_alphabet_calculate_scores(text, filter_list, mode, buffers);
+            }
+            _ => {}
+        }
+    }
+
+    match (multi_lang_script, mode) {
+        (MLS::Cyrillic, AM::Plain) => {
+            cyrillic::alphabet_calculate_scores(text, filter_list, buffers)
+        }
+        (MLS::Cyrillic, AM::Frequency) => {
+            cyrillic::frequency_alphabet_calculate_scores(text, filter_list, buffers)
+        }
+        (MLS::Latin, AM::Plain) => latin::alphabet_calculate_scores(text, filter_list, buffers),
+        (MLS::Latin, AM::Frequency) => {
+            latin::frequency_alphabet_calculate_scores(text, filter_list, buffers)
+        }
 
         // TODO: implement alphabets for Arabic script
-        MLS::Arabic => build_mock(vec![Lang::Ara, Lang::Urd, Lang::Pes], filter_list),
+        (MLS::Arabic, _) => build_mock(&[Lang::Ara, Lang::Urd, Lang::Pes], filter_list, buffers),
 
         // TODO: implement alphabets for Devanagari script
-        MLS::Devanagari => build_mock(vec![Lang::Hin, Lang::Mar, Lang::Nep], filter_list),
+        (MLS::Devanagari, _) => {
+            build_mock(&[Lang::Hin, Lang::Mar, Lang::Nep], filter_list, buffers)
+        }
 
         // TODO: implement alphabets for Hebrew script
-        MLS::Hebrew => build_mock(vec![Lang::Heb, Lang::Yid], filter_list),
+        (MLS::Hebrew, _) => build_mock(&[Lang::Heb, Lang::Yid], filter_list, buffers),
     }
 }
 
-fn build_mock(langs: Vec<Lang>, filter_list: &FilterList) -> RawOutcome {
+fn build_mock<'a>(
+    langs: &[Lang],
+    filter_list: &FilterList,
+    buffers: &'a mut AlphabetBuffers,
+) -> &'a RawOutcome {
     let filtered_langs = langs
-        .into_iter()
+        .iter()
+        .copied()
         .filter(|lang| filter_list.is_allowed(*lang));
-    let raw_scores = filtered_langs.clone().map(|l| (l, 1)).collect();
-    let scores = filtered_langs.map(|l| (l, 1.0)).collect();
-    RawOutcome {
-        count: 1,
-        raw_scores,
-        scores,
-    }
+    let outcome = &mut buffers.outcome;
+    outcome.count = 1;
+    outcome.raw_scores.clear();
+    outcome
+        .raw_scores
+        .extend(filtered_langs.clone().map(|l| (l, 1)));
+    outcome.scores.clear();
+    outcome.scores.extend(filtered_langs.map(|l| (l, 1.0)));
+    outcome
 }
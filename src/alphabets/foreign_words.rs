@@ -0,0 +1,238 @@
+//! Variant of the alphabet method which tolerates foreign words (proper names and loanwords).
+//!
+//! The plain method penalizes a language for every character out of its alphabet, so a single
+//! foreign name may outweigh the rest of the text:
+//! in `Can you tell me where is Schönheitstraße?` German gets more score than English
+//! just because of `ö` and `ß`. This variant scores the text word by word:
+//!
+//! * A word can not penalize a language for more than [MAX_WORD_PENALTY] characters, no matter how
+//!   many characters of the word are out of the alphabet of the language.
+//! * Capitalized words in the middle of a sentence are likely to be proper names,
+//!   so they are taken into account with [PROPER_NAME_WEIGHT].
+//!
+//! Otherwise characters are scored in the same way as in the plain or the frequency-aware methods.
+
+use super::AlphabetBuffers;
+use super::common::AlphabetMap;
+use super::frequency::FREQUENCY_SCALE;
+use crate::Script;
+use crate::core::{AlphabetMode, FilterList, Text};
+use crate::lang::LANGS_COUNT;
+use crate::utils::{is_stop_char, round_to_usize};
+
+/// Maximum number of characters a single word can be penalized for.
+const MAX_WORD_PENALTY: f64 = 1.0;
+
+/// Weight of a word which is likely to be a proper name.
+const PROPER_NAME_WEIGHT: f64 = 0.25;
+
+pub fn generic_tolerant_alphabet_calculate_scores(
+    script: Script,
+    lang_map: &AlphabetMap,
+    text: &Text,
+    filter_list: &FilterList,
+    mode: AlphabetMode,
+    buffers: &mut AlphabetBuffers,
+) {
+    let outcome = &mut buffers.outcome;
+    let script_langs = script.langs();
+
+    // Gain of a language for a character of its alphabet, on top of the neutral 1.0.
+    let gain = |weight: u16| match mode {
+        AlphabetMode::Plain => 1.0,
+        AlphabetMode::Frequency => weight as f64 / FREQUENCY_SCALE as f64,
+    };
+
+    // number of characters of the current word that are in the alphabet of every language,
+    // and the gain collected by them.
+    let mut word_in_counts = [0usize; LANGS_COUNT];
+    let mut word_gains = [0.0; LANGS_COUNT];
+
+    let mut lang_scores = [0.0; LANGS_COUNT];
+    let mut count = 0;
+    let mut weighted_count = 0.0;
+
+    for word in words(text.normalized()) {
+        let weight = if word.is_likely_proper_name {
+            PROPER_NAME_WEIGHT
+        } else {
+            1.0
+        };
+
+        let mut word_len = 0;
+        for ch in word.text.chars().flat_map(|ch| text.lowercase_char(ch)) {
+            word_len += 1;
+            if let Some(position) = lang_map.position(ch) {
+                for &(lang, lang_weight) in lang_map.lang_weights[position] {
+                    word_in_counts[lang as usize] += 1;
+                    word_gains[lang as usize] += gain(lang_weight);
+                }
+            }
+        }
+
+        for &lang in script_langs {
+            let in_count = core::mem::take(&mut word_in_counts[lang as usize]);
+            let gain = core::mem::take(&mut word_gains[lang as usize]);
+            let penalty = ((word_len - in_count) as f64).min(MAX_WORD_PENALTY);
+            lang_scores[lang as usize] += weight * (gain - penalty);
+        }
+
+        count += word_len;
+        weighted_count += weight * word_len as f64;
+    }
+
+    outcome.count = count;
+
+    let scores = &mut outcome.scores;
+    scores.clear();
+    scores.extend(
+        script_langs
+            .iter()
+            .filter(|&&l| filter_list.is_allowed(l))
+            .map(|&l| {
+                let score = if weighted_count > 0.0 {
+                    (lang_scores[l as usize] / weighted_count).max(0.0)
+                } else {
+                    0.0
+                };
+                (l, score)
+            }),
+    );
+
+    scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(core::cmp::Ordering::Less));
+
+    outcome.raw_scores.clear();
+    outcome
+        .raw_scores
+        .extend(outcome.scores.iter().map(|&(lang, score)| {
+            let raw_score = score * weighted_count * FREQUENCY_SCALE as f64;
+            (lang, round_to_usize(raw_score))
+        }));
+}
+
+#[derive(Debug, PartialEq)]
+struct Word<'a> {
+    text: &'a str,
+    is_likely_proper_name: bool,
+}
+
+/// Split a text into words, i.e. sequences of non-stop characters.
+fn words(text: &str) -> impl Iterator<Item = Word<'_>> {
+    let mut rest = text;
+    let mut is_sentence_start = true;
+
+    core::iter::from_fn(move || {
+        let start = rest.find(|ch| !is_stop_char(ch))?;
+        if rest[..start].contains(is_sentence_end) {
+            is_sentence_start = true;
+        }
+        rest = &rest[start..];
+
+        let end = rest.find(is_stop_char).unwrap_or(rest.len());
+        let (text, tail) = rest.split_at(end);
+        rest = tail;
+
+        let is_likely_proper_name = !is_sentence_start && is_capitalized(text);
+        is_sentence_start = false;
+        Some(Word {
+            text,
+            is_likely_proper_name,
+        })
+    })
+}
+
+fn is_sentence_end(ch: char) -> bool {
+    matches!(ch, '.' | '!' | '?' | '\n')
+}
+
+// Starts with an uppercase letter, but is not entirely uppercase (e.g. "Paris", but not "NASA").
+fn is_capitalized(word: &str) -> bool {
+    let mut chars = word.chars();
+    chars.next().is_some_and(char::is_uppercase) && chars.any(char::is_lowercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Lang;
+    use crate::alphabets::{RawOutcome, latin};
+
+    fn score_of(outcome: &RawOutcome, lang: Lang) -> f64 {
+        outcome.scores.iter().find(|(l, _)| *l == lang).unwrap().1
+    }
+
+    #[test]
+    fn test_words() {
+        let all: Vec<Word> =
+            words("Hello, Anna! I met Jürgen in NASA and Paris.\nParis is nice").collect();
+        let proper_names: Vec<&str> = all
+            .iter()
+            .filter(|w| w.is_likely_proper_name)
+            .map(|w| w.text)
+            .collect();
+        assert_eq!(all.len(), 12);
+        assert_eq!(all[0].text, "Hello");
+        assert_eq!(proper_names, vec!["Anna", "Jürgen", "Paris"]);
+
+        assert_eq!(words("").count(), 0);
+        assert_eq!(words(" ... ").count(), 0);
+    }
+
+    #[test]
+    fn test_word_penalty_is_capped() {
+        let filter_list = FilterList::allow(vec![Lang::Eng, Lang::Deu]);
+
+        // "schönheitstraße" has 2 characters out of the English alphabet, but costs only 1
+        let text = Text::new("schönheitstraße");
+        let mut buffers = AlphabetBuffers::default();
+        let outcome = latin::tolerant_alphabet_calculate_scores(
+            &text,
+            &filter_list,
+            AlphabetMode::Plain,
+            &mut buffers,
+        );
+        assert_eq!(outcome.count, 15);
+        assert_eq!(score_of(outcome, Lang::Deu), 1.0);
+        assert_eq!(score_of(outcome, Lang::Eng), 12.0 / 15.0);
+    }
+
+    #[test]
+    fn test_proper_names_get_less_weight() {
+        let filter_list = FilterList::allow(vec![Lang::Eng, Lang::Deu]);
+        let text = Text::new("Can you tell me where is Schönheitstraße?");
+
+        let mut plain_buffers = AlphabetBuffers::default();
+        let plain = latin::alphabet_calculate_scores(&text, &filter_list, &mut plain_buffers);
+        let mut tolerant_buffers = AlphabetBuffers::default();
+        let tolerant = latin::tolerant_alphabet_calculate_scores(
+            &text,
+            &filter_list,
+            AlphabetMode::Plain,
+            &mut tolerant_buffers,
+        );
+
+        let plain_gap = score_of(plain, Lang::Deu) - score_of(plain, Lang::Eng);
+        let tolerant_gap = score_of(tolerant, Lang::Deu) - score_of(tolerant, Lang::Eng);
+        assert!(tolerant_gap > 0.0);
+        assert!(tolerant_gap < plain_gap / 3.0);
+    }
+
+    #[test]
+    fn test_frequency_mode() {
+        let text = Text::new("The weather is nice today and we walk with Jürgen");
+        let mut buffers = AlphabetBuffers::default();
+        let outcome = latin::tolerant_alphabet_calculate_scores(
+            &text,
+            &FilterList::All,
+            AlphabetMode::Frequency,
+            &mut buffers,
+        );
+        assert_eq!(outcome.scores[0].0, Lang::Eng);
+        assert!(
+            outcome
+                .scores
+                .iter()
+                .all(|&(_, s)| (0.0..=1.0).contains(&s))
+        );
+    }
+}
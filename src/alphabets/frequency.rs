@@ -0,0 +1,308 @@
+//! Frequency-aware variant of the alphabet method.
+//!
+//! The plain alphabet method gives every language +1 for a character of its alphabet,
+//! no matter how common the character is in the language. This variant weights the character by
+//! its frequency in the language, relative to the language where the character is the most
+//! frequent. E.g. letter `o` is about 3 times more frequent in English than in German, so for every
+//! `o` English gets +1, while German gets a bit less.
+//!
+//! Relative frequencies are dampened (see [RELATIVE_FREQUENCY_EXPONENT]), otherwise
+//! the alphabet method would overrule the trigram method within the combined method.
+//!
+//! Characters which are not in the alphabet of a language still give -1, so the outcome stays
+//! within the same range as the plain method and both can be used interchangeably.
+//!
+//! Character frequencies are not taken from letter frequency tables, they are estimated from
+//! the trigram profiles of the languages (see [estimate_char_frequencies]). The weights are
+//! computed in advance and stored in the generated [tables](super::tables).
+
+use super::common::AlphabetMap;
+use super::{AlphabetBuffers, RawOutcome};
+#[cfg(test)]
+use crate::Lang;
+use crate::Script;
+use crate::core::{CharCounts, FilterList};
+use crate::lang::LANGS_COUNT;
+#[cfg(test)]
+use crate::trigrams::{LangProfile, LangProfileList};
+use core::cmp::Reverse;
+#[cfg(test)]
+use std::collections::HashMap;
+
+/// Weights are integers within `0..=FREQUENCY_SCALE`, where `FREQUENCY_SCALE` corresponds to 1.0.
+pub(super) const FREQUENCY_SCALE: usize = 1000;
+
+/// Frequency assumed for characters of an alphabet that do not occur in a trigram profile.
+#[cfg(test)]
+const MIN_FREQUENCY: f64 = 0.001;
+
+/// Relative frequencies are raised to this power. The value is picked empirically:
+/// it's big enough to improve the accuracy of the alphabet method on its own about twice,
+/// and small enough to keep the accuracy of the combined method on very short texts,
+/// see `test_accuracy_on_examples`.
+#[cfg(test)]
+const RELATIVE_FREQUENCY_EXPONENT: f64 = 0.125;
+
+/// Inverted map binding a character to a set of languages and the relative frequency of the
+/// character in every of them. It's used to generate [AlphabetMap]s.
+#[cfg(test)]
+pub(super) fn build_frequency_inverted_map(
+    alphabets: &[(Lang, &str)],
+    profiles: LangProfileList,
+) -> (Vec<char>, Vec<Vec<(Lang, usize)>>) {
+    let mut map: HashMap<char, Vec<(Lang, f64)>> = HashMap::new();
+
+    for &(lang, alphabet) in alphabets {
+        let frequencies = profiles
+            .iter()
+            .find(|(l, _)| *l == lang)
+            .map(|&(_, profile)| estimate_char_frequencies(profile))
+            .unwrap_or_default();
+
+        for ch in alphabet.chars() {
+            let frequency = frequencies
+                .get(&ch)
+                .copied()
+                .unwrap_or(0.0)
+                .max(MIN_FREQUENCY);
+            map.entry(ch).or_default().push((lang, frequency));
+        }
+    }
+
+    let mut char_lang: Vec<_> = map.into_iter().collect();
+    char_lang.sort_unstable_by_key(|(c, _)| *c);
+
+    let mut chars = Vec::with_capacity(char_lang.len());
+    let mut langs = Vec::with_capacity(char_lang.len());
+    for (ch, lang_frequencies) in char_lang {
+        let max_frequency = lang_frequencies
+            .iter()
+            .map(|&(_, f)| f)
+            .fold(MIN_FREQUENCY, f64::max);
+        let weights = lang_frequencies
+            .into_iter()
+            .map(|(lang, f)| {
+                let relative_frequency = (f / max_frequency).powf(RELATIVE_FREQUENCY_EXPONENT);
+                let weight = (relative_frequency * FREQUENCY_SCALE as f64).round() as usize;
+                (lang, weight)
+            })
+            .collect();
+        chars.push(ch);
+        langs.push(weights);
+    }
+
+    (chars, langs)
+}
+
+/// Estimate frequencies of characters in a language by its trigram profile.
+/// According to Zipf's law, a trigram of rank `r` is expected to be `1 / (r + 1)` as frequent
+/// as the most common one. Every character of a trigram inherits the frequency of the trigram.
+/// The result is normalized, so the frequencies of all characters sum up to 1.0.
+///
+/// It's an approximation: a profile keeps only the ranks of the 300 most common trigrams, so rare
+/// characters get [MIN_FREQUENCY] and the frequencies of common ones are rough. Letter frequency
+/// tables are not used, because there is no source of them for all the languages built from
+/// comparable corpora, while the trigram profiles are. The method needs only to tell in which
+/// languages a character is more common, which the estimate does well enough,
+/// see `test_accuracy_on_examples`.
+#[cfg(test)]
+fn estimate_char_frequencies(profile: LangProfile) -> HashMap<char, f64> {
+    let mut frequencies: HashMap<char, f64> = HashMap::new();
+    let mut total = 0.0;
+
+    for (rank, trigram) in profile.iter().enumerate() {
+        let trigram_frequency = 1.0 / (rank + 1) as f64;
+        for ch in [trigram.0, trigram.1, trigram.2] {
+            if ch != ' ' {
+                *frequencies.entry(ch).or_insert(0.0) += trigram_frequency;
+                total += trigram_frequency;
+            }
+        }
+    }
+
+    for frequency in frequencies.values_mut() {
+        *frequency /= total;
+    }
+    frequencies
+}
+
+pub fn generic_frequency_alphabet_calculate_scores(
+    script: Script,
+    lang_map: &AlphabetMap,
+    text_char_counts: &CharCounts,
+    filter_list: &FilterList,
+    buffers: &mut AlphabetBuffers,
+) {
+    let AlphabetBuffers {
+        char_counts,
+        outcome,
+    } = buffers;
+    // number of occurrences of each character.
+    char_counts.clear();
+    char_counts.resize(lang_map.chars.len(), 0);
+    let max_raw_score = text_char_counts.total();
+    for (ch, count) in text_char_counts.iter() {
+        if let Some(position) = lang_map.position(ch) {
+            char_counts[position] += count;
+        }
+    }
+
+    // score of each lang, where every character of the alphabet gives 1 + weight,
+    // so after subtracting max_raw_score at the end, the characters out of the alphabet give -1.
+    let mut lang_scores = [0; LANGS_COUNT];
+    for (position, &char_count) in char_counts.iter().enumerate() {
+        if char_count > 0 {
+            for &(lang, weight) in lang_map.lang_weights[position] {
+                lang_scores[lang as usize] += char_count * (FREQUENCY_SCALE + weight as usize);
+            }
+        }
+    }
+
+    let max_scaled_score = max_raw_score * FREQUENCY_SCALE;
+
+    let RawOutcome {
+        count,
+        raw_scores,
+        scores,
+    } = outcome;
+    *count = max_raw_score;
+
+    // remap languages with theirs scores.
+    raw_scores.clear();
+    raw_scores.extend(
+        script
+            .langs()
+            .iter()
+            .filter(|&&l| filter_list.is_allowed(l))
+            .map(|&l| {
+                let score = lang_scores[l as usize].saturating_sub(max_scaled_score);
+                (l, score)
+            }),
+    );
+
+    raw_scores.sort_unstable_by_key(|(_, score)| Reverse(*score));
+
+    scores.clear();
+    scores.extend(
+        raw_scores
+            .iter()
+            .map(|&(lang, raw_score)| (lang, raw_score as f64 / max_scaled_score as f64)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alphabets::{cyrillic, latin};
+    use crate::core::{AlphabetMode, Method, Options, Text, detect_with_options};
+    use crate::trigrams::LATIN_LANGS;
+
+    #[test]
+    fn test_estimate_char_frequencies() {
+        let profile = LATIN_LANGS.iter().find(|(l, _)| *l == Lang::Eng).unwrap().1;
+        let frequencies = estimate_char_frequencies(profile);
+
+        let sum: f64 = frequencies.values().sum();
+        assert!((sum - 1.0).abs() < 1e-9);
+
+        assert!(frequencies[&'e'] > frequencies[&'o']);
+        assert!(frequencies[&'o'] > frequencies[&'w']);
+        assert!(!frequencies.contains_key(&' '));
+    }
+
+    #[test]
+    fn test_breaks_ties_of_plain_alphabet_method() {
+        let text = Text::new("The weather is nice today and we walk with the dog");
+        let filter_list = FilterList::All;
+
+        // Plain alphabet method can not distinguish languages that use only a-z letters
+        let mut buffers = AlphabetBuffers::default();
+        let plain = latin::alphabet_calculate_scores(&text, &filter_list, &mut buffers);
+        assert_eq!(plain.scores[0].1, plain.scores[1].1);
+
+        let outcome = latin::frequency_alphabet_calculate_scores(&text, &filter_list, &mut buffers);
+        assert_eq!(outcome.count, 40);
+        assert_eq!(outcome.scores[0].0, Lang::Eng);
+        assert!(outcome.scores[0].1 > outcome.scores[1].1);
+        assert!(
+            outcome
+                .scores
+                .iter()
+                .all(|&(_, s)| (0.0..=1.0).contains(&s))
+        );
+    }
+
+    #[test]
+    fn test_out_of_alphabet_chars_are_penalized() {
+        let text = Text::new("Дуже цікаво");
+        let mut buffers = AlphabetBuffers::default();
+        let outcome =
+            cyrillic::frequency_alphabet_calculate_scores(&text, &FilterList::All, &mut buffers);
+
+        let score_of = |lang| outcome.scores.iter().find(|(l, _)| *l == lang).unwrap().1;
+        // 'і' is not in Russian alphabet, it gives -1 in the same way as the plain method does
+        assert!(score_of(Lang::Rus) <= 0.8);
+        assert!(score_of(Lang::Ukr) > 0.8);
+    }
+
+    // Number of texts detected correctly in the plain and the frequency modes, out of the words
+    // of Latin and Cyrillic examples split into texts of `words_per_text` words.
+    fn count_correct(method: Method, words_per_text: usize) -> (usize, usize, usize) {
+        let examples: HashMap<String, String> =
+            serde_json::from_str(include_str!("../../tests/examples.json")).unwrap();
+        let options = |alphabet_mode| Options {
+            method,
+            ..Options::new().set_alphabet_mode(alphabet_mode)
+        };
+        let (plain_options, frequency_options) = (
+            options(AlphabetMode::Plain),
+            options(AlphabetMode::Frequency),
+        );
+
+        let (mut total, mut plain, mut frequency) = (0, 0, 0);
+        for (code, example) in &examples {
+            let lang = Lang::from_code(code).unwrap();
+            let script = crate::detect_script(example);
+            if !matches!(script, Some(Script::Latin | Script::Cyrillic)) {
+                continue;
+            }
+            let words: Vec<&str> = example.split_whitespace().collect();
+            for text in words.chunks(words_per_text).map(|chunk| chunk.join(" ")) {
+                if !text.chars().any(char::is_alphabetic) {
+                    continue;
+                }
+                let is_correct = |options| {
+                    detect_with_options(&text, options).map(|info| info.lang()) == Some(lang)
+                };
+                total += 1;
+                plain += is_correct(&plain_options) as usize;
+                frequency += is_correct(&frequency_options) as usize;
+            }
+        }
+        (total, plain, frequency)
+    }
+
+    #[test]
+    fn test_accuracy_on_examples() {
+        // The alphabet method on its own is at least twice as accurate on short texts
+        let (mut plain, mut frequency) = (0, 0);
+        for words_per_text in [1, 2, 3, 5, 8] {
+            let (_, p, f) = count_correct(Method::Alphabet, words_per_text);
+            assert!(f > p, "{words_per_text} words: {f} <= {p}");
+            plain += p;
+            frequency += f;
+        }
+        assert!(frequency >= 2 * plain, "{frequency} < 2 * {plain}");
+
+        // The combined method keeps its accuracy, within 2% of the plain mode
+        for words_per_text in [1, 2, 3, 5, 8] {
+            let (total, plain, frequency) = count_correct(Method::Combined, words_per_text);
+            assert!(
+                frequency * 50 >= plain * 49,
+                "{words_per_text} words: {frequency} vs {plain} of {total}"
+            );
+        }
+        let (total, _, frequency) = count_correct(Method::Combined, usize::MAX);
+        assert_eq!(frequency, total);
+    }
+}
@@ -1,14 +1,15 @@
-use std::sync::LazyLock;
-
-use super::RawOutcome;
-use super::common::{build_inverted_map, generic_alphabet_calculate_scores};
-use crate::core::{FilterList, LowercaseText};
+use super::common::generic_alphabet_calculate_scores;
+use super::foreign_words::generic_tolerant_alphabet_calculate_scores;
+use super::frequency::generic_frequency_alphabet_calculate_scores;
+use super::tables;
+use super::{AlphabetBuffers, RawOutcome};
+use crate::core::{AlphabetMode, FilterList, Text};
 use crate::{Lang, Script};
 
 const AFR: &str = "abcdefghijklmnopqrstuvwxyzáèéêëíîïóôúû";
 const AKA: &str = "abdefghiklmnoprstuwyɔɛ";
-const AZE: &str = "abcdefghijklmnopqrstuvxyzçöüğışə̇";
-const CAT: &str = "abcdefghijklmnopqrstuvwxyz·àçèéíïòóúü";
+const AZE: &str = "abcdefghijklmnopqrstuvxyzçöüğışə";
+const CAT: &str = "abcdefghijklmnopqrstuvwxyzàçèéíïòóúü";
 const CES: &str = "abcdefghijklmnopqrstuvwxyzáéíóúýčďěňřšťůž";
 const CYM: &str = "abcdefghijklmnopqrstuvwxyzàáâäèéêëìíîïòóôöùúûüýÿŵŷẁẃẅỳ";
 const DAN: &str = "abcdefghijklmnopqrstuvwxyzåæø";
@@ -34,17 +35,19 @@ const RON: &str = "abcdefghijklmnopqrstuvwxyzâîăşţ";
 const SLK: &str = "abcdefghijklmnopqrstuvwxyzáäéíóôúýčďĺľňŕšťž";
 const SLV: &str = "abcdefghijklmnopqrstuvwxyzčšž";
 const SNA: &str = "abcdefghijklmnopqrstuvwxyz";
-const SPA: &str = "abcdefghijklmnopqrstuvwxyz¡¿áéíñóúü";
+const SPA: &str = "abcdefghijklmnopqrstuvwxyzáéíñóúü";
 const SWE: &str = "abcdefghijklmnopqrstuvwxyzäåö";
 const TGL: &str = "abcdefghijklmnopqrstuvwxyzáéíñóú";
 const TUK: &str = "abdefghijklmnoprstuwyzäçöüýňşž";
-const TUR: &str = "abcdefghijklmnopqrstuvwxyzçöüğış̇";
+const TUR: &str = "abcdefghijklmnopqrstuvwxyzçöüğış";
 const UZB: &str = "abcdefghijklmnopqrstuvxyzʻ";
 const VIE: &str =
     "abcdefghijklmnopqrstuvwxyzàáâãèéêìíòóôõùúýăđĩũơưạảấầẩẫậắằẳẵặẹẻẽếềểễệỉịọỏốồổỗộớờởỡợụủứừửữựỳỵỷỹ";
 const ZUL: &str = "abcdefghijklmnopqrstuvwxyz";
 
-const LATIN_ALPHABETS: &[(Lang, &str)] = &[
+// Source of the generated tables, see `test_tables_are_up_to_date` in src/alphabets/common.rs.
+#[cfg_attr(not(test), allow(dead_code))]
+pub(super) const LATIN_ALPHABETS: &[(Lang, &str)] = &[
     (Lang::Afr, AFR),
     (Lang::Aka, AKA),
     (Lang::Aze, AZE),
@@ -84,18 +87,58 @@ const LATIN_ALPHABETS: &[(Lang, &str)] = &[
     (Lang::Zul, ZUL),
 ];
 
-/// Inverted map binding a character to a set of languages.
-pub static ALPHABET_LANG_MAP: LazyLock<(Vec<char>, Vec<Vec<Lang>>)> =
-    LazyLock::new(|| build_inverted_map(LATIN_ALPHABETS));
+pub fn alphabet_calculate_scores<'a>(
+    text: &Text,
+    filter_list: &FilterList,
+    buffers: &'a mut AlphabetBuffers,
+) -> &'a RawOutcome {
+    generic_alphabet_calculate_scores(
+        Script::Latin,
+        &tables::LATIN,
+        &text.char_counts(),
+        filter_list,
+        buffers,
+    );
+    &buffers.outcome
+}
+
+pub fn frequency_alphabet_calculate_scores<'a>(
+    text: &Text,
+    filter_list: &FilterList,
+    buffers: &'a mut AlphabetBuffers,
+) -> &'a RawOutcome {
+    generic_frequency_alphabet_calculate_scores(
+        Script::Latin,
+        &tables::LATIN,
+        &text.char_counts(),
+        filter_list,
+        buffers,
+    );
+    &buffers.outcome
+}
 
-pub fn alphabet_calculate_scores(text: &LowercaseText, filter_list: &FilterList) -> RawOutcome {
-    generic_alphabet_calculate_scores(Script::Latin, &ALPHABET_LANG_MAP, text, filter_list)
+pub fn tolerant_alphabet_calculate_scores<'a>(
+    text: &Text,
+    filter_list: &FilterList,
+    mode: AlphabetMode,
+    buffers: &'a mut AlphabetBuffers,
+) -> &'a RawOutcome {
+    generic_tolerant_alphabet_calculate_scores(
+        Script::Latin,
+        &tables::LATIN,
+        text,
+        filter_list,
+        mode,
+        buffers,
+    );
+    &buffers.outcome
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::Script;
+    use crate::core::LowercaseText;
     use crate::utils::is_stop_char;
 
     // Old naive implementation, that is not very effective but easy to understand
@@ -159,11 +202,11 @@ mod tests {
 
     #[test]
     fn test_alphabet_calculate_scores_against_harmaja_hauras() {
-        let text =
-            LowercaseText::new("Ja kulkee kylmä hetki pariimme, Olet hauras kuin jää, Ja kulke");
+        let text = Text::new("Ja kulkee kylmä hetki pariimme, Olet hauras kuin jää, Ja kulke");
         let filter = FilterList::All;
 
-        let outcome = alphabet_calculate_scores(&text, &filter);
+        let mut buffers = AlphabetBuffers::default();
+        let outcome = alphabet_calculate_scores(&text, &filter, &mut buffers);
         assert_eq!(outcome.count, 50);
         assert_eq!(outcome.raw_scores.len(), 37);
         assert_eq!(outcome.scores.len(), 37);
@@ -196,10 +239,11 @@ mod tests {
             "Die Sonne scheint in das Büro der Grabdenkmalsfirma Heinrich Kroll & Söhne. Es ist April 923, und das Geschäf geht gut.",
         ];
 
+        let mut buffers = AlphabetBuffers::default();
         for text in texts {
-            let lowercase_text = LowercaseText::new(text);
-            let outcome = alphabet_calculate_scores(&lowercase_text, &filter);
-            let naive_outcome = naive_alphabet_calculate_scores(&lowercase_text, &filter);
+            let scored_text = Text::new(text);
+            let outcome = alphabet_calculate_scores(&scored_text, &filter, &mut buffers);
+            let naive_outcome = naive_alphabet_calculate_scores(&scored_text.lowercase(), &filter);
 
             // We can just compare outcome against naive_outcome, because ordering maybe different,
             // what is acceptable.
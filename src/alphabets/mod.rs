@@ -21,6 +21,7 @@
 //! * Character frequencies are not respected (e.g. letter `O` has 7.16% occurrence rate in English and only
 //!   2.24% in German, but according to this alphabet model, both English and German would get +1
 //!   score for letter `O`).
+//!   This is addressed by the frequency-aware variant of the method, see [frequency] module.
 //! * It does not work always well: here are some examples:
 //!   * `Can you tell me where is Schönheitstraße?` - it's cleary an English sentence with a German
 //!     proper name `Schönheitstraße`, but the model gives 34 scores to German and only 30 to
@@ -28,20 +29,41 @@
 //!   * `Façade` - is a valid English word. But English gets panished because of the untypical
 //!     character `ç` that was inherited from French.
 //!
+//!   This is mitigated by the variant of the method which tolerates foreign words,
+//!   see [foreign_words] module.
+//!
 //! The generic (agnostic to a script) implementation and algorithm can be found in the [common] module.
 
 pub(crate) mod common;
 pub(crate) mod cyrillic;
 pub(crate) mod detection;
+pub(crate) mod foreign_words;
+pub(crate) mod frequency;
 pub(crate) mod latin;
+mod scorer;
+#[rustfmt::skip]
+mod tables;
 
 use crate::Lang;
-pub use detection::{detect, raw_detect};
+use alloc::vec::Vec;
+pub use detection::calculate_scores_into;
+pub use detection::detect;
+#[cfg(feature = "dev")]
+pub use detection::raw_detect;
+pub use scorer::AlphabetScorer;
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct RawOutcome {
     pub count: usize,
     #[allow(dead_code)]
     pub raw_scores: Vec<(Lang, usize)>,
     pub scores: Vec<(Lang, f64)>,
 }
+
+/// Buffers reused by the alphabet method between texts.
+#[derive(Debug, Default)]
+pub struct AlphabetBuffers {
+    // Number of occurrences (or scores) of every character of an alphabet.
+    pub(crate) char_counts: Vec<usize>,
+    pub(crate) outcome: RawOutcome,
+}
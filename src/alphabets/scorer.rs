@@ -0,0 +1,47 @@
+use super::RawOutcome;
+use super::detection::calculate_scores_into;
+use crate::Script;
+use crate::core::{AlphabetMode, FilterList, Scorer, Scores, Text, with_thread_scratch};
+use crate::scripts::grouping::ScriptLangGroup;
+
+/// [Scorer] based on the alphabet method: languages are scored by how many characters of the
+/// text belong to their alphabets.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlphabetScorer {
+    mode: AlphabetMode,
+    tolerate_foreign_words: bool,
+}
+
+impl AlphabetScorer {
+    pub fn new(mode: AlphabetMode) -> Self {
+        Self {
+            mode,
+            tolerate_foreign_words: false,
+        }
+    }
+
+    /// See [Options::set_tolerate_foreign_words](crate::Options::set_tolerate_foreign_words).
+    pub fn set_tolerate_foreign_words(mut self, tolerate: bool) -> Self {
+        self.tolerate_foreign_words = tolerate;
+        self
+    }
+}
+
+impl Scorer for AlphabetScorer {
+    fn score(&self, text: &Text, script: Script, filter_list: &FilterList) -> Scores {
+        match script.to_lang_group() {
+            ScriptLangGroup::Multi(multi_lang_script) => with_thread_scratch(|scratch| {
+                let RawOutcome { count, scores, .. } = calculate_scores_into(
+                    text,
+                    filter_list,
+                    multi_lang_script,
+                    self.mode,
+                    self.tolerate_foreign_words,
+                    &mut scratch.buffers.alphabet,
+                );
+                Scores::new(scores.clone(), *count)
+            }),
+            _ => Scores::for_one_lang_script(script, filter_list),
+        }
+    }
+}
@@ -0,0 +1,460 @@
+// Generated from the alphabets of src/alphabets/latin.rs and src/alphabets/cyrillic.rs
+// and the trigram profiles of the languages.
+// Do not edit it manually, see `test_tables_are_up_to_date` in src/alphabets/common.rs.
+
+use super::common::AlphabetMap;
+// Unused when all the languages of the tables are excluded by cargo features.
+#[allow(unused_imports)]
+use crate::Lang;
+
+pub(super) static LATIN: AlphabetMap = AlphabetMap {
+    chars: &[
+        'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p',
+        'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'ß', 'à', 'á', 'â', 'ã', 'ä',
+        'å', 'æ', 'ç', 'è', 'é', 'ê', 'ë', 'ì', 'í', 'î', 'ï', 'ñ', 'ò', 'ó', 'ô', 'õ',
+        'ö', 'ø', 'ù', 'ú', 'û', 'ü', 'ý', 'ÿ', 'ā', 'ă', 'ą', 'ć', 'ĉ', 'č', 'ď', 'đ',
+        'ē', 'ė', 'ę', 'ě', 'ĝ', 'ğ', 'ģ', 'ĥ', 'ĩ', 'ī', 'į', 'ı', 'ĳ', 'ĵ', 'ķ', 'ĺ',
+        'ļ', 'ľ', 'ł', 'ń', 'ņ', 'ň', 'ō', 'ő', 'œ', 'ŕ', 'ŗ', 'ř', 'ś', 'ŝ', 'ş', 'š',
+        'ţ', 'ť', 'ũ', 'ū', 'ŭ', 'ů', 'ű', 'ų', 'ŵ', 'ŷ', 'ź', 'ż', 'ž', 'ơ', 'ư', 'ɔ',
+        'ə', 'ɛ', 'ʻ', 'ẁ', 'ẃ', 'ẅ', 'ạ', 'ả', 'ấ', 'ầ', 'ẩ', 'ẫ', 'ậ', 'ắ', 'ằ', 'ẳ',
+        'ẵ', 'ặ', 'ẹ', 'ẻ', 'ẽ', 'ế', 'ề', 'ể', 'ễ', 'ệ', 'ỉ', 'ị', 'ọ', 'ỏ', 'ố', 'ồ',
+        'ổ', 'ỗ', 'ộ', 'ớ', 'ờ', 'ở', 'ỡ', 'ợ', 'ụ', 'ủ', 'ứ', 'ừ', 'ử', 'ữ', 'ự', 'ỳ',
+        'ỵ', 'ỷ', 'ỹ',
+    ],
+    lang_sets: &[
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002fe0068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002fe8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002fe0068b9c1fea0372,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002ff8068b9c1fea0373,
+        0x000000000000002fe0068b9c1fea0373,
+        0x000000000000002ff8040b9c1fea0372,
+        0x000000000000002fe0068b9c1fea0372,
+        0x000000000000002ff8068b9c1fea0372,
+        0x000000000000002fe8068b9c1fea0373,
+        0x00000000000000000000000000000200,
+        0x00000000000000240000080004000160,
+        0x000000000000002a8000080018000030,
+        0x00000000000000200000080400000120,
+        0x00000000000000000000080000000020,
+        0x00000000000000220800020001800200,
+        0x00000000000000000000000000e00000,
+        0x00000000000000000000000000600000,
+        0x00000000000000040802000002000120,
+        0x00000000000000248000080004080140,
+        0x000000000000002e800008001c080170,
+        0x00000000000000208000080000000120,
+        0x00000000000000208000000004000100,
+        0x00000000000000200000080000000040,
+        0x000000000000002e8000080018000030,
+        0x00000000000000208000000400000100,
+        0x00000000000000248000000004000100,
+        0x00000000000000080000000000000010,
+        0x00000000000000240000080000000040,
+        0x000000000000002e8000080018020030,
+        0x00000000000000228000080000000120,
+        0x000000000000000000000a0000000020,
+        0x0000000000000020080202000b800200,
+        0x00000000000000000000000000600000,
+        0x00000000000000200000080000000140,
+        0x000000000000002e8000080018000030,
+        0x00000000000000208000000000000100,
+        0x0000000000000024080202000a000310,
+        0x00000000000000220800080010000000,
+        0x00000000000000200000000000000100,
+        0x00000000000000000000010000000000,
+        0x00000000000000000000080400000000,
+        0x00000000000000000000008000020000,
+        0x00000000000000000000001000020000,
+        0x00000000000000000000000000000001,
+        0x00000000000000020000019810000000,
+        0x00000000000000020000000010000000,
+        0x00000000000000000000081000000000,
+        0x00000000000000000000010000000000,
+        0x00000000000000000000008000000000,
+        0x00000000000000000000008000020000,
+        0x00000000000000000000000010000000,
+        0x00000000000000000000000000000001,
+        0x00000000000000000002000002000000,
+        0x00000000000000000000010000000000,
+        0x00000000000000000000000000000001,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000010000000000,
+        0x00000000000000000000008000000000,
+        0x00000000000000000002000002000000,
+        0x00000000000000000000000004000000,
+        0x00000000000000000000000000000001,
+        0x00000000000000000000010000000000,
+        0x00000000000000020000000000000000,
+        0x00000000000000000000010000000000,
+        0x00000000000000020000000000000000,
+        0x00000000000000000000000000020000,
+        0x00000000000000000000000000020000,
+        0x00000000000000000000010000000000,
+        0x00000000000000020800000010000000,
+        0x00000000000000000000010000000000,
+        0x00000000000000000000000008000000,
+        0x00000000000000000000000000000100,
+        0x00000000000000020000000000000000,
+        0x00000000000000000000010000000000,
+        0x00000000000000000000000010000000,
+        0x00000000000000000000000000020000,
+        0x00000000000000000000000000000001,
+        0x00000000000000000802000402000000,
+        0x00000000000000020000019811000000,
+        0x00000000000000000000000400000000,
+        0x00000000000000020000000010000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000018000000000,
+        0x00000000000000000000000000000001,
+        0x00000000000000000000000010000000,
+        0x00000000000000000000000008000000,
+        0x00000000000000000000008000000000,
+        0x00000000000000200000000000000000,
+        0x00000000000000200000000000000000,
+        0x00000000000000000000000000020000,
+        0x00000000000000000000000000020000,
+        0x00000000000000020800019811000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000001000000000000000,
+        0x00000000000000000002000000000000,
+        0x00000000000000001000000000000000,
+        0x00000000000000000000800000000000,
+        0x00000000000000200000000000000000,
+        0x00000000000000200000000000000000,
+        0x00000000000000200000000000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000200000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+        0x00000000000000000000080000000000,
+    ],
+    lang_weights: &[
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 805), #[cfg(feature = "lang-aka")] (Lang::Aka, 963), #[cfg(feature = "lang-aze")] (Lang::Aze, 846), #[cfg(feature = "lang-cat")] (Lang::Cat, 876), #[cfg(feature = "lang-ces")] (Lang::Ces, 843), #[cfg(feature = "lang-cym")] (Lang::Cym, 877), #[cfg(feature = "lang-dan")] (Lang::Dan, 750), #[cfg(feature = "lang-deu")] (Lang::Deu, 739), #[cfg(feature = "lang-eng")] (Lang::Eng, 839), #[cfg(feature = "lang-epo")] (Lang::Epo, 966), #[cfg(feature = "lang-est")] (Lang::Est, 881), #[cfg(feature = "lang-fin")] (Lang::Fin, 898), #[cfg(feature = "lang-fra")] (Lang::Fra, 785), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 909), #[cfg(feature = "lang-hun")] (Lang::Hun, 889), #[cfg(feature = "lang-ind")] (Lang::Ind, 985), #[cfg(feature = "lang-ita")] (Lang::Ita, 821), #[cfg(feature = "lang-jav")] (Lang::Jav, 973), #[cfg(feature = "lang-lat")] (Lang::Lat, 819), #[cfg(feature = "lang-lav")] (Lang::Lav, 924), #[cfg(feature = "lang-lit")] (Lang::Lit, 897), #[cfg(feature = "lang-nld")] (Lang::Nld, 830), #[cfg(feature = "lang-nob")] (Lang::Nob, 751), #[cfg(feature = "lang-pol")] (Lang::Pol, 853), #[cfg(feature = "lang-por")] (Lang::Por, 862), #[cfg(feature = "lang-ron")] (Lang::Ron, 845), #[cfg(feature = "lang-slk")] (Lang::Slk, 867), #[cfg(feature = "lang-slv")] (Lang::Slv, 872), #[cfg(feature = "lang-sna")] (Lang::Sna, 965), #[cfg(feature = "lang-spa")] (Lang::Spa, 871), #[cfg(feature = "lang-swe")] (Lang::Swe, 821), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 1000), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 938), #[cfg(feature = "lang-tur")] (Lang::Tur, 892), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 949), #[cfg(feature = "lang-vie")] (Lang::Vie, 808), #[cfg(feature = "lang-zul")] (Lang::Zul, 837)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 733), #[cfg(feature = "lang-aka")] (Lang::Aka, 897), #[cfg(feature = "lang-aze")] (Lang::Aze, 845), #[cfg(feature = "lang-cat")] (Lang::Cat, 677), #[cfg(feature = "lang-ces")] (Lang::Ces, 865), #[cfg(feature = "lang-cym")] (Lang::Cym, 698), #[cfg(feature = "lang-dan")] (Lang::Dan, 682), #[cfg(feature = "lang-deu")] (Lang::Deu, 728), #[cfg(feature = "lang-eng")] (Lang::Eng, 731), #[cfg(feature = "lang-epo")] (Lang::Epo, 739), #[cfg(feature = "lang-est")] (Lang::Est, 706), #[cfg(feature = "lang-fin")] (Lang::Fin, 589), #[cfg(feature = "lang-fra")] (Lang::Fra, 672), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 802), #[cfg(feature = "lang-hun")] (Lang::Hun, 798), #[cfg(feature = "lang-ind")] (Lang::Ind, 873), #[cfg(feature = "lang-ita")] (Lang::Ita, 667), #[cfg(feature = "lang-jav")] (Lang::Jav, 872), #[cfg(feature = "lang-lat")] (Lang::Lat, 809), #[cfg(feature = "lang-lav")] (Lang::Lav, 1000), #[cfg(feature = "lang-lit")] (Lang::Lit, 737), #[cfg(feature = "lang-nld")] (Lang::Nld, 755), #[cfg(feature = "lang-nob")] (Lang::Nob, 706), #[cfg(feature = "lang-pol")] (Lang::Pol, 714), #[cfg(feature = "lang-por")] (Lang::Por, 635), #[cfg(feature = "lang-ron")] (Lang::Ron, 706), #[cfg(feature = "lang-slk")] (Lang::Slk, 833), #[cfg(feature = "lang-slv")] (Lang::Slv, 748), #[cfg(feature = "lang-sna")] (Lang::Sna, 627), #[cfg(feature = "lang-spa")] (Lang::Spa, 678), #[cfg(feature = "lang-swe")] (Lang::Swe, 638), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 783), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 890), #[cfg(feature = "lang-tur")] (Lang::Tur, 830), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 892), #[cfg(feature = "lang-vie")] (Lang::Vie, 732), #[cfg(feature = "lang-zul")] (Lang::Zul, 752)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 549), #[cfg(feature = "lang-aze")] (Lang::Aze, 549), #[cfg(feature = "lang-cat")] (Lang::Cat, 824), #[cfg(feature = "lang-ces")] (Lang::Ces, 762), #[cfg(feature = "lang-cym")] (Lang::Cym, 782), #[cfg(feature = "lang-dan")] (Lang::Dan, 549), #[cfg(feature = "lang-deu")] (Lang::Deu, 872), #[cfg(feature = "lang-eng")] (Lang::Eng, 753), #[cfg(feature = "lang-epo")] (Lang::Epo, 768), #[cfg(feature = "lang-est")] (Lang::Est, 549), #[cfg(feature = "lang-fin")] (Lang::Fin, 549), #[cfg(feature = "lang-fra")] (Lang::Fra, 773), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 571), #[cfg(feature = "lang-hun")] (Lang::Hun, 549), #[cfg(feature = "lang-ind")] (Lang::Ind, 549), #[cfg(feature = "lang-ita")] (Lang::Ita, 752), #[cfg(feature = "lang-jav")] (Lang::Jav, 549), #[cfg(feature = "lang-lat")] (Lang::Lat, 800), #[cfg(feature = "lang-lav")] (Lang::Lav, 727), #[cfg(feature = "lang-lit")] (Lang::Lit, 627), #[cfg(feature = "lang-nld")] (Lang::Nld, 783), #[cfg(feature = "lang-nob")] (Lang::Nob, 549), #[cfg(feature = "lang-pol")] (Lang::Pol, 854), #[cfg(feature = "lang-por")] (Lang::Por, 791), #[cfg(feature = "lang-ron")] (Lang::Ron, 817), #[cfg(feature = "lang-slk")] (Lang::Slk, 784), #[cfg(feature = "lang-slv")] (Lang::Slv, 734), #[cfg(feature = "lang-sna")] (Lang::Sna, 788), #[cfg(feature = "lang-spa")] (Lang::Spa, 878), #[cfg(feature = "lang-swe")] (Lang::Swe, 1000), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 549), #[cfg(feature = "lang-tur")] (Lang::Tur, 549), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 610), #[cfg(feature = "lang-vie")] (Lang::Vie, 976), #[cfg(feature = "lang-zul")] (Lang::Zul, 549)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 923), #[cfg(feature = "lang-aka")] (Lang::Aka, 759), #[cfg(feature = "lang-aze")] (Lang::Aze, 787), #[cfg(feature = "lang-cat")] (Lang::Cat, 961), #[cfg(feature = "lang-ces")] (Lang::Ces, 773), #[cfg(feature = "lang-cym")] (Lang::Cym, 1000), #[cfg(feature = "lang-dan")] (Lang::Dan, 904), #[cfg(feature = "lang-deu")] (Lang::Deu, 927), #[cfg(feature = "lang-eng")] (Lang::Eng, 842), #[cfg(feature = "lang-epo")] (Lang::Epo, 807), #[cfg(feature = "lang-est")] (Lang::Est, 775), #[cfg(feature = "lang-fin")] (Lang::Fin, 632), #[cfg(feature = "lang-fra")] (Lang::Fra, 970), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 788), #[cfg(feature = "lang-hun")] (Lang::Hun, 757), #[cfg(feature = "lang-ind")] (Lang::Ind, 871), #[cfg(feature = "lang-ita")] (Lang::Ita, 987), #[cfg(feature = "lang-jav")] (Lang::Jav, 747), #[cfg(feature = "lang-lat")] (Lang::Lat, 721), #[cfg(feature = "lang-lav")] (Lang::Lav, 712), #[cfg(feature = "lang-lit")] (Lang::Lit, 627), #[cfg(feature = "lang-nld")] (Lang::Nld, 932), #[cfg(feature = "lang-nob")] (Lang::Nob, 818), #[cfg(feature = "lang-pol")] (Lang::Pol, 786), #[cfg(feature = "lang-por")] (Lang::Por, 979), #[cfg(feature = "lang-ron")] (Lang::Ron, 945), #[cfg(feature = "lang-slk")] (Lang::Slk, 768), #[cfg(feature = "lang-slv")] (Lang::Slv, 838), #[cfg(feature = "lang-sna")] (Lang::Sna, 798), #[cfg(feature = "lang-spa")] (Lang::Spa, 979), #[cfg(feature = "lang-swe")] (Lang::Swe, 812), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 638), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 879), #[cfg(feature = "lang-tur")] (Lang::Tur, 755), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 769), #[cfg(feature = "lang-vie")] (Lang::Vie, 606), #[cfg(feature = "lang-zul")] (Lang::Zul, 602)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 1000), #[cfg(feature = "lang-aka")] (Lang::Aka, 816), #[cfg(feature = "lang-aze")] (Lang::Aze, 599), #[cfg(feature = "lang-cat")] (Lang::Cat, 966), #[cfg(feature = "lang-ces")] (Lang::Ces, 799), #[cfg(feature = "lang-cym")] (Lang::Cym, 853), #[cfg(feature = "lang-dan")] (Lang::Dan, 976), #[cfg(feature = "lang-deu")] (Lang::Deu, 983), #[cfg(feature = "lang-eng")] (Lang::Eng, 898), #[cfg(feature = "lang-epo")] (Lang::Epo, 841), #[cfg(feature = "lang-est")] (Lang::Est, 941), #[cfg(feature = "lang-fin")] (Lang::Fin, 924), #[cfg(feature = "lang-fra")] (Lang::Fra, 980), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 822), #[cfg(feature = "lang-hun")] (Lang::Hun, 901), #[cfg(feature = "lang-ind")] (Lang::Ind, 859), #[cfg(feature = "lang-ita")] (Lang::Ita, 904), #[cfg(feature = "lang-jav")] (Lang::Jav, 763), #[cfg(feature = "lang-lat")] (Lang::Lat, 918), #[cfg(feature = "lang-lav")] (Lang::Lav, 835), #[cfg(feature = "lang-lit")] (Lang::Lit, 838), #[cfg(feature = "lang-nld")] (Lang::Nld, 997), #[cfg(feature = "lang-nob")] (Lang::Nob, 969), #[cfg(feature = "lang-pol")] (Lang::Pol, 872), #[cfg(feature = "lang-por")] (Lang::Por, 936), #[cfg(feature = "lang-ron")] (Lang::Ron, 967), #[cfg(feature = "lang-slk")] (Lang::Slk, 805), #[cfg(feature = "lang-slv")] (Lang::Slv, 809), #[cfg(feature = "lang-sna")] (Lang::Sna, 814), #[cfg(feature = "lang-spa")] (Lang::Spa, 962), #[cfg(feature = "lang-swe")] (Lang::Swe, 858), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 493), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 885), #[cfg(feature = "lang-tur")] (Lang::Tur, 955), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 674), #[cfg(feature = "lang-vie")] (Lang::Vie, 493), #[cfg(feature = "lang-zul")] (Lang::Zul, 917)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 729), #[cfg(feature = "lang-aka")] (Lang::Aka, 790), #[cfg(feature = "lang-aze")] (Lang::Aze, 684), #[cfg(feature = "lang-cat")] (Lang::Cat, 686), #[cfg(feature = "lang-ces")] (Lang::Ces, 651), #[cfg(feature = "lang-cym")] (Lang::Cym, 881), #[cfg(feature = "lang-dan")] (Lang::Dan, 1000), #[cfg(feature = "lang-deu")] (Lang::Deu, 869), #[cfg(feature = "lang-eng")] (Lang::Eng, 972), #[cfg(feature = "lang-epo")] (Lang::Epo, 673), #[cfg(feature = "lang-est")] (Lang::Est, 651), #[cfg(feature = "lang-fin")] (Lang::Fin, 651), #[cfg(feature = "lang-fra")] (Lang::Fra, 685), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 651), #[cfg(feature = "lang-hun")] (Lang::Hun, 690), #[cfg(feature = "lang-ind")] (Lang::Ind, 651), #[cfg(feature = "lang-ita")] (Lang::Ita, 651), #[cfg(feature = "lang-jav")] (Lang::Jav, 651), #[cfg(feature = "lang-lat")] (Lang::Lat, 693), #[cfg(feature = "lang-lav")] (Lang::Lav, 651), #[cfg(feature = "lang-lit")] (Lang::Lit, 651), #[cfg(feature = "lang-nld")] (Lang::Nld, 777), #[cfg(feature = "lang-nob")] (Lang::Nob, 966), #[cfg(feature = "lang-pol")] (Lang::Pol, 651), #[cfg(feature = "lang-por")] (Lang::Por, 722), #[cfg(feature = "lang-ron")] (Lang::Ron, 787), #[cfg(feature = "lang-slk")] (Lang::Slk, 651), #[cfg(feature = "lang-slv")] (Lang::Slv, 651), #[cfg(feature = "lang-sna")] (Lang::Sna, 651), #[cfg(feature = "lang-spa")] (Lang::Spa, 651), #[cfg(feature = "lang-swe")] (Lang::Swe, 967), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 651), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 651), #[cfg(feature = "lang-tur")] (Lang::Tur, 651), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 651), #[cfg(feature = "lang-vie")] (Lang::Vie, 651), #[cfg(feature = "lang-zul")] (Lang::Zul, 709)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 850), #[cfg(feature = "lang-aka")] (Lang::Aka, 530), #[cfg(feature = "lang-aze")] (Lang::Aze, 530), #[cfg(feature = "lang-cat")] (Lang::Cat, 611), #[cfg(feature = "lang-ces")] (Lang::Ces, 530), #[cfg(feature = "lang-cym")] (Lang::Cym, 774), #[cfg(feature = "lang-dan")] (Lang::Dan, 925), #[cfg(feature = "lang-deu")] (Lang::Deu, 822), #[cfg(feature = "lang-eng")] (Lang::Eng, 744), #[cfg(feature = "lang-epo")] (Lang::Epo, 630), #[cfg(feature = "lang-est")] (Lang::Est, 785), #[cfg(feature = "lang-fin")] (Lang::Fin, 530), #[cfg(feature = "lang-fra")] (Lang::Fra, 581), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 570), #[cfg(feature = "lang-hun")] (Lang::Hun, 827), #[cfg(feature = "lang-ind")] (Lang::Ind, 897), #[cfg(feature = "lang-ita")] (Lang::Ita, 661), #[cfg(feature = "lang-jav")] (Lang::Jav, 980), #[cfg(feature = "lang-lat")] (Lang::Lat, 606), #[cfg(feature = "lang-lav")] (Lang::Lav, 626), #[cfg(feature = "lang-lit")] (Lang::Lit, 714), #[cfg(feature = "lang-nld")] (Lang::Nld, 812), #[cfg(feature = "lang-nob")] (Lang::Nob, 923), #[cfg(feature = "lang-pol")] (Lang::Pol, 722), #[cfg(feature = "lang-por")] (Lang::Por, 579), #[cfg(feature = "lang-ron")] (Lang::Ron, 557), #[cfg(feature = "lang-slk")] (Lang::Slk, 530), #[cfg(feature = "lang-slv")] (Lang::Slv, 687), #[cfg(feature = "lang-sna")] (Lang::Sna, 706), #[cfg(feature = "lang-spa")] (Lang::Spa, 575), #[cfg(feature = "lang-swe")] (Lang::Swe, 817), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 1000), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 767), #[cfg(feature = "lang-tur")] (Lang::Tur, 591), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 837), #[cfg(feature = "lang-vie")] (Lang::Vie, 941), #[cfg(feature = "lang-zul")] (Lang::Zul, 950)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 728), #[cfg(feature = "lang-aka")] (Lang::Aka, 717), #[cfg(feature = "lang-aze")] (Lang::Aze, 846), #[cfg(feature = "lang-cat")] (Lang::Cat, 577), #[cfg(feature = "lang-ces")] (Lang::Ces, 782), #[cfg(feature = "lang-cym")] (Lang::Cym, 797), #[cfg(feature = "lang-dan")] (Lang::Dan, 789), #[cfg(feature = "lang-deu")] (Lang::Deu, 870), #[cfg(feature = "lang-eng")] (Lang::Eng, 1000), #[cfg(feature = "lang-epo")] (Lang::Epo, 631), #[cfg(feature = "lang-est")] (Lang::Est, 649), #[cfg(feature = "lang-fin")] (Lang::Fin, 672), #[cfg(feature = "lang-fra")] (Lang::Fra, 537), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 603), #[cfg(feature = "lang-hun")] (Lang::Hun, 656), #[cfg(feature = "lang-ind")] (Lang::Ind, 760), #[cfg(feature = "lang-ita")] (Lang::Ita, 660), #[cfg(feature = "lang-jav")] (Lang::Jav, 793), #[cfg(feature = "lang-lat")] (Lang::Lat, 685), #[cfg(feature = "lang-lav")] (Lang::Lav, 536), #[cfg(feature = "lang-lit")] (Lang::Lit, 536), #[cfg(feature = "lang-nld")] (Lang::Nld, 832), #[cfg(feature = "lang-nob")] (Lang::Nob, 796), #[cfg(feature = "lang-pol")] (Lang::Pol, 705), #[cfg(feature = "lang-por")] (Lang::Por, 557), #[cfg(feature = "lang-ron")] (Lang::Ron, 536), #[cfg(feature = "lang-slk")] (Lang::Slk, 804), #[cfg(feature = "lang-slv")] (Lang::Slv, 685), #[cfg(feature = "lang-sna")] (Lang::Sna, 848), #[cfg(feature = "lang-spa")] (Lang::Spa, 722), #[cfg(feature = "lang-swe")] (Lang::Swe, 907), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 690), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 803), #[cfg(feature = "lang-tur")] (Lang::Tur, 907), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 909), #[cfg(feature = "lang-vie")] (Lang::Vie, 913), #[cfg(feature = "lang-zul")] (Lang::Zul, 806)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 971), #[cfg(feature = "lang-aka")] (Lang::Aka, 833), #[cfg(feature = "lang-aze")] (Lang::Aze, 890), #[cfg(feature = "lang-cat")] (Lang::Cat, 893), #[cfg(feature = "lang-ces")] (Lang::Ces, 754), #[cfg(feature = "lang-cym")] (Lang::Cym, 820), #[cfg(feature = "lang-dan")] (Lang::Dan, 837), #[cfg(feature = "lang-deu")] (Lang::Deu, 861), #[cfg(feature = "lang-eng")] (Lang::Eng, 861), #[cfg(feature = "lang-epo")] (Lang::Epo, 854), #[cfg(feature = "lang-est")] (Lang::Est, 891), #[cfg(feature = "lang-fin")] (Lang::Fin, 928), #[cfg(feature = "lang-fra")] (Lang::Fra, 860), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 925), #[cfg(feature = "lang-hun")] (Lang::Hun, 804), #[cfg(feature = "lang-ind")] (Lang::Ind, 777), #[cfg(feature = "lang-ita")] (Lang::Ita, 993), #[cfg(feature = "lang-jav")] (Lang::Jav, 819), #[cfg(feature = "lang-lat")] (Lang::Lat, 973), #[cfg(feature = "lang-lav")] (Lang::Lav, 927), #[cfg(feature = "lang-lit")] (Lang::Lit, 1000), #[cfg(feature = "lang-nld")] (Lang::Nld, 831), #[cfg(feature = "lang-nob")] (Lang::Nob, 844), #[cfg(feature = "lang-pol")] (Lang::Pol, 956), #[cfg(feature = "lang-por")] (Lang::Por, 850), #[cfg(feature = "lang-ron")] (Lang::Ron, 933), #[cfg(feature = "lang-slk")] (Lang::Slk, 816), #[cfg(feature = "lang-slv")] (Lang::Slv, 945), #[cfg(feature = "lang-sna")] (Lang::Sna, 824), #[cfg(feature = "lang-spa")] (Lang::Spa, 834), #[cfg(feature = "lang-swe")] (Lang::Swe, 848), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 789), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 845), #[cfg(feature = "lang-tur")] (Lang::Tur, 885), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 948), #[cfg(feature = "lang-vie")] (Lang::Vie, 822), #[cfg(feature = "lang-zul")] (Lang::Zul, 780)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 538), #[cfg(feature = "lang-aze")] (Lang::Aze, 538), #[cfg(feature = "lang-cat")] (Lang::Cat, 538), #[cfg(feature = "lang-ces")] (Lang::Ces, 714), #[cfg(feature = "lang-cym")] (Lang::Cym, 538), #[cfg(feature = "lang-dan")] (Lang::Dan, 538), #[cfg(feature = "lang-deu")] (Lang::Deu, 616), #[cfg(feature = "lang-eng")] (Lang::Eng, 538), #[cfg(feature = "lang-epo")] (Lang::Epo, 1000), #[cfg(feature = "lang-est")] (Lang::Est, 890), #[cfg(feature = "lang-fin")] (Lang::Fin, 873), #[cfg(feature = "lang-fra")] (Lang::Fra, 538), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 899), #[cfg(feature = "lang-hun")] (Lang::Hun, 758), #[cfg(feature = "lang-ind")] (Lang::Ind, 538), #[cfg(feature = "lang-ita")] (Lang::Ita, 538), #[cfg(feature = "lang-jav")] (Lang::Jav, 548), #[cfg(feature = "lang-lat")] (Lang::Lat, 538), #[cfg(feature = "lang-lav")] (Lang::Lav, 708), #[cfg(feature = "lang-lit")] (Lang::Lit, 696), #[cfg(feature = "lang-nld")] (Lang::Nld, 736), #[cfg(feature = "lang-nob")] (Lang::Nob, 614), #[cfg(feature = "lang-pol")] (Lang::Pol, 730), #[cfg(feature = "lang-por")] (Lang::Por, 538), #[cfg(feature = "lang-ron")] (Lang::Ron, 538), #[cfg(feature = "lang-slk")] (Lang::Slk, 707), #[cfg(feature = "lang-slv")] (Lang::Slv, 844), #[cfg(feature = "lang-sna")] (Lang::Sna, 538), #[cfg(feature = "lang-spa")] (Lang::Spa, 538), #[cfg(feature = "lang-swe")] (Lang::Swe, 538), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 538), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 538), #[cfg(feature = "lang-tur")] (Lang::Tur, 538), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 538), #[cfg(feature = "lang-vie")] (Lang::Vie, 538), #[cfg(feature = "lang-zul")] (Lang::Zul, 538)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 814), #[cfg(feature = "lang-aka")] (Lang::Aka, 759), #[cfg(feature = "lang-aze")] (Lang::Aze, 753), #[cfg(feature = "lang-cat")] (Lang::Cat, 573), #[cfg(feature = "lang-ces")] (Lang::Ces, 812), #[cfg(feature = "lang-cym")] (Lang::Cym, 573), #[cfg(feature = "lang-dan")] (Lang::Dan, 774), #[cfg(feature = "lang-deu")] (Lang::Deu, 629), #[cfg(feature = "lang-eng")] (Lang::Eng, 573), #[cfg(feature = "lang-epo")] (Lang::Epo, 947), #[cfg(feature = "lang-est")] (Lang::Est, 824), #[cfg(feature = "lang-fin")] (Lang::Fin, 918), #[cfg(feature = "lang-fra")] (Lang::Fra, 573), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 804), #[cfg(feature = "lang-hun")] (Lang::Hun, 882), #[cfg(feature = "lang-ind")] (Lang::Ind, 932), #[cfg(feature = "lang-ita")] (Lang::Ita, 573), #[cfg(feature = "lang-jav")] (Lang::Jav, 964), #[cfg(feature = "lang-lat")] (Lang::Lat, 573), #[cfg(feature = "lang-lav")] (Lang::Lav, 810), #[cfg(feature = "lang-lit")] (Lang::Lit, 872), #[cfg(feature = "lang-nld")] (Lang::Nld, 723), #[cfg(feature = "lang-nob")] (Lang::Nob, 787), #[cfg(feature = "lang-pol")] (Lang::Pol, 824), #[cfg(feature = "lang-por")] (Lang::Por, 573), #[cfg(feature = "lang-ron")] (Lang::Ron, 573), #[cfg(feature = "lang-slk")] (Lang::Slk, 842), #[cfg(feature = "lang-slv")] (Lang::Slv, 831), #[cfg(feature = "lang-sna")] (Lang::Sna, 1000), #[cfg(feature = "lang-spa")] (Lang::Spa, 573), #[cfg(feature = "lang-swe")] (Lang::Swe, 783), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 871), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 833), #[cfg(feature = "lang-tur")] (Lang::Tur, 912), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 795), #[cfg(feature = "lang-vie")] (Lang::Vie, 644), #[cfg(feature = "lang-zul")] (Lang::Zul, 978)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 815), #[cfg(feature = "lang-aka")] (Lang::Aka, 558), #[cfg(feature = "lang-aze")] (Lang::Aze, 939), #[cfg(feature = "lang-cat")] (Lang::Cat, 949), #[cfg(feature = "lang-ces")] (Lang::Ces, 749), #[cfg(feature = "lang-cym")] (Lang::Cym, 823), #[cfg(feature = "lang-dan")] (Lang::Dan, 904), #[cfg(feature = "lang-deu")] (Lang::Deu, 773), #[cfg(feature = "lang-eng")] (Lang::Eng, 832), #[cfg(feature = "lang-epo")] (Lang::Epo, 972), #[cfg(feature = "lang-est")] (Lang::Est, 1000), #[cfg(feature = "lang-fin")] (Lang::Fin, 890), #[cfg(feature = "lang-fra")] (Lang::Fra, 901), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 778), #[cfg(feature = "lang-hun")] (Lang::Hun, 891), #[cfg(feature = "lang-ind")] (Lang::Ind, 752), #[cfg(feature = "lang-ita")] (Lang::Ita, 933), #[cfg(feature = "lang-jav")] (Lang::Jav, 811), #[cfg(feature = "lang-lat")] (Lang::Lat, 705), #[cfg(feature = "lang-lav")] (Lang::Lav, 742), #[cfg(feature = "lang-lit")] (Lang::Lit, 771), #[cfg(feature = "lang-nld")] (Lang::Nld, 774), #[cfg(feature = "lang-nob")] (Lang::Nob, 909), #[cfg(feature = "lang-pol")] (Lang::Pol, 745), #[cfg(feature = "lang-por")] (Lang::Por, 736), #[cfg(feature = "lang-ron")] (Lang::Ron, 894), #[cfg(feature = "lang-slk")] (Lang::Slk, 795), #[cfg(feature = "lang-slv")] (Lang::Slv, 786), #[cfg(feature = "lang-sna")] (Lang::Sna, 558), #[cfg(feature = "lang-spa")] (Lang::Spa, 941), #[cfg(feature = "lang-swe")] (Lang::Swe, 908), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 833), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 996), #[cfg(feature = "lang-tur")] (Lang::Tur, 933), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 986), #[cfg(feature = "lang-vie")] (Lang::Vie, 628), #[cfg(feature = "lang-zul")] (Lang::Zul, 970)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 829), #[cfg(feature = "lang-aka")] (Lang::Aka, 972), #[cfg(feature = "lang-aze")] (Lang::Aze, 941), #[cfg(feature = "lang-cat")] (Lang::Cat, 844), #[cfg(feature = "lang-ces")] (Lang::Ces, 905), #[cfg(feature = "lang-cym")] (Lang::Cym, 978), #[cfg(feature = "lang-dan")] (Lang::Dan, 840), #[cfg(feature = "lang-deu")] (Lang::Deu, 771), #[cfg(feature = "lang-eng")] (Lang::Eng, 792), #[cfg(feature = "lang-epo")] (Lang::Epo, 751), #[cfg(feature = "lang-est")] (Lang::Est, 929), #[cfg(feature = "lang-fin")] (Lang::Fin, 849), #[cfg(feature = "lang-fra")] (Lang::Fra, 811), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 968), #[cfg(feature = "lang-hun")] (Lang::Hun, 993), #[cfg(feature = "lang-ind")] (Lang::Ind, 949), #[cfg(feature = "lang-ita")] (Lang::Ita, 745), #[cfg(feature = "lang-jav")] (Lang::Jav, 880), #[cfg(feature = "lang-lat")] (Lang::Lat, 1000), #[cfg(feature = "lang-lav")] (Lang::Lav, 896), #[cfg(feature = "lang-lit")] (Lang::Lit, 937), #[cfg(feature = "lang-nld")] (Lang::Nld, 795), #[cfg(feature = "lang-nob")] (Lang::Nob, 875), #[cfg(feature = "lang-pol")] (Lang::Pol, 796), #[cfg(feature = "lang-por")] (Lang::Por, 915), #[cfg(feature = "lang-ron")] (Lang::Ron, 764), #[cfg(feature = "lang-slk")] (Lang::Slk, 915), #[cfg(feature = "lang-slv")] (Lang::Slv, 844), #[cfg(feature = "lang-sna")] (Lang::Sna, 948), #[cfg(feature = "lang-spa")] (Lang::Spa, 796), #[cfg(feature = "lang-swe")] (Lang::Swe, 853), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 949), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 916), #[cfg(feature = "lang-tur")] (Lang::Tur, 906), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 852), #[cfg(feature = "lang-vie")] (Lang::Vie, 779), #[cfg(feature = "lang-zul")] (Lang::Zul, 888)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 920), #[cfg(feature = "lang-aka")] (Lang::Aka, 905), #[cfg(feature = "lang-aze")] (Lang::Aze, 851), #[cfg(feature = "lang-cat")] (Lang::Cat, 828), #[cfg(feature = "lang-ces")] (Lang::Ces, 898), #[cfg(feature = "lang-cym")] (Lang::Cym, 949), #[cfg(feature = "lang-dan")] (Lang::Dan, 853), #[cfg(feature = "lang-deu")] (Lang::Deu, 965), #[cfg(feature = "lang-eng")] (Lang::Eng, 903), #[cfg(feature = "lang-epo")] (Lang::Epo, 820), #[cfg(feature = "lang-est")] (Lang::Est, 762), #[cfg(feature = "lang-fin")] (Lang::Fin, 936), #[cfg(feature = "lang-fra")] (Lang::Fra, 885), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 842), #[cfg(feature = "lang-hun")] (Lang::Hun, 881), #[cfg(feature = "lang-ind")] (Lang::Ind, 972), #[cfg(feature = "lang-ita")] (Lang::Ita, 877), #[cfg(feature = "lang-jav")] (Lang::Jav, 1000), #[cfg(feature = "lang-lat")] (Lang::Lat, 811), #[cfg(feature = "lang-lav")] (Lang::Lav, 849), #[cfg(feature = "lang-lit")] (Lang::Lit, 761), #[cfg(feature = "lang-nld")] (Lang::Nld, 975), #[cfg(feature = "lang-nob")] (Lang::Nob, 871), #[cfg(feature = "lang-pol")] (Lang::Pol, 875), #[cfg(feature = "lang-por")] (Lang::Por, 789), #[cfg(feature = "lang-ron")] (Lang::Ron, 808), #[cfg(feature = "lang-slk")] (Lang::Slk, 835), #[cfg(feature = "lang-slv")] (Lang::Slv, 911), #[cfg(feature = "lang-sna")] (Lang::Sna, 890), #[cfg(feature = "lang-spa")] (Lang::Spa, 848), #[cfg(feature = "lang-swe")] (Lang::Swe, 870), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 971), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 820), #[cfg(feature = "lang-tur")] (Lang::Tur, 842), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 846), #[cfg(feature = "lang-vie")] (Lang::Vie, 942), #[cfg(feature = "lang-zul")] (Lang::Zul, 961)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 786), #[cfg(feature = "lang-aka")] (Lang::Aka, 852), #[cfg(feature = "lang-aze")] (Lang::Aze, 726), #[cfg(feature = "lang-cat")] (Lang::Cat, 806), #[cfg(feature = "lang-ces")] (Lang::Ces, 931), #[cfg(feature = "lang-cym")] (Lang::Cym, 815), #[cfg(feature = "lang-dan")] (Lang::Dan, 921), #[cfg(feature = "lang-deu")] (Lang::Deu, 657), #[cfg(feature = "lang-eng")] (Lang::Eng, 910), #[cfg(feature = "lang-epo")] (Lang::Epo, 900), #[cfg(feature = "lang-est")] (Lang::Est, 749), #[cfg(feature = "lang-fin")] (Lang::Fin, 828), #[cfg(feature = "lang-fra")] (Lang::Fra, 898), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 892), #[cfg(feature = "lang-hun")] (Lang::Hun, 796), #[cfg(feature = "lang-ind")] (Lang::Ind, 640), #[cfg(feature = "lang-ita")] (Lang::Ita, 938), #[cfg(feature = "lang-jav")] (Lang::Jav, 696), #[cfg(feature = "lang-lat")] (Lang::Lat, 843), #[cfg(feature = "lang-lav")] (Lang::Lav, 687), #[cfg(feature = "lang-lit")] (Lang::Lit, 814), #[cfg(feature = "lang-nld")] (Lang::Nld, 792), #[cfg(feature = "lang-nob")] (Lang::Nob, 925), #[cfg(feature = "lang-pol")] (Lang::Pol, 910), #[cfg(feature = "lang-por")] (Lang::Por, 1000), #[cfg(feature = "lang-ron")] (Lang::Ron, 791), #[cfg(feature = "lang-slk")] (Lang::Slk, 943), #[cfg(feature = "lang-slv")] (Lang::Slv, 910), #[cfg(feature = "lang-sna")] (Lang::Sna, 868), #[cfg(feature = "lang-spa")] (Lang::Spa, 919), #[cfg(feature = "lang-swe")] (Lang::Swe, 947), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 722), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 608), #[cfg(feature = "lang-tur")] (Lang::Tur, 637), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 795), #[cfg(feature = "lang-vie")] (Lang::Vie, 761), #[cfg(feature = "lang-zul")] (Lang::Zul, 933)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 651), #[cfg(feature = "lang-aka")] (Lang::Aka, 687), #[cfg(feature = "lang-aze")] (Lang::Aze, 550), #[cfg(feature = "lang-cat")] (Lang::Cat, 833), #[cfg(feature = "lang-ces")] (Lang::Ces, 990), #[cfg(feature = "lang-cym")] (Lang::Cym, 553), #[cfg(feature = "lang-dan")] (Lang::Dan, 600), #[cfg(feature = "lang-deu")] (Lang::Deu, 550), #[cfg(feature = "lang-eng")] (Lang::Eng, 689), #[cfg(feature = "lang-epo")] (Lang::Epo, 785), #[cfg(feature = "lang-est")] (Lang::Est, 653), #[cfg(feature = "lang-fin")] (Lang::Fin, 685), #[cfg(feature = "lang-fra")] (Lang::Fra, 772), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 985), #[cfg(feature = "lang-hun")] (Lang::Hun, 550), #[cfg(feature = "lang-ind")] (Lang::Ind, 841), #[cfg(feature = "lang-ita")] (Lang::Ita, 746), #[cfg(feature = "lang-jav")] (Lang::Jav, 762), #[cfg(feature = "lang-lat")] (Lang::Lat, 796), #[cfg(feature = "lang-lav")] (Lang::Lav, 786), #[cfg(feature = "lang-lit")] (Lang::Lit, 761), #[cfg(feature = "lang-nld")] (Lang::Nld, 675), #[cfg(feature = "lang-nob")] (Lang::Nob, 634), #[cfg(feature = "lang-pol")] (Lang::Pol, 986), #[cfg(feature = "lang-por")] (Lang::Por, 791), #[cfg(feature = "lang-ron")] (Lang::Ron, 844), #[cfg(feature = "lang-slk")] (Lang::Slk, 1000), #[cfg(feature = "lang-slv")] (Lang::Slv, 977), #[cfg(feature = "lang-sna")] (Lang::Sna, 714), #[cfg(feature = "lang-spa")] (Lang::Spa, 771), #[cfg(feature = "lang-swe")] (Lang::Swe, 622), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 916), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 550), #[cfg(feature = "lang-tur")] (Lang::Tur, 550), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 550), #[cfg(feature = "lang-vie")] (Lang::Vie, 726), #[cfg(feature = "lang-zul")] (Lang::Zul, 668)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 640), #[cfg(feature = "lang-aze")] (Lang::Aze, 1000), #[cfg(feature = "lang-cat")] (Lang::Cat, 798), #[cfg(feature = "lang-ces")] (Lang::Ces, 640), #[cfg(feature = "lang-cym")] (Lang::Cym, 640), #[cfg(feature = "lang-dan")] (Lang::Dan, 640), #[cfg(feature = "lang-deu")] (Lang::Deu, 640), #[cfg(feature = "lang-eng")] (Lang::Eng, 660), #[cfg(feature = "lang-est")] (Lang::Est, 640), #[cfg(feature = "lang-fin")] (Lang::Fin, 640), #[cfg(feature = "lang-fra")] (Lang::Fra, 792), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 640), #[cfg(feature = "lang-hun")] (Lang::Hun, 640), #[cfg(feature = "lang-ind")] (Lang::Ind, 640), #[cfg(feature = "lang-ita")] (Lang::Ita, 669), #[cfg(feature = "lang-jav")] (Lang::Jav, 640), #[cfg(feature = "lang-lat")] (Lang::Lat, 929), #[cfg(feature = "lang-lav")] (Lang::Lav, 640), #[cfg(feature = "lang-lit")] (Lang::Lit, 640), #[cfg(feature = "lang-nld")] (Lang::Nld, 640), #[cfg(feature = "lang-nob")] (Lang::Nob, 640), #[cfg(feature = "lang-pol")] (Lang::Pol, 640), #[cfg(feature = "lang-por")] (Lang::Por, 764), #[cfg(feature = "lang-ron")] (Lang::Ron, 640), #[cfg(feature = "lang-slk")] (Lang::Slk, 640), #[cfg(feature = "lang-slv")] (Lang::Slv, 640), #[cfg(feature = "lang-sna")] (Lang::Sna, 640), #[cfg(feature = "lang-spa")] (Lang::Spa, 704), #[cfg(feature = "lang-swe")] (Lang::Swe, 640), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 640), #[cfg(feature = "lang-tur")] (Lang::Tur, 640), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 954), #[cfg(feature = "lang-vie")] (Lang::Vie, 955), #[cfg(feature = "lang-zul")] (Lang::Zul, 640)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 857), #[cfg(feature = "lang-aka")] (Lang::Aka, 838), #[cfg(feature = "lang-aze")] (Lang::Aze, 939), #[cfg(feature = "lang-cat")] (Lang::Cat, 870), #[cfg(feature = "lang-ces")] (Lang::Ces, 972), #[cfg(feature = "lang-cym")] (Lang::Cym, 862), #[cfg(feature = "lang-dan")] (Lang::Dan, 1000), #[cfg(feature = "lang-deu")] (Lang::Deu, 945), #[cfg(feature = "lang-eng")] (Lang::Eng, 854), #[cfg(feature = "lang-epo")] (Lang::Epo, 856), #[cfg(feature = "lang-est")] (Lang::Est, 678), #[cfg(feature = "lang-fin")] (Lang::Fin, 629), #[cfg(feature = "lang-fra")] (Lang::Fra, 840), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 978), #[cfg(feature = "lang-hun")] (Lang::Hun, 694), #[cfg(feature = "lang-ind")] (Lang::Ind, 837), #[cfg(feature = "lang-ita")] (Lang::Ita, 846), #[cfg(feature = "lang-jav")] (Lang::Jav, 787), #[cfg(feature = "lang-lat")] (Lang::Lat, 802), #[cfg(feature = "lang-lav")] (Lang::Lav, 828), #[cfg(feature = "lang-lit")] (Lang::Lit, 937), #[cfg(feature = "lang-nld")] (Lang::Nld, 857), #[cfg(feature = "lang-nob")] (Lang::Nob, 994), #[cfg(feature = "lang-pol")] (Lang::Pol, 961), #[cfg(feature = "lang-por")] (Lang::Por, 847), #[cfg(feature = "lang-ron")] (Lang::Ron, 943), #[cfg(feature = "lang-slk")] (Lang::Slk, 991), #[cfg(feature = "lang-slv")] (Lang::Slv, 976), #[cfg(feature = "lang-sna")] (Lang::Sna, 867), #[cfg(feature = "lang-spa")] (Lang::Spa, 865), #[cfg(feature = "lang-swe")] (Lang::Swe, 935), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 711), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 972), #[cfg(feature = "lang-tur")] (Lang::Tur, 955), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 965), #[cfg(feature = "lang-vie")] (Lang::Vie, 645), #[cfg(feature = "lang-zul")] (Lang::Zul, 538)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 794), #[cfg(feature = "lang-aka")] (Lang::Aka, 964), #[cfg(feature = "lang-aze")] (Lang::Aze, 762), #[cfg(feature = "lang-cat")] (Lang::Cat, 914), #[cfg(feature = "lang-ces")] (Lang::Ces, 860), #[cfg(feature = "lang-cym")] (Lang::Cym, 641), #[cfg(feature = "lang-dan")] (Lang::Dan, 802), #[cfg(feature = "lang-deu")] (Lang::Deu, 802), #[cfg(feature = "lang-eng")] (Lang::Eng, 811), #[cfg(feature = "lang-epo")] (Lang::Epo, 788), #[cfg(feature = "lang-est")] (Lang::Est, 1000), #[cfg(feature = "lang-fin")] (Lang::Fin, 955), #[cfg(feature = "lang-fra")] (Lang::Fra, 912), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 837), #[cfg(feature = "lang-hun")] (Lang::Hun, 972), #[cfg(feature = "lang-ind")] (Lang::Ind, 807), #[cfg(feature = "lang-ita")] (Lang::Ita, 799), #[cfg(feature = "lang-jav")] (Lang::Jav, 803), #[cfg(feature = "lang-lat")] (Lang::Lat, 978), #[cfg(feature = "lang-lav")] (Lang::Lav, 969), #[cfg(feature = "lang-lit")] (Lang::Lit, 996), #[cfg(feature = "lang-nld")] (Lang::Nld, 708), #[cfg(feature = "lang-nob")] (Lang::Nob, 808), #[cfg(feature = "lang-pol")] (Lang::Pol, 766), #[cfg(feature = "lang-por")] (Lang::Por, 967), #[cfg(feature = "lang-ron")] (Lang::Ron, 773), #[cfg(feature = "lang-slk")] (Lang::Slk, 827), #[cfg(feature = "lang-slv")] (Lang::Slv, 823), #[cfg(feature = "lang-sna")] (Lang::Sna, 730), #[cfg(feature = "lang-spa")] (Lang::Spa, 915), #[cfg(feature = "lang-swe")] (Lang::Swe, 798), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 840), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 654), #[cfg(feature = "lang-tur")] (Lang::Tur, 755), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 881), #[cfg(feature = "lang-vie")] (Lang::Vie, 527), #[cfg(feature = "lang-zul")] (Lang::Zul, 651)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 818), #[cfg(feature = "lang-aka")] (Lang::Aka, 650), #[cfg(feature = "lang-aze")] (Lang::Aze, 742), #[cfg(feature = "lang-cat")] (Lang::Cat, 904), #[cfg(feature = "lang-ces")] (Lang::Ces, 818), #[cfg(feature = "lang-cym")] (Lang::Cym, 740), #[cfg(feature = "lang-dan")] (Lang::Dan, 864), #[cfg(feature = "lang-deu")] (Lang::Deu, 831), #[cfg(feature = "lang-eng")] (Lang::Eng, 1000), #[cfg(feature = "lang-epo")] (Lang::Epo, 824), #[cfg(feature = "lang-est")] (Lang::Est, 867), #[cfg(feature = "lang-fin")] (Lang::Fin, 905), #[cfg(feature = "lang-fra")] (Lang::Fra, 940), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 796), #[cfg(feature = "lang-hun")] (Lang::Hun, 835), #[cfg(feature = "lang-ind")] (Lang::Ind, 816), #[cfg(feature = "lang-ita")] (Lang::Ita, 909), #[cfg(feature = "lang-jav")] (Lang::Jav, 776), #[cfg(feature = "lang-lat")] (Lang::Lat, 967), #[cfg(feature = "lang-lav")] (Lang::Lav, 843), #[cfg(feature = "lang-lit")] (Lang::Lit, 903), #[cfg(feature = "lang-nld")] (Lang::Nld, 842), #[cfg(feature = "lang-nob")] (Lang::Nob, 915), #[cfg(feature = "lang-pol")] (Lang::Pol, 706), #[cfg(feature = "lang-por")] (Lang::Por, 862), #[cfg(feature = "lang-ron")] (Lang::Ron, 856), #[cfg(feature = "lang-slk")] (Lang::Slk, 801), #[cfg(feature = "lang-slv")] (Lang::Slv, 785), #[cfg(feature = "lang-sna")] (Lang::Sna, 699), #[cfg(feature = "lang-spa")] (Lang::Spa, 803), #[cfg(feature = "lang-swe")] (Lang::Swe, 914), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 839), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 701), #[cfg(feature = "lang-tur")] (Lang::Tur, 773), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 740), #[cfg(feature = "lang-vie")] (Lang::Vie, 840), #[cfg(feature = "lang-zul")] (Lang::Zul, 766)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 631), #[cfg(feature = "lang-aka")] (Lang::Aka, 787), #[cfg(feature = "lang-aze")] (Lang::Aze, 834), #[cfg(feature = "lang-cat")] (Lang::Cat, 797), #[cfg(feature = "lang-ces")] (Lang::Ces, 750), #[cfg(feature = "lang-cym")] (Lang::Cym, 813), #[cfg(feature = "lang-dan")] (Lang::Dan, 702), #[cfg(feature = "lang-deu")] (Lang::Deu, 929), #[cfg(feature = "lang-eng")] (Lang::Eng, 713), #[cfg(feature = "lang-epo")] (Lang::Epo, 819), #[cfg(feature = "lang-est")] (Lang::Est, 917), #[cfg(feature = "lang-fin")] (Lang::Fin, 851), #[cfg(feature = "lang-fra")] (Lang::Fra, 849), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 830), #[cfg(feature = "lang-hun")] (Lang::Hun, 564), #[cfg(feature = "lang-ind")] (Lang::Ind, 826), #[cfg(feature = "lang-ita")] (Lang::Ita, 789), #[cfg(feature = "lang-jav")] (Lang::Jav, 817), #[cfg(feature = "lang-lat")] (Lang::Lat, 998), #[cfg(feature = "lang-lav")] (Lang::Lav, 966), #[cfg(feature = "lang-lit")] (Lang::Lit, 873), #[cfg(feature = "lang-nld")] (Lang::Nld, 564), #[cfg(feature = "lang-nob")] (Lang::Nob, 671), #[cfg(feature = "lang-pol")] (Lang::Pol, 692), #[cfg(feature = "lang-por")] (Lang::Por, 806), #[cfg(feature = "lang-ron")] (Lang::Ron, 860), #[cfg(feature = "lang-slk")] (Lang::Slk, 745), #[cfg(feature = "lang-slv")] (Lang::Slv, 689), #[cfg(feature = "lang-sna")] (Lang::Sna, 966), #[cfg(feature = "lang-spa")] (Lang::Spa, 779), #[cfg(feature = "lang-swe")] (Lang::Swe, 695), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 712), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 759), #[cfg(feature = "lang-tur")] (Lang::Tur, 709), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 859), #[cfg(feature = "lang-vie")] (Lang::Vie, 893), #[cfg(feature = "lang-zul")] (Lang::Zul, 1000)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 870), #[cfg(feature = "lang-aze")] (Lang::Aze, 1000), #[cfg(feature = "lang-cat")] (Lang::Cat, 643), #[cfg(feature = "lang-ces")] (Lang::Ces, 916), #[cfg(feature = "lang-cym")] (Lang::Cym, 559), #[cfg(feature = "lang-dan")] (Lang::Dan, 739), #[cfg(feature = "lang-deu")] (Lang::Deu, 697), #[cfg(feature = "lang-eng")] (Lang::Eng, 708), #[cfg(feature = "lang-epo")] (Lang::Epo, 668), #[cfg(feature = "lang-est")] (Lang::Est, 820), #[cfg(feature = "lang-fin")] (Lang::Fin, 750), #[cfg(feature = "lang-fra")] (Lang::Fra, 559), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 929), #[cfg(feature = "lang-hun")] (Lang::Hun, 850), #[cfg(feature = "lang-ind")] (Lang::Ind, 559), #[cfg(feature = "lang-ita")] (Lang::Ita, 676), #[cfg(feature = "lang-jav")] (Lang::Jav, 559), #[cfg(feature = "lang-lat")] (Lang::Lat, 656), #[cfg(feature = "lang-lav")] (Lang::Lav, 861), #[cfg(feature = "lang-lit")] (Lang::Lit, 822), #[cfg(feature = "lang-nld")] (Lang::Nld, 899), #[cfg(feature = "lang-nob")] (Lang::Nob, 763), #[cfg(feature = "lang-pol")] (Lang::Pol, 559), #[cfg(feature = "lang-por")] (Lang::Por, 589), #[cfg(feature = "lang-ron")] (Lang::Ron, 582), #[cfg(feature = "lang-slk")] (Lang::Slk, 915), #[cfg(feature = "lang-slv")] (Lang::Slv, 945), #[cfg(feature = "lang-sna")] (Lang::Sna, 810), #[cfg(feature = "lang-spa")] (Lang::Spa, 559), #[cfg(feature = "lang-swe")] (Lang::Swe, 762), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 559), #[cfg(feature = "lang-tur")] (Lang::Tur, 989), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 811), #[cfg(feature = "lang-vie")] (Lang::Vie, 803), #[cfg(feature = "lang-zul")] (Lang::Zul, 559)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 740), #[cfg(feature = "lang-aka")] (Lang::Aka, 855), #[cfg(feature = "lang-cat")] (Lang::Cat, 577), #[cfg(feature = "lang-ces")] (Lang::Ces, 577), #[cfg(feature = "lang-cym")] (Lang::Cym, 846), #[cfg(feature = "lang-dan")] (Lang::Dan, 577), #[cfg(feature = "lang-deu")] (Lang::Deu, 675), #[cfg(feature = "lang-eng")] (Lang::Eng, 698), #[cfg(feature = "lang-est")] (Lang::Est, 577), #[cfg(feature = "lang-fin")] (Lang::Fin, 577), #[cfg(feature = "lang-fra")] (Lang::Fra, 577), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 577), #[cfg(feature = "lang-hun")] (Lang::Hun, 577), #[cfg(feature = "lang-ind")] (Lang::Ind, 577), #[cfg(feature = "lang-ita")] (Lang::Ita, 577), #[cfg(feature = "lang-jav")] (Lang::Jav, 787), #[cfg(feature = "lang-lat")] (Lang::Lat, 577), #[cfg(feature = "lang-lav")] (Lang::Lav, 577), #[cfg(feature = "lang-lit")] (Lang::Lit, 577), #[cfg(feature = "lang-nld")] (Lang::Nld, 741), #[cfg(feature = "lang-nob")] (Lang::Nob, 577), #[cfg(feature = "lang-pol")] (Lang::Pol, 951), #[cfg(feature = "lang-por")] (Lang::Por, 577), #[cfg(feature = "lang-ron")] (Lang::Ron, 577), #[cfg(feature = "lang-slk")] (Lang::Slk, 577), #[cfg(feature = "lang-slv")] (Lang::Slv, 577), #[cfg(feature = "lang-sna")] (Lang::Sna, 1000), #[cfg(feature = "lang-spa")] (Lang::Spa, 577), #[cfg(feature = "lang-swe")] (Lang::Swe, 577), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 721), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 953), #[cfg(feature = "lang-tur")] (Lang::Tur, 577), #[cfg(feature = "lang-vie")] (Lang::Vie, 577), #[cfg(feature = "lang-zul")] (Lang::Zul, 840)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 783), #[cfg(feature = "lang-aze")] (Lang::Aze, 1000), #[cfg(feature = "lang-cat")] (Lang::Cat, 783), #[cfg(feature = "lang-ces")] (Lang::Ces, 783), #[cfg(feature = "lang-cym")] (Lang::Cym, 783), #[cfg(feature = "lang-dan")] (Lang::Dan, 783), #[cfg(feature = "lang-deu")] (Lang::Deu, 783), #[cfg(feature = "lang-eng")] (Lang::Eng, 783), #[cfg(feature = "lang-est")] (Lang::Est, 783), #[cfg(feature = "lang-fin")] (Lang::Fin, 783), #[cfg(feature = "lang-fra")] (Lang::Fra, 821), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 783), #[cfg(feature = "lang-hun")] (Lang::Hun, 783), #[cfg(feature = "lang-ind")] (Lang::Ind, 783), #[cfg(feature = "lang-ita")] (Lang::Ita, 783), #[cfg(feature = "lang-jav")] (Lang::Jav, 783), #[cfg(feature = "lang-lat")] (Lang::Lat, 783), #[cfg(feature = "lang-lav")] (Lang::Lav, 783), #[cfg(feature = "lang-lit")] (Lang::Lit, 783), #[cfg(feature = "lang-nld")] (Lang::Nld, 783), #[cfg(feature = "lang-nob")] (Lang::Nob, 783), #[cfg(feature = "lang-pol")] (Lang::Pol, 783), #[cfg(feature = "lang-por")] (Lang::Por, 783), #[cfg(feature = "lang-ron")] (Lang::Ron, 783), #[cfg(feature = "lang-slk")] (Lang::Slk, 783), #[cfg(feature = "lang-slv")] (Lang::Slv, 783), #[cfg(feature = "lang-sna")] (Lang::Sna, 783), #[cfg(feature = "lang-spa")] (Lang::Spa, 783), #[cfg(feature = "lang-swe")] (Lang::Swe, 783), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 783), #[cfg(feature = "lang-tur")] (Lang::Tur, 783), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 783), #[cfg(feature = "lang-vie")] (Lang::Vie, 783), #[cfg(feature = "lang-zul")] (Lang::Zul, 783)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 623), #[cfg(feature = "lang-aka")] (Lang::Aka, 738), #[cfg(feature = "lang-aze")] (Lang::Aze, 722), #[cfg(feature = "lang-cat")] (Lang::Cat, 522), #[cfg(feature = "lang-ces")] (Lang::Ces, 661), #[cfg(feature = "lang-cym")] (Lang::Cym, 1000), #[cfg(feature = "lang-dan")] (Lang::Dan, 522), #[cfg(feature = "lang-deu")] (Lang::Deu, 522), #[cfg(feature = "lang-eng")] (Lang::Eng, 717), #[cfg(feature = "lang-est")] (Lang::Est, 522), #[cfg(feature = "lang-fin")] (Lang::Fin, 647), #[cfg(feature = "lang-fra")] (Lang::Fra, 522), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 522), #[cfg(feature = "lang-hun")] (Lang::Hun, 761), #[cfg(feature = "lang-ind")] (Lang::Ind, 718), #[cfg(feature = "lang-ita")] (Lang::Ita, 522), #[cfg(feature = "lang-jav")] (Lang::Jav, 640), #[cfg(feature = "lang-lat")] (Lang::Lat, 522), #[cfg(feature = "lang-lav")] (Lang::Lav, 522), #[cfg(feature = "lang-lit")] (Lang::Lit, 642), #[cfg(feature = "lang-nld")] (Lang::Nld, 522), #[cfg(feature = "lang-nob")] (Lang::Nob, 522), #[cfg(feature = "lang-pol")] (Lang::Pol, 750), #[cfg(feature = "lang-por")] (Lang::Por, 522), #[cfg(feature = "lang-ron")] (Lang::Ron, 522), #[cfg(feature = "lang-slk")] (Lang::Slk, 648), #[cfg(feature = "lang-slv")] (Lang::Slv, 522), #[cfg(feature = "lang-sna")] (Lang::Sna, 708), #[cfg(feature = "lang-spa")] (Lang::Spa, 738), #[cfg(feature = "lang-swe")] (Lang::Swe, 525), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 761), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 892), #[cfg(feature = "lang-tur")] (Lang::Tur, 754), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 678), #[cfg(feature = "lang-vie")] (Lang::Vie, 738), #[cfg(feature = "lang-zul")] (Lang::Zul, 650)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 561), #[cfg(feature = "lang-aze")] (Lang::Aze, 682), #[cfg(feature = "lang-cat")] (Lang::Cat, 561), #[cfg(feature = "lang-ces")] (Lang::Ces, 754), #[cfg(feature = "lang-cym")] (Lang::Cym, 561), #[cfg(feature = "lang-dan")] (Lang::Dan, 561), #[cfg(feature = "lang-deu")] (Lang::Deu, 685), #[cfg(feature = "lang-eng")] (Lang::Eng, 561), #[cfg(feature = "lang-epo")] (Lang::Epo, 561), #[cfg(feature = "lang-est")] (Lang::Est, 561), #[cfg(feature = "lang-fin")] (Lang::Fin, 561), #[cfg(feature = "lang-fra")] (Lang::Fra, 561), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 697), #[cfg(feature = "lang-hun")] (Lang::Hun, 1000), #[cfg(feature = "lang-ind")] (Lang::Ind, 561), #[cfg(feature = "lang-ita")] (Lang::Ita, 778), #[cfg(feature = "lang-jav")] (Lang::Jav, 561), #[cfg(feature = "lang-lat")] (Lang::Lat, 561), #[cfg(feature = "lang-lav")] (Lang::Lav, 755), #[cfg(feature = "lang-lit")] (Lang::Lit, 561), #[cfg(feature = "lang-nld")] (Lang::Nld, 718), #[cfg(feature = "lang-nob")] (Lang::Nob, 561), #[cfg(feature = "lang-pol")] (Lang::Pol, 873), #[cfg(feature = "lang-por")] (Lang::Por, 561), #[cfg(feature = "lang-ron")] (Lang::Ron, 561), #[cfg(feature = "lang-slk")] (Lang::Slk, 722), #[cfg(feature = "lang-slv")] (Lang::Slv, 729), #[cfg(feature = "lang-sna")] (Lang::Sna, 890), #[cfg(feature = "lang-spa")] (Lang::Spa, 561), #[cfg(feature = "lang-swe")] (Lang::Swe, 561), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 561), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 715), #[cfg(feature = "lang-tur")] (Lang::Tur, 589), #[cfg(feature = "lang-uzb")] (Lang::Uzb, 619), #[cfg(feature = "lang-vie")] (Lang::Vie, 561), #[cfg(feature = "lang-zul")] (Lang::Zul, 737)],
+        &[#[cfg(feature = "lang-deu")] (Lang::Deu, 1000)],
+        &[#[cfg(feature = "lang-cat")] (Lang::Cat, 829), #[cfg(feature = "lang-cym")] (Lang::Cym, 821), #[cfg(feature = "lang-fra")] (Lang::Fra, 1000), #[cfg(feature = "lang-ita")] (Lang::Ita, 974), #[cfg(feature = "lang-nld")] (Lang::Nld, 821), #[cfg(feature = "lang-por")] (Lang::Por, 837), #[cfg(feature = "lang-vie")] (Lang::Vie, 821)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 599), #[cfg(feature = "lang-ces")] (Lang::Ces, 985), #[cfg(feature = "lang-cym")] (Lang::Cym, 599), #[cfg(feature = "lang-hun")] (Lang::Hun, 848), #[cfg(feature = "lang-por")] (Lang::Por, 599), #[cfg(feature = "lang-slk")] (Lang::Slk, 1000), #[cfg(feature = "lang-spa")] (Lang::Spa, 599), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 599), #[cfg(feature = "lang-vie")] (Lang::Vie, 599)],
+        &[#[cfg(feature = "lang-cym")] (Lang::Cym, 789), #[cfg(feature = "lang-fra")] (Lang::Fra, 789), #[cfg(feature = "lang-por")] (Lang::Por, 789), #[cfg(feature = "lang-ron")] (Lang::Ron, 793), #[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-por")] (Lang::Por, 1000), #[cfg(feature = "lang-vie")] (Lang::Vie, 720)],
+        &[#[cfg(feature = "lang-cym")] (Lang::Cym, 675), #[cfg(feature = "lang-deu")] (Lang::Deu, 675), #[cfg(feature = "lang-est")] (Lang::Est, 714), #[cfg(feature = "lang-fin")] (Lang::Fin, 990), #[cfg(feature = "lang-slk")] (Lang::Slk, 675), #[cfg(feature = "lang-swe")] (Lang::Swe, 1000), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 738)],
+        &[#[cfg(feature = "lang-dan")] (Lang::Dan, 898), #[cfg(feature = "lang-nob")] (Lang::Nob, 1000), #[cfg(feature = "lang-swe")] (Lang::Swe, 921)],
+        &[#[cfg(feature = "lang-dan")] (Lang::Dan, 1000), #[cfg(feature = "lang-nob")] (Lang::Nob, 945)],
+        &[#[cfg(feature = "lang-aze")] (Lang::Aze, 844), #[cfg(feature = "lang-cat")] (Lang::Cat, 788), #[cfg(feature = "lang-fra")] (Lang::Fra, 788), #[cfg(feature = "lang-por")] (Lang::Por, 1000), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 796), #[cfg(feature = "lang-tur")] (Lang::Tur, 846)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 1000), #[cfg(feature = "lang-cat")] (Lang::Cat, 1000), #[cfg(feature = "lang-cym")] (Lang::Cym, 1000), #[cfg(feature = "lang-fra")] (Lang::Fra, 1000), #[cfg(feature = "lang-ita")] (Lang::Ita, 1000), #[cfg(feature = "lang-jav")] (Lang::Jav, 1000), #[cfg(feature = "lang-nld")] (Lang::Nld, 1000), #[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 622), #[cfg(feature = "lang-cat")] (Lang::Cat, 743), #[cfg(feature = "lang-ces")] (Lang::Ces, 787), #[cfg(feature = "lang-cym")] (Lang::Cym, 622), #[cfg(feature = "lang-fra")] (Lang::Fra, 833), #[cfg(feature = "lang-hun")] (Lang::Hun, 1000), #[cfg(feature = "lang-ita")] (Lang::Ita, 622), #[cfg(feature = "lang-jav")] (Lang::Jav, 622), #[cfg(feature = "lang-nld")] (Lang::Nld, 622), #[cfg(feature = "lang-por")] (Lang::Por, 622), #[cfg(feature = "lang-slk")] (Lang::Slk, 816), #[cfg(feature = "lang-spa")] (Lang::Spa, 622), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 622), #[cfg(feature = "lang-vie")] (Lang::Vie, 622)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 670), #[cfg(feature = "lang-cym")] (Lang::Cym, 670), #[cfg(feature = "lang-fra")] (Lang::Fra, 672), #[cfg(feature = "lang-por")] (Lang::Por, 670), #[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 1000), #[cfg(feature = "lang-cym")] (Lang::Cym, 1000), #[cfg(feature = "lang-fra")] (Lang::Fra, 1000), #[cfg(feature = "lang-nld")] (Lang::Nld, 1000)],
+        &[#[cfg(feature = "lang-cym")] (Lang::Cym, 1000), #[cfg(feature = "lang-ita")] (Lang::Ita, 1000), #[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 625), #[cfg(feature = "lang-cat")] (Lang::Cat, 625), #[cfg(feature = "lang-ces")] (Lang::Ces, 1000), #[cfg(feature = "lang-cym")] (Lang::Cym, 625), #[cfg(feature = "lang-hun")] (Lang::Hun, 625), #[cfg(feature = "lang-por")] (Lang::Por, 625), #[cfg(feature = "lang-slk")] (Lang::Slk, 639), #[cfg(feature = "lang-spa")] (Lang::Spa, 625), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 625), #[cfg(feature = "lang-vie")] (Lang::Vie, 625)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 683), #[cfg(feature = "lang-cym")] (Lang::Cym, 683), #[cfg(feature = "lang-fra")] (Lang::Fra, 683), #[cfg(feature = "lang-ron")] (Lang::Ron, 1000)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 1000), #[cfg(feature = "lang-cat")] (Lang::Cat, 1000), #[cfg(feature = "lang-cym")] (Lang::Cym, 1000), #[cfg(feature = "lang-fra")] (Lang::Fra, 1000), #[cfg(feature = "lang-nld")] (Lang::Nld, 1000)],
+        &[#[cfg(feature = "lang-spa")] (Lang::Spa, 1000), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 1000)],
+        &[#[cfg(feature = "lang-cat")] (Lang::Cat, 1000), #[cfg(feature = "lang-cym")] (Lang::Cym, 1000), #[cfg(feature = "lang-ita")] (Lang::Ita, 1000), #[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 695), #[cfg(feature = "lang-cat")] (Lang::Cat, 938), #[cfg(feature = "lang-ces")] (Lang::Ces, 695), #[cfg(feature = "lang-cym")] (Lang::Cym, 695), #[cfg(feature = "lang-hun")] (Lang::Hun, 712), #[cfg(feature = "lang-pol")] (Lang::Pol, 817), #[cfg(feature = "lang-por")] (Lang::Por, 695), #[cfg(feature = "lang-slk")] (Lang::Slk, 695), #[cfg(feature = "lang-spa")] (Lang::Spa, 1000), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 695), #[cfg(feature = "lang-vie")] (Lang::Vie, 695)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 720), #[cfg(feature = "lang-cym")] (Lang::Cym, 720), #[cfg(feature = "lang-fra")] (Lang::Fra, 720), #[cfg(feature = "lang-por")] (Lang::Por, 720), #[cfg(feature = "lang-slk")] (Lang::Slk, 720), #[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-est")] (Lang::Est, 1000), #[cfg(feature = "lang-por")] (Lang::Por, 743), #[cfg(feature = "lang-vie")] (Lang::Vie, 691)],
+        &[#[cfg(feature = "lang-aze")] (Lang::Aze, 695), #[cfg(feature = "lang-cym")] (Lang::Cym, 695), #[cfg(feature = "lang-deu")] (Lang::Deu, 695), #[cfg(feature = "lang-est")] (Lang::Est, 695), #[cfg(feature = "lang-fin")] (Lang::Fin, 695), #[cfg(feature = "lang-hun")] (Lang::Hun, 728), #[cfg(feature = "lang-swe")] (Lang::Swe, 1000), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 880), #[cfg(feature = "lang-tur")] (Lang::Tur, 695)],
+        &[#[cfg(feature = "lang-dan")] (Lang::Dan, 1000), #[cfg(feature = "lang-nob")] (Lang::Nob, 1000)],
+        &[#[cfg(feature = "lang-cym")] (Lang::Cym, 1000), #[cfg(feature = "lang-fra")] (Lang::Fra, 1000), #[cfg(feature = "lang-ita")] (Lang::Ita, 1000), #[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 904), #[cfg(feature = "lang-cat")] (Lang::Cat, 904), #[cfg(feature = "lang-ces")] (Lang::Ces, 904), #[cfg(feature = "lang-cym")] (Lang::Cym, 904), #[cfg(feature = "lang-hun")] (Lang::Hun, 904), #[cfg(feature = "lang-por")] (Lang::Por, 904), #[cfg(feature = "lang-slk")] (Lang::Slk, 1000), #[cfg(feature = "lang-spa")] (Lang::Spa, 904), #[cfg(feature = "lang-tgl")] (Lang::Tgl, 904), #[cfg(feature = "lang-vie")] (Lang::Vie, 904)],
+        &[#[cfg(feature = "lang-afr")] (Lang::Afr, 1000), #[cfg(feature = "lang-cym")] (Lang::Cym, 1000), #[cfg(feature = "lang-fra")] (Lang::Fra, 1000)],
+        &[#[cfg(feature = "lang-aze")] (Lang::Aze, 1000), #[cfg(feature = "lang-cat")] (Lang::Cat, 671), #[cfg(feature = "lang-cym")] (Lang::Cym, 671), #[cfg(feature = "lang-deu")] (Lang::Deu, 671), #[cfg(feature = "lang-est")] (Lang::Est, 700), #[cfg(feature = "lang-fra")] (Lang::Fra, 671), #[cfg(feature = "lang-hun")] (Lang::Hun, 685), #[cfg(feature = "lang-spa")] (Lang::Spa, 671), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 686), #[cfg(feature = "lang-tur")] (Lang::Tur, 795)],
+        &[#[cfg(feature = "lang-ces")] (Lang::Ces, 1000), #[cfg(feature = "lang-cym")] (Lang::Cym, 736), #[cfg(feature = "lang-slk")] (Lang::Slk, 991), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 990), #[cfg(feature = "lang-vie")] (Lang::Vie, 736)],
+        &[#[cfg(feature = "lang-cym")] (Lang::Cym, 1000), #[cfg(feature = "lang-fra")] (Lang::Fra, 1000)],
+        &[#[cfg(feature = "lang-lav")] (Lang::Lav, 1000)],
+        &[#[cfg(feature = "lang-ron")] (Lang::Ron, 1000), #[cfg(feature = "lang-vie")] (Lang::Vie, 707)],
+        &[#[cfg(feature = "lang-lit")] (Lang::Lit, 1000), #[cfg(feature = "lang-pol")] (Lang::Pol, 953)],
+        &[#[cfg(feature = "lang-hrv")] (Lang::Hrv, 1000), #[cfg(feature = "lang-pol")] (Lang::Pol, 764)],
+        &[#[cfg(feature = "lang-epo")] (Lang::Epo, 1000)],
+        &[#[cfg(feature = "lang-ces")] (Lang::Ces, 960), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 862), #[cfg(feature = "lang-lav")] (Lang::Lav, 862), #[cfg(feature = "lang-lit")] (Lang::Lit, 862), #[cfg(feature = "lang-slk")] (Lang::Slk, 947), #[cfg(feature = "lang-slv")] (Lang::Slv, 1000)],
+        &[#[cfg(feature = "lang-ces")] (Lang::Ces, 1000), #[cfg(feature = "lang-slk")] (Lang::Slk, 1000)],
+        &[#[cfg(feature = "lang-hrv")] (Lang::Hrv, 725), #[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-lav")] (Lang::Lav, 1000)],
+        &[#[cfg(feature = "lang-lit")] (Lang::Lit, 1000)],
+        &[#[cfg(feature = "lang-lit")] (Lang::Lit, 1000), #[cfg(feature = "lang-pol")] (Lang::Pol, 879)],
+        &[#[cfg(feature = "lang-ces")] (Lang::Ces, 1000)],
+        &[#[cfg(feature = "lang-epo")] (Lang::Epo, 1000)],
+        &[#[cfg(feature = "lang-aze")] (Lang::Aze, 1000), #[cfg(feature = "lang-tur")] (Lang::Tur, 994)],
+        &[#[cfg(feature = "lang-lav")] (Lang::Lav, 1000)],
+        &[#[cfg(feature = "lang-epo")] (Lang::Epo, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-lav")] (Lang::Lav, 1000)],
+        &[#[cfg(feature = "lang-lit")] (Lang::Lit, 1000)],
+        &[#[cfg(feature = "lang-aze")] (Lang::Aze, 943), #[cfg(feature = "lang-tur")] (Lang::Tur, 1000)],
+        &[#[cfg(feature = "lang-nld")] (Lang::Nld, 1000)],
+        &[#[cfg(feature = "lang-epo")] (Lang::Epo, 1000)],
+        &[#[cfg(feature = "lang-lav")] (Lang::Lav, 1000)],
+        &[#[cfg(feature = "lang-slk")] (Lang::Slk, 1000)],
+        &[#[cfg(feature = "lang-lav")] (Lang::Lav, 1000)],
+        &[#[cfg(feature = "lang-slk")] (Lang::Slk, 1000)],
+        &[#[cfg(feature = "lang-pol")] (Lang::Pol, 1000)],
+        &[#[cfg(feature = "lang-pol")] (Lang::Pol, 1000)],
+        &[#[cfg(feature = "lang-lav")] (Lang::Lav, 1000)],
+        &[#[cfg(feature = "lang-ces")] (Lang::Ces, 677), #[cfg(feature = "lang-slk")] (Lang::Slk, 677), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 1000)],
+        &[#[cfg(feature = "lang-lav")] (Lang::Lav, 1000)],
+        &[#[cfg(feature = "lang-hun")] (Lang::Hun, 1000)],
+        &[#[cfg(feature = "lang-fra")] (Lang::Fra, 1000)],
+        &[#[cfg(feature = "lang-slk")] (Lang::Slk, 1000)],
+        &[#[cfg(feature = "lang-lav")] (Lang::Lav, 1000)],
+        &[#[cfg(feature = "lang-ces")] (Lang::Ces, 1000)],
+        &[#[cfg(feature = "lang-pol")] (Lang::Pol, 1000)],
+        &[#[cfg(feature = "lang-epo")] (Lang::Epo, 1000)],
+        &[#[cfg(feature = "lang-aze")] (Lang::Aze, 1000), #[cfg(feature = "lang-ron")] (Lang::Ron, 768), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 768), #[cfg(feature = "lang-tur")] (Lang::Tur, 943)],
+        &[#[cfg(feature = "lang-ces")] (Lang::Ces, 890), #[cfg(feature = "lang-fin")] (Lang::Fin, 841), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 856), #[cfg(feature = "lang-lav")] (Lang::Lav, 1000), #[cfg(feature = "lang-lit")] (Lang::Lit, 955), #[cfg(feature = "lang-slk")] (Lang::Slk, 955), #[cfg(feature = "lang-slv")] (Lang::Slv, 887)],
+        &[#[cfg(feature = "lang-ron")] (Lang::Ron, 1000)],
+        &[#[cfg(feature = "lang-ces")] (Lang::Ces, 819), #[cfg(feature = "lang-slk")] (Lang::Slk, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-lav")] (Lang::Lav, 848), #[cfg(feature = "lang-lit")] (Lang::Lit, 1000)],
+        &[#[cfg(feature = "lang-epo")] (Lang::Epo, 1000)],
+        &[#[cfg(feature = "lang-ces")] (Lang::Ces, 1000)],
+        &[#[cfg(feature = "lang-hun")] (Lang::Hun, 1000)],
+        &[#[cfg(feature = "lang-lit")] (Lang::Lit, 1000)],
+        &[#[cfg(feature = "lang-cym")] (Lang::Cym, 1000)],
+        &[#[cfg(feature = "lang-cym")] (Lang::Cym, 1000)],
+        &[#[cfg(feature = "lang-pol")] (Lang::Pol, 1000)],
+        &[#[cfg(feature = "lang-pol")] (Lang::Pol, 1000)],
+        &[#[cfg(feature = "lang-ces")] (Lang::Ces, 969), #[cfg(feature = "lang-fin")] (Lang::Fin, 739), #[cfg(feature = "lang-hrv")] (Lang::Hrv, 739), #[cfg(feature = "lang-lav")] (Lang::Lav, 739), #[cfg(feature = "lang-lit")] (Lang::Lit, 962), #[cfg(feature = "lang-slk")] (Lang::Slk, 1000), #[cfg(feature = "lang-slv")] (Lang::Slv, 879), #[cfg(feature = "lang-tuk")] (Lang::Tuk, 739)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-aka")] (Lang::Aka, 1000)],
+        &[#[cfg(feature = "lang-aze")] (Lang::Aze, 1000)],
+        &[#[cfg(feature = "lang-aka")] (Lang::Aka, 1000)],
+        &[#[cfg(feature = "lang-uzb")] (Lang::Uzb, 1000)],
+        &[#[cfg(feature = "lang-cym")] (Lang::Cym, 1000)],
+        &[#[cfg(feature = "lang-cym")] (Lang::Cym, 1000)],
+        &[#[cfg(feature = "lang-cym")] (Lang::Cym, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-cym")] (Lang::Cym, 1000), #[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+        &[#[cfg(feature = "lang-vie")] (Lang::Vie, 1000)],
+    ],
+};
+
+pub(super) static CYRILLIC: AlphabetMap = AlphabetMap {
+    chars: &[
+        'а', 'б', 'в', 'г', 'д', 'е', 'ж', 'з', 'и', 'й', 'к', 'л', 'м', 'н', 'о', 'п',
+        'р', 'с', 'т', 'у', 'ф', 'х', 'ц', 'ч', 'ш', 'щ', 'ъ', 'ы', 'ь', 'э', 'ю', 'я',
+        'ё', 'ђ', 'ѓ', 'є', 'ѕ', 'і', 'ї', 'ј', 'љ', 'њ', 'ћ', 'ќ', 'ў', 'џ', 'ґ',
+    ],
+    lang_sets: &[
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x00000000000000000000006040000404,
+        0x000000000000000000000000c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x000000000000000000000060c0000404,
+        0x00000000000000000000000040000404,
+        0x00000000000000000000000040000004,
+        0x00000000000000000000000080000004,
+        0x000000000000000000000000c0000404,
+        0x00000000000000000000000080000004,
+        0x000000000000000000000000c0000404,
+        0x000000000000000000000000c0000404,
+        0x00000000000000000000000080000004,
+        0x00000000000000000000002000000000,
+        0x00000000000000000000004000000000,
+        0x00000000000000000000000000000400,
+        0x00000000000000000000004000000000,
+        0x00000000000000000000000080000400,
+        0x00000000000000000000000000000400,
+        0x00000000000000000000006000000000,
+        0x00000000000000000000006000000000,
+        0x00000000000000000000006000000000,
+        0x00000000000000000000002000000000,
+        0x00000000000000000000004000000000,
+        0x00000000000000000000000080000000,
+        0x00000000000000000000006000000000,
+        0x00000000000000000000000000000400,
+    ],
+    lang_weights: &[
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 989), #[cfg(feature = "lang-rus")] (Lang::Rus, 884), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 956), #[cfg(feature = "lang-bel")] (Lang::Bel, 1000), #[cfg(feature = "lang-srp")] (Lang::Srp, 956), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 985)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 922), #[cfg(feature = "lang-rus")] (Lang::Rus, 940), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 964), #[cfg(feature = "lang-bel")] (Lang::Bel, 1000), #[cfg(feature = "lang-srp")] (Lang::Srp, 926), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 915)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 959), #[cfg(feature = "lang-rus")] (Lang::Rus, 1000), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 934), #[cfg(feature = "lang-bel")] (Lang::Bel, 948), #[cfg(feature = "lang-srp")] (Lang::Srp, 966), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 941)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 782), #[cfg(feature = "lang-rus")] (Lang::Rus, 1000), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 996), #[cfg(feature = "lang-bel")] (Lang::Bel, 959), #[cfg(feature = "lang-srp")] (Lang::Srp, 829), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 835)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 1000), #[cfg(feature = "lang-rus")] (Lang::Rus, 909), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 962), #[cfg(feature = "lang-bel")] (Lang::Bel, 930), #[cfg(feature = "lang-srp")] (Lang::Srp, 984), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 990)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 972), #[cfg(feature = "lang-rus")] (Lang::Rus, 983), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 839), #[cfg(feature = "lang-bel")] (Lang::Bel, 888), #[cfg(feature = "lang-srp")] (Lang::Srp, 906), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 1000)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 864), #[cfg(feature = "lang-rus")] (Lang::Rus, 993), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 1000), #[cfg(feature = "lang-bel")] (Lang::Bel, 964), #[cfg(feature = "lang-srp")] (Lang::Srp, 843), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 766)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 963), #[cfg(feature = "lang-rus")] (Lang::Rus, 807), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 1000), #[cfg(feature = "lang-bel")] (Lang::Bel, 930), #[cfg(feature = "lang-srp")] (Lang::Srp, 916), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 924)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 962), #[cfg(feature = "lang-rus")] (Lang::Rus, 991), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 890), #[cfg(feature = "lang-srp")] (Lang::Srp, 1000), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 963)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 856), #[cfg(feature = "lang-rus")] (Lang::Rus, 1000), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 859), #[cfg(feature = "lang-bel")] (Lang::Bel, 905)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 963), #[cfg(feature = "lang-rus")] (Lang::Rus, 971), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 928), #[cfg(feature = "lang-bel")] (Lang::Bel, 950), #[cfg(feature = "lang-srp")] (Lang::Srp, 1000), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 955)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 887), #[cfg(feature = "lang-rus")] (Lang::Rus, 1000), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 986), #[cfg(feature = "lang-bel")] (Lang::Bel, 976), #[cfg(feature = "lang-srp")] (Lang::Srp, 923), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 907)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 825), #[cfg(feature = "lang-rus")] (Lang::Rus, 901), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 916), #[cfg(feature = "lang-bel")] (Lang::Bel, 922), #[cfg(feature = "lang-srp")] (Lang::Srp, 1000), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 860)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 992), #[cfg(feature = "lang-rus")] (Lang::Rus, 892), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 1000), #[cfg(feature = "lang-bel")] (Lang::Bel, 938), #[cfg(feature = "lang-srp")] (Lang::Srp, 868), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 985)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 976), #[cfg(feature = "lang-rus")] (Lang::Rus, 1000), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 987), #[cfg(feature = "lang-bel")] (Lang::Bel, 850), #[cfg(feature = "lang-srp")] (Lang::Srp, 972), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 992)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 891), #[cfg(feature = "lang-rus")] (Lang::Rus, 972), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 943), #[cfg(feature = "lang-bel")] (Lang::Bel, 955), #[cfg(feature = "lang-srp")] (Lang::Srp, 1000), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 887)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 907), #[cfg(feature = "lang-rus")] (Lang::Rus, 983), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 936), #[cfg(feature = "lang-bel")] (Lang::Bel, 943), #[cfg(feature = "lang-srp")] (Lang::Srp, 1000), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 884)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 971), #[cfg(feature = "lang-rus")] (Lang::Rus, 1000), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 907), #[cfg(feature = "lang-bel")] (Lang::Bel, 897), #[cfg(feature = "lang-srp")] (Lang::Srp, 978), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 986)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 1000), #[cfg(feature = "lang-rus")] (Lang::Rus, 965), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 878), #[cfg(feature = "lang-bel")] (Lang::Bel, 774), #[cfg(feature = "lang-srp")] (Lang::Srp, 871), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 998)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 687), #[cfg(feature = "lang-rus")] (Lang::Rus, 781), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 910), #[cfg(feature = "lang-bel")] (Lang::Bel, 833), #[cfg(feature = "lang-srp")] (Lang::Srp, 1000), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 881)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 1000), #[cfg(feature = "lang-rus")] (Lang::Rus, 1000), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 1000), #[cfg(feature = "lang-bel")] (Lang::Bel, 1000), #[cfg(feature = "lang-srp")] (Lang::Srp, 1000), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 1000)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 788), #[cfg(feature = "lang-rus")] (Lang::Rus, 933), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 1000), #[cfg(feature = "lang-bel")] (Lang::Bel, 997), #[cfg(feature = "lang-srp")] (Lang::Srp, 868), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 788)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 717), #[cfg(feature = "lang-rus")] (Lang::Rus, 730), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 786), #[cfg(feature = "lang-bel")] (Lang::Bel, 1000), #[cfg(feature = "lang-srp")] (Lang::Srp, 732), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 743)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 979), #[cfg(feature = "lang-rus")] (Lang::Rus, 982), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 789), #[cfg(feature = "lang-bel")] (Lang::Bel, 1000), #[cfg(feature = "lang-srp")] (Lang::Srp, 790), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 836)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 842), #[cfg(feature = "lang-rus")] (Lang::Rus, 842), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 842), #[cfg(feature = "lang-bel")] (Lang::Bel, 888), #[cfg(feature = "lang-srp")] (Lang::Srp, 1000), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 982)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 993), #[cfg(feature = "lang-rus")] (Lang::Rus, 1000), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 973)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 1000), #[cfg(feature = "lang-rus")] (Lang::Rus, 811)],
+        &[#[cfg(feature = "lang-rus")] (Lang::Rus, 869), #[cfg(feature = "lang-bel")] (Lang::Bel, 1000)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 703), #[cfg(feature = "lang-rus")] (Lang::Rus, 976), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 940), #[cfg(feature = "lang-bel")] (Lang::Bel, 1000)],
+        &[#[cfg(feature = "lang-rus")] (Lang::Rus, 854), #[cfg(feature = "lang-bel")] (Lang::Bel, 1000)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 715), #[cfg(feature = "lang-rus")] (Lang::Rus, 774), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 1000), #[cfg(feature = "lang-bel")] (Lang::Bel, 779)],
+        &[#[cfg(feature = "lang-bul")] (Lang::Bul, 853), #[cfg(feature = "lang-rus")] (Lang::Rus, 907), #[cfg(feature = "lang-ukr")] (Lang::Ukr, 1000), #[cfg(feature = "lang-bel")] (Lang::Bel, 962)],
+        &[#[cfg(feature = "lang-rus")] (Lang::Rus, 1000), #[cfg(feature = "lang-bel")] (Lang::Bel, 1000)],
+        &[#[cfg(feature = "lang-srp")] (Lang::Srp, 1000)],
+        &[#[cfg(feature = "lang-mkd")] (Lang::Mkd, 1000)],
+        &[#[cfg(feature = "lang-ukr")] (Lang::Ukr, 1000)],
+        &[#[cfg(feature = "lang-mkd")] (Lang::Mkd, 1000)],
+        &[#[cfg(feature = "lang-ukr")] (Lang::Ukr, 927), #[cfg(feature = "lang-bel")] (Lang::Bel, 1000)],
+        &[#[cfg(feature = "lang-ukr")] (Lang::Ukr, 1000)],
+        &[#[cfg(feature = "lang-srp")] (Lang::Srp, 1000), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 979)],
+        &[#[cfg(feature = "lang-srp")] (Lang::Srp, 1000), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 1000)],
+        &[#[cfg(feature = "lang-srp")] (Lang::Srp, 1000), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 977)],
+        &[#[cfg(feature = "lang-srp")] (Lang::Srp, 1000)],
+        &[#[cfg(feature = "lang-mkd")] (Lang::Mkd, 1000)],
+        &[#[cfg(feature = "lang-bel")] (Lang::Bel, 1000)],
+        &[#[cfg(feature = "lang-srp")] (Lang::Srp, 1000), #[cfg(feature = "lang-mkd")] (Lang::Mkd, 1000)],
+        &[#[cfg(feature = "lang-ukr")] (Lang::Ukr, 1000)],
+    ],
+};
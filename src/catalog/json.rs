@@ -0,0 +1,305 @@
+use super::{CatalogEntry, CatalogFormat};
+use crate::error::CatalogError;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+// Nested objects and arrays deeper than this are an error, so a malicious file can not
+// overflow the stack.
+const MAX_DEPTH: usize = 64;
+
+/// Parse a JSON catalog: an object of messages by their keys. Keys of nested objects and arrays
+/// are joined by dots (e.g. `menu.file.open`), numbers, booleans and nulls are ignored.
+/// Keys starting with `@` are metadata of ARB files, the language is taken from `@@locale`.
+pub(super) fn parse(text: &str) -> Result<(Option<String>, Vec<CatalogEntry>), CatalogError> {
+    let mut parser = Parser {
+        text,
+        bytes: text.as_bytes(),
+        pos: 0,
+        locale: None,
+        entries: Vec::new(),
+    };
+    // A byte order mark
+    parser.pos = if text.starts_with('\u{feff}') { 3 } else { 0 };
+    parser.skip_whitespace();
+    if parser.peek() != Some(b'{') {
+        return Err(parser.error("expected an object"));
+    }
+    parser.parse_value(&mut String::new(), 0)?;
+    parser.skip_whitespace();
+    if parser.pos < parser.bytes.len() {
+        return Err(parser.error("unexpected characters after the object"));
+    }
+    Ok((parser.locale, parser.entries))
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    locale: Option<String>,
+    entries: Vec<CatalogEntry>,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &'static str) -> CatalogError {
+        CatalogError::new(CatalogFormat::Json, self.line(), message)
+    }
+
+    fn line(&self) -> usize {
+        let pos = self.pos.min(self.bytes.len());
+        self.bytes[..pos].iter().filter(|&&b| b == b'\n').count() + 1
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|b| b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, expected: u8, message: &'static str) -> Result<(), CatalogError> {
+        self.skip_whitespace();
+        if self.peek() != Some(expected) {
+            return Err(self.error(message));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    // A value of the key, the key is restored afterwards.
+    fn parse_value(&mut self, key: &mut String, depth: usize) -> Result<(), CatalogError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(b'{' | b'[') if depth >= MAX_DEPTH => Err(self.error("too deeply nested")),
+            Some(b'{') => self.parse_object(key, depth),
+            Some(b'[') => self.parse_array(key, depth),
+            Some(b'"') => {
+                let line = self.line();
+                let value = self.parse_string()?;
+                if key == "@@locale" {
+                    self.locale = Some(value);
+                } else if !key.starts_with('@') && !key.contains(".@") {
+                    self.entries.push(CatalogEntry {
+                        key: key.clone(),
+                        source: None,
+                        translation: value,
+                        line,
+                    });
+                }
+                Ok(())
+            }
+            Some(b't' | b'f' | b'n' | b'-' | b'0'..=b'9') => {
+                let len = self.bytes[self.pos..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_alphanumeric() || b"+-.".contains(b))
+                    .count();
+                let literal = &self.text[self.pos..self.pos + len];
+                let is_number = literal
+                    .bytes()
+                    .all(|b| b.is_ascii_digit() || b"+-.eE".contains(&b))
+                    && literal.parse::<f64>().is_ok();
+                let is_valid = is_number || matches!(literal, "true" | "false" | "null");
+                if !is_valid {
+                    return Err(self.error("invalid literal"));
+                }
+                self.pos += len;
+                Ok(())
+            }
+            _ => Err(self.error("expected a value")),
+        }
+    }
+
+    fn parse_object(&mut self, key: &mut String, depth: usize) -> Result<(), CatalogError> {
+        self.pos += 1;
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(());
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return Err(self.error("expected a key"));
+            }
+            let name = self.parse_string()?;
+            self.expect(b':', "expected `:`")?;
+            let key_len = key.len();
+            if !key.is_empty() {
+                key.push('.');
+            }
+            key.push_str(&name);
+            self.parse_value(key, depth + 1)?;
+            key.truncate(key_len);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                _ => return Err(self.error("expected `,` or `}`")),
+            }
+        }
+    }
+
+    fn parse_array(&mut self, key: &mut String, depth: usize) -> Result<(), CatalogError> {
+        self.pos += 1;
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(());
+        }
+        for i in 0.. {
+            let key_len = key.len();
+            if !key.is_empty() {
+                key.push('.');
+            }
+            key.push_str(&i.to_string());
+            self.parse_value(key, depth + 1)?;
+            key.truncate(key_len);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_string(&mut self) -> Result<String, CatalogError> {
+        self.pos += 1;
+        let mut value = String::new();
+        loop {
+            let Some(len) = self.text[self.pos..].find(['"', '\\', '\n']) else {
+                return Err(self.error("unterminated string"));
+            };
+            value.push_str(&self.text[self.pos..self.pos + len]);
+            self.pos += len;
+            match self.bytes[self.pos] {
+                b'"' => {
+                    self.pos += 1;
+                    return Ok(value);
+                }
+                b'\\' => {
+                    let ch = self.parse_escape()?;
+                    value.push(ch);
+                }
+                _ => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    fn parse_escape(&mut self) -> Result<char, CatalogError> {
+        let escaped = self.bytes.get(self.pos + 1).copied();
+        self.pos += 2;
+        let ch = match escaped {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                let high = self.parse_hex()?;
+                let code = if (0xD800..0xDC00).contains(&high)
+                    && self.bytes[self.pos..].starts_with(b"\\u")
+                {
+                    self.pos += 2;
+                    let low = self.parse_hex()?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err(self.error("invalid surrogate pair"));
+                    }
+                    0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                } else {
+                    high
+                };
+                char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+            }
+            _ => return Err(self.error("invalid escape")),
+        };
+        Ok(ch)
+    }
+
+    fn parse_hex(&mut self) -> Result<u32, CatalogError> {
+        let hex = self
+            .text
+            .get(self.pos..self.pos + 4)
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+        self.pos += 4;
+        Ok(hex)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(json: &str) -> Vec<(String, String, usize)> {
+        parse(json)
+            .unwrap()
+            .1
+            .into_iter()
+            .map(|entry| (entry.key, entry.translation, entry.line))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let json = r#"{
+            "greeting": "Hallo, {name}!",
+            "menu": {"file": {"open": "\u00d6ffnen \"\ud83d\udcc2\"", "recent": []}},
+            "steps": ["Eins", "Zwei"],
+            "count": 3, "enabled": true, "empty": null, "ratio": -1.5e3
+        }"#;
+        assert_eq!(
+            entries(json),
+            vec![
+                ("greeting".into(), "Hallo, {name}!".into(), 2),
+                ("menu.file.open".into(), "Öffnen \"📂\"".into(), 3),
+                ("steps.0".into(), "Eins".into(), 4),
+                ("steps.1".into(), "Zwei".into(), 4),
+            ]
+        );
+        assert_eq!(entries("\u{feff} {} "), vec![]);
+    }
+
+    #[test]
+    fn test_parse_arb() {
+        let json =
+            r#"{"@@locale": "de", "title": "Titel", "@title": {"description": "The title"}}"#;
+        let (locale, entries) = parse(json).unwrap();
+        assert_eq!(locale.as_deref(), Some("de"));
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].translation, "Titel");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let line = |json: &str| parse(json).err().map(|error| error.line());
+        assert_eq!(line("[]"), Some(1));
+        assert_eq!(line("{\n\"a\": \"b\",\n}"), Some(3));
+        assert_eq!(line("{\"a\": \"b\n\"}"), Some(1));
+        assert_eq!(line("{\"a\": \"\\x\"}"), Some(1));
+        assert_eq!(line("{\"a\": \"\\u12\"}"), Some(1));
+        assert_eq!(line("{\"a\": tru}"), Some(1));
+        assert_eq!(line("{\"a\": nan}"), Some(1));
+        assert_eq!(line("{\"a\": \"b\"} x"), Some(1));
+        assert_eq!(line("{\"a\" \"b\"}"), Some(1));
+        assert_eq!(line(&"[".repeat(100)), Some(1));
+        assert_eq!(
+            line(&alloc::format!("{{\"a\":{}", "[".repeat(100))),
+            Some(1)
+        );
+        assert_eq!(line("{\"a\": \"\\"), Some(1));
+    }
+}
@@ -0,0 +1,353 @@
+mod json;
+mod placeholders;
+mod po;
+mod xliff;
+
+use crate::core::{Detector, Info, with_thread_scratch};
+use crate::error::CatalogError;
+use crate::lang::Lang;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Format of a message catalog, see [Catalog].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CatalogFormat {
+    /// gettext PO files.
+    Po,
+    /// XLIFF 1.2 and 2.0 documents.
+    Xliff,
+    /// JSON objects of messages by their keys, including ARB files of Flutter.
+    Json,
+}
+
+impl CatalogFormat {
+    /// Get the format by a file extension, with or without the leading dot.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::CatalogFormat;
+    /// assert_eq!(CatalogFormat::from_extension("po"), Some(CatalogFormat::Po));
+    /// assert_eq!(CatalogFormat::from_extension(".XLF"), Some(CatalogFormat::Xliff));
+    /// assert_eq!(CatalogFormat::from_extension("yaml"), None);
+    /// ```
+    pub fn from_extension(extension: &str) -> Option<CatalogFormat> {
+        let extension = extension.strip_prefix('.').unwrap_or(extension);
+        let is = |extensions: &[&str]| {
+            extensions
+                .iter()
+                .any(|ext| ext.eq_ignore_ascii_case(extension))
+        };
+        if is(&["po", "pot"]) {
+            Some(CatalogFormat::Po)
+        } else if is(&["xlf", "xliff"]) {
+            Some(CatalogFormat::Xliff)
+        } else if is(&["json", "arb"]) {
+            Some(CatalogFormat::Json)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            CatalogFormat::Po => "PO",
+            CatalogFormat::Xliff => "XLIFF",
+            CatalogFormat::Json => "JSON",
+        }
+    }
+}
+
+/// A message of a [Catalog].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogEntry {
+    key: String,
+    source: Option<String>,
+    translation: String,
+    line: usize,
+}
+
+impl CatalogEntry {
+    /// The key of the message: `msgid` of PO files (prefixed by `msgctxt` and `\u{4}`, as gettext
+    /// does), `id` of XLIFF units or the key of JSON messages (keys of nested objects are joined
+    /// by dots).
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// The source text, if the format has one: `msgid` (and `msgid_plural`) of PO files
+    /// or `<source>` of XLIFF units.
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    /// The translated text. Plural forms of PO files are joined by line breaks.
+    /// Empty for untranslated messages.
+    pub fn translation(&self) -> &str {
+        &self.translation
+    }
+
+    /// The line of the message, starting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+/// A message catalog of translations: a gettext PO file, an XLIFF document or a JSON file.
+///
+/// # Example
+/// ```
+/// use whatlang::{Catalog, CatalogFormat, Lang};
+///
+/// let po = r#"
+/// msgid ""
+/// msgstr "Language: de\n"
+///
+/// msgid "Save %s"
+/// msgstr "%s speichern"
+/// "#;
+/// let catalog = Catalog::parse(po, CatalogFormat::Po).unwrap();
+/// assert_eq!(catalog.lang(), Some(Lang::Deu));
+/// assert_eq!(catalog.entries()[0].translation(), "%s speichern");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Catalog {
+    format: CatalogFormat,
+    locale: Option<String>,
+    entries: Vec<CatalogEntry>,
+}
+
+impl Catalog {
+    pub fn parse(text: &str, format: CatalogFormat) -> Result<Catalog, CatalogError> {
+        let (locale, entries) = match format {
+            CatalogFormat::Po => po::parse(text)?,
+            CatalogFormat::Xliff => xliff::parse(text)?,
+            CatalogFormat::Json => json::parse(text)?,
+        };
+        Ok(Catalog {
+            format,
+            locale,
+            entries,
+        })
+    }
+
+    pub fn format(&self) -> CatalogFormat {
+        self.format
+    }
+
+    /// The locale of the translations declared by the catalog: the `Language` header of PO files,
+    /// `target-language` (XLIFF 1.2) or `trgLang` (XLIFF 2.0) attributes, `@@locale` of ARB files.
+    /// Plain JSON files do not declare a locale, it's usually the name of the file.
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    /// The language of the declared locale, see [Lang::from_locale].
+    pub fn lang(&self) -> Option<Lang> {
+        self.locale.as_deref().and_then(Lang::from_locale)
+    }
+
+    pub fn entries(&self) -> &[CatalogEntry] {
+        &self.entries
+    }
+}
+
+/// An entry of a catalog, which is detected to be in another language than the target one,
+/// see [CatalogValidator].
+#[derive(Debug, PartialEq)]
+pub struct CatalogMismatch<'a> {
+    entry: &'a CatalogEntry,
+    text: String,
+    info: Info,
+}
+
+impl<'a> CatalogMismatch<'a> {
+    pub fn entry(&self) -> &'a CatalogEntry {
+        self.entry
+    }
+
+    /// The detected text: the translation with placeholders masked.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The detected language.
+    pub fn info(&self) -> &Info {
+        &self.info
+    }
+}
+
+/// Finds translations of a catalog, which are not in the target language, e.g. source strings
+/// pasted into a German catalog.
+///
+/// Placeholders are masked before the detection: `printf` conversions (`%s`, `%1$d`,
+/// `%(name)s`), `{name}`, `{{name}}`, `${name}`, ICU arguments (only the text of the plural
+/// and select sub-messages is kept) and HTML tags. Untranslated messages and the ones
+/// with too few letters are skipped. A message is reported if another language is detected
+/// with a confidence of at least [CatalogValidator::set_min_confidence].
+///
+/// # Example
+/// ```
+/// use whatlang::{Catalog, CatalogFormat, CatalogValidator, Lang};
+///
+/// let json = r#"{
+///     "welcome": "Willkommen zurück, {name}! Du hast {count, plural, one {# neue Nachricht} other {# neue Nachrichten}}.",
+///     "error": "The file could not be opened because it is used by another program."
+/// }"#;
+/// let catalog = Catalog::parse(json, CatalogFormat::Json).unwrap();
+/// let mismatches = CatalogValidator::new().validate(&catalog, Lang::Deu);
+/// assert_eq!(mismatches.len(), 1);
+/// assert_eq!(mismatches[0].entry().key(), "error");
+/// assert_eq!(mismatches[0].info().lang(), Lang::Eng);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CatalogValidator {
+    detector: Detector,
+    min_confidence: f64,
+    min_letters: usize,
+}
+
+impl Default for CatalogValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CatalogValidator {
+    pub fn new() -> Self {
+        Self {
+            detector: Detector::new(),
+            min_confidence: 0.5,
+            min_letters: 12,
+        }
+    }
+
+    /// The detector of the languages of the translations, [Detector::new] by default.
+    pub fn set_detector(mut self, detector: Detector) -> Self {
+        self.detector = detector;
+        self
+    }
+
+    /// The confidence, from which a detected language other than the target one is reported,
+    /// 0.5 by default.
+    pub fn set_min_confidence(mut self, min_confidence: f64) -> Self {
+        self.min_confidence = min_confidence;
+        self
+    }
+
+    /// Translations with fewer letters are skipped, 12 by default: the language of short texts,
+    /// e.g. `OK` or `Name`, can not be told apart.
+    pub fn set_min_letters(mut self, min_letters: usize) -> Self {
+        self.min_letters = min_letters;
+        self
+    }
+
+    /// Detect the translations of the catalog and report the ones in another language than
+    /// the target one, in order of the entries. Use [Catalog::lang] as the target language,
+    /// if the catalog declares one.
+    pub fn validate<'a>(&self, catalog: &'a Catalog, lang: Lang) -> Vec<CatalogMismatch<'a>> {
+        let mut text = String::new();
+        with_thread_scratch(|scratch| {
+            let mut mismatches = Vec::new();
+            for entry in catalog.entries() {
+                text.clear();
+                placeholders::push_masked(entry.translation(), &mut text);
+                let letters = text.chars().filter(|ch| ch.is_alphabetic()).count();
+                if letters < self.min_letters {
+                    continue;
+                }
+                let Some(info) = self.detector.detect_with(scratch, &text) else {
+                    continue;
+                };
+                if info.lang() != lang && info.confidence() >= self.min_confidence {
+                    mismatches.push(CatalogMismatch {
+                        entry,
+                        text: text.clone(),
+                        info,
+                    });
+                }
+            }
+            mismatches
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_extension() {
+        assert_eq!(
+            CatalogFormat::from_extension("pot"),
+            Some(CatalogFormat::Po)
+        );
+        assert_eq!(
+            CatalogFormat::from_extension("xliff"),
+            Some(CatalogFormat::Xliff)
+        );
+        assert_eq!(
+            CatalogFormat::from_extension(".arb"),
+            Some(CatalogFormat::Json)
+        );
+        assert_eq!(CatalogFormat::from_extension(""), None);
+    }
+
+    #[test]
+    fn test_lang() {
+        let xliff = r#"<xliff version="2.0" srcLang="en" trgLang="pt-BR"></xliff>"#;
+        let catalog = Catalog::parse(xliff, CatalogFormat::Xliff).unwrap();
+        assert_eq!(catalog.format(), CatalogFormat::Xliff);
+        assert_eq!(catalog.locale(), Some("pt-BR"));
+        assert_eq!(catalog.lang(), Some(Lang::Por));
+
+        let catalog = Catalog::parse("{}", CatalogFormat::Json).unwrap();
+        assert_eq!(catalog.lang(), None);
+    }
+
+    #[test]
+    fn test_validate() {
+        let po = r#"
+msgid ""
+msgstr "Language: de_DE\n"
+
+msgid "Your changes have been saved successfully."
+msgstr "Ihre Änderungen wurden erfolgreich gespeichert."
+
+msgid "The file %s could not be opened because it is used by another program."
+msgstr "The file %s could not be opened because it is used by another program."
+
+msgid "%(count)d files"
+msgstr "%(count)d Dateien"
+
+msgid "Cancel"
+msgstr "Cancel"
+
+msgid "Delete all messages?"
+msgstr ""
+"#;
+        let catalog = Catalog::parse(po, CatalogFormat::Po).unwrap();
+        let lang = catalog.lang().unwrap();
+        let mismatches = CatalogValidator::new().validate(&catalog, lang);
+        let reported: Vec<_> = mismatches
+            .iter()
+            .map(|mismatch| (mismatch.entry().line(), mismatch.info().lang()))
+            .collect();
+        assert_eq!(reported, vec![(8, Lang::Eng)]);
+        assert_eq!(
+            mismatches[0].text(),
+            "The file   could not be opened because it is used by another program."
+        );
+
+        // Short translations are detected too
+        let mismatches = CatalogValidator::new()
+            .set_min_letters(1)
+            .set_min_confidence(0.0)
+            .validate(&catalog, lang);
+        assert!(mismatches.iter().any(|m| m.entry().key() == "Cancel"));
+
+        // A detector, which knows only the target language, reports nothing
+        let validator =
+            CatalogValidator::new().set_detector(Detector::with_allowlist(vec![Lang::Deu]));
+        assert_eq!(validator.validate(&catalog, lang), vec![]);
+    }
+}
@@ -0,0 +1,227 @@
+use alloc::string::String;
+
+// Arguments of ICU MessageFormat, the sub-messages of which are translated text.
+const ICU_SELECT_TYPES: [&str; 3] = ["plural", "select", "selectordinal"];
+
+// Nested ICU arguments deeper than this are not parsed.
+const MAX_DEPTH: usize = 16;
+
+/// Append the text of a message with placeholders replaced by spaces: `printf` conversions
+/// (`%s`, `%1$d`, `%(name)s`, `%@`), `{name}`, `{{name}}`, `${name}`, ICU arguments
+/// (`{count, number}`, keywords and selectors of `{count, plural, one {...} other {...}}`,
+/// where only the text of the sub-messages is kept) and HTML tags.
+pub(super) fn push_masked(message: &str, out: &mut String) {
+    push_masked_at(message, out, 0, false);
+}
+
+fn push_masked_at(message: &str, out: &mut String, depth: usize, is_plural: bool) {
+    let bytes = message.as_bytes();
+    let mut text_start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let placeholder_end = match bytes[i] {
+            b'%' => printf_end(message, i),
+            b'$' if bytes.get(i + 1) == Some(&b'{') => closing_brace(message, i + 1),
+            b'{' if bytes.get(i + 1) == Some(&b'{') => {
+                message[i..].find("}}").map(|len| i + len + 2)
+            }
+            b'{' => match icu_argument(message, i, depth) {
+                Some((end, sub_messages)) => {
+                    out.push_str(&message[text_start..i]);
+                    out.push(' ');
+                    for (sub_message, is_plural) in sub_messages {
+                        push_masked_at(sub_message, out, depth + 1, is_plural);
+                        out.push(' ');
+                    }
+                    text_start = end;
+                    i = end;
+                    continue;
+                }
+                None => None,
+            },
+            // The number of a plural sub-message
+            b'#' if is_plural => Some(i + 1),
+            b'<' => html_tag_end(message, i),
+            _ => None,
+        };
+        match placeholder_end {
+            Some(end) => {
+                out.push_str(&message[text_start..i]);
+                out.push(' ');
+                text_start = end;
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+    out.push_str(&message[text_start..]);
+}
+
+// The end of a conversion of `printf`, e.g. `%s`, `%1$s`, `%-5.2f`, `%lld`, `%(name)s`, `%@`.
+// `%%` is a literal percent sign.
+fn printf_end(message: &str, start: usize) -> Option<usize> {
+    let bytes = message.as_bytes();
+    let mut i = start + 1;
+    if bytes.get(i) == Some(&b'%') {
+        return Some(i + 1);
+    }
+    // A named argument of Python
+    if bytes.get(i) == Some(&b'(') {
+        i += message[i..].find(')')? + 1;
+    }
+    let skip = |i: &mut usize, pred: fn(u8) -> bool| {
+        while bytes.get(*i).is_some_and(|&b| pred(b)) {
+            *i += 1;
+        }
+    };
+    // A positional argument, e.g. `1$`
+    let digits_end = {
+        let mut j = i;
+        skip(&mut j, |b| b.is_ascii_digit());
+        j
+    };
+    if digits_end > i && bytes.get(digits_end) == Some(&b'$') {
+        i = digits_end + 1;
+    }
+    // Flags, width, precision and length
+    skip(&mut i, |b| b"-+#0".contains(&b));
+    skip(&mut i, |b| b.is_ascii_digit() || b == b'*');
+    if bytes.get(i) == Some(&b'.') {
+        i += 1;
+        skip(&mut i, |b| b.is_ascii_digit() || b == b'*');
+    }
+    skip(&mut i, |b| b"hlLqjzt".contains(&b));
+    let is_conversion = bytes
+        .get(i)
+        .is_some_and(|b| b"diouxXeEfFgGaAcsSpn@".contains(b));
+    // A conversion must be followed by a non-letter, e.g. not `%some`
+    let is_word = bytes.get(i + 1).is_some_and(|b| b.is_ascii_alphabetic()) && i == start + 1;
+    (is_conversion && !is_word).then_some(i + 1)
+}
+
+// The position after the brace closing the one at the position `start`.
+fn closing_brace(message: &str, start: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, b) in message.bytes().enumerate().skip(start) {
+        match b {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+type SubMessages<'a> = alloc::vec::Vec<(&'a str, bool)>;
+
+// An ICU argument (or a simple placeholder, e.g. `{name}` or `{0}`) at the position `start`:
+// its end and sub-messages of `plural` and `select` arguments.
+fn icu_argument(message: &str, start: usize, depth: usize) -> Option<(usize, SubMessages<'_>)> {
+    let end = closing_brace(message, start)?;
+    let content = &message[start + 1..end - 1];
+    let mut parts = content.splitn(3, ',');
+    let name = parts.next()?.trim();
+    let is_name = !name.is_empty()
+        && name
+            .chars()
+            .all(|ch| ch.is_alphanumeric() || ch == '_' || ch == '-' || ch == '.');
+    if !is_name {
+        return None;
+    }
+    let Some(arg_type) = parts.next().map(str::trim) else {
+        return Some((end, SubMessages::new()));
+    };
+    if !ICU_SELECT_TYPES.contains(&arg_type) || depth >= MAX_DEPTH {
+        // E.g. `{count, number, integer}` or `{date, date, short}`
+        return Some((end, SubMessages::new()));
+    }
+    let is_plural = arg_type != "select";
+    let options = parts.next().unwrap_or("");
+    let mut sub_messages = SubMessages::new();
+    let mut i = 0;
+    // Selectors (e.g. `one`, `=0`, `offset:1`) followed by sub-messages in braces
+    while let Some(offset) = options[i..].find('{') {
+        let brace = i + offset;
+        let sub_end = closing_brace(options, brace)?;
+        sub_messages.push((&options[brace + 1..sub_end - 1], is_plural));
+        i = sub_end;
+    }
+    Some((end, sub_messages))
+}
+
+// The end of an HTML tag, e.g. `<b>`, `</a>`, `<br/>` or `<0>` of React Intl.
+fn html_tag_end(message: &str, start: usize) -> Option<usize> {
+    let rest = &message[start + 1..];
+    let rest = rest.strip_prefix('/').unwrap_or(rest);
+    let first = rest.bytes().next()?;
+    if !first.is_ascii_alphanumeric() {
+        return None;
+    }
+    let len = rest.find(['>', '<', '\n'])?;
+    (rest.as_bytes()[len] == b'>').then(|| message.len() - rest.len() + len + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn masked(message: &str) -> String {
+        let mut out = String::new();
+        push_masked(message, &mut out);
+        out.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    #[test]
+    fn test_printf() {
+        assert_eq!(masked("%s speichern"), "speichern");
+        assert_eq!(masked("%1$s von %2$d"), "von");
+        assert_eq!(masked("%(name)s hat %-5.2f%% erreicht"), "hat erreicht");
+        assert_eq!(masked("%lld Dateien, %@"), "Dateien,");
+        assert_eq!(masked("100% sicher"), "100% sicher");
+        assert_eq!(masked("%sicher"), "%sicher");
+    }
+
+    #[test]
+    fn test_braces() {
+        assert_eq!(masked("Hallo, {name}!"), "Hallo, !");
+        assert_eq!(masked("Hallo, {{user.name}} und ${host}"), "Hallo, und");
+        assert_eq!(masked("{0} von {1}"), "von");
+        assert_eq!(masked("Am {date, date, short}"), "Am");
+        assert_eq!(masked("Ein {unclosed"), "Ein {unclosed");
+        assert_eq!(masked("Menge {a b}"), "Menge {a b}");
+    }
+
+    #[test]
+    fn test_icu() {
+        assert_eq!(
+            masked("{count, plural, =0 {Keine Dateien} one {# Datei} other {# Dateien}}"),
+            "Keine Dateien Datei Dateien"
+        );
+        assert_eq!(
+            masked(
+                "{gender, select, female {Sie hat {n, plural, one {# Bild} other {# Bilder}}} other {Er hat #}}"
+            ),
+            "Sie hat Bild Bilder Er hat #"
+        );
+        assert_eq!(
+            masked("{n, plural, offset:1 one {Du und {name}} other {Ihr}}"),
+            "Du und Ihr"
+        );
+    }
+
+    #[test]
+    fn test_html() {
+        assert_eq!(
+            masked("<b>Wichtig</b>: <a href=\"/x\">hier</a> klicken<br/>"),
+            "Wichtig : hier klicken"
+        );
+        assert_eq!(masked("Klicke <0>hier</0>"), "Klicke hier");
+        assert_eq!(masked("a < b"), "a < b");
+    }
+}
@@ -0,0 +1,237 @@
+use super::{CatalogEntry, CatalogFormat};
+use crate::error::CatalogError;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// A keyword of an entry, the strings of which are continued by the following lines.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Context,
+    Id,
+    IdPlural,
+    Str,
+}
+
+#[derive(Default)]
+struct PendingEntry {
+    context: Option<String>,
+    id: Option<String>,
+    id_plural: Option<String>,
+    // Translations, a single one or one per plural form
+    strs: Vec<String>,
+    line: usize,
+}
+
+/// Parse a gettext PO file. The language is taken from the `Language` field of the header.
+pub(super) fn parse(text: &str) -> Result<(Option<String>, Vec<CatalogEntry>), CatalogError> {
+    let mut parser = Parser::default();
+    for (i, line) in text.lines().enumerate() {
+        let line_number = i + 1;
+        let line = line.trim();
+        // Comments and obsolete entries
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error = |message| CatalogError::new(CatalogFormat::Po, line_number, message);
+        if line.starts_with('"') {
+            let field = parser
+                .field
+                .ok_or_else(|| error("string without a keyword"))?;
+            let value = parse_string(line).ok_or_else(|| error("invalid string"))?;
+            parser.current_string(field).push_str(&value);
+            continue;
+        }
+        let (keyword, rest) = line
+            .split_once(|ch: char| ch.is_whitespace())
+            .ok_or_else(|| error("expected a keyword and a string"))?;
+        let value = parse_string(rest.trim()).ok_or_else(|| error("invalid string"))?;
+        let field = match keyword {
+            "msgctxt" => Field::Context,
+            "msgid" => Field::Id,
+            "msgid_plural" => Field::IdPlural,
+            "msgstr" => Field::Str,
+            _ if keyword.starts_with("msgstr[") && keyword.ends_with(']') => Field::Str,
+            _ => return Err(error("unknown keyword")),
+        };
+        parser.start_field(field, line_number);
+        *parser.current_string(field) = value;
+    }
+    parser.finish_entry();
+    Ok((parser.locale, parser.entries))
+}
+
+#[derive(Default)]
+struct Parser {
+    locale: Option<String>,
+    entries: Vec<CatalogEntry>,
+    entry: PendingEntry,
+    field: Option<Field>,
+}
+
+impl Parser {
+    fn start_field(&mut self, field: Field, line: usize) {
+        // A context or an id after the translations starts the next entry
+        let starts_entry = matches!(field, Field::Context | Field::Id)
+            && (self.field == Some(Field::Str) || self.entry.id.is_some());
+        if starts_entry {
+            self.finish_entry();
+        }
+        if self.field.is_none() {
+            self.entry.line = line;
+        }
+        if field == Field::Str {
+            self.entry.strs.push(String::new());
+        }
+        self.field = Some(field);
+    }
+
+    fn current_string(&mut self, field: Field) -> &mut String {
+        match field {
+            Field::Context => self.entry.context.get_or_insert_default(),
+            Field::Id => self.entry.id.get_or_insert_default(),
+            Field::IdPlural => self.entry.id_plural.get_or_insert_default(),
+            // A `msgstr` keyword always pushes a string first
+            Field::Str => self.entry.strs.last_mut().expect("msgstr is started"),
+        }
+    }
+
+    fn finish_entry(&mut self) {
+        let entry = core::mem::take(&mut self.entry);
+        self.field = None;
+        let Some(id) = entry.id else {
+            return;
+        };
+        if id.is_empty() && entry.context.is_none() {
+            // The header
+            self.locale = entry
+                .strs
+                .first()
+                .and_then(|header| header_language(header));
+            return;
+        }
+        let key = match entry.context {
+            Some(context) => alloc::format!("{}\u{4}{}", context, id),
+            None => id.clone(),
+        };
+        let source = match entry.id_plural {
+            Some(plural) => alloc::format!("{}\n{}", id, plural),
+            None => id,
+        };
+        self.entries.push(CatalogEntry {
+            key,
+            source: Some(source),
+            translation: entry.strs.join("\n"),
+            line: entry.line,
+        });
+    }
+}
+
+// E.g. `de_AT` of `Language: de_AT\n`.
+fn header_language(header: &str) -> Option<String> {
+    header.lines().find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        let value = value.trim();
+        (name.trim() == "Language" && !value.is_empty()).then(|| String::from(value))
+    })
+}
+
+// A quoted string of C with escapes, e.g. `"Say \"hi\"\n"`.
+fn parse_string(quoted: &str) -> Option<String> {
+    let content = quoted.strip_prefix('"')?.strip_suffix('"')?;
+    let mut value = String::with_capacity(content.len());
+    let mut chars = content.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next()? {
+                'n' => value.push('\n'),
+                't' => value.push('\t'),
+                'r' => value.push('\r'),
+                escaped => value.push(escaped),
+            },
+            // An unescaped quote inside the string
+            '"' => return None,
+            _ => value.push(ch),
+        }
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let po = r#"# German translations
+msgid ""
+msgstr ""
+"Project-Id-Version: demo\n"
+"Language: de_AT\n"
+
+#: src/main.c:10
+#, c-format
+msgid "Save %s"
+msgstr "%s speichern"
+
+msgctxt "menu"
+msgid ""
+"Open "
+"file"
+msgstr "Datei \"öffnen\""
+
+msgid "One file"
+msgid_plural "%d files"
+msgstr[0] "Eine Datei"
+msgstr[1] "%d Dateien"
+
+msgid "Untranslated"
+msgstr ""
+
+#~ msgid "Obsolete"
+#~ msgstr "Veraltet"
+"#;
+        let (locale, entries) = parse(po).unwrap();
+        assert_eq!(locale.as_deref(), Some("de_AT"));
+        let entries: Vec<_> = entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.key(),
+                    entry.source(),
+                    entry.translation(),
+                    entry.line(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            entries,
+            vec![
+                ("Save %s", Some("Save %s"), "%s speichern", 9),
+                (
+                    "menu\u{4}Open file",
+                    Some("Open file"),
+                    "Datei \"öffnen\"",
+                    12
+                ),
+                (
+                    "One file",
+                    Some("One file\n%d files"),
+                    "Eine Datei\n%d Dateien",
+                    18
+                ),
+                ("Untranslated", Some("Untranslated"), "", 23),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let line = |po: &str| parse(po).err().map(|error| error.line());
+        assert_eq!(line("msgid \"a\"\nmsgstr \"b"), Some(2));
+        assert_eq!(line("\"a\""), Some(1));
+        assert_eq!(line("msgid \"a\"\nmsgfoo \"b\""), Some(2));
+        assert_eq!(line("msgid \"a \"b\"\""), Some(1));
+        assert_eq!(line("msgid \"a\"\nmsgstr \"b\\\""), Some(2));
+        assert_eq!(line(""), None);
+    }
+}
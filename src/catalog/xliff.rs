@@ -0,0 +1,400 @@
+use super::{CatalogEntry, CatalogFormat};
+use crate::error::CatalogError;
+use crate::markup::decode_char_reference;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// Inline elements of XLIFF 1.2 containing native codes of the original format, e.g.
+// `<bpt id="1">&lt;b&gt;</bpt>`, which are not the text of the translation.
+const CODE_ELEMENTS: [&str; 4] = ["ph", "bpt", "ept", "it"];
+
+// Empty inline elements of XLIFF 1.2 and 2.0, which stand for codes and placeholders.
+const PLACEHOLDER_ELEMENTS: [&str; 9] = ["x", "bx", "ex", "ph", "sc", "ec", "cp", "sm", "em"];
+
+// Which text of a unit is read.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Capture {
+    Source,
+    Target,
+}
+
+#[derive(Default)]
+struct PendingUnit {
+    id: String,
+    line: usize,
+    source: String,
+    target: String,
+}
+
+/// Parse an XLIFF 1.2 or 2.0 document. The language is taken from the `target-language`
+/// attribute of the first `<file>` (1.2) or the `trgLang` attribute of `<xliff>` (2.0).
+/// Inline codes are ignored, alternative translations (`<alt-trans>`) are skipped.
+pub(super) fn parse(text: &str) -> Result<(Option<String>, Vec<CatalogEntry>), CatalogError> {
+    let mut parser = Parser {
+        text,
+        line: 1,
+        line_pos: 0,
+        is_xliff: false,
+        locale: None,
+        entries: Vec::new(),
+        unit: None,
+        capture: None,
+        skip_until: None,
+    };
+    parser.parse()?;
+    if !parser.is_xliff {
+        return Err(CatalogError::new(
+            CatalogFormat::Xliff,
+            1,
+            "not an XLIFF document",
+        ));
+    }
+    Ok((parser.locale, parser.entries))
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    // The line of the position `line_pos`
+    line: usize,
+    line_pos: usize,
+    is_xliff: bool,
+    locale: Option<String>,
+    entries: Vec<CatalogEntry>,
+    unit: Option<PendingUnit>,
+    capture: Option<Capture>,
+    // The name of an element, the content of which is skipped
+    skip_until: Option<&'a str>,
+}
+
+struct Tag<'a> {
+    name: &'a str,
+    attributes: &'a str,
+    is_end: bool,
+    is_self_closing: bool,
+}
+
+impl<'a> Parser<'a> {
+    fn line_at(&mut self, pos: usize) -> usize {
+        let bytes = &self.text.as_bytes()[self.line_pos..pos];
+        self.line += bytes.iter().filter(|&&b| b == b'\n').count();
+        self.line_pos = pos;
+        self.line
+    }
+
+    fn error(&mut self, pos: usize, message: &'static str) -> CatalogError {
+        CatalogError::new(CatalogFormat::Xliff, self.line_at(pos), message)
+    }
+
+    fn parse(&mut self) -> Result<(), CatalogError> {
+        let text = self.text;
+        let mut pos = 0;
+        while let Some(offset) = text[pos..].find(['<', '&']) {
+            let start = pos + offset;
+            self.push_text(&text[pos..start]);
+            let rest = &text[start..];
+            pos = if rest.starts_with('&') {
+                match decode_char_reference(rest) {
+                    Some((ch, len)) => {
+                        self.push_text(ch.encode_utf8(&mut [0; 4]));
+                        start + len
+                    }
+                    None => {
+                        self.push_text("&");
+                        start + 1
+                    }
+                }
+            } else if rest.starts_with("<!--") {
+                self.skip_past(start, "-->", "unterminated comment")?
+            } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+                let Some(len) = cdata.find("]]>") else {
+                    return Err(self.error(start, "unterminated CDATA section"));
+                };
+                self.push_text(&cdata[..len]);
+                start + "<![CDATA[".len() + len + "]]>".len()
+            } else if rest.starts_with("<?") {
+                self.skip_past(start, "?>", "unterminated processing instruction")?
+            } else if rest.starts_with("<!") {
+                self.skip_past(start, ">", "unterminated declaration")?
+            } else {
+                let (tag, end) = self.parse_tag(start)?;
+                self.handle_tag(tag, start);
+                end
+            };
+        }
+        self.push_text(&text[pos..]);
+        Ok(())
+    }
+
+    fn skip_past(
+        &mut self,
+        start: usize,
+        terminator: &str,
+        message: &'static str,
+    ) -> Result<usize, CatalogError> {
+        match self.text[start..].find(terminator) {
+            Some(len) => Ok(start + len + terminator.len()),
+            None => Err(self.error(start, message)),
+        }
+    }
+
+    // A start or an end tag at the position, attributes in quotes may contain `>`.
+    fn parse_tag(&mut self, start: usize) -> Result<(Tag<'a>, usize), CatalogError> {
+        let text = self.text;
+        let bytes = text.as_bytes();
+        let mut pos = start + 1;
+        let mut quote = None;
+        let end = loop {
+            match (bytes.get(pos), quote) {
+                (None, _) => return Err(self.error(start, "unterminated tag")),
+                (Some(&b), Some(q)) if b == q => quote = None,
+                (Some(_), Some(_)) => {}
+                (Some(&b), None) if b == b'"' || b == b'\'' => quote = Some(b),
+                (Some(b'>'), None) => break pos,
+                (Some(b'<'), None) => return Err(self.error(start, "unterminated tag")),
+                (Some(_), None) => {}
+            }
+            pos += 1;
+        };
+        let content = &text[start + 1..end];
+        let (content, is_end) = match content.strip_prefix('/') {
+            Some(content) => (content, true),
+            None => (content, false),
+        };
+        let (content, is_self_closing) = match content.strip_suffix('/') {
+            Some(content) => (content, true),
+            None => (content, false),
+        };
+        let name_len = content
+            .find(|ch: char| ch.is_whitespace())
+            .unwrap_or(content.len());
+        let name = &content[..name_len];
+        if name.is_empty() {
+            return Err(self.error(start, "a tag without a name"));
+        }
+        // Namespace prefixes are ignored, e.g. `xlf:target`
+        let name = name.rsplit(':').next().unwrap_or(name);
+        let tag = Tag {
+            name,
+            attributes: &content[name_len..],
+            is_end,
+            is_self_closing,
+        };
+        Ok((tag, end + 1))
+    }
+
+    fn handle_tag(&mut self, tag: Tag<'a>, start: usize) {
+        if let Some(name) = self.skip_until {
+            if tag.is_end && tag.name == name {
+                self.skip_until = None;
+            }
+            return;
+        }
+        if tag.is_end {
+            match tag.name {
+                "source" | "target" => {
+                    // Segments of XLIFF 2.0 units
+                    self.push_text("\n");
+                    self.capture = None;
+                }
+                "trans-unit" | "unit" => self.finish_unit(),
+                _ => {}
+            }
+            return;
+        }
+        if self.capture.is_some() {
+            if CODE_ELEMENTS.contains(&tag.name) && !tag.is_self_closing {
+                self.push_text(" ");
+                self.skip_until = Some(tag.name);
+            } else if PLACEHOLDER_ELEMENTS.contains(&tag.name) {
+                self.push_text(" ");
+            }
+            return;
+        }
+        match tag.name {
+            "xliff" => {
+                self.is_xliff = true;
+                self.locale = attribute(tag.attributes, "trgLang");
+            }
+            "file" if self.locale.is_none() => {
+                self.locale = attribute(tag.attributes, "target-language");
+            }
+            "trans-unit" | "unit" if !tag.is_self_closing => {
+                self.unit = Some(PendingUnit {
+                    id: attribute(tag.attributes, "id").unwrap_or_default(),
+                    line: self.line_at(start),
+                    ..PendingUnit::default()
+                });
+            }
+            "alt-trans" if !tag.is_self_closing => self.skip_until = Some("alt-trans"),
+            "source" if self.unit.is_some() && !tag.is_self_closing => {
+                self.capture = Some(Capture::Source);
+            }
+            "target" if self.unit.is_some() && !tag.is_self_closing => {
+                self.capture = Some(Capture::Target);
+            }
+            _ => {}
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        if self.skip_until.is_some() {
+            return;
+        }
+        let Some(unit) = &mut self.unit else {
+            return;
+        };
+        match self.capture {
+            Some(Capture::Source) => unit.source.push_str(text),
+            Some(Capture::Target) => unit.target.push_str(text),
+            None => {}
+        }
+    }
+
+    fn finish_unit(&mut self) {
+        let Some(unit) = self.unit.take() else {
+            return;
+        };
+        self.entries.push(CatalogEntry {
+            key: unit.id,
+            source: Some(String::from(unit.source.trim())),
+            translation: String::from(unit.target.trim()),
+            line: unit.line,
+        });
+    }
+}
+
+// The value of an attribute, e.g. `de` of `target-language="de"`.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let mut rest = attributes;
+    loop {
+        rest = rest.trim_start();
+        let (attr_name, after_name) = rest.split_once('=')?;
+        let after_name = after_name.trim_start();
+        let quote = after_name.chars().next()?;
+        if quote != '"' && quote != '\'' {
+            return None;
+        }
+        let value_len = after_name[1..].find(quote)?;
+        let value = &after_name[1..1 + value_len];
+        if attr_name.trim() == name {
+            return Some(String::from(value));
+        }
+        rest = &after_name[1 + value_len + 1..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Keys, sources, translations and lines of the entries
+    type Entries = Vec<(String, String, String, usize)>;
+
+    fn entries(xliff: &str) -> (Option<String>, Entries) {
+        let (locale, entries) = parse(xliff).unwrap();
+        let entries = entries
+            .into_iter()
+            .map(|entry| {
+                (
+                    entry.key,
+                    entry.source.unwrap_or_default(),
+                    entry.translation,
+                    entry.line,
+                )
+            })
+            .collect();
+        (locale, entries)
+    }
+
+    #[test]
+    fn test_parse_xliff_1_2() {
+        let xliff = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+  <file source-language="en" target-language='de-DE' datatype="plaintext" original="app">
+    <body>
+      <!-- <trans-unit id="commented"> -->
+      <trans-unit id="save">
+        <source>Save &lt;b&gt;all&lt;/b&gt; files</source>
+        <target state="translated">Alle <g id="1">Dateien</g> speichern<x id="2"/>&#33;</target>
+        <alt-trans><target>Alles sichern</target></alt-trans>
+      </trans-unit>
+      <trans-unit id="bold">
+        <source>Click <bpt id="1">&lt;b></bpt>here<ept id="1">&lt;/b></ept></source>
+        <target><![CDATA[Hier <klicken>]]></target>
+      </trans-unit>
+      <trans-unit id="new"><source>New</source></trans-unit>
+    </body>
+  </file>
+</xliff>"#;
+        assert_eq!(
+            entries(xliff),
+            (
+                Some("de-DE".into()),
+                vec![
+                    (
+                        "save".into(),
+                        "Save <b>all</b> files".into(),
+                        "Alle Dateien speichern !".into(),
+                        6
+                    ),
+                    (
+                        "bold".into(),
+                        "Click  here".into(),
+                        "Hier <klicken>".into(),
+                        11
+                    ),
+                    ("new".into(), "New".into(), "".into(), 15),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_xliff_2_0() {
+        let xliff = r#"<xliff xmlns="urn:oasis:names:tc:xliff:document:2.0" version="2.0" srcLang="en" trgLang="fr">
+<file id="f1">
+  <unit id="greeting">
+    <segment><source>Hello, <ph id="1"/>.</source><target>Bonjour, <ph id="1"/>.</target></segment>
+    <segment><source>Welcome!</source><target>Bienvenue <pc id="2">chez nous</pc> !</target></segment>
+  </unit>
+</file>
+</xliff>"#;
+        assert_eq!(
+            entries(xliff),
+            (
+                Some("fr".into()),
+                vec![(
+                    "greeting".into(),
+                    "Hello,  .\nWelcome!".into(),
+                    "Bonjour,  .\nBienvenue chez nous !".into(),
+                    3
+                )]
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let line = |xliff: &str| parse(xliff).err().map(|error| error.line());
+        assert_eq!(line("<html><body>Hallo</body></html>"), Some(1));
+        assert_eq!(line("<xliff>\n<file\n"), Some(2));
+        assert_eq!(line("<xliff>\n\n<!-- "), Some(3));
+        assert_eq!(line("<xliff><![CDATA[ "), Some(1));
+        assert_eq!(line("<xliff><a <b>"), Some(1));
+        assert_eq!(line("<xliff></>"), Some(1));
+        assert_eq!(line("<xliff version=\"2.0\"/>"), None);
+    }
+
+    #[test]
+    fn test_attribute() {
+        let attributes = r#" id="a" target-language = 'de' data="x>y" "#;
+        assert_eq!(attribute(attributes, "id").as_deref(), Some("a"));
+        assert_eq!(
+            attribute(attributes, "target-language").as_deref(),
+            Some("de")
+        );
+        assert_eq!(attribute(attributes, "data").as_deref(), Some("x>y"));
+        assert_eq!(attribute(attributes, "lang"), None);
+        assert_eq!(attribute("broken=x", "broken"), None);
+    }
+}
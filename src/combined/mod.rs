@@ -1,8 +1,11 @@
 use crate::Lang;
 use crate::alphabets;
-use crate::core::{Info, InternalQuery, calculate_confidence};
+use crate::core::{Buffers, Info, InternalQuery, calculate_confidence};
 use crate::trigrams;
+#[cfg(feature = "dev")]
+use alloc::vec::Vec;
 
+#[cfg(feature = "dev")]
 #[derive(Debug)]
 pub struct RawOutcome {
     pub scores: Vec<(Lang, f64)>,
@@ -11,19 +14,18 @@ pub struct RawOutcome {
     pub trigram_raw_outcome: trigrams::RawOutcome,
 }
 
-pub fn detect(iquery: &InternalQuery) -> Option<Info> {
-    let raw_outcome = raw_detect(iquery);
+pub fn detect(iquery: &InternalQuery, buffers: &mut Buffers) -> Option<Info> {
+    let (count, scores) = raw_detect_into(iquery, buffers);
 
-    let count = raw_outcome.trigram_raw_outcome.trigrams_count;
-    let mut normalized_scores_iter = raw_outcome.scores.into_iter();
+    let mut normalized_scores_iter = scores.iter();
 
     let opt_lang_score1 = normalized_scores_iter.next();
     let opt_lang_score2 = normalized_scores_iter.next();
 
     // TODO: Logic is duplicated in alphabets and trigrams. Consider refactoring
-    opt_lang_score1.map(|(lang1, score1)| {
+    opt_lang_score1.map(|&(lang1, score1)| {
         let script = iquery.multi_lang_script.to_script();
-        let confidence = if let Some((_, score2)) = opt_lang_score2 {
+        let confidence = if let Some(&(_, score2)) = opt_lang_score2 {
             calculate_confidence(score1, score2, count)
         } else {
             1.0
@@ -32,27 +34,60 @@ pub fn detect(iquery: &InternalQuery) -> Option<Info> {
     })
 }
 
-// TODO: optimize!
+#[cfg(feature = "dev")]
 pub fn raw_detect(iquery: &InternalQuery) -> RawOutcome {
-    let alphabet_raw_outcome: alphabets::RawOutcome = alphabets::raw_detect(iquery);
-    let trigram_raw_outcome: trigrams::RawOutcome = trigrams::raw_detect(iquery);
-
-    let alphabet_scores: &Vec<(Lang, f64)> = &alphabet_raw_outcome.scores;
-    let trigram_scores: &Vec<(Lang, f64)> = &trigram_raw_outcome.scores;
-
-    let mut all_langs: Vec<Lang> = alphabet_scores.iter().map(|x| x.0).collect();
-    for (lang, _) in trigram_scores.iter() {
-        if !all_langs.contains(lang) {
-            all_langs.push(*lang);
-        }
+    let mut buffers = Buffers::default();
+    raw_detect_into(iquery, &mut buffers);
+    let Buffers {
+        alphabet,
+        trigram,
+        scores,
+    } = buffers;
+    RawOutcome {
+        scores,
+        alphabet_raw_outcome: alphabet.outcome,
+        trigram_raw_outcome: trigram,
     }
+}
+
+// Returns the number of trigrams and the scores, which are kept in the buffers.
+// TODO: optimize!
+fn raw_detect_into<'a>(
+    iquery: &InternalQuery,
+    buffers: &'a mut Buffers,
+) -> (usize, &'a [(Lang, f64)]) {
+    let Buffers {
+        alphabet,
+        trigram,
+        scores,
+    } = buffers;
+    let alphabet_raw_outcome: &alphabets::RawOutcome = alphabets::calculate_scores_into(
+        iquery.text,
+        iquery.filter_list,
+        iquery.multi_lang_script,
+        iquery.alphabet_mode,
+        iquery.tolerate_foreign_words,
+        alphabet,
+    );
+    let trigram_raw_outcome: &trigrams::RawOutcome = trigrams::raw_detect_into(iquery, trigram);
+
+    let alphabet_scores: &[(Lang, f64)] = &alphabet_raw_outcome.scores;
+    let trigram_scores: &[(Lang, f64)] = &trigram_raw_outcome.scores;
+
+    // Languages of the alphabet method go first, followed by the rest of the trigram method.
+    let all_langs = alphabet_scores.iter().map(|x| x.0).chain(
+        trigram_scores
+            .iter()
+            .map(|x| x.0)
+            .filter(|lang| !alphabet_scores.iter().any(|(l, _)| l == lang)),
+    );
 
     let count = alphabet_raw_outcome.count;
 
-    let alphabet_weight = calc_alphabet_weight(count);
+    let alphabet_weight = iquery.alphabet_weight_curve.weight(count);
     let trigram_weight = 1.0 - alphabet_weight;
 
-    let mut scores = Vec::with_capacity(all_langs.len());
+    scores.clear();
 
     for lang in all_langs {
         let a: f64 = alphabet_scores
@@ -79,53 +114,7 @@ pub fn raw_detect(iquery: &InternalQuery) -> RawOutcome {
         scores.push((lang, score));
     }
 
-    scores.sort_unstable_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Less));
-
-    RawOutcome {
-        scores,
-        alphabet_raw_outcome,
-        trigram_raw_outcome,
-    }
-}
-
-// Function that calculates weight of alphabet score depending on number of characters in the given
-// text. The longer text the less significant alphabet weight is (and the more significant is
-// trigram weight).
-//
-// y = -(x/300) + 2/3
-// where:
-//   x - number of characters
-//   y = alphabet weight
-//
-//
-//          alphabet weight
-//          ^
-//          |
-//     2/3 -* (0; 2/3)
-//          | \_
-//          |   \_
-//          |     \_
-//          |       \_
-//          |         \  (100; 1/3)
-//     1/3 -|          *------------
-//          |
-//          +----------|------------> count
-//         0          100
-//
-fn calc_alphabet_weight(count: usize) -> f64 {
-    let weight = -(count as f64 / 300.0) + 2.0 / 3.0;
-    weight.clamp(1.0 / 3.0, 2.0 / 3.0)
-}
+    scores.sort_unstable_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(core::cmp::Ordering::Less));
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_calc_alphabet_weight() {
-        assert_eq!(calc_alphabet_weight(0), 2.0 / 3.0);
-        assert_eq!(calc_alphabet_weight(50), 0.5);
-        assert_eq!(calc_alphabet_weight(100), 1.0 / 3.0);
-        assert_eq!(calc_alphabet_weight(200), 1.0 / 3.0);
-    }
+    (trigram_raw_outcome.trigrams_count, scores)
 }
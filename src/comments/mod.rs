@@ -0,0 +1,440 @@
+mod prose;
+mod scanner;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use scanner::Piece;
+
+/// Syntax of comments and string literals of a programming language,
+/// see [CommentExtractor].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Syntax {
+    /// `//` and `/* */` comments, `"`, `'` and `` ` `` strings: C, C++, C#, Java, JavaScript,
+    /// TypeScript, Go, Kotlin, Swift, etc. `///` and `/** */` are doc comments.
+    CFamily,
+    /// `//` and nested `/* */` comments, `///`, `//!`, `/** */` and `/*! */` doc comments,
+    /// `"` and raw strings (e.g. `r#"..."#`).
+    Rust,
+    /// `#` comments, `"` and `'` strings, triple-quoted strings starting a line are docstrings.
+    Python,
+    /// `#` comments starting a line or following a whitespace, `"` and `'` strings:
+    /// shell, Ruby, Perl, R, YAML, TOML, etc.
+    Hash,
+}
+
+impl Syntax {
+    /// Get the syntax by a file extension, with or without the leading dot.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::Syntax;
+    /// assert_eq!(Syntax::from_extension("rs"), Some(Syntax::Rust));
+    /// assert_eq!(Syntax::from_extension(".TSX"), Some(Syntax::CFamily));
+    /// assert_eq!(Syntax::from_extension("txt"), None);
+    /// ```
+    pub fn from_extension(extension: &str) -> Option<Syntax> {
+        let extension = extension.strip_prefix('.').unwrap_or(extension);
+        let is = |extensions: &[&str]| {
+            extensions
+                .iter()
+                .any(|ext| ext.eq_ignore_ascii_case(extension))
+        };
+        if is(&["rs"]) {
+            Some(Syntax::Rust)
+        } else if is(&["py", "pyi", "pyw"]) {
+            Some(Syntax::Python)
+        } else if is(&[
+            "c", "h", "cc", "cpp", "cxx", "hh", "hpp", "hxx", "cs", "java", "js", "jsx", "mjs",
+            "cjs", "ts", "tsx", "go", "kt", "kts", "swift", "scala", "dart", "scss", "less",
+        ]) {
+            Some(Syntax::CFamily)
+        } else if is(&[
+            "sh", "bash", "zsh", "fish", "rb", "pl", "pm", "r", "yaml", "yml", "toml", "cmake",
+        ]) {
+            Some(Syntax::Hash)
+        } else {
+            None
+        }
+    }
+}
+
+/// Kind of a [Comment].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentKind {
+    /// Consecutive line comments, e.g. `// ...` or `# ...`.
+    Line,
+    /// A block comment, e.g. `/* ... */`.
+    Block,
+    /// Doc comments, e.g. `/// ...` or `/** ... */`.
+    Doc,
+    /// A docstring of Python.
+    Docstring,
+    /// A string literal.
+    StringLiteral,
+}
+
+/// A comment or a string literal of source code, see [CommentExtractor].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    kind: CommentKind,
+    range: Range<usize>,
+    line: usize,
+    text: String,
+}
+
+impl Comment {
+    pub fn kind(&self) -> CommentKind {
+        self.kind
+    }
+
+    /// Byte range of the comment in the source code, including comment markers and quotes.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Number of the line the comment starts at, starting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Prose of the comment: comment markers, escapes of strings, identifiers and other
+    /// code-like tokens are removed. Lines of the text are lines of the comment.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// Extracts comments and string literals of source code to detect their natural language,
+/// see [Detector::detect_comments](crate::Detector::detect_comments).
+///
+/// Comments are full of code: identifiers (`parse_config`, `HashMap`), paths, commented-out
+/// lines and examples, which would be detected as English. Such tokens and lines are removed
+/// from the text of a comment, while the prose is kept. Comments and string literals which have
+/// no prose left (e.g. `"utf-8"`) are skipped.
+///
+/// # Example
+/// ```
+/// use whatlang::{CommentExtractor, CommentKind, Detector, Lang, Syntax};
+///
+/// let source = r#"
+/// /// Liest die Konfiguration mit `fn parse_config` aus der Datei.
+/// fn read_config(path: &Path) -> Config {
+///     // let config = Config::default();
+///     parse_config(&fs::read_to_string(path).expect("Die Datei fehlt"))
+/// }
+/// "#;
+/// let extractor = CommentExtractor::new(Syntax::Rust);
+/// let comments = extractor.extract(source);
+/// assert_eq!(comments.len(), 2);
+/// assert_eq!(comments[0].kind(), CommentKind::Doc);
+/// assert_eq!(comments[0].line(), 2);
+/// assert_eq!(comments[0].text().trim(), "Liest die Konfiguration mit aus der Datei.");
+/// assert_eq!(comments[1].kind(), CommentKind::StringLiteral);
+/// assert_eq!(comments[1].text().trim(), "Die Datei fehlt");
+///
+/// for (comment, info) in Detector::new().detect_comments(source, &extractor) {
+///     assert_eq!(info.unwrap().lang(), Lang::Deu);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommentExtractor {
+    syntax: Syntax,
+    string_literals: bool,
+}
+
+impl CommentExtractor {
+    pub fn new(syntax: Syntax) -> Self {
+        Self {
+            syntax,
+            string_literals: true,
+        }
+    }
+
+    /// Extract string literals (except docstrings) besides comments, enabled by default.
+    pub fn set_string_literals(mut self, enabled: bool) -> Self {
+        self.string_literals = enabled;
+        self
+    }
+
+    /// Extract comments and string literals in order of their position.
+    pub fn extract(&self, source: &str) -> Vec<Comment> {
+        let mut extraction = Extraction {
+            source,
+            comments: Vec::new(),
+            pending: None,
+            line: 1,
+            line_counted: 0,
+        };
+        scanner::scan(source, self.syntax, |piece| {
+            if self.string_literals || piece.kind != CommentKind::StringLiteral {
+                extraction.add(piece);
+            }
+        });
+        extraction.finish()
+    }
+}
+
+// A comment, which raw text is not turned into prose yet, because it may be continued
+// by the next line comment.
+struct PendingComment {
+    kind: CommentKind,
+    range: Range<usize>,
+    line: usize,
+    is_line_comment: bool,
+    raw: String,
+}
+
+struct Extraction<'a> {
+    source: &'a str,
+    comments: Vec<Comment>,
+    pending: Option<PendingComment>,
+    // Number of the line at the position `line_counted` of the source
+    line: usize,
+    line_counted: usize,
+}
+
+impl Extraction<'_> {
+    fn add(&mut self, piece: Piece) {
+        if let Some(pending) = &mut self.pending
+            && pending.is_line_comment
+            && piece.is_line_comment
+            && pending.kind == piece.kind
+            && is_line_break(&self.source[pending.range.end..piece.range.start])
+        {
+            pending.range.end = piece.range.end;
+            piece.push_raw(self.source, &mut pending.raw);
+            return;
+        }
+        self.flush();
+
+        let start = piece.range.start;
+        self.line += self.source.as_bytes()[self.line_counted..start]
+            .iter()
+            .filter(|&&b| b == b'\n')
+            .count();
+        self.line_counted = start;
+
+        let mut raw = String::new();
+        piece.push_raw(self.source, &mut raw);
+        self.pending = Some(PendingComment {
+            kind: piece.kind,
+            range: piece.range,
+            line: self.line,
+            is_line_comment: piece.is_line_comment,
+            raw,
+        });
+    }
+
+    fn flush(&mut self) {
+        let Some(pending) = self.pending.take() else {
+            return;
+        };
+        let mut text = String::new();
+        prose::push_prose(&pending.raw, &mut text);
+        // A single letter is not a prose, e.g. a char literal of C
+        if text
+            .chars()
+            .filter(|ch| ch.is_alphabetic())
+            .nth(1)
+            .is_some()
+        {
+            self.comments.push(Comment {
+                kind: pending.kind,
+                range: pending.range,
+                line: pending.line,
+                text,
+            });
+        }
+    }
+
+    fn finish(mut self) -> Vec<Comment> {
+        self.flush();
+        self.comments
+    }
+}
+
+// Line comments are continued by the ones on the next line.
+fn is_line_break(between: &str) -> bool {
+    between.trim_matches([' ', '\t', '\r']) == "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(syntax: Syntax, source: &str) -> Vec<(CommentKind, usize, String)> {
+        CommentExtractor::new(syntax)
+            .extract(source)
+            .into_iter()
+            .map(|comment| {
+                let words: Vec<&str> = comment.text().split_whitespace().collect();
+                (comment.kind(), comment.line(), words.join(" "))
+            })
+            .collect()
+    }
+
+    fn text(kind: CommentKind, line: usize, text: &str) -> (CommentKind, usize, String) {
+        (kind, line, String::from(text))
+    }
+
+    #[test]
+    fn test_c_family() {
+        let source = r#"/**
+ * Öffnet die Verbindung zum Server.
+ * @param host Name des Servers
+ */
+int connect(const char *host) {
+    // Erst die Adresse auflösen,
+    // dann verbinden.
+    char c = 'x';  /* kurz warten */
+    printf("Verbindung zu %s wird hergestellt\n", host);
+    const s = `Vorlage ${host}`;
+    return 0; // Fertig!
+}"#;
+        assert_eq!(
+            texts(Syntax::CFamily, source),
+            vec![
+                text(
+                    CommentKind::Doc,
+                    1,
+                    "Öffnet die Verbindung zum Server. host Name des Servers"
+                ),
+                text(
+                    CommentKind::Line,
+                    6,
+                    "Erst die Adresse auflösen, dann verbinden."
+                ),
+                text(CommentKind::Block, 8, "kurz warten"),
+                text(
+                    CommentKind::StringLiteral,
+                    9,
+                    "Verbindung zu wird hergestellt"
+                ),
+                text(CommentKind::StringLiteral, 10, "Vorlage"),
+                text(CommentKind::Line, 11, "Fertig!"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rust() {
+        let source = r####"//! Módulo de configuración.
+
+/// Lee la configuración.
+///
+/// ```
+/// let config = read_config("config.toml");
+/// ```
+/* externo /* anidado */ todavía */
+fn read_config<'a>(path: &'a str) -> char {
+    let s = r#"cadena "cruda" aquí"#;
+    let b = br"bytes crudos";
+    '"' // comillas
+}"####;
+        assert_eq!(
+            texts(Syntax::Rust, source),
+            vec![
+                text(CommentKind::Doc, 1, "Módulo de configuración."),
+                text(CommentKind::Doc, 3, "Lee la configuración."),
+                text(CommentKind::Block, 8, "externo /* anidado */ todavía"),
+                text(CommentKind::StringLiteral, 10, "cadena \"cruda\" aquí"),
+                text(CommentKind::StringLiteral, 11, "bytes crudos"),
+                text(CommentKind::Line, 12, "comillas"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_python() {
+        let source = r#"#!/usr/bin/env python
+"""Outils de détection."""
+
+def detect(text):
+    '''
+    Détecte la langue du texte.
+
+    >>> detect("Bonjour")
+    '''
+    # Ignorer les textes vides
+    return LANGS.get(text, "inconnu") # pas trouvé
+"#;
+        assert_eq!(
+            texts(Syntax::Python, source),
+            vec![
+                text(CommentKind::Docstring, 2, "Outils de détection."),
+                text(CommentKind::Docstring, 5, "Détecte la langue du texte."),
+                text(CommentKind::Line, 10, "Ignorer les textes vides"),
+                text(CommentKind::StringLiteral, 11, "inconnu"),
+                text(CommentKind::Line, 11, "pas trouvé"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hash() {
+        let source = "# Installiert die Pakete\n\
+            echo \"Fertig, $USER\" ${#args[@]} 'it''s'\n\
+            url: https://example.com/#anchor # Die Adresse\n";
+        assert_eq!(
+            texts(Syntax::Hash, source),
+            vec![
+                text(CommentKind::Line, 1, "Installiert die Pakete"),
+                text(CommentKind::StringLiteral, 2, "Fertig,"),
+                text(CommentKind::StringLiteral, 2, "it"),
+                text(CommentKind::Line, 3, "Die Adresse"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_line_comments_are_merged() {
+        let source = "// eins\n  // zwei\n\n// drei\n/// vier\nx // fünf\n// sechs";
+        assert_eq!(
+            texts(Syntax::Rust, source),
+            vec![
+                text(CommentKind::Line, 1, "eins zwei"),
+                text(CommentKind::Line, 4, "drei"),
+                text(CommentKind::Doc, 5, "vier"),
+                text(CommentKind::Line, 6, "fünf sechs"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_string_literals() {
+        let source = "// Kommentar\nprint(\"Zeichenkette\")";
+        let extractor = CommentExtractor::new(Syntax::CFamily).set_string_literals(false);
+        let comments = extractor.extract(source);
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].kind(), CommentKind::Line);
+        assert_eq!(comments[0].range(), 0..12);
+    }
+
+    #[test]
+    fn test_code_is_skipped() {
+        let source = "// fn parse_config\n\
+            // let x = parse_config(path);\n\
+            // TODO: HashMap<String, usize>\n\
+            x = \"utf-8\" + \"{name}\" + \"%s\" + 'a'";
+        assert_eq!(texts(Syntax::CFamily, source), vec![]);
+    }
+
+    #[test]
+    fn test_unterminated() {
+        assert_eq!(
+            texts(Syntax::CFamily, "/* nicht beendet"),
+            vec![text(CommentKind::Block, 1, "nicht beendet")]
+        );
+        assert_eq!(
+            texts(Syntax::Rust, "\"nicht beendet"),
+            vec![text(CommentKind::StringLiteral, 1, "nicht beendet")]
+        );
+        // Strings of other languages end at the end of a line
+        assert_eq!(
+            texts(Syntax::Python, "x = 'nicht beendet\n# Kommentar"),
+            vec![text(CommentKind::Line, 2, "Kommentar")]
+        );
+    }
+}
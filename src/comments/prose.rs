@@ -0,0 +1,182 @@
+use alloc::string::String;
+
+// Characters of identifiers, paths, placeholders and markup, which are not used within words.
+const CODE_CHARS: [char; 12] = ['_', '{', '}', '<', '>', '=', '%', '$', '\\', '|', '@', '/'];
+
+// Characters used within words besides letters and digits, e.g. `don't`, `well-known`, `z.B.`
+const WORD_CHARS: [char; 4] = ['\'', '’', '-', '.'];
+
+// Operators, which are rarely used in prose.
+const CODE_OPERATORS: [&str; 10] = [" = ", "==", "!=", "=>", "->", "::", "&&", "||", "+=", "):"];
+
+// Keywords and names of common types and functions, which are not words of any language.
+const KEYWORDS: [&str; 24] = [
+    "args", "bool", "def", "elif", "elsif", "endif", "enum", "fn", "func", "ifdef", "ifndef",
+    "impl", "int", "isize", "kwargs", "mut", "nullptr", "printf", "println", "pub", "stderr",
+    "stdout", "struct", "usize",
+];
+
+/// Append the prose of the comment: lines of code and code-like tokens are skipped.
+pub(super) fn push_prose(comment: &str, out: &mut String) {
+    // Code blocks of Markdown in doc comments
+    let mut is_code_block = false;
+    for line in comment.lines() {
+        let line = line.trim();
+        if line.starts_with("```") || line.starts_with("~~~") {
+            is_code_block = !is_code_block;
+            continue;
+        }
+        if is_code_block || is_code_line(line) {
+            continue;
+        }
+        // Every second part of a line split by backticks is code, e.g. `parse_config`
+        for prose in line.split('`').step_by(2) {
+            for token in prose.split_whitespace() {
+                if !is_code_like(token) {
+                    out.push_str(token);
+                    out.push(' ');
+                }
+            }
+        }
+        out.push('\n');
+    }
+}
+
+// A line of commented-out code, an example or a doctest.
+fn is_code_line(line: &str) -> bool {
+    line.ends_with([';', '{'])
+        || line.starts_with(">>>")
+        || CODE_OPERATORS.iter().any(|op| line.contains(op))
+        || line
+            .split_once(' ')
+            .is_some_and(|(word, _)| KEYWORDS.contains(&word))
+}
+
+fn is_code_like(token: &str) -> bool {
+    let core = token.trim_matches(|ch: char| !ch.is_alphanumeric());
+    if core.is_empty() {
+        // Punctuation, e.g. `-` or `...`
+        return false;
+    }
+    token.contains(CODE_CHARS)
+        || token.contains("()")
+        || core
+            .chars()
+            .any(|ch| !ch.is_alphanumeric() && !WORD_CHARS.contains(&ch))
+        || is_dotted_path(core)
+        || is_mixed_case(core)
+        || (core.chars().any(char::is_alphabetic) && core.chars().any(char::is_numeric))
+        || is_acronym(core)
+        || KEYWORDS.contains(&core)
+}
+
+// E.g. `config.toml` or `self.name`, but not `e.g` or `z.B`.
+fn is_dotted_path(core: &str) -> bool {
+    core.contains('.') && core.split('.').any(|part| part.chars().nth(2).is_some())
+}
+
+// E.g. `parseConfig`, `HashMap` or `XMLParser`.
+fn is_mixed_case(core: &str) -> bool {
+    let mut prev = None;
+    let mut prev_prev = None;
+    for ch in core.chars() {
+        if let Some(prev) = prev {
+            let is_camel = char::is_lowercase(prev) && ch.is_uppercase();
+            let is_acronym_prefix = prev_prev.is_some_and(char::is_uppercase)
+                && char::is_uppercase(prev)
+                && ch.is_lowercase();
+            if is_camel || is_acronym_prefix {
+                return true;
+            }
+        }
+        prev_prev = prev;
+        prev = Some(ch);
+    }
+    false
+}
+
+// E.g. `TODO`, `HTTP` or `MAX`, but not `I`.
+fn is_acronym(core: &str) -> bool {
+    core.len() > 1 && core.bytes().all(|b| b.is_ascii_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prose(comment: &str) -> String {
+        let mut out = String::new();
+        push_prose(comment, &mut out);
+        out.split_whitespace()
+            .collect::<alloc::vec::Vec<_>>()
+            .join(" ")
+    }
+
+    #[test]
+    fn test_is_code_like() {
+        let code = [
+            "parse_config",
+            "parse_config()",
+            "foo()",
+            "f(x)",
+            "parseConfig",
+            "HashMap<String,",
+            "XMLParser",
+            "TODO:",
+            "HTTP",
+            "config.toml",
+            "self.name",
+            "std::fmt",
+            "https://example.com",
+            "anna@example.com",
+            "{name}",
+            "%s",
+            "$HOME",
+            "utf8",
+            "0xFF",
+            "fn",
+            "usize",
+            "a*b",
+            "__init__",
+        ];
+        for token in code {
+            assert!(is_code_like(token), "{}", token);
+        }
+
+        let prose = [
+            "Hello",
+            "world,",
+            "(see",
+            "above)",
+            "don't",
+            "l'homme",
+            "well-known",
+            "z.B.",
+            "e.g.",
+            "etc.",
+            "I",
+            "A",
+            "Straße",
+            "ÉCOLE",
+            "Всё",
+            "*wichtig*",
+            "—",
+            "...",
+            "42",
+            "配置文件",
+        ];
+        for token in prose {
+            assert!(!is_code_like(token), "{}", token);
+        }
+    }
+
+    #[test]
+    fn test_push_prose() {
+        assert_eq!(prose("Liest `parse_config` aus."), "Liest aus.");
+        assert_eq!(prose("fn parse_config"), "");
+        assert_eq!(prose("let x = 1\nif (x) {\nreturn x;"), "");
+        assert_eq!(prose(">>> detect('Hallo')\nHallo Welt"), "Hallo Welt");
+        assert_eq!(prose("Beispiel:\n```\nlet x\n```\nEnde"), "Beispiel: Ende");
+        assert_eq!(prose("def detect(text):"), "");
+    }
+}
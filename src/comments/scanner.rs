@@ -0,0 +1,301 @@
+use super::{CommentKind, Syntax};
+use alloc::string::String;
+use core::ops::Range;
+
+/// A comment or a string literal found in source code.
+pub(super) struct Piece {
+    pub(super) kind: CommentKind,
+    // The whole piece, including markers and quotes
+    pub(super) range: Range<usize>,
+    // The content without markers and quotes
+    content: Range<usize>,
+    pub(super) is_line_comment: bool,
+    is_block_comment: bool,
+    has_escapes: bool,
+}
+
+impl Piece {
+    /// Append the content of the piece with escapes of strings decoded and leading `*`
+    /// of block comment lines removed.
+    pub(super) fn push_raw(&self, source: &str, out: &mut String) {
+        let content = &source[self.content.clone()];
+        if self.is_block_comment {
+            for line in content.lines() {
+                let line = line.trim_start();
+                out.push_str(line.strip_prefix('*').unwrap_or(line));
+                out.push('\n');
+            }
+        } else if self.has_escapes {
+            push_unescaped(content, out);
+            out.push('\n');
+        } else {
+            out.push_str(content);
+            out.push('\n');
+        }
+    }
+}
+
+// Escapes are replaced by the escaped characters, while control characters (e.g. `\n`)
+// and escapes of code points (e.g. `\u{e9}`, `\x41`) separate words.
+fn push_unescaped(content: &str, out: &mut String) {
+    let mut chars = content.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some(escaped @ ('\\' | '"' | '\'' | '`')) => out.push(escaped),
+            Some(_) | None => out.push(' '),
+        }
+    }
+}
+
+/// Find comments and string literals of the source code and pass them to `on_piece`
+/// in order of their position.
+pub(super) fn scan(source: &str, syntax: Syntax, mut on_piece: impl FnMut(Piece)) {
+    let scanner = Scanner {
+        source,
+        bytes: source.as_bytes(),
+        syntax,
+    };
+    let mut pos = 0;
+    while pos < source.len() {
+        let (piece, end) = scanner.scan_at(pos);
+        if let Some(piece) = piece {
+            on_piece(piece);
+        }
+        pos = end;
+    }
+}
+
+struct Scanner<'a> {
+    source: &'a str,
+    bytes: &'a [u8],
+    syntax: Syntax,
+}
+
+impl Scanner<'_> {
+    // A piece starting at the position, if any, and the position to continue from.
+    fn scan_at(&self, pos: usize) -> (Option<Piece>, usize) {
+        let rest = &self.bytes[pos..];
+        let has_c_comments = matches!(self.syntax, Syntax::CFamily | Syntax::Rust);
+        match rest[0] {
+            b'/' if has_c_comments && rest.starts_with(b"//") => self.line_comment(pos, 2),
+            b'/' if has_c_comments && rest.starts_with(b"/*") => self.block_comment(pos),
+            b'#' if self.is_hash_comment(pos) => self.line_comment(pos, 1),
+            b'r' if self.syntax == Syntax::Rust => self.raw_string(pos),
+            b'\'' if self.syntax == Syntax::Rust => (None, self.skip_char_literal(pos)),
+            quote @ (b'"' | b'\'')
+                if self.syntax == Syntax::Python && rest.starts_with(&[quote; 3]) =>
+            {
+                self.triple_quoted_string(pos)
+            }
+            b'"' if self.syntax == Syntax::Rust => self.string(pos, b'"', true),
+            b'`' if self.syntax == Syntax::CFamily => self.string(pos, b'`', true),
+            quote @ (b'"' | b'\'') => self.string(pos, quote, false),
+            _ => (None, pos + 1),
+        }
+    }
+
+    fn is_hash_comment(&self, pos: usize) -> bool {
+        match self.syntax {
+            Syntax::Python => true,
+            Syntax::Hash => pos == 0 || self.bytes[pos - 1].is_ascii_whitespace(),
+            Syntax::CFamily | Syntax::Rust => false,
+        }
+    }
+
+    fn line_end(&self, pos: usize) -> usize {
+        self.source[pos..]
+            .find('\n')
+            .map_or(self.source.len(), |i| pos + i)
+    }
+
+    fn line_comment(&self, start: usize, marker_len: usize) -> (Option<Piece>, usize) {
+        let end = self.line_end(start);
+        let comment = &self.source[start..end];
+        // A shebang, e.g. `#!/bin/sh`
+        if start == 0 && comment.starts_with("#!") {
+            return (None, end);
+        }
+        let is_doc = marker_len == 2
+            && ((comment.starts_with("///") && !comment.starts_with("////"))
+                || comment.starts_with("//!"));
+        let (kind, content_start) = if is_doc {
+            (CommentKind::Doc, start + 3)
+        } else {
+            (CommentKind::Line, start + marker_len)
+        };
+        let piece = Piece {
+            kind,
+            range: start..end,
+            content: content_start..end,
+            is_line_comment: true,
+            is_block_comment: false,
+            has_escapes: false,
+        };
+        (Some(piece), end)
+    }
+
+    // Block comments of Rust can be nested.
+    fn block_comment(&self, start: usize) -> (Option<Piece>, usize) {
+        let mut depth = 0;
+        let mut pos = start;
+        let (content_end, end) = loop {
+            let Some(offset) = self.source[pos..].find(['/', '*']) else {
+                break (self.source.len(), self.source.len());
+            };
+            let at = pos + offset;
+            let rest = &self.bytes[at..];
+            if rest.starts_with(b"/*") && (depth == 0 || self.syntax == Syntax::Rust) {
+                depth += 1;
+                pos = at + 2;
+            } else if rest.starts_with(b"*/") {
+                depth -= 1;
+                if depth == 0 {
+                    break (at, at + 2);
+                }
+                pos = at + 2;
+            } else {
+                pos = at + 1;
+            }
+        };
+        let comment = &self.source[start..end];
+        let is_doc =
+            (comment.starts_with("/**") && !comment.starts_with("/***") && comment != "/**/")
+                || comment.starts_with("/*!");
+        let (kind, content_start) = if is_doc {
+            (CommentKind::Doc, start + 3)
+        } else {
+            (CommentKind::Block, start + 2)
+        };
+        let piece = Piece {
+            kind,
+            range: start..end,
+            content: content_start..content_end.max(content_start),
+            is_line_comment: false,
+            is_block_comment: true,
+            has_escapes: false,
+        };
+        (Some(piece), end)
+    }
+
+    // A string, which ends at the closing quote. Multiline strings end at the end of the source,
+    // if they are not closed, other ones are not strings if they are not closed on the same line.
+    fn string(&self, start: usize, quote: u8, is_multiline: bool) -> (Option<Piece>, usize) {
+        // Strings in single quotes of shell have no escapes
+        let has_escapes = !(self.syntax == Syntax::Hash && quote == b'\'');
+        let mut pos = start + 1;
+        let content_end = loop {
+            match self.bytes.get(pos) {
+                None if is_multiline => break self.bytes.len(),
+                None => return (None, start + 1),
+                Some(b'\n') if !is_multiline => return (None, start + 1),
+                Some(b'\\') if has_escapes => pos += 2,
+                Some(&b) if b == quote => break pos,
+                Some(_) => pos += 1,
+            }
+        };
+        let content_end = content_end.min(self.bytes.len());
+        let piece = Piece {
+            kind: CommentKind::StringLiteral,
+            range: start..(content_end + 1).min(self.bytes.len()),
+            content: start + 1..content_end,
+            is_line_comment: false,
+            is_block_comment: false,
+            has_escapes,
+        };
+        let end = piece.range.end;
+        (Some(piece), end)
+    }
+
+    // A docstring is a triple-quoted string which starts a line.
+    fn triple_quoted_string(&self, start: usize) -> (Option<Piece>, usize) {
+        let quote = self.bytes[start];
+        let content_start = start + 3;
+        let mut pos = content_start;
+        let (content_end, end) = loop {
+            match self.bytes.get(pos) {
+                None => break (self.bytes.len(), self.bytes.len()),
+                Some(b'\\') => pos += 2,
+                Some(&b) if b == quote && self.bytes[pos..].starts_with(&[quote; 3]) => {
+                    break (pos, pos + 3);
+                }
+                Some(_) => pos += 1,
+            }
+        };
+        let line_start = self.source[..start].rfind('\n').map_or(0, |i| i + 1);
+        // Prefixes of strings, e.g. `r"""`, are letters
+        let before = self.source[line_start..start].trim_start();
+        let kind = if before.bytes().all(|b| b.is_ascii_alphabetic()) {
+            CommentKind::Docstring
+        } else {
+            CommentKind::StringLiteral
+        };
+        let piece = Piece {
+            kind,
+            range: start..end,
+            content: content_start..content_end.max(content_start),
+            is_line_comment: false,
+            is_block_comment: false,
+            has_escapes: true,
+        };
+        (Some(piece), end)
+    }
+
+    // Raw strings of Rust, e.g. `r"\d+"` and `r#"say "hi""#`, otherwise just `r`.
+    fn raw_string(&self, start: usize) -> (Option<Piece>, usize) {
+        let hashes = self.bytes[start + 1..]
+            .iter()
+            .take_while(|&&b| b == b'#')
+            .count();
+        let content_start = start + 1 + hashes + 1;
+        if self.bytes.get(content_start - 1) != Some(&b'"') {
+            return (None, start + 1);
+        }
+        let mut pos = content_start;
+        let (content_end, end) = loop {
+            let Some(offset) = self.source[pos..].find('"') else {
+                break (self.bytes.len(), self.bytes.len());
+            };
+            let at = pos + offset;
+            let closing_hashes = self.bytes[at + 1..]
+                .iter()
+                .take(hashes)
+                .take_while(|&&b| b == b'#')
+                .count();
+            if closing_hashes == hashes {
+                break (at, at + 1 + hashes);
+            }
+            pos = at + 1;
+        };
+        let piece = Piece {
+            kind: CommentKind::StringLiteral,
+            range: start..end,
+            content: content_start..content_end,
+            is_line_comment: false,
+            is_block_comment: false,
+            has_escapes: false,
+        };
+        (Some(piece), end)
+    }
+
+    // A char literal of Rust (e.g. `'"'`, `'\''`, `'\u{e9}'`) or a lifetime (e.g. `'a`).
+    fn skip_char_literal(&self, start: usize) -> usize {
+        let rest = &self.source[start + 1..];
+        let mut chars = rest.char_indices();
+        match chars.next() {
+            Some((_, '\\')) => {
+                // The longest escape is `\u{10FFFF}`
+                let end = rest.get(2..).and_then(|s| s.find('\'')).filter(|&i| i < 10);
+                let end = end.map(|i| i + 2);
+                end.map_or(start + 1, |i| start + 1 + i + 1)
+            }
+            Some((_, ch)) if rest[ch.len_utf8()..].starts_with('\'') => {
+                start + 1 + ch.len_utf8() + 1
+            }
+            _ => start + 1,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+/// Variant of the alphabet method, which is used alone or as part of the combined method.
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlphabetMode {
+    /// Every character of a language's alphabet scores equally.
+    #[default]
+    Plain,
+
+    /// Characters of a language's alphabet are weighted by their frequency in the language.
+    /// Implemented for Latin and Cyrillic scripts; other scripts fall back to `Plain`.
+    Frequency,
+}
@@ -0,0 +1,105 @@
+use crate::utils::{is_ascii_stop_char, is_stop_char};
+use alloc::vec::Vec;
+
+/// Number of occurrences of every character of a text, except stop characters.
+///
+/// Methods that don't care about the order of characters (e.g. the alphabet method) look up every
+/// distinct character only once, instead of every occurrence of it.
+#[derive(Debug)]
+pub struct CharCounts {
+    ascii: [u32; 128],
+    // Sorted by char
+    non_ascii: Vec<(char, u32)>,
+    total: usize,
+}
+
+impl Default for CharCounts {
+    fn default() -> Self {
+        Self {
+            ascii: [0; 128],
+            non_ascii: Vec::new(),
+            total: 0,
+        }
+    }
+}
+
+impl CharCounts {
+    /// Count characters, replacing the previous counts.
+    pub fn fill(&mut self, chars: impl Iterator<Item = char>) {
+        self.ascii = [0; 128];
+        self.non_ascii.clear();
+        self.total = 0;
+
+        for ch in chars {
+            if is_stop_char(ch) {
+                continue;
+            }
+            self.total += 1;
+
+            if ch.is_ascii() {
+                self.ascii[ch as usize] += 1;
+            } else {
+                match self.non_ascii.binary_search_by_key(&ch, |&(c, _)| c) {
+                    Ok(i) => self.non_ascii[i].1 += 1,
+                    Err(i) => self.non_ascii.insert(i, (ch, 1)),
+                }
+            }
+        }
+    }
+
+    /// Same as [CharCounts::fill] with the lowercase chars of ASCII text, but byte-wise.
+    pub fn fill_ascii(&mut self, bytes: &[u8]) {
+        self.ascii = [0; 128];
+        self.non_ascii.clear();
+
+        for &b in bytes {
+            self.ascii[b.to_ascii_lowercase() as usize & 0x7F] += 1;
+        }
+        // Stop chars are counted above only to keep the loop branch-free
+        for b in 0..128u8 {
+            if is_ascii_stop_char(b) {
+                self.ascii[b as usize] = 0;
+            }
+        }
+        self.total = self.ascii.iter().map(|&count| count as usize).sum();
+    }
+
+    /// Total number of characters.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Distinct characters with their number of occurrences, in ascending order of characters.
+    pub fn iter(&self) -> impl Iterator<Item = (char, usize)> + '_ {
+        let ascii = self
+            .ascii
+            .iter()
+            .enumerate()
+            .filter(|&(_, &count)| count > 0)
+            .map(|(ch, &count)| (ch as u8 as char, count as usize));
+        let non_ascii = self
+            .non_ascii
+            .iter()
+            .map(|&(ch, count)| (ch, count as usize));
+        ascii.chain(non_ascii)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char_counts() {
+        let mut counts = CharCounts::default();
+        counts.fill("ča-ča, ab 1!".chars());
+
+        assert_eq!(counts.total(), 6);
+        let all: Vec<(char, usize)> = counts.iter().collect();
+        assert_eq!(all, vec![('a', 3), ('b', 1), ('č', 2)]);
+
+        counts.fill("b".chars());
+        assert_eq!(counts.total(), 1);
+        assert_eq!(counts.iter().collect::<Vec<_>>(), vec![('b', 1)]);
+    }
+}
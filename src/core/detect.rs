@@ -1,11 +1,12 @@
 use crate::Lang;
-use crate::core::{Info, Method, Options, Query};
-use crate::scripts::{
-    RawScriptInfo, Script,
-    grouping::{MultiLangScript, ScriptLangGroup},
-    raw_detect_script,
+use crate::core::{
+    Buffers, Coverage, DetectorScratch, Info, InternalQuery, Method, Options, PreparedText, Query,
+    Sampling, Text, with_thread_scratch,
 };
-use crate::{alphabets, combined, trigrams};
+use crate::markup::InputMode;
+use crate::scripts::{RawScriptInfo, Script, grouping::ScriptLangGroup, raw_detect_script_into};
+use crate::{alphabets, combined, ensemble, trigrams};
+use alloc::vec::Vec;
 
 /// Detect only a language by a given text.
 ///
@@ -33,38 +34,210 @@ pub fn detect(text: &str) -> Option<Info> {
 }
 
 pub fn detect_with_options(text: &str, options: &Options) -> Option<Info> {
-    let query = Query {
+    with_thread_scratch(|scratch| detect_with_options_and_scratch(text, options, scratch))
+}
+
+pub(crate) fn detect_with_options_and_scratch(
+    text: &str,
+    options: &Options,
+    scratch: &mut DetectorScratch,
+) -> Option<Info> {
+    if options.input_mode == InputMode::Plain {
+        return detect_extracted(text, options, scratch);
+    }
+    let mut extracted = core::mem::take(&mut scratch.extracted);
+    options
+        .input_mode
+        .extract_into(text, &mut extracted, &mut scratch.declared_langs);
+    let info = detect_extracted(&extracted, options, scratch);
+    scratch.extracted = extracted;
+
+    let declared_lang = scratch.declared_langs.main_lang();
+    match (info, declared_lang) {
+        (Some(info), Some(lang)) if options.use_lang_attributes => {
+            Some(apply_declared_lang(info, lang, options))
+        }
+        (info, _) => info,
+    }
+}
+
+// The declared language wins over a detected one, unless the detection is reliable.
+fn apply_declared_lang(info: Info, lang: Lang, options: &Options) -> Info {
+    let is_applicable = !info.is_reliable()
+        && lang.is_supported()
+        && options.filter_list.is_allowed(lang)
+        && info.script().langs().contains(&lang);
+    if is_applicable {
+        info.with_lang(lang)
+    } else {
+        info
+    }
+}
+
+pub(crate) fn detect_extracted(
+    text: &str,
+    options: &Options,
+    scratch: &mut DetectorScratch,
+) -> Option<Info> {
+    match &options.preprocessor {
+        Some(preprocessor) => {
+            let mut preprocessed = core::mem::take(&mut scratch.preprocessed);
+            preprocessor.preprocess_into(text, &mut preprocessed, |_| {});
+            let info = detect_preprocessed(&preprocessed, options, scratch);
+            scratch.preprocessed = preprocessed;
+            info
+        }
+        None => detect_preprocessed(text, options, scratch),
+    }
+}
+
+fn detect_preprocessed(
+    text: &str,
+    options: &Options,
+    scratch: &mut DetectorScratch,
+) -> Option<Info> {
+    match options.sampling {
+        Some(sampling) if sampling.votes_by_windows() => {
+            detect_by_window_votes(text, options, &sampling, scratch)
+        }
+        Some(sampling) => detect_sample(text, options, &sampling, scratch),
+        None => detect_by_query(&build_query(text, options), scratch),
+    }
+}
+
+// Windows are joined into a single text, separated by a stop char.
+fn detect_sample(
+    text: &str,
+    options: &Options,
+    sampling: &Sampling,
+    scratch: &mut DetectorScratch,
+) -> Option<Info> {
+    let mut coverage = Coverage::new(text.len());
+    let mut sample = core::mem::take(&mut scratch.sample);
+    sample.clear();
+    for window in sampling.windows(text) {
+        if coverage.windows() > 0 {
+            sample.push('\n');
+        }
+        sample.push_str(window);
+        coverage.add_window(window);
+    }
+
+    let info = detect_by_query(&build_query(&sample, options), scratch);
+    scratch.sample = sample;
+    info.map(|info| info.with_coverage(coverage))
+}
+
+fn detect_by_window_votes(
+    text: &str,
+    options: &Options,
+    sampling: &Sampling,
+    scratch: &mut DetectorScratch,
+) -> Option<Info> {
+    let mut coverage = Coverage::new(text.len());
+    // Script, language and weight of the votes, and the total weight of the windows which voted
+    let mut votes: Vec<(Script, Lang, f64)> = Vec::new();
+    let mut total_weight = 0.0;
+
+    for window in sampling.windows(text) {
+        coverage.add_window(window);
+        if let Some(info) = detect_by_query(&build_query(window, options), scratch) {
+            let chars_count = window.chars().count() as f64;
+            total_weight += chars_count;
+            let weight = chars_count * info.confidence();
+            match votes.iter_mut().find(|(_, lang, _)| *lang == info.lang()) {
+                Some(vote) => vote.2 += weight,
+                None => votes.push((info.script(), info.lang(), weight)),
+            }
+        }
+    }
+
+    let (script, lang, weight) = votes
+        .into_iter()
+        .reduce(|best, vote| if vote.2 > best.2 { vote } else { best })?;
+    let confidence = if total_weight > 0.0 {
+        weight / total_weight
+    } else {
+        0.0
+    };
+    Some(Info::new(script, lang, confidence).with_coverage(coverage))
+}
+
+pub(crate) fn detect_prepared_with_options(
+    prepared: &PreparedText,
+    options: &Options,
+    buffers: &mut Buffers,
+) -> Option<Info> {
+    let query = build_query(prepared.original(), options);
+    detect_by_text(&query, &prepared.script_info, &prepared.text, buffers)
+}
+
+fn build_query<'a, 'b>(text: &'a str, options: &'b Options) -> Query<'a, 'b> {
+    Query {
         text,
         filter_list: &options.filter_list,
         method: options.method,
-    };
-    detect_by_query(&query)
+        ensemble: options.ensemble.as_ref(),
+        alphabet_weight_curve: options.alphabet_weight_curve,
+        alphabet_mode: options.alphabet_mode,
+        tolerate_foreign_words: options.tolerate_foreign_words,
+        normalization: options.normalization,
+    }
 }
 
-pub fn detect_by_query(query: &Query) -> Option<Info> {
-    let raw_script_info = raw_detect_script(query.text);
+pub fn detect_by_query(query: &Query, scratch: &mut DetectorScratch) -> Option<Info> {
+    let DetectorScratch {
+        script_info: raw_script_info,
+        text: text_buffers,
+        buffers,
+        ..
+    } = scratch;
+    let text = Text::with_buffers(
+        query.text,
+        query.normalization,
+        core::mem::take(text_buffers),
+    );
+    raw_detect_script_into(text.normalized(), raw_script_info);
+    let info = detect_by_text(query, raw_script_info, &text, buffers);
+    *text_buffers = text.into_buffers();
+    info
+}
+
+// Detect a language of the text, which scripts are already counted.
+fn detect_by_text(
+    query: &Query,
+    raw_script_info: &RawScriptInfo,
+    text: &Text,
+    buffers: &mut Buffers,
+) -> Option<Info> {
     let script = raw_script_info.main_script()?;
 
-    match script.to_lang_group() {
+    let info = match script.to_lang_group() {
         ScriptLangGroup::One(lang) => Some(Info::new(script, lang, 1.0)),
         ScriptLangGroup::Multi(multi_lang_script) => {
-            detect_by_query_based_on_script(query, multi_lang_script)
+            let iquery = query.to_internal(multi_lang_script, text);
+            detect_by_internal_query(query, &iquery, buffers)
         }
         ScriptLangGroup::Mandarin => {
-            Some(detect_lang_base_on_mandarin_script(query, &raw_script_info))
+            Some(detect_lang_base_on_mandarin_script(query, raw_script_info))
         }
-    }
+    };
+    // Languages excluded by cargo features are never returned.
+    info.filter(|info| info.lang().is_supported())
 }
 
-fn detect_by_query_based_on_script(
+fn detect_by_internal_query(
     query: &Query,
-    multi_lang_script: MultiLangScript,
+    iquery: &InternalQuery,
+    buffers: &mut Buffers,
 ) -> Option<Info> {
-    let iquery = query.to_internal(multi_lang_script);
+    if let Some(ensemble) = query.ensemble {
+        return ensemble::detect(iquery, ensemble);
+    }
     match query.method {
-        Method::Alphabet => alphabets::detect(&iquery),
-        Method::Trigram => trigrams::detect(&iquery),
-        Method::Combined => combined::detect(&iquery),
+        Method::Alphabet => alphabets::detect(iquery, &mut buffers.alphabet),
+        Method::Trigram => trigrams::detect(iquery, &mut buffers.trigram),
+        Method::Combined => combined::detect(iquery, buffers),
     }
 }
 
@@ -74,7 +247,8 @@ pub(crate) fn detect_lang_base_on_mandarin_script(
     query: &Query,
     raw_script_info: &RawScriptInfo,
 ) -> Info {
-    let (lang, confidence) = if query.filter_list.is_allowed(Lang::Cmn) {
+    let (lang, confidence) = if query.filter_list.is_allowed(Lang::Cmn) && Lang::Cmn.is_supported()
+    {
         let mandarin_count = raw_script_info.count(Script::Mandarin);
         let katakana_count = raw_script_info.count(Script::Katakana);
         let hiragana_count = raw_script_info.count(Script::Hiragana);
@@ -103,7 +277,9 @@ pub(crate) fn detect_lang_base_on_mandarin_script(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::FilterList;
+    use crate::core::{AlphabetMode, FilterList, Preprocessor, Sampling};
+    use crate::markup::InputMode;
+    use crate::normalization::Normalization;
     use crate::scripts::Script;
 
     #[test]
@@ -193,6 +369,25 @@ mod tests {
         assert_eq!(info.lang(), Lang::Epo);
     }
 
+    #[test]
+    fn test_detect_with_options_with_frequency_alphabet_mode() {
+        let text = "The weather is nice today";
+        let options = Options::new().set_alphabet_mode(AlphabetMode::Frequency);
+        let info = detect_with_options(text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Eng);
+    }
+
+    #[test]
+    fn test_detect_with_options_with_tolerate_foreign_words() {
+        let text = "Can you tell me where is Schönheitstraße?";
+        let info = detect_with_options(text, &Options::default()).unwrap();
+        assert_eq!(info.lang(), Lang::Deu);
+
+        let options = Options::new().set_tolerate_foreign_words(true);
+        let info = detect_with_options(text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Eng);
+    }
+
     #[test]
     fn test_detect_with_options_with_allowlist_mandarin_japanese() {
         let text = "水";
@@ -218,4 +413,205 @@ mod tests {
         let info = detect_with_options(text, &cmn_opts).unwrap();
         assert_eq!(info.lang(), Lang::Jpn);
     }
+
+    #[test]
+    fn test_detect_with_options_with_sampling() {
+        let deu = "Ich sehe auf die Uhr, es ist kurz vor Mittag. ".repeat(100);
+        let eng = "There is no reason not to learn a new language. ".repeat(300);
+        let text = format!("{deu}{eng}");
+
+        let options = Options::new().set_sampling(Sampling::head(1000));
+        let info = detect_with_options(&text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Deu);
+        let coverage = info.coverage().unwrap();
+        assert_eq!(coverage.analysed_bytes(), 1000);
+        assert_eq!(coverage.total_bytes(), text.len());
+        assert_eq!(coverage.windows(), 1);
+
+        let options = Options::new().set_sampling(Sampling::even_windows(1000, 8));
+        let info = detect_with_options(&text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Eng);
+        assert_eq!(info.coverage().unwrap().windows(), 8);
+
+        // The whole text is analysed if it is short enough
+        let options = Options::new().set_sampling(Sampling::random_windows(100_000, 8, 7));
+        let info = detect_with_options(&text, &options).unwrap();
+        assert_eq!(info.confidence(), detect(&text).unwrap().confidence());
+        assert_eq!(info.coverage().unwrap().ratio(), 1.0);
+        assert_eq!(detect(&text).unwrap().coverage(), None);
+    }
+
+    #[test]
+    fn test_detect_with_options_with_sampling_by_window_votes() {
+        let deu = "Ich sehe auf die Uhr, es ist kurz vor Mittag. ".repeat(100);
+        let eng = "There is no reason not to learn a new language. ".repeat(300);
+        let text = format!("{deu}{eng}");
+
+        let sampling = Sampling::even_windows(1000, 8).set_vote_by_windows(true);
+        let info = detect_with_options(&text, &Options::new().set_sampling(sampling)).unwrap();
+        assert_eq!(info.lang(), Lang::Eng);
+        assert_eq!(info.script(), Script::Latin);
+        // 2 of 8 windows are German
+        assert!(info.confidence() <= 0.75);
+        assert!(info.confidence() > 0.5);
+        assert_eq!(info.coverage().unwrap().windows(), 8);
+
+        let sampling = Sampling::head(10).set_vote_by_windows(true);
+        let options = Options::new().set_sampling(sampling);
+        assert_eq!(detect_with_options("", &options), None);
+        assert_eq!(detect_with_options("1234", &options), None);
+    }
+
+    #[test]
+    fn test_detect_decomposed_vietnamese() {
+        // "tiếng Việt" and "Bạn khỏe không?" with letters and combining marks
+        let texts = [
+            ("tiếng Việt", "tie\u{0302}\u{0301}ng Vie\u{0323}\u{0302}t"),
+            ("Bạn khỏe không?", "Ba\u{0323}n kho\u{0309}e kho\u{0302}ng?"),
+        ];
+        for (precomposed, decomposed) in texts {
+            let info = detect(decomposed).unwrap();
+            assert_eq!(info.lang(), Lang::Vie);
+            assert_eq!(info, detect(precomposed).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_detect_with_options_with_normalization() {
+        let text = "Ｔｈｅｒｅ ｉｓ ｎｏ ｒｅａｓｏｎ ｎｏｔ ｔｏ ｌｅａｒｎ Ｅｓｐｅｒａｎｔｏ";
+        let options = Options::new().set_normalization(Normalization::Nfkc);
+        let info = detect_with_options(text, &options).unwrap();
+        assert_eq!(info.lang(), Lang::Eng);
+        assert_eq!(info.script(), Script::Latin);
+        // Fullwidth letters do not occur in any language profile
+        assert_eq!(detect(text).unwrap().confidence(), 0.0);
+
+        let options = Options::new().set_normalization(Normalization::None);
+        assert_eq!(
+            detect_with_options("tiếng Việt", &options),
+            detect("tiếng Việt")
+        );
+    }
+
+    #[test]
+    fn test_detect_uppercase_turkish_and_azerbaijani() {
+        let texts = [
+            (
+                "BUGÜN HAVA ÇOK SICAK VE GÜNEŞLİ",
+                "Bugün hava çok sıcak ve güneşli",
+                Lang::Tur,
+            ),
+            (
+                "TÜRKİYE CUMHURİYETİ ANAYASASI",
+                "Türkiye Cumhuriyeti Anayasası",
+                Lang::Tur,
+            ),
+            (
+                "AZƏRBAYCAN RESPUBLİKASININ PAYTAXTI BAKI ŞƏHƏRİDİR",
+                "Azərbaycan Respublikasının paytaxtı Bakı şəhəridir",
+                Lang::Aze,
+            ),
+        ];
+        for (uppercase, lowercase, lang) in texts {
+            let info = detect(uppercase).unwrap();
+            assert_eq!(info.lang(), lang);
+            assert_eq!(info, detect(lowercase).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_detect_with_options_with_preprocessor() {
+        let options = Options::new().set_preprocessor(Preprocessor::new());
+        let texts = [
+            "@jsmith Bin gleich da #onmyway #latenight https://twitter.com/status/please-wait",
+            "@the_best_friend_forever Tschüss bis morgen!",
+            "Kommst du auch? @johnny_the_walker_official @mary_and_friends",
+        ];
+        for text in texts {
+            assert_eq!(detect_lang(text), Some(Lang::Eng));
+            assert_eq!(
+                detect_with_options(text, &options).unwrap().lang(),
+                Lang::Deu
+            );
+        }
+
+        // Nothing but removed spans
+        let text = "@anna https://example.com 😍 #party";
+        assert_eq!(detect_with_options(text, &options), None);
+    }
+
+    #[test]
+    fn test_detect_with_options_with_input_mode() {
+        let html = r#"<p>Willkommen auf unserer Seite!</p><script>document.addEventListener("DOMContentLoaded", function () { initializeTheNavigationMenuForTheUser(); });</script>"#;
+        let options = Options::new().set_input_mode(InputMode::Html);
+        assert_eq!(detect_lang(html), Some(Lang::Eng));
+        assert_eq!(
+            detect_with_options(html, &options).unwrap().lang(),
+            Lang::Deu
+        );
+
+        let markdown = "Ejecuta `cargo build --release` y lee [la guía](https://example.com/getting-started/install).";
+        let options = Options::new().set_input_mode(InputMode::Markdown);
+        assert_eq!(detect_lang(markdown), Some(Lang::Eng));
+        assert_eq!(
+            detect_with_options(markdown, &options).unwrap().lang(),
+            Lang::Spa
+        );
+
+        let email = "Vielen Dank, das hat geklappt!\n\
+            \n\
+            Am 03.06.2024 um 10:00 schrieb Anna <anna@example.com>:\n\
+            > Please restart the server and check whether the error still occurs.\n";
+        let options = Options::new().set_input_mode(InputMode::Email);
+        assert_eq!(detect_lang(email), Some(Lang::Eng));
+        assert_eq!(
+            detect_with_options(email, &options).unwrap().lang(),
+            Lang::Deu
+        );
+
+        // Nothing but markup
+        let options = Options::new().set_input_mode(InputMode::Html);
+        assert_eq!(
+            detect_with_options("<script>alert('Hi')</script>", &options),
+            None
+        );
+    }
+
+    #[test]
+    fn test_detect_with_options_with_lang_attributes() {
+        let options = Options::new().set_input_mode(InputMode::Html);
+        let with_prior = options.clone().set_use_lang_attributes(true);
+
+        let html = r#"<html lang="da"><body><p>Tak for hjælpen</p></body></html>"#;
+        assert_eq!(
+            detect_with_options(html, &options).unwrap().lang(),
+            Lang::Nob
+        );
+        assert_eq!(
+            detect_with_options(html, &with_prior).unwrap().lang(),
+            Lang::Dan
+        );
+
+        // Not allowed by the filter list
+        let denied = with_prior
+            .clone()
+            .set_filter_list(FilterList::deny(vec![Lang::Dan]));
+        assert_eq!(
+            detect_with_options(html, &denied).unwrap().lang(),
+            Lang::Nob
+        );
+
+        // Another script
+        let html = r#"<html lang="ru"><body><p>Tak for hjælpen</p></body></html>"#;
+        assert_eq!(
+            detect_with_options(html, &with_prior).unwrap().lang(),
+            Lang::Nob
+        );
+
+        // The detection is reliable
+        let html = r#"<html lang="en"><p>Ich sehe auf die Uhr, es ist kurz vor Mittag und ich habe Hunger.</p></html>"#;
+        let info = detect_with_options(html, &with_prior).unwrap();
+        assert!(info.is_reliable());
+        assert_eq!(info.lang(), Lang::Deu);
+    }
 }
@@ -0,0 +1,92 @@
+use super::{Detector, DetectorScratch};
+use crate::Lang;
+
+/// Extension of iterators over texts, which detects languages of the texts one by one.
+///
+/// # Example
+/// ```
+/// use whatlang::{DetectLangsExt, Detector, Lang};
+///
+/// let detector = Detector::new();
+/// let texts = ["Where is the library?", "Та нічого, все нормально. А в тебе як?", "42"];
+/// let langs: Vec<Option<Lang>> = texts.iter().detect_langs(&detector).collect();
+/// assert_eq!(langs, vec![Some(Lang::Eng), Some(Lang::Ukr), None]);
+/// ```
+pub trait DetectLangsExt: Iterator + Sized
+where
+    Self::Item: AsRef<str>,
+{
+    /// Detect a language of every text with the detector. All the texts share one
+    /// [DetectorScratch], so the detection does not allocate for every text.
+    fn detect_langs(self, detector: &Detector) -> DetectLangs<'_, Self> {
+        DetectLangs {
+            texts: self,
+            detector,
+            scratch: DetectorScratch::new(),
+        }
+    }
+}
+
+impl<I> DetectLangsExt for I
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+}
+
+/// Iterator over languages of texts, see [DetectLangsExt::detect_langs].
+#[derive(Debug)]
+pub struct DetectLangs<'d, I> {
+    texts: I,
+    detector: &'d Detector,
+    scratch: DetectorScratch,
+}
+
+impl<I> Iterator for DetectLangs<'_, I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Option<Lang>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.texts.next()?;
+        let info = self.detector.detect_with(&mut self.scratch, text.as_ref());
+        Some(info.map(|info| info.lang()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.texts.size_hint()
+    }
+}
+
+impl<I> ExactSizeIterator for DetectLangs<'_, I>
+where
+    I: ExactSizeIterator,
+    I::Item: AsRef<str>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_langs() {
+        let detector = Detector::with_allowlist(vec![Lang::Eng, Lang::Deu]);
+        let texts = vec![
+            String::from("Ich sehe auf die Uhr, es ist kurz vor Mittag"),
+            String::from("Where is the library?"),
+            String::new(),
+        ];
+        let langs = texts.iter().detect_langs(&detector);
+        assert_eq!(langs.len(), 3);
+        assert_eq!(
+            langs.collect::<Vec<_>>(),
+            vec![Some(Lang::Deu), Some(Lang::Eng), None]
+        );
+
+        let langs: Vec<_> = texts.into_iter().detect_langs(&detector).collect();
+        assert_eq!(langs[0], Some(Lang::Deu));
+    }
+}
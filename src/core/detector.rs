@@ -1,9 +1,17 @@
 use crate::Lang;
+use crate::comments::{Comment, CommentExtractor};
 use crate::core;
 use crate::core::FilterList;
 use crate::core::Info;
 use crate::core::Options;
+use crate::core::detect::{
+    detect_extracted, detect_prepared_with_options, detect_with_options_and_scratch,
+};
+use crate::core::{DetectorScratch, PreparedText, with_thread_scratch};
+use crate::email::{Email, EmailInfo};
+use crate::error::UnsupportedLangError;
 use crate::scripts::{Script, detect_script};
+use alloc::vec::Vec;
 
 /// Configurable structure that holds detection options and provides functions
 /// to detect language and script.
@@ -39,17 +47,28 @@ impl Detector {
         Self::default()
     }
 
+    /// # Panics
+    ///
+    /// Panics if the allowlist contains a language excluded by cargo features,
+    /// see [Detector::try_with_allowlist].
     pub fn with_allowlist(list: Vec<Lang>) -> Self {
         let opts = Options::new().set_filter_list(FilterList::allow(list));
         Self::with_options(opts)
     }
 
+    /// Same as [Detector::with_allowlist], but returns an error if the allowlist contains
+    /// a language excluded by cargo features (see [Lang::is_supported]), instead of panicking.
+    pub fn try_with_allowlist(list: Vec<Lang>) -> Result<Self, UnsupportedLangError> {
+        let opts = Options::new().set_filter_list(FilterList::try_allow(list)?);
+        Ok(Self::with_options(opts))
+    }
+
     pub fn with_denylist(list: Vec<Lang>) -> Self {
         let opts = Options::new().set_filter_list(FilterList::deny(list));
         Self::with_options(opts)
     }
 
-    fn with_options(options: Options) -> Self {
+    pub fn with_options(options: Options) -> Self {
         Detector { options }
     }
 
@@ -57,6 +76,83 @@ impl Detector {
         core::detect_with_options(text, &self.options)
     }
 
+    /// Same as [Detector::detect], but reuses the buffers of the `scratch`, so repeated calls
+    /// do not allocate. See [DetectorScratch].
+    pub fn detect_with(&self, scratch: &mut DetectorScratch, text: &str) -> Option<Info> {
+        detect_with_options_and_scratch(text, &self.options, scratch)
+    }
+
+    /// Detect a language of every text, in the same order as the texts.
+    /// Buffers are reused between the texts, see [DetectorScratch].
+    pub fn detect_batch<S: AsRef<str>>(&self, texts: &[S]) -> Vec<Option<Info>> {
+        with_thread_scratch(|scratch| {
+            texts
+                .iter()
+                .map(|text| self.detect_with(scratch, text.as_ref()))
+                .collect()
+        })
+    }
+
+    /// Detect a language of every comment and string literal of the source code,
+    /// in order of their position. See [CommentExtractor].
+    pub fn detect_comments(
+        &self,
+        source: &str,
+        extractor: &CommentExtractor,
+    ) -> Vec<(Comment, Option<Info>)> {
+        let comments = extractor.extract(source);
+        with_thread_scratch(|scratch| {
+            comments
+                .into_iter()
+                .map(|comment| {
+                    let info = self.detect_with(scratch, comment.text());
+                    (comment, info)
+                })
+                .collect()
+        })
+    }
+
+    /// Detect a language of the new content written by the author of the e-mail message
+    /// and a language of the quoted history. See [Email].
+    ///
+    /// To detect only the new content use [InputMode::Email](crate::InputMode::Email).
+    pub fn detect_email(&self, message: &str) -> EmailInfo {
+        let email = Email::parse(message);
+        with_thread_scratch(|scratch| EmailInfo {
+            content: detect_extracted(email.content(), &self.options, scratch),
+            quoted: detect_extracted(email.quoted(), &self.options, scratch),
+        })
+    }
+
+    /// Same as [Detector::detect_batch], but the texts are distributed among the threads of
+    /// the [rayon] thread pool. Every thread reuses its own buffers.
+    #[cfg(feature = "rayon")]
+    pub fn par_detect_batch<S: AsRef<str> + Sync>(&self, texts: &[S]) -> Vec<Option<Info>> {
+        use rayon::prelude::*;
+
+        texts
+            .par_iter()
+            .map(|text| self.detect(text.as_ref()))
+            .collect()
+    }
+
+    /// Same as [Detector::detect], but for a text prepared once for any number of detectors.
+    /// See [PreparedText].
+    ///
+    /// The text is analysed as it was prepared, so only the options of the detection itself
+    /// apply: the filter list, the method and the settings of the methods. Options which change
+    /// the text are ignored:
+    /// * [Options::set_sampling]: the whole text is analysed.
+    /// * [Options::set_normalization]: the form of [PreparedText::with_normalization] applies.
+    /// * [Options::set_preprocessor]: the text is not preprocessed.
+    /// * [Options::set_input_mode]: the text is analysed as plain text, so
+    ///   [Options::set_use_lang_attributes] has no effect either.
+    pub fn detect_prepared(&self, prepared: &PreparedText) -> Option<Info> {
+        with_thread_scratch(|scratch| {
+            detect_prepared_with_options(prepared, &self.options, &mut scratch.buffers)
+        })
+    }
+
     pub fn detect_lang(&self, text: &str) -> Option<Lang> {
         core::detect_with_options(text, &self.options).map(|info| info.lang())
     }
@@ -69,6 +165,8 @@ impl Detector {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::comments::Syntax;
+    use crate::core::detect::detect_lang;
 
     #[test]
     fn test_detect_script() {
@@ -97,4 +195,181 @@ mod tests {
         assert_eq!(info.lang(), Lang::Epo);
         assert_eq!(info.script(), Script::Latin);
     }
+
+    #[test]
+    fn test_detect_with() {
+        let texts = [
+            "Ĉiuj redaktantoj de Esperanta Vikipedio estas volontuloj.",
+            "Та нічого, все нормально. А в тебе як?",
+            "水",
+            "Ελληνικά",
+            "",
+            "האקדמיה ללשון העברית",
+            "Can you tell me where is Schönheitstraße?",
+        ];
+        let detectors = [
+            Detector::new(),
+            Detector::with_allowlist(vec![Lang::Eng, Lang::Rus, Lang::Jpn]),
+            Detector::with_options(Options {
+                method: crate::core::Method::Alphabet,
+                ..Options::new()
+            }),
+            Detector::with_options(Options {
+                method: crate::core::Method::Trigram,
+                ..Options::new()
+            }),
+            Detector::with_options(Options::new().set_tolerate_foreign_words(true)),
+        ];
+
+        let mut scratch = DetectorScratch::new();
+        for detector in &detectors {
+            for text in texts {
+                assert_eq!(
+                    detector.detect_with(&mut scratch, text),
+                    detector.detect(text)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect_prepared() {
+        use crate::core::{AlphabetMode, Ensemble, Method};
+        use crate::{AlphabetScorer, TrigramScorer};
+
+        let texts = [
+            "Ĉiuj redaktantoj de Esperanta Vikipedio estas volontuloj.",
+            "Та нічого, все нормально. А в тебе як?",
+            "水",
+            "",
+            "האקדמיה ללשון העברית",
+            "Can you tell me where is Schönheitstraße?",
+        ];
+        let detectors = [
+            Detector::new(),
+            Detector::with_denylist(vec![Lang::Epo, Lang::Ukr]),
+            Detector::with_options(Options {
+                method: Method::Alphabet,
+                ..Options::new()
+            }),
+            Detector::with_options(Options {
+                method: Method::Trigram,
+                ..Options::new()
+            }),
+            Detector::with_options(Options::new().set_alphabet_mode(AlphabetMode::Frequency)),
+            Detector::with_options(Options::new().set_tolerate_foreign_words(true)),
+            Detector::with_options(
+                Options::new().set_ensemble(
+                    Ensemble::new()
+                        .add(AlphabetScorer::default(), 1.0)
+                        .add(TrigramScorer, 2.0),
+                ),
+            ),
+        ];
+
+        for text in texts {
+            let prepared = PreparedText::new(text);
+            assert_eq!(prepared.original(), text);
+            assert_eq!(prepared.script(), detect_script(text));
+            for detector in &detectors {
+                assert_eq!(detector.detect_prepared(&prepared), detector.detect(text));
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect_comments() {
+        let source = r#"
+# Prüft validateUserInput und getConfigValue
+def load(path):
+    """Lädt die Datei: loadConfigurationFile(path), DEFAULT_CONFIG_PATH, config.toml"""
+    # fn parse_config
+    return open(path, encoding="utf-8").read()  # Reads the whole file at once
+"#;
+        let extractor = CommentExtractor::new(Syntax::Python);
+        let detected: Vec<_> = Detector::new()
+            .detect_comments(source, &extractor)
+            .into_iter()
+            .map(|(comment, info)| (comment.line(), info.map(|info| info.lang())))
+            .collect();
+        assert_eq!(
+            detected,
+            vec![
+                (2, Some(Lang::Deu)),
+                (4, Some(Lang::Deu)),
+                (6, Some(Lang::Eng))
+            ]
+        );
+
+        // Without the code the comments are not German
+        assert_ne!(
+            detect_lang("Prüft validateUserInput und getConfigValue"),
+            Some(Lang::Deu)
+        );
+        assert_ne!(
+            detect_lang(
+                "Lädt die Datei: loadConfigurationFile(path), DEFAULT_CONFIG_PATH, config.toml"
+            ),
+            Some(Lang::Deu)
+        );
+    }
+
+    #[test]
+    fn test_detect_email() {
+        let message = "From: bernd@example.com\n\
+            Subject: Re: Delivery\n\
+            \n\
+            Hallo Anna, die Lieferung ist heute angekommen.\n\
+            \n\
+            On Mon, 3 Jun 2024, Anna <anna@example.com> wrote:\n\
+            > Hi Bernd, could you please let me know as soon as the delivery arrives?\n\
+            > We need to schedule the installation for next week.\n\
+            \n\
+            -- \n\
+            Bernd Müller\n";
+        let detected = Detector::new().detect_email(message);
+        assert_eq!(detected.content().map(Info::lang), Some(Lang::Deu));
+        assert_eq!(detected.quoted().map(Info::lang), Some(Lang::Eng));
+        assert_eq!(detect_lang(message), Some(Lang::Eng));
+
+        let detected = Detector::new().detect_email("Hallo, wie geht's?");
+        assert_eq!(detected.content().map(Info::lang), Some(Lang::Deu));
+        assert_eq!(detected.quoted(), None);
+    }
+
+    #[test]
+    fn test_detect_batch() {
+        let texts = [
+            "Ĉiuj redaktantoj de Esperanta Vikipedio estas volontuloj.",
+            "Та нічого, все нормально. А в тебе як?",
+            "水",
+            "",
+            "Can you tell me where is Schönheitstraße?",
+        ];
+        let detector = Detector::with_denylist(vec![Lang::Epo]);
+        let expected: Vec<_> = texts.iter().map(|text| detector.detect(text)).collect();
+        assert_eq!(detector.detect_batch(&texts), expected);
+        assert_eq!(detector.detect_batch::<&str>(&[]), vec![]);
+
+        #[cfg(feature = "rayon")]
+        {
+            let texts: Vec<String> = texts
+                .iter()
+                .cycle()
+                .take(1000)
+                .map(|t| t.to_string())
+                .collect();
+            let expected = detector.detect_batch(&texts);
+            assert_eq!(detector.par_detect_batch(&texts), expected);
+        }
+    }
+
+    #[test]
+    fn test_detector_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Detector>();
+
+        fn assert_send<T: Send>() {}
+        assert_send::<DetectorScratch>();
+    }
 }
@@ -0,0 +1,48 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use super::Scorer;
+
+/// Weighted set of [Scorer]s, which can be used instead of the built-in detection method.
+/// The final score of a language is the weighted average of the scores given by every scorer.
+///
+/// # Example
+/// ```
+/// use whatlang::{AlphabetScorer, Detector, Ensemble, Lang, Options, TrigramScorer};
+///
+/// let ensemble = Ensemble::new()
+///     .add(AlphabetScorer::default(), 1.0)
+///     .add(TrigramScorer, 3.0);
+/// let detector = Detector::with_options(Options::new().set_ensemble(ensemble));
+///
+/// let lang = detector.detect_lang("Ĉu vi ne volas eklerni Esperanton? Bonvolu!");
+/// assert_eq!(lang, Some(Lang::Epo));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Ensemble {
+    members: Vec<(Arc<dyn Scorer>, f64)>,
+}
+
+impl Ensemble {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a scorer with the given weight.
+    ///
+    /// # Panics
+    /// Panics if the weight is negative or not finite.
+    pub fn add<S: Scorer + 'static>(mut self, scorer: S, weight: f64) -> Self {
+        assert!(
+            weight.is_finite() && weight >= 0.0,
+            "Ensemble weight must be a non-negative finite number, got {}",
+            weight
+        );
+        self.members.push((Arc::new(scorer), weight));
+        self
+    }
+
+    pub(crate) fn members(&self) -> &[(Arc<dyn Scorer>, f64)] {
+        &self.members
+    }
+}
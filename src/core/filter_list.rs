@@ -1,4 +1,6 @@
 use crate::Lang;
+use crate::error::UnsupportedLangError;
+use alloc::vec::Vec;
 
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[derive(Debug, Clone, Default)]
@@ -15,8 +17,24 @@ impl FilterList {
         Self::All
     }
 
+    /// # Panics
+    ///
+    /// Panics if the allowlist contains a language excluded by cargo features,
+    /// see [FilterList::try_allow].
     pub fn allow(allowlist: Vec<Lang>) -> Self {
-        Self::Allow(allowlist)
+        match Self::try_allow(allowlist) {
+            Ok(list) => list,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Same as [FilterList::allow], but returns an error if the allowlist contains a language
+    /// excluded by cargo features (see [Lang::is_supported]), instead of panicking.
+    pub fn try_allow(allowlist: Vec<Lang>) -> Result<Self, UnsupportedLangError> {
+        match allowlist.iter().find(|lang| !lang.is_supported()) {
+            Some(&lang) => Err(UnsupportedLangError::new(lang)),
+            None => Ok(Self::Allow(allowlist)),
+        }
     }
 
     pub fn deny(denylist: Vec<Lang>) -> Self {
@@ -54,6 +72,12 @@ mod tests {
         assert!(list.is_allowed(Lang::Ukr));
     }
 
+    #[test]
+    fn test_try_allow() {
+        let list = FilterList::try_allow(vec![Lang::Rus, Lang::Ukr]).unwrap();
+        assert!(list.is_allowed(Lang::Rus));
+    }
+
     #[test]
     fn test_except() {
         let list = FilterList::deny(vec![Lang::Rus, Lang::Ukr]);
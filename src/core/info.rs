@@ -1,3 +1,4 @@
+use super::Coverage;
 use crate::{Lang, Script};
 
 const RELIABLE_CONFIDENCE_THRESHOLD: f64 = 0.9;
@@ -8,6 +9,7 @@ pub struct Info {
     script: Script,
     lang: Lang,
     confidence: f64,
+    coverage: Option<Coverage>,
 }
 
 impl Info {
@@ -16,9 +18,20 @@ impl Info {
             script,
             lang,
             confidence,
+            coverage: None,
         }
     }
 
+    pub(crate) fn with_lang(mut self, lang: Lang) -> Self {
+        self.lang = lang;
+        self
+    }
+
+    pub(crate) fn with_coverage(mut self, coverage: Coverage) -> Self {
+        self.coverage = Some(coverage);
+        self
+    }
+
     pub fn lang(&self) -> Lang {
         self.lang
     }
@@ -34,6 +47,11 @@ impl Info {
     pub fn is_reliable(&self) -> bool {
         self.confidence > RELIABLE_CONFIDENCE_THRESHOLD
     }
+
+    /// How much of the text was analysed, if [Sampling](crate::Sampling) is enabled.
+    pub fn coverage(&self) -> Option<Coverage> {
+        self.coverage
+    }
 }
 
 #[cfg(test)]
@@ -46,6 +64,7 @@ mod tests {
             script: Script::Greek,
             lang: Lang::Ell,
             confidence: 0.0,
+            coverage: None,
         };
         assert_eq!(info.is_reliable(), false);
 
@@ -1,19 +1,41 @@
+mod alphabet_mode;
+mod char_counts;
 mod confidence;
 pub(crate) mod detect;
+mod detect_langs;
 mod detector;
+mod ensemble;
 mod filter_list;
 mod info;
 mod method;
 mod options;
+mod prepared_text;
+mod preprocessor;
 mod query;
+mod sampling;
+mod scorer;
+mod scratch;
 mod text;
+mod weight_curve;
 
+pub use alphabet_mode::AlphabetMode;
+pub(crate) use char_counts::CharCounts;
 pub use confidence::calculate_confidence;
 pub use detect::{detect, detect_lang, detect_with_options};
+pub use detect_langs::{DetectLangs, DetectLangsExt};
 pub use detector::Detector;
+pub use ensemble::Ensemble;
 pub use filter_list::FilterList;
 pub use info::Info;
 pub use method::Method;
 pub use options::Options;
+pub use prepared_text::PreparedText;
+pub use preprocessor::{Preprocessed, Preprocessor, RemovedSpan, SpanKind};
 pub use query::{InternalQuery, Query};
+pub use sampling::{Coverage, Sampling};
+pub use scorer::{Scorer, Scores};
+pub use scratch::DetectorScratch;
+pub(crate) use scratch::{Buffers, with_thread_scratch};
+pub(crate) use text::TextBuffers;
 pub use text::{LowercaseText, Text};
+pub use weight_curve::WeightCurve;
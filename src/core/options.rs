@@ -1,10 +1,25 @@
-use super::{FilterList, Method};
+use super::{AlphabetMode, Ensemble, FilterList, Method, Preprocessor, Sampling, WeightCurve};
+use crate::markup::InputMode;
+use crate::normalization::Normalization;
 
 #[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
 #[derive(Debug, Clone)]
 pub struct Options {
     pub(crate) filter_list: FilterList,
     pub(crate) method: Method,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub(crate) ensemble: Option<Ensemble>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub(crate) alphabet_weight_curve: WeightCurve,
+    pub(crate) alphabet_mode: AlphabetMode,
+    pub(crate) tolerate_foreign_words: bool,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub(crate) sampling: Option<Sampling>,
+    pub(crate) normalization: Normalization,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    pub(crate) preprocessor: Option<Preprocessor>,
+    pub(crate) input_mode: InputMode,
+    pub(crate) use_lang_attributes: bool,
 }
 
 impl Options {
@@ -12,6 +27,15 @@ impl Options {
         Self {
             filter_list: FilterList::All,
             method: Method::Combined,
+            ensemble: None,
+            alphabet_weight_curve: WeightCurve::default(),
+            alphabet_mode: AlphabetMode::default(),
+            tolerate_foreign_words: false,
+            sampling: None,
+            normalization: Normalization::default(),
+            preprocessor: None,
+            input_mode: InputMode::default(),
+            use_lang_attributes: false,
         }
     }
 
@@ -35,6 +59,79 @@ impl Options {
         self.method = method;
         self
     }
+
+    /// Set the weight of the alphabet method within the combined method.
+    /// See [WeightCurve] for details.
+    pub fn set_alphabet_weight_curve(mut self, curve: WeightCurve) -> Self {
+        self.alphabet_weight_curve = curve;
+        self
+    }
+
+    /// Set the variant of the alphabet method, it's also used within the combined method.
+    pub fn set_alphabet_mode(mut self, mode: AlphabetMode) -> Self {
+        self.alphabet_mode = mode;
+        self
+    }
+
+    /// Make the alphabet method tolerant to foreign words, e.g. proper names and loanwords.
+    /// When enabled, a single word can not outweigh the rest of the text with characters which
+    /// are untypical for the language (e.g. `Schönheitstraße` in an English sentence), and
+    /// capitalized words in the middle of a sentence are considered as likely proper names
+    /// and get less weight.
+    pub fn set_tolerate_foreign_words(mut self, tolerate: bool) -> Self {
+        self.tolerate_foreign_words = tolerate;
+        self
+    }
+
+    /// Use the given ensemble of scorers instead of the built-in detection method
+    /// for scripts that are shared by multiple languages.
+    pub fn set_ensemble(mut self, ensemble: Ensemble) -> Self {
+        self.ensemble = Some(ensemble);
+        self
+    }
+
+    /// Analyse only a sample of very large texts, see [Sampling] for details.
+    /// It does not apply to a [PreparedText](crate::PreparedText), which is prepared entirely.
+    pub fn set_sampling(mut self, sampling: Sampling) -> Self {
+        self.sampling = Some(sampling);
+        self
+    }
+
+    /// Set the Unicode normalization form the text is brought to before detection,
+    /// NFC by default. See [Normalization] for details.
+    /// It does not apply to a [PreparedText](crate::PreparedText), which is normalized
+    /// on its own, see [PreparedText::with_normalization](crate::PreparedText::with_normalization).
+    pub fn set_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Remove URLs, mentions, emoji, etc. of social media texts before detection,
+    /// see [Preprocessor] for details. Sampling, if any, applies to the preprocessed text.
+    /// It does not apply to a [PreparedText](crate::PreparedText).
+    pub fn set_preprocessor(mut self, preprocessor: Preprocessor) -> Self {
+        self.preprocessor = Some(preprocessor);
+        self
+    }
+
+    /// Set the format of the texts, plain texts by default. In the HTML and Markdown modes
+    /// only the natural-language text of a document is detected, see [InputMode] for details.
+    /// The preprocessor and sampling, if any, apply to the extracted text.
+    /// It does not apply to a [PreparedText](crate::PreparedText).
+    pub fn set_input_mode(mut self, input_mode: InputMode) -> Self {
+        self.input_mode = input_mode;
+        self
+    }
+
+    /// Use the language declared by the `lang` attributes of an HTML document
+    /// (e.g. `<html lang="de">`) as a prior, see [InputMode::Html].
+    /// The declared language is returned instead of the detected one, unless the detection is
+    /// reliable (see [Info::is_reliable](crate::Info::is_reliable)). The declared language has to
+    /// be written in the detected script and be allowed by the filter list.
+    pub fn set_use_lang_attributes(mut self, use_lang_attributes: bool) -> Self {
+        self.use_lang_attributes = use_lang_attributes;
+        self
+    }
 }
 
 impl Default for Options {
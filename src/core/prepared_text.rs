@@ -0,0 +1,64 @@
+use super::Text;
+use crate::normalization::Normalization;
+use crate::scripts::grouping::ScriptLangGroup;
+use crate::scripts::{RawScriptInfo, Script, raw_detect_script_into};
+
+/// A text prepared for detection, see [Detector::detect_prepared](crate::Detector::detect_prepared).
+///
+/// Preparation normalizes the text, counts its scripts, lowercases it, counts its characters and
+/// ranks its trigrams. All of it does not depend on detection options, so it's done only once,
+/// no matter how many detectors (e.g. with different allowlists or methods) process the text
+/// afterwards. The normalization form is set by [PreparedText::with_normalization] instead,
+/// the one of the detector's [Options](crate::Options) does not apply.
+///
+/// # Example
+/// ```
+/// use whatlang::{Detector, Lang, PreparedText};
+///
+/// let prepared = PreparedText::new("Tämä on suomenkielinen lause");
+///
+/// let detector = Detector::with_allowlist(vec![Lang::Fin, Lang::Est]);
+/// assert_eq!(detector.detect_prepared(&prepared).unwrap().lang(), Lang::Fin);
+///
+/// let detector = Detector::with_denylist(vec![Lang::Fin]);
+/// assert_ne!(detector.detect_prepared(&prepared).unwrap().lang(), Lang::Fin);
+/// ```
+#[derive(Debug)]
+pub struct PreparedText<'a> {
+    pub(crate) script_info: RawScriptInfo,
+    pub(crate) text: Text<'a>,
+}
+
+impl<'a> PreparedText<'a> {
+    /// Prepare the text normalized to NFC (see [Normalization::Nfc]).
+    pub fn new(text: &'a str) -> Self {
+        Self::with_normalization(text, Normalization::default())
+    }
+
+    pub fn with_normalization(text: &'a str, normalization: Normalization) -> Self {
+        let text = Text::with_normalization(text, normalization);
+        let mut script_info = RawScriptInfo::default();
+        raw_detect_script_into(text.normalized(), &mut script_info);
+
+        // Languages of other scripts are detected by script counts only
+        let is_multi_lang_script = script_info
+            .main_script()
+            .is_some_and(|script| matches!(script.to_lang_group(), ScriptLangGroup::Multi(_)));
+        if is_multi_lang_script {
+            text.lowercase();
+            text.char_counts();
+            text.trigrams();
+        }
+
+        Self { script_info, text }
+    }
+
+    pub fn original(&self) -> &'a str {
+        self.text.original()
+    }
+
+    /// The main script of the text, the same as [detect_script](crate::detect_script) returns.
+    pub fn script(&self) -> Option<Script> {
+        self.script_info.main_script()
+    }
+}
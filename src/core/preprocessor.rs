@@ -0,0 +1,552 @@
+use crate::utils::is_stop_char;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Removes parts of social media texts and chats, which say nothing about the language of the text:
+/// URLs, e-mail addresses, @mentions, #hashtags and emoji. Elongated words (`sooooo`) and repeated
+/// punctuation (`!!!`) are shortened. See [Options::set_preprocessor](crate::Options::set_preprocessor).
+///
+/// Only runs of at least 4 identical letters and 3 punctuation marks are shortened, since shorter
+/// ones occur in correct spelling (`Schifffahrt`, `XIII`, `C++`): runs of 3 letters (e.g. `sooo`)
+/// and pairs of marks (e.g. `!!`) are left as they are.
+///
+/// Removed parts are replaced by a space, so the words around them are not glued together.
+/// All kinds of spans are handled by default, every kind can be turned off.
+///
+/// # Example
+/// ```
+/// use whatlang::{Detector, Lang, Options, Preprocessor, SpanKind};
+///
+/// let text = "@jsmith Bin gleich da!!! 🚗 #onmyway #latenight https://example.com/please-wait";
+///
+/// let preprocessed = Preprocessor::new().preprocess(text);
+/// let words: Vec<&str> = preprocessed.text().split_whitespace().collect();
+/// assert_eq!(words, vec!["Bin", "gleich", "da!"]);
+/// let kinds: Vec<SpanKind> = preprocessed.removed().iter().map(|span| span.kind()).collect();
+/// assert_eq!(
+///     kinds,
+///     vec![
+///         SpanKind::Mention,
+///         SpanKind::RepeatedPunctuation,
+///         SpanKind::Emoji,
+///         SpanKind::Hashtag,
+///         SpanKind::Hashtag,
+///         SpanKind::Url,
+///     ]
+/// );
+///
+/// let detector = Detector::with_options(Options::new().set_preprocessor(Preprocessor::new()));
+/// assert_eq!(detector.detect_lang(text), Some(Lang::Deu));
+/// assert_eq!(Detector::new().detect_lang(text), Some(Lang::Eng));
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Preprocessor {
+    urls: bool,
+    emails: bool,
+    mentions: bool,
+    hashtags: bool,
+    emoji: bool,
+    elongations: bool,
+    repeated_punctuation: bool,
+}
+
+/// Kind of a span removed by a [Preprocessor].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanKind {
+    /// A URL starting with a scheme (e.g. `https://`) or `www.`.
+    Url,
+    /// An e-mail address.
+    Email,
+    /// A mention of a user, e.g. `@anna`.
+    Mention,
+    /// A hashtag, e.g. `#weekend`. Hashtags starting with a digit only (e.g. `#1`) are kept.
+    Hashtag,
+    /// A sequence of emoji, including modifiers and joiners.
+    Emoji,
+    /// Repetitions of a letter beyond the second one, e.g. `ooo` of `sooooo`. Only runs of at least
+    /// 4 letters are shortened, runs of 3 occur in words, e.g. `Schifffahrt` or `XIII`.
+    Elongation,
+    /// Repetitions of a punctuation mark beyond the first one, e.g. `!!` of `!!!`. Only runs of at
+    /// least 3 marks are shortened, pairs are kept, e.g. `C++` or `--`.
+    /// Mixed `!` and `?` (e.g. `?!?!`) count as repetitions too.
+    RepeatedPunctuation,
+}
+
+/// A span of the original text removed by a [Preprocessor].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemovedSpan {
+    kind: SpanKind,
+    range: Range<usize>,
+}
+
+impl RemovedSpan {
+    pub fn kind(&self) -> SpanKind {
+        self.kind
+    }
+
+    /// Byte range of the span in the original text.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+}
+
+/// Outcome of [Preprocessor::preprocess].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preprocessed {
+    text: String,
+    removed: Vec<RemovedSpan>,
+}
+
+impl Preprocessed {
+    /// The text which is passed on to detection.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Removed spans of the original text, in order of their position.
+    pub fn removed(&self) -> &[RemovedSpan] {
+        &self.removed
+    }
+}
+
+impl Preprocessor {
+    pub fn new() -> Self {
+        Self {
+            urls: true,
+            emails: true,
+            mentions: true,
+            hashtags: true,
+            emoji: true,
+            elongations: true,
+            repeated_punctuation: true,
+        }
+    }
+
+    pub fn set_urls(mut self, enabled: bool) -> Self {
+        self.urls = enabled;
+        self
+    }
+
+    pub fn set_emails(mut self, enabled: bool) -> Self {
+        self.emails = enabled;
+        self
+    }
+
+    pub fn set_mentions(mut self, enabled: bool) -> Self {
+        self.mentions = enabled;
+        self
+    }
+
+    /// Hashtags often use English words, even in texts in other languages.
+    pub fn set_hashtags(mut self, enabled: bool) -> Self {
+        self.hashtags = enabled;
+        self
+    }
+
+    pub fn set_emoji(mut self, enabled: bool) -> Self {
+        self.emoji = enabled;
+        self
+    }
+
+    pub fn set_elongations(mut self, enabled: bool) -> Self {
+        self.elongations = enabled;
+        self
+    }
+
+    pub fn set_repeated_punctuation(mut self, enabled: bool) -> Self {
+        self.repeated_punctuation = enabled;
+        self
+    }
+
+    pub fn preprocess(&self, text: &str) -> Preprocessed {
+        let mut preprocessed = Preprocessed {
+            text: String::with_capacity(text.len()),
+            removed: Vec::new(),
+        };
+        self.preprocess_into(text, &mut preprocessed.text, |span| {
+            preprocessed.removed.push(span)
+        });
+        preprocessed
+    }
+
+    /// Same as [Preprocessor::preprocess], but reusing the output buffer, which is cleared first.
+    /// Removed spans are passed to `on_removed`.
+    pub(crate) fn preprocess_into(
+        &self,
+        text: &str,
+        out: &mut String,
+        on_removed: impl FnMut(RemovedSpan),
+    ) {
+        out.clear();
+        let mut writer = Writer {
+            text,
+            out,
+            copied: 0,
+            on_removed,
+        };
+
+        for token in tokens(text) {
+            let core = trim_token(text, token.clone());
+            match self.classify(&text[core.clone()]) {
+                Some((kind, len)) => {
+                    let span = core.start..core.start + len;
+                    self.shorten(&mut writer, token.start..span.start);
+                    writer.remove(kind, span.clone(), " ");
+                    self.shorten(&mut writer, span.end..token.end);
+                }
+                None => self.shorten(&mut writer, token),
+            }
+        }
+        writer.finish();
+    }
+
+    // Kind and length of the span at the beginning of the trimmed token, if it's removed entirely
+    // (URL or e-mail) or partially (the name of a mention or a hashtag, e.g. `@anna` of `@anna's`).
+    fn classify(&self, core: &str) -> Option<(SpanKind, usize)> {
+        if self.urls && is_url(core) {
+            return Some((SpanKind::Url, core.len()));
+        }
+        if self.emails && is_email(core) {
+            return Some((SpanKind::Email, core.len()));
+        }
+        let mut chars = core.chars();
+        let (kind, is_enabled) = match chars.next()? {
+            '@' => (SpanKind::Mention, self.mentions),
+            '#' => (SpanKind::Hashtag, self.hashtags),
+            _ => return None,
+        };
+        let name_len = chars
+            .take_while(|&ch| is_word_char(ch))
+            .map(char::len_utf8)
+            .sum::<usize>();
+        let name = &core[1..1 + name_len];
+        let is_valid = match kind {
+            SpanKind::Hashtag => name.chars().any(char::is_alphabetic),
+            _ => !name.is_empty(),
+        };
+        (is_enabled && is_valid).then_some((kind, 1 + name_len))
+    }
+
+    // Remove emoji, elongations and repeated punctuation within the range.
+    fn shorten<F: FnMut(RemovedSpan)>(&self, writer: &mut Writer<'_, '_, F>, range: Range<usize>) {
+        let text = writer.text;
+        let mut position = range.start;
+        while position < range.end {
+            let ch = text[position..].chars().next().unwrap_or_default();
+            let next = position + ch.len_utf8();
+
+            if self.emoji && is_emoji(ch) {
+                let end = run_end(text, next, range.end, |c| {
+                    is_emoji(c) || is_emoji_component(c)
+                });
+                writer.remove(SpanKind::Emoji, position..end, " ");
+                position = end;
+            } else if self.elongations && ch.is_alphabetic() {
+                let end = run_end(text, next, range.end, |c| is_same_letter(c, ch));
+                // The first two letters of a run of at least 4 letters are kept
+                let mut repetitions = text[next..end].char_indices();
+                if let (Some(_), Some((third, _)), Some(_)) =
+                    (repetitions.next(), repetitions.next(), repetitions.next())
+                {
+                    writer.remove(SpanKind::Elongation, next + third..end, "");
+                }
+                position = end;
+            } else if self.repeated_punctuation && is_punctuation(ch) {
+                let end = run_end(text, next, range.end, |c| is_repetition_of(c, ch));
+                // The first mark of a run of at least 3 marks is kept
+                if text[next..end].chars().nth(1).is_some() {
+                    writer.remove(SpanKind::RepeatedPunctuation, next..end, "");
+                }
+                position = end;
+            } else {
+                position = next;
+            }
+        }
+    }
+}
+
+impl Default for Preprocessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Copies the text to the output, except the removed spans.
+struct Writer<'t, 'o, F> {
+    text: &'t str,
+    out: &'o mut String,
+    // The text is copied up to this position
+    copied: usize,
+    on_removed: F,
+}
+
+impl<F: FnMut(RemovedSpan)> Writer<'_, '_, F> {
+    fn remove(&mut self, kind: SpanKind, range: Range<usize>, replacement: &str) {
+        self.out.push_str(&self.text[self.copied..range.start]);
+        self.out.push_str(replacement);
+        self.copied = range.end;
+        (self.on_removed)(RemovedSpan { kind, range });
+    }
+
+    fn finish(self) {
+        self.out.push_str(&self.text[self.copied..]);
+    }
+}
+
+// Byte ranges of whitespace separated tokens.
+fn tokens(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    text.split(char::is_whitespace)
+        .filter(|token| !token.is_empty())
+        .map(move |token| {
+            let start = token.as_ptr() as usize - text.as_ptr() as usize;
+            start..start + token.len()
+        })
+}
+
+// Trim the punctuation, which usually surrounds a word, e.g. brackets and quotes.
+fn trim_token(text: &str, token: Range<usize>) -> Range<usize> {
+    let core = text[token.clone()]
+        .trim_start_matches([
+            '(', '[', '{', '<', '"', '\'', '«', '‹', '“', '‘', '„', '¿', '¡',
+        ])
+        .trim_end_matches([
+            '.', ',', ':', ';', '!', '?', ')', ']', '}', '>', '"', '\'', '»', '›', '”', '’', '…',
+        ]);
+    let start = core.as_ptr() as usize - text.as_ptr() as usize;
+    start..start + core.len()
+}
+
+// End of the run of chars matching the predicate, which starts at `start`.
+fn run_end(text: &str, start: usize, end: usize, predicate: impl Fn(char) -> bool) -> usize {
+    let run_len: usize = text[start..end]
+        .chars()
+        .take_while(|&ch| predicate(ch))
+        .map(char::len_utf8)
+        .sum();
+    start + run_len
+}
+
+fn is_url(word: &str) -> bool {
+    let has_prefix = |prefix: &str| {
+        word.get(..prefix.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+    };
+    let prefix_len = ["http://", "https://", "ftp://", "www."]
+        .into_iter()
+        .find(|prefix| has_prefix(prefix))
+        .map_or(0, str::len);
+    prefix_len > 0 && word.len() > prefix_len
+}
+
+fn is_email(word: &str) -> bool {
+    let Some((local, domain)) = word.split_once('@') else {
+        return false;
+    };
+    let is_local_char =
+        |ch: char| ch.is_alphanumeric() || matches!(ch, '.' | '_' | '%' | '+' | '-');
+    let is_label = |label: &str| {
+        !label.is_empty() && label.chars().all(|ch| ch.is_alphanumeric() || ch == '-')
+    };
+    !local.is_empty()
+        && local.chars().all(is_local_char)
+        && domain.contains('.')
+        && domain.split('.').all(is_label)
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+// Pictographs, dingbats and regional indicators. It's an approximation of
+// the Extended_Pictographic property of Unicode, which covers most of the emoji in use.
+fn is_emoji(ch: char) -> bool {
+    matches!(ch as u32, 0x2300..=0x23FF | 0x2600..=0x27BF | 0x2B00..=0x2BFF | 0x1F000..=0x1FAFF)
+}
+
+// Zero width joiner, variation selectors and tags, which combine emoji into sequences.
+// Skin tone modifiers are covered by is_emoji().
+fn is_emoji_component(ch: char) -> bool {
+    matches!(
+        ch as u32,
+        0x200D | 0xFE0E | 0xFE0F | 0x20E3 | 0xE0020..=0xE007F
+    )
+}
+
+fn is_same_letter(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+fn is_punctuation(ch: char) -> bool {
+    is_stop_char(ch) && !ch.is_whitespace() && !ch.is_numeric() && !is_emoji(ch)
+}
+
+fn is_repetition_of(ch: char, first: char) -> bool {
+    ch == first || (matches!(first, '!' | '?') && matches!(ch, '!' | '?'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn removed(text: &str, preprocessor: Preprocessor) -> Vec<(SpanKind, &str)> {
+        preprocessor
+            .preprocess(text)
+            .removed()
+            .iter()
+            .map(|span| (span.kind(), &text[span.range()]))
+            .collect()
+    }
+
+    #[test]
+    fn test_urls_and_emails() {
+        let text =
+            "Schreib an anna.k@example.de oder (https://example.de/kontakt?x=1), www.example.de.";
+        let preprocessed = Preprocessor::new().preprocess(text);
+        assert_eq!(preprocessed.text(), "Schreib an   oder ( ),  .");
+        assert_eq!(
+            removed(text, Preprocessor::new()),
+            vec![
+                (SpanKind::Email, "anna.k@example.de"),
+                (SpanKind::Url, "https://example.de/kontakt?x=1"),
+                (SpanKind::Url, "www.example.de"),
+            ]
+        );
+
+        // Not a URL or an e-mail address
+        for text in [
+            "http://",
+            "https:",
+            "a@b",
+            "@example.com",
+            "a@.com",
+            "a@b..c",
+        ] {
+            assert!(
+                removed(
+                    text,
+                    Preprocessor::new()
+                        .set_mentions(false)
+                        .set_repeated_punctuation(false)
+                )
+                .is_empty(),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_mentions_and_hashtags() {
+        let text = "@anna's #Urlaub2024: #1 @ # @_";
+        assert_eq!(
+            Preprocessor::new().preprocess(text).text(),
+            " 's  : #1 @ #  "
+        );
+        assert_eq!(
+            removed(text, Preprocessor::new()),
+            vec![
+                (SpanKind::Mention, "@anna"),
+                (SpanKind::Hashtag, "#Urlaub2024"),
+                (SpanKind::Mention, "@_"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_emoji() {
+        let text = "Super😍👍🏽 Party 👨‍👩‍👧 ❤️ 🇩🇪!";
+        assert_eq!(
+            Preprocessor::new().preprocess(text).text(),
+            "Super  Party      !"
+        );
+        assert_eq!(
+            removed(text, Preprocessor::new()),
+            vec![
+                (SpanKind::Emoji, "😍👍🏽"),
+                (SpanKind::Emoji, "👨‍👩‍👧"),
+                (SpanKind::Emoji, "❤️"),
+                (SpanKind::Emoji, "🇩🇪"),
+            ]
+        );
+
+        // Joiners of other scripts are kept
+        let sinhala = "ශ්‍රී ලංකා";
+        assert_eq!(Preprocessor::new().preprocess(sinhala).text(), sinhala);
+    }
+
+    #[test]
+    fn test_elongations_and_repeated_punctuation() {
+        let text = "Sooooo cooool... Wirklich?!?! Schifffahrt -- 1000!";
+        assert_eq!(
+            Preprocessor::new().preprocess(text).text(),
+            "Soo cool. Wirklich? Schifffahrt -- 1000!"
+        );
+        assert_eq!(
+            removed(text, Preprocessor::new()),
+            vec![
+                (SpanKind::Elongation, "ooo"),
+                (SpanKind::Elongation, "oo"),
+                (SpanKind::RepeatedPunctuation, ".."),
+                (SpanKind::RepeatedPunctuation, "!?!"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_words_with_triple_letters_and_paired_marks_are_kept() {
+        for text in [
+            "Schifffahrt",
+            "Brennnessel",
+            "Kaffeeersatz",
+            "Ludwig XIII",
+            "C++ und C#",
+            "Ja -- nein",
+            "Nein!!",
+        ] {
+            let preprocessed = Preprocessor::new().preprocess(text);
+            assert_eq!(preprocessed.text(), text);
+            assert!(preprocessed.removed().is_empty());
+        }
+        assert_eq!(
+            removed("Schiffffahrt C+++", Preprocessor::new()),
+            vec![
+                (SpanKind::Elongation, "ff"),
+                (SpanKind::RepeatedPunctuation, "++"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_disabled_kinds_are_kept() {
+        let text = "@anna #party soooo!!! 😍 https://example.com a@example.com";
+        let preprocessor = Preprocessor::new()
+            .set_urls(false)
+            .set_emails(false)
+            .set_mentions(false)
+            .set_hashtags(false)
+            .set_emoji(false)
+            .set_elongations(false)
+            .set_repeated_punctuation(false);
+        let preprocessed = preprocessor.preprocess(text);
+        assert_eq!(preprocessed.text(), text);
+        assert!(preprocessed.removed().is_empty());
+
+        let preprocessor = Preprocessor::new().set_hashtags(false).set_emoji(false);
+        assert_eq!(preprocessor.preprocess(text).text(), "  #party soo! 😍    ");
+    }
+
+    #[test]
+    fn test_text_without_removed_spans() {
+        for text in [
+            "",
+            " ",
+            "Hello world",
+            "Та нічого, все нормально. А в тебе як?",
+        ] {
+            let preprocessed = Preprocessor::new().preprocess(text);
+            assert_eq!(preprocessed.text(), text);
+            assert!(preprocessed.removed().is_empty());
+        }
+    }
+}
@@ -1,26 +1,44 @@
-use super::{FilterList, Method, Text};
+use super::{AlphabetMode, Ensemble, FilterList, Method, Text, WeightCurve};
+use crate::normalization::Normalization;
 use crate::scripts::grouping::MultiLangScript;
 
 pub struct Query<'a, 'b> {
     pub(crate) text: &'a str,
     pub(crate) filter_list: &'b FilterList,
     pub(crate) method: Method,
+    pub(crate) ensemble: Option<&'b Ensemble>,
+    pub(crate) alphabet_weight_curve: WeightCurve,
+    pub(crate) alphabet_mode: AlphabetMode,
+    pub(crate) tolerate_foreign_words: bool,
+    pub(crate) normalization: Normalization,
 }
 
 // TODO: find a better name?
 // A query after script detection
 pub struct InternalQuery<'a, 'b> {
-    pub(crate) text: Text<'a>,
+    pub(crate) text: &'b Text<'a>,
     pub(crate) filter_list: &'b FilterList,
     pub(crate) multi_lang_script: MultiLangScript,
+    pub(crate) alphabet_weight_curve: WeightCurve,
+    pub(crate) alphabet_mode: AlphabetMode,
+    pub(crate) tolerate_foreign_words: bool,
 }
 
 impl<'a, 'b> Query<'a, 'b> {
-    pub(crate) fn to_internal(&self, multi_lang_script: MultiLangScript) -> InternalQuery<'a, 'b> {
+    // The text must be built from the text of the query.
+    pub(crate) fn to_internal(
+        &self,
+        multi_lang_script: MultiLangScript,
+        text: &'b Text<'a>,
+    ) -> InternalQuery<'a, 'b> {
+        debug_assert_eq!(text.original(), self.text);
         InternalQuery {
-            text: Text::new(self.text),
+            text,
             filter_list: self.filter_list,
             multi_lang_script,
+            alphabet_weight_curve: self.alphabet_weight_curve,
+            alphabet_mode: self.alphabet_mode,
+            tolerate_foreign_words: self.tolerate_foreign_words,
         }
     }
 }
@@ -0,0 +1,328 @@
+/// Bounds the cost of detection for very large texts: only a sample of the text is analysed.
+/// See [Options::set_sampling](crate::Options::set_sampling).
+///
+/// A sample takes at most `max_chars` characters of the text, either from the beginning of the
+/// text, or from windows spread over the whole text. Texts, which are not longer than `max_chars`,
+/// are analysed entirely.
+///
+/// By default the windows are analysed as a single text. Alternatively every window can be
+/// analysed on its own and give a vote for its language, see [Sampling::set_vote_by_windows].
+///
+/// How much of the text was analysed is reported by [Info::coverage](crate::Info::coverage).
+///
+/// # Example
+/// ```
+/// use whatlang::{Detector, Lang, Options, Sampling};
+///
+/// let text = "Ich sehe auf die Uhr, es ist kurz vor Mittag. ".repeat(10_000);
+///
+/// let sampling = Sampling::even_windows(2_000, 8);
+/// let detector = Detector::with_options(Options::new().set_sampling(sampling));
+/// let info = detector.detect(&text).unwrap();
+/// assert_eq!(info.lang(), Lang::Deu);
+///
+/// let coverage = info.coverage().unwrap();
+/// assert_eq!(coverage.windows(), 8);
+/// assert!(coverage.analysed_bytes() <= 2_000 * 2);
+/// assert_eq!(coverage.total_bytes(), text.len());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sampling {
+    max_chars: usize,
+    strategy: Strategy,
+    vote_by_windows: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Strategy {
+    Head,
+    EvenWindows { windows: usize },
+    RandomWindows { windows: usize, seed: u64 },
+}
+
+impl Sampling {
+    /// The first `max_chars` characters of the text.
+    ///
+    /// # Panics
+    /// Panics if `max_chars` is 0.
+    pub fn head(max_chars: usize) -> Self {
+        Self::new(max_chars, Strategy::Head)
+    }
+
+    /// `windows` windows of `max_chars / windows` characters each, evenly spaced over the text.
+    ///
+    /// # Panics
+    /// Panics if `windows` is 0 or greater than `max_chars`.
+    pub fn even_windows(max_chars: usize, windows: usize) -> Self {
+        Self::new(max_chars, Strategy::EvenWindows { windows })
+    }
+
+    /// Same as [Sampling::even_windows], but every window starts at a random position within its
+    /// part of the text. The same seed always selects the same windows of the same text.
+    ///
+    /// # Panics
+    /// Panics if `windows` is 0 or greater than `max_chars`.
+    pub fn random_windows(max_chars: usize, windows: usize, seed: u64) -> Self {
+        Self::new(max_chars, Strategy::RandomWindows { windows, seed })
+    }
+
+    fn new(max_chars: usize, strategy: Strategy) -> Self {
+        assert!(max_chars > 0, "max_chars must be positive");
+        if let Strategy::EvenWindows { windows } | Strategy::RandomWindows { windows, .. } =
+            strategy
+        {
+            assert!(
+                windows > 0 && windows <= max_chars,
+                "Sampling requires 0 < windows <= max_chars, got windows={}, max_chars={}",
+                windows,
+                max_chars
+            );
+        }
+        Self {
+            max_chars,
+            strategy,
+            vote_by_windows: false,
+        }
+    }
+
+    /// Detect a language of every window on its own, and pick the language with the most votes.
+    /// Votes are weighted by the number of characters in the window and the confidence of the
+    /// window. The confidence of the outcome is the weighted share of the votes for the language.
+    pub fn set_vote_by_windows(mut self, vote: bool) -> Self {
+        self.vote_by_windows = vote;
+        self
+    }
+
+    pub(crate) fn votes_by_windows(&self) -> bool {
+        self.vote_by_windows
+    }
+
+    /// Windows of the text to analyse, in the order of the text.
+    pub(crate) fn windows<'a>(&self, text: &'a str) -> impl Iterator<Item = &'a str> {
+        // A text can not have more characters than bytes, so most texts are not counted
+        let is_short =
+            text.len() <= self.max_chars || text.char_indices().nth(self.max_chars).is_none();
+        let (windows, seed) = match self.strategy {
+            _ if is_short => (0, None),
+            Strategy::Head => (0, None),
+            Strategy::EvenWindows { windows } => (windows, None),
+            Strategy::RandomWindows { windows, seed } => (windows, Some(seed)),
+        };
+        let head = if windows == 0 {
+            Some(take_chars(text, self.max_chars))
+        } else {
+            None
+        };
+
+        let window_chars = self.max_chars / windows.max(1);
+        let part_len = text.len() / windows.max(1);
+        let mut rng = seed.map(SplitMix64);
+        let windows = (0..windows).map(move |i| {
+            let part_start = i * part_len;
+            let part_end = if i + 1 == windows {
+                text.len()
+            } else {
+                part_start + part_len
+            };
+            let start = match rng.as_mut() {
+                // A window takes at least `window_chars` bytes
+                Some(rng) => {
+                    let latest_start = part_end.saturating_sub(window_chars).max(part_start);
+                    part_start + (rng.next() % (latest_start - part_start + 1) as u64) as usize
+                }
+                None => part_start,
+            };
+            let start = ceil_char_boundary(text, start);
+            let end = ceil_char_boundary(text, part_end);
+            take_chars(&text[start..end.max(start)], window_chars)
+        });
+
+        head.into_iter().chain(windows)
+    }
+}
+
+/// How much of a text was analysed, see [Sampling].
+///
+/// Sizes are reported in bytes of UTF-8, as [str::len] does, not in characters as `max_chars`
+/// of [Sampling] is: e.g. a sample of 2000 Cyrillic characters takes about 4000 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Coverage {
+    analysed_bytes: usize,
+    total_bytes: usize,
+    windows: usize,
+}
+
+impl Coverage {
+    pub(crate) fn new(total_bytes: usize) -> Self {
+        Self {
+            analysed_bytes: 0,
+            total_bytes,
+            windows: 0,
+        }
+    }
+
+    pub(crate) fn add_window(&mut self, window: &str) {
+        self.analysed_bytes += window.len();
+        self.windows += 1;
+    }
+
+    /// Number of analysed bytes (not characters) of the text.
+    pub fn analysed_bytes(&self) -> usize {
+        self.analysed_bytes
+    }
+
+    /// Length of the whole text in bytes (not characters).
+    pub fn total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+
+    /// Number of analysed windows, 1 if the text was sampled from its beginning or analysed
+    /// entirely.
+    pub fn windows(&self) -> usize {
+        self.windows
+    }
+
+    /// Analysed share of the bytes of the text, within `0.0..=1.0`.
+    pub fn ratio(&self) -> f64 {
+        if self.total_bytes == 0 {
+            1.0
+        } else {
+            self.analysed_bytes as f64 / self.total_bytes as f64
+        }
+    }
+}
+
+fn take_chars(text: &str, max_chars: usize) -> &str {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => &text[..end],
+        None => text,
+    }
+}
+
+fn ceil_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index += 1;
+    }
+    index
+}
+
+// Small and fast generator, good enough to pick positions of windows.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn windows(sampling: Sampling, text: &str) -> Vec<&str> {
+        sampling.windows(text).collect()
+    }
+
+    #[test]
+    fn test_short_text_is_taken_entirely() {
+        let text = "Дуже цікаво";
+        assert_eq!(windows(Sampling::head(100), text), vec![text]);
+        assert_eq!(windows(Sampling::even_windows(100, 4), text), vec![text]);
+        assert_eq!(
+            windows(Sampling::random_windows(100, 4, 1), text),
+            vec![text]
+        );
+        assert_eq!(windows(Sampling::head(100), ""), vec![""]);
+    }
+
+    #[test]
+    fn test_multibyte_text_within_max_chars_is_taken_entirely() {
+        // 2 bytes per character
+        let text = "абвгд".repeat(4);
+        assert_eq!(text.len(), 40);
+        assert_eq!(windows(Sampling::even_windows(20, 4), &text), vec![&text]);
+        assert_eq!(
+            windows(Sampling::random_windows(20, 4, 1), &text),
+            vec![&text]
+        );
+        assert_eq!(windows(Sampling::even_windows(19, 4), &text).len(), 4);
+
+        // 3 bytes per character
+        let text = "日本語のテキスト".repeat(250);
+        assert_eq!(text.chars().count(), 2000);
+        assert_eq!(windows(Sampling::even_windows(2000, 4), &text), vec![&text]);
+        assert_eq!(windows(Sampling::even_windows(1999, 4), &text).len(), 4);
+    }
+
+    #[test]
+    fn test_head() {
+        assert_eq!(windows(Sampling::head(4), "Дуже цікаво"), vec!["Дуже"]);
+        assert_eq!(windows(Sampling::head(6), "Дуже"), vec!["Дуже"]);
+    }
+
+    #[test]
+    fn test_even_windows() {
+        let text = "aaaaabbbbbcccccddddd";
+        assert_eq!(
+            windows(Sampling::even_windows(8, 4), text),
+            vec!["aa", "bb", "cc", "dd"]
+        );
+
+        let text = "ааааабббббвввввггггг";
+        assert_eq!(
+            windows(Sampling::even_windows(8, 4), text),
+            vec!["аа", "бб", "вв", "гг"]
+        );
+    }
+
+    #[test]
+    fn test_random_windows() {
+        let text = "abcdefghijklmnopqrstuvwxyz".repeat(100);
+        let sampling = Sampling::random_windows(100, 5, 42);
+        let first = windows(sampling, &text);
+        assert_eq!(first.len(), 5);
+        assert!(first.iter().all(|w| w.len() == 20));
+        assert_eq!(first, windows(sampling, &text));
+
+        let other = windows(Sampling::random_windows(100, 5, 43), &text);
+        assert_ne!(first, other);
+
+        // Windows are picked within their parts of the text
+        for (i, window) in first.iter().enumerate() {
+            let start = window.as_ptr() as usize - text.as_ptr() as usize;
+            assert!(start >= i * 520 && start + window.len() <= (i + 1) * 520);
+        }
+    }
+
+    #[test]
+    fn test_random_windows_of_multibyte_text() {
+        let text = "абвгдеёжзийклмнопрстуфхцчшщъыьэюя".repeat(20);
+        for seed in 0..100 {
+            for window in windows(Sampling::random_windows(60, 6, seed), &text) {
+                assert!(!window.is_empty());
+                assert!(window.chars().count() <= 10);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_too_many_windows() {
+        Sampling::even_windows(10, 11);
+    }
+
+    #[test]
+    fn test_coverage() {
+        let mut coverage = Coverage::new(10);
+        coverage.add_window("ab");
+        coverage.add_window("cde");
+        assert_eq!(coverage.analysed_bytes(), 5);
+        assert_eq!(coverage.windows(), 2);
+        assert_eq!(coverage.ratio(), 0.5);
+        assert_eq!(Coverage::new(0).ratio(), 1.0);
+    }
+}
@@ -0,0 +1,79 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use super::{FilterList, Text};
+use crate::{Lang, Script};
+
+/// A detection method that scores candidate languages of a given script.
+///
+/// Built-in methods are exposed as [AlphabetScorer](crate::AlphabetScorer) and
+/// [TrigramScorer](crate::TrigramScorer). Custom implementations can be blended with them
+/// using an [Ensemble](crate::Ensemble).
+///
+/// # Example
+/// ```
+/// use whatlang::{FilterList, Lang, Script, Scorer, Scores, Text};
+///
+/// // Believes that every text is English.
+/// #[derive(Debug)]
+/// struct AlwaysEnglish;
+///
+/// impl Scorer for AlwaysEnglish {
+///     fn score(&self, _text: &Text, script: Script, filter_list: &FilterList) -> Scores {
+///         let scores = script
+///             .langs()
+///             .iter()
+///             .filter(|&&lang| filter_list.is_allowed(lang))
+///             .map(|&lang| (lang, if lang == Lang::Eng { 1.0 } else { 0.0 }))
+///             .collect();
+///         Scores::new(scores, 1)
+///     }
+/// }
+/// ```
+pub trait Scorer: Debug + Send + Sync {
+    /// Score languages of the `script` which are allowed by the `filter_list`.
+    fn score(&self, text: &Text, script: Script, filter_list: &FilterList) -> Scores;
+}
+
+/// Outcome of a [Scorer].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Scores {
+    scores: Vec<(Lang, f64)>,
+    count: usize,
+}
+
+impl Scores {
+    /// `scores` - a score within `0.0..=1.0` for every candidate language.
+    /// `count` - amount of evidence (e.g. number of chars or trigrams) the scores are based on.
+    pub fn new(scores: Vec<(Lang, f64)>, count: usize) -> Self {
+        Self { scores, count }
+    }
+
+    pub fn scores(&self) -> &[(Lang, f64)] {
+        &self.scores
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    // Scores for a script that is used only by one language (e.g. Greek), so there is
+    // nothing to score.
+    pub(crate) fn for_one_lang_script(script: Script, filter_list: &FilterList) -> Self {
+        let scores = script
+            .langs()
+            .iter()
+            .filter(|&&lang| filter_list.is_allowed(lang))
+            .map(|&lang| (lang, 1.0))
+            .collect();
+        Self::new(scores, 0)
+    }
+
+    pub(crate) fn get(&self, lang: Lang) -> f64 {
+        self.scores
+            .iter()
+            .find(|(l, _)| *l == lang)
+            .map(|&(_, score)| score)
+            .unwrap_or(0.0)
+    }
+}
@@ -0,0 +1,99 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+
+use super::TextBuffers;
+use crate::Lang;
+use crate::alphabets::AlphabetBuffers;
+use crate::markup::DeclaredLangs;
+use crate::scripts::RawScriptInfo;
+use crate::trigrams;
+
+/// Reusable buffers for repeated detection, see [Detector::detect_with](crate::Detector::detect_with).
+///
+/// Detection needs memory for intermediate results: script counters, the lowercase text,
+/// trigrams of the text and scores of the languages. A scratch keeps this memory between calls,
+/// so once it has seen a few texts, detection does not allocate anymore.
+///
+/// A [Detector](crate::Detector) can be shared between threads, while a scratch is meant to be
+/// owned by one thread (or one worker) and passed to every call.
+///
+/// # Example
+/// ```
+/// use whatlang::{Detector, DetectorScratch, Lang};
+///
+/// let detector = Detector::new();
+/// let mut scratch = DetectorScratch::new();
+///
+/// let info = detector.detect_with(&mut scratch, "Where is the library?").unwrap();
+/// assert_eq!(info.lang(), Lang::Eng);
+///
+/// let text = "Ich sehe auf die Uhr, es ist kurz vor Mittag";
+/// let info = detector.detect_with(&mut scratch, text).unwrap();
+/// assert_eq!(info.lang(), Lang::Deu);
+/// ```
+#[derive(Debug, Default)]
+pub struct DetectorScratch {
+    pub(crate) script_info: RawScriptInfo,
+    pub(crate) text: TextBuffers,
+    pub(crate) buffers: Buffers,
+    // Windows of a sampled text, see [Sampling](crate::Sampling).
+    pub(crate) sample: String,
+    // Text without the spans removed by a [Preprocessor](crate::Preprocessor).
+    pub(crate) preprocessed: String,
+    // Natural-language text of a document, see [InputMode](crate::InputMode).
+    pub(crate) extracted: String,
+    pub(crate) declared_langs: DeclaredLangs,
+}
+
+impl DetectorScratch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Buffers of the detection methods.
+#[derive(Debug, Default)]
+pub(crate) struct Buffers {
+    pub(crate) alphabet: AlphabetBuffers,
+    pub(crate) trigram: trigrams::RawOutcome,
+    // Scores of the methods which blend other methods (e.g. the combined method).
+    pub(crate) scores: Vec<(Lang, f64)>,
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static THREAD_SCRATCH: RefCell<DetectorScratch> = RefCell::new(DetectorScratch::new());
+}
+
+/// Run the function with the scratch of the current thread, so functions without a scratch
+/// (e.g. [detect](crate::detect)) reuse buffers too.
+#[cfg(feature = "std")]
+pub(crate) fn with_thread_scratch<R>(f: impl FnOnce(&mut DetectorScratch) -> R) -> R {
+    THREAD_SCRATCH.with(|scratch| match scratch.try_borrow_mut() {
+        Ok(mut scratch) => f(&mut scratch),
+        // The scratch is already in use up the stack, e.g. by a scorer of an ensemble
+        Err(_) => f(&mut DetectorScratch::new()),
+    })
+}
+
+/// Without `std` there are no thread locals, so every call gets a new scratch.
+#[cfg(not(feature = "std"))]
+pub(crate) fn with_thread_scratch<R>(f: impl FnOnce(&mut DetectorScratch) -> R) -> R {
+    f(&mut DetectorScratch::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_thread_scratch_is_reentrant() {
+        let lowercase = with_thread_scratch(|outer| {
+            outer.text.lowercase.push_str("outer");
+            with_thread_scratch(|inner| inner.text.lowercase.clone())
+        });
+        assert_eq!(lowercase, "");
+    }
+}
@@ -1,6 +1,19 @@
-use std::cell::{Ref, RefCell};
-use std::ops::Deref;
+use super::char_counts::CharCounts;
+use crate::normalization::{Normalization, NormalizationBuffers, normalize_into};
+use crate::trigrams::utils::TrigramTable;
+use alloc::string::String;
+use core::cell::{Cell, Ref, RefCell};
+use core::char::ToLowercase;
+use core::ops::Deref;
 
+/// Letters specific to Turkish and Azerbaijani, a text containing them is lowercased with
+/// the dotless `ı` as the lowercase of `I`.
+const DOTLESS_I_LETTERS: [char; 6] = ['İ', 'ı', 'Ğ', 'ğ', 'Ə', 'ə'];
+
+/// Lowercased version of a text.
+///
+/// Unlike `str::to_lowercase()`, the dotted `İ` is lowercased to `i` without a combining dot,
+/// and `I` to the dotless `ı` in Turkish and Azerbaijani texts, see [Text::lowercase].
 #[derive(Debug)]
 pub struct LowercaseText {
     inner: String,
@@ -8,7 +21,8 @@ pub struct LowercaseText {
 
 impl LowercaseText {
     pub fn new(original_text: &str) -> Self {
-        let inner = original_text.to_lowercase();
+        let text = Text::with_normalization(original_text, Normalization::None);
+        let inner = text.lowercase_chars().collect();
         Self { inner }
     }
 }
@@ -21,29 +35,241 @@ impl Deref for LowercaseText {
     }
 }
 
+/// A text to detect a language of. Caches data derived from the original text (e.g. its
+/// lowercase version), so it's computed only once, no matter how many [Scorer](crate::Scorer)s
+/// process the text.
+///
+/// The text is normalized first (see [Normalization]), all the other data is derived from
+/// the normalized text.
 #[derive(Debug)]
 pub struct Text<'a> {
     original: &'a str,
-    lowercase: RefCell<Option<LowercaseText>>,
+    // The normalized copy of the text, if the original text is not normalized
+    normalization: NormalizationBuffers,
+    is_normalized_copy: bool,
+    // ASCII text is processed byte-wise, with the same results
+    is_ascii: bool,
+    // Computed on the first `I` of the text, see Text::uses_dotless_i()
+    uses_dotless_i: Cell<Option<bool>>,
+    lowercase: Cached<LowercaseText>,
+    char_counts: Cached<CharCounts>,
+    trigrams: Cached<TrigramTable>,
+}
+
+/// Buffers for the data derived from a text, reused between texts,
+/// see [Text::with_buffers] and [Text::into_buffers].
+#[derive(Debug, Default)]
+pub(crate) struct TextBuffers {
+    pub(crate) normalization: NormalizationBuffers,
+    pub(crate) lowercase: String,
+    pub(crate) char_counts: CharCounts,
+    pub(crate) trigrams: TrigramTable,
 }
 
 impl<'a> Text<'a> {
+    /// Create a text, normalized to NFC (see [Normalization::Nfc]).
     pub fn new(original_text: &'a str) -> Self {
+        Self::with_normalization(original_text, Normalization::default())
+    }
+
+    pub fn with_normalization(original_text: &'a str, normalization: Normalization) -> Self {
+        Self::with_buffers(original_text, normalization, TextBuffers::default())
+    }
+
+    pub(crate) fn with_buffers(
+        original_text: &'a str,
+        normalization: Normalization,
+        buffers: TextBuffers,
+    ) -> Self {
+        let TextBuffers {
+            normalization: mut normalization_buffers,
+            lowercase,
+            char_counts,
+            trigrams,
+        } = buffers;
+        let is_ascii = original_text.is_ascii();
+        // ASCII text is normalized in any form
+        let is_normalized_copy =
+            !is_ascii && normalize_into(original_text, normalization, &mut normalization_buffers);
         Self {
             original: original_text,
-            lowercase: RefCell::new(None),
+            is_ascii: is_ascii
+                || (is_normalized_copy && normalization_buffers.normalized.is_ascii()),
+            normalization: normalization_buffers,
+            is_normalized_copy,
+            uses_dotless_i: Cell::new(None),
+            lowercase: Cached::new(LowercaseText { inner: lowercase }),
+            char_counts: Cached::new(char_counts),
+            trigrams: Cached::new(trigrams),
+        }
+    }
+
+    pub(crate) fn into_buffers(self) -> TextBuffers {
+        TextBuffers {
+            normalization: self.normalization,
+            lowercase: self.lowercase.into_inner().inner,
+            char_counts: self.char_counts.into_inner(),
+            trigrams: self.trigrams.into_inner(),
         }
     }
 
+    pub fn original(&self) -> &'a str {
+        self.original
+    }
+
+    /// The normalized text, it's the original text unless normalization changes it.
+    pub fn normalized(&self) -> &str {
+        if self.is_normalized_copy {
+            &self.normalization.normalized
+        } else {
+            self.original
+        }
+    }
+
+    /// The lowercase text. Letters are lowercased as `str::to_lowercase()` does, except the dotted
+    /// and dotless I of Turkish and Azerbaijani: `İ` is lowercased to `i` (without a combining
+    /// dot), and `I` is lowercased to `ı` if the text contains letters specific to these
+    /// languages (`İ`, `ı`, `ğ` or `ə`), so capitalized text matches their alphabets.
+    ///
+    /// Turkish text in all caps without such letters (e.g. `KIRMIZI KITAP`) is lowercased with `i`:
+    /// before detection nothing tells it apart from texts of other languages, where `I` is
+    /// the uppercase of `i`.
     pub fn lowercase(&self) -> Ref<'_, LowercaseText> {
-        if self.lowercase.borrow().is_none() {
-            let lowercase_text = LowercaseText::new(self.original);
-            self.lowercase.replace(Some(lowercase_text));
+        self.lowercase.get_or_fill(|lowercase| {
+            lowercase.inner.clear();
+            if self.is_ascii {
+                lowercase.inner.push_str(self.normalized());
+                lowercase.inner.make_ascii_lowercase();
+            } else {
+                lowercase.inner.extend(self.lowercase_chars());
+            }
+        })
+    }
+
+    /// Number of occurrences of every character of the lowercase text.
+    pub(crate) fn char_counts(&self) -> Ref<'_, CharCounts> {
+        self.char_counts.get_or_fill(|counts| {
+            if self.is_ascii {
+                counts.fill_ascii(self.normalized().as_bytes());
+            } else {
+                counts.fill(self.lowercase_chars());
+            }
+        })
+    }
+
+    /// Trigrams of the lowercase text, ranked by number of occurrences.
+    pub(crate) fn trigrams(&self) -> Ref<'_, TrigramTable> {
+        self.trigrams.get_or_fill(|table| table.fill(self))
+    }
+
+    pub(crate) fn is_ascii(&self) -> bool {
+        self.is_ascii
+    }
+
+    /// Chars of the lowercase version of the text, lowercased on the fly without allocating.
+    pub(crate) fn lowercase_chars(&self) -> impl Iterator<Item = char> + '_ {
+        let normalized = self.normalized();
+        let mut preceded_by_cased = false;
+        normalized.char_indices().flat_map(move |(i, ch)| {
+            let lowercase = if ch == 'Σ' && preceded_by_cased {
+                let rest = &normalized[i + ch.len_utf8()..];
+                if is_followed_by_cased(rest) {
+                    'σ'
+                } else {
+                    'ς'
+                }
+            } else {
+                ch
+            };
+            if !is_case_ignorable(ch) {
+                preceded_by_cased = is_cased(ch);
+            }
+            self.lowercase_char(lowercase)
+        })
+    }
+
+    /// Lowercase a char of the text, see [Text::lowercase].
+    /// Unlike [Text::lowercase_chars], it does not take the final sigma into account.
+    #[inline]
+    pub(crate) fn lowercase_char(&self, ch: char) -> ToLowercase {
+        match ch {
+            'İ' => 'i',
+            'I' if self.uses_dotless_i() => 'ı',
+            _ => ch,
+        }
+        .to_lowercase()
+    }
+
+    // Whether `I` is the uppercase of the dotless `ı`, as in Turkish and Azerbaijani.
+    // It's checked only once the text turns out to contain `I`.
+    fn uses_dotless_i(&self) -> bool {
+        match self.uses_dotless_i.get() {
+            Some(uses_dotless_i) => uses_dotless_i,
+            None => {
+                let uses_dotless_i =
+                    !self.is_ascii && self.normalized().contains(DOTLESS_I_LETTERS);
+                self.uses_dotless_i.set(Some(uses_dotless_i));
+                uses_dotless_i
+            }
+        }
+    }
+}
+
+// Data derived from a text, computed on first access into a reused buffer.
+#[derive(Debug)]
+struct Cached<T> {
+    value: RefCell<T>,
+    is_filled: Cell<bool>,
+}
+
+impl<T> Cached<T> {
+    fn new(buffer: T) -> Self {
+        Self {
+            value: RefCell::new(buffer),
+            is_filled: Cell::new(false),
         }
+    }
 
-        let ref_opt_lowercase = self.lowercase.borrow();
-        Ref::map(ref_opt_lowercase, |r| r.as_ref().unwrap())
+    fn get_or_fill(&self, fill: impl FnOnce(&mut T)) -> Ref<'_, T> {
+        if !self.is_filled.get() {
+            fill(&mut self.value.borrow_mut());
+            self.is_filled.set(true);
+        }
+        self.value.borrow()
     }
+
+    fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+// Capital sigma is lowercased to final sigma at the end of a word, the same way
+// str::to_lowercase() does it. The Unicode properties it relies on are approximated.
+fn is_followed_by_cased(rest: &str) -> bool {
+    rest.chars()
+        .find(|&ch| !is_case_ignorable(ch))
+        .is_some_and(is_cased)
+}
+
+fn is_cased(ch: char) -> bool {
+    ch.is_lowercase() || ch.is_uppercase()
+}
+
+fn is_case_ignorable(ch: char) -> bool {
+    matches!(
+        ch,
+        '\'' | '.'
+            | ':'
+            | '^'
+            | '`'
+            | '\u{00A8}'
+            | '\u{00AD}'
+            | '\u{00AF}'
+            | '\u{00B4}'
+            | '\u{00B7}'
+            | '\u{00B8}'
+            | '\u{0300}'..='\u{036F}' | '\u{2018}' | '\u{2019}' | '\u{2024}' | '\u{2027}'
+    )
 }
 
 #[cfg(test)]
@@ -55,4 +281,120 @@ mod tests {
         let text = Text::new("Hello THERE");
         assert_eq!(text.lowercase().deref().deref(), "hello there");
     }
+
+    #[test]
+    fn test_normalized_text() {
+        let decomposed = "Tie\u{0302}\u{0301}ng Vie\u{0323}\u{0302}t";
+        let text = Text::new(decomposed);
+        assert_eq!(text.original(), decomposed);
+        assert_eq!(text.normalized(), "Tiếng Việt");
+        assert_eq!(text.lowercase().deref().deref(), "tiếng việt");
+        assert_eq!(text.char_counts().total(), 9);
+
+        let text = Text::with_normalization(decomposed, Normalization::None);
+        assert_eq!(text.normalized(), decomposed);
+        assert_eq!(text.char_counts().total(), 13);
+
+        // Fullwidth letters are kept by NFC and replaced by NFKC
+        let text = Text::new("ＡＢＣ ｄｅｆ");
+        assert_eq!(text.lowercase().deref().deref(), "ａｂｃ ｄｅｆ");
+        let text = Text::with_normalization("ＡＢＣ ｄｅｆ", Normalization::Nfkc);
+        assert_eq!(text.normalized(), "ABC def");
+        assert_eq!(text.lowercase().deref().deref(), "abc def");
+        assert!(text.is_ascii());
+    }
+
+    #[test]
+    fn test_lowercase_dotted_and_dotless_i() {
+        let examples = [
+            ("KIRMIZI İĞNE", "kırmızı iğne"),
+            ("İSTANBUL", "istanbul"),
+            ("AZƏRBAYCAN DİLİ", "azərbaycan dili"),
+            // Texts without letters specific to Turkish and Azerbaijani
+            ("IRIS", "iris"),
+            ("KIRMIZI KITAP", "kirmizi kitap"),
+            ("IVAN IVANOVIĆ", "ivan ivanović"),
+            ("ŞI ÎN ŢARĂ", "şi în ţară"),
+        ];
+        for (original, expected) in examples {
+            let text = Text::new(original);
+            assert_eq!(text.lowercase().deref().deref(), expected);
+            assert_eq!(text.lowercase_chars().collect::<String>(), expected);
+            assert_eq!(LowercaseText::new(original).deref(), expected);
+        }
+    }
+
+    #[test]
+    fn test_text_with_buffers() {
+        let mut buffers = TextBuffers::default();
+        buffers.lowercase.reserve(64);
+        buffers.lowercase.push_str("garbage");
+        buffers.char_counts.fill("garbage".chars());
+        buffers.trigrams.fill(&Text::new("garbage"));
+        let capacity = buffers.lowercase.capacity();
+
+        let text = Text::with_buffers("Hello THERE", Normalization::Nfc, buffers);
+        assert_eq!(text.lowercase().deref().deref(), "hello there");
+        assert_eq!(text.char_counts().total(), 10);
+        assert_eq!(text.trigrams().len(), 11);
+
+        let buffers = text.into_buffers();
+        assert_eq!(buffers.lowercase.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_lowercase_chars_as_lowercase_text() {
+        let texts = [
+            "Hello THERE",
+            "İSTANBUL",
+            "ΟΔΥΣΣΕΥΣ",
+            "Σ ΑΣ ΑΣ. ΑΣΑ Α'Σ ΑΣ'Α ΑΣ́",
+            include_str!("../../tests/examples.json"),
+        ];
+        for original in texts {
+            let text = Text::new(original);
+            let lowercase: String = text.lowercase_chars().collect();
+            assert_eq!(lowercase, text.lowercase().deref().deref());
+        }
+
+        for ch in char::MIN..=char::MAX {
+            let original = ch.to_string();
+            let text = Text::with_normalization(&original, Normalization::None);
+            let lowercase: String = text.lowercase_chars().collect();
+            if ch == 'İ' {
+                assert_eq!(lowercase, "i");
+            } else {
+                assert_eq!(lowercase, original.to_lowercase());
+            }
+        }
+    }
+
+    #[test]
+    fn test_ascii_text_as_generic() {
+        let all_ascii: String = (0u8..128).map(char::from).collect();
+        let english = "Where is the library? The LIBRARY is over there, 2nd floor (room #42).";
+        for original in [all_ascii.as_str(), english, "", "A", "a b"] {
+            let ascii = Text::new(original);
+            let mut generic = Text::new(original);
+            generic.is_ascii = false;
+            assert!(ascii.is_ascii());
+
+            assert_eq!(
+                ascii.lowercase().deref().deref(),
+                generic.lowercase().deref().deref()
+            );
+            assert_eq!(ascii.char_counts().total(), generic.char_counts().total());
+            assert!(ascii.char_counts().iter().eq(generic.char_counts().iter()));
+            let (ascii_trigrams, generic_trigrams) = (ascii.trigrams(), generic.trigrams());
+            assert_eq!(
+                ascii_trigrams.total_trigrams(),
+                generic_trigrams.total_trigrams()
+            );
+            assert!(
+                ascii_trigrams
+                    .trigram_positions()
+                    .eq(generic_trigrams.trigram_positions())
+            );
+        }
+    }
 }
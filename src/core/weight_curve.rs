@@ -0,0 +1,149 @@
+/// Weight of the alphabet method within the combined method, as a function of the number of
+/// characters in the text. The weight of the trigram method is `1.0 - alphabet_weight`.
+///
+/// The default curve gives the alphabet method `2/3` of the weight for an empty text and
+/// decreases it linearly to `1/3` for texts with 100 and more characters:
+///
+/// ```text
+///          alphabet weight
+///          ^
+///          |
+///     2/3 -* (0; 2/3)
+///          | \_
+///          |   \_
+///          |     \_
+///          |       \_
+///          |         \  (100; 1/3)
+///     1/3 -|          *------------
+///          |
+///          +----------|------------> count
+///         0          100
+/// ```
+///
+/// # Example
+/// ```
+/// use whatlang::{Detector, Lang, Options, WeightCurve};
+///
+/// // Trust the alphabet method less than by default.
+/// let curve = WeightCurve::linear(0.5, 0.2, 50);
+/// let detector = Detector::with_options(Options::new().set_alphabet_weight_curve(curve));
+/// assert_eq!(detector.detect_lang("Can you tell me where is Schönheitstraße?"), Some(Lang::Eng));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct WeightCurve(Curve);
+
+#[derive(Debug, Clone, Copy)]
+enum Curve {
+    Linear {
+        max_weight: f64,
+        min_weight: f64,
+        saturation_count: usize,
+    },
+    Custom(fn(usize) -> f64),
+}
+
+impl WeightCurve {
+    /// Weight decreases linearly from `max_weight` (empty text) down to `min_weight`,
+    /// which is reached at `saturation_count` characters.
+    ///
+    /// # Panics
+    /// Panics unless `0.0 <= min_weight <= max_weight <= 1.0` and `saturation_count > 0`.
+    pub fn linear(max_weight: f64, min_weight: f64, saturation_count: usize) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&min_weight)
+                && (0.0..=1.0).contains(&max_weight)
+                && min_weight <= max_weight,
+            "WeightCurve requires 0.0 <= min_weight <= max_weight <= 1.0, got min_weight={}, max_weight={}",
+            min_weight,
+            max_weight
+        );
+        assert!(saturation_count > 0, "saturation_count must be positive");
+        Self(Curve::Linear {
+            max_weight,
+            min_weight,
+            saturation_count,
+        })
+    }
+
+    /// Weight is calculated by the given function of the number of characters.
+    /// Results are clamped to `0.0..=1.0`.
+    pub fn custom(f: fn(usize) -> f64) -> Self {
+        Self(Curve::Custom(f))
+    }
+
+    /// Alphabet weight for a text with `count` characters.
+    pub fn weight(&self, count: usize) -> f64 {
+        match self.0 {
+            Curve::Linear {
+                max_weight,
+                min_weight,
+                saturation_count,
+            } => {
+                if max_weight == min_weight {
+                    return max_weight;
+                }
+                // Number of characters which decrease the weight by 1.0
+                let divisor = saturation_count as f64 / (max_weight - min_weight);
+                let weight = -(count as f64 / divisor) + max_weight;
+                weight.clamp(min_weight, max_weight)
+            }
+            Curve::Custom(f) => {
+                let weight = f(count);
+                if weight.is_nan() {
+                    0.0
+                } else {
+                    weight.clamp(0.0, 1.0)
+                }
+            }
+        }
+    }
+}
+
+impl Default for WeightCurve {
+    fn default() -> Self {
+        Self::linear(2.0 / 3.0, 1.0 / 3.0, 100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let curve = WeightCurve::default();
+        assert_eq!(curve.weight(0), 2.0 / 3.0);
+        assert_eq!(curve.weight(50), 0.5);
+        assert_eq!(curve.weight(100), 1.0 / 3.0);
+        assert_eq!(curve.weight(200), 1.0 / 3.0);
+    }
+
+    #[test]
+    fn test_linear() {
+        let curve = WeightCurve::linear(0.8, 0.2, 60);
+        assert_eq!(curve.weight(0), 0.8);
+        assert!((curve.weight(30) - 0.5).abs() < 1e-12);
+        assert_eq!(curve.weight(60), 0.2);
+        assert_eq!(curve.weight(1000), 0.2);
+
+        let flat = WeightCurve::linear(0.4, 0.4, 10);
+        assert_eq!(flat.weight(0), 0.4);
+        assert_eq!(flat.weight(100), 0.4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_linear_with_invalid_weights() {
+        WeightCurve::linear(0.2, 0.8, 100);
+    }
+
+    #[test]
+    fn test_custom() {
+        let curve = WeightCurve::custom(|count| if count < 10 { 2.0 } else { -1.0 });
+        assert_eq!(curve.weight(5), 1.0);
+        assert_eq!(curve.weight(20), 0.0);
+
+        let nan_curve = WeightCurve::custom(|_| f64::NAN);
+        assert_eq!(nan_curve.weight(5), 0.0);
+    }
+}
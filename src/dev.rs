@@ -2,7 +2,9 @@
 //! It exists only to enable tuning of the library with extra supporting tools (e.g. benchmarks).
 //! Developers are advised against relying on API.
 //!
-pub use crate::alphabets::{RawOutcome as RawAlphabetsInfo, raw_detect as alphabets_raw_detect};
+pub use crate::alphabets::{
+    AlphabetBuffers, RawOutcome as RawAlphabetsInfo, raw_detect as alphabets_raw_detect,
+};
 pub use crate::combined::{RawOutcome as RawCombinedInfo, raw_detect as combined_raw_detect};
 pub use crate::core::{Detector, Info, Method, Options, detect, detect_lang, detect_with_options};
 pub use crate::lang::Lang;
@@ -10,12 +12,15 @@ pub use crate::scripts::{RawScriptInfo, Script, detect_script, raw_detect_script
 pub use crate::trigrams::{RawOutcome as RawTrigramsInfo, raw_detect as trigrams_raw_detect};
 
 pub use crate::alphabets::cyrillic::alphabet_calculate_scores as alphabet_cyrillic_calculate_scores;
+pub use crate::alphabets::cyrillic::frequency_alphabet_calculate_scores as frequency_alphabet_cyrillic_calculate_scores;
 pub use crate::alphabets::latin::alphabet_calculate_scores as alphabet_latin_calculate_scores;
-pub use crate::core::{FilterList, LowercaseText};
+pub use crate::alphabets::latin::frequency_alphabet_calculate_scores as frequency_alphabet_latin_calculate_scores;
+pub use crate::core::{FilterList, Text};
 
 // private imports
-use crate::core::Query;
 use crate::core::detect::detect_lang_base_on_mandarin_script;
+use crate::core::{AlphabetMode, Query, WeightCurve};
+use crate::normalization::Normalization;
 use crate::scripts::grouping::ScriptLangGroup;
 
 #[derive(Debug)]
@@ -38,6 +43,11 @@ pub fn raw_detect(text: &str) -> RawInfo {
         text,
         filter_list: &FilterList::default(),
         method: Method::Combined,
+        ensemble: None,
+        alphabet_weight_curve: WeightCurve::default(),
+        alphabet_mode: AlphabetMode::default(),
+        tolerate_foreign_words: false,
+        normalization: Normalization::default(),
     };
 
     let lang_info = script_info
@@ -45,7 +55,8 @@ pub fn raw_detect(text: &str) -> RawInfo {
         .map(|script| match script.to_lang_group() {
             ScriptLangGroup::One(lang) => RawLangInfo::OneScript(lang),
             ScriptLangGroup::Multi(multi_lang_script) => {
-                let iquery = query.to_internal(multi_lang_script);
+                let text = Text::with_normalization(query.text, query.normalization);
+                let iquery = query.to_internal(multi_lang_script, &text);
                 let combined = combined_raw_detect(&iquery);
                 RawLangInfo::MultiScript(combined)
             }
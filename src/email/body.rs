@@ -0,0 +1,410 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// Verbs of attribution lines, e.g. `On Mon, 3 Jun 2024, Anna <anna@example.com> wrote:`.
+const ATTRIBUTION_VERBS: [&str; 17] = [
+    "wrote",
+    "schrieb",
+    "a écrit",
+    "escribió",
+    "ha scritto",
+    "escreveu",
+    "schreef",
+    "napisał",
+    "napisała",
+    "pisze",
+    "написал",
+    "написала",
+    "написал(а)",
+    "skrev",
+    "kirjoitti",
+    "yazdı",
+    "írta",
+];
+
+// Separators of forwarded and original messages, e.g. `-----Original Message-----`.
+const SEPARATORS: [&str; 19] = [
+    "original message",
+    "ursprüngliche nachricht",
+    "original-nachricht",
+    "message d'origine",
+    "mensaje original",
+    "messaggio originale",
+    "mensagem original",
+    "oorspronkelijk bericht",
+    "forwarded message",
+    "weitergeleitete nachricht",
+    "message transféré",
+    "mensaje reenviado",
+    "messaggio inoltrato",
+    "mensagem encaminhada",
+    "doorgestuurd bericht",
+    "begin forwarded message",
+    "anfang der weitergeleiteten nachricht",
+    "début du message réexpédié",
+    "inicio del mensaje reenviado",
+];
+
+// Headers, which start the quoted message of Outlook and other clients, e.g.
+// `From: Anna Schmidt` followed by `Sent: Monday, June 3, 2024 10:00 AM`.
+const FROM_HEADERS: [&str; 12] = [
+    "from",
+    "von",
+    "de",
+    "da",
+    "van",
+    "od",
+    "от",
+    "fra",
+    "från",
+    "lähettäjä",
+    "feladó",
+    "kimden",
+];
+
+const OTHER_HEADERS: [&str; 30] = [
+    "sent",
+    "date",
+    "to",
+    "subject",
+    "cc",
+    "gesendet",
+    "datum",
+    "an",
+    "betreff",
+    "envoyé",
+    "à",
+    "objet",
+    "enviado",
+    "fecha",
+    "para",
+    "asunto",
+    "inviato",
+    "data",
+    "a",
+    "oggetto",
+    "verzonden",
+    "aan",
+    "onderwerp",
+    "wysłano",
+    "do",
+    "temat",
+    "отправлено",
+    "дата",
+    "кому",
+    "тема",
+];
+
+// Beginnings of signatures added by mail apps, e.g. `Sent from my iPhone`.
+const APP_SIGNATURES: [&str; 17] = [
+    "Sent from my ",
+    "Sent from Outlook",
+    "Sent from Mail for ",
+    "Get Outlook for ",
+    "Von meinem ",
+    "Gesendet von meinem ",
+    "Gesendet von Outlook",
+    "Envoyé de mon ",
+    "Envoyé depuis ",
+    "Enviado desde mi ",
+    "Enviado do meu ",
+    "Inviato da ",
+    "Verzonden vanaf ",
+    "Verstuurd vanaf ",
+    "Wysłane z ",
+    "Отправлено с ",
+    "Отправлено из ",
+];
+
+// Phrases of legal disclaimers and other footers, in lowercase.
+const DISCLAIMER_PHRASES: [&str; 18] = [
+    "confidential",
+    "intended recipient",
+    "intended solely",
+    "disclaimer",
+    "think before you print",
+    "vertraulich",
+    "richtige adressat",
+    "bevor sie diese e-mail ausdrucken",
+    "confidentiel",
+    "destinataire",
+    "confidencial",
+    "destinatario",
+    "riservat",
+    "vertrouwelijk",
+    "geadresseerde",
+    "poufn",
+    "конфиденциальн",
+    "adresat",
+];
+
+/// Split a plain-text body into the new content written by the author and the quoted history.
+/// Attribution lines, separators, headers of quoted messages, signatures and trailing disclaimers
+/// are dropped.
+pub(super) fn split(body: &str, content: &mut String, quoted: &mut String) {
+    let lines: Vec<&str> = body.lines().collect();
+    let mut content_lines: Vec<&str> = Vec::new();
+    let mut is_signature = false;
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if let Some(quoted_line) = strip_quote_marker(line) {
+            is_signature = false;
+            if !is_attribution(quoted_line) {
+                quoted.push_str(quoted_line);
+                quoted.push('\n');
+            }
+        } else if is_attribution(trimmed) || is_quote_introduction(&lines, i) {
+            // The beginning of an attribution wrapped by the mail client
+            let is_wrapped = !trimmed.starts_with(char::is_uppercase);
+            if is_wrapped
+                && content_lines
+                    .last()
+                    .is_some_and(|line| !line.trim().is_empty())
+            {
+                content_lines.pop();
+            }
+            is_signature = false;
+        } else if is_separator(trimmed) || is_header_block(&lines[i..]) {
+            // Quoted messages of Outlook and forwarded messages have no quote markers
+            push_history(lines[i..].iter().copied(), quoted);
+            break;
+        } else if trimmed == "--" {
+            is_signature = true;
+        } else if !is_signature && !is_app_signature(trimmed) {
+            content_lines.push(line);
+        }
+    }
+    remove_disclaimers(&mut content_lines);
+    for line in content_lines {
+        content.push_str(line);
+        content.push('\n');
+    }
+}
+
+/// Append the quoted history without quote markers, attribution lines, separators and headers.
+pub(super) fn push_history<'a>(lines: impl IntoIterator<Item = &'a str>, quoted: &mut String) {
+    for line in lines {
+        let line = strip_quote_marker(line).unwrap_or(line).trim();
+        let is_header = header_name(line).is_some_and(|name| {
+            FROM_HEADERS.contains(&name.as_str()) || OTHER_HEADERS.contains(&name.as_str())
+        });
+        if !is_header && !is_attribution(line) && !is_separator(line) {
+            quoted.push_str(line);
+            quoted.push('\n');
+        }
+    }
+}
+
+// A quoted line without quote markers, e.g. `Hello` for `> > Hello`.
+fn strip_quote_marker(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    line.starts_with('>')
+        .then(|| line.trim_start_matches(|ch: char| ch == '>' || ch.is_whitespace()))
+}
+
+// E.g. `On Mon, 3 Jun 2024, Anna <anna@example.com> wrote:` or `Le 3 juin, Anna a écrit :`.
+fn is_attribution(line: &str) -> bool {
+    line.strip_suffix(':').is_some_and(|line| {
+        let line = line.trim_end();
+        ATTRIBUTION_VERBS.iter().any(|verb| line.ends_with(verb))
+    })
+}
+
+// A line introducing quoted lines, which mentions a date or an address of the author, e.g.
+// `Am 03.06.2024 um 10:00 schrieb Anna <anna@example.com>:`.
+fn is_quote_introduction(lines: &[&str], i: usize) -> bool {
+    let line = lines[i].trim();
+    line.ends_with(':')
+        && line.contains(|ch: char| ch == '@' || ch.is_ascii_digit())
+        && lines[i + 1..]
+            .iter()
+            .find(|line| !line.trim().is_empty())
+            .is_some_and(|line| strip_quote_marker(line).is_some())
+}
+
+// E.g. `-----Original Message-----` or `---------- Forwarded message ---------`.
+fn is_separator(line: &str) -> bool {
+    let text = line
+        .trim_matches(|ch: char| ch == '-' || ch == '_' || ch == '*' || ch.is_whitespace())
+        .trim_end_matches(':')
+        .trim_end();
+    let text = text.to_lowercase();
+    SEPARATORS.contains(&text.as_str())
+}
+
+// Headers of a quoted message: the sender followed by other headers.
+fn is_header_block(lines: &[&str]) -> bool {
+    let is_from = header_name(lines[0]).is_some_and(|name| FROM_HEADERS.contains(&name.as_str()));
+    is_from
+        && lines[1..].iter().take(4).any(|line| {
+            header_name(line).is_some_and(|name| OTHER_HEADERS.contains(&name.as_str()))
+        })
+}
+
+// The lowercase name of a header line, e.g. `from` for `From: Anna`, `*De :* Anna` or `Von: Anna`.
+fn header_name(line: &str) -> Option<String> {
+    let (name, _) = line.trim().trim_start_matches('*').split_once(':')?;
+    let name = name.trim_end_matches('*').trim_end();
+    let is_name =
+        !name.is_empty() && name.chars().count() <= 12 && name.chars().all(char::is_alphabetic);
+    is_name.then(|| name.to_lowercase())
+}
+
+fn is_app_signature(line: &str) -> bool {
+    APP_SIGNATURES
+        .iter()
+        .any(|signature| line.starts_with(signature))
+}
+
+// Trailing paragraphs containing phrases of disclaimers.
+fn remove_disclaimers(lines: &mut Vec<&str>) {
+    loop {
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+        let paragraph_start = lines
+            .iter()
+            .rposition(|line| line.trim().is_empty())
+            .map_or(0, |i| i + 1);
+        let is_disclaimer = paragraph_start < lines.len()
+            && lines[paragraph_start..].iter().any(|line| {
+                let line = line.to_lowercase();
+                DISCLAIMER_PHRASES
+                    .iter()
+                    .any(|phrase| line.contains(phrase))
+            });
+        if !is_disclaimer {
+            return;
+        }
+        lines.truncate(paragraph_start);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split_body(body: &str) -> (String, String) {
+        let mut content = String::new();
+        let mut quoted = String::new();
+        split(body, &mut content, &mut quoted);
+        (content, quoted)
+    }
+
+    #[test]
+    fn test_quoted_lines() {
+        let body = "Hallo Anna,\n\
+            \n\
+            danke für die schnelle Antwort.\n\
+            \n\
+            On Mon, Jun 3, 2024 at 10:00 AM Anna Schmidt <\n\
+            anna@example.com> wrote:\n\
+            > Hi Bernd,\n\
+            >\n\
+            > the invoice is attached.\n\
+            >> Older message\n";
+        let (content, quoted) = split_body(body);
+        assert_eq!(content, "Hallo Anna,\n\ndanke für die schnelle Antwort.\n");
+        assert_eq!(
+            quoted,
+            "Hi Bernd,\n\nthe invoice is attached.\nOlder message\n"
+        );
+    }
+
+    #[test]
+    fn test_attribution_lines() {
+        assert!(is_attribution(
+            "On Mon, 3 Jun 2024, Anna <anna@example.com> wrote:"
+        ));
+        assert!(is_attribution("Le lun. 3 juin 2024, Anna a écrit :"));
+        assert!(is_attribution("El lun, 3 jun 2024, Anna escribió:"));
+        assert!(!is_attribution("Anna wrote"));
+        assert!(!is_attribution("Here is what I need:"));
+
+        let body = "Passt!\n\nAm 03.06.2024 um 10:00 schrieb Anna <anna@example.com>:\n\n> Fine?\n";
+        assert_eq!(split_body(body), ("Passt!\n".into(), "Fine?\n".into()));
+        // Not followed by quoted lines
+        let body = "Die Termine:\n10:00 und 12:00\n";
+        assert_eq!(split_body(body), (body.into(), "".into()));
+    }
+
+    #[test]
+    fn test_inline_replies() {
+        let body = "On Monday Anna wrote:\n> Can you come?\nJa, gerne.\n> At 10?\nLieber um 11.\n";
+        assert_eq!(
+            split_body(body),
+            (
+                "Ja, gerne.\nLieber um 11.\n".into(),
+                "Can you come?\nAt 10?\n".into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_outlook_quotes() {
+        let body = "Ja, das passt.\n\
+            \n\
+            ________________________________\n\
+            From: Anna Schmidt <anna@example.com>\n\
+            Sent: Monday, June 3, 2024 10:00 AM\n\
+            To: Bernd\n\
+            Subject: Meeting\n\
+            \n\
+            Does Tuesday work for you?\n";
+        assert_eq!(
+            split_body(body),
+            (
+                "Ja, das passt.\n\n________________________________\n".into(),
+                "\nDoes Tuesday work for you?\n".into()
+            )
+        );
+
+        let body = "Siehe unten.\n\n-----Original Message-----\nDe : Anna\nObjet : Test\nBonjour\n";
+        assert_eq!(
+            split_body(body),
+            ("Siehe unten.\n".into(), "Bonjour\n".into())
+        );
+    }
+
+    #[test]
+    fn test_signatures() {
+        let body = "Bis morgen!\n\
+            \n\
+            -- \n\
+            Bernd Müller\n\
+            Head of Sales | Example GmbH\n";
+        assert_eq!(split_body(body), ("Bis morgen!\n".into(), "".into()));
+
+        let body = "Bis morgen!\n\nSent from my iPhone\n";
+        assert_eq!(split_body(body), ("Bis morgen!\n".into(), "".into()));
+    }
+
+    #[test]
+    fn test_disclaimers() {
+        let body = "Die Unterlagen sind vertraulich, bitte nicht weiterleiten.\n\
+            \n\
+            Viele Grüße\n\
+            \n\
+            This e-mail may contain confidential information.\n\
+            If you are not the intended recipient, please delete it.\n\
+            \n\
+            Diese E-Mail ist vertraulich.\n";
+        assert_eq!(
+            split_body(body),
+            (
+                "Die Unterlagen sind vertraulich, bitte nicht weiterleiten.\n\nViele Grüße\n"
+                    .into(),
+                "".into()
+            )
+        );
+    }
+
+    #[test]
+    fn test_header_name() {
+        assert_eq!(header_name("From: Anna"), Some("from".into()));
+        assert_eq!(header_name("*Von:* Anna"), Some("von".into()));
+        assert_eq!(header_name("De : Anna"), Some("de".into()));
+        assert_eq!(header_name("Meeting at 10:00"), None);
+        assert_eq!(header_name("No colon"), None);
+    }
+}
@@ -0,0 +1,392 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// Nested multiparts deeper than this are ignored.
+const MAX_DEPTH: usize = 8;
+
+// Headers, which are only found in messages, so a text starting with `Note: ...` is not
+// taken for a header.
+const MESSAGE_HEADERS: [&str; 9] = [
+    "from",
+    "to",
+    "subject",
+    "date",
+    "content-type",
+    "mime-version",
+    "message-id",
+    "received",
+    "return-path",
+];
+
+/// The text of a message with headers of the message or a part of it.
+pub(super) struct Part<'a> {
+    headers: &'a str,
+    body: &'a str,
+}
+
+/// A body of a message, which is a text.
+pub(super) struct TextBody<'a> {
+    pub(super) text: Cow<'a, str>,
+    pub(super) is_html: bool,
+}
+
+/// Split a message into headers and the body. A text without headers is a body.
+pub(super) fn parse_message(message: &str) -> Part<'_> {
+    let (headers, body) = split_headers(message);
+    let is_message = headers_iter(headers).any(|(name, _)| {
+        MESSAGE_HEADERS
+            .iter()
+            .any(|header| header.eq_ignore_ascii_case(name))
+    });
+    if is_message && !headers.is_empty() {
+        Part { headers, body }
+    } else {
+        Part {
+            headers: "",
+            body: message,
+        }
+    }
+}
+
+// Headers end with a blank line. A text which does not start with a header has no headers.
+fn split_headers(text: &str) -> (&str, &str) {
+    let mut pos = 0;
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\r', '\n']);
+        if content.is_empty() {
+            return (&text[..pos], &text[pos + line.len()..]);
+        }
+        let is_continuation = content.starts_with([' ', '\t']) && pos > 0;
+        if !is_continuation && parse_header_line(content).is_none() {
+            return ("", text);
+        }
+        pos += line.len();
+    }
+    (text, "")
+}
+
+fn parse_header_line(line: &str) -> Option<(&str, &str)> {
+    let (name, value) = line.split_once(':')?;
+    let is_name = !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+    is_name.then_some((name, value.trim()))
+}
+
+// Headers with folded lines, values of folded headers are returned by their first line only,
+// which is enough for the headers of MIME.
+fn headers_iter(headers: &str) -> impl Iterator<Item = (&str, &str)> {
+    headers.lines().filter_map(parse_header_line)
+}
+
+impl<'a> Part<'a> {
+    // Value of a header with continuation lines joined.
+    fn header(&self, name: &str) -> Option<String> {
+        let mut lines = self.headers.lines();
+        while let Some(line) = lines.next() {
+            let Some((header, value)) = parse_header_line(line) else {
+                continue;
+            };
+            if !header.eq_ignore_ascii_case(name) {
+                continue;
+            }
+            let mut value = String::from(value);
+            for continuation in lines.take_while(|line| line.starts_with([' ', '\t'])) {
+                value.push(' ');
+                value.push_str(continuation.trim());
+            }
+            return Some(value);
+        }
+        None
+    }
+
+    /// The text of the message: the plain text or, if there is none, the HTML text.
+    /// Attachments and other kinds of parts are ignored.
+    pub(super) fn text_body(&self) -> Option<TextBody<'a>> {
+        self.find_text_body(0)
+    }
+
+    fn find_text_body(&self, depth: usize) -> Option<TextBody<'a>> {
+        let content_type = self.header("content-type");
+        let content_type = content_type.as_deref().unwrap_or("text/plain");
+        let (mime_type, params) = content_type.split_once(';').unwrap_or((content_type, ""));
+        let mime_type = mime_type.trim();
+
+        let is_attachment = self
+            .header("content-disposition")
+            .is_some_and(|disposition| starts_with_ignore_case(&disposition, "attachment"));
+        if is_attachment {
+            return None;
+        }
+
+        if starts_with_ignore_case(mime_type, "multipart/") {
+            if depth >= MAX_DEPTH {
+                return None;
+            }
+            let boundary = param(params, "boundary")?;
+            let mut bodies = multipart_parts(self.body, &boundary)
+                .filter_map(|part| part.find_text_body(depth + 1));
+            let first = bodies.next()?;
+            if first.is_html && mime_type.eq_ignore_ascii_case("multipart/alternative") {
+                // Alternatives are ordered by preference, but the plain text is easier to split
+                return Some(bodies.find(|body| !body.is_html).unwrap_or(first));
+            }
+            return Some(first);
+        }
+
+        let is_html = mime_type.eq_ignore_ascii_case("text/html");
+        if !is_html && !mime_type.eq_ignore_ascii_case("text/plain") {
+            return None;
+        }
+        let charset = param(params, "charset");
+        let encoding = self.header("content-transfer-encoding");
+        let bytes = match encoding.as_deref().map(str::trim) {
+            Some(encoding) if encoding.eq_ignore_ascii_case("base64") => decode_base64(self.body),
+            Some(encoding) if encoding.eq_ignore_ascii_case("quoted-printable") => {
+                decode_quoted_printable(self.body)
+            }
+            _ => {
+                return Some(TextBody {
+                    text: Cow::Borrowed(self.body),
+                    is_html,
+                });
+            }
+        };
+        Some(TextBody {
+            text: Cow::Owned(decode_charset(&bytes, charset.as_deref())),
+            is_html,
+        })
+    }
+}
+
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    text.get(..prefix.len())
+        .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+}
+
+// A parameter of a header, e.g. `boundary` of `multipart/mixed; boundary="abc"`.
+fn param(params: &str, name: &str) -> Option<String> {
+    params.split(';').find_map(|param| {
+        let (key, value) = param.split_once('=')?;
+        key.trim()
+            .eq_ignore_ascii_case(name)
+            .then(|| String::from(value.trim().trim_matches('"')))
+    })
+}
+
+// Parts of a multipart body: the ones between `--boundary` lines, until `--boundary--`.
+fn multipart_parts<'a>(body: &'a str, boundary: &str) -> impl Iterator<Item = Part<'a>> {
+    let mut parts = Vec::new();
+    let mut part_start = None;
+    let mut pos = 0;
+    for line in body.split_inclusive('\n') {
+        let content = line.trim_end();
+        if let Some(delimiter) = content.strip_prefix("--")
+            && let Some(rest) = delimiter.strip_prefix(boundary)
+        {
+            if let Some(start) = part_start {
+                parts.push(&body[start..pos]);
+            }
+            if rest.starts_with("--") {
+                part_start = None;
+                break;
+            }
+            part_start = Some(pos + line.len());
+        }
+        pos += line.len();
+    }
+    if let Some(start) = part_start {
+        parts.push(&body[start..]);
+    }
+    parts.into_iter().map(|part| {
+        let (headers, body) = split_headers(part);
+        Part { headers, body }
+    })
+}
+
+fn decode_base64(text: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(text.len() / 4 * 3);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for b in text.bytes() {
+        let value = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            _ => continue,
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    bytes
+}
+
+fn decode_quoted_printable(text: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&b, tail)) = rest.split_first() {
+        rest = tail;
+        if b != b'=' {
+            bytes.push(b);
+            continue;
+        }
+        // A soft line break
+        if let Some(tail) = rest
+            .strip_prefix(b"\r\n")
+            .or_else(|| rest.strip_prefix(b"\n"))
+        {
+            rest = tail;
+            continue;
+        }
+        let hex = rest.get(..2).and_then(|hex| core::str::from_utf8(hex).ok());
+        match hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+            Some(decoded) => {
+                bytes.push(decoded);
+                rest = &rest[2..];
+            }
+            None => bytes.push(b),
+        }
+    }
+    bytes
+}
+
+// Characters of Windows-1252 in place of the C1 controls of ISO 8859-1.
+const WINDOWS_1252: [char; 32] = [
+    '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8d}', 'Ž', '\u{8f}',
+    '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9d}', 'ž', 'Ÿ',
+];
+
+// UTF-8 and Western European single-byte charsets are supported, texts in other charsets
+// are decoded as UTF-8 with replacement characters.
+fn decode_charset(bytes: &[u8], charset: Option<&str>) -> String {
+    let is_single_byte = charset.is_some_and(|charset| {
+        [
+            "iso-8859-1",
+            "iso-8859-15",
+            "latin1",
+            "windows-1252",
+            "cp1252",
+        ]
+        .iter()
+        .any(|name| name.eq_ignore_ascii_case(charset))
+    });
+    if is_single_byte {
+        bytes
+            .iter()
+            .map(|&b| match b {
+                0x80..=0x9F => WINDOWS_1252[b as usize - 0x80],
+                _ => b as char,
+            })
+            .collect()
+    } else {
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text_body(message: &str) -> Option<(String, bool)> {
+        parse_message(message)
+            .text_body()
+            .map(|body| (body.text.into_owned(), body.is_html))
+    }
+
+    #[test]
+    fn test_plain_text() {
+        assert_eq!(
+            text_body("Note: no headers\n\nHello"),
+            Some((String::from("Note: no headers\n\nHello"), false))
+        );
+        assert_eq!(
+            text_body("From: anna@example.com\r\nSubject: Hi\r\n\r\nHello\r\n"),
+            Some((String::from("Hello\r\n"), false))
+        );
+        assert_eq!(text_body("Subject: Hi"), Some((String::new(), false)));
+    }
+
+    #[test]
+    fn test_multipart() {
+        let message = "From: anna@example.com\n\
+            Content-Type: multipart/mixed;\n \
+            boundary=\"outer\"\n\
+            \n\
+            This is a multi-part message in MIME format.\n\
+            --outer\n\
+            Content-Type: multipart/alternative; boundary=inner\n\
+            \n\
+            --inner\n\
+            Content-Type: text/html; charset=utf-8\n\
+            \n\
+            <p>Hallo</p>\n\
+            --inner\n\
+            Content-Type: text/plain; charset=\"iso-8859-1\"\n\
+            Content-Transfer-Encoding: quoted-printable\n\
+            \n\
+            Gr=FC=DFe, das ist eine sehr lange Zeile, die umbro=\n\
+            chen wurde =93hier=94\n\
+            --inner--\n\
+            --outer\n\
+            Content-Type: text/plain\n\
+            Content-Disposition: attachment; filename=notes.txt\n\
+            \n\
+            Attachment\n\
+            --outer--\n";
+        assert_eq!(
+            text_body(message),
+            Some((
+                String::from("Grüße, das ist eine sehr lange Zeile, die umbrochen wurde “hier”\n"),
+                false
+            ))
+        );
+    }
+
+    #[test]
+    fn test_html_only() {
+        let message = "Content-Type: multipart/alternative; boundary=b\n\
+            \n\
+            --b\n\
+            Content-Type: text/html\n\
+            Content-Transfer-Encoding: base64\n\
+            \n\
+            PHA+R3LDvMOfZTwvcD4=\n\
+            --b--\n";
+        assert_eq!(
+            text_body(message),
+            Some((String::from("<p>Grüße</p>"), true))
+        );
+    }
+
+    #[test]
+    fn test_no_text() {
+        let message =
+            "Content-Type: image/png\nContent-Transfer-Encoding: base64\n\niVBORw0KGgo=\n";
+        assert_eq!(text_body(message), None);
+        let message = "Content-Type: multipart/mixed\n\n--b\n\nText\n--b--\n";
+        assert_eq!(text_body(message), None);
+    }
+
+    #[test]
+    fn test_decode_base64() {
+        assert_eq!(decode_base64("SGVsbG8sIFdvcmxkIQ=="), b"Hello, World!");
+        assert_eq!(decode_base64("SGVs\r\nbG8="), b"Hello");
+        assert_eq!(decode_base64(""), b"");
+    }
+
+    #[test]
+    fn test_decode_quoted_printable() {
+        assert_eq!(decode_quoted_printable("a=3Db=\r\nc=ZZ="), b"a=bc=ZZ=");
+        assert_eq!(decode_quoted_printable("=C3=BC"), "ü".as_bytes());
+    }
+}
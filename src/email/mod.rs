@@ -0,0 +1,138 @@
+mod body;
+mod mime;
+
+use crate::Info;
+use crate::markup::InputMode;
+use alloc::string::String;
+
+// Elements of HTML messages, which start the quoted history: quotes of Apple Mail and Thunderbird,
+// the quote of Gmail and the header of Outlook.
+const HTML_QUOTE_MARKERS: [&str; 4] = [
+    "<blockquote",
+    "gmail_quote",
+    "divrplyfwdmsg",
+    "moz-cite-prefix",
+];
+
+/// An e-mail message split into the new content written by the author and the quoted history.
+///
+/// Plain-text messages and simple MIME messages are supported: the plain-text part is used,
+/// or the HTML part if there is no plain-text one. Base64 and quoted-printable encodings are
+/// decoded, as well as UTF-8 and Western European single-byte charsets. Attachments are ignored.
+///
+/// Quoted lines (`> ...`), attribution lines (`On ... wrote:`, `Am ... schrieb ...:`, etc.),
+/// quoted messages of Outlook and forwarded messages belong to the quoted history.
+/// Signatures after the `-- ` delimiter, signatures of mail apps (`Sent from my iPhone`)
+/// and trailing disclaimers are dropped.
+///
+/// # Example
+/// ```
+/// use whatlang::Email;
+///
+/// let message = "From: bernd@example.com\n\
+///     Subject: Re: Invoice\n\
+///     \n\
+///     Danke, die Rechnung ist angekommen.\n\
+///     \n\
+///     On Mon, 3 Jun 2024, Anna <anna@example.com> wrote:\n\
+///     > Please find the invoice attached.\n\
+///     \n\
+///     -- \n\
+///     Bernd Müller, Example GmbH\n";
+/// let email = Email::parse(message);
+/// assert_eq!(email.content().trim(), "Danke, die Rechnung ist angekommen.");
+/// assert_eq!(email.quoted().trim(), "Please find the invoice attached.");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Email {
+    content: String,
+    quoted: String,
+}
+
+impl Email {
+    pub fn parse(message: &str) -> Email {
+        let mut email = Email::default();
+        let Some(body) = mime::parse_message(message).text_body() else {
+            return email;
+        };
+        if body.is_html {
+            email.split_html(&body.text);
+        } else {
+            body::split(&body.text, &mut email.content, &mut email.quoted);
+        }
+        email
+    }
+
+    fn split_html(&mut self, html: &str) {
+        let lowercase = html.to_ascii_lowercase();
+        let quote_start = HTML_QUOTE_MARKERS
+            .iter()
+            .filter_map(|marker| {
+                let pos = lowercase.find(marker)?;
+                // The start of the tag with the marker in an attribute
+                Some(lowercase[..=pos].rfind('<').unwrap_or(pos))
+            })
+            .min()
+            .unwrap_or(html.len());
+        let (new, history) = html.split_at(quote_start);
+        let new = InputMode::Html.extract(new);
+        body::split(new.text(), &mut self.content, &mut self.quoted);
+        let history = InputMode::Html.extract(history);
+        body::push_history(history.text().lines(), &mut self.quoted);
+    }
+
+    /// The new content written by the author.
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// The quoted history: earlier messages of the thread and forwarded messages.
+    pub fn quoted(&self) -> &str {
+        &self.quoted
+    }
+}
+
+/// Languages of an e-mail message, see [Detector::detect_email](crate::Detector::detect_email).
+#[derive(Debug, PartialEq)]
+pub struct EmailInfo {
+    pub(crate) content: Option<Info>,
+    pub(crate) quoted: Option<Info>,
+}
+
+impl EmailInfo {
+    /// The language of the new content written by the author.
+    pub fn content(&self) -> Option<&Info> {
+        self.content.as_ref()
+    }
+
+    /// The language of the quoted history.
+    pub fn quoted(&self) -> Option<&Info> {
+        self.quoted.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plain_text() {
+        let email = Email::parse("Hallo!\n\n> Hello!\n");
+        assert_eq!(email.content(), "Hallo!\n");
+        assert_eq!(email.quoted(), "Hello!\n");
+        assert_eq!(Email::parse(""), Email::default());
+    }
+
+    #[test]
+    fn test_parse_html() {
+        let message = "Content-Type: text/html; charset=utf-8\n\
+            \n\
+            <div dir=\"ltr\">Danke f&uuml;r die Info!</div><br>\
+            <div class=\"gmail_quote\"><div class=\"gmail_attr\">On Mon, Jun 3, 2024 at 10:00 AM \
+            Anna &lt;anna@example.com&gt; wrote:<br></div>\
+            <blockquote class=\"gmail_quote\">The meeting is moved to Friday.</blockquote></div>";
+        let email = Email::parse(message);
+        assert_eq!(email.content().trim(), "Danke für die Info!");
+        assert_eq!(email.quoted().trim(), "The meeting is moved to Friday.");
+    }
+}
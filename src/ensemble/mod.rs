@@ -0,0 +1,155 @@
+//! Detection method that blends scores of user-defined set of scorers.
+//! See [crate::Ensemble].
+
+use crate::Lang;
+use crate::core::{Ensemble, Info, InternalQuery, Scores, calculate_confidence};
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[derive(Debug)]
+pub struct RawOutcome {
+    pub count: usize,
+    pub scores: Vec<(Lang, f64)>,
+    #[allow(dead_code)]
+    pub scorer_outcomes: Vec<Scores>,
+}
+
+pub fn detect(iquery: &InternalQuery, ensemble: &Ensemble) -> Option<Info> {
+    let raw_outcome = raw_detect(iquery, ensemble);
+
+    let count = raw_outcome.count;
+    let mut normalized_scores_iter = raw_outcome.scores.into_iter();
+
+    let opt_lang_score1 = normalized_scores_iter.next();
+    let opt_lang_score2 = normalized_scores_iter.next();
+
+    opt_lang_score1.map(|(lang1, score1)| {
+        let script = iquery.multi_lang_script.to_script();
+        let confidence = if let Some((_, score2)) = opt_lang_score2 {
+            calculate_confidence(score1, score2, count)
+        } else {
+            1.0
+        };
+        Info::new(script, lang1, confidence)
+    })
+}
+
+pub fn raw_detect(iquery: &InternalQuery, ensemble: &Ensemble) -> RawOutcome {
+    let script = iquery.multi_lang_script.to_script();
+    let members = ensemble.members();
+
+    let scorer_outcomes: Vec<Scores> = members
+        .iter()
+        .map(|(scorer, _)| scorer.score(iquery.text, script, iquery.filter_list))
+        .collect();
+
+    // Scores are trusted as much as the scorer with the most evidence.
+    let count = scorer_outcomes.iter().map(|s| s.count()).max().unwrap_or(0);
+    let total_weight: f64 = members.iter().map(|(_, weight)| weight).sum();
+
+    let mut scores: Vec<(Lang, f64)> = if total_weight > 0.0 {
+        script
+            .langs()
+            .iter()
+            .filter(|&&lang| iquery.filter_list.is_allowed(lang))
+            .map(|&lang| {
+                let weighted_sum: f64 = members
+                    .iter()
+                    .zip(scorer_outcomes.iter())
+                    .map(|((_, weight), outcome)| weight * outcome.get(lang).clamp(0.0, 1.0))
+                    .sum();
+                (lang, weighted_sum / total_weight)
+            })
+            .collect()
+    } else {
+        vec![]
+    };
+
+    scores.sort_unstable_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(core::cmp::Ordering::Less));
+
+    RawOutcome {
+        count,
+        scores,
+        scorer_outcomes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{AlphabetMode, FilterList, Scorer, Text, WeightCurve};
+    use crate::scripts::grouping::MultiLangScript;
+    use crate::{AlphabetScorer, Script, TrigramScorer};
+
+    #[derive(Debug)]
+    struct FixedScorer(Lang, usize);
+
+    impl Scorer for FixedScorer {
+        fn score(&self, _text: &Text, _script: Script, _filter_list: &FilterList) -> Scores {
+            Scores::new(vec![(self.0, 1.0)], self.1)
+        }
+    }
+
+    fn build_iquery<'a, 'b>(
+        text: &'b Text<'a>,
+        filter_list: &'b FilterList,
+    ) -> InternalQuery<'a, 'b> {
+        InternalQuery {
+            text,
+            filter_list,
+            multi_lang_script: MultiLangScript::Latin,
+            alphabet_weight_curve: WeightCurve::default(),
+            alphabet_mode: AlphabetMode::default(),
+            tolerate_foreign_words: false,
+        }
+    }
+
+    #[test]
+    fn test_weighted_average() {
+        let filter_list = FilterList::default();
+        let text = Text::new("Hello");
+        let iquery = build_iquery(&text, &filter_list);
+        let ensemble = Ensemble::new()
+            .add(FixedScorer(Lang::Eng, 5), 3.0)
+            .add(FixedScorer(Lang::Deu, 20), 1.0);
+
+        let outcome = raw_detect(&iquery, &ensemble);
+        assert_eq!(outcome.count, 20);
+        assert_eq!(outcome.scores[0], (Lang::Eng, 0.75));
+        assert_eq!(outcome.scores[1], (Lang::Deu, 0.25));
+        assert_eq!(outcome.scores.len(), Script::Latin.langs().len());
+    }
+
+    #[test]
+    fn test_respects_filter_list() {
+        let filter_list = FilterList::deny(vec![Lang::Eng]);
+        let text = Text::new("Hello");
+        let iquery = build_iquery(&text, &filter_list);
+        let ensemble = Ensemble::new().add(FixedScorer(Lang::Eng, 5), 1.0);
+
+        let outcome = raw_detect(&iquery, &ensemble);
+        assert!(outcome.scores.iter().all(|(lang, _)| *lang != Lang::Eng));
+    }
+
+    #[test]
+    fn test_empty_ensemble() {
+        let filter_list = FilterList::default();
+        let text = Text::new("Hello");
+        let iquery = build_iquery(&text, &filter_list);
+        assert_eq!(detect(&iquery, &Ensemble::new()), None);
+    }
+
+    #[test]
+    fn test_builtin_scorers() {
+        let filter_list = FilterList::default();
+        let text = Text::new("Die Ordnung muss für immer in diesem Codebase bleiben");
+        let iquery = build_iquery(&text, &filter_list);
+        let ensemble = Ensemble::new()
+            .add(AlphabetScorer::default(), 1.0)
+            .add(TrigramScorer, 1.0);
+
+        let info = detect(&iquery, &ensemble).unwrap();
+        assert_eq!(info.lang(), Lang::Deu);
+        assert_eq!(info.script(), Script::Latin);
+    }
+}
@@ -1,5 +1,8 @@
-use std::error::Error as StdError;
-use std::fmt::{self, Display};
+use crate::Lang;
+use crate::catalog::CatalogFormat;
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{self, Display};
 
 #[derive(Debug)]
 pub enum ParseError {
@@ -24,4 +27,77 @@ impl Display for ParseError {
     }
 }
 
-impl StdError for ParseError {}
+impl Error for ParseError {}
+
+/// Error of an allowlist naming a language that is excluded from the build by cargo features,
+/// see [Lang::is_supported].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedLangError {
+    lang: Lang,
+}
+
+impl UnsupportedLangError {
+    pub(crate) fn new(lang: Lang) -> Self {
+        Self { lang }
+    }
+
+    /// The excluded language.
+    pub fn lang(&self) -> Lang {
+        self.lang
+    }
+}
+
+impl Display for UnsupportedLangError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}) is not supported: whatlang is built without feature \"lang-{}\"",
+            self.lang.eng_name(),
+            self.lang.code(),
+            self.lang.code()
+        )
+    }
+}
+
+impl Error for UnsupportedLangError {}
+
+/// Error of a message catalog, which can not be parsed, see [Catalog::parse](crate::Catalog::parse).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CatalogError {
+    format: CatalogFormat,
+    line: usize,
+    message: &'static str,
+}
+
+impl CatalogError {
+    pub(crate) fn new(format: CatalogFormat, line: usize, message: &'static str) -> Self {
+        Self {
+            format,
+            line,
+            message,
+        }
+    }
+
+    pub fn format(&self) -> CatalogFormat {
+        self.format
+    }
+
+    /// The line of the error, starting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Cannot parse {} catalog, line {}: {}",
+            self.format.name(),
+            self.line,
+            self.message
+        )
+    }
+}
+
+impl Error for CatalogError {}
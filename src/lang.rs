@@ -2,8 +2,9 @@
 //    This file is generated automatically.
 //    Edit misc/lang.rs.erb template instead of editing lang.rs file directly.
 
-use std::fmt;
-use std::str::FromStr;
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
 
 use crate::error::ParseError;
 
@@ -301,6 +302,22 @@ const VALUES: [Lang; 70] = [
     Lang::Cym,
 ];
 
+/// Number of languages, e.g. to size arrays indexed by `Lang as usize`.
+pub(crate) const LANGS_COUNT: usize = VALUES.len();
+
+/// Languages included by cargo features, as a set of bits `1 << lang as usize`.
+pub(crate) const SUPPORTED_LANGS: u128 = {
+    let mut set = 0;
+    let mut i = 0;
+    while i < LANGS_COUNT {
+        if lang_is_supported(VALUES[i]) {
+            set |= 1 << VALUES[i] as usize;
+        }
+        i += 1;
+    }
+    set
+};
+
 fn lang_from_code<S: Into<String>>(code: S) -> Option<Lang> {
     match code.into().to_lowercase().as_ref() {
         "epo" => Some(Lang::Epo),
@@ -377,6 +394,116 @@ fn lang_from_code<S: Into<String>>(code: S) -> Option<Lang> {
     }
 }
 
+fn lang_from_iso_639_1(code: &str) -> Option<Lang> {
+    match code {
+        "eo" => Some(Lang::Epo),
+        "en" => Some(Lang::Eng),
+        "ru" => Some(Lang::Rus),
+        "zh" => Some(Lang::Cmn),
+        "es" => Some(Lang::Spa),
+        "pt" => Some(Lang::Por),
+        "it" => Some(Lang::Ita),
+        "bn" => Some(Lang::Ben),
+        "fr" => Some(Lang::Fra),
+        "de" => Some(Lang::Deu),
+        "uk" => Some(Lang::Ukr),
+        "ka" => Some(Lang::Kat),
+        "ar" => Some(Lang::Ara),
+        "hi" => Some(Lang::Hin),
+        "ja" => Some(Lang::Jpn),
+        "he" => Some(Lang::Heb),
+        "yi" => Some(Lang::Yid),
+        "pl" => Some(Lang::Pol),
+        "am" => Some(Lang::Amh),
+        "jv" => Some(Lang::Jav),
+        "ko" => Some(Lang::Kor),
+        "nb" => Some(Lang::Nob),
+        "da" => Some(Lang::Dan),
+        "sv" => Some(Lang::Swe),
+        "fi" => Some(Lang::Fin),
+        "tr" => Some(Lang::Tur),
+        "nl" => Some(Lang::Nld),
+        "hu" => Some(Lang::Hun),
+        "cs" => Some(Lang::Ces),
+        "el" => Some(Lang::Ell),
+        "bg" => Some(Lang::Bul),
+        "be" => Some(Lang::Bel),
+        "mr" => Some(Lang::Mar),
+        "kn" => Some(Lang::Kan),
+        "ro" => Some(Lang::Ron),
+        "sl" => Some(Lang::Slv),
+        "hr" => Some(Lang::Hrv),
+        "sr" => Some(Lang::Srp),
+        "mk" => Some(Lang::Mkd),
+        "lt" => Some(Lang::Lit),
+        "lv" => Some(Lang::Lav),
+        "et" => Some(Lang::Est),
+        "ta" => Some(Lang::Tam),
+        "vi" => Some(Lang::Vie),
+        "ur" => Some(Lang::Urd),
+        "th" => Some(Lang::Tha),
+        "gu" => Some(Lang::Guj),
+        "uz" => Some(Lang::Uzb),
+        "pa" => Some(Lang::Pan),
+        "az" => Some(Lang::Aze),
+        "id" => Some(Lang::Ind),
+        "te" => Some(Lang::Tel),
+        "fa" => Some(Lang::Pes),
+        "ml" => Some(Lang::Mal),
+        "or" => Some(Lang::Ori),
+        "my" => Some(Lang::Mya),
+        "ne" => Some(Lang::Nep),
+        "si" => Some(Lang::Sin),
+        "km" => Some(Lang::Khm),
+        "tk" => Some(Lang::Tuk),
+        "ak" => Some(Lang::Aka),
+        "zu" => Some(Lang::Zul),
+        "sn" => Some(Lang::Sna),
+        "af" => Some(Lang::Afr),
+        "la" => Some(Lang::Lat),
+        "sk" => Some(Lang::Slk),
+        "ca" => Some(Lang::Cat),
+        "tl" => Some(Lang::Tgl),
+        "hy" => Some(Lang::Hye),
+        "cy" => Some(Lang::Cym),
+        _ => None,
+    }
+}
+
+// Codes of locales, which are neither ISO 639-1 nor ISO 639-3 codes of the languages:
+// deprecated ISO 639-1 codes, ISO 639-2/B codes, macrolanguages and their individual languages.
+fn lang_from_locale_alias(code: &str) -> Option<Lang> {
+    match code {
+        "iw" => Some(Lang::Heb),
+        "ji" => Some(Lang::Yid),
+        "in" => Some(Lang::Ind),
+        "mo" | "rum" => Some(Lang::Ron),
+        "no" | "nor" => Some(Lang::Nob),
+        "zho" | "chi" => Some(Lang::Cmn),
+        "fas" | "per" => Some(Lang::Pes),
+        "arb" => Some(Lang::Ara),
+        "azj" => Some(Lang::Aze),
+        "ekk" => Some(Lang::Est),
+        "lvs" => Some(Lang::Lav),
+        "npi" => Some(Lang::Nep),
+        "ory" => Some(Lang::Ori),
+        "uzn" => Some(Lang::Uzb),
+        "fil" => Some(Lang::Tgl),
+        "arm" => Some(Lang::Hye),
+        "bur" => Some(Lang::Mya),
+        "cze" => Some(Lang::Ces),
+        "dut" => Some(Lang::Nld),
+        "fre" => Some(Lang::Fra),
+        "geo" => Some(Lang::Kat),
+        "ger" => Some(Lang::Deu),
+        "gre" => Some(Lang::Ell),
+        "mac" => Some(Lang::Mkd),
+        "slo" => Some(Lang::Slk),
+        "wel" => Some(Lang::Cym),
+        _ => None,
+    }
+}
+
 fn lang_to_code(lang: Lang) -> &'static str {
     match lang {
         Lang::Epo => "epo",
@@ -602,6 +729,81 @@ fn lang_to_eng_name(lang: Lang) -> &'static str {
     }
 }
 
+const fn lang_is_supported(lang: Lang) -> bool {
+    match lang {
+        Lang::Epo => cfg!(feature = "lang-epo"),
+        Lang::Eng => cfg!(feature = "lang-eng"),
+        Lang::Rus => cfg!(feature = "lang-rus"),
+        Lang::Cmn => cfg!(feature = "lang-cmn"),
+        Lang::Spa => cfg!(feature = "lang-spa"),
+        Lang::Por => cfg!(feature = "lang-por"),
+        Lang::Ita => cfg!(feature = "lang-ita"),
+        Lang::Ben => cfg!(feature = "lang-ben"),
+        Lang::Fra => cfg!(feature = "lang-fra"),
+        Lang::Deu => cfg!(feature = "lang-deu"),
+        Lang::Ukr => cfg!(feature = "lang-ukr"),
+        Lang::Kat => cfg!(feature = "lang-kat"),
+        Lang::Ara => cfg!(feature = "lang-ara"),
+        Lang::Hin => cfg!(feature = "lang-hin"),
+        Lang::Jpn => cfg!(feature = "lang-jpn"),
+        Lang::Heb => cfg!(feature = "lang-heb"),
+        Lang::Yid => cfg!(feature = "lang-yid"),
+        Lang::Pol => cfg!(feature = "lang-pol"),
+        Lang::Amh => cfg!(feature = "lang-amh"),
+        Lang::Jav => cfg!(feature = "lang-jav"),
+        Lang::Kor => cfg!(feature = "lang-kor"),
+        Lang::Nob => cfg!(feature = "lang-nob"),
+        Lang::Dan => cfg!(feature = "lang-dan"),
+        Lang::Swe => cfg!(feature = "lang-swe"),
+        Lang::Fin => cfg!(feature = "lang-fin"),
+        Lang::Tur => cfg!(feature = "lang-tur"),
+        Lang::Nld => cfg!(feature = "lang-nld"),
+        Lang::Hun => cfg!(feature = "lang-hun"),
+        Lang::Ces => cfg!(feature = "lang-ces"),
+        Lang::Ell => cfg!(feature = "lang-ell"),
+        Lang::Bul => cfg!(feature = "lang-bul"),
+        Lang::Bel => cfg!(feature = "lang-bel"),
+        Lang::Mar => cfg!(feature = "lang-mar"),
+        Lang::Kan => cfg!(feature = "lang-kan"),
+        Lang::Ron => cfg!(feature = "lang-ron"),
+        Lang::Slv => cfg!(feature = "lang-slv"),
+        Lang::Hrv => cfg!(feature = "lang-hrv"),
+        Lang::Srp => cfg!(feature = "lang-srp"),
+        Lang::Mkd => cfg!(feature = "lang-mkd"),
+        Lang::Lit => cfg!(feature = "lang-lit"),
+        Lang::Lav => cfg!(feature = "lang-lav"),
+        Lang::Est => cfg!(feature = "lang-est"),
+        Lang::Tam => cfg!(feature = "lang-tam"),
+        Lang::Vie => cfg!(feature = "lang-vie"),
+        Lang::Urd => cfg!(feature = "lang-urd"),
+        Lang::Tha => cfg!(feature = "lang-tha"),
+        Lang::Guj => cfg!(feature = "lang-guj"),
+        Lang::Uzb => cfg!(feature = "lang-uzb"),
+        Lang::Pan => cfg!(feature = "lang-pan"),
+        Lang::Aze => cfg!(feature = "lang-aze"),
+        Lang::Ind => cfg!(feature = "lang-ind"),
+        Lang::Tel => cfg!(feature = "lang-tel"),
+        Lang::Pes => cfg!(feature = "lang-pes"),
+        Lang::Mal => cfg!(feature = "lang-mal"),
+        Lang::Ori => cfg!(feature = "lang-ori"),
+        Lang::Mya => cfg!(feature = "lang-mya"),
+        Lang::Nep => cfg!(feature = "lang-nep"),
+        Lang::Sin => cfg!(feature = "lang-sin"),
+        Lang::Khm => cfg!(feature = "lang-khm"),
+        Lang::Tuk => cfg!(feature = "lang-tuk"),
+        Lang::Aka => cfg!(feature = "lang-aka"),
+        Lang::Zul => cfg!(feature = "lang-zul"),
+        Lang::Sna => cfg!(feature = "lang-sna"),
+        Lang::Afr => cfg!(feature = "lang-afr"),
+        Lang::Lat => cfg!(feature = "lang-lat"),
+        Lang::Slk => cfg!(feature = "lang-slk"),
+        Lang::Cat => cfg!(feature = "lang-cat"),
+        Lang::Tgl => cfg!(feature = "lang-tgl"),
+        Lang::Hye => cfg!(feature = "lang-hye"),
+        Lang::Cym => cfg!(feature = "lang-cym"),
+    }
+}
+
 impl Lang {
     /// Get enum by ISO 639-3 code as a string.
     ///
@@ -614,6 +816,32 @@ impl Lang {
         lang_from_code(code)
     }
 
+    /// Get enum by a language tag of a locale: a [BCP 47](https://www.rfc-editor.org/info/bcp47)
+    /// tag (e.g. `de`, `pt-BR`, `zh-Hant-TW`) or a POSIX locale name (e.g. `pt_BR.UTF-8`).
+    /// Only the language subtag is taken into account, it can be an ISO 639-1 or ISO 639-3 code.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::Lang;
+    /// assert_eq!(Lang::from_locale("uk"), Some(Lang::Ukr));
+    /// assert_eq!(Lang::from_locale("pt-BR"), Some(Lang::Por));
+    /// assert_eq!(Lang::from_locale("de_AT.UTF-8"), Some(Lang::Deu));
+    /// assert_eq!(Lang::from_locale("tlh"), None);
+    /// ```
+    pub fn from_locale(tag: &str) -> Option<Lang> {
+        let subtag = tag.trim().split(['-', '_', '.', '@']).next()?;
+        if !matches!(subtag.len(), 2 | 3) || !subtag.is_ascii() {
+            return None;
+        }
+        let mut code = [0; 3];
+        code[..subtag.len()].copy_from_slice(subtag.as_bytes());
+        code.make_ascii_lowercase();
+        let code = core::str::from_utf8(&code[..subtag.len()]).ok()?;
+        lang_from_iso_639_1(code)
+            .or_else(|| lang_from_locale_alias(code))
+            .or_else(|| lang_from_code(code))
+    }
+
     /// Convert enum into ISO 639-3 code as a string.
     ///
     /// # Example
@@ -647,6 +875,19 @@ impl Lang {
         lang_to_eng_name(self)
     }
 
+    /// Whether the language is supported by the build: every language can be excluded
+    /// with cargo features (see `lang-*` features, e.g. `lang-ukr`) to reduce the binary size.
+    /// Unsupported languages are never detected.
+    ///
+    /// # Example
+    /// ```
+    /// use whatlang::Lang;
+    /// assert!(Lang::Ukr.is_supported());
+    /// ```
+    pub fn is_supported(self) -> bool {
+        lang_is_supported(self)
+    }
+
     /// Get all existing languages.
     ///
     /// # Example
@@ -687,6 +928,34 @@ mod tests {
         assert_eq!(Lang::from_code("oops"), None);
     }
 
+    #[test]
+    fn test_from_locale() {
+        assert_eq!(Lang::from_locale("en"), Some(Lang::Eng));
+        assert_eq!(Lang::from_locale("en-GB"), Some(Lang::Eng));
+        assert_eq!(Lang::from_locale("EN_us"), Some(Lang::Eng));
+        assert_eq!(Lang::from_locale("sr-Latn-RS"), Some(Lang::Srp));
+        assert_eq!(Lang::from_locale("zh-Hans"), Some(Lang::Cmn));
+        assert_eq!(Lang::from_locale("ca_ES@valencia"), Some(Lang::Cat));
+        assert_eq!(Lang::from_locale("no"), Some(Lang::Nob));
+        assert_eq!(Lang::from_locale("fil-PH"), Some(Lang::Tgl));
+        assert_eq!(Lang::from_locale("iw"), Some(Lang::Heb));
+        assert_eq!(Lang::from_locale("ukr"), Some(Lang::Ukr));
+        assert_eq!(Lang::from_locale(" fr "), Some(Lang::Fra));
+
+        assert_eq!(Lang::from_locale(""), None);
+        assert_eq!(Lang::from_locale("C"), None);
+        assert_eq!(Lang::from_locale("POSIX"), None);
+        assert_eq!(Lang::from_locale("x-klingon"), None);
+        assert_eq!(Lang::from_locale("ру"), None);
+    }
+
+    #[test]
+    fn test_from_locale_supports_every_lang() {
+        for &lang in Lang::all() {
+            assert_eq!(Lang::from_locale(lang.code()), Some(lang));
+        }
+    }
+
     #[test]
     fn test_code() {
         assert_eq!(Lang::Spa.code(), "spa");
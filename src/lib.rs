@@ -36,16 +36,31 @@
 //!
 //! | Feature     | Description                                                                           |
 //! |-------------|---------------------------------------------------------------------------------------|
+//! | `std`       | Enabled by default. Without it the crate is `no_std` and requires only `alloc`.<br/> Functions without a `DetectorScratch` (e.g. `detect()`) then allocate buffers on every call |
+//! | `all-langs` | Enabled by default. Includes all the languages, same as enabling every `script-*` feature |
+//! | `script-*`  | Includes all the languages of a script, e.g. `script-latin`, `script-cyrillic`, `script-arabic` |
+//! | `lang-*`    | Includes a single language by its code, e.g. `lang-eng`, `lang-ukr`.<br/> Data of the excluded languages is not compiled in, they are never detected and `Lang::is_supported()` returns `false` for them |
 //! | `enum-map`  | `Lang` and `Script` implement `Enum` trait from [enum-map](https://docs.rs/enum-map/) |
 //! | `arbitrary` | Support [Arbitrary](https://crates.io/crates/arbitrary)                               |
 //! | `serde`     | Implements `Serialize` and `Deserialize` for `Lang` and `Script`                      |
+//! | `rayon`     | Enables `Detector::par_detect_batch()` to detect languages of texts in parallel       |
 //! | `dev`       | Enables `whatlang::dev` module which provides some internal API.<br/> It exists for profiling purposes and normal users are discouraged to to rely on this API.  |
 //!
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 mod alphabets;
+mod catalog;
 mod combined;
+mod comments;
 mod core;
+mod email;
+mod ensemble;
 mod error;
 mod lang;
+mod markup;
+mod normalization;
 mod scripts;
 mod trigrams;
 mod utils;
@@ -53,6 +68,25 @@ mod utils;
 #[cfg(feature = "dev")]
 pub mod dev;
 
-pub use crate::core::{Detector, Info, detect, detect_lang};
+pub use crate::alphabets::AlphabetScorer;
+pub use crate::catalog::{Catalog, CatalogEntry, CatalogFormat, CatalogMismatch, CatalogValidator};
+pub use crate::comments::{Comment, CommentExtractor, CommentKind, Syntax};
+pub use crate::core::{
+    AlphabetMode, Coverage, DetectLangs, DetectLangsExt, Detector, DetectorScratch, Ensemble,
+    FilterList, Info, LowercaseText, Options, PreparedText, Preprocessed, Preprocessor,
+    RemovedSpan, Sampling, Scorer, Scores, SpanKind, Text, WeightCurve, detect, detect_lang,
+};
+pub use crate::email::{Email, EmailInfo};
+pub use crate::error::{CatalogError, UnsupportedLangError};
 pub use crate::lang::Lang;
+pub use crate::markup::{Extracted, InputMode};
+pub use crate::normalization::Normalization;
 pub use crate::scripts::{Script, detect_script};
+pub use crate::trigrams::TrigramScorer;
+
+/// Version of Unicode, which the lookup tables are generated from (see `src/scripts/tables.rs`
+/// and `src/normalization/tables.rs`).
+/// The dev-dependencies providing Unicode data to the generators must be of the same version,
+/// every generator checks it.
+#[cfg(test)]
+const UNICODE_VERSION: (u64, u64, u64) = (16, 0, 0);
@@ -0,0 +1,436 @@
+use super::DeclaredLangs;
+use super::entities::ENTITIES;
+use crate::Lang;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+// Elements, which do not separate words, e.g. `<b>W</b>ort`.
+// Other tags are replaced by a line break.
+const INLINE_ELEMENTS: [&str; 27] = [
+    "a", "abbr", "b", "bdi", "bdo", "cite", "data", "del", "dfn", "em", "font", "i", "ins", "kbd",
+    "mark", "q", "rp", "rt", "ruby", "s", "samp", "small", "span", "strong", "sub", "sup", "u",
+];
+
+// Elements, which have no content and no closing tag.
+const VOID_ELEMENTS: [&str; 14] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+// Elements, which content is not a natural-language text.
+const IGNORED_ELEMENTS: [&str; 3] = ["script", "style", "code"];
+
+/// A start or an end tag. Only the `lang` attribute is kept.
+#[derive(Debug, PartialEq)]
+pub(super) struct Tag<'a> {
+    pub(super) name: &'a str,
+    pub(super) is_end: bool,
+    pub(super) is_self_closing: bool,
+    pub(super) lang: Option<&'a str>,
+}
+
+impl Tag<'_> {
+    fn is(&self, names: &[&str]) -> bool {
+        names
+            .iter()
+            .any(|name| self.name.eq_ignore_ascii_case(name))
+    }
+
+    fn opens_element(&self) -> bool {
+        !self.is_end && !self.is_self_closing && !self.is(&VOID_ELEMENTS)
+    }
+}
+
+// Markup starting with `<`.
+#[derive(Debug, PartialEq)]
+pub(super) enum Markup<'a> {
+    Tag(Tag<'a>),
+    // Comments, doctype, processing instructions, etc.
+    Other,
+}
+
+// An element with a declared language, which may contain elements of the same name.
+struct LangScope<'a> {
+    name: &'a str,
+    depth: usize,
+    lang: Option<Lang>,
+}
+
+pub(super) fn extract(html: &str, out: &mut String, declared: &mut DeclaredLangs) {
+    let mut scopes: Vec<LangScope> = Vec::new();
+    let mut text_start = 0;
+    let mut pos = 0;
+    while let Some(offset) = html[pos..].find(['<', '&']) {
+        let at = pos + offset;
+        let lang = scopes.last().and_then(|scope| scope.lang);
+        if html.as_bytes()[at] == b'&' {
+            if let Some((ch, len)) = decode_char_reference(&html[at..]) {
+                push_text(&html[text_start..at], lang, out, declared);
+                let mut buf = [0; 4];
+                push_text(ch.encode_utf8(&mut buf), lang, out, declared);
+                text_start = at + len;
+                pos = text_start;
+            } else {
+                pos = at + 1;
+            }
+            continue;
+        }
+        let Some((markup, end)) = parse_markup(html, at) else {
+            pos = at + 1;
+            continue;
+        };
+        push_text(&html[text_start..at], lang, out, declared);
+        pos = end;
+        match markup {
+            Markup::Tag(tag) => {
+                if !tag.is(&INLINE_ELEMENTS) {
+                    out.push('\n');
+                }
+                if tag.opens_element() && tag.is(&IGNORED_ELEMENTS) {
+                    pos = find_end_tag(html, end, tag.name);
+                } else {
+                    update_scopes(&mut scopes, &tag);
+                }
+            }
+            Markup::Other => out.push(' '),
+        }
+        text_start = pos;
+    }
+    let lang = scopes.last().and_then(|scope| scope.lang);
+    push_text(&html[text_start..], lang, out, declared);
+}
+
+fn push_text(text: &str, lang: Option<Lang>, out: &mut String, declared: &mut DeclaredLangs) {
+    out.push_str(text);
+    declared.add(lang, text);
+}
+
+fn update_scopes<'a>(scopes: &mut Vec<LangScope<'a>>, tag: &Tag<'a>) {
+    if let Some(lang) = tag.lang {
+        if tag.opens_element() {
+            scopes.push(LangScope {
+                name: tag.name,
+                depth: 1,
+                lang: Lang::from_locale(lang),
+            });
+        }
+        return;
+    }
+    let Some(scope) = scopes.last_mut() else {
+        return;
+    };
+    if !scope.name.eq_ignore_ascii_case(tag.name) {
+        return;
+    }
+    if tag.is_end {
+        scope.depth -= 1;
+        if scope.depth == 0 {
+            scopes.pop();
+        }
+    } else if tag.opens_element() {
+        scope.depth += 1;
+    }
+}
+
+/// Parse markup starting with `<` at the position `start`, return it with its end position.
+/// A `<` which does not start any markup (e.g. `a < b`) is a text.
+pub(super) fn parse_markup(html: &str, start: usize) -> Option<(Markup<'_>, usize)> {
+    let rest = &html[start..];
+    if let Some(comment) = rest.strip_prefix("<!--") {
+        let end = comment
+            .find("-->")
+            .map_or(html.len(), |i| start + 4 + i + 3);
+        return Some((Markup::Other, end));
+    }
+    if rest.starts_with("<!") || rest.starts_with("<?") {
+        let end = rest.find('>').map_or(html.len(), |i| start + i + 1);
+        return Some((Markup::Other, end));
+    }
+    parse_tag(html, start).map(|(tag, end)| (Markup::Tag(tag), end))
+}
+
+fn parse_tag(html: &str, start: usize) -> Option<(Tag<'_>, usize)> {
+    let bytes = html.as_bytes();
+    let mut i = start + 1;
+    let is_end = bytes.get(i) == Some(&b'/');
+    if is_end {
+        i += 1;
+    }
+    if !bytes.get(i)?.is_ascii_alphabetic() {
+        return None;
+    }
+    let name_start = i;
+    while i < bytes.len() && !is_tag_name_end(bytes[i]) {
+        i += 1;
+    }
+    let name = &html[name_start..i];
+
+    let mut lang = None;
+    let mut is_self_closing = false;
+    loop {
+        while bytes.get(i)?.is_ascii_whitespace() {
+            i += 1;
+        }
+        match bytes[i] {
+            b'>' => break,
+            b'/' => {
+                is_self_closing = true;
+                i += 1;
+                continue;
+            }
+            _ => is_self_closing = false,
+        }
+
+        let attr_start = i;
+        while i < bytes.len() && !is_tag_name_end(bytes[i]) && bytes[i] != b'=' {
+            i += 1;
+        }
+        let attr = &html[attr_start..i];
+        while bytes.get(i)?.is_ascii_whitespace() {
+            i += 1;
+        }
+        if bytes[i] != b'=' {
+            continue;
+        }
+        i += 1;
+        while bytes.get(i)?.is_ascii_whitespace() {
+            i += 1;
+        }
+        let value = match bytes[i] {
+            quote @ (b'"' | b'\'') => {
+                let value_start = i + 1;
+                i = value_start + html[value_start..].find(quote as char)?;
+                let value = &html[value_start..i];
+                i += 1;
+                value
+            }
+            _ => {
+                let value_start = i;
+                while i < bytes.len() && !bytes[i].is_ascii_whitespace() && bytes[i] != b'>' {
+                    i += 1;
+                }
+                &html[value_start..i]
+            }
+        };
+        if attr.eq_ignore_ascii_case("lang") || attr.eq_ignore_ascii_case("xml:lang") {
+            lang = Some(value);
+        }
+    }
+
+    let tag = Tag {
+        name,
+        is_end,
+        is_self_closing,
+        lang,
+    };
+    Some((tag, i + 1))
+}
+
+fn is_tag_name_end(b: u8) -> bool {
+    b.is_ascii_whitespace() || b == b'/' || b == b'>'
+}
+
+// Position of the end tag of the element, or the end of the document if the element is not closed.
+fn find_end_tag(html: &str, from: usize, name: &str) -> usize {
+    let bytes = html.as_bytes();
+    let mut pos = from;
+    while let Some(offset) = html[pos..].find("</") {
+        let at = pos + offset;
+        let name_end = at + 2 + name.len();
+        let is_name = html
+            .get(at + 2..name_end)
+            .is_some_and(|s| s.eq_ignore_ascii_case(name));
+        if is_name && bytes.get(name_end).is_none_or(|&b| is_tag_name_end(b)) {
+            return at;
+        }
+        pos = at + 2;
+    }
+    html.len()
+}
+
+/// Decode a character reference (e.g. `&amp;`, `&#228;`, `&#xE4;`) at the start of the text,
+/// return the character and the length of the reference.
+pub(crate) fn decode_char_reference(text: &str) -> Option<(char, usize)> {
+    // The longest name of an entity is 8 characters, the longest number is 7 digits
+    let end = text.bytes().take(11).position(|b| b == b';')?;
+    let reference = &text[1..end];
+    let ch = if let Some(number) = reference.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => parse_number(hex, 16)?,
+            None => parse_number(number, 10)?,
+        };
+        char::from_u32(code).filter(|&ch| ch != '\0')?
+    } else {
+        let index = ENTITIES
+            .binary_search_by(|(name, _)| (*name).cmp(reference))
+            .ok()?;
+        ENTITIES[index].1
+    };
+    Some((ch, end + 1))
+}
+
+fn parse_number(digits: &str, radix: u32) -> Option<u32> {
+    if digits.is_empty() || digits.len() > 7 || !digits.chars().all(|ch| ch.is_digit(radix)) {
+        return None;
+    }
+    u32::from_str_radix(digits, radix).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extract_html(html: &str) -> (String, Option<Lang>) {
+        let mut out = String::new();
+        let mut declared = DeclaredLangs::default();
+        extract(html, &mut out, &mut declared);
+        (out, declared.main_lang())
+    }
+
+    fn words(html: &str) -> Vec<String> {
+        let (text, _) = extract_html(html);
+        text.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_entities_are_sorted() {
+        assert!(ENTITIES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn test_extract_text() {
+        assert_eq!(
+            words(
+                r#"<!DOCTYPE html>
+                <html>
+                <head><title>Über uns</title><meta charset="utf-8"></head>
+                <body class="page">
+                  <!-- <p>A comment</p> -->
+                  <p>Wir sind <a href="/team" title="The team">ein Team</a>.</p>
+                </body>
+                </html>"#
+            ),
+            vec!["Über", "uns", "Wir", "sind", "ein", "Team."]
+        );
+    }
+
+    #[test]
+    fn test_inline_elements_do_not_split_words() {
+        assert_eq!(words("<p>Hallo</p><p>Welt</p>"), vec!["Hallo", "Welt"]);
+        assert_eq!(words("Hallo<br>Welt<br/>!"), vec!["Hallo", "Welt", "!"]);
+        assert_eq!(words("<b>W</b>ort"), vec!["Wort"]);
+    }
+
+    #[test]
+    fn test_ignored_elements() {
+        let html = r#"<style>p { color: red; }</style>
+            <p>Ein <code>let x = 1;</code> Beispiel</p>
+            <script type="text/javascript">if (a < b && c) { alert("</p>"); }</script>
+            <SCRIPT>var x;</SCRIPT >Ende
+            <script>unclosed script"#;
+        assert_eq!(words(html), vec!["Ein", "Beispiel", "Ende"]);
+    }
+
+    #[test]
+    fn test_attributes() {
+        assert_eq!(
+            words(r#"<img alt="A picture" src='a.png'> <input value=hello disabled>Text"#),
+            vec!["Text"]
+        );
+        assert_eq!(words(r#"<a title="x > y" href="/">Link</a>"#), vec!["Link"]);
+    }
+
+    #[test]
+    fn test_text_which_is_not_markup() {
+        assert_eq!(words("a < b, c <= d"), vec!["a", "<", "b,", "c", "<=", "d"]);
+        assert_eq!(words("Tom & Jerry"), vec!["Tom", "&", "Jerry"]);
+        assert_eq!(
+            words("<p>unclosed <a href="),
+            vec!["unclosed", "<a", "href="]
+        );
+    }
+
+    #[test]
+    fn test_char_references() {
+        assert_eq!(
+            words("Gr&uuml;&szlig;e &amp; K&#252;sse &#x2764;&#xFE0F; &lt;3"),
+            vec!["Grüße", "&", "Küsse", "❤\u{fe0f}", "<3"]
+        );
+        assert_eq!(words("&nbsp;caf&eacute;&apos;s"), vec!["café's"]);
+        assert_eq!(
+            words("&unknown; &#; &#xZZ; &#0; &#x110000; &amp"),
+            vec!["&unknown;", "&#;", "&#xZZ;", "&#0;", "&#x110000;", "&amp"]
+        );
+    }
+
+    #[test]
+    fn test_decode_char_reference() {
+        assert_eq!(decode_char_reference("&amp;rest"), Some(('&', 5)));
+        assert_eq!(decode_char_reference("&#1093;"), Some(('х', 7)));
+        assert_eq!(decode_char_reference("&#X445;"), Some(('х', 7)));
+        assert_eq!(decode_char_reference("&AMP;"), None);
+        assert_eq!(decode_char_reference("&amp"), None);
+    }
+
+    #[test]
+    fn test_declared_lang() {
+        let (_, lang) = extract_html(
+            r#"<html lang="uk"><body><div><p>Доброго ранку!</p><div>Як справи?</div></div></body></html>"#,
+        );
+        assert_eq!(lang, Some(Lang::Ukr));
+
+        let (_, lang) = extract_html(r#"<html xml:lang="pt-BR"><p>Bom dia</p></html>"#);
+        assert_eq!(lang, Some(Lang::Por));
+
+        // Most of the text is not in the declared language
+        let (_, lang) = extract_html(
+            r#"<p>Good morning, how are you?</p><p lang="de">Guten Morgen!</p><p>Fine.</p>"#,
+        );
+        assert_eq!(lang, None);
+
+        // Nested elements of the same name
+        let (_, lang) = extract_html(
+            r#"<div lang="fr"><div>Bonjour</div><div>tout le monde</div></div><div>Hi</div>"#,
+        );
+        assert_eq!(lang, Some(Lang::Fra));
+        let (_, lang) = extract_html(
+            r#"<div lang="fr"><div>Salut</div></div><div>Good morning everyone</div>"#,
+        );
+        assert_eq!(lang, None);
+
+        // Unknown languages
+        let (_, lang) = extract_html(r#"<html lang="tlh"><p>nuqneH</p></html>"#);
+        assert_eq!(lang, None);
+        let (_, lang) = extract_html(r#"<html lang="en"><p lang="">Hmm</p></html>"#);
+        assert_eq!(lang, None);
+    }
+
+    #[test]
+    fn test_parse_markup() {
+        let tag = |html| parse_markup(html, 0).map(|(markup, _)| markup);
+        assert_eq!(
+            tag(r#"<P Lang="de" class=x />"#),
+            Some(Markup::Tag(Tag {
+                name: "P",
+                is_end: false,
+                is_self_closing: true,
+                lang: Some("de"),
+            }))
+        );
+        assert_eq!(
+            tag("</div >"),
+            Some(Markup::Tag(Tag {
+                name: "div",
+                is_end: true,
+                is_self_closing: false,
+                lang: None,
+            }))
+        );
+        assert_eq!(tag("<!-- x -->"), Some(Markup::Other));
+        assert_eq!(tag("<?xml version=\"1.0\"?>"), Some(Markup::Other));
+        assert_eq!(tag("< p>"), None);
+        assert_eq!(tag("<3"), None);
+        assert_eq!(tag("<p"), None);
+        assert_eq!(tag("<p title=\"x>"), None);
+    }
+}
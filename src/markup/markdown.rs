@@ -0,0 +1,266 @@
+use super::html::{decode_char_reference, parse_markup};
+use alloc::string::String;
+
+pub(super) fn extract(markdown: &str, out: &mut String) {
+    // Start of the text, which is not processed yet
+    let mut text_start = 0;
+    // The character and the length of the opening fence of a code block
+    let mut fence: Option<(u8, usize)> = None;
+    let mut line_start = 0;
+    for line in markdown.split_inclusive('\n') {
+        let line_end = line_start + line.len();
+        let content = strip_indentation(line);
+        match fence {
+            Some((ch, len)) => {
+                let (fence_ch, fence_len) = parse_fence(content).unwrap_or_default();
+                if fence_ch == ch && fence_len >= len && content[fence_len..].trim().is_empty() {
+                    fence = None;
+                    text_start = line_end;
+                }
+            }
+            None => {
+                fence = parse_fence(content);
+                if fence.is_some() || is_link_reference_definition(content) {
+                    extract_inline(&markdown[text_start..line_start], out);
+                    out.push('\n');
+                    text_start = line_end;
+                }
+            }
+        }
+        line_start = line_end;
+    }
+    if fence.is_none() {
+        extract_inline(&markdown[text_start..], out);
+    }
+}
+
+// Up to 3 spaces of indentation are allowed before fences and link reference definitions.
+fn strip_indentation(line: &str) -> &str {
+    let spaces = line.bytes().take(4).take_while(|&b| b == b' ').count();
+    if spaces < 4 { &line[spaces..] } else { line }
+}
+
+// An opening or a closing fence of a code block: at least 3 backticks or tildes.
+fn parse_fence(line: &str) -> Option<(u8, usize)> {
+    let ch = *line.as_bytes().first()?;
+    if ch != b'`' && ch != b'~' {
+        return None;
+    }
+    let len = line.bytes().take_while(|&b| b == ch).count();
+    // The info string of a backtick fence can not contain backticks, otherwise it's a code span
+    let is_fence = len >= 3 && (ch == b'~' || !line[len..].contains('`'));
+    is_fence.then_some((ch, len))
+}
+
+// E.g. `[docs]: https://example.com/docs "Documentation"`
+fn is_link_reference_definition(line: &str) -> bool {
+    line.starts_with('[')
+        && !line.starts_with("[^")
+        && find_closing_bracket(line, 0, b'[', b']')
+            .is_some_and(|end| line[end + 1..].starts_with(':'))
+}
+
+// Position of the bracket closing the one at the position `start`, nested brackets are skipped.
+fn find_closing_bracket(text: &str, start: usize, open: u8, close: u8) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0;
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 1,
+            b if b == open => depth += 1,
+            b if b == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+// Paragraphs, headings, lists, etc. Markers of blocks and emphasis are kept, they are
+// punctuation and do not affect the detection.
+fn extract_inline(text: &str, out: &mut String) {
+    let bytes = text.as_bytes();
+    let mut text_start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let skip_to = match bytes[i] {
+            b'\\' if bytes.get(i + 1).is_some_and(|b| b.is_ascii_punctuation()) => {
+                out.push_str(&text[text_start..i]);
+                text_start = i + 1;
+                Some(i + 2)
+            }
+            b'`' => {
+                let len = bytes[i..].iter().take_while(|&&b| b == b'`').count();
+                match find_code_span_end(text, i + len, len) {
+                    Some(end) => {
+                        out.push_str(&text[text_start..i]);
+                        out.push(' ');
+                        text_start = end;
+                        Some(end)
+                    }
+                    None => Some(i + len),
+                }
+            }
+            b'[' => find_closing_bracket(text, i, b'[', b']').map(|end| {
+                out.push_str(&text[text_start..i]);
+                extract_inline(&text[i + 1..end], out);
+                text_start = skip_link_destination(text, end + 1);
+                text_start
+            }),
+            b'<' => {
+                let end =
+                    autolink_end(text, i).or_else(|| parse_markup(text, i).map(|(_, end)| end));
+                end.inspect(|&end| {
+                    out.push_str(&text[text_start..i]);
+                    out.push(' ');
+                    text_start = end;
+                })
+            }
+            b'&' => decode_char_reference(&text[i..]).map(|(ch, len)| {
+                out.push_str(&text[text_start..i]);
+                out.push(ch);
+                text_start = i + len;
+                text_start
+            }),
+            _ => None,
+        };
+        i = skip_to.unwrap_or(i + 1);
+    }
+    out.push_str(&text[text_start..]);
+}
+
+// The end of a code span opened by `len` backticks: a run of exactly `len` backticks.
+fn find_code_span_end(text: &str, from: usize, len: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut i = from;
+    while i < bytes.len() {
+        if bytes[i] == b'`' {
+            let run = bytes[i..].iter().take_while(|&&b| b == b'`').count();
+            if run == len {
+                return Some(i + run);
+            }
+            i += run;
+        } else {
+            i += 1;
+        }
+    }
+    None
+}
+
+// Skip `(https://example.com "Title")` of inline links and `[label]` of reference links
+// following the text of a link at the position `start`.
+fn skip_link_destination(text: &str, start: usize) -> usize {
+    let (open, close) = match text.as_bytes().get(start) {
+        Some(b'(') => (b'(', b')'),
+        Some(b'[') => (b'[', b']'),
+        _ => return start,
+    };
+    find_closing_bracket(text, start, open, close).map_or(start, |end| end + 1)
+}
+
+// The end of an autolink, e.g. `<https://example.com>` or `<anna@example.com>`.
+fn autolink_end(text: &str, start: usize) -> Option<usize> {
+    let len = text[start + 1..].find(['>', '<', ' ', '\n'])?;
+    let end = start + 1 + len;
+    let link = &text[start + 1..end];
+    let is_autolink = text.as_bytes()[end] == b'>' && (link.contains("://") || link.contains('@'));
+    is_autolink.then_some(end + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn extract_markdown(markdown: &str) -> String {
+        let mut out = String::new();
+        extract(markdown, &mut out);
+        out
+    }
+
+    fn words(markdown: &str) -> Vec<String> {
+        extract_markdown(markdown)
+            .split_whitespace()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_text() {
+        let markdown = "# Installation\n\
+            \n\
+            Füge die Abhängigkeit in `Cargo.toml` hinzu:\n\
+            \n\
+            ```toml\n\
+            [dependencies]\n\
+            whatlang = \"0.18\"\n\
+            ```\n\
+            \n\
+            * Siehe [die Dokumentation](https://docs.rs/whatlang \"Docs\") und [Beispiele][examples].\n\
+            \n\
+            [examples]: https://github.com/greyblake/whatlang-rs/tree/master/examples\n";
+        assert_eq!(
+            words(markdown),
+            vec![
+                "#",
+                "Installation",
+                "Füge",
+                "die",
+                "Abhängigkeit",
+                "in",
+                "hinzu:",
+                "*",
+                "Siehe",
+                "die",
+                "Dokumentation",
+                "und",
+                "Beispiele."
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fenced_code_blocks() {
+        assert_eq!(words("a\n~~~\ncode\n~~~\nb"), vec!["a", "b"]);
+        assert_eq!(words("a\n  ````rust\n```\ncode\n````\nb"), vec!["a", "b"]);
+        // Not closed by a shorter fence or a fence of another character
+        assert_eq!(words("a\n````\n```\n~~~~\ncode"), vec!["a"]);
+        // Not a fence
+        assert_eq!(words("``` a ` b\nc"), vec!["```", "a", "`", "b", "c"]);
+        assert_eq!(words("    ```\nc"), vec!["```", "c"]);
+    }
+
+    #[test]
+    fn test_code_spans() {
+        assert_eq!(words("a `b` c"), vec!["a", "c"]);
+        assert_eq!(words("a ``b ` c`` d"), vec!["a", "d"]);
+        assert_eq!(words("a `b\nc` d"), vec!["a", "d"]);
+        assert_eq!(words("a ``b` c"), vec!["a", "``b`", "c"]);
+        assert_eq!(words("a \\`b` c"), vec!["a", "`b`", "c"]);
+    }
+
+    #[test]
+    fn test_links() {
+        assert_eq!(words("[a](http://x.com) b"), vec!["a", "b"]);
+        assert_eq!(words("[a [b]](<http://x.com> (c)) d"), vec!["a", "b", "d"]);
+        assert_eq!(words("![a picture](img.png)"), vec!["!a", "picture"]);
+        assert_eq!(words("[`code`](x) [a][] [b] [c"), vec!["a", "b", "[c"]);
+        assert_eq!(words("[a](unclosed b"), vec!["a(unclosed", "b"]);
+        assert_eq!(words("  [a]: http://x.com\nb"), vec!["b"]);
+        assert_eq!(words("[^1]: A footnote"), vec!["^1:", "A", "footnote"]);
+    }
+
+    #[test]
+    fn test_html() {
+        assert_eq!(
+            words("a <https://x.com> <a@b.com> <b>c</b> <!-- d --> e &amp; f < g"),
+            vec!["a", "c", "e", "&", "f", "<", "g"]
+        );
+    }
+}
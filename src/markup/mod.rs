@@ -0,0 +1,163 @@
+mod entities;
+mod html;
+mod markdown;
+
+pub(crate) use html::decode_char_reference;
+
+use crate::Lang;
+use crate::email::Email;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Format of the texts given to a detector, see [Options::set_input_mode](crate::Options::set_input_mode).
+///
+/// Markup says nothing about the language of a document, but it can easily outweigh short texts:
+/// tag names, attributes and URLs are mostly English. In the `Html` and `Markdown` modes only
+/// the natural-language text of a document is detected.
+///
+/// # Example
+/// ```
+/// use whatlang::{InputMode, Lang};
+///
+/// let html = r#"<p class="intro">Der Zug f&auml;hrt um <b>acht</b> Uhr ab.</p>
+///     <script>document.title = "Welcome";</script>"#;
+/// let extracted = InputMode::Html.extract(html);
+/// assert_eq!(extracted.text().trim(), "Der Zug fährt um acht Uhr ab.");
+///
+/// let markdown = "Run `cargo build --release` and see [die Anleitung](https://example.com/how-to).";
+/// let extracted = InputMode::Markdown.extract(markdown);
+/// assert_eq!(extracted.text().trim(), "Run   and see die Anleitung.");
+///
+/// let extracted = InputMode::Html.extract(r#"<html lang="de-AT"><p>Servus!</p></html>"#);
+/// assert_eq!(extracted.lang(), Some(Lang::Deu));
+/// ```
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputMode {
+    /// Texts are detected as they are.
+    #[default]
+    Plain,
+    /// HTML documents or fragments. Tags, attributes, comments, `<script>`, `<style>` and
+    /// `<code>` elements are ignored, character references (e.g. `&auml;`, `&#228;`) are decoded.
+    Html,
+    /// Markdown documents. Fenced code blocks, code spans, URLs of links and images,
+    /// link reference definitions and inline HTML are ignored.
+    Markdown,
+    /// E-mail messages. Only the new content written by the author is detected: the quoted
+    /// history, signatures and disclaimers are ignored, see [Email](crate::Email).
+    Email,
+}
+
+impl InputMode {
+    /// Extract the natural-language text of the document.
+    pub fn extract(self, text: &str) -> Extracted {
+        let mut extracted = String::new();
+        let mut declared = DeclaredLangs::default();
+        self.extract_into(text, &mut extracted, &mut declared);
+        Extracted {
+            text: extracted,
+            lang: declared.main_lang(),
+        }
+    }
+
+    pub(crate) fn extract_into(self, text: &str, out: &mut String, declared: &mut DeclaredLangs) {
+        out.clear();
+        declared.clear();
+        match self {
+            InputMode::Plain => out.push_str(text),
+            InputMode::Html => html::extract(text, out, declared),
+            InputMode::Markdown => markdown::extract(text, out),
+            InputMode::Email => out.push_str(Email::parse(text).content()),
+        }
+    }
+}
+
+/// Natural-language text of a document, see [InputMode::extract].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extracted {
+    text: String,
+    lang: Option<Lang>,
+}
+
+impl Extracted {
+    /// The extracted text. Ignored parts of the document are replaced by whitespace,
+    /// so the words around them are not glued together.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The language declared by the document (the `lang` attributes of HTML),
+    /// if it's declared for most of the text.
+    pub fn lang(&self) -> Option<Lang> {
+        self.lang
+    }
+}
+
+/// Letters of an extracted text by the languages declared for them.
+#[derive(Debug, Default)]
+pub(crate) struct DeclaredLangs {
+    counts: Vec<(Lang, usize)>,
+    total: usize,
+}
+
+impl DeclaredLangs {
+    fn clear(&mut self) {
+        self.counts.clear();
+        self.total = 0;
+    }
+
+    fn add(&mut self, lang: Option<Lang>, text: &str) {
+        let count = text.chars().filter(|ch| ch.is_alphabetic()).count();
+        if count == 0 {
+            return;
+        }
+        self.total += count;
+        if let Some(lang) = lang {
+            match self.counts.iter_mut().find(|(l, _)| *l == lang) {
+                Some((_, lang_count)) => *lang_count += count,
+                None => self.counts.push((lang, count)),
+            }
+        }
+    }
+
+    /// The language declared for more than a half of the letters.
+    pub(crate) fn main_lang(&self) -> Option<Lang> {
+        self.counts
+            .iter()
+            .find(|(_, count)| count * 2 > self.total)
+            .map(|(lang, _)| *lang)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain() {
+        let text = "<b>Hello</b> `world`";
+        let extracted = InputMode::Plain.extract(text);
+        assert_eq!(extracted.text(), text);
+        assert_eq!(extracted.lang(), None);
+    }
+
+    #[test]
+    fn test_declared_langs() {
+        let mut declared = DeclaredLangs::default();
+        assert_eq!(declared.main_lang(), None);
+
+        declared.add(Some(Lang::Deu), "Guten Tag, 123!");
+        assert_eq!(declared.main_lang(), Some(Lang::Deu));
+
+        declared.add(Some(Lang::Fra), "Bonjour");
+        declared.add(None, "...");
+        assert_eq!(declared.main_lang(), Some(Lang::Deu));
+
+        declared.add(None, "Hello");
+        assert_eq!(declared.main_lang(), None);
+
+        declared.clear();
+        declared.add(Some(Lang::Fra), "Bonjour");
+        assert_eq!(declared.main_lang(), Some(Lang::Fra));
+    }
+}
@@ -0,0 +1,649 @@
+//! Unicode normalization of a text before detection, see [Normalization].
+//!
+//! The same text can be encoded by different sequences of code points, e.g. `ệ` is either
+//! the single character `U+1EC7` or `e` followed by the combining marks `U+0323` and `U+0302`.
+//! Trigram profiles and alphabets are built from precomposed characters, so a text is brought to
+//! the same form before its characters are counted.
+//!
+//! ## Algorithm
+//!
+//! * A quick check tells whether the text is already normalized, which is the case for the vast
+//!   majority of texts. Such a text is used as is, without copying it.
+//! * Otherwise every character is replaced by its full decomposition (canonical for NFC,
+//!   compatibility for NFKC), and combining marks are put in the canonical order.
+//! * Finally the decomposed characters are composed back, as described by
+//!   [UAX #15](https://unicode.org/reports/tr15/).
+//!
+//! Properties of the characters (combining class and quick check values) are looked up in a
+//! two-level table, the same way as scripts are (see [crate::scripts::chars]).
+//! Decompositions and compositions are looked up in sorted lists. All of them are generated
+//! in advance, see [tables].
+
+#[rustfmt::skip]
+mod tables;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use tables::{
+    BLOCK_INDEXES, BLOCK_SHIFT, BLOCKS, CANONICAL_DECOMPOSITIONS, COMPATIBILITY_DECOMPOSITIONS,
+    COMPOSITIONS, DECOMPOSED_CHARS, PROPERTIES,
+};
+
+/// Unicode normalization form a text is brought to before detection.
+#[cfg_attr(feature = "arbitrary", derive(::arbitrary::Arbitrary))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Normalization {
+    /// The text is used as is.
+    None,
+
+    /// Canonical composition (NFC): decomposed characters, e.g. a letter followed by combining
+    /// accents, are composed into precomposed ones.
+    #[default]
+    Nfc,
+
+    /// Compatibility composition (NFKC): in addition to NFC, compatibility forms are replaced by
+    /// their ordinary equivalents, e.g. fullwidth `Ｗｈａｔ` becomes `What` and `ﬁ` becomes `fi`.
+    Nfkc,
+}
+
+// Quick check flags of PROPERTIES, see https://unicode.org/reports/tr15/#Detecting_Normalization_Forms
+const NFC_NO: u8 = 1;
+const NFC_MAYBE: u8 = 2;
+const NFKC_NO: u8 = 4;
+const NFKC_MAYBE: u8 = 8;
+
+const BLOCK_MASK: usize = (1 << BLOCK_SHIFT) - 1;
+
+// Hangul syllables are composed and decomposed algorithmically.
+const HANGUL_S_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_L_COUNT: u32 = 19;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_N_COUNT: u32 = HANGUL_V_COUNT * HANGUL_T_COUNT;
+const HANGUL_S_COUNT: u32 = HANGUL_L_COUNT * HANGUL_N_COUNT;
+
+/// Buffers for normalization, reused between texts.
+#[derive(Debug, Default)]
+pub(crate) struct NormalizationBuffers {
+    pub(crate) normalized: String,
+    decomposed: Vec<char>,
+}
+
+/// Normalize the text into `buffers.normalized`.
+/// Returns `false` if the text is already normalized, then the buffer is left empty.
+pub(crate) fn normalize_into(
+    text: &str,
+    normalization: Normalization,
+    buffers: &mut NormalizationBuffers,
+) -> bool {
+    let NormalizationBuffers {
+        normalized,
+        decomposed,
+    } = buffers;
+    normalized.clear();
+    if normalization == Normalization::None || is_normalized(text, normalization) {
+        return false;
+    }
+
+    decomposed.clear();
+    for ch in text.chars() {
+        decompose_into(ch, normalization, decomposed);
+    }
+    compose(decomposed);
+    normalized.extend(decomposed.iter());
+    true
+}
+
+#[inline]
+fn char_properties(ch: char) -> (u8, u8) {
+    let code_point = ch as usize;
+    let code = match BLOCK_INDEXES.get(code_point >> BLOCK_SHIFT) {
+        Some(&block) => BLOCKS[block as usize][code_point & BLOCK_MASK],
+        None => 0,
+    };
+    PROPERTIES[code as usize]
+}
+
+#[inline]
+fn combining_class(ch: char) -> u8 {
+    char_properties(ch).0
+}
+
+// The quick check, where "maybe" characters are checked for being composable with
+// the preceding starter, so texts with combining marks (e.g. Devanagari nukta) are not
+// normalized needlessly.
+fn is_normalized(text: &str, normalization: Normalization) -> bool {
+    let (no, maybe) = match normalization {
+        Normalization::None => return true,
+        Normalization::Nfc => (NFC_NO, NFC_MAYBE),
+        Normalization::Nfkc => (NFKC_NO, NFKC_MAYBE),
+    };
+
+    let mut starter: Option<char> = None;
+    // Combining class of the last character after the starter, if any
+    let mut last_ccc: Option<u8> = None;
+    for ch in text.chars() {
+        if ch.is_ascii() {
+            starter = Some(ch);
+            last_ccc = None;
+            continue;
+        }
+        let (ccc, flags) = char_properties(ch);
+        if flags & no != 0 {
+            return false;
+        }
+        if ccc != 0 && last_ccc.is_some_and(|last| last > ccc) {
+            return false;
+        }
+        if flags & maybe != 0 && starter.is_some_and(|s| may_compose(s, ch, last_ccc, ccc)) {
+            return false;
+        }
+        if ccc == 0 {
+            starter = Some(ch);
+            last_ccc = None;
+        } else {
+            last_ccc = Some(ccc);
+        }
+    }
+    true
+}
+
+// Whether a "maybe" character may compose with the starter. Marks of the starter's decomposition
+// would be reordered together with the character, so such a starter is never trusted.
+fn may_compose(starter: char, ch: char, last_ccc: Option<u8>, ccc: u8) -> bool {
+    let is_blocked = last_ccc.is_some_and(|last| last >= ccc);
+    let is_decomposable = is_hangul_syllable(starter)
+        || find_decomposition(&CANONICAL_DECOMPOSITIONS, starter).is_some();
+    is_decomposable || (!is_blocked && compose_pair(starter, ch).is_some())
+}
+
+fn is_hangul_syllable(ch: char) -> bool {
+    (ch as u32).wrapping_sub(HANGUL_S_BASE) < HANGUL_S_COUNT
+}
+
+// Push the full decomposition of the character, keeping combining marks in the canonical order.
+fn decompose_into(ch: char, normalization: Normalization, out: &mut Vec<char>) {
+    if ch.is_ascii() {
+        out.push(ch);
+        return;
+    }
+
+    let s_index = (ch as u32).wrapping_sub(HANGUL_S_BASE);
+    if s_index < HANGUL_S_COUNT {
+        let l = HANGUL_L_BASE + s_index / HANGUL_N_COUNT;
+        let v = HANGUL_V_BASE + (s_index % HANGUL_N_COUNT) / HANGUL_T_COUNT;
+        let t = HANGUL_T_BASE + s_index % HANGUL_T_COUNT;
+        // Jamos are starters, no reordering is needed
+        out.extend([l, v].into_iter().filter_map(char::from_u32));
+        if t != HANGUL_T_BASE {
+            out.extend(char::from_u32(t));
+        }
+        return;
+    }
+
+    let decomposition = match normalization {
+        Normalization::Nfkc => find_decomposition(&COMPATIBILITY_DECOMPOSITIONS, ch)
+            .or_else(|| find_decomposition(&CANONICAL_DECOMPOSITIONS, ch)),
+        _ => find_decomposition(&CANONICAL_DECOMPOSITIONS, ch),
+    };
+    match decomposition {
+        Some(chars) => chars.chars().for_each(|c| push_in_canonical_order(c, out)),
+        None => push_in_canonical_order(ch, out),
+    }
+}
+
+fn find_decomposition(decompositions: &[(char, u16, u8)], ch: char) -> Option<&'static str> {
+    let index = decompositions.binary_search_by_key(&ch, |d| d.0).ok()?;
+    let (_, offset, len) = decompositions[index];
+    let offset = offset as usize;
+    Some(&DECOMPOSED_CHARS[offset..offset + len as usize])
+}
+
+// Combining marks are stably sorted by their combining class, starters are never moved.
+fn push_in_canonical_order(ch: char, out: &mut Vec<char>) {
+    let ccc = combining_class(ch);
+    let mut position = out.len();
+    if ccc != 0 {
+        while position > 0 && combining_class(out[position - 1]) > ccc {
+            position -= 1;
+        }
+    }
+    out.insert(position, ch);
+}
+
+// Canonical composition of decomposed characters in place.
+fn compose(chars: &mut Vec<char>) {
+    let mut starter: Option<usize> = None;
+    // Combining class of the last character after the starter, if any
+    let mut last_ccc: Option<u8> = None;
+    let mut len = 0;
+    for i in 0..chars.len() {
+        let ch = chars[i];
+        let ccc = combining_class(ch);
+        let is_blocked = last_ccc.is_some_and(|last| last >= ccc);
+        if let Some(starter) = starter
+            && !is_blocked
+            && let Some(composed) = compose_pair(chars[starter], ch)
+        {
+            chars[starter] = composed;
+            continue;
+        }
+        if ccc == 0 {
+            starter = Some(len);
+            last_ccc = None;
+        } else {
+            last_ccc = Some(ccc);
+        }
+        chars[len] = ch;
+        len += 1;
+    }
+    chars.truncate(len);
+}
+
+fn compose_pair(first: char, second: char) -> Option<char> {
+    let (first_code, second_code) = (first as u32, second as u32);
+
+    let l_index = first_code.wrapping_sub(HANGUL_L_BASE);
+    let v_index = second_code.wrapping_sub(HANGUL_V_BASE);
+    if l_index < HANGUL_L_COUNT && v_index < HANGUL_V_COUNT {
+        let s_index = (l_index * HANGUL_V_COUNT + v_index) * HANGUL_T_COUNT;
+        return char::from_u32(HANGUL_S_BASE + s_index);
+    }
+
+    let s_index = first_code.wrapping_sub(HANGUL_S_BASE);
+    let t_index = second_code.wrapping_sub(HANGUL_T_BASE);
+    if s_index < HANGUL_S_COUNT
+        && s_index % HANGUL_T_COUNT == 0
+        && (1..HANGUL_T_COUNT).contains(&t_index)
+    {
+        return char::from_u32(first_code + t_index);
+    }
+
+    COMPOSITIONS
+        .binary_search_by_key(&(first, second), |&(a, b, _)| (a, b))
+        .ok()
+        .map(|index| COMPOSITIONS[index].2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Write;
+    use std::collections::HashMap;
+    use unicode_normalization::char::{
+        canonical_combining_class, compose as unicode_compose, decompose_canonical,
+        decompose_compatible,
+    };
+    use unicode_normalization::{IsNormalized, UnicodeNormalization, is_nfc_quick, is_nfkc_quick};
+
+    const TABLES_PATH: &str = "src/normalization/tables.rs";
+
+    fn unicode_char_properties(ch: char) -> (u8, u8) {
+        let flags = |quick_check, no, maybe| match quick_check {
+            IsNormalized::Yes => 0,
+            IsNormalized::No => no,
+            IsNormalized::Maybe => maybe,
+        };
+        let nfc = flags(is_nfc_quick(core::iter::once(ch)), NFC_NO, NFC_MAYBE);
+        let nfkc = flags(is_nfkc_quick(core::iter::once(ch)), NFKC_NO, NFKC_MAYBE);
+        (canonical_combining_class(ch), nfc | nfkc)
+    }
+
+    // Characters and their full decompositions
+    type Decompositions = Vec<(char, String)>;
+
+    fn unicode_decompositions() -> (Decompositions, Decompositions) {
+        let mut canonical = Vec::new();
+        let mut compatibility = Vec::new();
+        for ch in char::MIN..=char::MAX {
+            if ch.is_ascii() || is_hangul_syllable(ch) {
+                continue;
+            }
+            let mut canonical_chars = String::new();
+            decompose_canonical(ch, |c| canonical_chars.push(c));
+            let mut compatibility_chars = String::new();
+            decompose_compatible(ch, |c| compatibility_chars.push(c));
+
+            if compatibility_chars != canonical_chars {
+                compatibility.push((ch, compatibility_chars));
+            }
+            if canonical_chars != ch.to_string() {
+                canonical.push((ch, canonical_chars));
+            }
+        }
+        (canonical, compatibility)
+    }
+
+    // The first character of a primary composite either has a decomposition itself or occurs in
+    // a decomposition, and the second one is a "maybe" character of the NFC quick check.
+    fn unicode_compositions(canonical: &[(char, String)]) -> Vec<(char, char, char)> {
+        let mut firsts: Vec<char> = canonical
+            .iter()
+            .flat_map(|(ch, chars)| chars.chars().chain([*ch]))
+            .collect();
+        firsts.sort_unstable();
+        firsts.dedup();
+        let seconds: Vec<char> = (char::MIN..=char::MAX)
+            .filter(|&ch| unicode_char_properties(ch).1 & NFC_MAYBE != 0)
+            .collect();
+
+        let mut compositions = Vec::new();
+        for &first in &firsts {
+            for &second in &seconds {
+                if let Some(composed) = unicode_compose(first, second)
+                    && !is_hangul_syllable(composed)
+                {
+                    compositions.push((first, second, composed));
+                }
+            }
+        }
+        compositions.sort_unstable();
+        compositions
+    }
+
+    fn escape(chars: &str) -> String {
+        chars
+            .chars()
+            .map(|ch| format!("\\u{{{:04X}}}", ch as u32))
+            .collect()
+    }
+
+    fn render_decompositions(
+        out: &mut String,
+        name: &str,
+        decompositions: &[(char, String)],
+        decomposed_chars: &mut String,
+        offsets: &mut HashMap<String, usize>,
+    ) {
+        writeln!(
+            out,
+            "pub(super) static {name}: [(char, u16, u8); {}] = [",
+            decompositions.len()
+        )
+        .unwrap();
+        for (ch, chars) in decompositions {
+            let offset = *offsets.entry(chars.clone()).or_insert_with(|| {
+                decomposed_chars.push_str(chars);
+                decomposed_chars.len() - chars.len()
+            });
+            assert!(offset <= u16::MAX as usize && chars.len() <= u8::MAX as usize);
+            writeln!(
+                out,
+                "    ('{}', {offset}, {}),",
+                escape(&ch.to_string()),
+                chars.len()
+            )
+            .unwrap();
+        }
+        writeln!(out, "];").unwrap();
+        writeln!(out).unwrap();
+    }
+
+    fn assert_unicode_data_version() {
+        let (major, minor, update) = unicode_normalization::UNICODE_VERSION;
+        let version = (major as u64, minor as u64, update as u64);
+        assert_eq!(version, crate::UNICODE_VERSION);
+    }
+
+    fn render_tables() -> String {
+        const SHIFT: u32 = 7;
+        let block_len = 1 << SHIFT;
+        assert_unicode_data_version();
+
+        let mut properties = vec![(0, 0)];
+        let codes: Vec<u8> = (0..=char::MAX as u32)
+            .map(|code_point| {
+                let Some(ch) = char::from_u32(code_point) else {
+                    return 0;
+                };
+                let char_properties = unicode_char_properties(ch);
+                let code = match properties.iter().position(|&p| p == char_properties) {
+                    Some(code) => code,
+                    None => {
+                        properties.push(char_properties);
+                        properties.len() - 1
+                    }
+                };
+                code as u8
+            })
+            .collect();
+        assert!(properties.len() <= 256);
+        let last = codes.iter().rposition(|&code| code != 0).unwrap();
+
+        // Code points after the last block have the default properties
+        let blocks_count = last / block_len + 1;
+        let mut blocks: Vec<&[u8]> = Vec::new();
+        let mut indexes = Vec::new();
+        for block in codes[..blocks_count * block_len].chunks(block_len) {
+            let index = match blocks.iter().position(|b| *b == block) {
+                Some(index) => index,
+                None => {
+                    blocks.push(block);
+                    blocks.len() - 1
+                }
+            };
+            indexes.push(index);
+        }
+        assert!(blocks.len() <= 256);
+
+        let (canonical, compatibility) = unicode_decompositions();
+        let compositions = unicode_compositions(&canonical);
+
+        let mut out = String::new();
+        let (major, minor, update) = crate::UNICODE_VERSION;
+        writeln!(
+            out,
+            "// Generated from the normalization data of Unicode {major}.{minor}.{update}"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "// Do not edit it manually, see `test_tables_are_up_to_date` in src/normalization/mod.rs."
+        )
+        .unwrap();
+        writeln!(out, "//").unwrap();
+        writeln!(
+            out,
+            "// Properties of a character are encoded as their position in `PROPERTIES`, which holds"
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "// the canonical combining class and the quick check flags of the character."
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "// Decompositions (full, except Hangul syllables) are offsets and lengths in `DECOMPOSED_CHARS`."
+        )
+        .unwrap();
+        writeln!(
+            out,
+            "// Compatibility decompositions are listed only if they differ from canonical ones."
+        )
+        .unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "pub(super) const BLOCK_SHIFT: u32 = {SHIFT};").unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "pub(super) static BLOCK_INDEXES: [u8; {}] = [",
+            indexes.len()
+        )
+        .unwrap();
+        for row in indexes.chunks(32) {
+            let row: Vec<String> = row.iter().map(|i| i.to_string()).collect();
+            writeln!(out, "    {},", row.join(", ")).unwrap();
+        }
+        writeln!(out, "];").unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "pub(super) static BLOCKS: [[u8; {}]; {}] = [",
+            block_len,
+            blocks.len()
+        )
+        .unwrap();
+        for block in &blocks {
+            let block: Vec<String> = block.iter().map(|c| c.to_string()).collect();
+            writeln!(out, "    [{}],", block.join(", ")).unwrap();
+        }
+        writeln!(out, "];").unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "pub(super) static PROPERTIES: [(u8, u8); {}] = [",
+            properties.len()
+        )
+        .unwrap();
+        for row in properties.chunks(16) {
+            let row: Vec<String> = row.iter().map(|(c, f)| format!("({c}, {f})")).collect();
+            writeln!(out, "    {},", row.join(", ")).unwrap();
+        }
+        writeln!(out, "];").unwrap();
+        writeln!(out).unwrap();
+
+        let mut decomposed_chars = String::new();
+        let mut offsets = HashMap::new();
+        render_decompositions(
+            &mut out,
+            "CANONICAL_DECOMPOSITIONS",
+            &canonical,
+            &mut decomposed_chars,
+            &mut offsets,
+        );
+        render_decompositions(
+            &mut out,
+            "COMPATIBILITY_DECOMPOSITIONS",
+            &compatibility,
+            &mut decomposed_chars,
+            &mut offsets,
+        );
+
+        writeln!(out, "pub(super) static DECOMPOSED_CHARS: &str = \"\\").unwrap();
+        let decomposed_chars: Vec<char> = decomposed_chars.chars().collect();
+        for row in decomposed_chars.chunks(16) {
+            let row: String = row.iter().collect();
+            writeln!(out, "    {}\\", escape(&row)).unwrap();
+        }
+        writeln!(out, "\";").unwrap();
+        writeln!(out).unwrap();
+
+        writeln!(
+            out,
+            "pub(super) static COMPOSITIONS: [(char, char, char); {}] = [",
+            compositions.len()
+        )
+        .unwrap();
+        for (first, second, composed) in &compositions {
+            writeln!(
+                out,
+                "    ('{}', '{}', '{}'),",
+                escape(&first.to_string()),
+                escape(&second.to_string()),
+                escape(&composed.to_string())
+            )
+            .unwrap();
+        }
+        writeln!(out, "];").unwrap();
+        out
+    }
+
+    // Run with `WHATLANG_UPDATE_TABLES=1` to regenerate the tables, e.g. after an update of
+    // the unicode-normalization crate (and `UNICODE_VERSION`).
+    #[test]
+    fn test_tables_are_up_to_date() {
+        let tables = render_tables();
+        if std::env::var_os("WHATLANG_UPDATE_TABLES").is_some() {
+            std::fs::write(TABLES_PATH, &tables).unwrap();
+        }
+        let actual = std::fs::read_to_string(TABLES_PATH).unwrap();
+        assert!(actual == tables, "{TABLES_PATH} is outdated");
+    }
+
+    fn normalize(text: &str, normalization: Normalization) -> String {
+        let mut buffers = NormalizationBuffers::default();
+        if normalize_into(text, normalization, &mut buffers) {
+            buffers.normalized
+        } else {
+            text.to_string()
+        }
+    }
+
+    #[test]
+    fn test_normalize_chars_as_unicode_normalization() {
+        assert_unicode_data_version();
+        for ch in char::MIN..=char::MAX {
+            let text = ch.to_string();
+            assert_eq!(
+                normalize(&text, Normalization::Nfc),
+                text.nfc().collect::<String>(),
+                "{ch:?}"
+            );
+            assert_eq!(
+                normalize(&text, Normalization::Nfkc),
+                text.nfkc().collect::<String>(),
+                "{ch:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_normalize_texts_as_unicode_normalization() {
+        // Bases, combining marks of different classes, Hangul jamos and compatibility characters
+        let alphabet: Vec<char> =
+            "aeoAEOuUsSıi ᄀᄁ가각\u{1161}\u{11A8}\u{0300}\u{0301}\u{0302}\u{0308}\u{0323}\u{0327}\u{0345}\u{031B}\u{093C}\u{0928}\u{09BE}\u{09C7}ǖǕếệΩω\u{0344}ﬁＡｶ\u{FF9E}ｶﾞ①"
+                .chars()
+                .collect();
+        // A simple linear congruential generator, to keep the test deterministic
+        let mut seed: u64 = 42;
+        let mut next = move || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as usize
+        };
+
+        let mut texts: Vec<String> = vec![
+            include_str!("../../tests/examples.json").to_string(),
+            "Tiê\u{0301}ng Viê\u{0323}t".to_string(),
+        ];
+        for _ in 0..20_000 {
+            let len = next() % 8;
+            texts.push(
+                (0..len)
+                    .map(|_| alphabet[next() % alphabet.len()])
+                    .collect(),
+            );
+        }
+
+        for text in &texts {
+            let nfc: String = text.nfc().collect();
+            let nfkc: String = text.nfkc().collect();
+            assert_eq!(normalize(text, Normalization::Nfc), nfc, "{text:?}");
+            assert_eq!(normalize(text, Normalization::Nfkc), nfkc, "{text:?}");
+            assert_eq!(normalize(text, Normalization::None), *text);
+        }
+    }
+
+    #[test]
+    fn test_normalized_text_is_not_copied() {
+        let mut buffers = NormalizationBuffers::default();
+        for text in [
+            "",
+            "Hello",
+            "Tiếng Việt",
+            "\u{091C}\u{093C}िंदगी",
+            "한국어",
+            "ＡＢＣ",
+        ] {
+            assert!(!normalize_into(text, Normalization::Nfc, &mut buffers));
+            assert!(buffers.normalized.is_empty());
+        }
+        assert!(normalize_into("ＡＢＣ", Normalization::Nfkc, &mut buffers));
+        assert_eq!(buffers.normalized, "ABC");
+    }
+}
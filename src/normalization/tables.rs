@@ -0,0 +1,7550 @@
+// Generated from the normalization data of Unicode 16.0.0
+// Do not edit it manually, see `test_tables_are_up_to_date` in src/normalization/mod.rs.
+//
+// Properties of a character are encoded as their position in `PROPERTIES`, which holds
+// the canonical combining class and the quick check flags of the character.
+// Decompositions (full, except Hangul syllables) are offsets and lengths in `DECOMPOSED_CHARS`.
+// Compatibility decompositions are listed only if they differ from canonical ones.
+
+pub(super) const BLOCK_SHIFT: u32 = 7;
+
+pub(super) static BLOCK_INDEXES: [u8; 1525] = [
+    0, 1, 2, 3, 0, 4, 5, 6, 0, 7, 0, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28,
+    29, 30, 31, 32, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 34, 35, 0, 36, 37, 0, 38, 39, 40, 41, 42, 43, 44, 45, 0, 46, 47, 48,
+    49, 50, 51, 52, 53, 0, 54, 0, 55, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 58, 0, 0, 59, 60, 61, 62, 0, 63, 64, 65,
+    66, 67, 68, 69, 70, 64, 64, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 72, 73, 74, 75, 76, 77, 78, 0, 79, 80, 81, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 82, 83, 84, 85, 86, 64, 64, 87, 88, 89, 90, 91, 92,
+    0, 0, 0, 93, 0, 94, 95, 0, 0, 0, 0, 0, 0, 0, 0, 96, 0, 0, 0, 0, 97, 98, 0, 0, 0, 0, 99, 0, 0, 100, 101, 102,
+    103, 104, 105, 106, 107, 108, 109, 110, 111, 112, 0, 113, 114, 115, 116, 0, 117, 0, 118, 119, 120, 121, 0, 0, 114, 0, 122, 123, 0, 0, 124, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 126, 127, 0, 0, 0, 128, 0, 0, 0, 0, 129,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 0, 0, 0, 0, 0, 0,
+    0, 0, 132, 133, 134, 0, 0, 0, 135, 136, 137, 64, 64, 138, 64, 139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    140, 141, 127, 0, 0, 142, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 148, 0, 0,
+    0, 0, 149, 150, 151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 82, 82, 82, 153,
+];
+
+pub(super) static BLOCKS: [[u8; 128]; 154] = [
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 1, 0, 0, 1, 1, 1, 1, 0, 0, 1, 1, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [2, 2, 2, 2, 2, 3, 2, 2, 2, 2, 2, 2, 2, 3, 3, 2, 3, 2, 3, 2, 2, 4, 5, 5, 5, 5, 4, 6, 5, 5, 5, 5, 5, 7, 7, 8, 8, 8, 8, 9, 9, 5, 5, 5, 5, 8, 8, 5, 8, 8, 5, 5, 10, 10, 10, 10, 11, 5, 5, 5, 5, 3, 3, 3, 12, 12, 2, 12, 12, 13, 3, 5, 5, 5, 3, 3, 3, 5, 5, 0, 3, 3, 3, 5, 5, 5, 5, 3, 4, 5, 5, 3, 14, 15, 15, 14, 15, 15, 14, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 1, 0, 0, 0, 16, 0],
+    [0, 0, 0, 0, 1, 1, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 3, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 3, 3, 3, 3, 5, 3, 3, 3, 17, 5, 3, 3, 3, 3, 3, 3, 5, 5, 5, 5, 5, 5, 3, 3, 5, 3, 3, 17, 18, 3, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 28, 29, 30, 31, 0, 32, 0, 33, 34, 0, 3, 5, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 3, 35, 36, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 39, 40, 35, 36, 37, 41, 42, 2, 2, 8, 5, 3, 3, 3, 3, 3, 5, 3, 3, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 0, 0, 3, 3, 3, 3, 5, 3, 0, 0, 3, 3, 0, 5, 3, 3, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 5, 3, 3, 5, 3, 3, 5, 5, 5, 3, 5, 5, 3, 5, 3, 3, 3, 5, 3, 5, 3, 5, 3, 5, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 5, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 0, 3, 3, 3, 3, 3, 3, 3, 3, 3, 0, 3, 3, 3, 0, 3, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 5, 5, 5, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 5, 5, 5, 5, 5, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 0, 5, 3, 3, 5, 3, 3, 5, 3, 3, 3, 5, 5, 5, 38, 39, 40, 3, 3, 3, 5, 3, 3, 5, 5, 3, 3, 3, 3, 3],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 3, 5, 3, 3, 0, 0, 0, 16, 16, 16, 16, 16, 16, 16, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 16, 16, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 16, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 16, 16, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 48, 48, 0, 0, 0, 0, 16, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 49, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 48, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 46, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 52, 52, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 53, 53, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 54, 54, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 55, 55, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 5, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 16, 0, 0, 0, 0, 16, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 57, 58, 16, 59, 16, 16, 1, 16, 1, 58, 58, 58, 58, 0, 0],
+    [58, 16, 3, 3, 46, 0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 16, 0, 0, 0, 0, 16, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 46, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 3, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 3, 0, 0, 5],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 5, 5, 5, 5, 5, 5, 3, 3, 5, 0, 5, 5, 3, 3, 5, 5, 3, 3, 3, 3, 3, 5, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 5, 3, 3, 3, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 0, 10, 5, 5, 5, 5, 5, 3, 3, 5, 5, 5, 5, 3, 0, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 3, 3, 5, 3, 3, 3, 3, 3, 3, 3, 5, 3, 3, 15, 60, 5, 7, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 4, 18, 18, 5, 61, 3, 14, 5, 3, 5],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 16, 0, 16, 0, 16, 0, 16, 0, 16, 0, 16, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 1, 16, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 16, 0, 16, 0, 1, 1, 1, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 16, 0, 1, 1, 1, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 16, 0, 1, 16, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 16, 0, 16, 1, 0],
+    [16, 16, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 10, 10, 3, 3, 3, 3, 10, 10, 10, 3, 3, 0, 0, 0, 0, 3, 0, 0, 0, 10, 10, 3, 5, 3, 10, 10, 5, 5, 5, 5, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [1, 1, 1, 1, 0, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 0, 1, 0, 16, 0, 1, 0, 16, 16, 1, 1, 0, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 18, 4, 17, 62, 62, 0, 0, 0, 0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 63, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 3, 3, 5, 0, 0, 3, 3, 0, 0, 0, 0, 0, 3, 3, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16],
+    [16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 0, 0, 16, 0, 16, 0, 0, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 0, 16, 0, 16, 0, 0, 16, 16, 0, 0, 0, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 0, 0, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16],
+    [16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 16, 64, 16, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 0, 16, 16, 16, 16, 16, 0, 16, 0, 16, 16, 0, 16, 16, 0, 16, 16, 16, 16, 16, 16, 16, 16, 16, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 5, 5, 5, 5, 5, 5, 5, 3, 3, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0],
+    [0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 0, 0, 0, 0, 0],
+    [0, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 10, 5, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 3, 3, 3, 5, 3, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 3, 5, 3, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 47, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 0, 0, 0, 3, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 48, 0, 0, 0, 0, 0, 0, 48, 0, 0, 48, 0, 48, 48, 48, 0, 0, 0, 0, 46, 46, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 48, 0, 0, 0, 0, 46, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 46, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 46, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 10, 10, 10, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 16, 16, 16, 16, 16, 16, 56, 56, 10, 10, 10, 0, 0, 0, 66, 56, 56, 56, 56, 56, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5, 5, 5],
+    [5, 5, 5, 0, 0, 3, 3, 3, 3, 3, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 16, 16, 16, 16, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 0, 0, 1, 0, 0, 1, 1, 0, 0, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 0, 1, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1],
+    [3, 3, 3, 3, 3, 3, 3, 0, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 0, 0, 3, 3, 3, 3, 3, 3, 3, 0, 3, 3, 0, 3, 3, 3, 3, 3, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 4, 5, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 1, 0, 1, 1, 1, 0, 1, 1, 0, 1, 0, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 0, 1, 1, 0, 1, 0, 0, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 0, 1, 1, 1, 1, 0, 1, 0],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 0, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0],
+    [16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+];
+
+pub(super) static PROPERTIES: [(u8, u8); 67] = [
+    (0, 0), (0, 4), (230, 10), (230, 0), (232, 0), (220, 0), (216, 10), (202, 0), (220, 10), (202, 10), (1, 0), (1, 10), (230, 5), (240, 10), (233, 0), (234, 0),
+    (0, 5), (222, 0), (228, 0), (10, 0), (11, 0), (12, 0), (13, 0), (14, 0), (15, 0), (16, 0), (17, 0), (18, 0), (19, 0), (20, 0), (21, 0), (22, 0),
+    (23, 0), (24, 0), (25, 0), (30, 0), (31, 0), (32, 0), (27, 0), (28, 0), (29, 0), (33, 0), (34, 0), (35, 0), (36, 0), (7, 10), (9, 0), (7, 0),
+    (0, 10), (84, 0), (91, 10), (9, 10), (103, 0), (107, 0), (118, 0), (122, 0), (216, 0), (129, 0), (130, 0), (132, 0), (214, 0), (218, 0), (224, 0), (8, 10),
+    (26, 0), (6, 0), (226, 0),
+];
+
+pub(super) static CANONICAL_DECOMPOSITIONS: [(char, u16, u8); 2081] = [
+    ('\u{00C0}', 0, 3),
+    ('\u{00C1}', 3, 3),
+    ('\u{00C2}', 6, 3),
+    ('\u{00C3}', 9, 3),
+    ('\u{00C4}', 12, 3),
+    ('\u{00C5}', 15, 3),
+    ('\u{00C7}', 18, 3),
+    ('\u{00C8}', 21, 3),
+    ('\u{00C9}', 24, 3),
+    ('\u{00CA}', 27, 3),
+    ('\u{00CB}', 30, 3),
+    ('\u{00CC}', 33, 3),
+    ('\u{00CD}', 36, 3),
+    ('\u{00CE}', 39, 3),
+    ('\u{00CF}', 42, 3),
+    ('\u{00D1}', 45, 3),
+    ('\u{00D2}', 48, 3),
+    ('\u{00D3}', 51, 3),
+    ('\u{00D4}', 54, 3),
+    ('\u{00D5}', 57, 3),
+    ('\u{00D6}', 60, 3),
+    ('\u{00D9}', 63, 3),
+    ('\u{00DA}', 66, 3),
+    ('\u{00DB}', 69, 3),
+    ('\u{00DC}', 72, 3),
+    ('\u{00DD}', 75, 3),
+    ('\u{00E0}', 78, 3),
+    ('\u{00E1}', 81, 3),
+    ('\u{00E2}', 84, 3),
+    ('\u{00E3}', 87, 3),
+    ('\u{00E4}', 90, 3),
+    ('\u{00E5}', 93, 3),
+    ('\u{00E7}', 96, 3),
+    ('\u{00E8}', 99, 3),
+    ('\u{00E9}', 102, 3),
+    ('\u{00EA}', 105, 3),
+    ('\u{00EB}', 108, 3),
+    ('\u{00EC}', 111, 3),
+    ('\u{00ED}', 114, 3),
+    ('\u{00EE}', 117, 3),
+    ('\u{00EF}', 120, 3),
+    ('\u{00F1}', 123, 3),
+    ('\u{00F2}', 126, 3),
+    ('\u{00F3}', 129, 3),
+    ('\u{00F4}', 132, 3),
+    ('\u{00F5}', 135, 3),
+    ('\u{00F6}', 138, 3),
+    ('\u{00F9}', 141, 3),
+    ('\u{00FA}', 144, 3),
+    ('\u{00FB}', 147, 3),
+    ('\u{00FC}', 150, 3),
+    ('\u{00FD}', 153, 3),
+    ('\u{00FF}', 156, 3),
+    ('\u{0100}', 159, 3),
+    ('\u{0101}', 162, 3),
+    ('\u{0102}', 165, 3),
+    ('\u{0103}', 168, 3),
+    ('\u{0104}', 171, 3),
+    ('\u{0105}', 174, 3),
+    ('\u{0106}', 177, 3),
+    ('\u{0107}', 180, 3),
+    ('\u{0108}', 183, 3),
+    ('\u{0109}', 186, 3),
+    ('\u{010A}', 189, 3),
+    ('\u{010B}', 192, 3),
+    ('\u{010C}', 195, 3),
+    ('\u{010D}', 198, 3),
+    ('\u{010E}', 201, 3),
+    ('\u{010F}', 204, 3),
+    ('\u{0112}', 207, 3),
+    ('\u{0113}', 210, 3),
+    ('\u{0114}', 213, 3),
+    ('\u{0115}', 216, 3),
+    ('\u{0116}', 219, 3),
+    ('\u{0117}', 222, 3),
+    ('\u{0118}', 225, 3),
+    ('\u{0119}', 228, 3),
+    ('\u{011A}', 231, 3),
+    ('\u{011B}', 234, 3),
+    ('\u{011C}', 237, 3),
+    ('\u{011D}', 240, 3),
+    ('\u{011E}', 243, 3),
+    ('\u{011F}', 246, 3),
+    ('\u{0120}', 249, 3),
+    ('\u{0121}', 252, 3),
+    ('\u{0122}', 255, 3),
+    ('\u{0123}', 258, 3),
+    ('\u{0124}', 261, 3),
+    ('\u{0125}', 264, 3),
+    ('\u{0128}', 267, 3),
+    ('\u{0129}', 270, 3),
+    ('\u{012A}', 273, 3),
+    ('\u{012B}', 276, 3),
+    ('\u{012C}', 279, 3),
+    ('\u{012D}', 282, 3),
+    ('\u{012E}', 285, 3),
+    ('\u{012F}', 288, 3),
+    ('\u{0130}', 291, 3),
+    ('\u{0134}', 294, 3),
+    ('\u{0135}', 297, 3),
+    ('\u{0136}', 300, 3),
+    ('\u{0137}', 303, 3),
+    ('\u{0139}', 306, 3),
+    ('\u{013A}', 309, 3),
+    ('\u{013B}', 312, 3),
+    ('\u{013C}', 315, 3),
+    ('\u{013D}', 318, 3),
+    ('\u{013E}', 321, 3),
+    ('\u{0143}', 324, 3),
+    ('\u{0144}', 327, 3),
+    ('\u{0145}', 330, 3),
+    ('\u{0146}', 333, 3),
+    ('\u{0147}', 336, 3),
+    ('\u{0148}', 339, 3),
+    ('\u{014C}', 342, 3),
+    ('\u{014D}', 345, 3),
+    ('\u{014E}', 348, 3),
+    ('\u{014F}', 351, 3),
+    ('\u{0150}', 354, 3),
+    ('\u{0151}', 357, 3),
+    ('\u{0154}', 360, 3),
+    ('\u{0155}', 363, 3),
+    ('\u{0156}', 366, 3),
+    ('\u{0157}', 369, 3),
+    ('\u{0158}', 372, 3),
+    ('\u{0159}', 375, 3),
+    ('\u{015A}', 378, 3),
+    ('\u{015B}', 381, 3),
+    ('\u{015C}', 384, 3),
+    ('\u{015D}', 387, 3),
+    ('\u{015E}', 390, 3),
+    ('\u{015F}', 393, 3),
+    ('\u{0160}', 396, 3),
+    ('\u{0161}', 399, 3),
+    ('\u{0162}', 402, 3),
+    ('\u{0163}', 405, 3),
+    ('\u{0164}', 408, 3),
+    ('\u{0165}', 411, 3),
+    ('\u{0168}', 414, 3),
+    ('\u{0169}', 417, 3),
+    ('\u{016A}', 420, 3),
+    ('\u{016B}', 423, 3),
+    ('\u{016C}', 426, 3),
+    ('\u{016D}', 429, 3),
+    ('\u{016E}', 432, 3),
+    ('\u{016F}', 435, 3),
+    ('\u{0170}', 438, 3),
+    ('\u{0171}', 441, 3),
+    ('\u{0172}', 444, 3),
+    ('\u{0173}', 447, 3),
+    ('\u{0174}', 450, 3),
+    ('\u{0175}', 453, 3),
+    ('\u{0176}', 456, 3),
+    ('\u{0177}', 459, 3),
+    ('\u{0178}', 462, 3),
+    ('\u{0179}', 465, 3),
+    ('\u{017A}', 468, 3),
+    ('\u{017B}', 471, 3),
+    ('\u{017C}', 474, 3),
+    ('\u{017D}', 477, 3),
+    ('\u{017E}', 480, 3),
+    ('\u{01A0}', 483, 3),
+    ('\u{01A1}', 486, 3),
+    ('\u{01AF}', 489, 3),
+    ('\u{01B0}', 492, 3),
+    ('\u{01CD}', 495, 3),
+    ('\u{01CE}', 498, 3),
+    ('\u{01CF}', 501, 3),
+    ('\u{01D0}', 504, 3),
+    ('\u{01D1}', 507, 3),
+    ('\u{01D2}', 510, 3),
+    ('\u{01D3}', 513, 3),
+    ('\u{01D4}', 516, 3),
+    ('\u{01D5}', 519, 5),
+    ('\u{01D6}', 524, 5),
+    ('\u{01D7}', 529, 5),
+    ('\u{01D8}', 534, 5),
+    ('\u{01D9}', 539, 5),
+    ('\u{01DA}', 544, 5),
+    ('\u{01DB}', 549, 5),
+    ('\u{01DC}', 554, 5),
+    ('\u{01DE}', 559, 5),
+    ('\u{01DF}', 564, 5),
+    ('\u{01E0}', 569, 5),
+    ('\u{01E1}', 574, 5),
+    ('\u{01E2}', 579, 4),
+    ('\u{01E3}', 583, 4),
+    ('\u{01E6}', 587, 3),
+    ('\u{01E7}', 590, 3),
+    ('\u{01E8}', 593, 3),
+    ('\u{01E9}', 596, 3),
+    ('\u{01EA}', 599, 3),
+    ('\u{01EB}', 602, 3),
+    ('\u{01EC}', 605, 5),
+    ('\u{01ED}', 610, 5),
+    ('\u{01EE}', 615, 4),
+    ('\u{01EF}', 619, 4),
+    ('\u{01F0}', 623, 3),
+    ('\u{01F4}', 626, 3),
+    ('\u{01F5}', 629, 3),
+    ('\u{01F8}', 632, 3),
+    ('\u{01F9}', 635, 3),
+    ('\u{01FA}', 638, 5),
+    ('\u{01FB}', 643, 5),
+    ('\u{01FC}', 648, 4),
+    ('\u{01FD}', 652, 4),
+    ('\u{01FE}', 656, 4),
+    ('\u{01FF}', 660, 4),
+    ('\u{0200}', 664, 3),
+    ('\u{0201}', 667, 3),
+    ('\u{0202}', 670, 3),
+    ('\u{0203}', 673, 3),
+    ('\u{0204}', 676, 3),
+    ('\u{0205}', 679, 3),
+    ('\u{0206}', 682, 3),
+    ('\u{0207}', 685, 3),
+    ('\u{0208}', 688, 3),
+    ('\u{0209}', 691, 3),
+    ('\u{020A}', 694, 3),
+    ('\u{020B}', 697, 3),
+    ('\u{020C}', 700, 3),
+    ('\u{020D}', 703, 3),
+    ('\u{020E}', 706, 3),
+    ('\u{020F}', 709, 3),
+    ('\u{0210}', 712, 3),
+    ('\u{0211}', 715, 3),
+    ('\u{0212}', 718, 3),
+    ('\u{0213}', 721, 3),
+    ('\u{0214}', 724, 3),
+    ('\u{0215}', 727, 3),
+    ('\u{0216}', 730, 3),
+    ('\u{0217}', 733, 3),
+    ('\u{0218}', 736, 3),
+    ('\u{0219}', 739, 3),
+    ('\u{021A}', 742, 3),
+    ('\u{021B}', 745, 3),
+    ('\u{021E}', 748, 3),
+    ('\u{021F}', 751, 3),
+    ('\u{0226}', 754, 3),
+    ('\u{0227}', 757, 3),
+    ('\u{0228}', 760, 3),
+    ('\u{0229}', 763, 3),
+    ('\u{022A}', 766, 5),
+    ('\u{022B}', 771, 5),
+    ('\u{022C}', 776, 5),
+    ('\u{022D}', 781, 5),
+    ('\u{022E}', 786, 3),
+    ('\u{022F}', 789, 3),
+    ('\u{0230}', 792, 5),
+    ('\u{0231}', 797, 5),
+    ('\u{0232}', 802, 3),
+    ('\u{0233}', 805, 3),
+    ('\u{0340}', 808, 2),
+    ('\u{0341}', 810, 2),
+    ('\u{0343}', 812, 2),
+    ('\u{0344}', 814, 4),
+    ('\u{0374}', 818, 2),
+    ('\u{037E}', 820, 1),
+    ('\u{0385}', 821, 4),
+    ('\u{0386}', 825, 4),
+    ('\u{0387}', 829, 2),
+    ('\u{0388}', 831, 4),
+    ('\u{0389}', 835, 4),
+    ('\u{038A}', 839, 4),
+    ('\u{038C}', 843, 4),
+    ('\u{038E}', 847, 4),
+    ('\u{038F}', 851, 4),
+    ('\u{0390}', 855, 6),
+    ('\u{03AA}', 861, 4),
+    ('\u{03AB}', 865, 4),
+    ('\u{03AC}', 869, 4),
+    ('\u{03AD}', 873, 4),
+    ('\u{03AE}', 877, 4),
+    ('\u{03AF}', 881, 4),
+    ('\u{03B0}', 885, 6),
+    ('\u{03CA}', 891, 4),
+    ('\u{03CB}', 895, 4),
+    ('\u{03CC}', 899, 4),
+    ('\u{03CD}', 903, 4),
+    ('\u{03CE}', 907, 4),
+    ('\u{03D3}', 911, 4),
+    ('\u{03D4}', 915, 4),
+    ('\u{0400}', 919, 4),
+    ('\u{0401}', 923, 4),
+    ('\u{0403}', 927, 4),
+    ('\u{0407}', 931, 4),
+    ('\u{040C}', 935, 4),
+    ('\u{040D}', 939, 4),
+    ('\u{040E}', 943, 4),
+    ('\u{0419}', 947, 4),
+    ('\u{0439}', 951, 4),
+    ('\u{0450}', 955, 4),
+    ('\u{0451}', 959, 4),
+    ('\u{0453}', 963, 4),
+    ('\u{0457}', 967, 4),
+    ('\u{045C}', 971, 4),
+    ('\u{045D}', 975, 4),
+    ('\u{045E}', 979, 4),
+    ('\u{0476}', 983, 4),
+    ('\u{0477}', 987, 4),
+    ('\u{04C1}', 991, 4),
+    ('\u{04C2}', 995, 4),
+    ('\u{04D0}', 999, 4),
+    ('\u{04D1}', 1003, 4),
+    ('\u{04D2}', 1007, 4),
+    ('\u{04D3}', 1011, 4),
+    ('\u{04D6}', 1015, 4),
+    ('\u{04D7}', 1019, 4),
+    ('\u{04DA}', 1023, 4),
+    ('\u{04DB}', 1027, 4),
+    ('\u{04DC}', 1031, 4),
+    ('\u{04DD}', 1035, 4),
+    ('\u{04DE}', 1039, 4),
+    ('\u{04DF}', 1043, 4),
+    ('\u{04E2}', 1047, 4),
+    ('\u{04E3}', 1051, 4),
+    ('\u{04E4}', 1055, 4),
+    ('\u{04E5}', 1059, 4),
+    ('\u{04E6}', 1063, 4),
+    ('\u{04E7}', 1067, 4),
+    ('\u{04EA}', 1071, 4),
+    ('\u{04EB}', 1075, 4),
+    ('\u{04EC}', 1079, 4),
+    ('\u{04ED}', 1083, 4),
+    ('\u{04EE}', 1087, 4),
+    ('\u{04EF}', 1091, 4),
+    ('\u{04F0}', 1095, 4),
+    ('\u{04F1}', 1099, 4),
+    ('\u{04F2}', 1103, 4),
+    ('\u{04F3}', 1107, 4),
+    ('\u{04F4}', 1111, 4),
+    ('\u{04F5}', 1115, 4),
+    ('\u{04F8}', 1119, 4),
+    ('\u{04F9}', 1123, 4),
+    ('\u{0622}', 1127, 4),
+    ('\u{0623}', 1131, 4),
+    ('\u{0624}', 1135, 4),
+    ('\u{0625}', 1139, 4),
+    ('\u{0626}', 1143, 4),
+    ('\u{06C0}', 1147, 4),
+    ('\u{06C2}', 1151, 4),
+    ('\u{06D3}', 1155, 4),
+    ('\u{0929}', 1159, 6),
+    ('\u{0931}', 1165, 6),
+    ('\u{0934}', 1171, 6),
+    ('\u{0958}', 1177, 6),
+    ('\u{0959}', 1183, 6),
+    ('\u{095A}', 1189, 6),
+    ('\u{095B}', 1195, 6),
+    ('\u{095C}', 1201, 6),
+    ('\u{095D}', 1207, 6),
+    ('\u{095E}', 1213, 6),
+    ('\u{095F}', 1219, 6),
+    ('\u{09CB}', 1225, 6),
+    ('\u{09CC}', 1231, 6),
+    ('\u{09DC}', 1237, 6),
+    ('\u{09DD}', 1243, 6),
+    ('\u{09DF}', 1249, 6),
+    ('\u{0A33}', 1255, 6),
+    ('\u{0A36}', 1261, 6),
+    ('\u{0A59}', 1267, 6),
+    ('\u{0A5A}', 1273, 6),
+    ('\u{0A5B}', 1279, 6),
+    ('\u{0A5E}', 1285, 6),
+    ('\u{0B48}', 1291, 6),
+    ('\u{0B4B}', 1297, 6),
+    ('\u{0B4C}', 1303, 6),
+    ('\u{0B5C}', 1309, 6),
+    ('\u{0B5D}', 1315, 6),
+    ('\u{0B94}', 1321, 6),
+    ('\u{0BCA}', 1327, 6),
+    ('\u{0BCB}', 1333, 6),
+    ('\u{0BCC}', 1339, 6),
+    ('\u{0C48}', 1345, 6),
+    ('\u{0CC0}', 1351, 6),
+    ('\u{0CC7}', 1357, 6),
+    ('\u{0CC8}', 1363, 6),
+    ('\u{0CCA}', 1369, 6),
+    ('\u{0CCB}', 1375, 9),
+    ('\u{0D4A}', 1384, 6),
+    ('\u{0D4B}', 1390, 6),
+    ('\u{0D4C}', 1396, 6),
+    ('\u{0DDA}', 1402, 6),
+    ('\u{0DDC}', 1408, 6),
+    ('\u{0DDD}', 1414, 9),
+    ('\u{0DDE}', 1423, 6),
+    ('\u{0F43}', 1429, 6),
+    ('\u{0F4D}', 1435, 6),
+    ('\u{0F52}', 1441, 6),
+    ('\u{0F57}', 1447, 6),
+    ('\u{0F5C}', 1453, 6),
+    ('\u{0F69}', 1459, 6),
+    ('\u{0F73}', 1465, 6),
+    ('\u{0F75}', 1471, 6),
+    ('\u{0F76}', 1477, 6),
+    ('\u{0F78}', 1483, 6),
+    ('\u{0F81}', 1489, 6),
+    ('\u{0F93}', 1495, 6),
+    ('\u{0F9D}', 1501, 6),
+    ('\u{0FA2}', 1507, 6),
+    ('\u{0FA7}', 1513, 6),
+    ('\u{0FAC}', 1519, 6),
+    ('\u{0FB9}', 1525, 6),
+    ('\u{1026}', 1531, 6),
+    ('\u{1B06}', 1537, 6),
+    ('\u{1B08}', 1543, 6),
+    ('\u{1B0A}', 1549, 6),
+    ('\u{1B0C}', 1555, 6),
+    ('\u{1B0E}', 1561, 6),
+    ('\u{1B12}', 1567, 6),
+    ('\u{1B3B}', 1573, 6),
+    ('\u{1B3D}', 1579, 6),
+    ('\u{1B40}', 1585, 6),
+    ('\u{1B41}', 1591, 6),
+    ('\u{1B43}', 1597, 6),
+    ('\u{1E00}', 1603, 3),
+    ('\u{1E01}', 1606, 3),
+    ('\u{1E02}', 1609, 3),
+    ('\u{1E03}', 1612, 3),
+    ('\u{1E04}', 1615, 3),
+    ('\u{1E05}', 1618, 3),
+    ('\u{1E06}', 1621, 3),
+    ('\u{1E07}', 1624, 3),
+    ('\u{1E08}', 1627, 5),
+    ('\u{1E09}', 1632, 5),
+    ('\u{1E0A}', 1637, 3),
+    ('\u{1E0B}', 1640, 3),
+    ('\u{1E0C}', 1643, 3),
+    ('\u{1E0D}', 1646, 3),
+    ('\u{1E0E}', 1649, 3),
+    ('\u{1E0F}', 1652, 3),
+    ('\u{1E10}', 1655, 3),
+    ('\u{1E11}', 1658, 3),
+    ('\u{1E12}', 1661, 3),
+    ('\u{1E13}', 1664, 3),
+    ('\u{1E14}', 1667, 5),
+    ('\u{1E15}', 1672, 5),
+    ('\u{1E16}', 1677, 5),
+    ('\u{1E17}', 1682, 5),
+    ('\u{1E18}', 1687, 3),
+    ('\u{1E19}', 1690, 3),
+    ('\u{1E1A}', 1693, 3),
+    ('\u{1E1B}', 1696, 3),
+    ('\u{1E1C}', 1699, 5),
+    ('\u{1E1D}', 1704, 5),
+    ('\u{1E1E}', 1709, 3),
+    ('\u{1E1F}', 1712, 3),
+    ('\u{1E20}', 1715, 3),
+    ('\u{1E21}', 1718, 3),
+    ('\u{1E22}', 1721, 3),
+    ('\u{1E23}', 1724, 3),
+    ('\u{1E24}', 1727, 3),
+    ('\u{1E25}', 1730, 3),
+    ('\u{1E26}', 1733, 3),
+    ('\u{1E27}', 1736, 3),
+    ('\u{1E28}', 1739, 3),
+    ('\u{1E29}', 1742, 3),
+    ('\u{1E2A}', 1745, 3),
+    ('\u{1E2B}', 1748, 3),
+    ('\u{1E2C}', 1751, 3),
+    ('\u{1E2D}', 1754, 3),
+    ('\u{1E2E}', 1757, 5),
+    ('\u{1E2F}', 1762, 5),
+    ('\u{1E30}', 1767, 3),
+    ('\u{1E31}', 1770, 3),
+    ('\u{1E32}', 1773, 3),
+    ('\u{1E33}', 1776, 3),
+    ('\u{1E34}', 1779, 3),
+    ('\u{1E35}', 1782, 3),
+    ('\u{1E36}', 1785, 3),
+    ('\u{1E37}', 1788, 3),
+    ('\u{1E38}', 1791, 5),
+    ('\u{1E39}', 1796, 5),
+    ('\u{1E3A}', 1801, 3),
+    ('\u{1E3B}', 1804, 3),
+    ('\u{1E3C}', 1807, 3),
+    ('\u{1E3D}', 1810, 3),
+    ('\u{1E3E}', 1813, 3),
+    ('\u{1E3F}', 1816, 3),
+    ('\u{1E40}', 1819, 3),
+    ('\u{1E41}', 1822, 3),
+    ('\u{1E42}', 1825, 3),
+    ('\u{1E43}', 1828, 3),
+    ('\u{1E44}', 1831, 3),
+    ('\u{1E45}', 1834, 3),
+    ('\u{1E46}', 1837, 3),
+    ('\u{1E47}', 1840, 3),
+    ('\u{1E48}', 1843, 3),
+    ('\u{1E49}', 1846, 3),
+    ('\u{1E4A}', 1849, 3),
+    ('\u{1E4B}', 1852, 3),
+    ('\u{1E4C}', 1855, 5),
+    ('\u{1E4D}', 1860, 5),
+    ('\u{1E4E}', 1865, 5),
+    ('\u{1E4F}', 1870, 5),
+    ('\u{1E50}', 1875, 5),
+    ('\u{1E51}', 1880, 5),
+    ('\u{1E52}', 1885, 5),
+    ('\u{1E53}', 1890, 5),
+    ('\u{1E54}', 1895, 3),
+    ('\u{1E55}', 1898, 3),
+    ('\u{1E56}', 1901, 3),
+    ('\u{1E57}', 1904, 3),
+    ('\u{1E58}', 1907, 3),
+    ('\u{1E59}', 1910, 3),
+    ('\u{1E5A}', 1913, 3),
+    ('\u{1E5B}', 1916, 3),
+    ('\u{1E5C}', 1919, 5),
+    ('\u{1E5D}', 1924, 5),
+    ('\u{1E5E}', 1929, 3),
+    ('\u{1E5F}', 1932, 3),
+    ('\u{1E60}', 1935, 3),
+    ('\u{1E61}', 1938, 3),
+    ('\u{1E62}', 1941, 3),
+    ('\u{1E63}', 1944, 3),
+    ('\u{1E64}', 1947, 5),
+    ('\u{1E65}', 1952, 5),
+    ('\u{1E66}', 1957, 5),
+    ('\u{1E67}', 1962, 5),
+    ('\u{1E68}', 1967, 5),
+    ('\u{1E69}', 1972, 5),
+    ('\u{1E6A}', 1977, 3),
+    ('\u{1E6B}', 1980, 3),
+    ('\u{1E6C}', 1983, 3),
+    ('\u{1E6D}', 1986, 3),
+    ('\u{1E6E}', 1989, 3),
+    ('\u{1E6F}', 1992, 3),
+    ('\u{1E70}', 1995, 3),
+    ('\u{1E71}', 1998, 3),
+    ('\u{1E72}', 2001, 3),
+    ('\u{1E73}', 2004, 3),
+    ('\u{1E74}', 2007, 3),
+    ('\u{1E75}', 2010, 3),
+    ('\u{1E76}', 2013, 3),
+    ('\u{1E77}', 2016, 3),
+    ('\u{1E78}', 2019, 5),
+    ('\u{1E79}', 2024, 5),
+    ('\u{1E7A}', 2029, 5),
+    ('\u{1E7B}', 2034, 5),
+    ('\u{1E7C}', 2039, 3),
+    ('\u{1E7D}', 2042, 3),
+    ('\u{1E7E}', 2045, 3),
+    ('\u{1E7F}', 2048, 3),
+    ('\u{1E80}', 2051, 3),
+    ('\u{1E81}', 2054, 3),
+    ('\u{1E82}', 2057, 3),
+    ('\u{1E83}', 2060, 3),
+    ('\u{1E84}', 2063, 3),
+    ('\u{1E85}', 2066, 3),
+    ('\u{1E86}', 2069, 3),
+    ('\u{1E87}', 2072, 3),
+    ('\u{1E88}', 2075, 3),
+    ('\u{1E89}', 2078, 3),
+    ('\u{1E8A}', 2081, 3),
+    ('\u{1E8B}', 2084, 3),
+    ('\u{1E8C}', 2087, 3),
+    ('\u{1E8D}', 2090, 3),
+    ('\u{1E8E}', 2093, 3),
+    ('\u{1E8F}', 2096, 3),
+    ('\u{1E90}', 2099, 3),
+    ('\u{1E91}', 2102, 3),
+    ('\u{1E92}', 2105, 3),
+    ('\u{1E93}', 2108, 3),
+    ('\u{1E94}', 2111, 3),
+    ('\u{1E95}', 2114, 3),
+    ('\u{1E96}', 2117, 3),
+    ('\u{1E97}', 2120, 3),
+    ('\u{1E98}', 2123, 3),
+    ('\u{1E99}', 2126, 3),
+    ('\u{1E9B}', 2129, 4),
+    ('\u{1EA0}', 2133, 3),
+    ('\u{1EA1}', 2136, 3),
+    ('\u{1EA2}', 2139, 3),
+    ('\u{1EA3}', 2142, 3),
+    ('\u{1EA4}', 2145, 5),
+    ('\u{1EA5}', 2150, 5),
+    ('\u{1EA6}', 2155, 5),
+    ('\u{1EA7}', 2160, 5),
+    ('\u{1EA8}', 2165, 5),
+    ('\u{1EA9}', 2170, 5),
+    ('\u{1EAA}', 2175, 5),
+    ('\u{1EAB}', 2180, 5),
+    ('\u{1EAC}', 2185, 5),
+    ('\u{1EAD}', 2190, 5),
+    ('\u{1EAE}', 2195, 5),
+    ('\u{1EAF}', 2200, 5),
+    ('\u{1EB0}', 2205, 5),
+    ('\u{1EB1}', 2210, 5),
+    ('\u{1EB2}', 2215, 5),
+    ('\u{1EB3}', 2220, 5),
+    ('\u{1EB4}', 2225, 5),
+    ('\u{1EB5}', 2230, 5),
+    ('\u{1EB6}', 2235, 5),
+    ('\u{1EB7}', 2240, 5),
+    ('\u{1EB8}', 2245, 3),
+    ('\u{1EB9}', 2248, 3),
+    ('\u{1EBA}', 2251, 3),
+    ('\u{1EBB}', 2254, 3),
+    ('\u{1EBC}', 2257, 3),
+    ('\u{1EBD}', 2260, 3),
+    ('\u{1EBE}', 2263, 5),
+    ('\u{1EBF}', 2268, 5),
+    ('\u{1EC0}', 2273, 5),
+    ('\u{1EC1}', 2278, 5),
+    ('\u{1EC2}', 2283, 5),
+    ('\u{1EC3}', 2288, 5),
+    ('\u{1EC4}', 2293, 5),
+    ('\u{1EC5}', 2298, 5),
+    ('\u{1EC6}', 2303, 5),
+    ('\u{1EC7}', 2308, 5),
+    ('\u{1EC8}', 2313, 3),
+    ('\u{1EC9}', 2316, 3),
+    ('\u{1ECA}', 2319, 3),
+    ('\u{1ECB}', 2322, 3),
+    ('\u{1ECC}', 2325, 3),
+    ('\u{1ECD}', 2328, 3),
+    ('\u{1ECE}', 2331, 3),
+    ('\u{1ECF}', 2334, 3),
+    ('\u{1ED0}', 2337, 5),
+    ('\u{1ED1}', 2342, 5),
+    ('\u{1ED2}', 2347, 5),
+    ('\u{1ED3}', 2352, 5),
+    ('\u{1ED4}', 2357, 5),
+    ('\u{1ED5}', 2362, 5),
+    ('\u{1ED6}', 2367, 5),
+    ('\u{1ED7}', 2372, 5),
+    ('\u{1ED8}', 2377, 5),
+    ('\u{1ED9}', 2382, 5),
+    ('\u{1EDA}', 2387, 5),
+    ('\u{1EDB}', 2392, 5),
+    ('\u{1EDC}', 2397, 5),
+    ('\u{1EDD}', 2402, 5),
+    ('\u{1EDE}', 2407, 5),
+    ('\u{1EDF}', 2412, 5),
+    ('\u{1EE0}', 2417, 5),
+    ('\u{1EE1}', 2422, 5),
+    ('\u{1EE2}', 2427, 5),
+    ('\u{1EE3}', 2432, 5),
+    ('\u{1EE4}', 2437, 3),
+    ('\u{1EE5}', 2440, 3),
+    ('\u{1EE6}', 2443, 3),
+    ('\u{1EE7}', 2446, 3),
+    ('\u{1EE8}', 2449, 5),
+    ('\u{1EE9}', 2454, 5),
+    ('\u{1EEA}', 2459, 5),
+    ('\u{1EEB}', 2464, 5),
+    ('\u{1EEC}', 2469, 5),
+    ('\u{1EED}', 2474, 5),
+    ('\u{1EEE}', 2479, 5),
+    ('\u{1EEF}', 2484, 5),
+    ('\u{1EF0}', 2489, 5),
+    ('\u{1EF1}', 2494, 5),
+    ('\u{1EF2}', 2499, 3),
+    ('\u{1EF3}', 2502, 3),
+    ('\u{1EF4}', 2505, 3),
+    ('\u{1EF5}', 2508, 3),
+    ('\u{1EF6}', 2511, 3),
+    ('\u{1EF7}', 2514, 3),
+    ('\u{1EF8}', 2517, 3),
+    ('\u{1EF9}', 2520, 3),
+    ('\u{1F00}', 2523, 4),
+    ('\u{1F01}', 2527, 4),
+    ('\u{1F02}', 2531, 6),
+    ('\u{1F03}', 2537, 6),
+    ('\u{1F04}', 2543, 6),
+    ('\u{1F05}', 2549, 6),
+    ('\u{1F06}', 2555, 6),
+    ('\u{1F07}', 2561, 6),
+    ('\u{1F08}', 2567, 4),
+    ('\u{1F09}', 2571, 4),
+    ('\u{1F0A}', 2575, 6),
+    ('\u{1F0B}', 2581, 6),
+    ('\u{1F0C}', 2587, 6),
+    ('\u{1F0D}', 2593, 6),
+    ('\u{1F0E}', 2599, 6),
+    ('\u{1F0F}', 2605, 6),
+    ('\u{1F10}', 2611, 4),
+    ('\u{1F11}', 2615, 4),
+    ('\u{1F12}', 2619, 6),
+    ('\u{1F13}', 2625, 6),
+    ('\u{1F14}', 2631, 6),
+    ('\u{1F15}', 2637, 6),
+    ('\u{1F18}', 2643, 4),
+    ('\u{1F19}', 2647, 4),
+    ('\u{1F1A}', 2651, 6),
+    ('\u{1F1B}', 2657, 6),
+    ('\u{1F1C}', 2663, 6),
+    ('\u{1F1D}', 2669, 6),
+    ('\u{1F20}', 2675, 4),
+    ('\u{1F21}', 2679, 4),
+    ('\u{1F22}', 2683, 6),
+    ('\u{1F23}', 2689, 6),
+    ('\u{1F24}', 2695, 6),
+    ('\u{1F25}', 2701, 6),
+    ('\u{1F26}', 2707, 6),
+    ('\u{1F27}', 2713, 6),
+    ('\u{1F28}', 2719, 4),
+    ('\u{1F29}', 2723, 4),
+    ('\u{1F2A}', 2727, 6),
+    ('\u{1F2B}', 2733, 6),
+    ('\u{1F2C}', 2739, 6),
+    ('\u{1F2D}', 2745, 6),
+    ('\u{1F2E}', 2751, 6),
+    ('\u{1F2F}', 2757, 6),
+    ('\u{1F30}', 2763, 4),
+    ('\u{1F31}', 2767, 4),
+    ('\u{1F32}', 2771, 6),
+    ('\u{1F33}', 2777, 6),
+    ('\u{1F34}', 2783, 6),
+    ('\u{1F35}', 2789, 6),
+    ('\u{1F36}', 2795, 6),
+    ('\u{1F37}', 2801, 6),
+    ('\u{1F38}', 2807, 4),
+    ('\u{1F39}', 2811, 4),
+    ('\u{1F3A}', 2815, 6),
+    ('\u{1F3B}', 2821, 6),
+    ('\u{1F3C}', 2827, 6),
+    ('\u{1F3D}', 2833, 6),
+    ('\u{1F3E}', 2839, 6),
+    ('\u{1F3F}', 2845, 6),
+    ('\u{1F40}', 2851, 4),
+    ('\u{1F41}', 2855, 4),
+    ('\u{1F42}', 2859, 6),
+    ('\u{1F43}', 2865, 6),
+    ('\u{1F44}', 2871, 6),
+    ('\u{1F45}', 2877, 6),
+    ('\u{1F48}', 2883, 4),
+    ('\u{1F49}', 2887, 4),
+    ('\u{1F4A}', 2891, 6),
+    ('\u{1F4B}', 2897, 6),
+    ('\u{1F4C}', 2903, 6),
+    ('\u{1F4D}', 2909, 6),
+    ('\u{1F50}', 2915, 4),
+    ('\u{1F51}', 2919, 4),
+    ('\u{1F52}', 2923, 6),
+    ('\u{1F53}', 2929, 6),
+    ('\u{1F54}', 2935, 6),
+    ('\u{1F55}', 2941, 6),
+    ('\u{1F56}', 2947, 6),
+    ('\u{1F57}', 2953, 6),
+    ('\u{1F59}', 2959, 4),
+    ('\u{1F5B}', 2963, 6),
+    ('\u{1F5D}', 2969, 6),
+    ('\u{1F5F}', 2975, 6),
+    ('\u{1F60}', 2981, 4),
+    ('\u{1F61}', 2985, 4),
+    ('\u{1F62}', 2989, 6),
+    ('\u{1F63}', 2995, 6),
+    ('\u{1F64}', 3001, 6),
+    ('\u{1F65}', 3007, 6),
+    ('\u{1F66}', 3013, 6),
+    ('\u{1F67}', 3019, 6),
+    ('\u{1F68}', 3025, 4),
+    ('\u{1F69}', 3029, 4),
+    ('\u{1F6A}', 3033, 6),
+    ('\u{1F6B}', 3039, 6),
+    ('\u{1F6C}', 3045, 6),
+    ('\u{1F6D}', 3051, 6),
+    ('\u{1F6E}', 3057, 6),
+    ('\u{1F6F}', 3063, 6),
+    ('\u{1F70}', 3069, 4),
+    ('\u{1F71}', 869, 4),
+    ('\u{1F72}', 3073, 4),
+    ('\u{1F73}', 873, 4),
+    ('\u{1F74}', 3077, 4),
+    ('\u{1F75}', 877, 4),
+    ('\u{1F76}', 3081, 4),
+    ('\u{1F77}', 881, 4),
+    ('\u{1F78}', 3085, 4),
+    ('\u{1F79}', 899, 4),
+    ('\u{1F7A}', 3089, 4),
+    ('\u{1F7B}', 903, 4),
+    ('\u{1F7C}', 3093, 4),
+    ('\u{1F7D}', 907, 4),
+    ('\u{1F80}', 3097, 6),
+    ('\u{1F81}', 3103, 6),
+    ('\u{1F82}', 3109, 8),
+    ('\u{1F83}', 3117, 8),
+    ('\u{1F84}', 3125, 8),
+    ('\u{1F85}', 3133, 8),
+    ('\u{1F86}', 3141, 8),
+    ('\u{1F87}', 3149, 8),
+    ('\u{1F88}', 3157, 6),
+    ('\u{1F89}', 3163, 6),
+    ('\u{1F8A}', 3169, 8),
+    ('\u{1F8B}', 3177, 8),
+    ('\u{1F8C}', 3185, 8),
+    ('\u{1F8D}', 3193, 8),
+    ('\u{1F8E}', 3201, 8),
+    ('\u{1F8F}', 3209, 8),
+    ('\u{1F90}', 3217, 6),
+    ('\u{1F91}', 3223, 6),
+    ('\u{1F92}', 3229, 8),
+    ('\u{1F93}', 3237, 8),
+    ('\u{1F94}', 3245, 8),
+    ('\u{1F95}', 3253, 8),
+    ('\u{1F96}', 3261, 8),
+    ('\u{1F97}', 3269, 8),
+    ('\u{1F98}', 3277, 6),
+    ('\u{1F99}', 3283, 6),
+    ('\u{1F9A}', 3289, 8),
+    ('\u{1F9B}', 3297, 8),
+    ('\u{1F9C}', 3305, 8),
+    ('\u{1F9D}', 3313, 8),
+    ('\u{1F9E}', 3321, 8),
+    ('\u{1F9F}', 3329, 8),
+    ('\u{1FA0}', 3337, 6),
+    ('\u{1FA1}', 3343, 6),
+    ('\u{1FA2}', 3349, 8),
+    ('\u{1FA3}', 3357, 8),
+    ('\u{1FA4}', 3365, 8),
+    ('\u{1FA5}', 3373, 8),
+    ('\u{1FA6}', 3381, 8),
+    ('\u{1FA7}', 3389, 8),
+    ('\u{1FA8}', 3397, 6),
+    ('\u{1FA9}', 3403, 6),
+    ('\u{1FAA}', 3409, 8),
+    ('\u{1FAB}', 3417, 8),
+    ('\u{1FAC}', 3425, 8),
+    ('\u{1FAD}', 3433, 8),
+    ('\u{1FAE}', 3441, 8),
+    ('\u{1FAF}', 3449, 8),
+    ('\u{1FB0}', 3457, 4),
+    ('\u{1FB1}', 3461, 4),
+    ('\u{1FB2}', 3465, 6),
+    ('\u{1FB3}', 3471, 4),
+    ('\u{1FB4}', 3475, 6),
+    ('\u{1FB6}', 3481, 4),
+    ('\u{1FB7}', 3485, 6),
+    ('\u{1FB8}', 3491, 4),
+    ('\u{1FB9}', 3495, 4),
+    ('\u{1FBA}', 3499, 4),
+    ('\u{1FBB}', 825, 4),
+    ('\u{1FBC}', 3503, 4),
+    ('\u{1FBE}', 3507, 2),
+    ('\u{1FC1}', 3509, 4),
+    ('\u{1FC2}', 3513, 6),
+    ('\u{1FC3}', 3519, 4),
+    ('\u{1FC4}', 3523, 6),
+    ('\u{1FC6}', 3529, 4),
+    ('\u{1FC7}', 3533, 6),
+    ('\u{1FC8}', 3539, 4),
+    ('\u{1FC9}', 831, 4),
+    ('\u{1FCA}', 3543, 4),
+    ('\u{1FCB}', 835, 4),
+    ('\u{1FCC}', 3547, 4),
+    ('\u{1FCD}', 3551, 5),
+    ('\u{1FCE}', 3556, 5),
+    ('\u{1FCF}', 3561, 5),
+    ('\u{1FD0}', 3566, 4),
+    ('\u{1FD1}', 3570, 4),
+    ('\u{1FD2}', 3574, 6),
+    ('\u{1FD3}', 855, 6),
+    ('\u{1FD6}', 3580, 4),
+    ('\u{1FD7}', 3584, 6),
+    ('\u{1FD8}', 3590, 4),
+    ('\u{1FD9}', 3594, 4),
+    ('\u{1FDA}', 3598, 4),
+    ('\u{1FDB}', 839, 4),
+    ('\u{1FDD}', 3602, 5),
+    ('\u{1FDE}', 3607, 5),
+    ('\u{1FDF}', 3612, 5),
+    ('\u{1FE0}', 3617, 4),
+    ('\u{1FE1}', 3621, 4),
+    ('\u{1FE2}', 3625, 6),
+    ('\u{1FE3}', 885, 6),
+    ('\u{1FE4}', 3631, 4),
+    ('\u{1FE5}', 3635, 4),
+    ('\u{1FE6}', 3639, 4),
+    ('\u{1FE7}', 3643, 6),
+    ('\u{1FE8}', 3649, 4),
+    ('\u{1FE9}', 3653, 4),
+    ('\u{1FEA}', 3657, 4),
+    ('\u{1FEB}', 847, 4),
+    ('\u{1FEC}', 3661, 4),
+    ('\u{1FED}', 3665, 4),
+    ('\u{1FEE}', 821, 4),
+    ('\u{1FEF}', 3669, 1),
+    ('\u{1FF2}', 3670, 6),
+    ('\u{1FF3}', 3676, 4),
+    ('\u{1FF4}', 3680, 6),
+    ('\u{1FF6}', 3686, 4),
+    ('\u{1FF7}', 3690, 6),
+    ('\u{1FF8}', 3696, 4),
+    ('\u{1FF9}', 843, 4),
+    ('\u{1FFA}', 3700, 4),
+    ('\u{1FFB}', 851, 4),
+    ('\u{1FFC}', 3704, 4),
+    ('\u{1FFD}', 3708, 2),
+    ('\u{2000}', 3710, 3),
+    ('\u{2001}', 3713, 3),
+    ('\u{2126}', 3716, 2),
+    ('\u{212A}', 3718, 1),
+    ('\u{212B}', 15, 3),
+    ('\u{219A}', 3719, 5),
+    ('\u{219B}', 3724, 5),
+    ('\u{21AE}', 3729, 5),
+    ('\u{21CD}', 3734, 5),
+    ('\u{21CE}', 3739, 5),
+    ('\u{21CF}', 3744, 5),
+    ('\u{2204}', 3749, 5),
+    ('\u{2209}', 3754, 5),
+    ('\u{220C}', 3759, 5),
+    ('\u{2224}', 3764, 5),
+    ('\u{2226}', 3769, 5),
+    ('\u{2241}', 3774, 5),
+    ('\u{2244}', 3779, 5),
+    ('\u{2247}', 3784, 5),
+    ('\u{2249}', 3789, 5),
+    ('\u{2260}', 3794, 3),
+    ('\u{2262}', 3797, 5),
+    ('\u{226D}', 3802, 5),
+    ('\u{226E}', 3807, 3),
+    ('\u{226F}', 3810, 3),
+    ('\u{2270}', 3813, 5),
+    ('\u{2271}', 3818, 5),
+    ('\u{2274}', 3823, 5),
+    ('\u{2275}', 3828, 5),
+    ('\u{2278}', 3833, 5),
+    ('\u{2279}', 3838, 5),
+    ('\u{2280}', 3843, 5),
+    ('\u{2281}', 3848, 5),
+    ('\u{2284}', 3853, 5),
+    ('\u{2285}', 3858, 5),
+    ('\u{2288}', 3863, 5),
+    ('\u{2289}', 3868, 5),
+    ('\u{22AC}', 3873, 5),
+    ('\u{22AD}', 3878, 5),
+    ('\u{22AE}', 3883, 5),
+    ('\u{22AF}', 3888, 5),
+    ('\u{22E0}', 3893, 5),
+    ('\u{22E1}', 3898, 5),
+    ('\u{22E2}', 3903, 5),
+    ('\u{22E3}', 3908, 5),
+    ('\u{22EA}', 3913, 5),
+    ('\u{22EB}', 3918, 5),
+    ('\u{22EC}', 3923, 5),
+    ('\u{22ED}', 3928, 5),
+    ('\u{2329}', 3933, 3),
+    ('\u{232A}', 3936, 3),
+    ('\u{2ADC}', 3939, 5),
+    ('\u{304C}', 3944, 6),
+    ('\u{304E}', 3950, 6),
+    ('\u{3050}', 3956, 6),
+    ('\u{3052}', 3962, 6),
+    ('\u{3054}', 3968, 6),
+    ('\u{3056}', 3974, 6),
+    ('\u{3058}', 3980, 6),
+    ('\u{305A}', 3986, 6),
+    ('\u{305C}', 3992, 6),
+    ('\u{305E}', 3998, 6),
+    ('\u{3060}', 4004, 6),
+    ('\u{3062}', 4010, 6),
+    ('\u{3065}', 4016, 6),
+    ('\u{3067}', 4022, 6),
+    ('\u{3069}', 4028, 6),
+    ('\u{3070}', 4034, 6),
+    ('\u{3071}', 4040, 6),
+    ('\u{3073}', 4046, 6),
+    ('\u{3074}', 4052, 6),
+    ('\u{3076}', 4058, 6),
+    ('\u{3077}', 4064, 6),
+    ('\u{3079}', 4070, 6),
+    ('\u{307A}', 4076, 6),
+    ('\u{307C}', 4082, 6),
+    ('\u{307D}', 4088, 6),
+    ('\u{3094}', 4094, 6),
+    ('\u{309E}', 4100, 6),
+    ('\u{30AC}', 4106, 6),
+    ('\u{30AE}', 4112, 6),
+    ('\u{30B0}', 4118, 6),
+    ('\u{30B2}', 4124, 6),
+    ('\u{30B4}', 4130, 6),
+    ('\u{30B6}', 4136, 6),
+    ('\u{30B8}', 4142, 6),
+    ('\u{30BA}', 4148, 6),
+    ('\u{30BC}', 4154, 6),
+    ('\u{30BE}', 4160, 6),
+    ('\u{30C0}', 4166, 6),
+    ('\u{30C2}', 4172, 6),
+    ('\u{30C5}', 4178, 6),
+    ('\u{30C7}', 4184, 6),
+    ('\u{30C9}', 4190, 6),
+    ('\u{30D0}', 4196, 6),
+    ('\u{30D1}', 4202, 6),
+    ('\u{30D3}', 4208, 6),
+    ('\u{30D4}', 4214, 6),
+    ('\u{30D6}', 4220, 6),
+    ('\u{30D7}', 4226, 6),
+    ('\u{30D9}', 4232, 6),
+    ('\u{30DA}', 4238, 6),
+    ('\u{30DC}', 4244, 6),
+    ('\u{30DD}', 4250, 6),
+    ('\u{30F4}', 4256, 6),
+    ('\u{30F7}', 4262, 6),
+    ('\u{30F8}', 4268, 6),
+    ('\u{30F9}', 4274, 6),
+    ('\u{30FA}', 4280, 6),
+    ('\u{30FE}', 4286, 6),
+    ('\u{F900}', 4292, 3),
+    ('\u{F901}', 4295, 3),
+    ('\u{F902}', 4298, 3),
+    ('\u{F903}', 4301, 3),
+    ('\u{F904}', 4304, 3),
+    ('\u{F905}', 4307, 3),
+    ('\u{F906}', 4310, 3),
+    ('\u{F907}', 4313, 3),
+    ('\u{F908}', 4313, 3),
+    ('\u{F909}', 4316, 3),
+    ('\u{F90A}', 4319, 3),
+    ('\u{F90B}', 4322, 3),
+    ('\u{F90C}', 4325, 3),
+    ('\u{F90D}', 4328, 3),
+    ('\u{F90E}', 4331, 3),
+    ('\u{F90F}', 4334, 3),
+    ('\u{F910}', 4337, 3),
+    ('\u{F911}', 4340, 3),
+    ('\u{F912}', 4343, 3),
+    ('\u{F913}', 4346, 3),
+    ('\u{F914}', 4349, 3),
+    ('\u{F915}', 4352, 3),
+    ('\u{F916}', 4355, 3),
+    ('\u{F917}', 4358, 3),
+    ('\u{F918}', 4361, 3),
+    ('\u{F919}', 4364, 3),
+    ('\u{F91A}', 4367, 3),
+    ('\u{F91B}', 4370, 3),
+    ('\u{F91C}', 4373, 3),
+    ('\u{F91D}', 4376, 3),
+    ('\u{F91E}', 4379, 3),
+    ('\u{F91F}', 4382, 3),
+    ('\u{F920}', 4385, 3),
+    ('\u{F921}', 4388, 3),
+    ('\u{F922}', 4391, 3),
+    ('\u{F923}', 4394, 3),
+    ('\u{F924}', 4397, 3),
+    ('\u{F925}', 4400, 3),
+    ('\u{F926}', 4403, 3),
+    ('\u{F927}', 4406, 3),
+    ('\u{F928}', 4409, 3),
+    ('\u{F929}', 4412, 3),
+    ('\u{F92A}', 4415, 3),
+    ('\u{F92B}', 4418, 3),
+    ('\u{F92C}', 4421, 3),
+    ('\u{F92D}', 4424, 3),
+    ('\u{F92E}', 4427, 3),
+    ('\u{F92F}', 4430, 3),
+    ('\u{F930}', 4433, 3),
+    ('\u{F931}', 4436, 3),
+    ('\u{F932}', 4439, 3),
+    ('\u{F933}', 4442, 3),
+    ('\u{F934}', 4445, 3),
+    ('\u{F935}', 4448, 3),
+    ('\u{F936}', 4451, 3),
+    ('\u{F937}', 4454, 3),
+    ('\u{F938}', 4457, 3),
+    ('\u{F939}', 4460, 3),
+    ('\u{F93A}', 4463, 3),
+    ('\u{F93B}', 4466, 3),
+    ('\u{F93C}', 4469, 3),
+    ('\u{F93D}', 4472, 3),
+    ('\u{F93E}', 4475, 3),
+    ('\u{F93F}', 4478, 3),
+    ('\u{F940}', 4481, 3),
+    ('\u{F941}', 4484, 3),
+    ('\u{F942}', 4487, 3),
+    ('\u{F943}', 4490, 3),
+    ('\u{F944}', 4493, 3),
+    ('\u{F945}', 4496, 3),
+    ('\u{F946}', 4499, 3),
+    ('\u{F947}', 4502, 3),
+    ('\u{F948}', 4505, 3),
+    ('\u{F949}', 4508, 3),
+    ('\u{F94A}', 4511, 3),
+    ('\u{F94B}', 4514, 3),
+    ('\u{F94C}', 4517, 3),
+    ('\u{F94D}', 4520, 3),
+    ('\u{F94E}', 4523, 3),
+    ('\u{F94F}', 4526, 3),
+    ('\u{F950}', 4529, 3),
+    ('\u{F951}', 4532, 3),
+    ('\u{F952}', 4535, 3),
+    ('\u{F953}', 4538, 3),
+    ('\u{F954}', 4541, 3),
+    ('\u{F955}', 4544, 3),
+    ('\u{F956}', 4547, 3),
+    ('\u{F957}', 4550, 3),
+    ('\u{F958}', 4553, 3),
+    ('\u{F959}', 4556, 3),
+    ('\u{F95A}', 4559, 3),
+    ('\u{F95B}', 4562, 3),
+    ('\u{F95C}', 4349, 3),
+    ('\u{F95D}', 4565, 3),
+    ('\u{F95E}', 4568, 3),
+    ('\u{F95F}', 4571, 3),
+    ('\u{F960}', 4574, 3),
+    ('\u{F961}', 4577, 3),
+    ('\u{F962}', 4580, 3),
+    ('\u{F963}', 4583, 3),
+    ('\u{F964}', 4586, 3),
+    ('\u{F965}', 4589, 3),
+    ('\u{F966}', 4592, 3),
+    ('\u{F967}', 4595, 3),
+    ('\u{F968}', 4598, 3),
+    ('\u{F969}', 4601, 3),
+    ('\u{F96A}', 4604, 3),
+    ('\u{F96B}', 4607, 3),
+    ('\u{F96C}', 4610, 3),
+    ('\u{F96D}', 4613, 3),
+    ('\u{F96E}', 4616, 3),
+    ('\u{F96F}', 4619, 3),
+    ('\u{F970}', 4622, 3),
+    ('\u{F971}', 4625, 3),
+    ('\u{F972}', 4628, 3),
+    ('\u{F973}', 4631, 3),
+    ('\u{F974}', 4634, 3),
+    ('\u{F975}', 4637, 3),
+    ('\u{F976}', 4640, 3),
+    ('\u{F977}', 4643, 3),
+    ('\u{F978}', 4646, 3),
+    ('\u{F979}', 4649, 3),
+    ('\u{F97A}', 4652, 3),
+    ('\u{F97B}', 4655, 3),
+    ('\u{F97C}', 4658, 3),
+    ('\u{F97D}', 4661, 3),
+    ('\u{F97E}', 4664, 3),
+    ('\u{F97F}', 4667, 3),
+    ('\u{F980}', 4670, 3),
+    ('\u{F981}', 4673, 3),
+    ('\u{F982}', 4676, 3),
+    ('\u{F983}', 4679, 3),
+    ('\u{F984}', 4682, 3),
+    ('\u{F985}', 4685, 3),
+    ('\u{F986}', 4688, 3),
+    ('\u{F987}', 4691, 3),
+    ('\u{F988}', 4694, 3),
+    ('\u{F989}', 4697, 3),
+    ('\u{F98A}', 4700, 3),
+    ('\u{F98B}', 4703, 3),
+    ('\u{F98C}', 4706, 3),
+    ('\u{F98D}', 4709, 3),
+    ('\u{F98E}', 4712, 3),
+    ('\u{F98F}', 4715, 3),
+    ('\u{F990}', 4718, 3),
+    ('\u{F991}', 4721, 3),
+    ('\u{F992}', 4724, 3),
+    ('\u{F993}', 4727, 3),
+    ('\u{F994}', 4730, 3),
+    ('\u{F995}', 4733, 3),
+    ('\u{F996}', 4736, 3),
+    ('\u{F997}', 4739, 3),
+    ('\u{F998}', 4742, 3),
+    ('\u{F999}', 4745, 3),
+    ('\u{F99A}', 4748, 3),
+    ('\u{F99B}', 4751, 3),
+    ('\u{F99C}', 4754, 3),
+    ('\u{F99D}', 4757, 3),
+    ('\u{F99E}', 4760, 3),
+    ('\u{F99F}', 4763, 3),
+    ('\u{F9A0}', 4766, 3),
+    ('\u{F9A1}', 4619, 3),
+    ('\u{F9A2}', 4769, 3),
+    ('\u{F9A3}', 4772, 3),
+    ('\u{F9A4}', 4775, 3),
+    ('\u{F9A5}', 4778, 3),
+    ('\u{F9A6}', 4781, 3),
+    ('\u{F9A7}', 4784, 3),
+    ('\u{F9A8}', 4787, 3),
+    ('\u{F9A9}', 4790, 3),
+    ('\u{F9AA}', 4571, 3),
+    ('\u{F9AB}', 4793, 3),
+    ('\u{F9AC}', 4796, 3),
+    ('\u{F9AD}', 4799, 3),
+    ('\u{F9AE}', 4802, 3),
+    ('\u{F9AF}', 4805, 3),
+    ('\u{F9B0}', 4808, 3),
+    ('\u{F9B1}', 4811, 3),
+    ('\u{F9B2}', 4814, 3),
+    ('\u{F9B3}', 4817, 3),
+    ('\u{F9B4}', 4820, 3),
+    ('\u{F9B5}', 4823, 3),
+    ('\u{F9B6}', 4826, 3),
+    ('\u{F9B7}', 4829, 3),
+    ('\u{F9B8}', 4832, 3),
+    ('\u{F9B9}', 4835, 3),
+    ('\u{F9BA}', 4838, 3),
+    ('\u{F9BB}', 4841, 3),
+    ('\u{F9BC}', 4844, 3),
+    ('\u{F9BD}', 4847, 3),
+    ('\u{F9BE}', 4850, 3),
+    ('\u{F9BF}', 4349, 3),
+    ('\u{F9C0}', 4853, 3),
+    ('\u{F9C1}', 4856, 3),
+    ('\u{F9C2}', 4859, 3),
+    ('\u{F9C3}', 4862, 3),
+    ('\u{F9C4}', 4865, 3),
+    ('\u{F9C5}', 4868, 3),
+    ('\u{F9C6}', 4871, 3),
+    ('\u{F9C7}', 4874, 3),
+    ('\u{F9C8}', 4877, 3),
+    ('\u{F9C9}', 4880, 3),
+    ('\u{F9CA}', 4883, 3),
+    ('\u{F9CB}', 4886, 3),
+    ('\u{F9CC}', 4889, 3),
+    ('\u{F9CD}', 4892, 3),
+    ('\u{F9CE}', 4895, 3),
+    ('\u{F9CF}', 4898, 3),
+    ('\u{F9D0}', 4901, 3),
+    ('\u{F9D1}', 4904, 3),
+    ('\u{F9D2}', 4907, 3),
+    ('\u{F9D3}', 4910, 3),
+    ('\u{F9D4}', 4913, 3),
+    ('\u{F9D5}', 4916, 3),
+    ('\u{F9D6}', 4919, 3),
+    ('\u{F9D7}', 4922, 3),
+    ('\u{F9D8}', 4925, 3),
+    ('\u{F9D9}', 4928, 3),
+    ('\u{F9DA}', 4931, 3),
+    ('\u{F9DB}', 4577, 3),
+    ('\u{F9DC}', 4934, 3),
+    ('\u{F9DD}', 4937, 3),
+    ('\u{F9DE}', 4940, 3),
+    ('\u{F9DF}', 4943, 3),
+    ('\u{F9E0}', 4946, 3),
+    ('\u{F9E1}', 4949, 3),
+    ('\u{F9E2}', 4952, 3),
+    ('\u{F9E3}', 4955, 3),
+    ('\u{F9E4}', 4958, 3),
+    ('\u{F9E5}', 4961, 3),
+    ('\u{F9E6}', 4964, 3),
+    ('\u{F9E7}', 4967, 3),
+    ('\u{F9E8}', 4970, 3),
+    ('\u{F9E9}', 4973, 3),
+    ('\u{F9EA}', 4976, 3),
+    ('\u{F9EB}', 4979, 3),
+    ('\u{F9EC}', 4982, 3),
+    ('\u{F9ED}', 4985, 3),
+    ('\u{F9EE}', 4988, 3),
+    ('\u{F9EF}', 4991, 3),
+    ('\u{F9F0}', 4994, 3),
+    ('\u{F9F1}', 4997, 3),
+    ('\u{F9F2}', 5000, 3),
+    ('\u{F9F3}', 5003, 3),
+    ('\u{F9F4}', 5006, 3),
+    ('\u{F9F5}', 5009, 3),
+    ('\u{F9F6}', 5012, 3),
+    ('\u{F9F7}', 5015, 3),
+    ('\u{F9F8}', 5018, 3),
+    ('\u{F9F9}', 5021, 3),
+    ('\u{F9FA}', 5024, 3),
+    ('\u{F9FB}', 5027, 3),
+    ('\u{F9FC}', 5030, 3),
+    ('\u{F9FD}', 5033, 3),
+    ('\u{F9FE}', 5036, 3),
+    ('\u{F9FF}', 5039, 3),
+    ('\u{FA00}', 5042, 3),
+    ('\u{FA01}', 5045, 3),
+    ('\u{FA02}', 5048, 3),
+    ('\u{FA03}', 5051, 3),
+    ('\u{FA04}', 5054, 3),
+    ('\u{FA05}', 5057, 3),
+    ('\u{FA06}', 5060, 3),
+    ('\u{FA07}', 5063, 3),
+    ('\u{FA08}', 5066, 3),
+    ('\u{FA09}', 5069, 3),
+    ('\u{FA0A}', 5072, 3),
+    ('\u{FA0B}', 5075, 3),
+    ('\u{FA0C}', 5078, 3),
+    ('\u{FA0D}', 5081, 3),
+    ('\u{FA10}', 5084, 3),
+    ('\u{FA12}', 5087, 3),
+    ('\u{FA15}', 5090, 3),
+    ('\u{FA16}', 5093, 3),
+    ('\u{FA17}', 5096, 3),
+    ('\u{FA18}', 5099, 3),
+    ('\u{FA19}', 5102, 3),
+    ('\u{FA1A}', 5105, 3),
+    ('\u{FA1B}', 5108, 3),
+    ('\u{FA1C}', 5111, 3),
+    ('\u{FA1D}', 5114, 3),
+    ('\u{FA1E}', 5117, 3),
+    ('\u{FA20}', 5120, 3),
+    ('\u{FA22}', 5123, 3),
+    ('\u{FA25}', 5126, 3),
+    ('\u{FA26}', 5129, 3),
+    ('\u{FA2A}', 5132, 3),
+    ('\u{FA2B}', 5135, 3),
+    ('\u{FA2C}', 5138, 3),
+    ('\u{FA2D}', 5141, 3),
+    ('\u{FA2E}', 5144, 3),
+    ('\u{FA2F}', 5147, 3),
+    ('\u{FA30}', 5150, 3),
+    ('\u{FA31}', 5153, 3),
+    ('\u{FA32}', 5156, 3),
+    ('\u{FA33}', 5159, 3),
+    ('\u{FA34}', 5162, 3),
+    ('\u{FA35}', 5165, 3),
+    ('\u{FA36}', 5168, 3),
+    ('\u{FA37}', 5171, 3),
+    ('\u{FA38}', 5174, 3),
+    ('\u{FA39}', 5177, 3),
+    ('\u{FA3A}', 5180, 3),
+    ('\u{FA3B}', 5183, 3),
+    ('\u{FA3C}', 5186, 3),
+    ('\u{FA3D}', 5189, 3),
+    ('\u{FA3E}', 5192, 3),
+    ('\u{FA3F}', 5195, 3),
+    ('\u{FA40}', 5198, 3),
+    ('\u{FA41}', 5201, 3),
+    ('\u{FA42}', 5204, 3),
+    ('\u{FA43}', 5207, 3),
+    ('\u{FA44}', 5210, 3),
+    ('\u{FA45}', 5213, 3),
+    ('\u{FA46}', 5216, 3),
+    ('\u{FA47}', 5219, 3),
+    ('\u{FA48}', 5222, 3),
+    ('\u{FA49}', 5225, 3),
+    ('\u{FA4A}', 5228, 3),
+    ('\u{FA4B}', 5231, 3),
+    ('\u{FA4C}', 5234, 3),
+    ('\u{FA4D}', 5237, 3),
+    ('\u{FA4E}', 5240, 3),
+    ('\u{FA4F}', 5243, 3),
+    ('\u{FA50}', 5246, 3),
+    ('\u{FA51}', 5249, 3),
+    ('\u{FA52}', 5252, 3),
+    ('\u{FA53}', 5255, 3),
+    ('\u{FA54}', 5258, 3),
+    ('\u{FA55}', 5261, 3),
+    ('\u{FA56}', 5264, 3),
+    ('\u{FA57}', 4736, 3),
+    ('\u{FA58}', 5267, 3),
+    ('\u{FA59}', 5270, 3),
+    ('\u{FA5A}', 5273, 3),
+    ('\u{FA5B}', 5276, 3),
+    ('\u{FA5C}', 5279, 3),
+    ('\u{FA5D}', 5282, 3),
+    ('\u{FA5E}', 5282, 3),
+    ('\u{FA5F}', 5285, 3),
+    ('\u{FA60}', 5288, 3),
+    ('\u{FA61}', 5291, 3),
+    ('\u{FA62}', 5294, 3),
+    ('\u{FA63}', 5297, 3),
+    ('\u{FA64}', 5300, 3),
+    ('\u{FA65}', 5303, 3),
+    ('\u{FA66}', 5306, 3),
+    ('\u{FA67}', 5126, 3),
+    ('\u{FA68}', 5309, 3),
+    ('\u{FA69}', 5312, 3),
+    ('\u{FA6A}', 5315, 3),
+    ('\u{FA6B}', 5318, 3),
+    ('\u{FA6C}', 5321, 4),
+    ('\u{FA6D}', 5325, 3),
+    ('\u{FA70}', 5328, 3),
+    ('\u{FA71}', 5331, 3),
+    ('\u{FA72}', 5334, 3),
+    ('\u{FA73}', 5337, 3),
+    ('\u{FA74}', 5340, 3),
+    ('\u{FA75}', 5343, 3),
+    ('\u{FA76}', 5346, 3),
+    ('\u{FA77}', 5349, 3),
+    ('\u{FA78}', 5168, 3),
+    ('\u{FA79}', 5352, 3),
+    ('\u{FA7A}', 5355, 3),
+    ('\u{FA7B}', 5358, 3),
+    ('\u{FA7C}', 5084, 3),
+    ('\u{FA7D}', 5361, 3),
+    ('\u{FA7E}', 5364, 3),
+    ('\u{FA7F}', 5367, 3),
+    ('\u{FA80}', 5370, 3),
+    ('\u{FA81}', 5373, 3),
+    ('\u{FA82}', 5376, 3),
+    ('\u{FA83}', 5379, 3),
+    ('\u{FA84}', 5382, 3),
+    ('\u{FA85}', 5385, 3),
+    ('\u{FA86}', 5388, 3),
+    ('\u{FA87}', 5391, 3),
+    ('\u{FA88}', 5394, 3),
+    ('\u{FA89}', 5195, 3),
+    ('\u{FA8A}', 5397, 3),
+    ('\u{FA8B}', 5198, 3),
+    ('\u{FA8C}', 5400, 3),
+    ('\u{FA8D}', 5403, 3),
+    ('\u{FA8E}', 5406, 3),
+    ('\u{FA8F}', 5409, 3),
+    ('\u{FA90}', 5412, 3),
+    ('\u{FA91}', 5087, 3),
+    ('\u{FA92}', 4412, 3),
+    ('\u{FA93}', 5415, 3),
+    ('\u{FA94}', 5418, 3),
+    ('\u{FA95}', 5421, 3),
+    ('\u{FA96}', 4622, 3),
+    ('\u{FA97}', 4883, 3),
+    ('\u{FA98}', 5424, 3),
+    ('\u{FA99}', 5427, 3),
+    ('\u{FA9A}', 5219, 3),
+    ('\u{FA9B}', 5430, 3),
+    ('\u{FA9C}', 5222, 3),
+    ('\u{FA9D}', 5433, 3),
+    ('\u{FA9E}', 5436, 3),
+    ('\u{FA9F}', 5439, 3),
+    ('\u{FAA0}', 5093, 3),
+    ('\u{FAA1}', 5442, 3),
+    ('\u{FAA2}', 5445, 3),
+    ('\u{FAA3}', 5448, 3),
+    ('\u{FAA4}', 5451, 3),
+    ('\u{FAA5}', 5454, 3),
+    ('\u{FAA6}', 5096, 3),
+    ('\u{FAA7}', 5457, 3),
+    ('\u{FAA8}', 5460, 3),
+    ('\u{FAA9}', 5463, 3),
+    ('\u{FAAA}', 5466, 3),
+    ('\u{FAAB}', 5469, 3),
+    ('\u{FAAC}', 5472, 3),
+    ('\u{FAAD}', 5264, 3),
+    ('\u{FAAE}', 5475, 3),
+    ('\u{FAAF}', 5478, 3),
+    ('\u{FAB0}', 4736, 3),
+    ('\u{FAB1}', 5481, 3),
+    ('\u{FAB2}', 5276, 3),
+    ('\u{FAB3}', 5484, 3),
+    ('\u{FAB4}', 5487, 3),
+    ('\u{FAB5}', 5490, 3),
+    ('\u{FAB6}', 5493, 3),
+    ('\u{FAB7}', 5496, 3),
+    ('\u{FAB8}', 5291, 3),
+    ('\u{FAB9}', 5499, 3),
+    ('\u{FABA}', 5123, 3),
+    ('\u{FABB}', 5502, 3),
+    ('\u{FABC}', 5294, 3),
+    ('\u{FABD}', 4565, 3),
+    ('\u{FABE}', 5505, 3),
+    ('\u{FABF}', 5297, 3),
+    ('\u{FAC0}', 5508, 3),
+    ('\u{FAC1}', 5303, 3),
+    ('\u{FAC2}', 5511, 3),
+    ('\u{FAC3}', 5514, 3),
+    ('\u{FAC4}', 5517, 3),
+    ('\u{FAC5}', 5520, 3),
+    ('\u{FAC6}', 5523, 3),
+    ('\u{FAC7}', 5309, 3),
+    ('\u{FAC8}', 5111, 3),
+    ('\u{FAC9}', 5526, 3),
+    ('\u{FACA}', 5312, 3),
+    ('\u{FACB}', 5529, 3),
+    ('\u{FACC}', 5315, 3),
+    ('\u{FACD}', 5532, 3),
+    ('\u{FACE}', 4313, 3),
+    ('\u{FACF}', 5535, 4),
+    ('\u{FAD0}', 5539, 4),
+    ('\u{FAD1}', 5543, 4),
+    ('\u{FAD2}', 5547, 3),
+    ('\u{FAD3}', 5550, 3),
+    ('\u{FAD4}', 5553, 3),
+    ('\u{FAD5}', 5556, 4),
+    ('\u{FAD6}', 5560, 4),
+    ('\u{FAD7}', 5564, 4),
+    ('\u{FAD8}', 5568, 3),
+    ('\u{FAD9}', 5571, 3),
+    ('\u{FB1D}', 5574, 4),
+    ('\u{FB1F}', 5578, 4),
+    ('\u{FB2A}', 5582, 4),
+    ('\u{FB2B}', 5586, 4),
+    ('\u{FB2C}', 5590, 6),
+    ('\u{FB2D}', 5596, 6),
+    ('\u{FB2E}', 5602, 4),
+    ('\u{FB2F}', 5606, 4),
+    ('\u{FB30}', 5610, 4),
+    ('\u{FB31}', 5614, 4),
+    ('\u{FB32}', 5618, 4),
+    ('\u{FB33}', 5622, 4),
+    ('\u{FB34}', 5626, 4),
+    ('\u{FB35}', 5630, 4),
+    ('\u{FB36}', 5634, 4),
+    ('\u{FB38}', 5638, 4),
+    ('\u{FB39}', 5642, 4),
+    ('\u{FB3A}', 5646, 4),
+    ('\u{FB3B}', 5650, 4),
+    ('\u{FB3C}', 5654, 4),
+    ('\u{FB3E}', 5658, 4),
+    ('\u{FB40}', 5662, 4),
+    ('\u{FB41}', 5666, 4),
+    ('\u{FB43}', 5670, 4),
+    ('\u{FB44}', 5674, 4),
+    ('\u{FB46}', 5678, 4),
+    ('\u{FB47}', 5682, 4),
+    ('\u{FB48}', 5686, 4),
+    ('\u{FB49}', 5690, 4),
+    ('\u{FB4A}', 5694, 4),
+    ('\u{FB4B}', 5698, 4),
+    ('\u{FB4C}', 5702, 4),
+    ('\u{FB4D}', 5706, 4),
+    ('\u{FB4E}', 5710, 4),
+    ('\u{105C9}', 5714, 6),
+    ('\u{105E4}', 5720, 6),
+    ('\u{1109A}', 5726, 8),
+    ('\u{1109C}', 5734, 8),
+    ('\u{110AB}', 5742, 8),
+    ('\u{1112E}', 5750, 8),
+    ('\u{1112F}', 5758, 8),
+    ('\u{1134B}', 5766, 8),
+    ('\u{1134C}', 5774, 8),
+    ('\u{11383}', 5782, 8),
+    ('\u{11385}', 5790, 8),
+    ('\u{1138E}', 5798, 8),
+    ('\u{11391}', 5806, 8),
+    ('\u{113C5}', 5814, 8),
+    ('\u{113C7}', 5822, 8),
+    ('\u{113C8}', 5830, 8),
+    ('\u{114BB}', 5838, 8),
+    ('\u{114BC}', 5846, 8),
+    ('\u{114BE}', 5854, 8),
+    ('\u{115BA}', 5862, 8),
+    ('\u{115BB}', 5870, 8),
+    ('\u{11938}', 5878, 8),
+    ('\u{16121}', 5886, 8),
+    ('\u{16122}', 5894, 8),
+    ('\u{16123}', 5902, 8),
+    ('\u{16124}', 5910, 8),
+    ('\u{16125}', 5918, 8),
+    ('\u{16126}', 5926, 12),
+    ('\u{16127}', 5938, 12),
+    ('\u{16128}', 5950, 12),
+    ('\u{16D68}', 5962, 8),
+    ('\u{16D69}', 5970, 8),
+    ('\u{16D6A}', 5978, 12),
+    ('\u{1D15E}', 5990, 8),
+    ('\u{1D15F}', 5998, 8),
+    ('\u{1D160}', 6006, 12),
+    ('\u{1D161}', 6018, 12),
+    ('\u{1D162}', 6030, 12),
+    ('\u{1D163}', 6042, 12),
+    ('\u{1D164}', 6054, 12),
+    ('\u{1D1BB}', 6066, 8),
+    ('\u{1D1BC}', 6074, 8),
+    ('\u{1D1BD}', 6082, 12),
+    ('\u{1D1BE}', 6094, 12),
+    ('\u{1D1BF}', 6106, 12),
+    ('\u{1D1C0}', 6118, 12),
+    ('\u{2F800}', 6130, 3),
+    ('\u{2F801}', 6133, 3),
+    ('\u{2F802}', 6136, 3),
+    ('\u{2F803}', 6139, 4),
+    ('\u{2F804}', 6143, 3),
+    ('\u{2F805}', 5150, 3),
+    ('\u{2F806}', 6146, 3),
+    ('\u{2F807}', 6149, 3),
+    ('\u{2F808}', 6152, 3),
+    ('\u{2F809}', 6155, 3),
+    ('\u{2F80A}', 5153, 3),
+    ('\u{2F80B}', 6158, 3),
+    ('\u{2F80C}', 6161, 3),
+    ('\u{2F80D}', 6164, 4),
+    ('\u{2F80E}', 5156, 3),
+    ('\u{2F80F}', 6168, 3),
+    ('\u{2F810}', 6171, 3),
+    ('\u{2F811}', 6174, 3),
+    ('\u{2F812}', 6177, 4),
+    ('\u{2F813}', 6181, 3),
+    ('\u{2F814}', 6184, 3),
+    ('\u{2F815}', 6187, 3),
+    ('\u{2F816}', 6190, 4),
+    ('\u{2F817}', 6194, 3),
+    ('\u{2F818}', 6197, 3),
+    ('\u{2F819}', 6200, 3),
+    ('\u{2F81A}', 6203, 3),
+    ('\u{2F81B}', 5331, 3),
+    ('\u{2F81C}', 6206, 4),
+    ('\u{2F81D}', 6210, 3),
+    ('\u{2F81E}', 6213, 3),
+    ('\u{2F81F}', 6216, 3),
+    ('\u{2F820}', 6219, 3),
+    ('\u{2F821}', 6222, 3),
+    ('\u{2F822}', 6225, 3),
+    ('\u{2F823}', 6228, 3),
+    ('\u{2F824}', 6231, 3),
+    ('\u{2F825}', 5346, 3),
+    ('\u{2F826}', 5159, 3),
+    ('\u{2F827}', 5162, 3),
+    ('\u{2F828}', 5349, 3),
+    ('\u{2F829}', 6234, 3),
+    ('\u{2F82A}', 6237, 3),
+    ('\u{2F82B}', 4583, 3),
+    ('\u{2F82C}', 6240, 3),
+    ('\u{2F82D}', 5165, 3),
+    ('\u{2F82E}', 6243, 3),
+    ('\u{2F82F}', 6246, 3),
+    ('\u{2F830}', 6249, 3),
+    ('\u{2F831}', 6252, 3),
+    ('\u{2F832}', 6252, 3),
+    ('\u{2F833}', 6252, 3),
+    ('\u{2F834}', 6255, 4),
+    ('\u{2F835}', 6259, 3),
+    ('\u{2F836}', 6262, 3),
+    ('\u{2F837}', 6265, 3),
+    ('\u{2F838}', 6268, 4),
+    ('\u{2F839}', 6272, 3),
+    ('\u{2F83A}', 6275, 3),
+    ('\u{2F83B}', 6278, 3),
+    ('\u{2F83C}', 6281, 3),
+    ('\u{2F83D}', 6284, 3),
+    ('\u{2F83E}', 6287, 3),
+    ('\u{2F83F}', 6290, 3),
+    ('\u{2F840}', 6293, 3),
+    ('\u{2F841}', 6296, 3),
+    ('\u{2F842}', 6299, 3),
+    ('\u{2F843}', 6302, 3),
+    ('\u{2F844}', 6305, 3),
+    ('\u{2F845}', 6308, 3),
+    ('\u{2F846}', 6308, 3),
+    ('\u{2F847}', 5355, 3),
+    ('\u{2F848}', 6311, 3),
+    ('\u{2F849}', 6314, 3),
+    ('\u{2F84A}', 6317, 3),
+    ('\u{2F84B}', 6320, 3),
+    ('\u{2F84C}', 5171, 3),
+    ('\u{2F84D}', 6323, 3),
+    ('\u{2F84E}', 6326, 3),
+    ('\u{2F84F}', 6329, 3),
+    ('\u{2F850}', 5042, 3),
+    ('\u{2F851}', 6332, 3),
+    ('\u{2F852}', 6335, 3),
+    ('\u{2F853}', 6338, 3),
+    ('\u{2F854}', 6341, 3),
+    ('\u{2F855}', 6344, 3),
+    ('\u{2F856}', 6347, 3),
+    ('\u{2F857}', 6350, 3),
+    ('\u{2F858}', 6353, 3),
+    ('\u{2F859}', 6356, 4),
+    ('\u{2F85A}', 6360, 3),
+    ('\u{2F85B}', 6363, 3),
+    ('\u{2F85C}', 6366, 3),
+    ('\u{2F85D}', 6369, 3),
+    ('\u{2F85E}', 6372, 3),
+    ('\u{2F85F}', 6375, 3),
+    ('\u{2F860}', 6378, 4),
+    ('\u{2F861}', 6382, 4),
+    ('\u{2F862}', 6386, 3),
+    ('\u{2F863}', 6389, 3),
+    ('\u{2F864}', 6392, 3),
+    ('\u{2F865}', 6395, 3),
+    ('\u{2F866}', 6398, 3),
+    ('\u{2F867}', 6401, 3),
+    ('\u{2F868}', 6404, 3),
+    ('\u{2F869}', 6407, 3),
+    ('\u{2F86A}', 6410, 3),
+    ('\u{2F86B}', 6410, 3),
+    ('\u{2F86C}', 6413, 4),
+    ('\u{2F86D}', 6417, 3),
+    ('\u{2F86E}', 6420, 3),
+    ('\u{2F86F}', 4571, 3),
+    ('\u{2F870}', 6423, 3),
+    ('\u{2F871}', 6426, 4),
+    ('\u{2F872}', 6430, 3),
+    ('\u{2F873}', 6433, 3),
+    ('\u{2F874}', 6436, 3),
+    ('\u{2F875}', 6439, 3),
+    ('\u{2F876}', 6442, 3),
+    ('\u{2F877}', 6445, 3),
+    ('\u{2F878}', 5186, 3),
+    ('\u{2F879}', 6448, 3),
+    ('\u{2F87A}', 6451, 3),
+    ('\u{2F87B}', 6454, 4),
+    ('\u{2F87C}', 6458, 3),
+    ('\u{2F87D}', 6461, 4),
+    ('\u{2F87E}', 6465, 3),
+    ('\u{2F87F}', 6468, 3),
+    ('\u{2F880}', 6471, 3),
+    ('\u{2F881}', 6474, 3),
+    ('\u{2F882}', 6477, 3),
+    ('\u{2F883}', 6480, 3),
+    ('\u{2F884}', 6483, 3),
+    ('\u{2F885}', 6486, 3),
+    ('\u{2F886}', 6489, 3),
+    ('\u{2F887}', 6492, 3),
+    ('\u{2F888}', 6495, 3),
+    ('\u{2F889}', 6498, 4),
+    ('\u{2F88A}', 6502, 3),
+    ('\u{2F88B}', 6505, 3),
+    ('\u{2F88C}', 6508, 3),
+    ('\u{2F88D}', 6511, 3),
+    ('\u{2F88E}', 4409, 3),
+    ('\u{2F88F}', 6514, 4),
+    ('\u{2F890}', 6518, 3),
+    ('\u{2F891}', 6521, 4),
+    ('\u{2F892}', 6521, 4),
+    ('\u{2F893}', 6525, 3),
+    ('\u{2F894}', 6528, 3),
+    ('\u{2F895}', 6528, 3),
+    ('\u{2F896}', 6531, 3),
+    ('\u{2F897}', 6534, 4),
+    ('\u{2F898}', 6538, 4),
+    ('\u{2F899}', 6542, 3),
+    ('\u{2F89A}', 6545, 3),
+    ('\u{2F89B}', 6548, 3),
+    ('\u{2F89C}', 6551, 3),
+    ('\u{2F89D}', 6554, 3),
+    ('\u{2F89E}', 6557, 3),
+    ('\u{2F89F}', 6560, 3),
+    ('\u{2F8A0}', 6563, 3),
+    ('\u{2F8A1}', 6566, 3),
+    ('\u{2F8A2}', 6569, 3),
+    ('\u{2F8A3}', 5189, 3),
+    ('\u{2F8A4}', 6572, 4),
+    ('\u{2F8A5}', 6576, 3),
+    ('\u{2F8A6}', 6579, 3),
+    ('\u{2F8A7}', 6582, 3),
+    ('\u{2F8A8}', 5391, 3),
+    ('\u{2F8A9}', 6582, 3),
+    ('\u{2F8AA}', 6585, 3),
+    ('\u{2F8AB}', 5195, 3),
+    ('\u{2F8AC}', 6588, 3),
+    ('\u{2F8AD}', 6591, 3),
+    ('\u{2F8AE}', 6594, 3),
+    ('\u{2F8AF}', 6597, 3),
+    ('\u{2F8B0}', 5198, 3),
+    ('\u{2F8B1}', 4328, 3),
+    ('\u{2F8B2}', 6600, 3),
+    ('\u{2F8B3}', 6603, 3),
+    ('\u{2F8B4}', 6606, 3),
+    ('\u{2F8B5}', 6609, 3),
+    ('\u{2F8B6}', 6612, 3),
+    ('\u{2F8B7}', 6615, 3),
+    ('\u{2F8B8}', 6618, 4),
+    ('\u{2F8B9}', 6622, 3),
+    ('\u{2F8BA}', 6625, 3),
+    ('\u{2F8BB}', 6628, 3),
+    ('\u{2F8BC}', 6631, 3),
+    ('\u{2F8BD}', 6634, 3),
+    ('\u{2F8BE}', 6637, 4),
+    ('\u{2F8BF}', 6641, 3),
+    ('\u{2F8C0}', 6644, 3),
+    ('\u{2F8C1}', 6647, 3),
+    ('\u{2F8C2}', 6650, 3),
+    ('\u{2F8C3}', 6653, 3),
+    ('\u{2F8C4}', 6656, 3),
+    ('\u{2F8C5}', 6659, 3),
+    ('\u{2F8C6}', 6662, 3),
+    ('\u{2F8C7}', 6665, 3),
+    ('\u{2F8C8}', 5201, 3),
+    ('\u{2F8C9}', 6668, 3),
+    ('\u{2F8CA}', 6671, 4),
+    ('\u{2F8CB}', 6675, 3),
+    ('\u{2F8CC}', 6678, 3),
+    ('\u{2F8CD}', 6681, 3),
+    ('\u{2F8CE}', 6684, 3),
+    ('\u{2F8CF}', 5207, 3),
+    ('\u{2F8D0}', 6687, 3),
+    ('\u{2F8D1}', 6690, 3),
+    ('\u{2F8D2}', 6693, 3),
+    ('\u{2F8D3}', 6696, 3),
+    ('\u{2F8D4}', 6699, 3),
+    ('\u{2F8D5}', 6702, 3),
+    ('\u{2F8D6}', 6705, 3),
+    ('\u{2F8D7}', 6708, 3),
+    ('\u{2F8D8}', 4412, 3),
+    ('\u{2F8D9}', 5415, 3),
+    ('\u{2F8DA}', 6711, 3),
+    ('\u{2F8DB}', 6714, 3),
+    ('\u{2F8DC}', 6717, 3),
+    ('\u{2F8DD}', 6720, 4),
+    ('\u{2F8DE}', 6724, 3),
+    ('\u{2F8DF}', 6727, 3),
+    ('\u{2F8E0}', 6730, 3),
+    ('\u{2F8E1}', 6733, 3),
+    ('\u{2F8E2}', 5210, 3),
+    ('\u{2F8E3}', 6736, 4),
+    ('\u{2F8E4}', 6740, 3),
+    ('\u{2F8E5}', 6743, 3),
+    ('\u{2F8E6}', 6746, 3),
+    ('\u{2F8E7}', 5547, 3),
+    ('\u{2F8E8}', 6749, 3),
+    ('\u{2F8E9}', 6752, 3),
+    ('\u{2F8EA}', 6755, 3),
+    ('\u{2F8EB}', 6758, 3),
+    ('\u{2F8EC}', 6761, 4),
+    ('\u{2F8ED}', 6765, 3),
+    ('\u{2F8EE}', 6768, 3),
+    ('\u{2F8EF}', 6771, 3),
+    ('\u{2F8F0}', 6774, 4),
+    ('\u{2F8F1}', 6778, 3),
+    ('\u{2F8F2}', 6781, 3),
+    ('\u{2F8F3}', 6784, 3),
+    ('\u{2F8F4}', 6787, 3),
+    ('\u{2F8F5}', 4622, 3),
+    ('\u{2F8F6}', 6790, 3),
+    ('\u{2F8F7}', 6793, 4),
+    ('\u{2F8F8}', 6797, 4),
+    ('\u{2F8F9}', 6801, 4),
+    ('\u{2F8FA}', 6805, 3),
+    ('\u{2F8FB}', 6808, 4),
+    ('\u{2F8FC}', 6812, 3),
+    ('\u{2F8FD}', 6815, 3),
+    ('\u{2F8FE}', 6818, 3),
+    ('\u{2F8FF}', 6821, 3),
+    ('\u{2F900}', 6824, 3),
+    ('\u{2F901}', 5213, 3),
+    ('\u{2F902}', 4883, 3),
+    ('\u{2F903}', 6827, 3),
+    ('\u{2F904}', 6830, 3),
+    ('\u{2F905}', 6833, 3),
+    ('\u{2F906}', 6836, 4),
+    ('\u{2F907}', 6840, 3),
+    ('\u{2F908}', 6843, 3),
+    ('\u{2F909}', 6846, 3),
+    ('\u{2F90A}', 6849, 3),
+    ('\u{2F90B}', 5427, 3),
+    ('\u{2F90C}', 6852, 3),
+    ('\u{2F90D}', 6855, 4),
+    ('\u{2F90E}', 6859, 3),
+    ('\u{2F90F}', 6862, 3),
+    ('\u{2F910}', 6865, 4),
+    ('\u{2F911}', 6869, 4),
+    ('\u{2F912}', 6873, 3),
+    ('\u{2F913}', 6876, 3),
+    ('\u{2F914}', 5430, 3),
+    ('\u{2F915}', 6879, 3),
+    ('\u{2F916}', 6882, 3),
+    ('\u{2F917}', 6885, 3),
+    ('\u{2F918}', 6888, 3),
+    ('\u{2F919}', 6891, 3),
+    ('\u{2F91A}', 6894, 3),
+    ('\u{2F91B}', 6897, 4),
+    ('\u{2F91C}', 6901, 3),
+    ('\u{2F91D}', 6904, 4),
+    ('\u{2F91E}', 6908, 3),
+    ('\u{2F91F}', 6911, 4),
+    ('\u{2F920}', 6915, 3),
+    ('\u{2F921}', 5436, 3),
+    ('\u{2F922}', 6918, 3),
+    ('\u{2F923}', 6921, 4),
+    ('\u{2F924}', 6925, 3),
+    ('\u{2F925}', 6928, 3),
+    ('\u{2F926}', 6931, 4),
+    ('\u{2F927}', 6935, 4),
+    ('\u{2F928}', 6939, 3),
+    ('\u{2F929}', 6942, 3),
+    ('\u{2F92A}', 6945, 3),
+    ('\u{2F92B}', 6948, 3),
+    ('\u{2F92C}', 6951, 3),
+    ('\u{2F92D}', 6951, 3),
+    ('\u{2F92E}', 6954, 3),
+    ('\u{2F92F}', 6957, 3),
+    ('\u{2F930}', 5442, 3),
+    ('\u{2F931}', 6960, 3),
+    ('\u{2F932}', 6963, 3),
+    ('\u{2F933}', 6966, 3),
+    ('\u{2F934}', 6969, 3),
+    ('\u{2F935}', 6972, 4),
+    ('\u{2F936}', 6976, 3),
+    ('\u{2F937}', 6979, 4),
+    ('\u{2F938}', 4580, 3),
+    ('\u{2F939}', 6983, 4),
+    ('\u{2F93A}', 6987, 3),
+    ('\u{2F93B}', 6990, 4),
+    ('\u{2F93C}', 6994, 4),
+    ('\u{2F93D}', 6998, 4),
+    ('\u{2F93E}', 7002, 3),
+    ('\u{2F93F}', 7005, 3),
+    ('\u{2F940}', 5460, 3),
+    ('\u{2F941}', 7008, 4),
+    ('\u{2F942}', 7012, 4),
+    ('\u{2F943}', 7016, 4),
+    ('\u{2F944}', 7020, 4),
+    ('\u{2F945}', 7024, 3),
+    ('\u{2F946}', 7027, 3),
+    ('\u{2F947}', 7027, 3),
+    ('\u{2F948}', 5463, 3),
+    ('\u{2F949}', 5553, 3),
+    ('\u{2F94A}', 7030, 3),
+    ('\u{2F94B}', 7033, 3),
+    ('\u{2F94C}', 7036, 3),
+    ('\u{2F94D}', 7039, 4),
+    ('\u{2F94E}', 7043, 3),
+    ('\u{2F94F}', 4466, 3),
+    ('\u{2F950}', 5469, 3),
+    ('\u{2F951}', 7046, 3),
+    ('\u{2F952}', 7049, 4),
+    ('\u{2F953}', 5246, 3),
+    ('\u{2F954}', 7053, 4),
+    ('\u{2F955}', 7057, 4),
+    ('\u{2F956}', 5108, 3),
+    ('\u{2F957}', 7061, 3),
+    ('\u{2F958}', 7064, 3),
+    ('\u{2F959}', 5258, 3),
+    ('\u{2F95A}', 7067, 3),
+    ('\u{2F95B}', 7070, 3),
+    ('\u{2F95C}', 7073, 4),
+    ('\u{2F95D}', 7077, 4),
+    ('\u{2F95E}', 7077, 4),
+    ('\u{2F95F}', 7081, 3),
+    ('\u{2F960}', 7084, 3),
+    ('\u{2F961}', 7087, 4),
+    ('\u{2F962}', 7091, 3),
+    ('\u{2F963}', 7094, 3),
+    ('\u{2F964}', 7097, 3),
+    ('\u{2F965}', 7100, 4),
+    ('\u{2F966}', 7104, 3),
+    ('\u{2F967}', 7107, 3),
+    ('\u{2F968}', 7110, 3),
+    ('\u{2F969}', 7113, 3),
+    ('\u{2F96A}', 7116, 3),
+    ('\u{2F96B}', 7119, 4),
+    ('\u{2F96C}', 7123, 3),
+    ('\u{2F96D}', 7126, 3),
+    ('\u{2F96E}', 7129, 3),
+    ('\u{2F96F}', 7132, 3),
+    ('\u{2F970}', 7135, 3),
+    ('\u{2F971}', 7138, 3),
+    ('\u{2F972}', 7141, 4),
+    ('\u{2F973}', 7145, 4),
+    ('\u{2F974}', 7149, 3),
+    ('\u{2F975}', 7152, 4),
+    ('\u{2F976}', 7156, 3),
+    ('\u{2F977}', 7159, 4),
+    ('\u{2F978}', 7163, 3),
+    ('\u{2F979}', 7166, 3),
+    ('\u{2F97A}', 5276, 3),
+    ('\u{2F97B}', 7169, 4),
+    ('\u{2F97C}', 7173, 4),
+    ('\u{2F97D}', 7177, 3),
+    ('\u{2F97E}', 7180, 4),
+    ('\u{2F97F}', 7184, 3),
+    ('\u{2F980}', 7187, 4),
+    ('\u{2F981}', 7191, 3),
+    ('\u{2F982}', 7194, 3),
+    ('\u{2F983}', 7197, 3),
+    ('\u{2F984}', 7200, 3),
+    ('\u{2F985}', 7203, 3),
+    ('\u{2F986}', 7206, 3),
+    ('\u{2F987}', 7209, 4),
+    ('\u{2F988}', 7213, 4),
+    ('\u{2F989}', 7217, 4),
+    ('\u{2F98A}', 7221, 4),
+    ('\u{2F98B}', 6525, 3),
+    ('\u{2F98C}', 7225, 3),
+    ('\u{2F98D}', 7228, 3),
+    ('\u{2F98E}', 7231, 3),
+    ('\u{2F98F}', 7234, 3),
+    ('\u{2F990}', 7237, 3),
+    ('\u{2F991}', 7240, 3),
+    ('\u{2F992}', 7243, 3),
+    ('\u{2F993}', 7246, 3),
+    ('\u{2F994}', 7249, 3),
+    ('\u{2F995}', 7252, 3),
+    ('\u{2F996}', 7255, 3),
+    ('\u{2F997}', 7258, 4),
+    ('\u{2F998}', 4634, 3),
+    ('\u{2F999}', 7262, 3),
+    ('\u{2F99A}', 7265, 3),
+    ('\u{2F99B}', 7268, 3),
+    ('\u{2F99C}', 7271, 3),
+    ('\u{2F99D}', 7274, 3),
+    ('\u{2F99E}', 7277, 3),
+    ('\u{2F99F}', 5285, 3),
+    ('\u{2F9A0}', 7280, 3),
+    ('\u{2F9A1}', 7283, 3),
+    ('\u{2F9A2}', 7286, 3),
+    ('\u{2F9A3}', 7289, 3),
+    ('\u{2F9A4}', 7292, 4),
+    ('\u{2F9A5}', 7296, 4),
+    ('\u{2F9A6}', 7300, 4),
+    ('\u{2F9A7}', 7304, 3),
+    ('\u{2F9A8}', 7307, 3),
+    ('\u{2F9A9}', 7310, 3),
+    ('\u{2F9AA}', 7313, 3),
+    ('\u{2F9AB}', 7316, 4),
+    ('\u{2F9AC}', 7320, 3),
+    ('\u{2F9AD}', 7323, 4),
+    ('\u{2F9AE}', 7327, 3),
+    ('\u{2F9AF}', 7330, 3),
+    ('\u{2F9B0}', 7333, 4),
+    ('\u{2F9B1}', 7337, 4),
+    ('\u{2F9B2}', 7341, 3),
+    ('\u{2F9B3}', 7344, 3),
+    ('\u{2F9B4}', 4451, 3),
+    ('\u{2F9B5}', 7347, 3),
+    ('\u{2F9B6}', 7350, 3),
+    ('\u{2F9B7}', 7353, 3),
+    ('\u{2F9B8}', 7356, 3),
+    ('\u{2F9B9}', 7359, 3),
+    ('\u{2F9BA}', 7362, 3),
+    ('\u{2F9BB}', 5490, 3),
+    ('\u{2F9BC}', 7365, 3),
+    ('\u{2F9BD}', 7368, 3),
+    ('\u{2F9BE}', 7371, 3),
+    ('\u{2F9BF}', 7374, 3),
+    ('\u{2F9C0}', 7377, 3),
+    ('\u{2F9C1}', 7380, 3),
+    ('\u{2F9C2}', 7383, 3),
+    ('\u{2F9C3}', 7386, 3),
+    ('\u{2F9C4}', 7389, 3),
+    ('\u{2F9C5}', 7392, 4),
+    ('\u{2F9C6}', 7396, 3),
+    ('\u{2F9C7}', 7399, 3),
+    ('\u{2F9C8}', 7402, 3),
+    ('\u{2F9C9}', 7405, 3),
+    ('\u{2F9CA}', 7408, 3),
+    ('\u{2F9CB}', 7411, 4),
+    ('\u{2F9CC}', 7415, 4),
+    ('\u{2F9CD}', 7419, 3),
+    ('\u{2F9CE}', 7422, 3),
+    ('\u{2F9CF}', 7425, 3),
+    ('\u{2F9D0}', 5505, 3),
+    ('\u{2F9D1}', 5508, 3),
+    ('\u{2F9D2}', 7428, 3),
+    ('\u{2F9D3}', 7431, 4),
+    ('\u{2F9D4}', 7435, 3),
+    ('\u{2F9D5}', 7438, 3),
+    ('\u{2F9D6}', 7441, 3),
+    ('\u{2F9D7}', 7444, 3),
+    ('\u{2F9D8}', 7447, 4),
+    ('\u{2F9D9}', 7451, 4),
+    ('\u{2F9DA}', 7455, 3),
+    ('\u{2F9DB}', 7458, 3),
+    ('\u{2F9DC}', 7461, 3),
+    ('\u{2F9DD}', 7464, 4),
+    ('\u{2F9DE}', 7468, 3),
+    ('\u{2F9DF}', 5511, 3),
+    ('\u{2F9E0}', 7471, 4),
+    ('\u{2F9E1}', 7475, 4),
+    ('\u{2F9E2}', 7479, 3),
+    ('\u{2F9E3}', 7482, 3),
+    ('\u{2F9E4}', 7485, 3),
+    ('\u{2F9E5}', 7488, 4),
+    ('\u{2F9E6}', 7492, 3),
+    ('\u{2F9E7}', 7495, 3),
+    ('\u{2F9E8}', 7498, 3),
+    ('\u{2F9E9}', 7501, 3),
+    ('\u{2F9EA}', 7504, 3),
+    ('\u{2F9EB}', 7507, 3),
+    ('\u{2F9EC}', 7510, 3),
+    ('\u{2F9ED}', 7513, 4),
+    ('\u{2F9EE}', 7517, 3),
+    ('\u{2F9EF}', 7520, 3),
+    ('\u{2F9F0}', 7523, 3),
+    ('\u{2F9F1}', 7526, 4),
+    ('\u{2F9F2}', 7530, 3),
+    ('\u{2F9F3}', 7533, 3),
+    ('\u{2F9F4}', 7536, 3),
+    ('\u{2F9F5}', 7539, 3),
+    ('\u{2F9F6}', 7542, 4),
+    ('\u{2F9F7}', 7546, 4),
+    ('\u{2F9F8}', 7550, 3),
+    ('\u{2F9F9}', 7553, 3),
+    ('\u{2F9FA}', 7556, 3),
+    ('\u{2F9FB}', 7559, 4),
+    ('\u{2F9FC}', 7563, 3),
+    ('\u{2F9FD}', 7566, 4),
+    ('\u{2F9FE}', 5529, 3),
+    ('\u{2F9FF}', 5529, 3),
+    ('\u{2FA00}', 7570, 3),
+    ('\u{2FA01}', 7573, 4),
+    ('\u{2FA02}', 7577, 3),
+    ('\u{2FA03}', 7580, 3),
+    ('\u{2FA04}', 7583, 3),
+    ('\u{2FA05}', 7586, 3),
+    ('\u{2FA06}', 7589, 3),
+    ('\u{2FA07}', 7592, 3),
+    ('\u{2FA08}', 7595, 3),
+    ('\u{2FA09}', 7598, 4),
+    ('\u{2FA0A}', 5532, 3),
+    ('\u{2FA0B}', 7602, 3),
+    ('\u{2FA0C}', 7605, 3),
+    ('\u{2FA0D}', 7608, 3),
+    ('\u{2FA0E}', 7611, 3),
+    ('\u{2FA0F}', 7614, 3),
+    ('\u{2FA10}', 7617, 4),
+    ('\u{2FA11}', 7621, 3),
+    ('\u{2FA12}', 7624, 4),
+    ('\u{2FA13}', 7628, 4),
+    ('\u{2FA14}', 7632, 4),
+    ('\u{2FA15}', 7636, 3),
+    ('\u{2FA16}', 7639, 3),
+    ('\u{2FA17}', 7642, 3),
+    ('\u{2FA18}', 7645, 3),
+    ('\u{2FA19}', 7648, 3),
+    ('\u{2FA1A}', 7651, 3),
+    ('\u{2FA1B}', 7654, 3),
+    ('\u{2FA1C}', 7657, 3),
+    ('\u{2FA1D}', 7660, 4),
+];
+
+pub(super) static COMPATIBILITY_DECOMPOSITIONS: [(char, u16, u8); 3848] = [
+    ('\u{00A0}', 7664, 1),
+    ('\u{00A8}', 7665, 3),
+    ('\u{00AA}', 7668, 1),
+    ('\u{00AF}', 7669, 3),
+    ('\u{00B2}', 7672, 1),
+    ('\u{00B3}', 7673, 1),
+    ('\u{00B4}', 7674, 3),
+    ('\u{00B5}', 7677, 2),
+    ('\u{00B8}', 7679, 3),
+    ('\u{00B9}', 7682, 1),
+    ('\u{00BA}', 7683, 1),
+    ('\u{00BC}', 7684, 5),
+    ('\u{00BD}', 7689, 5),
+    ('\u{00BE}', 7694, 5),
+    ('\u{0132}', 7699, 2),
+    ('\u{0133}', 7701, 2),
+    ('\u{013F}', 7703, 3),
+    ('\u{0140}', 7706, 3),
+    ('\u{0149}', 7709, 3),
+    ('\u{017F}', 7712, 1),
+    ('\u{01C4}', 7713, 4),
+    ('\u{01C5}', 7717, 4),
+    ('\u{01C6}', 7721, 4),
+    ('\u{01C7}', 7725, 2),
+    ('\u{01C8}', 7727, 2),
+    ('\u{01C9}', 7729, 2),
+    ('\u{01CA}', 7731, 2),
+    ('\u{01CB}', 7733, 2),
+    ('\u{01CC}', 7735, 2),
+    ('\u{01F1}', 7737, 2),
+    ('\u{01F2}', 7739, 2),
+    ('\u{01F3}', 7741, 2),
+    ('\u{02B0}', 7743, 1),
+    ('\u{02B1}', 7744, 2),
+    ('\u{02B2}', 7746, 1),
+    ('\u{02B3}', 7747, 1),
+    ('\u{02B4}', 7748, 2),
+    ('\u{02B5}', 7750, 2),
+    ('\u{02B6}', 7752, 2),
+    ('\u{02B7}', 7754, 1),
+    ('\u{02B8}', 7755, 1),
+    ('\u{02D8}', 7756, 3),
+    ('\u{02D9}', 7759, 3),
+    ('\u{02DA}', 7762, 3),
+    ('\u{02DB}', 7765, 3),
+    ('\u{02DC}', 7768, 3),
+    ('\u{02DD}', 7771, 3),
+    ('\u{02E0}', 7774, 2),
+    ('\u{02E1}', 7776, 1),
+    ('\u{02E2}', 7712, 1),
+    ('\u{02E3}', 7777, 1),
+    ('\u{02E4}', 7778, 2),
+    ('\u{037A}', 7780, 3),
+    ('\u{0384}', 7674, 3),
+    ('\u{0385}', 7783, 5),
+    ('\u{03D0}', 7788, 2),
+    ('\u{03D1}', 7790, 2),
+    ('\u{03D2}', 7792, 2),
+    ('\u{03D3}', 847, 4),
+    ('\u{03D4}', 865, 4),
+    ('\u{03D5}', 7794, 2),
+    ('\u{03D6}', 7796, 2),
+    ('\u{03F0}', 7798, 2),
+    ('\u{03F1}', 7800, 2),
+    ('\u{03F2}', 7802, 2),
+    ('\u{03F4}', 7804, 2),
+    ('\u{03F5}', 7806, 2),
+    ('\u{03F9}', 7808, 2),
+    ('\u{0587}', 7810, 4),
+    ('\u{0675}', 7814, 4),
+    ('\u{0676}', 7818, 4),
+    ('\u{0677}', 7822, 4),
+    ('\u{0678}', 7826, 4),
+    ('\u{0E33}', 7830, 6),
+    ('\u{0EB3}', 7836, 6),
+    ('\u{0EDC}', 7842, 6),
+    ('\u{0EDD}', 7848, 6),
+    ('\u{0F0C}', 7854, 3),
+    ('\u{0F77}', 7857, 9),
+    ('\u{0F79}', 7866, 9),
+    ('\u{10FC}', 7875, 3),
+    ('\u{1D2C}', 7878, 1),
+    ('\u{1D2D}', 7879, 2),
+    ('\u{1D2E}', 7881, 1),
+    ('\u{1D30}', 7882, 1),
+    ('\u{1D31}', 7883, 1),
+    ('\u{1D32}', 7884, 2),
+    ('\u{1D33}', 7886, 1),
+    ('\u{1D34}', 7887, 1),
+    ('\u{1D35}', 7888, 1),
+    ('\u{1D36}', 7889, 1),
+    ('\u{1D37}', 3718, 1),
+    ('\u{1D38}', 7890, 1),
+    ('\u{1D39}', 7891, 1),
+    ('\u{1D3A}', 7892, 1),
+    ('\u{1D3C}', 7893, 1),
+    ('\u{1D3D}', 7894, 2),
+    ('\u{1D3E}', 7896, 1),
+    ('\u{1D3F}', 7897, 1),
+    ('\u{1D40}', 7898, 1),
+    ('\u{1D41}', 7899, 1),
+    ('\u{1D42}', 7900, 1),
+    ('\u{1D43}', 7668, 1),
+    ('\u{1D44}', 7901, 2),
+    ('\u{1D45}', 7903, 2),
+    ('\u{1D46}', 7905, 3),
+    ('\u{1D47}', 7908, 1),
+    ('\u{1D48}', 7909, 1),
+    ('\u{1D49}', 7910, 1),
+    ('\u{1D4A}', 7911, 2),
+    ('\u{1D4B}', 7913, 2),
+    ('\u{1D4C}', 7915, 2),
+    ('\u{1D4D}', 7917, 1),
+    ('\u{1D4F}', 7918, 1),
+    ('\u{1D50}', 7919, 1),
+    ('\u{1D51}', 7920, 2),
+    ('\u{1D52}', 7683, 1),
+    ('\u{1D53}', 7922, 2),
+    ('\u{1D54}', 7924, 3),
+    ('\u{1D55}', 7927, 3),
+    ('\u{1D56}', 7930, 1),
+    ('\u{1D57}', 7931, 1),
+    ('\u{1D58}', 7932, 1),
+    ('\u{1D59}', 7933, 3),
+    ('\u{1D5A}', 7936, 2),
+    ('\u{1D5B}', 7938, 1),
+    ('\u{1D5C}', 7939, 3),
+    ('\u{1D5D}', 7788, 2),
+    ('\u{1D5E}', 7942, 2),
+    ('\u{1D5F}', 7944, 2),
+    ('\u{1D60}', 7794, 2),
+    ('\u{1D61}', 7946, 2),
+    ('\u{1D62}', 7948, 1),
+    ('\u{1D63}', 7747, 1),
+    ('\u{1D64}', 7932, 1),
+    ('\u{1D65}', 7938, 1),
+    ('\u{1D66}', 7788, 2),
+    ('\u{1D67}', 7942, 2),
+    ('\u{1D68}', 7800, 2),
+    ('\u{1D69}', 7794, 2),
+    ('\u{1D6A}', 7946, 2),
+    ('\u{1D78}', 7949, 2),
+    ('\u{1D9B}', 7951, 2),
+    ('\u{1D9C}', 7953, 1),
+    ('\u{1D9D}', 7954, 2),
+    ('\u{1D9E}', 7956, 2),
+    ('\u{1D9F}', 7915, 2),
+    ('\u{1DA0}', 7958, 1),
+    ('\u{1DA1}', 7959, 2),
+    ('\u{1DA2}', 7961, 2),
+    ('\u{1DA3}', 7963, 2),
+    ('\u{1DA4}', 7965, 2),
+    ('\u{1DA5}', 7967, 2),
+    ('\u{1DA6}', 7969, 2),
+    ('\u{1DA7}', 7971, 3),
+    ('\u{1DA8}', 7974, 2),
+    ('\u{1DA9}', 7976, 2),
+    ('\u{1DAA}', 7978, 3),
+    ('\u{1DAB}', 7981, 2),
+    ('\u{1DAC}', 7983, 2),
+    ('\u{1DAD}', 7985, 2),
+    ('\u{1DAE}', 7987, 2),
+    ('\u{1DAF}', 7989, 2),
+    ('\u{1DB0}', 7991, 2),
+    ('\u{1DB1}', 7993, 2),
+    ('\u{1DB2}', 7995, 2),
+    ('\u{1DB3}', 7997, 2),
+    ('\u{1DB4}', 7999, 2),
+    ('\u{1DB5}', 8001, 2),
+    ('\u{1DB6}', 8003, 2),
+    ('\u{1DB7}', 8005, 2),
+    ('\u{1DB8}', 8007, 3),
+    ('\u{1DB9}', 8010, 2),
+    ('\u{1DBA}', 8012, 2),
+    ('\u{1DBB}', 8014, 1),
+    ('\u{1DBC}', 8015, 2),
+    ('\u{1DBD}', 8017, 2),
+    ('\u{1DBE}', 8019, 2),
+    ('\u{1DBF}', 7790, 2),
+    ('\u{1E9A}', 8021, 3),
+    ('\u{1E9B}', 1938, 3),
+    ('\u{1FBD}', 8024, 3),
+    ('\u{1FBF}', 8024, 3),
+    ('\u{1FC0}', 8027, 3),
+    ('\u{1FC1}', 8030, 5),
+    ('\u{1FCD}', 8035, 5),
+    ('\u{1FCE}', 8040, 5),
+    ('\u{1FCF}', 8045, 5),
+    ('\u{1FDD}', 8050, 5),
+    ('\u{1FDE}', 8055, 5),
+    ('\u{1FDF}', 8060, 5),
+    ('\u{1FED}', 8065, 5),
+    ('\u{1FEE}', 7783, 5),
+    ('\u{1FFD}', 7674, 3),
+    ('\u{1FFE}', 8070, 3),
+    ('\u{2000}', 7664, 1),
+    ('\u{2001}', 7664, 1),
+    ('\u{2002}', 7664, 1),
+    ('\u{2003}', 7664, 1),
+    ('\u{2004}', 7664, 1),
+    ('\u{2005}', 7664, 1),
+    ('\u{2006}', 7664, 1),
+    ('\u{2007}', 7664, 1),
+    ('\u{2008}', 7664, 1),
+    ('\u{2009}', 7664, 1),
+    ('\u{200A}', 7664, 1),
+    ('\u{2011}', 8073, 3),
+    ('\u{2017}', 8076, 3),
+    ('\u{2024}', 8079, 1),
+    ('\u{2025}', 8080, 2),
+    ('\u{2026}', 8082, 3),
+    ('\u{202F}', 7664, 1),
+    ('\u{2033}', 8085, 6),
+    ('\u{2034}', 8091, 9),
+    ('\u{2036}', 8100, 6),
+    ('\u{2037}', 8106, 9),
+    ('\u{203C}', 8115, 2),
+    ('\u{203E}', 8117, 3),
+    ('\u{2047}', 8120, 2),
+    ('\u{2048}', 8122, 2),
+    ('\u{2049}', 8124, 2),
+    ('\u{2057}', 8126, 12),
+    ('\u{205F}', 7664, 1),
+    ('\u{2070}', 8138, 1),
+    ('\u{2071}', 7948, 1),
+    ('\u{2074}', 8139, 1),
+    ('\u{2075}', 8140, 1),
+    ('\u{2076}', 8141, 1),
+    ('\u{2077}', 8142, 1),
+    ('\u{2078}', 8143, 1),
+    ('\u{2079}', 8144, 1),
+    ('\u{207A}', 8145, 1),
+    ('\u{207B}', 8146, 3),
+    ('\u{207C}', 8149, 1),
+    ('\u{207D}', 8150, 1),
+    ('\u{207E}', 8151, 1),
+    ('\u{207F}', 8152, 1),
+    ('\u{2080}', 8138, 1),
+    ('\u{2081}', 7682, 1),
+    ('\u{2082}', 7672, 1),
+    ('\u{2083}', 7673, 1),
+    ('\u{2084}', 8139, 1),
+    ('\u{2085}', 8140, 1),
+    ('\u{2086}', 8141, 1),
+    ('\u{2087}', 8142, 1),
+    ('\u{2088}', 8143, 1),
+    ('\u{2089}', 8144, 1),
+    ('\u{208A}', 8145, 1),
+    ('\u{208B}', 8146, 3),
+    ('\u{208C}', 8149, 1),
+    ('\u{208D}', 8150, 1),
+    ('\u{208E}', 8151, 1),
+    ('\u{2090}', 7668, 1),
+    ('\u{2091}', 7910, 1),
+    ('\u{2092}', 7683, 1),
+    ('\u{2093}', 7777, 1),
+    ('\u{2094}', 7911, 2),
+    ('\u{2095}', 7743, 1),
+    ('\u{2096}', 7918, 1),
+    ('\u{2097}', 7776, 1),
+    ('\u{2098}', 7919, 1),
+    ('\u{2099}', 8152, 1),
+    ('\u{209A}', 7930, 1),
+    ('\u{209B}', 7712, 1),
+    ('\u{209C}', 7931, 1),
+    ('\u{20A8}', 8153, 2),
+    ('\u{2100}', 8155, 3),
+    ('\u{2101}', 8158, 3),
+    ('\u{2102}', 8161, 1),
+    ('\u{2103}', 8162, 3),
+    ('\u{2105}', 8165, 3),
+    ('\u{2106}', 8168, 3),
+    ('\u{2107}', 8171, 2),
+    ('\u{2109}', 8173, 3),
+    ('\u{210A}', 7917, 1),
+    ('\u{210B}', 7887, 1),
+    ('\u{210C}', 7887, 1),
+    ('\u{210D}', 7887, 1),
+    ('\u{210E}', 7743, 1),
+    ('\u{210F}', 8176, 2),
+    ('\u{2110}', 7888, 1),
+    ('\u{2111}', 7888, 1),
+    ('\u{2112}', 7890, 1),
+    ('\u{2113}', 7776, 1),
+    ('\u{2115}', 7892, 1),
+    ('\u{2116}', 8178, 2),
+    ('\u{2119}', 7896, 1),
+    ('\u{211A}', 8180, 1),
+    ('\u{211B}', 7897, 1),
+    ('\u{211C}', 7897, 1),
+    ('\u{211D}', 7897, 1),
+    ('\u{2120}', 8181, 2),
+    ('\u{2121}', 8183, 3),
+    ('\u{2122}', 8186, 2),
+    ('\u{2124}', 8188, 1),
+    ('\u{2128}', 8188, 1),
+    ('\u{212C}', 7881, 1),
+    ('\u{212D}', 8161, 1),
+    ('\u{212F}', 7910, 1),
+    ('\u{2130}', 7883, 1),
+    ('\u{2131}', 8189, 1),
+    ('\u{2133}', 7891, 1),
+    ('\u{2134}', 7683, 1),
+    ('\u{2135}', 8190, 2),
+    ('\u{2136}', 8192, 2),
+    ('\u{2137}', 8194, 2),
+    ('\u{2138}', 8196, 2),
+    ('\u{2139}', 7948, 1),
+    ('\u{213B}', 8198, 3),
+    ('\u{213C}', 7796, 2),
+    ('\u{213D}', 7942, 2),
+    ('\u{213E}', 8201, 2),
+    ('\u{213F}', 8203, 2),
+    ('\u{2140}', 8205, 3),
+    ('\u{2145}', 7882, 1),
+    ('\u{2146}', 7909, 1),
+    ('\u{2147}', 7910, 1),
+    ('\u{2148}', 7948, 1),
+    ('\u{2149}', 7746, 1),
+    ('\u{2150}', 8208, 5),
+    ('\u{2151}', 8213, 5),
+    ('\u{2152}', 8218, 6),
+    ('\u{2153}', 8224, 5),
+    ('\u{2154}', 8229, 5),
+    ('\u{2155}', 8234, 5),
+    ('\u{2156}', 8239, 5),
+    ('\u{2157}', 8244, 5),
+    ('\u{2158}', 8249, 5),
+    ('\u{2159}', 8254, 5),
+    ('\u{215A}', 8259, 5),
+    ('\u{215B}', 8264, 5),
+    ('\u{215C}', 8269, 5),
+    ('\u{215D}', 8274, 5),
+    ('\u{215E}', 8279, 5),
+    ('\u{215F}', 8284, 4),
+    ('\u{2160}', 7888, 1),
+    ('\u{2161}', 8288, 2),
+    ('\u{2162}', 8290, 3),
+    ('\u{2163}', 8293, 2),
+    ('\u{2164}', 8295, 1),
+    ('\u{2165}', 8296, 2),
+    ('\u{2166}', 8298, 3),
+    ('\u{2167}', 8301, 4),
+    ('\u{2168}', 8305, 2),
+    ('\u{2169}', 8307, 1),
+    ('\u{216A}', 8308, 2),
+    ('\u{216B}', 8310, 3),
+    ('\u{216C}', 7890, 1),
+    ('\u{216D}', 8161, 1),
+    ('\u{216E}', 7882, 1),
+    ('\u{216F}', 7891, 1),
+    ('\u{2170}', 7948, 1),
+    ('\u{2171}', 8313, 2),
+    ('\u{2172}', 8315, 3),
+    ('\u{2173}', 8318, 2),
+    ('\u{2174}', 7938, 1),
+    ('\u{2175}', 8320, 2),
+    ('\u{2176}', 8322, 3),
+    ('\u{2177}', 8325, 4),
+    ('\u{2178}', 8329, 2),
+    ('\u{2179}', 7777, 1),
+    ('\u{217A}', 8331, 2),
+    ('\u{217B}', 8333, 3),
+    ('\u{217C}', 7776, 1),
+    ('\u{217D}', 7953, 1),
+    ('\u{217E}', 7909, 1),
+    ('\u{217F}', 7919, 1),
+    ('\u{2189}', 8336, 5),
+    ('\u{222C}', 8341, 6),
+    ('\u{222D}', 8347, 9),
+    ('\u{222F}', 8356, 6),
+    ('\u{2230}', 8362, 9),
+    ('\u{2460}', 7682, 1),
+    ('\u{2461}', 7672, 1),
+    ('\u{2462}', 7673, 1),
+    ('\u{2463}', 8139, 1),
+    ('\u{2464}', 8140, 1),
+    ('\u{2465}', 8141, 1),
+    ('\u{2466}', 8142, 1),
+    ('\u{2467}', 8143, 1),
+    ('\u{2468}', 8144, 1),
+    ('\u{2469}', 8371, 2),
+    ('\u{246A}', 8373, 2),
+    ('\u{246B}', 8375, 2),
+    ('\u{246C}', 8377, 2),
+    ('\u{246D}', 8379, 2),
+    ('\u{246E}', 8381, 2),
+    ('\u{246F}', 8383, 2),
+    ('\u{2470}', 8385, 2),
+    ('\u{2471}', 8387, 2),
+    ('\u{2472}', 8389, 2),
+    ('\u{2473}', 8391, 2),
+    ('\u{2474}', 8393, 3),
+    ('\u{2475}', 8396, 3),
+    ('\u{2476}', 8399, 3),
+    ('\u{2477}', 8402, 3),
+    ('\u{2478}', 8405, 3),
+    ('\u{2479}', 8408, 3),
+    ('\u{247A}', 8411, 3),
+    ('\u{247B}', 8414, 3),
+    ('\u{247C}', 8417, 3),
+    ('\u{247D}', 8420, 4),
+    ('\u{247E}', 8424, 4),
+    ('\u{247F}', 8428, 4),
+    ('\u{2480}', 8432, 4),
+    ('\u{2481}', 8436, 4),
+    ('\u{2482}', 8440, 4),
+    ('\u{2483}', 8444, 4),
+    ('\u{2484}', 8448, 4),
+    ('\u{2485}', 8452, 4),
+    ('\u{2486}', 8456, 4),
+    ('\u{2487}', 8460, 4),
+    ('\u{2488}', 8464, 2),
+    ('\u{2489}', 8466, 2),
+    ('\u{248A}', 8468, 2),
+    ('\u{248B}', 8470, 2),
+    ('\u{248C}', 8472, 2),
+    ('\u{248D}', 8474, 2),
+    ('\u{248E}', 8476, 2),
+    ('\u{248F}', 8478, 2),
+    ('\u{2490}', 8480, 2),
+    ('\u{2491}', 8482, 3),
+    ('\u{2492}', 8485, 3),
+    ('\u{2493}', 8488, 3),
+    ('\u{2494}', 8491, 3),
+    ('\u{2495}', 8494, 3),
+    ('\u{2496}', 8497, 3),
+    ('\u{2497}', 8500, 3),
+    ('\u{2498}', 8503, 3),
+    ('\u{2499}', 8506, 3),
+    ('\u{249A}', 8509, 3),
+    ('\u{249B}', 8512, 3),
+    ('\u{249C}', 8515, 3),
+    ('\u{249D}', 8518, 3),
+    ('\u{249E}', 8521, 3),
+    ('\u{249F}', 8524, 3),
+    ('\u{24A0}', 8527, 3),
+    ('\u{24A1}', 8530, 3),
+    ('\u{24A2}', 8533, 3),
+    ('\u{24A3}', 8536, 3),
+    ('\u{24A4}', 8539, 3),
+    ('\u{24A5}', 8542, 3),
+    ('\u{24A6}', 8545, 3),
+    ('\u{24A7}', 8548, 3),
+    ('\u{24A8}', 8551, 3),
+    ('\u{24A9}', 8554, 3),
+    ('\u{24AA}', 8557, 3),
+    ('\u{24AB}', 8560, 3),
+    ('\u{24AC}', 8563, 3),
+    ('\u{24AD}', 8566, 3),
+    ('\u{24AE}', 8569, 3),
+    ('\u{24AF}', 8572, 3),
+    ('\u{24B0}', 8575, 3),
+    ('\u{24B1}', 8578, 3),
+    ('\u{24B2}', 8581, 3),
+    ('\u{24B3}', 8584, 3),
+    ('\u{24B4}', 8587, 3),
+    ('\u{24B5}', 8590, 3),
+    ('\u{24B6}', 7878, 1),
+    ('\u{24B7}', 7881, 1),
+    ('\u{24B8}', 8161, 1),
+    ('\u{24B9}', 7882, 1),
+    ('\u{24BA}', 7883, 1),
+    ('\u{24BB}', 8189, 1),
+    ('\u{24BC}', 7886, 1),
+    ('\u{24BD}', 7887, 1),
+    ('\u{24BE}', 7888, 1),
+    ('\u{24BF}', 7889, 1),
+    ('\u{24C0}', 3718, 1),
+    ('\u{24C1}', 7890, 1),
+    ('\u{24C2}', 7891, 1),
+    ('\u{24C3}', 7892, 1),
+    ('\u{24C4}', 7893, 1),
+    ('\u{24C5}', 7896, 1),
+    ('\u{24C6}', 8180, 1),
+    ('\u{24C7}', 7897, 1),
+    ('\u{24C8}', 8593, 1),
+    ('\u{24C9}', 7898, 1),
+    ('\u{24CA}', 7899, 1),
+    ('\u{24CB}', 8295, 1),
+    ('\u{24CC}', 7900, 1),
+    ('\u{24CD}', 8307, 1),
+    ('\u{24CE}', 8594, 1),
+    ('\u{24CF}', 8188, 1),
+    ('\u{24D0}', 7668, 1),
+    ('\u{24D1}', 7908, 1),
+    ('\u{24D2}', 7953, 1),
+    ('\u{24D3}', 7909, 1),
+    ('\u{24D4}', 7910, 1),
+    ('\u{24D5}', 7958, 1),
+    ('\u{24D6}', 7917, 1),
+    ('\u{24D7}', 7743, 1),
+    ('\u{24D8}', 7948, 1),
+    ('\u{24D9}', 7746, 1),
+    ('\u{24DA}', 7918, 1),
+    ('\u{24DB}', 7776, 1),
+    ('\u{24DC}', 7919, 1),
+    ('\u{24DD}', 8152, 1),
+    ('\u{24DE}', 7683, 1),
+    ('\u{24DF}', 7930, 1),
+    ('\u{24E0}', 8595, 1),
+    ('\u{24E1}', 7747, 1),
+    ('\u{24E2}', 7712, 1),
+    ('\u{24E3}', 7931, 1),
+    ('\u{24E4}', 7932, 1),
+    ('\u{24E5}', 7938, 1),
+    ('\u{24E6}', 7754, 1),
+    ('\u{24E7}', 7777, 1),
+    ('\u{24E8}', 7755, 1),
+    ('\u{24E9}', 8014, 1),
+    ('\u{24EA}', 8138, 1),
+    ('\u{2A0C}', 8596, 12),
+    ('\u{2A74}', 8608, 3),
+    ('\u{2A75}', 8611, 2),
+    ('\u{2A76}', 8613, 3),
+    ('\u{2C7C}', 7746, 1),
+    ('\u{2C7D}', 8295, 1),
+    ('\u{2D6F}', 8616, 3),
+    ('\u{2E9F}', 8619, 3),
+    ('\u{2EF3}', 8622, 3),
+    ('\u{2F00}', 8625, 3),
+    ('\u{2F01}', 8628, 3),
+    ('\u{2F02}', 8631, 3),
+    ('\u{2F03}', 8634, 3),
+    ('\u{2F04}', 8637, 3),
+    ('\u{2F05}', 8640, 3),
+    ('\u{2F06}', 8643, 3),
+    ('\u{2F07}', 8646, 3),
+    ('\u{2F08}', 8649, 3),
+    ('\u{2F09}', 8652, 3),
+    ('\u{2F0A}', 8655, 3),
+    ('\u{2F0B}', 8658, 3),
+    ('\u{2F0C}', 8661, 3),
+    ('\u{2F0D}', 8664, 3),
+    ('\u{2F0E}', 8667, 3),
+    ('\u{2F0F}', 8670, 3),
+    ('\u{2F10}', 6210, 3),
+    ('\u{2F11}', 8673, 3),
+    ('\u{2F12}', 4700, 3),
+    ('\u{2F13}', 8676, 3),
+    ('\u{2F14}', 8679, 3),
+    ('\u{2F15}', 8682, 3),
+    ('\u{2F16}', 8685, 3),
+    ('\u{2F17}', 8688, 3),
+    ('\u{2F18}', 8691, 3),
+    ('\u{2F19}', 8694, 3),
+    ('\u{2F1A}', 8697, 3),
+    ('\u{2F1B}', 8700, 3),
+    ('\u{2F1C}', 8703, 3),
+    ('\u{2F1D}', 8706, 3),
+    ('\u{2F1E}', 8709, 3),
+    ('\u{2F1F}', 8712, 3),
+    ('\u{2F20}', 8715, 3),
+    ('\u{2F21}', 8718, 3),
+    ('\u{2F22}', 8721, 3),
+    ('\u{2F23}', 8724, 3),
+    ('\u{2F24}', 8727, 3),
+    ('\u{2F25}', 4673, 3),
+    ('\u{2F26}', 8730, 3),
+    ('\u{2F27}', 8733, 3),
+    ('\u{2F28}', 8736, 3),
+    ('\u{2F29}', 8739, 3),
+    ('\u{2F2A}', 6439, 3),
+    ('\u{2F2B}', 8742, 3),
+    ('\u{2F2C}', 5186, 3),
+    ('\u{2F2D}', 8745, 3),
+    ('\u{2F2E}', 8748, 3),
+    ('\u{2F2F}', 8751, 3),
+    ('\u{2F30}', 8754, 3),
+    ('\u{2F31}', 8757, 3),
+    ('\u{2F32}', 8760, 3),
+    ('\u{2F33}', 8763, 3),
+    ('\u{2F34}', 8766, 3),
+    ('\u{2F35}', 8769, 3),
+    ('\u{2F36}', 6518, 3),
+    ('\u{2F37}', 8772, 3),
+    ('\u{2F38}', 8775, 3),
+    ('\u{2F39}', 8778, 3),
+    ('\u{2F3A}', 8781, 3),
+    ('\u{2F3B}', 8784, 3),
+    ('\u{2F3C}', 8787, 3),
+    ('\u{2F3D}', 8790, 3),
+    ('\u{2F3E}', 8793, 3),
+    ('\u{2F3F}', 8796, 3),
+    ('\u{2F40}', 8799, 3),
+    ('\u{2F41}', 8802, 3),
+    ('\u{2F42}', 8805, 3),
+    ('\u{2F43}', 8808, 3),
+    ('\u{2F44}', 8811, 3),
+    ('\u{2F45}', 8814, 3),
+    ('\u{2F46}', 8817, 3),
+    ('\u{2F47}', 8820, 3),
+    ('\u{2F48}', 8823, 3),
+    ('\u{2F49}', 8826, 3),
+    ('\u{2F4A}', 8829, 3),
+    ('\u{2F4B}', 8832, 3),
+    ('\u{2F4C}', 8835, 3),
+    ('\u{2F4D}', 5421, 3),
+    ('\u{2F4E}', 8838, 3),
+    ('\u{2F4F}', 8841, 3),
+    ('\u{2F50}', 8844, 3),
+    ('\u{2F51}', 8847, 3),
+    ('\u{2F52}', 8850, 3),
+    ('\u{2F53}', 8853, 3),
+    ('\u{2F54}', 8856, 3),
+    ('\u{2F55}', 8859, 3),
+    ('\u{2F56}', 8862, 3),
+    ('\u{2F57}', 8865, 3),
+    ('\u{2F58}', 8868, 3),
+    ('\u{2F59}', 8871, 3),
+    ('\u{2F5A}', 8874, 3),
+    ('\u{2F5B}', 8877, 3),
+    ('\u{2F5C}', 8880, 3),
+    ('\u{2F5D}', 8883, 3),
+    ('\u{2F5E}', 8886, 3),
+    ('\u{2F5F}', 8889, 3),
+    ('\u{2F60}', 8892, 3),
+    ('\u{2F61}', 8895, 3),
+    ('\u{2F62}', 8898, 3),
+    ('\u{2F63}', 8901, 3),
+    ('\u{2F64}', 8904, 3),
+    ('\u{2F65}', 8907, 3),
+    ('\u{2F66}', 8910, 3),
+    ('\u{2F67}', 8913, 3),
+    ('\u{2F68}', 8916, 3),
+    ('\u{2F69}', 8919, 3),
+    ('\u{2F6A}', 8922, 3),
+    ('\u{2F6B}', 8925, 3),
+    ('\u{2F6C}', 8928, 3),
+    ('\u{2F6D}', 8931, 3),
+    ('\u{2F6E}', 8934, 3),
+    ('\u{2F6F}', 8937, 3),
+    ('\u{2F70}', 8940, 3),
+    ('\u{2F71}', 8943, 3),
+    ('\u{2F72}', 8946, 3),
+    ('\u{2F73}', 8949, 3),
+    ('\u{2F74}', 5015, 3),
+    ('\u{2F75}', 8952, 3),
+    ('\u{2F76}', 8955, 3),
+    ('\u{2F77}', 8958, 3),
+    ('\u{2F78}', 8961, 3),
+    ('\u{2F79}', 8964, 3),
+    ('\u{2F7A}', 8967, 3),
+    ('\u{2F7B}', 5117, 3),
+    ('\u{2F7C}', 4445, 3),
+    ('\u{2F7D}', 8970, 3),
+    ('\u{2F7E}', 8973, 3),
+    ('\u{2F7F}', 8976, 3),
+    ('\u{2F80}', 8979, 3),
+    ('\u{2F81}', 8982, 3),
+    ('\u{2F82}', 8985, 3),
+    ('\u{2F83}', 8988, 3),
+    ('\u{2F84}', 8991, 3),
+    ('\u{2F85}', 8994, 3),
+    ('\u{2F86}', 8997, 3),
+    ('\u{2F87}', 9000, 3),
+    ('\u{2F88}', 9003, 3),
+    ('\u{2F89}', 9006, 3),
+    ('\u{2F8A}', 9009, 3),
+    ('\u{2F8B}', 9012, 3),
+    ('\u{2F8C}', 9015, 3),
+    ('\u{2F8D}', 9018, 3),
+    ('\u{2F8E}', 9021, 3),
+    ('\u{2F8F}', 5066, 3),
+    ('\u{2F90}', 7389, 3),
+    ('\u{2F91}', 9024, 3),
+    ('\u{2F92}', 5072, 3),
+    ('\u{2F93}', 9027, 3),
+    ('\u{2F94}', 9030, 3),
+    ('\u{2F95}', 9033, 3),
+    ('\u{2F96}', 9036, 3),
+    ('\u{2F97}', 7428, 3),
+    ('\u{2F98}', 9039, 3),
+    ('\u{2F99}', 9042, 3),
+    ('\u{2F9A}', 9045, 3),
+    ('\u{2F9B}', 9048, 3),
+    ('\u{2F9C}', 9051, 3),
+    ('\u{2F9D}', 9054, 3),
+    ('\u{2F9E}', 4298, 3),
+    ('\u{2F9F}', 9057, 3),
+    ('\u{2FA0}', 4625, 3),
+    ('\u{2FA1}', 9060, 3),
+    ('\u{2FA2}', 9063, 3),
+    ('\u{2FA3}', 9066, 3),
+    ('\u{2FA4}', 9069, 3),
+    ('\u{2FA5}', 4973, 3),
+    ('\u{2FA6}', 4319, 3),
+    ('\u{2FA7}', 9072, 3),
+    ('\u{2FA8}', 9075, 3),
+    ('\u{2FA9}', 9078, 3),
+    ('\u{2FAA}', 9081, 3),
+    ('\u{2FAB}', 9084, 3),
+    ('\u{2FAC}', 9087, 3),
+    ('\u{2FAD}', 9090, 3),
+    ('\u{2FAE}', 9093, 3),
+    ('\u{2FAF}', 9096, 3),
+    ('\u{2FB0}', 9099, 3),
+    ('\u{2FB1}', 9102, 3),
+    ('\u{2FB2}', 9105, 3),
+    ('\u{2FB3}', 9108, 3),
+    ('\u{2FB4}', 9111, 3),
+    ('\u{2FB5}', 9114, 3),
+    ('\u{2FB6}', 9117, 3),
+    ('\u{2FB7}', 9120, 3),
+    ('\u{2FB8}', 9123, 3),
+    ('\u{2FB9}', 9126, 3),
+    ('\u{2FBA}', 9129, 3),
+    ('\u{2FBB}', 9132, 3),
+    ('\u{2FBC}', 9135, 3),
+    ('\u{2FBD}', 9138, 3),
+    ('\u{2FBE}', 9141, 3),
+    ('\u{2FBF}', 9144, 3),
+    ('\u{2FC0}', 9147, 3),
+    ('\u{2FC1}', 9150, 3),
+    ('\u{2FC2}', 9153, 3),
+    ('\u{2FC3}', 9156, 3),
+    ('\u{2FC4}', 9159, 3),
+    ('\u{2FC5}', 4481, 3),
+    ('\u{2FC6}', 9162, 3),
+    ('\u{2FC7}', 7636, 3),
+    ('\u{2FC8}', 9165, 3),
+    ('\u{2FC9}', 9168, 3),
+    ('\u{2FCA}', 9171, 3),
+    ('\u{2FCB}', 7642, 3),
+    ('\u{2FCC}', 9174, 3),
+    ('\u{2FCD}', 9177, 3),
+    ('\u{2FCE}', 9180, 3),
+    ('\u{2FCF}', 9183, 3),
+    ('\u{2FD0}', 7657, 3),
+    ('\u{2FD1}', 9186, 3),
+    ('\u{2FD2}', 9189, 3),
+    ('\u{2FD3}', 4865, 3),
+    ('\u{2FD4}', 4313, 3),
+    ('\u{2FD5}', 9192, 3),
+    ('\u{3000}', 7664, 1),
+    ('\u{3036}', 9195, 3),
+    ('\u{3038}', 8688, 3),
+    ('\u{3039}', 9198, 3),
+    ('\u{303A}', 9201, 3),
+    ('\u{309B}', 9204, 4),
+    ('\u{309C}', 9208, 4),
+    ('\u{309F}', 9212, 6),
+    ('\u{30FF}', 9218, 6),
+    ('\u{3131}', 9224, 3),
+    ('\u{3132}', 9227, 3),
+    ('\u{3133}', 9230, 3),
+    ('\u{3134}', 9233, 3),
+    ('\u{3135}', 9236, 3),
+    ('\u{3136}', 9239, 3),
+    ('\u{3137}', 9242, 3),
+    ('\u{3138}', 9245, 3),
+    ('\u{3139}', 9248, 3),
+    ('\u{313A}', 9251, 3),
+    ('\u{313B}', 9254, 3),
+    ('\u{313C}', 9257, 3),
+    ('\u{313D}', 9260, 3),
+    ('\u{313E}', 9263, 3),
+    ('\u{313F}', 9266, 3),
+    ('\u{3140}', 9269, 3),
+    ('\u{3141}', 9272, 3),
+    ('\u{3142}', 9275, 3),
+    ('\u{3143}', 9278, 3),
+    ('\u{3144}', 9281, 3),
+    ('\u{3145}', 9284, 3),
+    ('\u{3146}', 9287, 3),
+    ('\u{3147}', 9290, 3),
+    ('\u{3148}', 9293, 3),
+    ('\u{3149}', 9296, 3),
+    ('\u{314A}', 9299, 3),
+    ('\u{314B}', 9302, 3),
+    ('\u{314C}', 9305, 3),
+    ('\u{314D}', 9308, 3),
+    ('\u{314E}', 9311, 3),
+    ('\u{314F}', 9314, 3),
+    ('\u{3150}', 9317, 3),
+    ('\u{3151}', 9320, 3),
+    ('\u{3152}', 9323, 3),
+    ('\u{3153}', 9326, 3),
+    ('\u{3154}', 9329, 3),
+    ('\u{3155}', 9332, 3),
+    ('\u{3156}', 9335, 3),
+    ('\u{3157}', 9338, 3),
+    ('\u{3158}', 9341, 3),
+    ('\u{3159}', 9344, 3),
+    ('\u{315A}', 9347, 3),
+    ('\u{315B}', 9350, 3),
+    ('\u{315C}', 9353, 3),
+    ('\u{315D}', 9356, 3),
+    ('\u{315E}', 9359, 3),
+    ('\u{315F}', 9362, 3),
+    ('\u{3160}', 9365, 3),
+    ('\u{3161}', 9368, 3),
+    ('\u{3162}', 9371, 3),
+    ('\u{3163}', 9374, 3),
+    ('\u{3164}', 9377, 3),
+    ('\u{3165}', 9380, 3),
+    ('\u{3166}', 9383, 3),
+    ('\u{3167}', 9386, 3),
+    ('\u{3168}', 9389, 3),
+    ('\u{3169}', 9392, 3),
+    ('\u{316A}', 9395, 3),
+    ('\u{316B}', 9398, 3),
+    ('\u{316C}', 9401, 3),
+    ('\u{316D}', 9404, 3),
+    ('\u{316E}', 9407, 3),
+    ('\u{316F}', 9410, 3),
+    ('\u{3170}', 9413, 3),
+    ('\u{3171}', 9416, 3),
+    ('\u{3172}', 9419, 3),
+    ('\u{3173}', 9422, 3),
+    ('\u{3174}', 9425, 3),
+    ('\u{3175}', 9428, 3),
+    ('\u{3176}', 9431, 3),
+    ('\u{3177}', 9434, 3),
+    ('\u{3178}', 9437, 3),
+    ('\u{3179}', 9440, 3),
+    ('\u{317A}', 9443, 3),
+    ('\u{317B}', 9446, 3),
+    ('\u{317C}', 9449, 3),
+    ('\u{317D}', 9452, 3),
+    ('\u{317E}', 9455, 3),
+    ('\u{317F}', 9458, 3),
+    ('\u{3180}', 9461, 3),
+    ('\u{3181}', 9464, 3),
+    ('\u{3182}', 9467, 3),
+    ('\u{3183}', 9470, 3),
+    ('\u{3184}', 9473, 3),
+    ('\u{3185}', 9476, 3),
+    ('\u{3186}', 9479, 3),
+    ('\u{3187}', 9482, 3),
+    ('\u{3188}', 9485, 3),
+    ('\u{3189}', 9488, 3),
+    ('\u{318A}', 9491, 3),
+    ('\u{318B}', 9494, 3),
+    ('\u{318C}', 9497, 3),
+    ('\u{318D}', 9500, 3),
+    ('\u{318E}', 9503, 3),
+    ('\u{3192}', 8625, 3),
+    ('\u{3193}', 8643, 3),
+    ('\u{3194}', 9506, 3),
+    ('\u{3195}', 9509, 3),
+    ('\u{3196}', 9512, 3),
+    ('\u{3197}', 9515, 3),
+    ('\u{3198}', 9518, 3),
+    ('\u{3199}', 9521, 3),
+    ('\u{319A}', 8637, 3),
+    ('\u{319B}', 9524, 3),
+    ('\u{319C}', 9527, 3),
+    ('\u{319D}', 9530, 3),
+    ('\u{319E}', 9533, 3),
+    ('\u{319F}', 8649, 3),
+    ('\u{3200}', 9536, 5),
+    ('\u{3201}', 9541, 5),
+    ('\u{3202}', 9546, 5),
+    ('\u{3203}', 9551, 5),
+    ('\u{3204}', 9556, 5),
+    ('\u{3205}', 9561, 5),
+    ('\u{3206}', 9566, 5),
+    ('\u{3207}', 9571, 5),
+    ('\u{3208}', 9576, 5),
+    ('\u{3209}', 9581, 5),
+    ('\u{320A}', 9586, 5),
+    ('\u{320B}', 9591, 5),
+    ('\u{320C}', 9596, 5),
+    ('\u{320D}', 9601, 5),
+    ('\u{320E}', 9606, 8),
+    ('\u{320F}', 9614, 8),
+    ('\u{3210}', 9622, 8),
+    ('\u{3211}', 9630, 8),
+    ('\u{3212}', 9638, 8),
+    ('\u{3213}', 9646, 8),
+    ('\u{3214}', 9654, 8),
+    ('\u{3215}', 9662, 8),
+    ('\u{3216}', 9670, 8),
+    ('\u{3217}', 9678, 8),
+    ('\u{3218}', 9686, 8),
+    ('\u{3219}', 9694, 8),
+    ('\u{321A}', 9702, 8),
+    ('\u{321B}', 9710, 8),
+    ('\u{321C}', 9718, 8),
+    ('\u{321D}', 9726, 17),
+    ('\u{321E}', 9743, 14),
+    ('\u{3220}', 9757, 5),
+    ('\u{3221}', 9762, 5),
+    ('\u{3222}', 9767, 5),
+    ('\u{3223}', 9772, 5),
+    ('\u{3224}', 9777, 5),
+    ('\u{3225}', 9782, 5),
+    ('\u{3226}', 9787, 5),
+    ('\u{3227}', 9792, 5),
+    ('\u{3228}', 9797, 5),
+    ('\u{3229}', 9802, 5),
+    ('\u{322A}', 9807, 5),
+    ('\u{322B}', 9812, 5),
+    ('\u{322C}', 9817, 5),
+    ('\u{322D}', 9822, 5),
+    ('\u{322E}', 9827, 5),
+    ('\u{322F}', 9832, 5),
+    ('\u{3230}', 9837, 5),
+    ('\u{3231}', 9842, 5),
+    ('\u{3232}', 9847, 5),
+    ('\u{3233}', 9852, 5),
+    ('\u{3234}', 9857, 5),
+    ('\u{3235}', 9862, 5),
+    ('\u{3236}', 9867, 5),
+    ('\u{3237}', 9872, 5),
+    ('\u{3238}', 9877, 5),
+    ('\u{3239}', 9882, 5),
+    ('\u{323A}', 9887, 5),
+    ('\u{323B}', 9892, 5),
+    ('\u{323C}', 9897, 5),
+    ('\u{323D}', 9902, 5),
+    ('\u{323E}', 9907, 5),
+    ('\u{323F}', 9912, 5),
+    ('\u{3240}', 9917, 5),
+    ('\u{3241}', 9922, 5),
+    ('\u{3242}', 9927, 5),
+    ('\u{3243}', 9932, 5),
+    ('\u{3244}', 9937, 3),
+    ('\u{3245}', 9940, 3),
+    ('\u{3246}', 8805, 3),
+    ('\u{3247}', 9943, 3),
+    ('\u{3250}', 9946, 3),
+    ('\u{3251}', 9949, 2),
+    ('\u{3252}', 9951, 2),
+    ('\u{3253}', 9953, 2),
+    ('\u{3254}', 9955, 2),
+    ('\u{3255}', 9957, 2),
+    ('\u{3256}', 9959, 2),
+    ('\u{3257}', 9961, 2),
+    ('\u{3258}', 9963, 2),
+    ('\u{3259}', 9965, 2),
+    ('\u{325A}', 9967, 2),
+    ('\u{325B}', 9969, 2),
+    ('\u{325C}', 9971, 2),
+    ('\u{325D}', 9973, 2),
+    ('\u{325E}', 9975, 2),
+    ('\u{325F}', 9977, 2),
+    ('\u{3260}', 9224, 3),
+    ('\u{3261}', 9233, 3),
+    ('\u{3262}', 9242, 3),
+    ('\u{3263}', 9248, 3),
+    ('\u{3264}', 9272, 3),
+    ('\u{3265}', 9275, 3),
+    ('\u{3266}', 9284, 3),
+    ('\u{3267}', 9290, 3),
+    ('\u{3268}', 9293, 3),
+    ('\u{3269}', 9299, 3),
+    ('\u{326A}', 9302, 3),
+    ('\u{326B}', 9305, 3),
+    ('\u{326C}', 9308, 3),
+    ('\u{326D}', 9311, 3),
+    ('\u{326E}', 9979, 6),
+    ('\u{326F}', 9985, 6),
+    ('\u{3270}', 9991, 6),
+    ('\u{3271}', 9997, 6),
+    ('\u{3272}', 10003, 6),
+    ('\u{3273}', 10009, 6),
+    ('\u{3274}', 10015, 6),
+    ('\u{3275}', 10021, 6),
+    ('\u{3276}', 10027, 6),
+    ('\u{3277}', 10033, 6),
+    ('\u{3278}', 10039, 6),
+    ('\u{3279}', 10045, 6),
+    ('\u{327A}', 10051, 6),
+    ('\u{327B}', 10057, 6),
+    ('\u{327C}', 10063, 15),
+    ('\u{327D}', 10078, 12),
+    ('\u{327E}', 10090, 6),
+    ('\u{3280}', 8625, 3),
+    ('\u{3281}', 8643, 3),
+    ('\u{3282}', 9506, 3),
+    ('\u{3283}', 9509, 3),
+    ('\u{3284}', 10096, 3),
+    ('\u{3285}', 4904, 3),
+    ('\u{3286}', 10099, 3),
+    ('\u{3287}', 8658, 3),
+    ('\u{3288}', 10102, 3),
+    ('\u{3289}', 8688, 3),
+    ('\u{328A}', 8826, 3),
+    ('\u{328B}', 8859, 3),
+    ('\u{328C}', 8856, 3),
+    ('\u{328D}', 8829, 3),
+    ('\u{328E}', 4319, 3),
+    ('\u{328F}', 8712, 3),
+    ('\u{3290}', 8820, 3),
+    ('\u{3291}', 10105, 3),
+    ('\u{3292}', 10108, 3),
+    ('\u{3293}', 5234, 3),
+    ('\u{3294}', 10111, 3),
+    ('\u{3295}', 10114, 3),
+    ('\u{3296}', 10117, 3),
+    ('\u{3297}', 5249, 3),
+    ('\u{3298}', 10120, 3),
+    ('\u{3299}', 10123, 3),
+    ('\u{329A}', 10126, 3),
+    ('\u{329B}', 4673, 3),
+    ('\u{329C}', 10129, 3),
+    ('\u{329D}', 10132, 3),
+    ('\u{329E}', 10135, 3),
+    ('\u{329F}', 10138, 3),
+    ('\u{32A0}', 10141, 3),
+    ('\u{32A1}', 10144, 3),
+    ('\u{32A2}', 10147, 3),
+    ('\u{32A3}', 10150, 3),
+    ('\u{32A4}', 9512, 3),
+    ('\u{32A5}', 9515, 3),
+    ('\u{32A6}', 9518, 3),
+    ('\u{32A7}', 10153, 3),
+    ('\u{32A8}', 10156, 3),
+    ('\u{32A9}', 10159, 3),
+    ('\u{32AA}', 10162, 3),
+    ('\u{32AB}', 10165, 3),
+    ('\u{32AC}', 10168, 3),
+    ('\u{32AD}', 10171, 3),
+    ('\u{32AE}', 10174, 3),
+    ('\u{32AF}', 10177, 3),
+    ('\u{32B0}', 10180, 3),
+    ('\u{32B1}', 10183, 2),
+    ('\u{32B2}', 10185, 2),
+    ('\u{32B3}', 10187, 2),
+    ('\u{32B4}', 10189, 2),
+    ('\u{32B5}', 10191, 2),
+    ('\u{32B6}', 10193, 2),
+    ('\u{32B7}', 10195, 2),
+    ('\u{32B8}', 10197, 2),
+    ('\u{32B9}', 10199, 2),
+    ('\u{32BA}', 10201, 2),
+    ('\u{32BB}', 10203, 2),
+    ('\u{32BC}', 10205, 2),
+    ('\u{32BD}', 10207, 2),
+    ('\u{32BE}', 10209, 2),
+    ('\u{32BF}', 10211, 2),
+    ('\u{32C0}', 10213, 4),
+    ('\u{32C1}', 10217, 4),
+    ('\u{32C2}', 10221, 4),
+    ('\u{32C3}', 10225, 4),
+    ('\u{32C4}', 10229, 4),
+    ('\u{32C5}', 10233, 4),
+    ('\u{32C6}', 10237, 4),
+    ('\u{32C7}', 10241, 4),
+    ('\u{32C8}', 10245, 4),
+    ('\u{32C9}', 10249, 5),
+    ('\u{32CA}', 10254, 5),
+    ('\u{32CB}', 10259, 5),
+    ('\u{32CC}', 10264, 2),
+    ('\u{32CD}', 10266, 3),
+    ('\u{32CE}', 10269, 2),
+    ('\u{32CF}', 10271, 3),
+    ('\u{32D0}', 10274, 3),
+    ('\u{32D1}', 10277, 3),
+    ('\u{32D2}', 10280, 3),
+    ('\u{32D3}', 10283, 3),
+    ('\u{32D4}', 10286, 3),
+    ('\u{32D5}', 10289, 3),
+    ('\u{32D6}', 10292, 3),
+    ('\u{32D7}', 10295, 3),
+    ('\u{32D8}', 10298, 3),
+    ('\u{32D9}', 10301, 3),
+    ('\u{32DA}', 10304, 3),
+    ('\u{32DB}', 10307, 3),
+    ('\u{32DC}', 10310, 3),
+    ('\u{32DD}', 10313, 3),
+    ('\u{32DE}', 10316, 3),
+    ('\u{32DF}', 10319, 3),
+    ('\u{32E0}', 10322, 3),
+    ('\u{32E1}', 10325, 3),
+    ('\u{32E2}', 10328, 3),
+    ('\u{32E3}', 10331, 3),
+    ('\u{32E4}', 10334, 3),
+    ('\u{32E5}', 10337, 3),
+    ('\u{32E6}', 10340, 3),
+    ('\u{32E7}', 10343, 3),
+    ('\u{32E8}', 10346, 3),
+    ('\u{32E9}', 10349, 3),
+    ('\u{32EA}', 10352, 3),
+    ('\u{32EB}', 10355, 3),
+    ('\u{32EC}', 10358, 3),
+    ('\u{32ED}', 10361, 3),
+    ('\u{32EE}', 10364, 3),
+    ('\u{32EF}', 10367, 3),
+    ('\u{32F0}', 10370, 3),
+    ('\u{32F1}', 10373, 3),
+    ('\u{32F2}', 10376, 3),
+    ('\u{32F3}', 10379, 3),
+    ('\u{32F4}', 10382, 3),
+    ('\u{32F5}', 10385, 3),
+    ('\u{32F6}', 10388, 3),
+    ('\u{32F7}', 10391, 3),
+    ('\u{32F8}', 10394, 3),
+    ('\u{32F9}', 10397, 3),
+    ('\u{32FA}', 10400, 3),
+    ('\u{32FB}', 10403, 3),
+    ('\u{32FC}', 10406, 3),
+    ('\u{32FD}', 10409, 3),
+    ('\u{32FE}', 10412, 3),
+    ('\u{32FF}', 10415, 6),
+    ('\u{3300}', 10421, 15),
+    ('\u{3301}', 10436, 12),
+    ('\u{3302}', 10448, 15),
+    ('\u{3303}', 10463, 9),
+    ('\u{3304}', 10472, 15),
+    ('\u{3305}', 10487, 9),
+    ('\u{3306}', 10496, 9),
+    ('\u{3307}', 10505, 18),
+    ('\u{3308}', 10523, 12),
+    ('\u{3309}', 10535, 9),
+    ('\u{330A}', 10544, 9),
+    ('\u{330B}', 10553, 9),
+    ('\u{330C}', 10562, 12),
+    ('\u{330D}', 10574, 12),
+    ('\u{330E}', 10586, 12),
+    ('\u{330F}', 10598, 12),
+    ('\u{3310}', 10610, 12),
+    ('\u{3311}', 10622, 12),
+    ('\u{3312}', 10634, 12),
+    ('\u{3313}', 10646, 18),
+    ('\u{3314}', 10664, 6),
+    ('\u{3315}', 10670, 18),
+    ('\u{3316}', 10688, 18),
+    ('\u{3317}', 10706, 15),
+    ('\u{3318}', 10721, 12),
+    ('\u{3319}', 10733, 18),
+    ('\u{331A}', 10751, 18),
+    ('\u{331B}', 10769, 12),
+    ('\u{331C}', 10781, 9),
+    ('\u{331D}', 10790, 9),
+    ('\u{331E}', 10799, 12),
+    ('\u{331F}', 10811, 12),
+    ('\u{3320}', 10823, 15),
+    ('\u{3321}', 10838, 15),
+    ('\u{3322}', 10853, 9),
+    ('\u{3323}', 10862, 9),
+    ('\u{3324}', 10871, 12),
+    ('\u{3325}', 10883, 9),
+    ('\u{3326}', 10892, 9),
+    ('\u{3327}', 10901, 6),
+    ('\u{3328}', 10907, 6),
+    ('\u{3329}', 10913, 9),
+    ('\u{332A}', 10922, 9),
+    ('\u{332B}', 10931, 18),
+    ('\u{332C}', 10949, 12),
+    ('\u{332D}', 10961, 15),
+    ('\u{332E}', 10976, 18),
+    ('\u{332F}', 10994, 12),
+    ('\u{3330}', 11006, 9),
+    ('\u{3331}', 11015, 9),
+    ('\u{3332}', 11024, 18),
+    ('\u{3333}', 11042, 12),
+    ('\u{3334}', 11054, 18),
+    ('\u{3335}', 11072, 9),
+    ('\u{3336}', 11081, 15),
+    ('\u{3337}', 11096, 9),
+    ('\u{3338}', 11105, 12),
+    ('\u{3339}', 11117, 9),
+    ('\u{333A}', 11126, 12),
+    ('\u{333B}', 11138, 15),
+    ('\u{333C}', 11153, 12),
+    ('\u{333D}', 11165, 15),
+    ('\u{333E}', 11180, 12),
+    ('\u{333F}', 11192, 6),
+    ('\u{3340}', 11198, 15),
+    ('\u{3341}', 11213, 9),
+    ('\u{3342}', 11222, 9),
+    ('\u{3343}', 11231, 12),
+    ('\u{3344}', 11243, 9),
+    ('\u{3345}', 11252, 9),
+    ('\u{3346}', 11261, 9),
+    ('\u{3347}', 11270, 15),
+    ('\u{3348}', 11285, 12),
+    ('\u{3349}', 11297, 6),
+    ('\u{334A}', 11303, 18),
+    ('\u{334B}', 11321, 9),
+    ('\u{334C}', 11330, 15),
+    ('\u{334D}', 11345, 12),
+    ('\u{334E}', 11357, 12),
+    ('\u{334F}', 11369, 9),
+    ('\u{3350}', 11378, 9),
+    ('\u{3351}', 11387, 12),
+    ('\u{3352}', 11399, 6),
+    ('\u{3353}', 11405, 12),
+    ('\u{3354}', 11417, 15),
+    ('\u{3355}', 11432, 6),
+    ('\u{3356}', 11438, 18),
+    ('\u{3357}', 11456, 9),
+    ('\u{3358}', 11465, 4),
+    ('\u{3359}', 11469, 4),
+    ('\u{335A}', 11473, 4),
+    ('\u{335B}', 11477, 4),
+    ('\u{335C}', 11481, 4),
+    ('\u{335D}', 11485, 4),
+    ('\u{335E}', 11489, 4),
+    ('\u{335F}', 11493, 4),
+    ('\u{3360}', 11497, 4),
+    ('\u{3361}', 11501, 4),
+    ('\u{3362}', 11505, 5),
+    ('\u{3363}', 11510, 5),
+    ('\u{3364}', 11515, 5),
+    ('\u{3365}', 11520, 5),
+    ('\u{3366}', 11525, 5),
+    ('\u{3367}', 11530, 5),
+    ('\u{3368}', 11535, 5),
+    ('\u{3369}', 11540, 5),
+    ('\u{336A}', 11545, 5),
+    ('\u{336B}', 11550, 5),
+    ('\u{336C}', 11555, 5),
+    ('\u{336D}', 11560, 5),
+    ('\u{336E}', 11565, 5),
+    ('\u{336F}', 11570, 5),
+    ('\u{3370}', 11575, 5),
+    ('\u{3371}', 11580, 3),
+    ('\u{3372}', 11583, 2),
+    ('\u{3373}', 11585, 2),
+    ('\u{3374}', 11587, 3),
+    ('\u{3375}', 11590, 2),
+    ('\u{3376}', 11592, 2),
+    ('\u{3377}', 11594, 2),
+    ('\u{3378}', 11596, 3),
+    ('\u{3379}', 11599, 3),
+    ('\u{337A}', 11602, 2),
+    ('\u{337B}', 11604, 6),
+    ('\u{337C}', 11610, 6),
+    ('\u{337D}', 11616, 6),
+    ('\u{337E}', 11622, 6),
+    ('\u{337F}', 11628, 12),
+    ('\u{3380}', 11640, 2),
+    ('\u{3381}', 11642, 2),
+    ('\u{3382}', 11644, 3),
+    ('\u{3383}', 11647, 2),
+    ('\u{3384}', 11649, 2),
+    ('\u{3385}', 11651, 2),
+    ('\u{3386}', 11653, 2),
+    ('\u{3387}', 11655, 2),
+    ('\u{3388}', 11657, 3),
+    ('\u{3389}', 11660, 4),
+    ('\u{338A}', 11664, 2),
+    ('\u{338B}', 11666, 2),
+    ('\u{338C}', 11668, 3),
+    ('\u{338D}', 11671, 3),
+    ('\u{338E}', 11674, 2),
+    ('\u{338F}', 11676, 2),
+    ('\u{3390}', 11678, 2),
+    ('\u{3391}', 11680, 3),
+    ('\u{3392}', 11683, 3),
+    ('\u{3393}', 11686, 3),
+    ('\u{3394}', 11689, 3),
+    ('\u{3395}', 11692, 3),
+    ('\u{3396}', 11695, 2),
+    ('\u{3397}', 11697, 2),
+    ('\u{3398}', 11699, 2),
+    ('\u{3399}', 11701, 2),
+    ('\u{339A}', 11703, 2),
+    ('\u{339B}', 11705, 3),
+    ('\u{339C}', 11708, 2),
+    ('\u{339D}', 11710, 2),
+    ('\u{339E}', 11712, 2),
+    ('\u{339F}', 11714, 3),
+    ('\u{33A0}', 11717, 3),
+    ('\u{33A1}', 11720, 2),
+    ('\u{33A2}', 11722, 3),
+    ('\u{33A3}', 11725, 3),
+    ('\u{33A4}', 11728, 3),
+    ('\u{33A5}', 11731, 2),
+    ('\u{33A6}', 11733, 3),
+    ('\u{33A7}', 11736, 5),
+    ('\u{33A8}', 11741, 6),
+    ('\u{33A9}', 11747, 2),
+    ('\u{33AA}', 11749, 3),
+    ('\u{33AB}', 11752, 3),
+    ('\u{33AC}', 11755, 3),
+    ('\u{33AD}', 11758, 3),
+    ('\u{33AE}', 11761, 7),
+    ('\u{33AF}', 11768, 8),
+    ('\u{33B0}', 11776, 2),
+    ('\u{33B1}', 11778, 2),
+    ('\u{33B2}', 11780, 3),
+    ('\u{33B3}', 11783, 2),
+    ('\u{33B4}', 11785, 2),
+    ('\u{33B5}', 11787, 2),
+    ('\u{33B6}', 11789, 3),
+    ('\u{33B7}', 11792, 2),
+    ('\u{33B8}', 11794, 2),
+    ('\u{33B9}', 11796, 2),
+    ('\u{33BA}', 11798, 2),
+    ('\u{33BB}', 11800, 2),
+    ('\u{33BC}', 11802, 3),
+    ('\u{33BD}', 11805, 2),
+    ('\u{33BE}', 11807, 2),
+    ('\u{33BF}', 11809, 2),
+    ('\u{33C0}', 11811, 3),
+    ('\u{33C1}', 11814, 3),
+    ('\u{33C2}', 11817, 4),
+    ('\u{33C3}', 11821, 2),
+    ('\u{33C4}', 11823, 2),
+    ('\u{33C5}', 11825, 2),
+    ('\u{33C6}', 11827, 6),
+    ('\u{33C7}', 11833, 3),
+    ('\u{33C8}', 11836, 2),
+    ('\u{33C9}', 11838, 2),
+    ('\u{33CA}', 11840, 2),
+    ('\u{33CB}', 11842, 2),
+    ('\u{33CC}', 11844, 2),
+    ('\u{33CD}', 11846, 2),
+    ('\u{33CE}', 11848, 2),
+    ('\u{33CF}', 11850, 2),
+    ('\u{33D0}', 11852, 2),
+    ('\u{33D1}', 11854, 2),
+    ('\u{33D2}', 11856, 3),
+    ('\u{33D3}', 11859, 2),
+    ('\u{33D4}', 11861, 2),
+    ('\u{33D5}', 11863, 3),
+    ('\u{33D6}', 11866, 3),
+    ('\u{33D7}', 11869, 2),
+    ('\u{33D8}', 11871, 4),
+    ('\u{33D9}', 11875, 3),
+    ('\u{33DA}', 11878, 2),
+    ('\u{33DB}', 11880, 2),
+    ('\u{33DC}', 11882, 2),
+    ('\u{33DD}', 11884, 2),
+    ('\u{33DE}', 11886, 5),
+    ('\u{33DF}', 11891, 5),
+    ('\u{33E0}', 11896, 4),
+    ('\u{33E1}', 11900, 4),
+    ('\u{33E2}', 11904, 4),
+    ('\u{33E3}', 11908, 4),
+    ('\u{33E4}', 11912, 4),
+    ('\u{33E5}', 11916, 4),
+    ('\u{33E6}', 11920, 4),
+    ('\u{33E7}', 11924, 4),
+    ('\u{33E8}', 11928, 4),
+    ('\u{33E9}', 11932, 5),
+    ('\u{33EA}', 11937, 5),
+    ('\u{33EB}', 11942, 5),
+    ('\u{33EC}', 11947, 5),
+    ('\u{33ED}', 11952, 5),
+    ('\u{33EE}', 11957, 5),
+    ('\u{33EF}', 11962, 5),
+    ('\u{33F0}', 11967, 5),
+    ('\u{33F1}', 11972, 5),
+    ('\u{33F2}', 11977, 5),
+    ('\u{33F3}', 11982, 5),
+    ('\u{33F4}', 11987, 5),
+    ('\u{33F5}', 11992, 5),
+    ('\u{33F6}', 11997, 5),
+    ('\u{33F7}', 12002, 5),
+    ('\u{33F8}', 12007, 5),
+    ('\u{33F9}', 12012, 5),
+    ('\u{33FA}', 12017, 5),
+    ('\u{33FB}', 12022, 5),
+    ('\u{33FC}', 12027, 5),
+    ('\u{33FD}', 12032, 5),
+    ('\u{33FE}', 12037, 5),
+    ('\u{33FF}', 12042, 3),
+    ('\u{A69C}', 12045, 2),
+    ('\u{A69D}', 12047, 2),
+    ('\u{A770}', 12049, 3),
+    ('\u{A7F2}', 8161, 1),
+    ('\u{A7F3}', 8189, 1),
+    ('\u{A7F4}', 8180, 1),
+    ('\u{A7F8}', 12052, 2),
+    ('\u{A7F9}', 12054, 2),
+    ('\u{AB5C}', 12056, 3),
+    ('\u{AB5D}', 12059, 3),
+    ('\u{AB5E}', 12062, 2),
+    ('\u{AB5F}', 12064, 3),
+    ('\u{AB69}', 12067, 2),
+    ('\u{FB00}', 12069, 2),
+    ('\u{FB01}', 12071, 2),
+    ('\u{FB02}', 12073, 2),
+    ('\u{FB03}', 12075, 3),
+    ('\u{FB04}', 12078, 3),
+    ('\u{FB05}', 12081, 2),
+    ('\u{FB06}', 12081, 2),
+    ('\u{FB13}', 12083, 4),
+    ('\u{FB14}', 12087, 4),
+    ('\u{FB15}', 12091, 4),
+    ('\u{FB16}', 12095, 4),
+    ('\u{FB17}', 12099, 4),
+    ('\u{FB20}', 12103, 2),
+    ('\u{FB21}', 8190, 2),
+    ('\u{FB22}', 8196, 2),
+    ('\u{FB23}', 12105, 2),
+    ('\u{FB24}', 12107, 2),
+    ('\u{FB25}', 12109, 2),
+    ('\u{FB26}', 12111, 2),
+    ('\u{FB27}', 12113, 2),
+    ('\u{FB28}', 12115, 2),
+    ('\u{FB29}', 8145, 1),
+    ('\u{FB4F}', 12117, 4),
+    ('\u{FB50}', 12121, 2),
+    ('\u{FB51}', 12121, 2),
+    ('\u{FB52}', 12123, 2),
+    ('\u{FB53}', 12123, 2),
+    ('\u{FB54}', 12123, 2),
+    ('\u{FB55}', 12123, 2),
+    ('\u{FB56}', 12125, 2),
+    ('\u{FB57}', 12125, 2),
+    ('\u{FB58}', 12125, 2),
+    ('\u{FB59}', 12125, 2),
+    ('\u{FB5A}', 12127, 2),
+    ('\u{FB5B}', 12127, 2),
+    ('\u{FB5C}', 12127, 2),
+    ('\u{FB5D}', 12127, 2),
+    ('\u{FB5E}', 12129, 2),
+    ('\u{FB5F}', 12129, 2),
+    ('\u{FB60}', 12129, 2),
+    ('\u{FB61}', 12129, 2),
+    ('\u{FB62}', 12131, 2),
+    ('\u{FB63}', 12131, 2),
+    ('\u{FB64}', 12131, 2),
+    ('\u{FB65}', 12131, 2),
+    ('\u{FB66}', 12133, 2),
+    ('\u{FB67}', 12133, 2),
+    ('\u{FB68}', 12133, 2),
+    ('\u{FB69}', 12133, 2),
+    ('\u{FB6A}', 12135, 2),
+    ('\u{FB6B}', 12135, 2),
+    ('\u{FB6C}', 12135, 2),
+    ('\u{FB6D}', 12135, 2),
+    ('\u{FB6E}', 12137, 2),
+    ('\u{FB6F}', 12137, 2),
+    ('\u{FB70}', 12137, 2),
+    ('\u{FB71}', 12137, 2),
+    ('\u{FB72}', 12139, 2),
+    ('\u{FB73}', 12139, 2),
+    ('\u{FB74}', 12139, 2),
+    ('\u{FB75}', 12139, 2),
+    ('\u{FB76}', 12141, 2),
+    ('\u{FB77}', 12141, 2),
+    ('\u{FB78}', 12141, 2),
+    ('\u{FB79}', 12141, 2),
+    ('\u{FB7A}', 12143, 2),
+    ('\u{FB7B}', 12143, 2),
+    ('\u{FB7C}', 12143, 2),
+    ('\u{FB7D}', 12143, 2),
+    ('\u{FB7E}', 12145, 2),
+    ('\u{FB7F}', 12145, 2),
+    ('\u{FB80}', 12145, 2),
+    ('\u{FB81}', 12145, 2),
+    ('\u{FB82}', 12147, 2),
+    ('\u{FB83}', 12147, 2),
+    ('\u{FB84}', 12149, 2),
+    ('\u{FB85}', 12149, 2),
+    ('\u{FB86}', 12151, 2),
+    ('\u{FB87}', 12151, 2),
+    ('\u{FB88}', 12153, 2),
+    ('\u{FB89}', 12153, 2),
+    ('\u{FB8A}', 12155, 2),
+    ('\u{FB8B}', 12155, 2),
+    ('\u{FB8C}', 12157, 2),
+    ('\u{FB8D}', 12157, 2),
+    ('\u{FB8E}', 12159, 2),
+    ('\u{FB8F}', 12159, 2),
+    ('\u{FB90}', 12159, 2),
+    ('\u{FB91}', 12159, 2),
+    ('\u{FB92}', 12161, 2),
+    ('\u{FB93}', 12161, 2),
+    ('\u{FB94}', 12161, 2),
+    ('\u{FB95}', 12161, 2),
+    ('\u{FB96}', 12163, 2),
+    ('\u{FB97}', 12163, 2),
+    ('\u{FB98}', 12163, 2),
+    ('\u{FB99}', 12163, 2),
+    ('\u{FB9A}', 12165, 2),
+    ('\u{FB9B}', 12165, 2),
+    ('\u{FB9C}', 12165, 2),
+    ('\u{FB9D}', 12165, 2),
+    ('\u{FB9E}', 12167, 2),
+    ('\u{FB9F}', 12167, 2),
+    ('\u{FBA0}', 12169, 2),
+    ('\u{FBA1}', 12169, 2),
+    ('\u{FBA2}', 12169, 2),
+    ('\u{FBA3}', 12169, 2),
+    ('\u{FBA4}', 1147, 4),
+    ('\u{FBA5}', 1147, 4),
+    ('\u{FBA6}', 12171, 2),
+    ('\u{FBA7}', 12171, 2),
+    ('\u{FBA8}', 12171, 2),
+    ('\u{FBA9}', 12171, 2),
+    ('\u{FBAA}', 12173, 2),
+    ('\u{FBAB}', 12173, 2),
+    ('\u{FBAC}', 12173, 2),
+    ('\u{FBAD}', 12173, 2),
+    ('\u{FBAE}', 12175, 2),
+    ('\u{FBAF}', 12175, 2),
+    ('\u{FBB0}', 1155, 4),
+    ('\u{FBB1}', 1155, 4),
+    ('\u{FBD3}', 12177, 2),
+    ('\u{FBD4}', 12177, 2),
+    ('\u{FBD5}', 12177, 2),
+    ('\u{FBD6}', 12177, 2),
+    ('\u{FBD7}', 12179, 2),
+    ('\u{FBD8}', 12179, 2),
+    ('\u{FBD9}', 12181, 2),
+    ('\u{FBDA}', 12181, 2),
+    ('\u{FBDB}', 12183, 2),
+    ('\u{FBDC}', 12183, 2),
+    ('\u{FBDD}', 7822, 4),
+    ('\u{FBDE}', 12185, 2),
+    ('\u{FBDF}', 12185, 2),
+    ('\u{FBE0}', 12187, 2),
+    ('\u{FBE1}', 12187, 2),
+    ('\u{FBE2}', 12189, 2),
+    ('\u{FBE3}', 12189, 2),
+    ('\u{FBE4}', 12191, 2),
+    ('\u{FBE5}', 12191, 2),
+    ('\u{FBE6}', 12191, 2),
+    ('\u{FBE7}', 12191, 2),
+    ('\u{FBE8}', 12193, 2),
+    ('\u{FBE9}', 12193, 2),
+    ('\u{FBEA}', 12195, 6),
+    ('\u{FBEB}', 12195, 6),
+    ('\u{FBEC}', 12201, 6),
+    ('\u{FBED}', 12201, 6),
+    ('\u{FBEE}', 12207, 6),
+    ('\u{FBEF}', 12207, 6),
+    ('\u{FBF0}', 12213, 6),
+    ('\u{FBF1}', 12213, 6),
+    ('\u{FBF2}', 12219, 6),
+    ('\u{FBF3}', 12219, 6),
+    ('\u{FBF4}', 12225, 6),
+    ('\u{FBF5}', 12225, 6),
+    ('\u{FBF6}', 12231, 6),
+    ('\u{FBF7}', 12231, 6),
+    ('\u{FBF8}', 12231, 6),
+    ('\u{FBF9}', 12237, 6),
+    ('\u{FBFA}', 12237, 6),
+    ('\u{FBFB}', 12237, 6),
+    ('\u{FBFC}', 12243, 2),
+    ('\u{FBFD}', 12243, 2),
+    ('\u{FBFE}', 12243, 2),
+    ('\u{FBFF}', 12243, 2),
+    ('\u{FC00}', 12245, 6),
+    ('\u{FC01}', 12251, 6),
+    ('\u{FC02}', 12257, 6),
+    ('\u{FC03}', 12237, 6),
+    ('\u{FC04}', 12263, 6),
+    ('\u{FC05}', 12269, 4),
+    ('\u{FC06}', 12273, 4),
+    ('\u{FC07}', 12277, 4),
+    ('\u{FC08}', 12281, 4),
+    ('\u{FC09}', 12285, 4),
+    ('\u{FC0A}', 12289, 4),
+    ('\u{FC0B}', 12293, 4),
+    ('\u{FC0C}', 12297, 4),
+    ('\u{FC0D}', 12301, 4),
+    ('\u{FC0E}', 12305, 4),
+    ('\u{FC0F}', 12309, 4),
+    ('\u{FC10}', 12313, 4),
+    ('\u{FC11}', 12317, 4),
+    ('\u{FC12}', 12321, 4),
+    ('\u{FC13}', 12325, 4),
+    ('\u{FC14}', 12329, 4),
+    ('\u{FC15}', 12333, 4),
+    ('\u{FC16}', 12337, 4),
+    ('\u{FC17}', 12341, 4),
+    ('\u{FC18}', 12345, 4),
+    ('\u{FC19}', 12349, 4),
+    ('\u{FC1A}', 12353, 4),
+    ('\u{FC1B}', 12357, 4),
+    ('\u{FC1C}', 12361, 4),
+    ('\u{FC1D}', 12365, 4),
+    ('\u{FC1E}', 12369, 4),
+    ('\u{FC1F}', 12373, 4),
+    ('\u{FC20}', 12377, 4),
+    ('\u{FC21}', 12381, 4),
+    ('\u{FC22}', 12385, 4),
+    ('\u{FC23}', 12389, 4),
+    ('\u{FC24}', 12393, 4),
+    ('\u{FC25}', 12397, 4),
+    ('\u{FC26}', 12401, 4),
+    ('\u{FC27}', 12405, 4),
+    ('\u{FC28}', 12409, 4),
+    ('\u{FC29}', 12413, 4),
+    ('\u{FC2A}', 12417, 4),
+    ('\u{FC2B}', 12421, 4),
+    ('\u{FC2C}', 12425, 4),
+    ('\u{FC2D}', 12429, 4),
+    ('\u{FC2E}', 12433, 4),
+    ('\u{FC2F}', 12437, 4),
+    ('\u{FC30}', 12441, 4),
+    ('\u{FC31}', 12445, 4),
+    ('\u{FC32}', 12449, 4),
+    ('\u{FC33}', 12453, 4),
+    ('\u{FC34}', 12457, 4),
+    ('\u{FC35}', 12461, 4),
+    ('\u{FC36}', 12465, 4),
+    ('\u{FC37}', 12469, 4),
+    ('\u{FC38}', 12473, 4),
+    ('\u{FC39}', 12477, 4),
+    ('\u{FC3A}', 12481, 4),
+    ('\u{FC3B}', 12485, 4),
+    ('\u{FC3C}', 12489, 4),
+    ('\u{FC3D}', 12493, 4),
+    ('\u{FC3E}', 12497, 4),
+    ('\u{FC3F}', 12501, 4),
+    ('\u{FC40}', 12505, 4),
+    ('\u{FC41}', 12509, 4),
+    ('\u{FC42}', 12513, 4),
+    ('\u{FC43}', 12517, 4),
+    ('\u{FC44}', 12521, 4),
+    ('\u{FC45}', 12525, 4),
+    ('\u{FC46}', 12529, 4),
+    ('\u{FC47}', 12533, 4),
+    ('\u{FC48}', 12537, 4),
+    ('\u{FC49}', 12541, 4),
+    ('\u{FC4A}', 12545, 4),
+    ('\u{FC4B}', 12549, 4),
+    ('\u{FC4C}', 12553, 4),
+    ('\u{FC4D}', 12557, 4),
+    ('\u{FC4E}', 12561, 4),
+    ('\u{FC4F}', 12565, 4),
+    ('\u{FC50}', 12569, 4),
+    ('\u{FC51}', 12573, 4),
+    ('\u{FC52}', 12577, 4),
+    ('\u{FC53}', 12581, 4),
+    ('\u{FC54}', 12585, 4),
+    ('\u{FC55}', 12589, 4),
+    ('\u{FC56}', 12593, 4),
+    ('\u{FC57}', 12597, 4),
+    ('\u{FC58}', 12601, 4),
+    ('\u{FC59}', 12605, 4),
+    ('\u{FC5A}', 12609, 4),
+    ('\u{FC5B}', 12613, 4),
+    ('\u{FC5C}', 12617, 4),
+    ('\u{FC5D}', 12621, 4),
+    ('\u{FC5E}', 12625, 5),
+    ('\u{FC5F}', 12630, 5),
+    ('\u{FC60}', 12635, 5),
+    ('\u{FC61}', 12640, 5),
+    ('\u{FC62}', 12645, 5),
+    ('\u{FC63}', 12650, 5),
+    ('\u{FC64}', 12655, 6),
+    ('\u{FC65}', 12661, 6),
+    ('\u{FC66}', 12257, 6),
+    ('\u{FC67}', 12667, 6),
+    ('\u{FC68}', 12237, 6),
+    ('\u{FC69}', 12263, 6),
+    ('\u{FC6A}', 12673, 4),
+    ('\u{FC6B}', 12677, 4),
+    ('\u{FC6C}', 12281, 4),
+    ('\u{FC6D}', 12681, 4),
+    ('\u{FC6E}', 12285, 4),
+    ('\u{FC6F}', 12289, 4),
+    ('\u{FC70}', 12685, 4),
+    ('\u{FC71}', 12689, 4),
+    ('\u{FC72}', 12305, 4),
+    ('\u{FC73}', 12693, 4),
+    ('\u{FC74}', 12309, 4),
+    ('\u{FC75}', 12313, 4),
+    ('\u{FC76}', 12697, 4),
+    ('\u{FC77}', 12701, 4),
+    ('\u{FC78}', 12321, 4),
+    ('\u{FC79}', 12705, 4),
+    ('\u{FC7A}', 12325, 4),
+    ('\u{FC7B}', 12329, 4),
+    ('\u{FC7C}', 12445, 4),
+    ('\u{FC7D}', 12449, 4),
+    ('\u{FC7E}', 12461, 4),
+    ('\u{FC7F}', 12465, 4),
+    ('\u{FC80}', 12469, 4),
+    ('\u{FC81}', 12485, 4),
+    ('\u{FC82}', 12489, 4),
+    ('\u{FC83}', 12493, 4),
+    ('\u{FC84}', 12497, 4),
+    ('\u{FC85}', 12513, 4),
+    ('\u{FC86}', 12517, 4),
+    ('\u{FC87}', 12521, 4),
+    ('\u{FC88}', 12709, 4),
+    ('\u{FC89}', 12537, 4),
+    ('\u{FC8A}', 12713, 4),
+    ('\u{FC8B}', 12717, 4),
+    ('\u{FC8C}', 12561, 4),
+    ('\u{FC8D}', 12721, 4),
+    ('\u{FC8E}', 12565, 4),
+    ('\u{FC8F}', 12569, 4),
+    ('\u{FC90}', 12621, 4),
+    ('\u{FC91}', 12725, 4),
+    ('\u{FC92}', 12729, 4),
+    ('\u{FC93}', 12601, 4),
+    ('\u{FC94}', 12733, 4),
+    ('\u{FC95}', 12605, 4),
+    ('\u{FC96}', 12609, 4),
+    ('\u{FC97}', 12245, 6),
+    ('\u{FC98}', 12251, 6),
+    ('\u{FC99}', 12737, 6),
+    ('\u{FC9A}', 12257, 6),
+    ('\u{FC9B}', 12743, 6),
+    ('\u{FC9C}', 12269, 4),
+    ('\u{FC9D}', 12273, 4),
+    ('\u{FC9E}', 12277, 4),
+    ('\u{FC9F}', 12281, 4),
+    ('\u{FCA0}', 12749, 4),
+    ('\u{FCA1}', 12293, 4),
+    ('\u{FCA2}', 12297, 4),
+    ('\u{FCA3}', 12301, 4),
+    ('\u{FCA4}', 12305, 4),
+    ('\u{FCA5}', 12753, 4),
+    ('\u{FCA6}', 12321, 4),
+    ('\u{FCA7}', 12333, 4),
+    ('\u{FCA8}', 12337, 4),
+    ('\u{FCA9}', 12341, 4),
+    ('\u{FCAA}', 12345, 4),
+    ('\u{FCAB}', 12349, 4),
+    ('\u{FCAC}', 12357, 4),
+    ('\u{FCAD}', 12361, 4),
+    ('\u{FCAE}', 12365, 4),
+    ('\u{FCAF}', 12369, 4),
+    ('\u{FCB0}', 12373, 4),
+    ('\u{FCB1}', 12377, 4),
+    ('\u{FCB2}', 12757, 4),
+    ('\u{FCB3}', 12381, 4),
+    ('\u{FCB4}', 12385, 4),
+    ('\u{FCB5}', 12389, 4),
+    ('\u{FCB6}', 12393, 4),
+    ('\u{FCB7}', 12397, 4),
+    ('\u{FCB8}', 12401, 4),
+    ('\u{FCB9}', 12409, 4),
+    ('\u{FCBA}', 12413, 4),
+    ('\u{FCBB}', 12417, 4),
+    ('\u{FCBC}', 12421, 4),
+    ('\u{FCBD}', 12425, 4),
+    ('\u{FCBE}', 12429, 4),
+    ('\u{FCBF}', 12433, 4),
+    ('\u{FCC0}', 12437, 4),
+    ('\u{FCC1}', 12441, 4),
+    ('\u{FCC2}', 12453, 4),
+    ('\u{FCC3}', 12457, 4),
+    ('\u{FCC4}', 12473, 4),
+    ('\u{FCC5}', 12477, 4),
+    ('\u{FCC6}', 12481, 4),
+    ('\u{FCC7}', 12485, 4),
+    ('\u{FCC8}', 12489, 4),
+    ('\u{FCC9}', 12501, 4),
+    ('\u{FCCA}', 12505, 4),
+    ('\u{FCCB}', 12509, 4),
+    ('\u{FCCC}', 12513, 4),
+    ('\u{FCCD}', 12761, 4),
+    ('\u{FCCE}', 12525, 4),
+    ('\u{FCCF}', 12529, 4),
+    ('\u{FCD0}', 12533, 4),
+    ('\u{FCD1}', 12537, 4),
+    ('\u{FCD2}', 12549, 4),
+    ('\u{FCD3}', 12553, 4),
+    ('\u{FCD4}', 12557, 4),
+    ('\u{FCD5}', 12561, 4),
+    ('\u{FCD6}', 12765, 4),
+    ('\u{FCD7}', 12573, 4),
+    ('\u{FCD8}', 12577, 4),
+    ('\u{FCD9}', 12769, 4),
+    ('\u{FCDA}', 12589, 4),
+    ('\u{FCDB}', 12593, 4),
+    ('\u{FCDC}', 12597, 4),
+    ('\u{FCDD}', 12601, 4),
+    ('\u{FCDE}', 12773, 4),
+    ('\u{FCDF}', 12257, 6),
+    ('\u{FCE0}', 12743, 6),
+    ('\u{FCE1}', 12281, 4),
+    ('\u{FCE2}', 12749, 4),
+    ('\u{FCE3}', 12305, 4),
+    ('\u{FCE4}', 12753, 4),
+    ('\u{FCE5}', 12321, 4),
+    ('\u{FCE6}', 12777, 4),
+    ('\u{FCE7}', 12373, 4),
+    ('\u{FCE8}', 12781, 4),
+    ('\u{FCE9}', 12785, 4),
+    ('\u{FCEA}', 12789, 4),
+    ('\u{FCEB}', 12485, 4),
+    ('\u{FCEC}', 12489, 4),
+    ('\u{FCED}', 12513, 4),
+    ('\u{FCEE}', 12561, 4),
+    ('\u{FCEF}', 12765, 4),
+    ('\u{FCF0}', 12601, 4),
+    ('\u{FCF1}', 12773, 4),
+    ('\u{FCF2}', 12793, 6),
+    ('\u{FCF3}', 12799, 6),
+    ('\u{FCF4}', 12805, 6),
+    ('\u{FCF5}', 12811, 4),
+    ('\u{FCF6}', 12815, 4),
+    ('\u{FCF7}', 12819, 4),
+    ('\u{FCF8}', 12823, 4),
+    ('\u{FCF9}', 12827, 4),
+    ('\u{FCFA}', 12831, 4),
+    ('\u{FCFB}', 12835, 4),
+    ('\u{FCFC}', 12839, 4),
+    ('\u{FCFD}', 12843, 4),
+    ('\u{FCFE}', 12847, 4),
+    ('\u{FCFF}', 12851, 4),
+    ('\u{FD00}', 12855, 4),
+    ('\u{FD01}', 12859, 4),
+    ('\u{FD02}', 12863, 4),
+    ('\u{FD03}', 12867, 4),
+    ('\u{FD04}', 12871, 4),
+    ('\u{FD05}', 12875, 4),
+    ('\u{FD06}', 12879, 4),
+    ('\u{FD07}', 12883, 4),
+    ('\u{FD08}', 12887, 4),
+    ('\u{FD09}', 12891, 4),
+    ('\u{FD0A}', 12895, 4),
+    ('\u{FD0B}', 12899, 4),
+    ('\u{FD0C}', 12785, 4),
+    ('\u{FD0D}', 12903, 4),
+    ('\u{FD0E}', 12907, 4),
+    ('\u{FD0F}', 12911, 4),
+    ('\u{FD10}', 12915, 4),
+    ('\u{FD11}', 12811, 4),
+    ('\u{FD12}', 12815, 4),
+    ('\u{FD13}', 12819, 4),
+    ('\u{FD14}', 12823, 4),
+    ('\u{FD15}', 12827, 4),
+    ('\u{FD16}', 12831, 4),
+    ('\u{FD17}', 12835, 4),
+    ('\u{FD18}', 12839, 4),
+    ('\u{FD19}', 12843, 4),
+    ('\u{FD1A}', 12847, 4),
+    ('\u{FD1B}', 12851, 4),
+    ('\u{FD1C}', 12855, 4),
+    ('\u{FD1D}', 12859, 4),
+    ('\u{FD1E}', 12863, 4),
+    ('\u{FD1F}', 12867, 4),
+    ('\u{FD20}', 12871, 4),
+    ('\u{FD21}', 12875, 4),
+    ('\u{FD22}', 12879, 4),
+    ('\u{FD23}', 12883, 4),
+    ('\u{FD24}', 12887, 4),
+    ('\u{FD25}', 12891, 4),
+    ('\u{FD26}', 12895, 4),
+    ('\u{FD27}', 12899, 4),
+    ('\u{FD28}', 12785, 4),
+    ('\u{FD29}', 12903, 4),
+    ('\u{FD2A}', 12907, 4),
+    ('\u{FD2B}', 12911, 4),
+    ('\u{FD2C}', 12915, 4),
+    ('\u{FD2D}', 12891, 4),
+    ('\u{FD2E}', 12895, 4),
+    ('\u{FD2F}', 12899, 4),
+    ('\u{FD30}', 12785, 4),
+    ('\u{FD31}', 12781, 4),
+    ('\u{FD32}', 12789, 4),
+    ('\u{FD33}', 12405, 4),
+    ('\u{FD34}', 12361, 4),
+    ('\u{FD35}', 12365, 4),
+    ('\u{FD36}', 12369, 4),
+    ('\u{FD37}', 12891, 4),
+    ('\u{FD38}', 12895, 4),
+    ('\u{FD39}', 12899, 4),
+    ('\u{FD3A}', 12405, 4),
+    ('\u{FD3B}', 12409, 4),
+    ('\u{FD3C}', 12919, 4),
+    ('\u{FD3D}', 12919, 4),
+    ('\u{FD50}', 12923, 6),
+    ('\u{FD51}', 12929, 6),
+    ('\u{FD52}', 12929, 6),
+    ('\u{FD53}', 12935, 6),
+    ('\u{FD54}', 12941, 6),
+    ('\u{FD55}', 12947, 6),
+    ('\u{FD56}', 12953, 6),
+    ('\u{FD57}', 12959, 6),
+    ('\u{FD58}', 12965, 6),
+    ('\u{FD59}', 12965, 6),
+    ('\u{FD5A}', 12971, 6),
+    ('\u{FD5B}', 12977, 6),
+    ('\u{FD5C}', 12983, 6),
+    ('\u{FD5D}', 12989, 6),
+    ('\u{FD5E}', 12995, 6),
+    ('\u{FD5F}', 13001, 6),
+    ('\u{FD60}', 13001, 6),
+    ('\u{FD61}', 13007, 6),
+    ('\u{FD62}', 13013, 6),
+    ('\u{FD63}', 13013, 6),
+    ('\u{FD64}', 13019, 6),
+    ('\u{FD65}', 13019, 6),
+    ('\u{FD66}', 13025, 6),
+    ('\u{FD67}', 13031, 6),
+    ('\u{FD68}', 13031, 6),
+    ('\u{FD69}', 13037, 6),
+    ('\u{FD6A}', 13043, 6),
+    ('\u{FD6B}', 13043, 6),
+    ('\u{FD6C}', 13049, 6),
+    ('\u{FD6D}', 13049, 6),
+    ('\u{FD6E}', 13055, 6),
+    ('\u{FD6F}', 13061, 6),
+    ('\u{FD70}', 13061, 6),
+    ('\u{FD71}', 13067, 6),
+    ('\u{FD72}', 13067, 6),
+    ('\u{FD73}', 13073, 6),
+    ('\u{FD74}', 13079, 6),
+    ('\u{FD75}', 13085, 6),
+    ('\u{FD76}', 13091, 6),
+    ('\u{FD77}', 13091, 6),
+    ('\u{FD78}', 13097, 6),
+    ('\u{FD79}', 13103, 6),
+    ('\u{FD7A}', 13109, 6),
+    ('\u{FD7B}', 13115, 6),
+    ('\u{FD7C}', 13121, 6),
+    ('\u{FD7D}', 13121, 6),
+    ('\u{FD7E}', 13127, 6),
+    ('\u{FD7F}', 13133, 6),
+    ('\u{FD80}', 13139, 6),
+    ('\u{FD81}', 13145, 6),
+    ('\u{FD82}', 13151, 6),
+    ('\u{FD83}', 13157, 6),
+    ('\u{FD84}', 13157, 6),
+    ('\u{FD85}', 13163, 6),
+    ('\u{FD86}', 13163, 6),
+    ('\u{FD87}', 13169, 6),
+    ('\u{FD88}', 13169, 6),
+    ('\u{FD89}', 13175, 6),
+    ('\u{FD8A}', 13181, 6),
+    ('\u{FD8B}', 13187, 6),
+    ('\u{FD8C}', 13193, 6),
+    ('\u{FD8D}', 13199, 6),
+    ('\u{FD8E}', 13205, 6),
+    ('\u{FD8F}', 13211, 6),
+    ('\u{FD92}', 13217, 6),
+    ('\u{FD93}', 13223, 6),
+    ('\u{FD94}', 13229, 6),
+    ('\u{FD95}', 13235, 6),
+    ('\u{FD96}', 13241, 6),
+    ('\u{FD97}', 13247, 6),
+    ('\u{FD98}', 13247, 6),
+    ('\u{FD99}', 13253, 6),
+    ('\u{FD9A}', 13259, 6),
+    ('\u{FD9B}', 13265, 6),
+    ('\u{FD9C}', 13271, 6),
+    ('\u{FD9D}', 13271, 6),
+    ('\u{FD9E}', 13277, 6),
+    ('\u{FD9F}', 13283, 6),
+    ('\u{FDA0}', 13289, 6),
+    ('\u{FDA1}', 13295, 6),
+    ('\u{FDA2}', 13301, 6),
+    ('\u{FDA3}', 13307, 6),
+    ('\u{FDA4}', 13313, 6),
+    ('\u{FDA5}', 13319, 6),
+    ('\u{FDA6}', 13325, 6),
+    ('\u{FDA7}', 13331, 6),
+    ('\u{FDA8}', 13337, 6),
+    ('\u{FDA9}', 13343, 6),
+    ('\u{FDAA}', 13349, 6),
+    ('\u{FDAB}', 13355, 6),
+    ('\u{FDAC}', 13361, 6),
+    ('\u{FDAD}', 13367, 6),
+    ('\u{FDAE}', 13373, 6),
+    ('\u{FDAF}', 13379, 6),
+    ('\u{FDB0}', 13385, 6),
+    ('\u{FDB1}', 13391, 6),
+    ('\u{FDB2}', 13397, 6),
+    ('\u{FDB3}', 13403, 6),
+    ('\u{FDB4}', 13127, 6),
+    ('\u{FDB5}', 13139, 6),
+    ('\u{FDB6}', 13409, 6),
+    ('\u{FDB7}', 13415, 6),
+    ('\u{FDB8}', 13421, 6),
+    ('\u{FDB9}', 13427, 6),
+    ('\u{FDBA}', 13433, 6),
+    ('\u{FDBB}', 13439, 6),
+    ('\u{FDBC}', 13433, 6),
+    ('\u{FDBD}', 13421, 6),
+    ('\u{FDBE}', 13445, 6),
+    ('\u{FDBF}', 13451, 6),
+    ('\u{FDC0}', 13457, 6),
+    ('\u{FDC1}', 13463, 6),
+    ('\u{FDC2}', 13469, 6),
+    ('\u{FDC3}', 13439, 6),
+    ('\u{FDC4}', 13085, 6),
+    ('\u{FDC5}', 13025, 6),
+    ('\u{FDC6}', 13475, 6),
+    ('\u{FDC7}', 13481, 6),
+    ('\u{FDF0}', 13487, 6),
+    ('\u{FDF1}', 13493, 6),
+    ('\u{FDF2}', 13499, 8),
+    ('\u{FDF3}', 13507, 8),
+    ('\u{FDF4}', 13515, 8),
+    ('\u{FDF5}', 13523, 8),
+    ('\u{FDF6}', 13531, 8),
+    ('\u{FDF7}', 13539, 8),
+    ('\u{FDF8}', 13547, 8),
+    ('\u{FDF9}', 13555, 6),
+    ('\u{FDFA}', 13561, 33),
+    ('\u{FDFB}', 13594, 15),
+    ('\u{FDFC}', 13609, 8),
+    ('\u{FE10}', 13617, 1),
+    ('\u{FE11}', 13618, 3),
+    ('\u{FE12}', 13621, 3),
+    ('\u{FE13}', 13624, 1),
+    ('\u{FE14}', 820, 1),
+    ('\u{FE15}', 13625, 1),
+    ('\u{FE16}', 13626, 1),
+    ('\u{FE17}', 13627, 3),
+    ('\u{FE18}', 13630, 3),
+    ('\u{FE19}', 8082, 3),
+    ('\u{FE30}', 8080, 2),
+    ('\u{FE31}', 13633, 3),
+    ('\u{FE32}', 13636, 3),
+    ('\u{FE33}', 13639, 1),
+    ('\u{FE34}', 13639, 1),
+    ('\u{FE35}', 8150, 1),
+    ('\u{FE36}', 8151, 1),
+    ('\u{FE37}', 13640, 1),
+    ('\u{FE38}', 13641, 1),
+    ('\u{FE39}', 13642, 3),
+    ('\u{FE3A}', 13645, 3),
+    ('\u{FE3B}', 13648, 3),
+    ('\u{FE3C}', 13651, 3),
+    ('\u{FE3D}', 13654, 3),
+    ('\u{FE3E}', 13657, 3),
+    ('\u{FE3F}', 3933, 3),
+    ('\u{FE40}', 3936, 3),
+    ('\u{FE41}', 13660, 3),
+    ('\u{FE42}', 13663, 3),
+    ('\u{FE43}', 13666, 3),
+    ('\u{FE44}', 13669, 3),
+    ('\u{FE47}', 13672, 1),
+    ('\u{FE48}', 13673, 1),
+    ('\u{FE49}', 8117, 3),
+    ('\u{FE4A}', 8117, 3),
+    ('\u{FE4B}', 8117, 3),
+    ('\u{FE4C}', 8117, 3),
+    ('\u{FE4D}', 13639, 1),
+    ('\u{FE4E}', 13639, 1),
+    ('\u{FE4F}', 13639, 1),
+    ('\u{FE50}', 13617, 1),
+    ('\u{FE51}', 13618, 3),
+    ('\u{FE52}', 8079, 1),
+    ('\u{FE54}', 820, 1),
+    ('\u{FE55}', 13624, 1),
+    ('\u{FE56}', 13626, 1),
+    ('\u{FE57}', 13625, 1),
+    ('\u{FE58}', 13633, 3),
+    ('\u{FE59}', 8150, 1),
+    ('\u{FE5A}', 8151, 1),
+    ('\u{FE5B}', 13640, 1),
+    ('\u{FE5C}', 13641, 1),
+    ('\u{FE5D}', 13642, 3),
+    ('\u{FE5E}', 13645, 3),
+    ('\u{FE5F}', 13674, 1),
+    ('\u{FE60}', 13675, 1),
+    ('\u{FE61}', 13676, 1),
+    ('\u{FE62}', 8145, 1),
+    ('\u{FE63}', 13677, 1),
+    ('\u{FE64}', 13678, 1),
+    ('\u{FE65}', 13679, 1),
+    ('\u{FE66}', 8149, 1),
+    ('\u{FE68}', 13680, 1),
+    ('\u{FE69}', 13681, 1),
+    ('\u{FE6A}', 13682, 1),
+    ('\u{FE6B}', 13683, 1),
+    ('\u{FE70}', 13684, 3),
+    ('\u{FE71}', 13687, 4),
+    ('\u{FE72}', 13691, 3),
+    ('\u{FE74}', 13694, 3),
+    ('\u{FE76}', 13697, 3),
+    ('\u{FE77}', 13700, 4),
+    ('\u{FE78}', 13704, 3),
+    ('\u{FE79}', 13707, 4),
+    ('\u{FE7A}', 13711, 3),
+    ('\u{FE7B}', 13714, 4),
+    ('\u{FE7C}', 13718, 3),
+    ('\u{FE7D}', 13721, 4),
+    ('\u{FE7E}', 13725, 3),
+    ('\u{FE7F}', 13728, 4),
+    ('\u{FE80}', 13732, 2),
+    ('\u{FE81}', 1127, 4),
+    ('\u{FE82}', 1127, 4),
+    ('\u{FE83}', 1131, 4),
+    ('\u{FE84}', 1131, 4),
+    ('\u{FE85}', 1135, 4),
+    ('\u{FE86}', 1135, 4),
+    ('\u{FE87}', 1139, 4),
+    ('\u{FE88}', 1139, 4),
+    ('\u{FE89}', 1143, 4),
+    ('\u{FE8A}', 1143, 4),
+    ('\u{FE8B}', 1143, 4),
+    ('\u{FE8C}', 1143, 4),
+    ('\u{FE8D}', 13734, 2),
+    ('\u{FE8E}', 13734, 2),
+    ('\u{FE8F}', 13736, 2),
+    ('\u{FE90}', 13736, 2),
+    ('\u{FE91}', 13736, 2),
+    ('\u{FE92}', 13736, 2),
+    ('\u{FE93}', 13738, 2),
+    ('\u{FE94}', 13738, 2),
+    ('\u{FE95}', 13740, 2),
+    ('\u{FE96}', 13740, 2),
+    ('\u{FE97}', 13740, 2),
+    ('\u{FE98}', 13740, 2),
+    ('\u{FE99}', 13742, 2),
+    ('\u{FE9A}', 13742, 2),
+    ('\u{FE9B}', 13742, 2),
+    ('\u{FE9C}', 13742, 2),
+    ('\u{FE9D}', 13744, 2),
+    ('\u{FE9E}', 13744, 2),
+    ('\u{FE9F}', 13744, 2),
+    ('\u{FEA0}', 13744, 2),
+    ('\u{FEA1}', 13746, 2),
+    ('\u{FEA2}', 13746, 2),
+    ('\u{FEA3}', 13746, 2),
+    ('\u{FEA4}', 13746, 2),
+    ('\u{FEA5}', 13748, 2),
+    ('\u{FEA6}', 13748, 2),
+    ('\u{FEA7}', 13748, 2),
+    ('\u{FEA8}', 13748, 2),
+    ('\u{FEA9}', 13750, 2),
+    ('\u{FEAA}', 13750, 2),
+    ('\u{FEAB}', 13752, 2),
+    ('\u{FEAC}', 13752, 2),
+    ('\u{FEAD}', 13754, 2),
+    ('\u{FEAE}', 13754, 2),
+    ('\u{FEAF}', 13756, 2),
+    ('\u{FEB0}', 13756, 2),
+    ('\u{FEB1}', 13758, 2),
+    ('\u{FEB2}', 13758, 2),
+    ('\u{FEB3}', 13758, 2),
+    ('\u{FEB4}', 13758, 2),
+    ('\u{FEB5}', 13760, 2),
+    ('\u{FEB6}', 13760, 2),
+    ('\u{FEB7}', 13760, 2),
+    ('\u{FEB8}', 13760, 2),
+    ('\u{FEB9}', 13762, 2),
+    ('\u{FEBA}', 13762, 2),
+    ('\u{FEBB}', 13762, 2),
+    ('\u{FEBC}', 13762, 2),
+    ('\u{FEBD}', 13764, 2),
+    ('\u{FEBE}', 13764, 2),
+    ('\u{FEBF}', 13764, 2),
+    ('\u{FEC0}', 13764, 2),
+    ('\u{FEC1}', 13766, 2),
+    ('\u{FEC2}', 13766, 2),
+    ('\u{FEC3}', 13766, 2),
+    ('\u{FEC4}', 13766, 2),
+    ('\u{FEC5}', 13768, 2),
+    ('\u{FEC6}', 13768, 2),
+    ('\u{FEC7}', 13768, 2),
+    ('\u{FEC8}', 13768, 2),
+    ('\u{FEC9}', 13770, 2),
+    ('\u{FECA}', 13770, 2),
+    ('\u{FECB}', 13770, 2),
+    ('\u{FECC}', 13770, 2),
+    ('\u{FECD}', 13772, 2),
+    ('\u{FECE}', 13772, 2),
+    ('\u{FECF}', 13772, 2),
+    ('\u{FED0}', 13772, 2),
+    ('\u{FED1}', 13774, 2),
+    ('\u{FED2}', 13774, 2),
+    ('\u{FED3}', 13774, 2),
+    ('\u{FED4}', 13774, 2),
+    ('\u{FED5}', 13776, 2),
+    ('\u{FED6}', 13776, 2),
+    ('\u{FED7}', 13776, 2),
+    ('\u{FED8}', 13776, 2),
+    ('\u{FED9}', 13778, 2),
+    ('\u{FEDA}', 13778, 2),
+    ('\u{FEDB}', 13778, 2),
+    ('\u{FEDC}', 13778, 2),
+    ('\u{FEDD}', 13780, 2),
+    ('\u{FEDE}', 13780, 2),
+    ('\u{FEDF}', 13780, 2),
+    ('\u{FEE0}', 13780, 2),
+    ('\u{FEE1}', 13782, 2),
+    ('\u{FEE2}', 13782, 2),
+    ('\u{FEE3}', 13782, 2),
+    ('\u{FEE4}', 13782, 2),
+    ('\u{FEE5}', 13784, 2),
+    ('\u{FEE6}', 13784, 2),
+    ('\u{FEE7}', 13784, 2),
+    ('\u{FEE8}', 13784, 2),
+    ('\u{FEE9}', 13786, 2),
+    ('\u{FEEA}', 13786, 2),
+    ('\u{FEEB}', 13786, 2),
+    ('\u{FEEC}', 13786, 2),
+    ('\u{FEED}', 13788, 2),
+    ('\u{FEEE}', 13788, 2),
+    ('\u{FEEF}', 12193, 2),
+    ('\u{FEF0}', 12193, 2),
+    ('\u{FEF1}', 13790, 2),
+    ('\u{FEF2}', 13790, 2),
+    ('\u{FEF3}', 13790, 2),
+    ('\u{FEF4}', 13790, 2),
+    ('\u{FEF5}', 13792, 6),
+    ('\u{FEF6}', 13792, 6),
+    ('\u{FEF7}', 13798, 6),
+    ('\u{FEF8}', 13798, 6),
+    ('\u{FEF9}', 13804, 6),
+    ('\u{FEFA}', 13804, 6),
+    ('\u{FEFB}', 13810, 4),
+    ('\u{FEFC}', 13810, 4),
+    ('\u{FF01}', 13625, 1),
+    ('\u{FF02}', 13814, 1),
+    ('\u{FF03}', 13674, 1),
+    ('\u{FF04}', 13681, 1),
+    ('\u{FF05}', 13682, 1),
+    ('\u{FF06}', 13675, 1),
+    ('\u{FF07}', 13815, 1),
+    ('\u{FF08}', 8150, 1),
+    ('\u{FF09}', 8151, 1),
+    ('\u{FF0A}', 13676, 1),
+    ('\u{FF0B}', 8145, 1),
+    ('\u{FF0C}', 13617, 1),
+    ('\u{FF0D}', 13677, 1),
+    ('\u{FF0E}', 8079, 1),
+    ('\u{FF0F}', 13816, 1),
+    ('\u{FF10}', 8138, 1),
+    ('\u{FF11}', 7682, 1),
+    ('\u{FF12}', 7672, 1),
+    ('\u{FF13}', 7673, 1),
+    ('\u{FF14}', 8139, 1),
+    ('\u{FF15}', 8140, 1),
+    ('\u{FF16}', 8141, 1),
+    ('\u{FF17}', 8142, 1),
+    ('\u{FF18}', 8143, 1),
+    ('\u{FF19}', 8144, 1),
+    ('\u{FF1A}', 13624, 1),
+    ('\u{FF1B}', 820, 1),
+    ('\u{FF1C}', 13678, 1),
+    ('\u{FF1D}', 8149, 1),
+    ('\u{FF1E}', 13679, 1),
+    ('\u{FF1F}', 13626, 1),
+    ('\u{FF20}', 13683, 1),
+    ('\u{FF21}', 7878, 1),
+    ('\u{FF22}', 7881, 1),
+    ('\u{FF23}', 8161, 1),
+    ('\u{FF24}', 7882, 1),
+    ('\u{FF25}', 7883, 1),
+    ('\u{FF26}', 8189, 1),
+    ('\u{FF27}', 7886, 1),
+    ('\u{FF28}', 7887, 1),
+    ('\u{FF29}', 7888, 1),
+    ('\u{FF2A}', 7889, 1),
+    ('\u{FF2B}', 3718, 1),
+    ('\u{FF2C}', 7890, 1),
+    ('\u{FF2D}', 7891, 1),
+    ('\u{FF2E}', 7892, 1),
+    ('\u{FF2F}', 7893, 1),
+    ('\u{FF30}', 7896, 1),
+    ('\u{FF31}', 8180, 1),
+    ('\u{FF32}', 7897, 1),
+    ('\u{FF33}', 8593, 1),
+    ('\u{FF34}', 7898, 1),
+    ('\u{FF35}', 7899, 1),
+    ('\u{FF36}', 8295, 1),
+    ('\u{FF37}', 7900, 1),
+    ('\u{FF38}', 8307, 1),
+    ('\u{FF39}', 8594, 1),
+    ('\u{FF3A}', 8188, 1),
+    ('\u{FF3B}', 13672, 1),
+    ('\u{FF3C}', 13680, 1),
+    ('\u{FF3D}', 13673, 1),
+    ('\u{FF3E}', 13817, 1),
+    ('\u{FF3F}', 13639, 1),
+    ('\u{FF40}', 3669, 1),
+    ('\u{FF41}', 7668, 1),
+    ('\u{FF42}', 7908, 1),
+    ('\u{FF43}', 7953, 1),
+    ('\u{FF44}', 7909, 1),
+    ('\u{FF45}', 7910, 1),
+    ('\u{FF46}', 7958, 1),
+    ('\u{FF47}', 7917, 1),
+    ('\u{FF48}', 7743, 1),
+    ('\u{FF49}', 7948, 1),
+    ('\u{FF4A}', 7746, 1),
+    ('\u{FF4B}', 7918, 1),
+    ('\u{FF4C}', 7776, 1),
+    ('\u{FF4D}', 7919, 1),
+    ('\u{FF4E}', 8152, 1),
+    ('\u{FF4F}', 7683, 1),
+    ('\u{FF50}', 7930, 1),
+    ('\u{FF51}', 8595, 1),
+    ('\u{FF52}', 7747, 1),
+    ('\u{FF53}', 7712, 1),
+    ('\u{FF54}', 7931, 1),
+    ('\u{FF55}', 7932, 1),
+    ('\u{FF56}', 7938, 1),
+    ('\u{FF57}', 7754, 1),
+    ('\u{FF58}', 7777, 1),
+    ('\u{FF59}', 7755, 1),
+    ('\u{FF5A}', 8014, 1),
+    ('\u{FF5B}', 13640, 1),
+    ('\u{FF5C}', 13818, 1),
+    ('\u{FF5D}', 13641, 1),
+    ('\u{FF5E}', 13819, 1),
+    ('\u{FF5F}', 13820, 3),
+    ('\u{FF60}', 13823, 3),
+    ('\u{FF61}', 13621, 3),
+    ('\u{FF62}', 13660, 3),
+    ('\u{FF63}', 13663, 3),
+    ('\u{FF64}', 13618, 3),
+    ('\u{FF65}', 13826, 3),
+    ('\u{FF66}', 10412, 3),
+    ('\u{FF67}', 13829, 3),
+    ('\u{FF68}', 13832, 3),
+    ('\u{FF69}', 13835, 3),
+    ('\u{FF6A}', 13838, 3),
+    ('\u{FF6B}', 13841, 3),
+    ('\u{FF6C}', 13844, 3),
+    ('\u{FF6D}', 13847, 3),
+    ('\u{FF6E}', 13850, 3),
+    ('\u{FF6F}', 13853, 3),
+    ('\u{FF70}', 13856, 3),
+    ('\u{FF71}', 10274, 3),
+    ('\u{FF72}', 10277, 3),
+    ('\u{FF73}', 10280, 3),
+    ('\u{FF74}', 10283, 3),
+    ('\u{FF75}', 10286, 3),
+    ('\u{FF76}', 10289, 3),
+    ('\u{FF77}', 10292, 3),
+    ('\u{FF78}', 10295, 3),
+    ('\u{FF79}', 10298, 3),
+    ('\u{FF7A}', 10301, 3),
+    ('\u{FF7B}', 10304, 3),
+    ('\u{FF7C}', 10307, 3),
+    ('\u{FF7D}', 10310, 3),
+    ('\u{FF7E}', 10313, 3),
+    ('\u{FF7F}', 10316, 3),
+    ('\u{FF80}', 10319, 3),
+    ('\u{FF81}', 10322, 3),
+    ('\u{FF82}', 10325, 3),
+    ('\u{FF83}', 10328, 3),
+    ('\u{FF84}', 10331, 3),
+    ('\u{FF85}', 10334, 3),
+    ('\u{FF86}', 10337, 3),
+    ('\u{FF87}', 10340, 3),
+    ('\u{FF88}', 10343, 3),
+    ('\u{FF89}', 10346, 3),
+    ('\u{FF8A}', 10349, 3),
+    ('\u{FF8B}', 10352, 3),
+    ('\u{FF8C}', 10355, 3),
+    ('\u{FF8D}', 10358, 3),
+    ('\u{FF8E}', 10361, 3),
+    ('\u{FF8F}', 10364, 3),
+    ('\u{FF90}', 10367, 3),
+    ('\u{FF91}', 10370, 3),
+    ('\u{FF92}', 10373, 3),
+    ('\u{FF93}', 10376, 3),
+    ('\u{FF94}', 10379, 3),
+    ('\u{FF95}', 10382, 3),
+    ('\u{FF96}', 10385, 3),
+    ('\u{FF97}', 10388, 3),
+    ('\u{FF98}', 10391, 3),
+    ('\u{FF99}', 10394, 3),
+    ('\u{FF9A}', 10397, 3),
+    ('\u{FF9B}', 10400, 3),
+    ('\u{FF9C}', 10403, 3),
+    ('\u{FF9D}', 13859, 3),
+    ('\u{FF9E}', 13862, 3),
+    ('\u{FF9F}', 13865, 3),
+    ('\u{FFA0}', 9377, 3),
+    ('\u{FFA1}', 9224, 3),
+    ('\u{FFA2}', 9227, 3),
+    ('\u{FFA3}', 9230, 3),
+    ('\u{FFA4}', 9233, 3),
+    ('\u{FFA5}', 9236, 3),
+    ('\u{FFA6}', 9239, 3),
+    ('\u{FFA7}', 9242, 3),
+    ('\u{FFA8}', 9245, 3),
+    ('\u{FFA9}', 9248, 3),
+    ('\u{FFAA}', 9251, 3),
+    ('\u{FFAB}', 9254, 3),
+    ('\u{FFAC}', 9257, 3),
+    ('\u{FFAD}', 9260, 3),
+    ('\u{FFAE}', 9263, 3),
+    ('\u{FFAF}', 9266, 3),
+    ('\u{FFB0}', 9269, 3),
+    ('\u{FFB1}', 9272, 3),
+    ('\u{FFB2}', 9275, 3),
+    ('\u{FFB3}', 9278, 3),
+    ('\u{FFB4}', 9281, 3),
+    ('\u{FFB5}', 9284, 3),
+    ('\u{FFB6}', 9287, 3),
+    ('\u{FFB7}', 9290, 3),
+    ('\u{FFB8}', 9293, 3),
+    ('\u{FFB9}', 9296, 3),
+    ('\u{FFBA}', 9299, 3),
+    ('\u{FFBB}', 9302, 3),
+    ('\u{FFBC}', 9305, 3),
+    ('\u{FFBD}', 9308, 3),
+    ('\u{FFBE}', 9311, 3),
+    ('\u{FFC2}', 9314, 3),
+    ('\u{FFC3}', 9317, 3),
+    ('\u{FFC4}', 9320, 3),
+    ('\u{FFC5}', 9323, 3),
+    ('\u{FFC6}', 9326, 3),
+    ('\u{FFC7}', 9329, 3),
+    ('\u{FFCA}', 9332, 3),
+    ('\u{FFCB}', 9335, 3),
+    ('\u{FFCC}', 9338, 3),
+    ('\u{FFCD}', 9341, 3),
+    ('\u{FFCE}', 9344, 3),
+    ('\u{FFCF}', 9347, 3),
+    ('\u{FFD2}', 9350, 3),
+    ('\u{FFD3}', 9353, 3),
+    ('\u{FFD4}', 9356, 3),
+    ('\u{FFD5}', 9359, 3),
+    ('\u{FFD6}', 9362, 3),
+    ('\u{FFD7}', 9365, 3),
+    ('\u{FFDA}', 9368, 3),
+    ('\u{FFDB}', 9371, 3),
+    ('\u{FFDC}', 9374, 3),
+    ('\u{FFE0}', 13868, 2),
+    ('\u{FFE1}', 13870, 2),
+    ('\u{FFE2}', 13872, 2),
+    ('\u{FFE3}', 7669, 3),
+    ('\u{FFE4}', 13874, 2),
+    ('\u{FFE5}', 13876, 2),
+    ('\u{FFE6}', 13878, 3),
+    ('\u{FFE8}', 13881, 3),
+    ('\u{FFE9}', 13884, 3),
+    ('\u{FFEA}', 13887, 3),
+    ('\u{FFEB}', 13890, 3),
+    ('\u{FFEC}', 13893, 3),
+    ('\u{FFED}', 13896, 3),
+    ('\u{FFEE}', 13899, 3),
+    ('\u{10781}', 13902, 2),
+    ('\u{10782}', 13904, 2),
+    ('\u{10783}', 13906, 2),
+    ('\u{10784}', 13908, 2),
+    ('\u{10785}', 13910, 2),
+    ('\u{10787}', 13912, 2),
+    ('\u{10788}', 13914, 3),
+    ('\u{10789}', 13917, 2),
+    ('\u{1078A}', 13919, 2),
+    ('\u{1078B}', 13921, 2),
+    ('\u{1078C}', 13923, 2),
+    ('\u{1078D}', 13925, 3),
+    ('\u{1078E}', 13928, 2),
+    ('\u{1078F}', 13930, 2),
+    ('\u{10790}', 13932, 2),
+    ('\u{10791}', 13934, 2),
+    ('\u{10792}', 13936, 2),
+    ('\u{10793}', 13938, 2),
+    ('\u{10794}', 13940, 2),
+    ('\u{10795}', 8176, 2),
+    ('\u{10796}', 13942, 2),
+    ('\u{10797}', 13944, 2),
+    ('\u{10798}', 13946, 2),
+    ('\u{10799}', 13948, 2),
+    ('\u{1079A}', 13950, 2),
+    ('\u{1079B}', 13952, 2),
+    ('\u{1079C}', 13954, 4),
+    ('\u{1079D}', 13958, 3),
+    ('\u{1079E}', 13961, 2),
+    ('\u{1079F}', 13963, 4),
+    ('\u{107A0}', 13967, 2),
+    ('\u{107A1}', 13969, 4),
+    ('\u{107A2}', 13973, 2),
+    ('\u{107A3}', 13975, 2),
+    ('\u{107A4}', 13977, 2),
+    ('\u{107A5}', 8595, 1),
+    ('\u{107A6}', 13979, 2),
+    ('\u{107A7}', 13981, 4),
+    ('\u{107A8}', 13985, 2),
+    ('\u{107A9}', 13987, 2),
+    ('\u{107AA}', 13989, 2),
+    ('\u{107AB}', 13991, 2),
+    ('\u{107AC}', 13993, 2),
+    ('\u{107AD}', 13995, 3),
+    ('\u{107AE}', 13998, 2),
+    ('\u{107AF}', 14000, 2),
+    ('\u{107B0}', 14002, 3),
+    ('\u{107B2}', 14005, 2),
+    ('\u{107B3}', 14007, 2),
+    ('\u{107B4}', 14009, 2),
+    ('\u{107B5}', 14011, 2),
+    ('\u{107B6}', 14013, 2),
+    ('\u{107B7}', 14015, 2),
+    ('\u{107B8}', 14017, 2),
+    ('\u{107B9}', 14019, 4),
+    ('\u{107BA}', 14023, 4),
+    ('\u{1CCD6}', 7878, 1),
+    ('\u{1CCD7}', 7881, 1),
+    ('\u{1CCD8}', 8161, 1),
+    ('\u{1CCD9}', 7882, 1),
+    ('\u{1CCDA}', 7883, 1),
+    ('\u{1CCDB}', 8189, 1),
+    ('\u{1CCDC}', 7886, 1),
+    ('\u{1CCDD}', 7887, 1),
+    ('\u{1CCDE}', 7888, 1),
+    ('\u{1CCDF}', 7889, 1),
+    ('\u{1CCE0}', 3718, 1),
+    ('\u{1CCE1}', 7890, 1),
+    ('\u{1CCE2}', 7891, 1),
+    ('\u{1CCE3}', 7892, 1),
+    ('\u{1CCE4}', 7893, 1),
+    ('\u{1CCE5}', 7896, 1),
+    ('\u{1CCE6}', 8180, 1),
+    ('\u{1CCE7}', 7897, 1),
+    ('\u{1CCE8}', 8593, 1),
+    ('\u{1CCE9}', 7898, 1),
+    ('\u{1CCEA}', 7899, 1),
+    ('\u{1CCEB}', 8295, 1),
+    ('\u{1CCEC}', 7900, 1),
+    ('\u{1CCED}', 8307, 1),
+    ('\u{1CCEE}', 8594, 1),
+    ('\u{1CCEF}', 8188, 1),
+    ('\u{1CCF0}', 8138, 1),
+    ('\u{1CCF1}', 7682, 1),
+    ('\u{1CCF2}', 7672, 1),
+    ('\u{1CCF3}', 7673, 1),
+    ('\u{1CCF4}', 8139, 1),
+    ('\u{1CCF5}', 8140, 1),
+    ('\u{1CCF6}', 8141, 1),
+    ('\u{1CCF7}', 8142, 1),
+    ('\u{1CCF8}', 8143, 1),
+    ('\u{1CCF9}', 8144, 1),
+    ('\u{1D400}', 7878, 1),
+    ('\u{1D401}', 7881, 1),
+    ('\u{1D402}', 8161, 1),
+    ('\u{1D403}', 7882, 1),
+    ('\u{1D404}', 7883, 1),
+    ('\u{1D405}', 8189, 1),
+    ('\u{1D406}', 7886, 1),
+    ('\u{1D407}', 7887, 1),
+    ('\u{1D408}', 7888, 1),
+    ('\u{1D409}', 7889, 1),
+    ('\u{1D40A}', 3718, 1),
+    ('\u{1D40B}', 7890, 1),
+    ('\u{1D40C}', 7891, 1),
+    ('\u{1D40D}', 7892, 1),
+    ('\u{1D40E}', 7893, 1),
+    ('\u{1D40F}', 7896, 1),
+    ('\u{1D410}', 8180, 1),
+    ('\u{1D411}', 7897, 1),
+    ('\u{1D412}', 8593, 1),
+    ('\u{1D413}', 7898, 1),
+    ('\u{1D414}', 7899, 1),
+    ('\u{1D415}', 8295, 1),
+    ('\u{1D416}', 7900, 1),
+    ('\u{1D417}', 8307, 1),
+    ('\u{1D418}', 8594, 1),
+    ('\u{1D419}', 8188, 1),
+    ('\u{1D41A}', 7668, 1),
+    ('\u{1D41B}', 7908, 1),
+    ('\u{1D41C}', 7953, 1),
+    ('\u{1D41D}', 7909, 1),
+    ('\u{1D41E}', 7910, 1),
+    ('\u{1D41F}', 7958, 1),
+    ('\u{1D420}', 7917, 1),
+    ('\u{1D421}', 7743, 1),
+    ('\u{1D422}', 7948, 1),
+    ('\u{1D423}', 7746, 1),
+    ('\u{1D424}', 7918, 1),
+    ('\u{1D425}', 7776, 1),
+    ('\u{1D426}', 7919, 1),
+    ('\u{1D427}', 8152, 1),
+    ('\u{1D428}', 7683, 1),
+    ('\u{1D429}', 7930, 1),
+    ('\u{1D42A}', 8595, 1),
+    ('\u{1D42B}', 7747, 1),
+    ('\u{1D42C}', 7712, 1),
+    ('\u{1D42D}', 7931, 1),
+    ('\u{1D42E}', 7932, 1),
+    ('\u{1D42F}', 7938, 1),
+    ('\u{1D430}', 7754, 1),
+    ('\u{1D431}', 7777, 1),
+    ('\u{1D432}', 7755, 1),
+    ('\u{1D433}', 8014, 1),
+    ('\u{1D434}', 7878, 1),
+    ('\u{1D435}', 7881, 1),
+    ('\u{1D436}', 8161, 1),
+    ('\u{1D437}', 7882, 1),
+    ('\u{1D438}', 7883, 1),
+    ('\u{1D439}', 8189, 1),
+    ('\u{1D43A}', 7886, 1),
+    ('\u{1D43B}', 7887, 1),
+    ('\u{1D43C}', 7888, 1),
+    ('\u{1D43D}', 7889, 1),
+    ('\u{1D43E}', 3718, 1),
+    ('\u{1D43F}', 7890, 1),
+    ('\u{1D440}', 7891, 1),
+    ('\u{1D441}', 7892, 1),
+    ('\u{1D442}', 7893, 1),
+    ('\u{1D443}', 7896, 1),
+    ('\u{1D444}', 8180, 1),
+    ('\u{1D445}', 7897, 1),
+    ('\u{1D446}', 8593, 1),
+    ('\u{1D447}', 7898, 1),
+    ('\u{1D448}', 7899, 1),
+    ('\u{1D449}', 8295, 1),
+    ('\u{1D44A}', 7900, 1),
+    ('\u{1D44B}', 8307, 1),
+    ('\u{1D44C}', 8594, 1),
+    ('\u{1D44D}', 8188, 1),
+    ('\u{1D44E}', 7668, 1),
+    ('\u{1D44F}', 7908, 1),
+    ('\u{1D450}', 7953, 1),
+    ('\u{1D451}', 7909, 1),
+    ('\u{1D452}', 7910, 1),
+    ('\u{1D453}', 7958, 1),
+    ('\u{1D454}', 7917, 1),
+    ('\u{1D456}', 7948, 1),
+    ('\u{1D457}', 7746, 1),
+    ('\u{1D458}', 7918, 1),
+    ('\u{1D459}', 7776, 1),
+    ('\u{1D45A}', 7919, 1),
+    ('\u{1D45B}', 8152, 1),
+    ('\u{1D45C}', 7683, 1),
+    ('\u{1D45D}', 7930, 1),
+    ('\u{1D45E}', 8595, 1),
+    ('\u{1D45F}', 7747, 1),
+    ('\u{1D460}', 7712, 1),
+    ('\u{1D461}', 7931, 1),
+    ('\u{1D462}', 7932, 1),
+    ('\u{1D463}', 7938, 1),
+    ('\u{1D464}', 7754, 1),
+    ('\u{1D465}', 7777, 1),
+    ('\u{1D466}', 7755, 1),
+    ('\u{1D467}', 8014, 1),
+    ('\u{1D468}', 7878, 1),
+    ('\u{1D469}', 7881, 1),
+    ('\u{1D46A}', 8161, 1),
+    ('\u{1D46B}', 7882, 1),
+    ('\u{1D46C}', 7883, 1),
+    ('\u{1D46D}', 8189, 1),
+    ('\u{1D46E}', 7886, 1),
+    ('\u{1D46F}', 7887, 1),
+    ('\u{1D470}', 7888, 1),
+    ('\u{1D471}', 7889, 1),
+    ('\u{1D472}', 3718, 1),
+    ('\u{1D473}', 7890, 1),
+    ('\u{1D474}', 7891, 1),
+    ('\u{1D475}', 7892, 1),
+    ('\u{1D476}', 7893, 1),
+    ('\u{1D477}', 7896, 1),
+    ('\u{1D478}', 8180, 1),
+    ('\u{1D479}', 7897, 1),
+    ('\u{1D47A}', 8593, 1),
+    ('\u{1D47B}', 7898, 1),
+    ('\u{1D47C}', 7899, 1),
+    ('\u{1D47D}', 8295, 1),
+    ('\u{1D47E}', 7900, 1),
+    ('\u{1D47F}', 8307, 1),
+    ('\u{1D480}', 8594, 1),
+    ('\u{1D481}', 8188, 1),
+    ('\u{1D482}', 7668, 1),
+    ('\u{1D483}', 7908, 1),
+    ('\u{1D484}', 7953, 1),
+    ('\u{1D485}', 7909, 1),
+    ('\u{1D486}', 7910, 1),
+    ('\u{1D487}', 7958, 1),
+    ('\u{1D488}', 7917, 1),
+    ('\u{1D489}', 7743, 1),
+    ('\u{1D48A}', 7948, 1),
+    ('\u{1D48B}', 7746, 1),
+    ('\u{1D48C}', 7918, 1),
+    ('\u{1D48D}', 7776, 1),
+    ('\u{1D48E}', 7919, 1),
+    ('\u{1D48F}', 8152, 1),
+    ('\u{1D490}', 7683, 1),
+    ('\u{1D491}', 7930, 1),
+    ('\u{1D492}', 8595, 1),
+    ('\u{1D493}', 7747, 1),
+    ('\u{1D494}', 7712, 1),
+    ('\u{1D495}', 7931, 1),
+    ('\u{1D496}', 7932, 1),
+    ('\u{1D497}', 7938, 1),
+    ('\u{1D498}', 7754, 1),
+    ('\u{1D499}', 7777, 1),
+    ('\u{1D49A}', 7755, 1),
+    ('\u{1D49B}', 8014, 1),
+    ('\u{1D49C}', 7878, 1),
+    ('\u{1D49E}', 8161, 1),
+    ('\u{1D49F}', 7882, 1),
+    ('\u{1D4A2}', 7886, 1),
+    ('\u{1D4A5}', 7889, 1),
+    ('\u{1D4A6}', 3718, 1),
+    ('\u{1D4A9}', 7892, 1),
+    ('\u{1D4AA}', 7893, 1),
+    ('\u{1D4AB}', 7896, 1),
+    ('\u{1D4AC}', 8180, 1),
+    ('\u{1D4AE}', 8593, 1),
+    ('\u{1D4AF}', 7898, 1),
+    ('\u{1D4B0}', 7899, 1),
+    ('\u{1D4B1}', 8295, 1),
+    ('\u{1D4B2}', 7900, 1),
+    ('\u{1D4B3}', 8307, 1),
+    ('\u{1D4B4}', 8594, 1),
+    ('\u{1D4B5}', 8188, 1),
+    ('\u{1D4B6}', 7668, 1),
+    ('\u{1D4B7}', 7908, 1),
+    ('\u{1D4B8}', 7953, 1),
+    ('\u{1D4B9}', 7909, 1),
+    ('\u{1D4BB}', 7958, 1),
+    ('\u{1D4BD}', 7743, 1),
+    ('\u{1D4BE}', 7948, 1),
+    ('\u{1D4BF}', 7746, 1),
+    ('\u{1D4C0}', 7918, 1),
+    ('\u{1D4C1}', 7776, 1),
+    ('\u{1D4C2}', 7919, 1),
+    ('\u{1D4C3}', 8152, 1),
+    ('\u{1D4C5}', 7930, 1),
+    ('\u{1D4C6}', 8595, 1),
+    ('\u{1D4C7}', 7747, 1),
+    ('\u{1D4C8}', 7712, 1),
+    ('\u{1D4C9}', 7931, 1),
+    ('\u{1D4CA}', 7932, 1),
+    ('\u{1D4CB}', 7938, 1),
+    ('\u{1D4CC}', 7754, 1),
+    ('\u{1D4CD}', 7777, 1),
+    ('\u{1D4CE}', 7755, 1),
+    ('\u{1D4CF}', 8014, 1),
+    ('\u{1D4D0}', 7878, 1),
+    ('\u{1D4D1}', 7881, 1),
+    ('\u{1D4D2}', 8161, 1),
+    ('\u{1D4D3}', 7882, 1),
+    ('\u{1D4D4}', 7883, 1),
+    ('\u{1D4D5}', 8189, 1),
+    ('\u{1D4D6}', 7886, 1),
+    ('\u{1D4D7}', 7887, 1),
+    ('\u{1D4D8}', 7888, 1),
+    ('\u{1D4D9}', 7889, 1),
+    ('\u{1D4DA}', 3718, 1),
+    ('\u{1D4DB}', 7890, 1),
+    ('\u{1D4DC}', 7891, 1),
+    ('\u{1D4DD}', 7892, 1),
+    ('\u{1D4DE}', 7893, 1),
+    ('\u{1D4DF}', 7896, 1),
+    ('\u{1D4E0}', 8180, 1),
+    ('\u{1D4E1}', 7897, 1),
+    ('\u{1D4E2}', 8593, 1),
+    ('\u{1D4E3}', 7898, 1),
+    ('\u{1D4E4}', 7899, 1),
+    ('\u{1D4E5}', 8295, 1),
+    ('\u{1D4E6}', 7900, 1),
+    ('\u{1D4E7}', 8307, 1),
+    ('\u{1D4E8}', 8594, 1),
+    ('\u{1D4E9}', 8188, 1),
+    ('\u{1D4EA}', 7668, 1),
+    ('\u{1D4EB}', 7908, 1),
+    ('\u{1D4EC}', 7953, 1),
+    ('\u{1D4ED}', 7909, 1),
+    ('\u{1D4EE}', 7910, 1),
+    ('\u{1D4EF}', 7958, 1),
+    ('\u{1D4F0}', 7917, 1),
+    ('\u{1D4F1}', 7743, 1),
+    ('\u{1D4F2}', 7948, 1),
+    ('\u{1D4F3}', 7746, 1),
+    ('\u{1D4F4}', 7918, 1),
+    ('\u{1D4F5}', 7776, 1),
+    ('\u{1D4F6}', 7919, 1),
+    ('\u{1D4F7}', 8152, 1),
+    ('\u{1D4F8}', 7683, 1),
+    ('\u{1D4F9}', 7930, 1),
+    ('\u{1D4FA}', 8595, 1),
+    ('\u{1D4FB}', 7747, 1),
+    ('\u{1D4FC}', 7712, 1),
+    ('\u{1D4FD}', 7931, 1),
+    ('\u{1D4FE}', 7932, 1),
+    ('\u{1D4FF}', 7938, 1),
+    ('\u{1D500}', 7754, 1),
+    ('\u{1D501}', 7777, 1),
+    ('\u{1D502}', 7755, 1),
+    ('\u{1D503}', 8014, 1),
+    ('\u{1D504}', 7878, 1),
+    ('\u{1D505}', 7881, 1),
+    ('\u{1D507}', 7882, 1),
+    ('\u{1D508}', 7883, 1),
+    ('\u{1D509}', 8189, 1),
+    ('\u{1D50A}', 7886, 1),
+    ('\u{1D50D}', 7889, 1),
+    ('\u{1D50E}', 3718, 1),
+    ('\u{1D50F}', 7890, 1),
+    ('\u{1D510}', 7891, 1),
+    ('\u{1D511}', 7892, 1),
+    ('\u{1D512}', 7893, 1),
+    ('\u{1D513}', 7896, 1),
+    ('\u{1D514}', 8180, 1),
+    ('\u{1D516}', 8593, 1),
+    ('\u{1D517}', 7898, 1),
+    ('\u{1D518}', 7899, 1),
+    ('\u{1D519}', 8295, 1),
+    ('\u{1D51A}', 7900, 1),
+    ('\u{1D51B}', 8307, 1),
+    ('\u{1D51C}', 8594, 1),
+    ('\u{1D51E}', 7668, 1),
+    ('\u{1D51F}', 7908, 1),
+    ('\u{1D520}', 7953, 1),
+    ('\u{1D521}', 7909, 1),
+    ('\u{1D522}', 7910, 1),
+    ('\u{1D523}', 7958, 1),
+    ('\u{1D524}', 7917, 1),
+    ('\u{1D525}', 7743, 1),
+    ('\u{1D526}', 7948, 1),
+    ('\u{1D527}', 7746, 1),
+    ('\u{1D528}', 7918, 1),
+    ('\u{1D529}', 7776, 1),
+    ('\u{1D52A}', 7919, 1),
+    ('\u{1D52B}', 8152, 1),
+    ('\u{1D52C}', 7683, 1),
+    ('\u{1D52D}', 7930, 1),
+    ('\u{1D52E}', 8595, 1),
+    ('\u{1D52F}', 7747, 1),
+    ('\u{1D530}', 7712, 1),
+    ('\u{1D531}', 7931, 1),
+    ('\u{1D532}', 7932, 1),
+    ('\u{1D533}', 7938, 1),
+    ('\u{1D534}', 7754, 1),
+    ('\u{1D535}', 7777, 1),
+    ('\u{1D536}', 7755, 1),
+    ('\u{1D537}', 8014, 1),
+    ('\u{1D538}', 7878, 1),
+    ('\u{1D539}', 7881, 1),
+    ('\u{1D53B}', 7882, 1),
+    ('\u{1D53C}', 7883, 1),
+    ('\u{1D53D}', 8189, 1),
+    ('\u{1D53E}', 7886, 1),
+    ('\u{1D540}', 7888, 1),
+    ('\u{1D541}', 7889, 1),
+    ('\u{1D542}', 3718, 1),
+    ('\u{1D543}', 7890, 1),
+    ('\u{1D544}', 7891, 1),
+    ('\u{1D546}', 7893, 1),
+    ('\u{1D54A}', 8593, 1),
+    ('\u{1D54B}', 7898, 1),
+    ('\u{1D54C}', 7899, 1),
+    ('\u{1D54D}', 8295, 1),
+    ('\u{1D54E}', 7900, 1),
+    ('\u{1D54F}', 8307, 1),
+    ('\u{1D550}', 8594, 1),
+    ('\u{1D552}', 7668, 1),
+    ('\u{1D553}', 7908, 1),
+    ('\u{1D554}', 7953, 1),
+    ('\u{1D555}', 7909, 1),
+    ('\u{1D556}', 7910, 1),
+    ('\u{1D557}', 7958, 1),
+    ('\u{1D558}', 7917, 1),
+    ('\u{1D559}', 7743, 1),
+    ('\u{1D55A}', 7948, 1),
+    ('\u{1D55B}', 7746, 1),
+    ('\u{1D55C}', 7918, 1),
+    ('\u{1D55D}', 7776, 1),
+    ('\u{1D55E}', 7919, 1),
+    ('\u{1D55F}', 8152, 1),
+    ('\u{1D560}', 7683, 1),
+    ('\u{1D561}', 7930, 1),
+    ('\u{1D562}', 8595, 1),
+    ('\u{1D563}', 7747, 1),
+    ('\u{1D564}', 7712, 1),
+    ('\u{1D565}', 7931, 1),
+    ('\u{1D566}', 7932, 1),
+    ('\u{1D567}', 7938, 1),
+    ('\u{1D568}', 7754, 1),
+    ('\u{1D569}', 7777, 1),
+    ('\u{1D56A}', 7755, 1),
+    ('\u{1D56B}', 8014, 1),
+    ('\u{1D56C}', 7878, 1),
+    ('\u{1D56D}', 7881, 1),
+    ('\u{1D56E}', 8161, 1),
+    ('\u{1D56F}', 7882, 1),
+    ('\u{1D570}', 7883, 1),
+    ('\u{1D571}', 8189, 1),
+    ('\u{1D572}', 7886, 1),
+    ('\u{1D573}', 7887, 1),
+    ('\u{1D574}', 7888, 1),
+    ('\u{1D575}', 7889, 1),
+    ('\u{1D576}', 3718, 1),
+    ('\u{1D577}', 7890, 1),
+    ('\u{1D578}', 7891, 1),
+    ('\u{1D579}', 7892, 1),
+    ('\u{1D57A}', 7893, 1),
+    ('\u{1D57B}', 7896, 1),
+    ('\u{1D57C}', 8180, 1),
+    ('\u{1D57D}', 7897, 1),
+    ('\u{1D57E}', 8593, 1),
+    ('\u{1D57F}', 7898, 1),
+    ('\u{1D580}', 7899, 1),
+    ('\u{1D581}', 8295, 1),
+    ('\u{1D582}', 7900, 1),
+    ('\u{1D583}', 8307, 1),
+    ('\u{1D584}', 8594, 1),
+    ('\u{1D585}', 8188, 1),
+    ('\u{1D586}', 7668, 1),
+    ('\u{1D587}', 7908, 1),
+    ('\u{1D588}', 7953, 1),
+    ('\u{1D589}', 7909, 1),
+    ('\u{1D58A}', 7910, 1),
+    ('\u{1D58B}', 7958, 1),
+    ('\u{1D58C}', 7917, 1),
+    ('\u{1D58D}', 7743, 1),
+    ('\u{1D58E}', 7948, 1),
+    ('\u{1D58F}', 7746, 1),
+    ('\u{1D590}', 7918, 1),
+    ('\u{1D591}', 7776, 1),
+    ('\u{1D592}', 7919, 1),
+    ('\u{1D593}', 8152, 1),
+    ('\u{1D594}', 7683, 1),
+    ('\u{1D595}', 7930, 1),
+    ('\u{1D596}', 8595, 1),
+    ('\u{1D597}', 7747, 1),
+    ('\u{1D598}', 7712, 1),
+    ('\u{1D599}', 7931, 1),
+    ('\u{1D59A}', 7932, 1),
+    ('\u{1D59B}', 7938, 1),
+    ('\u{1D59C}', 7754, 1),
+    ('\u{1D59D}', 7777, 1),
+    ('\u{1D59E}', 7755, 1),
+    ('\u{1D59F}', 8014, 1),
+    ('\u{1D5A0}', 7878, 1),
+    ('\u{1D5A1}', 7881, 1),
+    ('\u{1D5A2}', 8161, 1),
+    ('\u{1D5A3}', 7882, 1),
+    ('\u{1D5A4}', 7883, 1),
+    ('\u{1D5A5}', 8189, 1),
+    ('\u{1D5A6}', 7886, 1),
+    ('\u{1D5A7}', 7887, 1),
+    ('\u{1D5A8}', 7888, 1),
+    ('\u{1D5A9}', 7889, 1),
+    ('\u{1D5AA}', 3718, 1),
+    ('\u{1D5AB}', 7890, 1),
+    ('\u{1D5AC}', 7891, 1),
+    ('\u{1D5AD}', 7892, 1),
+    ('\u{1D5AE}', 7893, 1),
+    ('\u{1D5AF}', 7896, 1),
+    ('\u{1D5B0}', 8180, 1),
+    ('\u{1D5B1}', 7897, 1),
+    ('\u{1D5B2}', 8593, 1),
+    ('\u{1D5B3}', 7898, 1),
+    ('\u{1D5B4}', 7899, 1),
+    ('\u{1D5B5}', 8295, 1),
+    ('\u{1D5B6}', 7900, 1),
+    ('\u{1D5B7}', 8307, 1),
+    ('\u{1D5B8}', 8594, 1),
+    ('\u{1D5B9}', 8188, 1),
+    ('\u{1D5BA}', 7668, 1),
+    ('\u{1D5BB}', 7908, 1),
+    ('\u{1D5BC}', 7953, 1),
+    ('\u{1D5BD}', 7909, 1),
+    ('\u{1D5BE}', 7910, 1),
+    ('\u{1D5BF}', 7958, 1),
+    ('\u{1D5C0}', 7917, 1),
+    ('\u{1D5C1}', 7743, 1),
+    ('\u{1D5C2}', 7948, 1),
+    ('\u{1D5C3}', 7746, 1),
+    ('\u{1D5C4}', 7918, 1),
+    ('\u{1D5C5}', 7776, 1),
+    ('\u{1D5C6}', 7919, 1),
+    ('\u{1D5C7}', 8152, 1),
+    ('\u{1D5C8}', 7683, 1),
+    ('\u{1D5C9}', 7930, 1),
+    ('\u{1D5CA}', 8595, 1),
+    ('\u{1D5CB}', 7747, 1),
+    ('\u{1D5CC}', 7712, 1),
+    ('\u{1D5CD}', 7931, 1),
+    ('\u{1D5CE}', 7932, 1),
+    ('\u{1D5CF}', 7938, 1),
+    ('\u{1D5D0}', 7754, 1),
+    ('\u{1D5D1}', 7777, 1),
+    ('\u{1D5D2}', 7755, 1),
+    ('\u{1D5D3}', 8014, 1),
+    ('\u{1D5D4}', 7878, 1),
+    ('\u{1D5D5}', 7881, 1),
+    ('\u{1D5D6}', 8161, 1),
+    ('\u{1D5D7}', 7882, 1),
+    ('\u{1D5D8}', 7883, 1),
+    ('\u{1D5D9}', 8189, 1),
+    ('\u{1D5DA}', 7886, 1),
+    ('\u{1D5DB}', 7887, 1),
+    ('\u{1D5DC}', 7888, 1),
+    ('\u{1D5DD}', 7889, 1),
+    ('\u{1D5DE}', 3718, 1),
+    ('\u{1D5DF}', 7890, 1),
+    ('\u{1D5E0}', 7891, 1),
+    ('\u{1D5E1}', 7892, 1),
+    ('\u{1D5E2}', 7893, 1),
+    ('\u{1D5E3}', 7896, 1),
+    ('\u{1D5E4}', 8180, 1),
+    ('\u{1D5E5}', 7897, 1),
+    ('\u{1D5E6}', 8593, 1),
+    ('\u{1D5E7}', 7898, 1),
+    ('\u{1D5E8}', 7899, 1),
+    ('\u{1D5E9}', 8295, 1),
+    ('\u{1D5EA}', 7900, 1),
+    ('\u{1D5EB}', 8307, 1),
+    ('\u{1D5EC}', 8594, 1),
+    ('\u{1D5ED}', 8188, 1),
+    ('\u{1D5EE}', 7668, 1),
+    ('\u{1D5EF}', 7908, 1),
+    ('\u{1D5F0}', 7953, 1),
+    ('\u{1D5F1}', 7909, 1),
+    ('\u{1D5F2}', 7910, 1),
+    ('\u{1D5F3}', 7958, 1),
+    ('\u{1D5F4}', 7917, 1),
+    ('\u{1D5F5}', 7743, 1),
+    ('\u{1D5F6}', 7948, 1),
+    ('\u{1D5F7}', 7746, 1),
+    ('\u{1D5F8}', 7918, 1),
+    ('\u{1D5F9}', 7776, 1),
+    ('\u{1D5FA}', 7919, 1),
+    ('\u{1D5FB}', 8152, 1),
+    ('\u{1D5FC}', 7683, 1),
+    ('\u{1D5FD}', 7930, 1),
+    ('\u{1D5FE}', 8595, 1),
+    ('\u{1D5FF}', 7747, 1),
+    ('\u{1D600}', 7712, 1),
+    ('\u{1D601}', 7931, 1),
+    ('\u{1D602}', 7932, 1),
+    ('\u{1D603}', 7938, 1),
+    ('\u{1D604}', 7754, 1),
+    ('\u{1D605}', 7777, 1),
+    ('\u{1D606}', 7755, 1),
+    ('\u{1D607}', 8014, 1),
+    ('\u{1D608}', 7878, 1),
+    ('\u{1D609}', 7881, 1),
+    ('\u{1D60A}', 8161, 1),
+    ('\u{1D60B}', 7882, 1),
+    ('\u{1D60C}', 7883, 1),
+    ('\u{1D60D}', 8189, 1),
+    ('\u{1D60E}', 7886, 1),
+    ('\u{1D60F}', 7887, 1),
+    ('\u{1D610}', 7888, 1),
+    ('\u{1D611}', 7889, 1),
+    ('\u{1D612}', 3718, 1),
+    ('\u{1D613}', 7890, 1),
+    ('\u{1D614}', 7891, 1),
+    ('\u{1D615}', 7892, 1),
+    ('\u{1D616}', 7893, 1),
+    ('\u{1D617}', 7896, 1),
+    ('\u{1D618}', 8180, 1),
+    ('\u{1D619}', 7897, 1),
+    ('\u{1D61A}', 8593, 1),
+    ('\u{1D61B}', 7898, 1),
+    ('\u{1D61C}', 7899, 1),
+    ('\u{1D61D}', 8295, 1),
+    ('\u{1D61E}', 7900, 1),
+    ('\u{1D61F}', 8307, 1),
+    ('\u{1D620}', 8594, 1),
+    ('\u{1D621}', 8188, 1),
+    ('\u{1D622}', 7668, 1),
+    ('\u{1D623}', 7908, 1),
+    ('\u{1D624}', 7953, 1),
+    ('\u{1D625}', 7909, 1),
+    ('\u{1D626}', 7910, 1),
+    ('\u{1D627}', 7958, 1),
+    ('\u{1D628}', 7917, 1),
+    ('\u{1D629}', 7743, 1),
+    ('\u{1D62A}', 7948, 1),
+    ('\u{1D62B}', 7746, 1),
+    ('\u{1D62C}', 7918, 1),
+    ('\u{1D62D}', 7776, 1),
+    ('\u{1D62E}', 7919, 1),
+    ('\u{1D62F}', 8152, 1),
+    ('\u{1D630}', 7683, 1),
+    ('\u{1D631}', 7930, 1),
+    ('\u{1D632}', 8595, 1),
+    ('\u{1D633}', 7747, 1),
+    ('\u{1D634}', 7712, 1),
+    ('\u{1D635}', 7931, 1),
+    ('\u{1D636}', 7932, 1),
+    ('\u{1D637}', 7938, 1),
+    ('\u{1D638}', 7754, 1),
+    ('\u{1D639}', 7777, 1),
+    ('\u{1D63A}', 7755, 1),
+    ('\u{1D63B}', 8014, 1),
+    ('\u{1D63C}', 7878, 1),
+    ('\u{1D63D}', 7881, 1),
+    ('\u{1D63E}', 8161, 1),
+    ('\u{1D63F}', 7882, 1),
+    ('\u{1D640}', 7883, 1),
+    ('\u{1D641}', 8189, 1),
+    ('\u{1D642}', 7886, 1),
+    ('\u{1D643}', 7887, 1),
+    ('\u{1D644}', 7888, 1),
+    ('\u{1D645}', 7889, 1),
+    ('\u{1D646}', 3718, 1),
+    ('\u{1D647}', 7890, 1),
+    ('\u{1D648}', 7891, 1),
+    ('\u{1D649}', 7892, 1),
+    ('\u{1D64A}', 7893, 1),
+    ('\u{1D64B}', 7896, 1),
+    ('\u{1D64C}', 8180, 1),
+    ('\u{1D64D}', 7897, 1),
+    ('\u{1D64E}', 8593, 1),
+    ('\u{1D64F}', 7898, 1),
+    ('\u{1D650}', 7899, 1),
+    ('\u{1D651}', 8295, 1),
+    ('\u{1D652}', 7900, 1),
+    ('\u{1D653}', 8307, 1),
+    ('\u{1D654}', 8594, 1),
+    ('\u{1D655}', 8188, 1),
+    ('\u{1D656}', 7668, 1),
+    ('\u{1D657}', 7908, 1),
+    ('\u{1D658}', 7953, 1),
+    ('\u{1D659}', 7909, 1),
+    ('\u{1D65A}', 7910, 1),
+    ('\u{1D65B}', 7958, 1),
+    ('\u{1D65C}', 7917, 1),
+    ('\u{1D65D}', 7743, 1),
+    ('\u{1D65E}', 7948, 1),
+    ('\u{1D65F}', 7746, 1),
+    ('\u{1D660}', 7918, 1),
+    ('\u{1D661}', 7776, 1),
+    ('\u{1D662}', 7919, 1),
+    ('\u{1D663}', 8152, 1),
+    ('\u{1D664}', 7683, 1),
+    ('\u{1D665}', 7930, 1),
+    ('\u{1D666}', 8595, 1),
+    ('\u{1D667}', 7747, 1),
+    ('\u{1D668}', 7712, 1),
+    ('\u{1D669}', 7931, 1),
+    ('\u{1D66A}', 7932, 1),
+    ('\u{1D66B}', 7938, 1),
+    ('\u{1D66C}', 7754, 1),
+    ('\u{1D66D}', 7777, 1),
+    ('\u{1D66E}', 7755, 1),
+    ('\u{1D66F}', 8014, 1),
+    ('\u{1D670}', 7878, 1),
+    ('\u{1D671}', 7881, 1),
+    ('\u{1D672}', 8161, 1),
+    ('\u{1D673}', 7882, 1),
+    ('\u{1D674}', 7883, 1),
+    ('\u{1D675}', 8189, 1),
+    ('\u{1D676}', 7886, 1),
+    ('\u{1D677}', 7887, 1),
+    ('\u{1D678}', 7888, 1),
+    ('\u{1D679}', 7889, 1),
+    ('\u{1D67A}', 3718, 1),
+    ('\u{1D67B}', 7890, 1),
+    ('\u{1D67C}', 7891, 1),
+    ('\u{1D67D}', 7892, 1),
+    ('\u{1D67E}', 7893, 1),
+    ('\u{1D67F}', 7896, 1),
+    ('\u{1D680}', 8180, 1),
+    ('\u{1D681}', 7897, 1),
+    ('\u{1D682}', 8593, 1),
+    ('\u{1D683}', 7898, 1),
+    ('\u{1D684}', 7899, 1),
+    ('\u{1D685}', 8295, 1),
+    ('\u{1D686}', 7900, 1),
+    ('\u{1D687}', 8307, 1),
+    ('\u{1D688}', 8594, 1),
+    ('\u{1D689}', 8188, 1),
+    ('\u{1D68A}', 7668, 1),
+    ('\u{1D68B}', 7908, 1),
+    ('\u{1D68C}', 7953, 1),
+    ('\u{1D68D}', 7909, 1),
+    ('\u{1D68E}', 7910, 1),
+    ('\u{1D68F}', 7958, 1),
+    ('\u{1D690}', 7917, 1),
+    ('\u{1D691}', 7743, 1),
+    ('\u{1D692}', 7948, 1),
+    ('\u{1D693}', 7746, 1),
+    ('\u{1D694}', 7918, 1),
+    ('\u{1D695}', 7776, 1),
+    ('\u{1D696}', 7919, 1),
+    ('\u{1D697}', 8152, 1),
+    ('\u{1D698}', 7683, 1),
+    ('\u{1D699}', 7930, 1),
+    ('\u{1D69A}', 8595, 1),
+    ('\u{1D69B}', 7747, 1),
+    ('\u{1D69C}', 7712, 1),
+    ('\u{1D69D}', 7931, 1),
+    ('\u{1D69E}', 7932, 1),
+    ('\u{1D69F}', 7938, 1),
+    ('\u{1D6A0}', 7754, 1),
+    ('\u{1D6A1}', 7777, 1),
+    ('\u{1D6A2}', 7755, 1),
+    ('\u{1D6A3}', 8014, 1),
+    ('\u{1D6A4}', 14027, 2),
+    ('\u{1D6A5}', 14029, 2),
+    ('\u{1D6A8}', 14031, 2),
+    ('\u{1D6A9}', 14033, 2),
+    ('\u{1D6AA}', 8201, 2),
+    ('\u{1D6AB}', 14035, 2),
+    ('\u{1D6AC}', 14037, 2),
+    ('\u{1D6AD}', 14039, 2),
+    ('\u{1D6AE}', 14041, 2),
+    ('\u{1D6AF}', 7804, 2),
+    ('\u{1D6B0}', 14043, 2),
+    ('\u{1D6B1}', 14045, 2),
+    ('\u{1D6B2}', 14047, 2),
+    ('\u{1D6B3}', 14049, 2),
+    ('\u{1D6B4}', 14051, 2),
+    ('\u{1D6B5}', 14053, 2),
+    ('\u{1D6B6}', 14055, 2),
+    ('\u{1D6B7}', 8203, 2),
+    ('\u{1D6B8}', 14057, 2),
+    ('\u{1D6B9}', 7804, 2),
+    ('\u{1D6BA}', 7808, 2),
+    ('\u{1D6BB}', 14059, 2),
+    ('\u{1D6BC}', 7792, 2),
+    ('\u{1D6BD}', 14061, 2),
+    ('\u{1D6BE}', 14063, 2),
+    ('\u{1D6BF}', 14065, 2),
+    ('\u{1D6C0}', 3716, 2),
+    ('\u{1D6C1}', 14067, 3),
+    ('\u{1D6C2}', 14070, 2),
+    ('\u{1D6C3}', 7788, 2),
+    ('\u{1D6C4}', 7942, 2),
+    ('\u{1D6C5}', 7944, 2),
+    ('\u{1D6C6}', 7806, 2),
+    ('\u{1D6C7}', 14072, 2),
+    ('\u{1D6C8}', 14074, 2),
+    ('\u{1D6C9}', 7790, 2),
+    ('\u{1D6CA}', 3507, 2),
+    ('\u{1D6CB}', 7798, 2),
+    ('\u{1D6CC}', 14076, 2),
+    ('\u{1D6CD}', 7677, 2),
+    ('\u{1D6CE}', 14078, 2),
+    ('\u{1D6CF}', 14080, 2),
+    ('\u{1D6D0}', 14082, 2),
+    ('\u{1D6D1}', 7796, 2),
+    ('\u{1D6D2}', 7800, 2),
+    ('\u{1D6D3}', 7802, 2),
+    ('\u{1D6D4}', 14084, 2),
+    ('\u{1D6D5}', 14086, 2),
+    ('\u{1D6D6}', 14088, 2),
+    ('\u{1D6D7}', 7794, 2),
+    ('\u{1D6D8}', 7946, 2),
+    ('\u{1D6D9}', 14090, 2),
+    ('\u{1D6DA}', 14092, 2),
+    ('\u{1D6DB}', 14094, 3),
+    ('\u{1D6DC}', 7806, 2),
+    ('\u{1D6DD}', 7790, 2),
+    ('\u{1D6DE}', 7798, 2),
+    ('\u{1D6DF}', 7794, 2),
+    ('\u{1D6E0}', 7800, 2),
+    ('\u{1D6E1}', 7796, 2),
+    ('\u{1D6E2}', 14031, 2),
+    ('\u{1D6E3}', 14033, 2),
+    ('\u{1D6E4}', 8201, 2),
+    ('\u{1D6E5}', 14035, 2),
+    ('\u{1D6E6}', 14037, 2),
+    ('\u{1D6E7}', 14039, 2),
+    ('\u{1D6E8}', 14041, 2),
+    ('\u{1D6E9}', 7804, 2),
+    ('\u{1D6EA}', 14043, 2),
+    ('\u{1D6EB}', 14045, 2),
+    ('\u{1D6EC}', 14047, 2),
+    ('\u{1D6ED}', 14049, 2),
+    ('\u{1D6EE}', 14051, 2),
+    ('\u{1D6EF}', 14053, 2),
+    ('\u{1D6F0}', 14055, 2),
+    ('\u{1D6F1}', 8203, 2),
+    ('\u{1D6F2}', 14057, 2),
+    ('\u{1D6F3}', 7804, 2),
+    ('\u{1D6F4}', 7808, 2),
+    ('\u{1D6F5}', 14059, 2),
+    ('\u{1D6F6}', 7792, 2),
+    ('\u{1D6F7}', 14061, 2),
+    ('\u{1D6F8}', 14063, 2),
+    ('\u{1D6F9}', 14065, 2),
+    ('\u{1D6FA}', 3716, 2),
+    ('\u{1D6FB}', 14067, 3),
+    ('\u{1D6FC}', 14070, 2),
+    ('\u{1D6FD}', 7788, 2),
+    ('\u{1D6FE}', 7942, 2),
+    ('\u{1D6FF}', 7944, 2),
+    ('\u{1D700}', 7806, 2),
+    ('\u{1D701}', 14072, 2),
+    ('\u{1D702}', 14074, 2),
+    ('\u{1D703}', 7790, 2),
+    ('\u{1D704}', 3507, 2),
+    ('\u{1D705}', 7798, 2),
+    ('\u{1D706}', 14076, 2),
+    ('\u{1D707}', 7677, 2),
+    ('\u{1D708}', 14078, 2),
+    ('\u{1D709}', 14080, 2),
+    ('\u{1D70A}', 14082, 2),
+    ('\u{1D70B}', 7796, 2),
+    ('\u{1D70C}', 7800, 2),
+    ('\u{1D70D}', 7802, 2),
+    ('\u{1D70E}', 14084, 2),
+    ('\u{1D70F}', 14086, 2),
+    ('\u{1D710}', 14088, 2),
+    ('\u{1D711}', 7794, 2),
+    ('\u{1D712}', 7946, 2),
+    ('\u{1D713}', 14090, 2),
+    ('\u{1D714}', 14092, 2),
+    ('\u{1D715}', 14094, 3),
+    ('\u{1D716}', 7806, 2),
+    ('\u{1D717}', 7790, 2),
+    ('\u{1D718}', 7798, 2),
+    ('\u{1D719}', 7794, 2),
+    ('\u{1D71A}', 7800, 2),
+    ('\u{1D71B}', 7796, 2),
+    ('\u{1D71C}', 14031, 2),
+    ('\u{1D71D}', 14033, 2),
+    ('\u{1D71E}', 8201, 2),
+    ('\u{1D71F}', 14035, 2),
+    ('\u{1D720}', 14037, 2),
+    ('\u{1D721}', 14039, 2),
+    ('\u{1D722}', 14041, 2),
+    ('\u{1D723}', 7804, 2),
+    ('\u{1D724}', 14043, 2),
+    ('\u{1D725}', 14045, 2),
+    ('\u{1D726}', 14047, 2),
+    ('\u{1D727}', 14049, 2),
+    ('\u{1D728}', 14051, 2),
+    ('\u{1D729}', 14053, 2),
+    ('\u{1D72A}', 14055, 2),
+    ('\u{1D72B}', 8203, 2),
+    ('\u{1D72C}', 14057, 2),
+    ('\u{1D72D}', 7804, 2),
+    ('\u{1D72E}', 7808, 2),
+    ('\u{1D72F}', 14059, 2),
+    ('\u{1D730}', 7792, 2),
+    ('\u{1D731}', 14061, 2),
+    ('\u{1D732}', 14063, 2),
+    ('\u{1D733}', 14065, 2),
+    ('\u{1D734}', 3716, 2),
+    ('\u{1D735}', 14067, 3),
+    ('\u{1D736}', 14070, 2),
+    ('\u{1D737}', 7788, 2),
+    ('\u{1D738}', 7942, 2),
+    ('\u{1D739}', 7944, 2),
+    ('\u{1D73A}', 7806, 2),
+    ('\u{1D73B}', 14072, 2),
+    ('\u{1D73C}', 14074, 2),
+    ('\u{1D73D}', 7790, 2),
+    ('\u{1D73E}', 3507, 2),
+    ('\u{1D73F}', 7798, 2),
+    ('\u{1D740}', 14076, 2),
+    ('\u{1D741}', 7677, 2),
+    ('\u{1D742}', 14078, 2),
+    ('\u{1D743}', 14080, 2),
+    ('\u{1D744}', 14082, 2),
+    ('\u{1D745}', 7796, 2),
+    ('\u{1D746}', 7800, 2),
+    ('\u{1D747}', 7802, 2),
+    ('\u{1D748}', 14084, 2),
+    ('\u{1D749}', 14086, 2),
+    ('\u{1D74A}', 14088, 2),
+    ('\u{1D74B}', 7794, 2),
+    ('\u{1D74C}', 7946, 2),
+    ('\u{1D74D}', 14090, 2),
+    ('\u{1D74E}', 14092, 2),
+    ('\u{1D74F}', 14094, 3),
+    ('\u{1D750}', 7806, 2),
+    ('\u{1D751}', 7790, 2),
+    ('\u{1D752}', 7798, 2),
+    ('\u{1D753}', 7794, 2),
+    ('\u{1D754}', 7800, 2),
+    ('\u{1D755}', 7796, 2),
+    ('\u{1D756}', 14031, 2),
+    ('\u{1D757}', 14033, 2),
+    ('\u{1D758}', 8201, 2),
+    ('\u{1D759}', 14035, 2),
+    ('\u{1D75A}', 14037, 2),
+    ('\u{1D75B}', 14039, 2),
+    ('\u{1D75C}', 14041, 2),
+    ('\u{1D75D}', 7804, 2),
+    ('\u{1D75E}', 14043, 2),
+    ('\u{1D75F}', 14045, 2),
+    ('\u{1D760}', 14047, 2),
+    ('\u{1D761}', 14049, 2),
+    ('\u{1D762}', 14051, 2),
+    ('\u{1D763}', 14053, 2),
+    ('\u{1D764}', 14055, 2),
+    ('\u{1D765}', 8203, 2),
+    ('\u{1D766}', 14057, 2),
+    ('\u{1D767}', 7804, 2),
+    ('\u{1D768}', 7808, 2),
+    ('\u{1D769}', 14059, 2),
+    ('\u{1D76A}', 7792, 2),
+    ('\u{1D76B}', 14061, 2),
+    ('\u{1D76C}', 14063, 2),
+    ('\u{1D76D}', 14065, 2),
+    ('\u{1D76E}', 3716, 2),
+    ('\u{1D76F}', 14067, 3),
+    ('\u{1D770}', 14070, 2),
+    ('\u{1D771}', 7788, 2),
+    ('\u{1D772}', 7942, 2),
+    ('\u{1D773}', 7944, 2),
+    ('\u{1D774}', 7806, 2),
+    ('\u{1D775}', 14072, 2),
+    ('\u{1D776}', 14074, 2),
+    ('\u{1D777}', 7790, 2),
+    ('\u{1D778}', 3507, 2),
+    ('\u{1D779}', 7798, 2),
+    ('\u{1D77A}', 14076, 2),
+    ('\u{1D77B}', 7677, 2),
+    ('\u{1D77C}', 14078, 2),
+    ('\u{1D77D}', 14080, 2),
+    ('\u{1D77E}', 14082, 2),
+    ('\u{1D77F}', 7796, 2),
+    ('\u{1D780}', 7800, 2),
+    ('\u{1D781}', 7802, 2),
+    ('\u{1D782}', 14084, 2),
+    ('\u{1D783}', 14086, 2),
+    ('\u{1D784}', 14088, 2),
+    ('\u{1D785}', 7794, 2),
+    ('\u{1D786}', 7946, 2),
+    ('\u{1D787}', 14090, 2),
+    ('\u{1D788}', 14092, 2),
+    ('\u{1D789}', 14094, 3),
+    ('\u{1D78A}', 7806, 2),
+    ('\u{1D78B}', 7790, 2),
+    ('\u{1D78C}', 7798, 2),
+    ('\u{1D78D}', 7794, 2),
+    ('\u{1D78E}', 7800, 2),
+    ('\u{1D78F}', 7796, 2),
+    ('\u{1D790}', 14031, 2),
+    ('\u{1D791}', 14033, 2),
+    ('\u{1D792}', 8201, 2),
+    ('\u{1D793}', 14035, 2),
+    ('\u{1D794}', 14037, 2),
+    ('\u{1D795}', 14039, 2),
+    ('\u{1D796}', 14041, 2),
+    ('\u{1D797}', 7804, 2),
+    ('\u{1D798}', 14043, 2),
+    ('\u{1D799}', 14045, 2),
+    ('\u{1D79A}', 14047, 2),
+    ('\u{1D79B}', 14049, 2),
+    ('\u{1D79C}', 14051, 2),
+    ('\u{1D79D}', 14053, 2),
+    ('\u{1D79E}', 14055, 2),
+    ('\u{1D79F}', 8203, 2),
+    ('\u{1D7A0}', 14057, 2),
+    ('\u{1D7A1}', 7804, 2),
+    ('\u{1D7A2}', 7808, 2),
+    ('\u{1D7A3}', 14059, 2),
+    ('\u{1D7A4}', 7792, 2),
+    ('\u{1D7A5}', 14061, 2),
+    ('\u{1D7A6}', 14063, 2),
+    ('\u{1D7A7}', 14065, 2),
+    ('\u{1D7A8}', 3716, 2),
+    ('\u{1D7A9}', 14067, 3),
+    ('\u{1D7AA}', 14070, 2),
+    ('\u{1D7AB}', 7788, 2),
+    ('\u{1D7AC}', 7942, 2),
+    ('\u{1D7AD}', 7944, 2),
+    ('\u{1D7AE}', 7806, 2),
+    ('\u{1D7AF}', 14072, 2),
+    ('\u{1D7B0}', 14074, 2),
+    ('\u{1D7B1}', 7790, 2),
+    ('\u{1D7B2}', 3507, 2),
+    ('\u{1D7B3}', 7798, 2),
+    ('\u{1D7B4}', 14076, 2),
+    ('\u{1D7B5}', 7677, 2),
+    ('\u{1D7B6}', 14078, 2),
+    ('\u{1D7B7}', 14080, 2),
+    ('\u{1D7B8}', 14082, 2),
+    ('\u{1D7B9}', 7796, 2),
+    ('\u{1D7BA}', 7800, 2),
+    ('\u{1D7BB}', 7802, 2),
+    ('\u{1D7BC}', 14084, 2),
+    ('\u{1D7BD}', 14086, 2),
+    ('\u{1D7BE}', 14088, 2),
+    ('\u{1D7BF}', 7794, 2),
+    ('\u{1D7C0}', 7946, 2),
+    ('\u{1D7C1}', 14090, 2),
+    ('\u{1D7C2}', 14092, 2),
+    ('\u{1D7C3}', 14094, 3),
+    ('\u{1D7C4}', 7806, 2),
+    ('\u{1D7C5}', 7790, 2),
+    ('\u{1D7C6}', 7798, 2),
+    ('\u{1D7C7}', 7794, 2),
+    ('\u{1D7C8}', 7800, 2),
+    ('\u{1D7C9}', 7796, 2),
+    ('\u{1D7CA}', 14097, 2),
+    ('\u{1D7CB}', 14099, 2),
+    ('\u{1D7CE}', 8138, 1),
+    ('\u{1D7CF}', 7682, 1),
+    ('\u{1D7D0}', 7672, 1),
+    ('\u{1D7D1}', 7673, 1),
+    ('\u{1D7D2}', 8139, 1),
+    ('\u{1D7D3}', 8140, 1),
+    ('\u{1D7D4}', 8141, 1),
+    ('\u{1D7D5}', 8142, 1),
+    ('\u{1D7D6}', 8143, 1),
+    ('\u{1D7D7}', 8144, 1),
+    ('\u{1D7D8}', 8138, 1),
+    ('\u{1D7D9}', 7682, 1),
+    ('\u{1D7DA}', 7672, 1),
+    ('\u{1D7DB}', 7673, 1),
+    ('\u{1D7DC}', 8139, 1),
+    ('\u{1D7DD}', 8140, 1),
+    ('\u{1D7DE}', 8141, 1),
+    ('\u{1D7DF}', 8142, 1),
+    ('\u{1D7E0}', 8143, 1),
+    ('\u{1D7E1}', 8144, 1),
+    ('\u{1D7E2}', 8138, 1),
+    ('\u{1D7E3}', 7682, 1),
+    ('\u{1D7E4}', 7672, 1),
+    ('\u{1D7E5}', 7673, 1),
+    ('\u{1D7E6}', 8139, 1),
+    ('\u{1D7E7}', 8140, 1),
+    ('\u{1D7E8}', 8141, 1),
+    ('\u{1D7E9}', 8142, 1),
+    ('\u{1D7EA}', 8143, 1),
+    ('\u{1D7EB}', 8144, 1),
+    ('\u{1D7EC}', 8138, 1),
+    ('\u{1D7ED}', 7682, 1),
+    ('\u{1D7EE}', 7672, 1),
+    ('\u{1D7EF}', 7673, 1),
+    ('\u{1D7F0}', 8139, 1),
+    ('\u{1D7F1}', 8140, 1),
+    ('\u{1D7F2}', 8141, 1),
+    ('\u{1D7F3}', 8142, 1),
+    ('\u{1D7F4}', 8143, 1),
+    ('\u{1D7F5}', 8144, 1),
+    ('\u{1D7F6}', 8138, 1),
+    ('\u{1D7F7}', 7682, 1),
+    ('\u{1D7F8}', 7672, 1),
+    ('\u{1D7F9}', 7673, 1),
+    ('\u{1D7FA}', 8139, 1),
+    ('\u{1D7FB}', 8140, 1),
+    ('\u{1D7FC}', 8141, 1),
+    ('\u{1D7FD}', 8142, 1),
+    ('\u{1D7FE}', 8143, 1),
+    ('\u{1D7FF}', 8144, 1),
+    ('\u{1E030}', 14101, 2),
+    ('\u{1E031}', 14103, 2),
+    ('\u{1E032}', 14105, 2),
+    ('\u{1E033}', 14107, 2),
+    ('\u{1E034}', 14109, 2),
+    ('\u{1E035}', 14111, 2),
+    ('\u{1E036}', 14113, 2),
+    ('\u{1E037}', 14115, 2),
+    ('\u{1E038}', 14117, 2),
+    ('\u{1E039}', 14119, 2),
+    ('\u{1E03A}', 14121, 2),
+    ('\u{1E03B}', 14123, 2),
+    ('\u{1E03C}', 14125, 2),
+    ('\u{1E03D}', 14127, 2),
+    ('\u{1E03E}', 14129, 2),
+    ('\u{1E03F}', 14131, 2),
+    ('\u{1E040}', 14133, 2),
+    ('\u{1E041}', 14135, 2),
+    ('\u{1E042}', 14137, 2),
+    ('\u{1E043}', 14139, 2),
+    ('\u{1E044}', 14141, 2),
+    ('\u{1E045}', 14143, 2),
+    ('\u{1E046}', 14145, 2),
+    ('\u{1E047}', 14147, 2),
+    ('\u{1E048}', 14149, 2),
+    ('\u{1E049}', 14151, 2),
+    ('\u{1E04A}', 14153, 3),
+    ('\u{1E04B}', 14156, 2),
+    ('\u{1E04C}', 14158, 2),
+    ('\u{1E04D}', 14160, 2),
+    ('\u{1E04E}', 14162, 2),
+    ('\u{1E04F}', 14164, 2),
+    ('\u{1E050}', 14166, 2),
+    ('\u{1E051}', 14101, 2),
+    ('\u{1E052}', 14103, 2),
+    ('\u{1E053}', 14105, 2),
+    ('\u{1E054}', 14107, 2),
+    ('\u{1E055}', 14109, 2),
+    ('\u{1E056}', 14111, 2),
+    ('\u{1E057}', 14113, 2),
+    ('\u{1E058}', 14115, 2),
+    ('\u{1E059}', 14117, 2),
+    ('\u{1E05A}', 14119, 2),
+    ('\u{1E05B}', 14121, 2),
+    ('\u{1E05C}', 14125, 2),
+    ('\u{1E05D}', 14127, 2),
+    ('\u{1E05E}', 14131, 2),
+    ('\u{1E05F}', 14135, 2),
+    ('\u{1E060}', 14137, 2),
+    ('\u{1E061}', 14139, 2),
+    ('\u{1E062}', 14141, 2),
+    ('\u{1E063}', 14143, 2),
+    ('\u{1E064}', 14145, 2),
+    ('\u{1E065}', 12045, 2),
+    ('\u{1E066}', 14147, 2),
+    ('\u{1E067}', 14168, 2),
+    ('\u{1E068}', 14158, 2),
+    ('\u{1E069}', 14170, 2),
+    ('\u{1E06A}', 14172, 2),
+    ('\u{1E06B}', 14174, 2),
+    ('\u{1E06C}', 14176, 3),
+    ('\u{1E06D}', 14179, 2),
+    ('\u{1EE00}', 13734, 2),
+    ('\u{1EE01}', 13736, 2),
+    ('\u{1EE02}', 13744, 2),
+    ('\u{1EE03}', 13750, 2),
+    ('\u{1EE05}', 13788, 2),
+    ('\u{1EE06}', 13756, 2),
+    ('\u{1EE07}', 13746, 2),
+    ('\u{1EE08}', 13766, 2),
+    ('\u{1EE09}', 13790, 2),
+    ('\u{1EE0A}', 13778, 2),
+    ('\u{1EE0B}', 13780, 2),
+    ('\u{1EE0C}', 13782, 2),
+    ('\u{1EE0D}', 13784, 2),
+    ('\u{1EE0E}', 13758, 2),
+    ('\u{1EE0F}', 13770, 2),
+    ('\u{1EE10}', 13774, 2),
+    ('\u{1EE11}', 13762, 2),
+    ('\u{1EE12}', 13776, 2),
+    ('\u{1EE13}', 13754, 2),
+    ('\u{1EE14}', 13760, 2),
+    ('\u{1EE15}', 13740, 2),
+    ('\u{1EE16}', 13742, 2),
+    ('\u{1EE17}', 13748, 2),
+    ('\u{1EE18}', 13752, 2),
+    ('\u{1EE19}', 13764, 2),
+    ('\u{1EE1A}', 13768, 2),
+    ('\u{1EE1B}', 13772, 2),
+    ('\u{1EE1C}', 14181, 2),
+    ('\u{1EE1D}', 12167, 2),
+    ('\u{1EE1E}', 14183, 2),
+    ('\u{1EE1F}', 14185, 2),
+    ('\u{1EE21}', 13736, 2),
+    ('\u{1EE22}', 13744, 2),
+    ('\u{1EE24}', 13786, 2),
+    ('\u{1EE27}', 13746, 2),
+    ('\u{1EE29}', 13790, 2),
+    ('\u{1EE2A}', 13778, 2),
+    ('\u{1EE2B}', 13780, 2),
+    ('\u{1EE2C}', 13782, 2),
+    ('\u{1EE2D}', 13784, 2),
+    ('\u{1EE2E}', 13758, 2),
+    ('\u{1EE2F}', 13770, 2),
+    ('\u{1EE30}', 13774, 2),
+    ('\u{1EE31}', 13762, 2),
+    ('\u{1EE32}', 13776, 2),
+    ('\u{1EE34}', 13760, 2),
+    ('\u{1EE35}', 13740, 2),
+    ('\u{1EE36}', 13742, 2),
+    ('\u{1EE37}', 13748, 2),
+    ('\u{1EE39}', 13764, 2),
+    ('\u{1EE3B}', 13772, 2),
+    ('\u{1EE42}', 13744, 2),
+    ('\u{1EE47}', 13746, 2),
+    ('\u{1EE49}', 13790, 2),
+    ('\u{1EE4B}', 13780, 2),
+    ('\u{1EE4D}', 13784, 2),
+    ('\u{1EE4E}', 13758, 2),
+    ('\u{1EE4F}', 13770, 2),
+    ('\u{1EE51}', 13762, 2),
+    ('\u{1EE52}', 13776, 2),
+    ('\u{1EE54}', 13760, 2),
+    ('\u{1EE57}', 13748, 2),
+    ('\u{1EE59}', 13764, 2),
+    ('\u{1EE5B}', 13772, 2),
+    ('\u{1EE5D}', 12167, 2),
+    ('\u{1EE5F}', 14185, 2),
+    ('\u{1EE61}', 13736, 2),
+    ('\u{1EE62}', 13744, 2),
+    ('\u{1EE64}', 13786, 2),
+    ('\u{1EE67}', 13746, 2),
+    ('\u{1EE68}', 13766, 2),
+    ('\u{1EE69}', 13790, 2),
+    ('\u{1EE6A}', 13778, 2),
+    ('\u{1EE6C}', 13782, 2),
+    ('\u{1EE6D}', 13784, 2),
+    ('\u{1EE6E}', 13758, 2),
+    ('\u{1EE6F}', 13770, 2),
+    ('\u{1EE70}', 13774, 2),
+    ('\u{1EE71}', 13762, 2),
+    ('\u{1EE72}', 13776, 2),
+    ('\u{1EE74}', 13760, 2),
+    ('\u{1EE75}', 13740, 2),
+    ('\u{1EE76}', 13742, 2),
+    ('\u{1EE77}', 13748, 2),
+    ('\u{1EE79}', 13764, 2),
+    ('\u{1EE7A}', 13768, 2),
+    ('\u{1EE7B}', 13772, 2),
+    ('\u{1EE7C}', 14181, 2),
+    ('\u{1EE7E}', 14183, 2),
+    ('\u{1EE80}', 13734, 2),
+    ('\u{1EE81}', 13736, 2),
+    ('\u{1EE82}', 13744, 2),
+    ('\u{1EE83}', 13750, 2),
+    ('\u{1EE84}', 13786, 2),
+    ('\u{1EE85}', 13788, 2),
+    ('\u{1EE86}', 13756, 2),
+    ('\u{1EE87}', 13746, 2),
+    ('\u{1EE88}', 13766, 2),
+    ('\u{1EE89}', 13790, 2),
+    ('\u{1EE8B}', 13780, 2),
+    ('\u{1EE8C}', 13782, 2),
+    ('\u{1EE8D}', 13784, 2),
+    ('\u{1EE8E}', 13758, 2),
+    ('\u{1EE8F}', 13770, 2),
+    ('\u{1EE90}', 13774, 2),
+    ('\u{1EE91}', 13762, 2),
+    ('\u{1EE92}', 13776, 2),
+    ('\u{1EE93}', 13754, 2),
+    ('\u{1EE94}', 13760, 2),
+    ('\u{1EE95}', 13740, 2),
+    ('\u{1EE96}', 13742, 2),
+    ('\u{1EE97}', 13748, 2),
+    ('\u{1EE98}', 13752, 2),
+    ('\u{1EE99}', 13764, 2),
+    ('\u{1EE9A}', 13768, 2),
+    ('\u{1EE9B}', 13772, 2),
+    ('\u{1EEA1}', 13736, 2),
+    ('\u{1EEA2}', 13744, 2),
+    ('\u{1EEA3}', 13750, 2),
+    ('\u{1EEA5}', 13788, 2),
+    ('\u{1EEA6}', 13756, 2),
+    ('\u{1EEA7}', 13746, 2),
+    ('\u{1EEA8}', 13766, 2),
+    ('\u{1EEA9}', 13790, 2),
+    ('\u{1EEAB}', 13780, 2),
+    ('\u{1EEAC}', 13782, 2),
+    ('\u{1EEAD}', 13784, 2),
+    ('\u{1EEAE}', 13758, 2),
+    ('\u{1EEAF}', 13770, 2),
+    ('\u{1EEB0}', 13774, 2),
+    ('\u{1EEB1}', 13762, 2),
+    ('\u{1EEB2}', 13776, 2),
+    ('\u{1EEB3}', 13754, 2),
+    ('\u{1EEB4}', 13760, 2),
+    ('\u{1EEB5}', 13740, 2),
+    ('\u{1EEB6}', 13742, 2),
+    ('\u{1EEB7}', 13748, 2),
+    ('\u{1EEB8}', 13752, 2),
+    ('\u{1EEB9}', 13764, 2),
+    ('\u{1EEBA}', 13768, 2),
+    ('\u{1EEBB}', 13772, 2),
+    ('\u{1F100}', 14187, 2),
+    ('\u{1F101}', 14189, 2),
+    ('\u{1F102}', 14191, 2),
+    ('\u{1F103}', 14193, 2),
+    ('\u{1F104}', 14195, 2),
+    ('\u{1F105}', 14197, 2),
+    ('\u{1F106}', 14199, 2),
+    ('\u{1F107}', 14201, 2),
+    ('\u{1F108}', 14203, 2),
+    ('\u{1F109}', 14205, 2),
+    ('\u{1F10A}', 14207, 2),
+    ('\u{1F110}', 14209, 3),
+    ('\u{1F111}', 14212, 3),
+    ('\u{1F112}', 14215, 3),
+    ('\u{1F113}', 14218, 3),
+    ('\u{1F114}', 14221, 3),
+    ('\u{1F115}', 14224, 3),
+    ('\u{1F116}', 14227, 3),
+    ('\u{1F117}', 14230, 3),
+    ('\u{1F118}', 14233, 3),
+    ('\u{1F119}', 14236, 3),
+    ('\u{1F11A}', 14239, 3),
+    ('\u{1F11B}', 14242, 3),
+    ('\u{1F11C}', 14245, 3),
+    ('\u{1F11D}', 14248, 3),
+    ('\u{1F11E}', 14251, 3),
+    ('\u{1F11F}', 14254, 3),
+    ('\u{1F120}', 14257, 3),
+    ('\u{1F121}', 14260, 3),
+    ('\u{1F122}', 14263, 3),
+    ('\u{1F123}', 14266, 3),
+    ('\u{1F124}', 14269, 3),
+    ('\u{1F125}', 14272, 3),
+    ('\u{1F126}', 14275, 3),
+    ('\u{1F127}', 14278, 3),
+    ('\u{1F128}', 14281, 3),
+    ('\u{1F129}', 14284, 3),
+    ('\u{1F12A}', 14287, 7),
+    ('\u{1F12B}', 8161, 1),
+    ('\u{1F12C}', 7897, 1),
+    ('\u{1F12D}', 14294, 2),
+    ('\u{1F12E}', 14296, 2),
+    ('\u{1F130}', 7878, 1),
+    ('\u{1F131}', 7881, 1),
+    ('\u{1F132}', 8161, 1),
+    ('\u{1F133}', 7882, 1),
+    ('\u{1F134}', 7883, 1),
+    ('\u{1F135}', 8189, 1),
+    ('\u{1F136}', 7886, 1),
+    ('\u{1F137}', 7887, 1),
+    ('\u{1F138}', 7888, 1),
+    ('\u{1F139}', 7889, 1),
+    ('\u{1F13A}', 3718, 1),
+    ('\u{1F13B}', 7890, 1),
+    ('\u{1F13C}', 7891, 1),
+    ('\u{1F13D}', 7892, 1),
+    ('\u{1F13E}', 7893, 1),
+    ('\u{1F13F}', 7896, 1),
+    ('\u{1F140}', 8180, 1),
+    ('\u{1F141}', 7897, 1),
+    ('\u{1F142}', 8593, 1),
+    ('\u{1F143}', 7898, 1),
+    ('\u{1F144}', 7899, 1),
+    ('\u{1F145}', 8295, 1),
+    ('\u{1F146}', 7900, 1),
+    ('\u{1F147}', 8307, 1),
+    ('\u{1F148}', 8594, 1),
+    ('\u{1F149}', 8188, 1),
+    ('\u{1F14A}', 14298, 2),
+    ('\u{1F14B}', 11796, 2),
+    ('\u{1F14C}', 14300, 2),
+    ('\u{1F14D}', 14302, 2),
+    ('\u{1F14E}', 14304, 3),
+    ('\u{1F14F}', 14307, 2),
+    ('\u{1F16A}', 14309, 2),
+    ('\u{1F16B}', 14311, 2),
+    ('\u{1F16C}', 14313, 2),
+    ('\u{1F190}', 14315, 2),
+    ('\u{1F200}', 14317, 6),
+    ('\u{1F201}', 14323, 6),
+    ('\u{1F202}', 10304, 3),
+    ('\u{1F210}', 8796, 3),
+    ('\u{1F211}', 14329, 3),
+    ('\u{1F212}', 14332, 3),
+    ('\u{1F213}', 4184, 6),
+    ('\u{1F214}', 8643, 3),
+    ('\u{1F215}', 6369, 3),
+    ('\u{1F216}', 14335, 3),
+    ('\u{1F217}', 9530, 3),
+    ('\u{1F218}', 14338, 3),
+    ('\u{1F219}', 14341, 3),
+    ('\u{1F21A}', 14344, 3),
+    ('\u{1F21B}', 4850, 3),
+    ('\u{1F21C}', 14347, 3),
+    ('\u{1F21D}', 14350, 3),
+    ('\u{1F21E}', 6187, 3),
+    ('\u{1F21F}', 14353, 3),
+    ('\u{1F220}', 14356, 3),
+    ('\u{1F221}', 14359, 3),
+    ('\u{1F222}', 8901, 3),
+    ('\u{1F223}', 14362, 3),
+    ('\u{1F224}', 14365, 3),
+    ('\u{1F225}', 14368, 3),
+    ('\u{1F226}', 14371, 3),
+    ('\u{1F227}', 14374, 3),
+    ('\u{1F228}', 14377, 3),
+    ('\u{1F229}', 8625, 3),
+    ('\u{1F22A}', 9506, 3),
+    ('\u{1F22B}', 14380, 3),
+    ('\u{1F22C}', 10153, 3),
+    ('\u{1F22D}', 9515, 3),
+    ('\u{1F22E}', 10156, 3),
+    ('\u{1F22F}', 14383, 3),
+    ('\u{1F230}', 9048, 3),
+    ('\u{1F231}', 14386, 3),
+    ('\u{1F232}', 14389, 3),
+    ('\u{1F233}', 14392, 3),
+    ('\u{1F234}', 14395, 3),
+    ('\u{1F235}', 14398, 3),
+    ('\u{1F236}', 10108, 3),
+    ('\u{1F237}', 8826, 3),
+    ('\u{1F238}', 14401, 3),
+    ('\u{1F239}', 6225, 3),
+    ('\u{1F23A}', 14404, 3),
+    ('\u{1F23B}', 14407, 3),
+    ('\u{1F240}', 14410, 9),
+    ('\u{1F241}', 14419, 9),
+    ('\u{1F242}', 14428, 9),
+    ('\u{1F243}', 14437, 9),
+    ('\u{1F244}', 14446, 9),
+    ('\u{1F245}', 14455, 9),
+    ('\u{1F246}', 14464, 9),
+    ('\u{1F247}', 14473, 9),
+    ('\u{1F248}', 14482, 9),
+    ('\u{1F250}', 14491, 3),
+    ('\u{1F251}', 14494, 3),
+    ('\u{1FBF0}', 8138, 1),
+    ('\u{1FBF1}', 7682, 1),
+    ('\u{1FBF2}', 7672, 1),
+    ('\u{1FBF3}', 7673, 1),
+    ('\u{1FBF4}', 8139, 1),
+    ('\u{1FBF5}', 8140, 1),
+    ('\u{1FBF6}', 8141, 1),
+    ('\u{1FBF7}', 8142, 1),
+    ('\u{1FBF8}', 8143, 1),
+    ('\u{1FBF9}', 8144, 1),
+];
+
+pub(super) static DECOMPOSED_CHARS: &str = "\
+    \u{0041}\u{0300}\u{0041}\u{0301}\u{0041}\u{0302}\u{0041}\u{0303}\u{0041}\u{0308}\u{0041}\u{030A}\u{0043}\u{0327}\u{0045}\u{0300}\
+    \u{0045}\u{0301}\u{0045}\u{0302}\u{0045}\u{0308}\u{0049}\u{0300}\u{0049}\u{0301}\u{0049}\u{0302}\u{0049}\u{0308}\u{004E}\u{0303}\
+    \u{004F}\u{0300}\u{004F}\u{0301}\u{004F}\u{0302}\u{004F}\u{0303}\u{004F}\u{0308}\u{0055}\u{0300}\u{0055}\u{0301}\u{0055}\u{0302}\
+    \u{0055}\u{0308}\u{0059}\u{0301}\u{0061}\u{0300}\u{0061}\u{0301}\u{0061}\u{0302}\u{0061}\u{0303}\u{0061}\u{0308}\u{0061}\u{030A}\
+    \u{0063}\u{0327}\u{0065}\u{0300}\u{0065}\u{0301}\u{0065}\u{0302}\u{0065}\u{0308}\u{0069}\u{0300}\u{0069}\u{0301}\u{0069}\u{0302}\
+    \u{0069}\u{0308}\u{006E}\u{0303}\u{006F}\u{0300}\u{006F}\u{0301}\u{006F}\u{0302}\u{006F}\u{0303}\u{006F}\u{0308}\u{0075}\u{0300}\
+    \u{0075}\u{0301}\u{0075}\u{0302}\u{0075}\u{0308}\u{0079}\u{0301}\u{0079}\u{0308}\u{0041}\u{0304}\u{0061}\u{0304}\u{0041}\u{0306}\
+    \u{0061}\u{0306}\u{0041}\u{0328}\u{0061}\u{0328}\u{0043}\u{0301}\u{0063}\u{0301}\u{0043}\u{0302}\u{0063}\u{0302}\u{0043}\u{0307}\
+    \u{0063}\u{0307}\u{0043}\u{030C}\u{0063}\u{030C}\u{0044}\u{030C}\u{0064}\u{030C}\u{0045}\u{0304}\u{0065}\u{0304}\u{0045}\u{0306}\
+    \u{0065}\u{0306}\u{0045}\u{0307}\u{0065}\u{0307}\u{0045}\u{0328}\u{0065}\u{0328}\u{0045}\u{030C}\u{0065}\u{030C}\u{0047}\u{0302}\
+    \u{0067}\u{0302}\u{0047}\u{0306}\u{0067}\u{0306}\u{0047}\u{0307}\u{0067}\u{0307}\u{0047}\u{0327}\u{0067}\u{0327}\u{0048}\u{0302}\
+    \u{0068}\u{0302}\u{0049}\u{0303}\u{0069}\u{0303}\u{0049}\u{0304}\u{0069}\u{0304}\u{0049}\u{0306}\u{0069}\u{0306}\u{0049}\u{0328}\
+    \u{0069}\u{0328}\u{0049}\u{0307}\u{004A}\u{0302}\u{006A}\u{0302}\u{004B}\u{0327}\u{006B}\u{0327}\u{004C}\u{0301}\u{006C}\u{0301}\
+    \u{004C}\u{0327}\u{006C}\u{0327}\u{004C}\u{030C}\u{006C}\u{030C}\u{004E}\u{0301}\u{006E}\u{0301}\u{004E}\u{0327}\u{006E}\u{0327}\
+    \u{004E}\u{030C}\u{006E}\u{030C}\u{004F}\u{0304}\u{006F}\u{0304}\u{004F}\u{0306}\u{006F}\u{0306}\u{004F}\u{030B}\u{006F}\u{030B}\
+    \u{0052}\u{0301}\u{0072}\u{0301}\u{0052}\u{0327}\u{0072}\u{0327}\u{0052}\u{030C}\u{0072}\u{030C}\u{0053}\u{0301}\u{0073}\u{0301}\
+    \u{0053}\u{0302}\u{0073}\u{0302}\u{0053}\u{0327}\u{0073}\u{0327}\u{0053}\u{030C}\u{0073}\u{030C}\u{0054}\u{0327}\u{0074}\u{0327}\
+    \u{0054}\u{030C}\u{0074}\u{030C}\u{0055}\u{0303}\u{0075}\u{0303}\u{0055}\u{0304}\u{0075}\u{0304}\u{0055}\u{0306}\u{0075}\u{0306}\
+    \u{0055}\u{030A}\u{0075}\u{030A}\u{0055}\u{030B}\u{0075}\u{030B}\u{0055}\u{0328}\u{0075}\u{0328}\u{0057}\u{0302}\u{0077}\u{0302}\
+    \u{0059}\u{0302}\u{0079}\u{0302}\u{0059}\u{0308}\u{005A}\u{0301}\u{007A}\u{0301}\u{005A}\u{0307}\u{007A}\u{0307}\u{005A}\u{030C}\
+    \u{007A}\u{030C}\u{004F}\u{031B}\u{006F}\u{031B}\u{0055}\u{031B}\u{0075}\u{031B}\u{0041}\u{030C}\u{0061}\u{030C}\u{0049}\u{030C}\
+    \u{0069}\u{030C}\u{004F}\u{030C}\u{006F}\u{030C}\u{0055}\u{030C}\u{0075}\u{030C}\u{0055}\u{0308}\u{0304}\u{0075}\u{0308}\u{0304}\
+    \u{0055}\u{0308}\u{0301}\u{0075}\u{0308}\u{0301}\u{0055}\u{0308}\u{030C}\u{0075}\u{0308}\u{030C}\u{0055}\u{0308}\u{0300}\u{0075}\
+    \u{0308}\u{0300}\u{0041}\u{0308}\u{0304}\u{0061}\u{0308}\u{0304}\u{0041}\u{0307}\u{0304}\u{0061}\u{0307}\u{0304}\u{00C6}\u{0304}\
+    \u{00E6}\u{0304}\u{0047}\u{030C}\u{0067}\u{030C}\u{004B}\u{030C}\u{006B}\u{030C}\u{004F}\u{0328}\u{006F}\u{0328}\u{004F}\u{0328}\
+    \u{0304}\u{006F}\u{0328}\u{0304}\u{01B7}\u{030C}\u{0292}\u{030C}\u{006A}\u{030C}\u{0047}\u{0301}\u{0067}\u{0301}\u{004E}\u{0300}\
+    \u{006E}\u{0300}\u{0041}\u{030A}\u{0301}\u{0061}\u{030A}\u{0301}\u{00C6}\u{0301}\u{00E6}\u{0301}\u{00D8}\u{0301}\u{00F8}\u{0301}\
+    \u{0041}\u{030F}\u{0061}\u{030F}\u{0041}\u{0311}\u{0061}\u{0311}\u{0045}\u{030F}\u{0065}\u{030F}\u{0045}\u{0311}\u{0065}\u{0311}\
+    \u{0049}\u{030F}\u{0069}\u{030F}\u{0049}\u{0311}\u{0069}\u{0311}\u{004F}\u{030F}\u{006F}\u{030F}\u{004F}\u{0311}\u{006F}\u{0311}\
+    \u{0052}\u{030F}\u{0072}\u{030F}\u{0052}\u{0311}\u{0072}\u{0311}\u{0055}\u{030F}\u{0075}\u{030F}\u{0055}\u{0311}\u{0075}\u{0311}\
+    \u{0053}\u{0326}\u{0073}\u{0326}\u{0054}\u{0326}\u{0074}\u{0326}\u{0048}\u{030C}\u{0068}\u{030C}\u{0041}\u{0307}\u{0061}\u{0307}\
+    \u{0045}\u{0327}\u{0065}\u{0327}\u{004F}\u{0308}\u{0304}\u{006F}\u{0308}\u{0304}\u{004F}\u{0303}\u{0304}\u{006F}\u{0303}\u{0304}\
+    \u{004F}\u{0307}\u{006F}\u{0307}\u{004F}\u{0307}\u{0304}\u{006F}\u{0307}\u{0304}\u{0059}\u{0304}\u{0079}\u{0304}\u{0300}\u{0301}\
+    \u{0313}\u{0308}\u{0301}\u{02B9}\u{003B}\u{00A8}\u{0301}\u{0391}\u{0301}\u{00B7}\u{0395}\u{0301}\u{0397}\u{0301}\u{0399}\u{0301}\
+    \u{039F}\u{0301}\u{03A5}\u{0301}\u{03A9}\u{0301}\u{03B9}\u{0308}\u{0301}\u{0399}\u{0308}\u{03A5}\u{0308}\u{03B1}\u{0301}\u{03B5}\
+    \u{0301}\u{03B7}\u{0301}\u{03B9}\u{0301}\u{03C5}\u{0308}\u{0301}\u{03B9}\u{0308}\u{03C5}\u{0308}\u{03BF}\u{0301}\u{03C5}\u{0301}\
+    \u{03C9}\u{0301}\u{03D2}\u{0301}\u{03D2}\u{0308}\u{0415}\u{0300}\u{0415}\u{0308}\u{0413}\u{0301}\u{0406}\u{0308}\u{041A}\u{0301}\
+    \u{0418}\u{0300}\u{0423}\u{0306}\u{0418}\u{0306}\u{0438}\u{0306}\u{0435}\u{0300}\u{0435}\u{0308}\u{0433}\u{0301}\u{0456}\u{0308}\
+    \u{043A}\u{0301}\u{0438}\u{0300}\u{0443}\u{0306}\u{0474}\u{030F}\u{0475}\u{030F}\u{0416}\u{0306}\u{0436}\u{0306}\u{0410}\u{0306}\
+    \u{0430}\u{0306}\u{0410}\u{0308}\u{0430}\u{0308}\u{0415}\u{0306}\u{0435}\u{0306}\u{04D8}\u{0308}\u{04D9}\u{0308}\u{0416}\u{0308}\
+    \u{0436}\u{0308}\u{0417}\u{0308}\u{0437}\u{0308}\u{0418}\u{0304}\u{0438}\u{0304}\u{0418}\u{0308}\u{0438}\u{0308}\u{041E}\u{0308}\
+    \u{043E}\u{0308}\u{04E8}\u{0308}\u{04E9}\u{0308}\u{042D}\u{0308}\u{044D}\u{0308}\u{0423}\u{0304}\u{0443}\u{0304}\u{0423}\u{0308}\
+    \u{0443}\u{0308}\u{0423}\u{030B}\u{0443}\u{030B}\u{0427}\u{0308}\u{0447}\u{0308}\u{042B}\u{0308}\u{044B}\u{0308}\u{0627}\u{0653}\
+    \u{0627}\u{0654}\u{0648}\u{0654}\u{0627}\u{0655}\u{064A}\u{0654}\u{06D5}\u{0654}\u{06C1}\u{0654}\u{06D2}\u{0654}\u{0928}\u{093C}\
+    \u{0930}\u{093C}\u{0933}\u{093C}\u{0915}\u{093C}\u{0916}\u{093C}\u{0917}\u{093C}\u{091C}\u{093C}\u{0921}\u{093C}\u{0922}\u{093C}\
+    \u{092B}\u{093C}\u{092F}\u{093C}\u{09C7}\u{09BE}\u{09C7}\u{09D7}\u{09A1}\u{09BC}\u{09A2}\u{09BC}\u{09AF}\u{09BC}\u{0A32}\u{0A3C}\
+    \u{0A38}\u{0A3C}\u{0A16}\u{0A3C}\u{0A17}\u{0A3C}\u{0A1C}\u{0A3C}\u{0A2B}\u{0A3C}\u{0B47}\u{0B56}\u{0B47}\u{0B3E}\u{0B47}\u{0B57}\
+    \u{0B21}\u{0B3C}\u{0B22}\u{0B3C}\u{0B92}\u{0BD7}\u{0BC6}\u{0BBE}\u{0BC7}\u{0BBE}\u{0BC6}\u{0BD7}\u{0C46}\u{0C56}\u{0CBF}\u{0CD5}\
+    \u{0CC6}\u{0CD5}\u{0CC6}\u{0CD6}\u{0CC6}\u{0CC2}\u{0CC6}\u{0CC2}\u{0CD5}\u{0D46}\u{0D3E}\u{0D47}\u{0D3E}\u{0D46}\u{0D57}\u{0DD9}\
+    \u{0DCA}\u{0DD9}\u{0DCF}\u{0DD9}\u{0DCF}\u{0DCA}\u{0DD9}\u{0DDF}\u{0F42}\u{0FB7}\u{0F4C}\u{0FB7}\u{0F51}\u{0FB7}\u{0F56}\u{0FB7}\
+    \u{0F5B}\u{0FB7}\u{0F40}\u{0FB5}\u{0F71}\u{0F72}\u{0F71}\u{0F74}\u{0FB2}\u{0F80}\u{0FB3}\u{0F80}\u{0F71}\u{0F80}\u{0F92}\u{0FB7}\
+    \u{0F9C}\u{0FB7}\u{0FA1}\u{0FB7}\u{0FA6}\u{0FB7}\u{0FAB}\u{0FB7}\u{0F90}\u{0FB5}\u{1025}\u{102E}\u{1B05}\u{1B35}\u{1B07}\u{1B35}\
+    \u{1B09}\u{1B35}\u{1B0B}\u{1B35}\u{1B0D}\u{1B35}\u{1B11}\u{1B35}\u{1B3A}\u{1B35}\u{1B3C}\u{1B35}\u{1B3E}\u{1B35}\u{1B3F}\u{1B35}\
+    \u{1B42}\u{1B35}\u{0041}\u{0325}\u{0061}\u{0325}\u{0042}\u{0307}\u{0062}\u{0307}\u{0042}\u{0323}\u{0062}\u{0323}\u{0042}\u{0331}\
+    \u{0062}\u{0331}\u{0043}\u{0327}\u{0301}\u{0063}\u{0327}\u{0301}\u{0044}\u{0307}\u{0064}\u{0307}\u{0044}\u{0323}\u{0064}\u{0323}\
+    \u{0044}\u{0331}\u{0064}\u{0331}\u{0044}\u{0327}\u{0064}\u{0327}\u{0044}\u{032D}\u{0064}\u{032D}\u{0045}\u{0304}\u{0300}\u{0065}\
+    \u{0304}\u{0300}\u{0045}\u{0304}\u{0301}\u{0065}\u{0304}\u{0301}\u{0045}\u{032D}\u{0065}\u{032D}\u{0045}\u{0330}\u{0065}\u{0330}\
+    \u{0045}\u{0327}\u{0306}\u{0065}\u{0327}\u{0306}\u{0046}\u{0307}\u{0066}\u{0307}\u{0047}\u{0304}\u{0067}\u{0304}\u{0048}\u{0307}\
+    \u{0068}\u{0307}\u{0048}\u{0323}\u{0068}\u{0323}\u{0048}\u{0308}\u{0068}\u{0308}\u{0048}\u{0327}\u{0068}\u{0327}\u{0048}\u{032E}\
+    \u{0068}\u{032E}\u{0049}\u{0330}\u{0069}\u{0330}\u{0049}\u{0308}\u{0301}\u{0069}\u{0308}\u{0301}\u{004B}\u{0301}\u{006B}\u{0301}\
+    \u{004B}\u{0323}\u{006B}\u{0323}\u{004B}\u{0331}\u{006B}\u{0331}\u{004C}\u{0323}\u{006C}\u{0323}\u{004C}\u{0323}\u{0304}\u{006C}\
+    \u{0323}\u{0304}\u{004C}\u{0331}\u{006C}\u{0331}\u{004C}\u{032D}\u{006C}\u{032D}\u{004D}\u{0301}\u{006D}\u{0301}\u{004D}\u{0307}\
+    \u{006D}\u{0307}\u{004D}\u{0323}\u{006D}\u{0323}\u{004E}\u{0307}\u{006E}\u{0307}\u{004E}\u{0323}\u{006E}\u{0323}\u{004E}\u{0331}\
+    \u{006E}\u{0331}\u{004E}\u{032D}\u{006E}\u{032D}\u{004F}\u{0303}\u{0301}\u{006F}\u{0303}\u{0301}\u{004F}\u{0303}\u{0308}\u{006F}\
+    \u{0303}\u{0308}\u{004F}\u{0304}\u{0300}\u{006F}\u{0304}\u{0300}\u{004F}\u{0304}\u{0301}\u{006F}\u{0304}\u{0301}\u{0050}\u{0301}\
+    \u{0070}\u{0301}\u{0050}\u{0307}\u{0070}\u{0307}\u{0052}\u{0307}\u{0072}\u{0307}\u{0052}\u{0323}\u{0072}\u{0323}\u{0052}\u{0323}\
+    \u{0304}\u{0072}\u{0323}\u{0304}\u{0052}\u{0331}\u{0072}\u{0331}\u{0053}\u{0307}\u{0073}\u{0307}\u{0053}\u{0323}\u{0073}\u{0323}\
+    \u{0053}\u{0301}\u{0307}\u{0073}\u{0301}\u{0307}\u{0053}\u{030C}\u{0307}\u{0073}\u{030C}\u{0307}\u{0053}\u{0323}\u{0307}\u{0073}\
+    \u{0323}\u{0307}\u{0054}\u{0307}\u{0074}\u{0307}\u{0054}\u{0323}\u{0074}\u{0323}\u{0054}\u{0331}\u{0074}\u{0331}\u{0054}\u{032D}\
+    \u{0074}\u{032D}\u{0055}\u{0324}\u{0075}\u{0324}\u{0055}\u{0330}\u{0075}\u{0330}\u{0055}\u{032D}\u{0075}\u{032D}\u{0055}\u{0303}\
+    \u{0301}\u{0075}\u{0303}\u{0301}\u{0055}\u{0304}\u{0308}\u{0075}\u{0304}\u{0308}\u{0056}\u{0303}\u{0076}\u{0303}\u{0056}\u{0323}\
+    \u{0076}\u{0323}\u{0057}\u{0300}\u{0077}\u{0300}\u{0057}\u{0301}\u{0077}\u{0301}\u{0057}\u{0308}\u{0077}\u{0308}\u{0057}\u{0307}\
+    \u{0077}\u{0307}\u{0057}\u{0323}\u{0077}\u{0323}\u{0058}\u{0307}\u{0078}\u{0307}\u{0058}\u{0308}\u{0078}\u{0308}\u{0059}\u{0307}\
+    \u{0079}\u{0307}\u{005A}\u{0302}\u{007A}\u{0302}\u{005A}\u{0323}\u{007A}\u{0323}\u{005A}\u{0331}\u{007A}\u{0331}\u{0068}\u{0331}\
+    \u{0074}\u{0308}\u{0077}\u{030A}\u{0079}\u{030A}\u{017F}\u{0307}\u{0041}\u{0323}\u{0061}\u{0323}\u{0041}\u{0309}\u{0061}\u{0309}\
+    \u{0041}\u{0302}\u{0301}\u{0061}\u{0302}\u{0301}\u{0041}\u{0302}\u{0300}\u{0061}\u{0302}\u{0300}\u{0041}\u{0302}\u{0309}\u{0061}\
+    \u{0302}\u{0309}\u{0041}\u{0302}\u{0303}\u{0061}\u{0302}\u{0303}\u{0041}\u{0323}\u{0302}\u{0061}\u{0323}\u{0302}\u{0041}\u{0306}\
+    \u{0301}\u{0061}\u{0306}\u{0301}\u{0041}\u{0306}\u{0300}\u{0061}\u{0306}\u{0300}\u{0041}\u{0306}\u{0309}\u{0061}\u{0306}\u{0309}\
+    \u{0041}\u{0306}\u{0303}\u{0061}\u{0306}\u{0303}\u{0041}\u{0323}\u{0306}\u{0061}\u{0323}\u{0306}\u{0045}\u{0323}\u{0065}\u{0323}\
+    \u{0045}\u{0309}\u{0065}\u{0309}\u{0045}\u{0303}\u{0065}\u{0303}\u{0045}\u{0302}\u{0301}\u{0065}\u{0302}\u{0301}\u{0045}\u{0302}\
+    \u{0300}\u{0065}\u{0302}\u{0300}\u{0045}\u{0302}\u{0309}\u{0065}\u{0302}\u{0309}\u{0045}\u{0302}\u{0303}\u{0065}\u{0302}\u{0303}\
+    \u{0045}\u{0323}\u{0302}\u{0065}\u{0323}\u{0302}\u{0049}\u{0309}\u{0069}\u{0309}\u{0049}\u{0323}\u{0069}\u{0323}\u{004F}\u{0323}\
+    \u{006F}\u{0323}\u{004F}\u{0309}\u{006F}\u{0309}\u{004F}\u{0302}\u{0301}\u{006F}\u{0302}\u{0301}\u{004F}\u{0302}\u{0300}\u{006F}\
+    \u{0302}\u{0300}\u{004F}\u{0302}\u{0309}\u{006F}\u{0302}\u{0309}\u{004F}\u{0302}\u{0303}\u{006F}\u{0302}\u{0303}\u{004F}\u{0323}\
+    \u{0302}\u{006F}\u{0323}\u{0302}\u{004F}\u{031B}\u{0301}\u{006F}\u{031B}\u{0301}\u{004F}\u{031B}\u{0300}\u{006F}\u{031B}\u{0300}\
+    \u{004F}\u{031B}\u{0309}\u{006F}\u{031B}\u{0309}\u{004F}\u{031B}\u{0303}\u{006F}\u{031B}\u{0303}\u{004F}\u{031B}\u{0323}\u{006F}\
+    \u{031B}\u{0323}\u{0055}\u{0323}\u{0075}\u{0323}\u{0055}\u{0309}\u{0075}\u{0309}\u{0055}\u{031B}\u{0301}\u{0075}\u{031B}\u{0301}\
+    \u{0055}\u{031B}\u{0300}\u{0075}\u{031B}\u{0300}\u{0055}\u{031B}\u{0309}\u{0075}\u{031B}\u{0309}\u{0055}\u{031B}\u{0303}\u{0075}\
+    \u{031B}\u{0303}\u{0055}\u{031B}\u{0323}\u{0075}\u{031B}\u{0323}\u{0059}\u{0300}\u{0079}\u{0300}\u{0059}\u{0323}\u{0079}\u{0323}\
+    \u{0059}\u{0309}\u{0079}\u{0309}\u{0059}\u{0303}\u{0079}\u{0303}\u{03B1}\u{0313}\u{03B1}\u{0314}\u{03B1}\u{0313}\u{0300}\u{03B1}\
+    \u{0314}\u{0300}\u{03B1}\u{0313}\u{0301}\u{03B1}\u{0314}\u{0301}\u{03B1}\u{0313}\u{0342}\u{03B1}\u{0314}\u{0342}\u{0391}\u{0313}\
+    \u{0391}\u{0314}\u{0391}\u{0313}\u{0300}\u{0391}\u{0314}\u{0300}\u{0391}\u{0313}\u{0301}\u{0391}\u{0314}\u{0301}\u{0391}\u{0313}\
+    \u{0342}\u{0391}\u{0314}\u{0342}\u{03B5}\u{0313}\u{03B5}\u{0314}\u{03B5}\u{0313}\u{0300}\u{03B5}\u{0314}\u{0300}\u{03B5}\u{0313}\
+    \u{0301}\u{03B5}\u{0314}\u{0301}\u{0395}\u{0313}\u{0395}\u{0314}\u{0395}\u{0313}\u{0300}\u{0395}\u{0314}\u{0300}\u{0395}\u{0313}\
+    \u{0301}\u{0395}\u{0314}\u{0301}\u{03B7}\u{0313}\u{03B7}\u{0314}\u{03B7}\u{0313}\u{0300}\u{03B7}\u{0314}\u{0300}\u{03B7}\u{0313}\
+    \u{0301}\u{03B7}\u{0314}\u{0301}\u{03B7}\u{0313}\u{0342}\u{03B7}\u{0314}\u{0342}\u{0397}\u{0313}\u{0397}\u{0314}\u{0397}\u{0313}\
+    \u{0300}\u{0397}\u{0314}\u{0300}\u{0397}\u{0313}\u{0301}\u{0397}\u{0314}\u{0301}\u{0397}\u{0313}\u{0342}\u{0397}\u{0314}\u{0342}\
+    \u{03B9}\u{0313}\u{03B9}\u{0314}\u{03B9}\u{0313}\u{0300}\u{03B9}\u{0314}\u{0300}\u{03B9}\u{0313}\u{0301}\u{03B9}\u{0314}\u{0301}\
+    \u{03B9}\u{0313}\u{0342}\u{03B9}\u{0314}\u{0342}\u{0399}\u{0313}\u{0399}\u{0314}\u{0399}\u{0313}\u{0300}\u{0399}\u{0314}\u{0300}\
+    \u{0399}\u{0313}\u{0301}\u{0399}\u{0314}\u{0301}\u{0399}\u{0313}\u{0342}\u{0399}\u{0314}\u{0342}\u{03BF}\u{0313}\u{03BF}\u{0314}\
+    \u{03BF}\u{0313}\u{0300}\u{03BF}\u{0314}\u{0300}\u{03BF}\u{0313}\u{0301}\u{03BF}\u{0314}\u{0301}\u{039F}\u{0313}\u{039F}\u{0314}\
+    \u{039F}\u{0313}\u{0300}\u{039F}\u{0314}\u{0300}\u{039F}\u{0313}\u{0301}\u{039F}\u{0314}\u{0301}\u{03C5}\u{0313}\u{03C5}\u{0314}\
+    \u{03C5}\u{0313}\u{0300}\u{03C5}\u{0314}\u{0300}\u{03C5}\u{0313}\u{0301}\u{03C5}\u{0314}\u{0301}\u{03C5}\u{0313}\u{0342}\u{03C5}\
+    \u{0314}\u{0342}\u{03A5}\u{0314}\u{03A5}\u{0314}\u{0300}\u{03A5}\u{0314}\u{0301}\u{03A5}\u{0314}\u{0342}\u{03C9}\u{0313}\u{03C9}\
+    \u{0314}\u{03C9}\u{0313}\u{0300}\u{03C9}\u{0314}\u{0300}\u{03C9}\u{0313}\u{0301}\u{03C9}\u{0314}\u{0301}\u{03C9}\u{0313}\u{0342}\
+    \u{03C9}\u{0314}\u{0342}\u{03A9}\u{0313}\u{03A9}\u{0314}\u{03A9}\u{0313}\u{0300}\u{03A9}\u{0314}\u{0300}\u{03A9}\u{0313}\u{0301}\
+    \u{03A9}\u{0314}\u{0301}\u{03A9}\u{0313}\u{0342}\u{03A9}\u{0314}\u{0342}\u{03B1}\u{0300}\u{03B5}\u{0300}\u{03B7}\u{0300}\u{03B9}\
+    \u{0300}\u{03BF}\u{0300}\u{03C5}\u{0300}\u{03C9}\u{0300}\u{03B1}\u{0313}\u{0345}\u{03B1}\u{0314}\u{0345}\u{03B1}\u{0313}\u{0300}\
+    \u{0345}\u{03B1}\u{0314}\u{0300}\u{0345}\u{03B1}\u{0313}\u{0301}\u{0345}\u{03B1}\u{0314}\u{0301}\u{0345}\u{03B1}\u{0313}\u{0342}\
+    \u{0345}\u{03B1}\u{0314}\u{0342}\u{0345}\u{0391}\u{0313}\u{0345}\u{0391}\u{0314}\u{0345}\u{0391}\u{0313}\u{0300}\u{0345}\u{0391}\
+    \u{0314}\u{0300}\u{0345}\u{0391}\u{0313}\u{0301}\u{0345}\u{0391}\u{0314}\u{0301}\u{0345}\u{0391}\u{0313}\u{0342}\u{0345}\u{0391}\
+    \u{0314}\u{0342}\u{0345}\u{03B7}\u{0313}\u{0345}\u{03B7}\u{0314}\u{0345}\u{03B7}\u{0313}\u{0300}\u{0345}\u{03B7}\u{0314}\u{0300}\
+    \u{0345}\u{03B7}\u{0313}\u{0301}\u{0345}\u{03B7}\u{0314}\u{0301}\u{0345}\u{03B7}\u{0313}\u{0342}\u{0345}\u{03B7}\u{0314}\u{0342}\
+    \u{0345}\u{0397}\u{0313}\u{0345}\u{0397}\u{0314}\u{0345}\u{0397}\u{0313}\u{0300}\u{0345}\u{0397}\u{0314}\u{0300}\u{0345}\u{0397}\
+    \u{0313}\u{0301}\u{0345}\u{0397}\u{0314}\u{0301}\u{0345}\u{0397}\u{0313}\u{0342}\u{0345}\u{0397}\u{0314}\u{0342}\u{0345}\u{03C9}\
+    \u{0313}\u{0345}\u{03C9}\u{0314}\u{0345}\u{03C9}\u{0313}\u{0300}\u{0345}\u{03C9}\u{0314}\u{0300}\u{0345}\u{03C9}\u{0313}\u{0301}\
+    \u{0345}\u{03C9}\u{0314}\u{0301}\u{0345}\u{03C9}\u{0313}\u{0342}\u{0345}\u{03C9}\u{0314}\u{0342}\u{0345}\u{03A9}\u{0313}\u{0345}\
+    \u{03A9}\u{0314}\u{0345}\u{03A9}\u{0313}\u{0300}\u{0345}\u{03A9}\u{0314}\u{0300}\u{0345}\u{03A9}\u{0313}\u{0301}\u{0345}\u{03A9}\
+    \u{0314}\u{0301}\u{0345}\u{03A9}\u{0313}\u{0342}\u{0345}\u{03A9}\u{0314}\u{0342}\u{0345}\u{03B1}\u{0306}\u{03B1}\u{0304}\u{03B1}\
+    \u{0300}\u{0345}\u{03B1}\u{0345}\u{03B1}\u{0301}\u{0345}\u{03B1}\u{0342}\u{03B1}\u{0342}\u{0345}\u{0391}\u{0306}\u{0391}\u{0304}\
+    \u{0391}\u{0300}\u{0391}\u{0345}\u{03B9}\u{00A8}\u{0342}\u{03B7}\u{0300}\u{0345}\u{03B7}\u{0345}\u{03B7}\u{0301}\u{0345}\u{03B7}\
+    \u{0342}\u{03B7}\u{0342}\u{0345}\u{0395}\u{0300}\u{0397}\u{0300}\u{0397}\u{0345}\u{1FBF}\u{0300}\u{1FBF}\u{0301}\u{1FBF}\u{0342}\
+    \u{03B9}\u{0306}\u{03B9}\u{0304}\u{03B9}\u{0308}\u{0300}\u{03B9}\u{0342}\u{03B9}\u{0308}\u{0342}\u{0399}\u{0306}\u{0399}\u{0304}\
+    \u{0399}\u{0300}\u{1FFE}\u{0300}\u{1FFE}\u{0301}\u{1FFE}\u{0342}\u{03C5}\u{0306}\u{03C5}\u{0304}\u{03C5}\u{0308}\u{0300}\u{03C1}\
+    \u{0313}\u{03C1}\u{0314}\u{03C5}\u{0342}\u{03C5}\u{0308}\u{0342}\u{03A5}\u{0306}\u{03A5}\u{0304}\u{03A5}\u{0300}\u{03A1}\u{0314}\
+    \u{00A8}\u{0300}\u{0060}\u{03C9}\u{0300}\u{0345}\u{03C9}\u{0345}\u{03C9}\u{0301}\u{0345}\u{03C9}\u{0342}\u{03C9}\u{0342}\u{0345}\
+    \u{039F}\u{0300}\u{03A9}\u{0300}\u{03A9}\u{0345}\u{00B4}\u{2002}\u{2003}\u{03A9}\u{004B}\u{2190}\u{0338}\u{2192}\u{0338}\u{2194}\
+    \u{0338}\u{21D0}\u{0338}\u{21D4}\u{0338}\u{21D2}\u{0338}\u{2203}\u{0338}\u{2208}\u{0338}\u{220B}\u{0338}\u{2223}\u{0338}\u{2225}\
+    \u{0338}\u{223C}\u{0338}\u{2243}\u{0338}\u{2245}\u{0338}\u{2248}\u{0338}\u{003D}\u{0338}\u{2261}\u{0338}\u{224D}\u{0338}\u{003C}\
+    \u{0338}\u{003E}\u{0338}\u{2264}\u{0338}\u{2265}\u{0338}\u{2272}\u{0338}\u{2273}\u{0338}\u{2276}\u{0338}\u{2277}\u{0338}\u{227A}\
+    \u{0338}\u{227B}\u{0338}\u{2282}\u{0338}\u{2283}\u{0338}\u{2286}\u{0338}\u{2287}\u{0338}\u{22A2}\u{0338}\u{22A8}\u{0338}\u{22A9}\
+    \u{0338}\u{22AB}\u{0338}\u{227C}\u{0338}\u{227D}\u{0338}\u{2291}\u{0338}\u{2292}\u{0338}\u{22B2}\u{0338}\u{22B3}\u{0338}\u{22B4}\
+    \u{0338}\u{22B5}\u{0338}\u{3008}\u{3009}\u{2ADD}\u{0338}\u{304B}\u{3099}\u{304D}\u{3099}\u{304F}\u{3099}\u{3051}\u{3099}\u{3053}\
+    \u{3099}\u{3055}\u{3099}\u{3057}\u{3099}\u{3059}\u{3099}\u{305B}\u{3099}\u{305D}\u{3099}\u{305F}\u{3099}\u{3061}\u{3099}\u{3064}\
+    \u{3099}\u{3066}\u{3099}\u{3068}\u{3099}\u{306F}\u{3099}\u{306F}\u{309A}\u{3072}\u{3099}\u{3072}\u{309A}\u{3075}\u{3099}\u{3075}\
+    \u{309A}\u{3078}\u{3099}\u{3078}\u{309A}\u{307B}\u{3099}\u{307B}\u{309A}\u{3046}\u{3099}\u{309D}\u{3099}\u{30AB}\u{3099}\u{30AD}\
+    \u{3099}\u{30AF}\u{3099}\u{30B1}\u{3099}\u{30B3}\u{3099}\u{30B5}\u{3099}\u{30B7}\u{3099}\u{30B9}\u{3099}\u{30BB}\u{3099}\u{30BD}\
+    \u{3099}\u{30BF}\u{3099}\u{30C1}\u{3099}\u{30C4}\u{3099}\u{30C6}\u{3099}\u{30C8}\u{3099}\u{30CF}\u{3099}\u{30CF}\u{309A}\u{30D2}\
+    \u{3099}\u{30D2}\u{309A}\u{30D5}\u{3099}\u{30D5}\u{309A}\u{30D8}\u{3099}\u{30D8}\u{309A}\u{30DB}\u{3099}\u{30DB}\u{309A}\u{30A6}\
+    \u{3099}\u{30EF}\u{3099}\u{30F0}\u{3099}\u{30F1}\u{3099}\u{30F2}\u{3099}\u{30FD}\u{3099}\u{8C48}\u{66F4}\u{8ECA}\u{8CC8}\u{6ED1}\
+    \u{4E32}\u{53E5}\u{9F9C}\u{5951}\u{91D1}\u{5587}\u{5948}\u{61F6}\u{7669}\u{7F85}\u{863F}\u{87BA}\u{88F8}\u{908F}\u{6A02}\u{6D1B}\
+    \u{70D9}\u{73DE}\u{843D}\u{916A}\u{99F1}\u{4E82}\u{5375}\u{6B04}\u{721B}\u{862D}\u{9E1E}\u{5D50}\u{6FEB}\u{85CD}\u{8964}\u{62C9}\
+    \u{81D8}\u{881F}\u{5ECA}\u{6717}\u{6D6A}\u{72FC}\u{90CE}\u{4F86}\u{51B7}\u{52DE}\u{64C4}\u{6AD3}\u{7210}\u{76E7}\u{8001}\u{8606}\
+    \u{865C}\u{8DEF}\u{9732}\u{9B6F}\u{9DFA}\u{788C}\u{797F}\u{7DA0}\u{83C9}\u{9304}\u{9E7F}\u{8AD6}\u{58DF}\u{5F04}\u{7C60}\u{807E}\
+    \u{7262}\u{78CA}\u{8CC2}\u{96F7}\u{58D8}\u{5C62}\u{6A13}\u{6DDA}\u{6F0F}\u{7D2F}\u{7E37}\u{964B}\u{52D2}\u{808B}\u{51DC}\u{51CC}\
+    \u{7A1C}\u{7DBE}\u{83F1}\u{9675}\u{8B80}\u{62CF}\u{8AFE}\u{4E39}\u{5BE7}\u{6012}\u{7387}\u{7570}\u{5317}\u{78FB}\u{4FBF}\u{5FA9}\
+    \u{4E0D}\u{6CCC}\u{6578}\u{7D22}\u{53C3}\u{585E}\u{7701}\u{8449}\u{8AAA}\u{6BBA}\u{8FB0}\u{6C88}\u{62FE}\u{82E5}\u{63A0}\u{7565}\
+    \u{4EAE}\u{5169}\u{51C9}\u{6881}\u{7CE7}\u{826F}\u{8AD2}\u{91CF}\u{52F5}\u{5442}\u{5973}\u{5EEC}\u{65C5}\u{6FFE}\u{792A}\u{95AD}\
+    \u{9A6A}\u{9E97}\u{9ECE}\u{529B}\u{66C6}\u{6B77}\u{8F62}\u{5E74}\u{6190}\u{6200}\u{649A}\u{6F23}\u{7149}\u{7489}\u{79CA}\u{7DF4}\
+    \u{806F}\u{8F26}\u{84EE}\u{9023}\u{934A}\u{5217}\u{52A3}\u{54BD}\u{70C8}\u{88C2}\u{5EC9}\u{5FF5}\u{637B}\u{6BAE}\u{7C3E}\u{7375}\
+    \u{4EE4}\u{56F9}\u{5DBA}\u{601C}\u{73B2}\u{7469}\u{7F9A}\u{8046}\u{9234}\u{96F6}\u{9748}\u{9818}\u{4F8B}\u{79AE}\u{91B4}\u{96B8}\
+    \u{60E1}\u{4E86}\u{50DA}\u{5BEE}\u{5C3F}\u{6599}\u{71CE}\u{7642}\u{84FC}\u{907C}\u{9F8D}\u{6688}\u{962E}\u{5289}\u{677B}\u{67F3}\
+    \u{6D41}\u{6E9C}\u{7409}\u{7559}\u{786B}\u{7D10}\u{985E}\u{516D}\u{622E}\u{9678}\u{502B}\u{5D19}\u{6DEA}\u{8F2A}\u{5F8B}\u{6144}\
+    \u{6817}\u{9686}\u{5229}\u{540F}\u{5C65}\u{6613}\u{674E}\u{68A8}\u{6CE5}\u{7406}\u{75E2}\u{7F79}\u{88CF}\u{88E1}\u{91CC}\u{96E2}\
+    \u{533F}\u{6EBA}\u{541D}\u{71D0}\u{7498}\u{85FA}\u{96A3}\u{9C57}\u{9E9F}\u{6797}\u{6DCB}\u{81E8}\u{7ACB}\u{7B20}\u{7C92}\u{72C0}\
+    \u{7099}\u{8B58}\u{4EC0}\u{8336}\u{523A}\u{5207}\u{5EA6}\u{62D3}\u{7CD6}\u{5B85}\u{6D1E}\u{66B4}\u{8F3B}\u{884C}\u{964D}\u{898B}\
+    \u{5ED3}\u{5140}\u{55C0}\u{585A}\u{6674}\u{51DE}\u{732A}\u{76CA}\u{793C}\u{795E}\u{7965}\u{798F}\u{9756}\u{7CBE}\u{7FBD}\u{8612}\
+    \u{8AF8}\u{9038}\u{90FD}\u{98EF}\u{98FC}\u{9928}\u{9DB4}\u{90DE}\u{96B7}\u{4FAE}\u{50E7}\u{514D}\u{52C9}\u{52E4}\u{5351}\u{559D}\
+    \u{5606}\u{5668}\u{5840}\u{58A8}\u{5C64}\u{5C6E}\u{6094}\u{6168}\u{618E}\u{61F2}\u{654F}\u{65E2}\u{6691}\u{6885}\u{6D77}\u{6E1A}\
+    \u{6F22}\u{716E}\u{722B}\u{7422}\u{7891}\u{793E}\u{7949}\u{7948}\u{7950}\u{7956}\u{795D}\u{798D}\u{798E}\u{7A40}\u{7A81}\u{7BC0}\
+    \u{7E09}\u{7E41}\u{7F72}\u{8005}\u{81ED}\u{8279}\u{8457}\u{8910}\u{8996}\u{8B01}\u{8B39}\u{8CD3}\u{8D08}\u{8FB6}\u{96E3}\u{97FF}\
+    \u{983B}\u{6075}\u{242EE}\u{8218}\u{4E26}\u{51B5}\u{5168}\u{4F80}\u{5145}\u{5180}\u{52C7}\u{52FA}\u{5555}\u{5599}\u{55E2}\u{58B3}\
+    \u{5944}\u{5954}\u{5A62}\u{5B28}\u{5ED2}\u{5ED9}\u{5F69}\u{5FAD}\u{60D8}\u{614E}\u{6108}\u{6160}\u{6234}\u{63C4}\u{641C}\u{6452}\
+    \u{6556}\u{671B}\u{6756}\u{6B79}\u{6EDB}\u{6ECB}\u{701E}\u{77A7}\u{7235}\u{72AF}\u{7471}\u{7506}\u{753B}\u{761D}\u{761F}\u{76DB}\
+    \u{76F4}\u{774A}\u{7740}\u{78CC}\u{7AB1}\u{7C7B}\u{7D5B}\u{7F3E}\u{8352}\u{83EF}\u{8779}\u{8941}\u{8986}\u{8ABF}\u{8ACB}\u{8AED}\
+    \u{8B8A}\u{8F38}\u{9072}\u{9199}\u{9276}\u{967C}\u{97DB}\u{980B}\u{9B12}\u{2284A}\u{22844}\u{233D5}\u{3B9D}\u{4018}\u{4039}\u{25249}\
+    \u{25CD0}\u{27ED3}\u{9F43}\u{9F8E}\u{05D9}\u{05B4}\u{05F2}\u{05B7}\u{05E9}\u{05C1}\u{05E9}\u{05C2}\u{05E9}\u{05BC}\u{05C1}\u{05E9}\
+    \u{05BC}\u{05C2}\u{05D0}\u{05B7}\u{05D0}\u{05B8}\u{05D0}\u{05BC}\u{05D1}\u{05BC}\u{05D2}\u{05BC}\u{05D3}\u{05BC}\u{05D4}\u{05BC}\
+    \u{05D5}\u{05BC}\u{05D6}\u{05BC}\u{05D8}\u{05BC}\u{05D9}\u{05BC}\u{05DA}\u{05BC}\u{05DB}\u{05BC}\u{05DC}\u{05BC}\u{05DE}\u{05BC}\
+    \u{05E0}\u{05BC}\u{05E1}\u{05BC}\u{05E3}\u{05BC}\u{05E4}\u{05BC}\u{05E6}\u{05BC}\u{05E7}\u{05BC}\u{05E8}\u{05BC}\u{05E9}\u{05BC}\
+    \u{05EA}\u{05BC}\u{05D5}\u{05B9}\u{05D1}\u{05BF}\u{05DB}\u{05BF}\u{05E4}\u{05BF}\u{105D2}\u{0307}\u{105DA}\u{0307}\u{11099}\u{110BA}\
+    \u{1109B}\u{110BA}\u{110A5}\u{110BA}\u{11131}\u{11127}\u{11132}\u{11127}\u{11347}\u{1133E}\u{11347}\u{11357}\u{11382}\u{113C9}\u{11384}\u{113BB}\
+    \u{1138B}\u{113C2}\u{11390}\u{113C9}\u{113C2}\u{113C2}\u{113C2}\u{113B8}\u{113C2}\u{113C9}\u{114B9}\u{114BA}\u{114B9}\u{114B0}\u{114B9}\u{114BD}\
+    \u{115B8}\u{115AF}\u{115B9}\u{115AF}\u{11935}\u{11930}\u{1611E}\u{1611E}\u{1611E}\u{16129}\u{1611E}\u{1611F}\u{16129}\u{1611F}\u{1611E}\u{16120}\
+    \u{1611E}\u{1611E}\u{1611F}\u{1611E}\u{16129}\u{1611F}\u{1611E}\u{1611E}\u{16120}\u{16D67}\u{16D67}\u{16D63}\u{16D67}\u{16D63}\u{16D67}\u{16D67}\
+    \u{1D157}\u{1D165}\u{1D158}\u{1D165}\u{1D158}\u{1D165}\u{1D16E}\u{1D158}\u{1D165}\u{1D16F}\u{1D158}\u{1D165}\u{1D170}\u{1D158}\u{1D165}\u{1D171}\
+    \u{1D158}\u{1D165}\u{1D172}\u{1D1B9}\u{1D165}\u{1D1BA}\u{1D165}\u{1D1B9}\u{1D165}\u{1D16E}\u{1D1BA}\u{1D165}\u{1D16E}\u{1D1B9}\u{1D165}\u{1D16F}\
+    \u{1D1BA}\u{1D165}\u{1D16F}\u{4E3D}\u{4E38}\u{4E41}\u{20122}\u{4F60}\u{4FBB}\u{5002}\u{507A}\u{5099}\u{50CF}\u{349E}\u{2063A}\u{5154}\
+    \u{5164}\u{5177}\u{2051C}\u{34B9}\u{5167}\u{518D}\u{2054B}\u{5197}\u{51A4}\u{4ECC}\u{51AC}\u{291DF}\u{51F5}\u{5203}\u{34DF}\u{523B}\
+    \u{5246}\u{5272}\u{5277}\u{3515}\u{5305}\u{5306}\u{5349}\u{535A}\u{5373}\u{537D}\u{537F}\u{20A2C}\u{7070}\u{53CA}\u{53DF}\u{20B63}\
+    \u{53EB}\u{53F1}\u{5406}\u{549E}\u{5438}\u{5448}\u{5468}\u{54A2}\u{54F6}\u{5510}\u{5553}\u{5563}\u{5584}\u{55AB}\u{55B3}\u{55C2}\
+    \u{5716}\u{5717}\u{5651}\u{5674}\u{58EE}\u{57CE}\u{57F4}\u{580D}\u{578B}\u{5832}\u{5831}\u{58AC}\u{214E4}\u{58F2}\u{58F7}\u{5906}\
+    \u{591A}\u{5922}\u{5962}\u{216A8}\u{216EA}\u{59EC}\u{5A1B}\u{5A27}\u{59D8}\u{5A66}\u{36EE}\u{36FC}\u{5B08}\u{5B3E}\u{219C8}\u{5BC3}\
+    \u{5BD8}\u{5BF3}\u{21B18}\u{5BFF}\u{5C06}\u{5F53}\u{5C22}\u{3781}\u{5C60}\u{5CC0}\u{5C8D}\u{21DE4}\u{5D43}\u{21DE6}\u{5D6E}\u{5D6B}\
+    \u{5D7C}\u{5DE1}\u{5DE2}\u{382F}\u{5DFD}\u{5E28}\u{5E3D}\u{5E69}\u{3862}\u{22183}\u{387C}\u{5EB0}\u{5EB3}\u{5EB6}\u{2A392}\u{5EFE}\
+    \u{22331}\u{8201}\u{5F22}\u{38C7}\u{232B8}\u{261DA}\u{5F62}\u{5F6B}\u{38E3}\u{5F9A}\u{5FCD}\u{5FD7}\u{5FF9}\u{6081}\u{393A}\u{391C}\
+    \u{226D4}\u{60C7}\u{6148}\u{614C}\u{617A}\u{61B2}\u{61A4}\u{61AF}\u{61DE}\u{6210}\u{621B}\u{625D}\u{62B1}\u{62D4}\u{6350}\u{22B0C}\
+    \u{633D}\u{62FC}\u{6368}\u{6383}\u{63E4}\u{22BF1}\u{6422}\u{63C5}\u{63A9}\u{3A2E}\u{6469}\u{647E}\u{649D}\u{6477}\u{3A6C}\u{656C}\
+    \u{2300A}\u{65E3}\u{66F8}\u{6649}\u{3B19}\u{3B08}\u{3AE4}\u{5192}\u{5195}\u{6700}\u{669C}\u{80AD}\u{43D9}\u{6721}\u{675E}\u{6753}\
+    \u{233C3}\u{3B49}\u{67FA}\u{6785}\u{6852}\u{2346D}\u{688E}\u{681F}\u{6914}\u{6942}\u{69A3}\u{69EA}\u{6AA8}\u{236A3}\u{6ADB}\u{3C18}\
+    \u{6B21}\u{238A7}\u{6B54}\u{3C4E}\u{6B72}\u{6B9F}\u{6BBB}\u{23A8D}\u{21D0B}\u{23AFA}\u{6C4E}\u{23CBC}\u{6CBF}\u{6CCD}\u{6C67}\u{6D16}\
+    \u{6D3E}\u{6D69}\u{6D78}\u{6D85}\u{23D1E}\u{6D34}\u{6E2F}\u{6E6E}\u{3D33}\u{6EC7}\u{23ED1}\u{6DF9}\u{6F6E}\u{23F5E}\u{23F8E}\u{6FC6}\
+    \u{7039}\u{701B}\u{3D96}\u{704A}\u{707D}\u{7077}\u{70AD}\u{20525}\u{7145}\u{24263}\u{719C}\u{243AB}\u{7228}\u{7250}\u{24608}\u{7280}\
+    \u{7295}\u{24735}\u{24814}\u{737A}\u{738B}\u{3EAC}\u{73A5}\u{3EB8}\u{7447}\u{745C}\u{7485}\u{74CA}\u{3F1B}\u{7524}\u{24C36}\u{753E}\
+    \u{24C92}\u{2219F}\u{7610}\u{24FA1}\u{24FB8}\u{25044}\u{3FFC}\u{4008}\u{250F3}\u{250F2}\u{25119}\u{25133}\u{771E}\u{771F}\u{778B}\u{4046}\
+    \u{4096}\u{2541D}\u{784E}\u{40E3}\u{25626}\u{2569A}\u{256C5}\u{79EB}\u{412F}\u{7A4A}\u{7A4F}\u{2597C}\u{25AA7}\u{7AEE}\u{4202}\u{25BAB}\
+    \u{7BC6}\u{7BC9}\u{4227}\u{25C80}\u{7CD2}\u{42A0}\u{7CE8}\u{7CE3}\u{7D00}\u{25F86}\u{7D63}\u{4301}\u{7DC7}\u{7E02}\u{7E45}\u{4334}\
+    \u{26228}\u{26247}\u{4359}\u{262D9}\u{7F7A}\u{2633E}\u{7F95}\u{7FFA}\u{264DA}\u{26523}\u{8060}\u{265A8}\u{8070}\u{2335F}\u{43D5}\u{80B2}\
+    \u{8103}\u{440B}\u{813E}\u{5AB5}\u{267A7}\u{267B5}\u{23393}\u{2339C}\u{8204}\u{8F9E}\u{446B}\u{8291}\u{828B}\u{829D}\u{52B3}\u{82B1}\
+    \u{82B3}\u{82BD}\u{82E6}\u{26B3C}\u{831D}\u{8363}\u{83AD}\u{8323}\u{83BD}\u{83E7}\u{8353}\u{83CA}\u{83CC}\u{83DC}\u{26C36}\u{26D6B}\
+    \u{26CD5}\u{452B}\u{84F1}\u{84F3}\u{8516}\u{273CA}\u{8564}\u{26F2C}\u{455D}\u{4561}\u{26FB1}\u{270D2}\u{456B}\u{8650}\u{8667}\u{8669}\
+    \u{86A9}\u{8688}\u{870E}\u{86E2}\u{8728}\u{876B}\u{8786}\u{45D7}\u{87E1}\u{8801}\u{45F9}\u{8860}\u{8863}\u{27667}\u{88D7}\u{88DE}\
+    \u{4635}\u{88FA}\u{34BB}\u{278AE}\u{27966}\u{46BE}\u{46C7}\u{8AA0}\u{8C55}\u{27CA8}\u{8CAB}\u{8CC1}\u{8D1B}\u{8D77}\u{27F2F}\u{20804}\
+    \u{8DCB}\u{8DBC}\u{8DF0}\u{208DE}\u{8ED4}\u{285D2}\u{285ED}\u{9094}\u{90F1}\u{9111}\u{2872E}\u{911B}\u{9238}\u{92D7}\u{92D8}\u{927C}\
+    \u{93F9}\u{9415}\u{28BFA}\u{958B}\u{4995}\u{95B7}\u{28D77}\u{49E6}\u{96C3}\u{5DB2}\u{9723}\u{29145}\u{2921A}\u{4A6E}\u{4A76}\u{97E0}\
+    \u{2940A}\u{4AB2}\u{29496}\u{9829}\u{295B6}\u{98E2}\u{4B33}\u{9929}\u{99A7}\u{99C2}\u{99FE}\u{4BCE}\u{29B30}\u{9C40}\u{9CFD}\u{4CCE}\
+    \u{4CED}\u{9D67}\u{2A0CE}\u{4CF8}\u{2A105}\u{2A20E}\u{2A291}\u{9EBB}\u{4D56}\u{9EF9}\u{9EFE}\u{9F05}\u{9F0F}\u{9F16}\u{9F3B}\u{2A600}\
+    \u{0020}\u{0020}\u{0308}\u{0061}\u{0020}\u{0304}\u{0032}\u{0033}\u{0020}\u{0301}\u{03BC}\u{0020}\u{0327}\u{0031}\u{006F}\u{0031}\
+    \u{2044}\u{0034}\u{0031}\u{2044}\u{0032}\u{0033}\u{2044}\u{0034}\u{0049}\u{004A}\u{0069}\u{006A}\u{004C}\u{00B7}\u{006C}\u{00B7}\
+    \u{02BC}\u{006E}\u{0073}\u{0044}\u{005A}\u{030C}\u{0044}\u{007A}\u{030C}\u{0064}\u{007A}\u{030C}\u{004C}\u{004A}\u{004C}\u{006A}\
+    \u{006C}\u{006A}\u{004E}\u{004A}\u{004E}\u{006A}\u{006E}\u{006A}\u{0044}\u{005A}\u{0044}\u{007A}\u{0064}\u{007A}\u{0068}\u{0266}\
+    \u{006A}\u{0072}\u{0279}\u{027B}\u{0281}\u{0077}\u{0079}\u{0020}\u{0306}\u{0020}\u{0307}\u{0020}\u{030A}\u{0020}\u{0328}\u{0020}\
+    \u{0303}\u{0020}\u{030B}\u{0263}\u{006C}\u{0078}\u{0295}\u{0020}\u{0345}\u{0020}\u{0308}\u{0301}\u{03B2}\u{03B8}\u{03A5}\u{03C6}\
+    \u{03C0}\u{03BA}\u{03C1}\u{03C2}\u{0398}\u{03B5}\u{03A3}\u{0565}\u{0582}\u{0627}\u{0674}\u{0648}\u{0674}\u{06C7}\u{0674}\u{064A}\
+    \u{0674}\u{0E4D}\u{0E32}\u{0ECD}\u{0EB2}\u{0EAB}\u{0E99}\u{0EAB}\u{0EA1}\u{0F0B}\u{0FB2}\u{0F71}\u{0F80}\u{0FB3}\u{0F71}\u{0F80}\
+    \u{10DC}\u{0041}\u{00C6}\u{0042}\u{0044}\u{0045}\u{018E}\u{0047}\u{0048}\u{0049}\u{004A}\u{004C}\u{004D}\u{004E}\u{004F}\u{0222}\
+    \u{0050}\u{0052}\u{0054}\u{0055}\u{0057}\u{0250}\u{0251}\u{1D02}\u{0062}\u{0064}\u{0065}\u{0259}\u{025B}\u{025C}\u{0067}\u{006B}\
+    \u{006D}\u{014B}\u{0254}\u{1D16}\u{1D17}\u{0070}\u{0074}\u{0075}\u{1D1D}\u{026F}\u{0076}\u{1D25}\u{03B3}\u{03B4}\u{03C7}\u{0069}\
+    \u{043D}\u{0252}\u{0063}\u{0255}\u{00F0}\u{0066}\u{025F}\u{0261}\u{0265}\u{0268}\u{0269}\u{026A}\u{1D7B}\u{029D}\u{026D}\u{1D85}\
+    \u{029F}\u{0271}\u{0270}\u{0272}\u{0273}\u{0274}\u{0275}\u{0278}\u{0282}\u{0283}\u{01AB}\u{0289}\u{028A}\u{1D1C}\u{028B}\u{028C}\
+    \u{007A}\u{0290}\u{0291}\u{0292}\u{0061}\u{02BE}\u{0020}\u{0313}\u{0020}\u{0342}\u{0020}\u{0308}\u{0342}\u{0020}\u{0313}\u{0300}\
+    \u{0020}\u{0313}\u{0301}\u{0020}\u{0313}\u{0342}\u{0020}\u{0314}\u{0300}\u{0020}\u{0314}\u{0301}\u{0020}\u{0314}\u{0342}\u{0020}\
+    \u{0308}\u{0300}\u{0020}\u{0314}\u{2010}\u{0020}\u{0333}\u{002E}\u{002E}\u{002E}\u{002E}\u{002E}\u{002E}\u{2032}\u{2032}\u{2032}\
+    \u{2032}\u{2032}\u{2035}\u{2035}\u{2035}\u{2035}\u{2035}\u{0021}\u{0021}\u{0020}\u{0305}\u{003F}\u{003F}\u{003F}\u{0021}\u{0021}\
+    \u{003F}\u{2032}\u{2032}\u{2032}\u{2032}\u{0030}\u{0034}\u{0035}\u{0036}\u{0037}\u{0038}\u{0039}\u{002B}\u{2212}\u{003D}\u{0028}\
+    \u{0029}\u{006E}\u{0052}\u{0073}\u{0061}\u{002F}\u{0063}\u{0061}\u{002F}\u{0073}\u{0043}\u{00B0}\u{0043}\u{0063}\u{002F}\u{006F}\
+    \u{0063}\u{002F}\u{0075}\u{0190}\u{00B0}\u{0046}\u{0127}\u{004E}\u{006F}\u{0051}\u{0053}\u{004D}\u{0054}\u{0045}\u{004C}\u{0054}\
+    \u{004D}\u{005A}\u{0046}\u{05D0}\u{05D1}\u{05D2}\u{05D3}\u{0046}\u{0041}\u{0058}\u{0393}\u{03A0}\u{2211}\u{0031}\u{2044}\u{0037}\
+    \u{0031}\u{2044}\u{0039}\u{0031}\u{2044}\u{0031}\u{0030}\u{0031}\u{2044}\u{0033}\u{0032}\u{2044}\u{0033}\u{0031}\u{2044}\u{0035}\
+    \u{0032}\u{2044}\u{0035}\u{0033}\u{2044}\u{0035}\u{0034}\u{2044}\u{0035}\u{0031}\u{2044}\u{0036}\u{0035}\u{2044}\u{0036}\u{0031}\
+    \u{2044}\u{0038}\u{0033}\u{2044}\u{0038}\u{0035}\u{2044}\u{0038}\u{0037}\u{2044}\u{0038}\u{0031}\u{2044}\u{0049}\u{0049}\u{0049}\
+    \u{0049}\u{0049}\u{0049}\u{0056}\u{0056}\u{0056}\u{0049}\u{0056}\u{0049}\u{0049}\u{0056}\u{0049}\u{0049}\u{0049}\u{0049}\u{0058}\
+    \u{0058}\u{0058}\u{0049}\u{0058}\u{0049}\u{0049}\u{0069}\u{0069}\u{0069}\u{0069}\u{0069}\u{0069}\u{0076}\u{0076}\u{0069}\u{0076}\
+    \u{0069}\u{0069}\u{0076}\u{0069}\u{0069}\u{0069}\u{0069}\u{0078}\u{0078}\u{0069}\u{0078}\u{0069}\u{0069}\u{0030}\u{2044}\u{0033}\
+    \u{222B}\u{222B}\u{222B}\u{222B}\u{222B}\u{222E}\u{222E}\u{222E}\u{222E}\u{222E}\u{0031}\u{0030}\u{0031}\u{0031}\u{0031}\u{0032}\
+    \u{0031}\u{0033}\u{0031}\u{0034}\u{0031}\u{0035}\u{0031}\u{0036}\u{0031}\u{0037}\u{0031}\u{0038}\u{0031}\u{0039}\u{0032}\u{0030}\
+    \u{0028}\u{0031}\u{0029}\u{0028}\u{0032}\u{0029}\u{0028}\u{0033}\u{0029}\u{0028}\u{0034}\u{0029}\u{0028}\u{0035}\u{0029}\u{0028}\
+    \u{0036}\u{0029}\u{0028}\u{0037}\u{0029}\u{0028}\u{0038}\u{0029}\u{0028}\u{0039}\u{0029}\u{0028}\u{0031}\u{0030}\u{0029}\u{0028}\
+    \u{0031}\u{0031}\u{0029}\u{0028}\u{0031}\u{0032}\u{0029}\u{0028}\u{0031}\u{0033}\u{0029}\u{0028}\u{0031}\u{0034}\u{0029}\u{0028}\
+    \u{0031}\u{0035}\u{0029}\u{0028}\u{0031}\u{0036}\u{0029}\u{0028}\u{0031}\u{0037}\u{0029}\u{0028}\u{0031}\u{0038}\u{0029}\u{0028}\
+    \u{0031}\u{0039}\u{0029}\u{0028}\u{0032}\u{0030}\u{0029}\u{0031}\u{002E}\u{0032}\u{002E}\u{0033}\u{002E}\u{0034}\u{002E}\u{0035}\
+    \u{002E}\u{0036}\u{002E}\u{0037}\u{002E}\u{0038}\u{002E}\u{0039}\u{002E}\u{0031}\u{0030}\u{002E}\u{0031}\u{0031}\u{002E}\u{0031}\
+    \u{0032}\u{002E}\u{0031}\u{0033}\u{002E}\u{0031}\u{0034}\u{002E}\u{0031}\u{0035}\u{002E}\u{0031}\u{0036}\u{002E}\u{0031}\u{0037}\
+    \u{002E}\u{0031}\u{0038}\u{002E}\u{0031}\u{0039}\u{002E}\u{0032}\u{0030}\u{002E}\u{0028}\u{0061}\u{0029}\u{0028}\u{0062}\u{0029}\
+    \u{0028}\u{0063}\u{0029}\u{0028}\u{0064}\u{0029}\u{0028}\u{0065}\u{0029}\u{0028}\u{0066}\u{0029}\u{0028}\u{0067}\u{0029}\u{0028}\
+    \u{0068}\u{0029}\u{0028}\u{0069}\u{0029}\u{0028}\u{006A}\u{0029}\u{0028}\u{006B}\u{0029}\u{0028}\u{006C}\u{0029}\u{0028}\u{006D}\
+    \u{0029}\u{0028}\u{006E}\u{0029}\u{0028}\u{006F}\u{0029}\u{0028}\u{0070}\u{0029}\u{0028}\u{0071}\u{0029}\u{0028}\u{0072}\u{0029}\
+    \u{0028}\u{0073}\u{0029}\u{0028}\u{0074}\u{0029}\u{0028}\u{0075}\u{0029}\u{0028}\u{0076}\u{0029}\u{0028}\u{0077}\u{0029}\u{0028}\
+    \u{0078}\u{0029}\u{0028}\u{0079}\u{0029}\u{0028}\u{007A}\u{0029}\u{0053}\u{0059}\u{0071}\u{222B}\u{222B}\u{222B}\u{222B}\u{003A}\
+    \u{003A}\u{003D}\u{003D}\u{003D}\u{003D}\u{003D}\u{003D}\u{2D61}\u{6BCD}\u{9F9F}\u{4E00}\u{4E28}\u{4E36}\u{4E3F}\u{4E59}\u{4E85}\
+    \u{4E8C}\u{4EA0}\u{4EBA}\u{513F}\u{5165}\u{516B}\u{5182}\u{5196}\u{51AB}\u{51E0}\u{5200}\u{52F9}\u{5315}\u{531A}\u{5338}\u{5341}\
+    \u{535C}\u{5369}\u{5382}\u{53B6}\u{53C8}\u{53E3}\u{56D7}\u{571F}\u{58EB}\u{5902}\u{590A}\u{5915}\u{5927}\u{5B50}\u{5B80}\u{5BF8}\
+    \u{5C0F}\u{5C38}\u{5C71}\u{5DDB}\u{5DE5}\u{5DF1}\u{5DFE}\u{5E72}\u{5E7A}\u{5E7F}\u{5EF4}\u{5F0B}\u{5F13}\u{5F50}\u{5F61}\u{5F73}\
+    \u{5FC3}\u{6208}\u{6236}\u{624B}\u{652F}\u{6534}\u{6587}\u{6597}\u{65A4}\u{65B9}\u{65E0}\u{65E5}\u{66F0}\u{6708}\u{6728}\u{6B20}\
+    \u{6B62}\u{6BB3}\u{6BCB}\u{6BD4}\u{6BDB}\u{6C0F}\u{6C14}\u{6C34}\u{706B}\u{722A}\u{7236}\u{723B}\u{723F}\u{7247}\u{7259}\u{725B}\
+    \u{72AC}\u{7384}\u{7389}\u{74DC}\u{74E6}\u{7518}\u{751F}\u{7528}\u{7530}\u{758B}\u{7592}\u{7676}\u{767D}\u{76AE}\u{76BF}\u{76EE}\
+    \u{77DB}\u{77E2}\u{77F3}\u{793A}\u{79B8}\u{79BE}\u{7A74}\u{7AF9}\u{7C73}\u{7CF8}\u{7F36}\u{7F51}\u{7F8A}\u{800C}\u{8012}\u{8033}\
+    \u{807F}\u{8089}\u{81E3}\u{81EA}\u{81F3}\u{81FC}\u{820C}\u{821B}\u{821F}\u{826E}\u{8272}\u{8278}\u{864D}\u{866B}\u{8840}\u{897E}\
+    \u{89D2}\u{8A00}\u{8C37}\u{8C46}\u{8C78}\u{8C9D}\u{8D64}\u{8D70}\u{8DB3}\u{8EAB}\u{8F9B}\u{8FB5}\u{9091}\u{9149}\u{91C6}\u{9577}\
+    \u{9580}\u{961C}\u{96B6}\u{96B9}\u{96E8}\u{9751}\u{975E}\u{9762}\u{9769}\u{97CB}\u{97ED}\u{97F3}\u{9801}\u{98A8}\u{98DB}\u{98DF}\
+    \u{9996}\u{9999}\u{99AC}\u{9AA8}\u{9AD8}\u{9ADF}\u{9B25}\u{9B2F}\u{9B32}\u{9B3C}\u{9B5A}\u{9CE5}\u{9E75}\u{9EA5}\u{9EC3}\u{9ECD}\
+    \u{9ED1}\u{9EFD}\u{9F0E}\u{9F13}\u{9F20}\u{9F4A}\u{9F52}\u{9FA0}\u{3012}\u{5344}\u{5345}\u{0020}\u{3099}\u{0020}\u{309A}\u{3088}\
+    \u{308A}\u{30B3}\u{30C8}\u{1100}\u{1101}\u{11AA}\u{1102}\u{11AC}\u{11AD}\u{1103}\u{1104}\u{1105}\u{11B0}\u{11B1}\u{11B2}\u{11B3}\
+    \u{11B4}\u{11B5}\u{111A}\u{1106}\u{1107}\u{1108}\u{1121}\u{1109}\u{110A}\u{110B}\u{110C}\u{110D}\u{110E}\u{110F}\u{1110}\u{1111}\
+    \u{1112}\u{1161}\u{1162}\u{1163}\u{1164}\u{1165}\u{1166}\u{1167}\u{1168}\u{1169}\u{116A}\u{116B}\u{116C}\u{116D}\u{116E}\u{116F}\
+    \u{1170}\u{1171}\u{1172}\u{1173}\u{1174}\u{1175}\u{1160}\u{1114}\u{1115}\u{11C7}\u{11C8}\u{11CC}\u{11CE}\u{11D3}\u{11D7}\u{11D9}\
+    \u{111C}\u{11DD}\u{11DF}\u{111D}\u{111E}\u{1120}\u{1122}\u{1123}\u{1127}\u{1129}\u{112B}\u{112C}\u{112D}\u{112E}\u{112F}\u{1132}\
+    \u{1136}\u{1140}\u{1147}\u{114C}\u{11F1}\u{11F2}\u{1157}\u{1158}\u{1159}\u{1184}\u{1185}\u{1188}\u{1191}\u{1192}\u{1194}\u{119E}\
+    \u{11A1}\u{4E09}\u{56DB}\u{4E0A}\u{4E2D}\u{4E0B}\u{7532}\u{4E19}\u{4E01}\u{5929}\u{5730}\u{0028}\u{1100}\u{0029}\u{0028}\u{1102}\
+    \u{0029}\u{0028}\u{1103}\u{0029}\u{0028}\u{1105}\u{0029}\u{0028}\u{1106}\u{0029}\u{0028}\u{1107}\u{0029}\u{0028}\u{1109}\u{0029}\
+    \u{0028}\u{110B}\u{0029}\u{0028}\u{110C}\u{0029}\u{0028}\u{110E}\u{0029}\u{0028}\u{110F}\u{0029}\u{0028}\u{1110}\u{0029}\u{0028}\
+    \u{1111}\u{0029}\u{0028}\u{1112}\u{0029}\u{0028}\u{1100}\u{1161}\u{0029}\u{0028}\u{1102}\u{1161}\u{0029}\u{0028}\u{1103}\u{1161}\
+    \u{0029}\u{0028}\u{1105}\u{1161}\u{0029}\u{0028}\u{1106}\u{1161}\u{0029}\u{0028}\u{1107}\u{1161}\u{0029}\u{0028}\u{1109}\u{1161}\
+    \u{0029}\u{0028}\u{110B}\u{1161}\u{0029}\u{0028}\u{110C}\u{1161}\u{0029}\u{0028}\u{110E}\u{1161}\u{0029}\u{0028}\u{110F}\u{1161}\
+    \u{0029}\u{0028}\u{1110}\u{1161}\u{0029}\u{0028}\u{1111}\u{1161}\u{0029}\u{0028}\u{1112}\u{1161}\u{0029}\u{0028}\u{110C}\u{116E}\
+    \u{0029}\u{0028}\u{110B}\u{1169}\u{110C}\u{1165}\u{11AB}\u{0029}\u{0028}\u{110B}\u{1169}\u{1112}\u{116E}\u{0029}\u{0028}\u{4E00}\
+    \u{0029}\u{0028}\u{4E8C}\u{0029}\u{0028}\u{4E09}\u{0029}\u{0028}\u{56DB}\u{0029}\u{0028}\u{4E94}\u{0029}\u{0028}\u{516D}\u{0029}\
+    \u{0028}\u{4E03}\u{0029}\u{0028}\u{516B}\u{0029}\u{0028}\u{4E5D}\u{0029}\u{0028}\u{5341}\u{0029}\u{0028}\u{6708}\u{0029}\u{0028}\
+    \u{706B}\u{0029}\u{0028}\u{6C34}\u{0029}\u{0028}\u{6728}\u{0029}\u{0028}\u{91D1}\u{0029}\u{0028}\u{571F}\u{0029}\u{0028}\u{65E5}\
+    \u{0029}\u{0028}\u{682A}\u{0029}\u{0028}\u{6709}\u{0029}\u{0028}\u{793E}\u{0029}\u{0028}\u{540D}\u{0029}\u{0028}\u{7279}\u{0029}\
+    \u{0028}\u{8CA1}\u{0029}\u{0028}\u{795D}\u{0029}\u{0028}\u{52B4}\u{0029}\u{0028}\u{4EE3}\u{0029}\u{0028}\u{547C}\u{0029}\u{0028}\
+    \u{5B66}\u{0029}\u{0028}\u{76E3}\u{0029}\u{0028}\u{4F01}\u{0029}\u{0028}\u{8CC7}\u{0029}\u{0028}\u{5354}\u{0029}\u{0028}\u{796D}\
+    \u{0029}\u{0028}\u{4F11}\u{0029}\u{0028}\u{81EA}\u{0029}\u{0028}\u{81F3}\u{0029}\u{554F}\u{5E7C}\u{7B8F}\u{0050}\u{0054}\u{0045}\
+    \u{0032}\u{0031}\u{0032}\u{0032}\u{0032}\u{0033}\u{0032}\u{0034}\u{0032}\u{0035}\u{0032}\u{0036}\u{0032}\u{0037}\u{0032}\u{0038}\
+    \u{0032}\u{0039}\u{0033}\u{0030}\u{0033}\u{0031}\u{0033}\u{0032}\u{0033}\u{0033}\u{0033}\u{0034}\u{0033}\u{0035}\u{1100}\u{1161}\
+    \u{1102}\u{1161}\u{1103}\u{1161}\u{1105}\u{1161}\u{1106}\u{1161}\u{1107}\u{1161}\u{1109}\u{1161}\u{110B}\u{1161}\u{110C}\u{1161}\
+    \u{110E}\u{1161}\u{110F}\u{1161}\u{1110}\u{1161}\u{1111}\u{1161}\u{1112}\u{1161}\u{110E}\u{1161}\u{11B7}\u{1100}\u{1169}\u{110C}\
+    \u{116E}\u{110B}\u{1174}\u{110B}\u{116E}\u{4E94}\u{4E03}\u{4E5D}\u{682A}\u{6709}\u{540D}\u{7279}\u{8CA1}\u{52B4}\u{79D8}\u{7537}\
+    \u{9069}\u{512A}\u{5370}\u{6CE8}\u{9805}\u{4F11}\u{5199}\u{6B63}\u{5DE6}\u{53F3}\u{533B}\u{5B97}\u{5B66}\u{76E3}\u{4F01}\u{8CC7}\
+    \u{5354}\u{591C}\u{0033}\u{0036}\u{0033}\u{0037}\u{0033}\u{0038}\u{0033}\u{0039}\u{0034}\u{0030}\u{0034}\u{0031}\u{0034}\u{0032}\
+    \u{0034}\u{0033}\u{0034}\u{0034}\u{0034}\u{0035}\u{0034}\u{0036}\u{0034}\u{0037}\u{0034}\u{0038}\u{0034}\u{0039}\u{0035}\u{0030}\
+    \u{0031}\u{6708}\u{0032}\u{6708}\u{0033}\u{6708}\u{0034}\u{6708}\u{0035}\u{6708}\u{0036}\u{6708}\u{0037}\u{6708}\u{0038}\u{6708}\
+    \u{0039}\u{6708}\u{0031}\u{0030}\u{6708}\u{0031}\u{0031}\u{6708}\u{0031}\u{0032}\u{6708}\u{0048}\u{0067}\u{0065}\u{0072}\u{0067}\
+    \u{0065}\u{0056}\u{004C}\u{0054}\u{0044}\u{30A2}\u{30A4}\u{30A6}\u{30A8}\u{30AA}\u{30AB}\u{30AD}\u{30AF}\u{30B1}\u{30B3}\u{30B5}\
+    \u{30B7}\u{30B9}\u{30BB}\u{30BD}\u{30BF}\u{30C1}\u{30C4}\u{30C6}\u{30C8}\u{30CA}\u{30CB}\u{30CC}\u{30CD}\u{30CE}\u{30CF}\u{30D2}\
+    \u{30D5}\u{30D8}\u{30DB}\u{30DE}\u{30DF}\u{30E0}\u{30E1}\u{30E2}\u{30E4}\u{30E6}\u{30E8}\u{30E9}\u{30EA}\u{30EB}\u{30EC}\u{30ED}\
+    \u{30EF}\u{30F0}\u{30F1}\u{30F2}\u{4EE4}\u{548C}\u{30A2}\u{30CF}\u{309A}\u{30FC}\u{30C8}\u{30A2}\u{30EB}\u{30D5}\u{30A1}\u{30A2}\
+    \u{30F3}\u{30D8}\u{309A}\u{30A2}\u{30A2}\u{30FC}\u{30EB}\u{30A4}\u{30CB}\u{30F3}\u{30AF}\u{3099}\u{30A4}\u{30F3}\u{30C1}\u{30A6}\
+    \u{30A9}\u{30F3}\u{30A8}\u{30B9}\u{30AF}\u{30FC}\u{30C8}\u{3099}\u{30A8}\u{30FC}\u{30AB}\u{30FC}\u{30AA}\u{30F3}\u{30B9}\u{30AA}\
+    \u{30FC}\u{30E0}\u{30AB}\u{30A4}\u{30EA}\u{30AB}\u{30E9}\u{30C3}\u{30C8}\u{30AB}\u{30ED}\u{30EA}\u{30FC}\u{30AB}\u{3099}\u{30ED}\
+    \u{30F3}\u{30AB}\u{3099}\u{30F3}\u{30DE}\u{30AD}\u{3099}\u{30AB}\u{3099}\u{30AD}\u{3099}\u{30CB}\u{30FC}\u{30AD}\u{30E5}\u{30EA}\
+    \u{30FC}\u{30AD}\u{3099}\u{30EB}\u{30BF}\u{3099}\u{30FC}\u{30AD}\u{30ED}\u{30AD}\u{30ED}\u{30AF}\u{3099}\u{30E9}\u{30E0}\u{30AD}\
+    \u{30ED}\u{30E1}\u{30FC}\u{30C8}\u{30EB}\u{30AD}\u{30ED}\u{30EF}\u{30C3}\u{30C8}\u{30AF}\u{3099}\u{30E9}\u{30E0}\u{30AF}\u{3099}\
+    \u{30E9}\u{30E0}\u{30C8}\u{30F3}\u{30AF}\u{30EB}\u{30BB}\u{3099}\u{30A4}\u{30ED}\u{30AF}\u{30ED}\u{30FC}\u{30CD}\u{30B1}\u{30FC}\
+    \u{30B9}\u{30B3}\u{30EB}\u{30CA}\u{30B3}\u{30FC}\u{30DB}\u{309A}\u{30B5}\u{30A4}\u{30AF}\u{30EB}\u{30B5}\u{30F3}\u{30C1}\u{30FC}\
+    \u{30E0}\u{30B7}\u{30EA}\u{30F3}\u{30AF}\u{3099}\u{30BB}\u{30F3}\u{30C1}\u{30BB}\u{30F3}\u{30C8}\u{30BF}\u{3099}\u{30FC}\u{30B9}\
+    \u{30C6}\u{3099}\u{30B7}\u{30C8}\u{3099}\u{30EB}\u{30C8}\u{30F3}\u{30CA}\u{30CE}\u{30CE}\u{30C3}\u{30C8}\u{30CF}\u{30A4}\u{30C4}\
+    \u{30CF}\u{309A}\u{30FC}\u{30BB}\u{30F3}\u{30C8}\u{30CF}\u{309A}\u{30FC}\u{30C4}\u{30CF}\u{3099}\u{30FC}\u{30EC}\u{30EB}\u{30D2}\
+    \u{309A}\u{30A2}\u{30B9}\u{30C8}\u{30EB}\u{30D2}\u{309A}\u{30AF}\u{30EB}\u{30D2}\u{309A}\u{30B3}\u{30D2}\u{3099}\u{30EB}\u{30D5}\
+    \u{30A1}\u{30E9}\u{30C3}\u{30C8}\u{3099}\u{30D5}\u{30A3}\u{30FC}\u{30C8}\u{30D5}\u{3099}\u{30C3}\u{30B7}\u{30A7}\u{30EB}\u{30D5}\
+    \u{30E9}\u{30F3}\u{30D8}\u{30AF}\u{30BF}\u{30FC}\u{30EB}\u{30D8}\u{309A}\u{30BD}\u{30D8}\u{309A}\u{30CB}\u{30D2}\u{30D8}\u{30EB}\
+    \u{30C4}\u{30D8}\u{309A}\u{30F3}\u{30B9}\u{30D8}\u{309A}\u{30FC}\u{30B7}\u{3099}\u{30D8}\u{3099}\u{30FC}\u{30BF}\u{30DB}\u{309A}\
+    \u{30A4}\u{30F3}\u{30C8}\u{30DB}\u{3099}\u{30EB}\u{30C8}\u{30DB}\u{30F3}\u{30DB}\u{309A}\u{30F3}\u{30C8}\u{3099}\u{30DB}\u{30FC}\
+    \u{30EB}\u{30DB}\u{30FC}\u{30F3}\u{30DE}\u{30A4}\u{30AF}\u{30ED}\u{30DE}\u{30A4}\u{30EB}\u{30DE}\u{30C3}\u{30CF}\u{30DE}\u{30EB}\
+    \u{30AF}\u{30DE}\u{30F3}\u{30B7}\u{30E7}\u{30F3}\u{30DF}\u{30AF}\u{30ED}\u{30F3}\u{30DF}\u{30EA}\u{30DF}\u{30EA}\u{30CF}\u{3099}\
+    \u{30FC}\u{30EB}\u{30E1}\u{30AB}\u{3099}\u{30E1}\u{30AB}\u{3099}\u{30C8}\u{30F3}\u{30E1}\u{30FC}\u{30C8}\u{30EB}\u{30E4}\u{30FC}\
+    \u{30C8}\u{3099}\u{30E4}\u{30FC}\u{30EB}\u{30E6}\u{30A2}\u{30F3}\u{30EA}\u{30C3}\u{30C8}\u{30EB}\u{30EA}\u{30E9}\u{30EB}\u{30D2}\
+    \u{309A}\u{30FC}\u{30EB}\u{30FC}\u{30D5}\u{3099}\u{30EB}\u{30EC}\u{30E0}\u{30EC}\u{30F3}\u{30C8}\u{30B1}\u{3099}\u{30F3}\u{30EF}\
+    \u{30C3}\u{30C8}\u{0030}\u{70B9}\u{0031}\u{70B9}\u{0032}\u{70B9}\u{0033}\u{70B9}\u{0034}\u{70B9}\u{0035}\u{70B9}\u{0036}\u{70B9}\
+    \u{0037}\u{70B9}\u{0038}\u{70B9}\u{0039}\u{70B9}\u{0031}\u{0030}\u{70B9}\u{0031}\u{0031}\u{70B9}\u{0031}\u{0032}\u{70B9}\u{0031}\
+    \u{0033}\u{70B9}\u{0031}\u{0034}\u{70B9}\u{0031}\u{0035}\u{70B9}\u{0031}\u{0036}\u{70B9}\u{0031}\u{0037}\u{70B9}\u{0031}\u{0038}\
+    \u{70B9}\u{0031}\u{0039}\u{70B9}\u{0032}\u{0030}\u{70B9}\u{0032}\u{0031}\u{70B9}\u{0032}\u{0032}\u{70B9}\u{0032}\u{0033}\u{70B9}\
+    \u{0032}\u{0034}\u{70B9}\u{0068}\u{0050}\u{0061}\u{0064}\u{0061}\u{0041}\u{0055}\u{0062}\u{0061}\u{0072}\u{006F}\u{0056}\u{0070}\
+    \u{0063}\u{0064}\u{006D}\u{0064}\u{006D}\u{0032}\u{0064}\u{006D}\u{0033}\u{0049}\u{0055}\u{5E73}\u{6210}\u{662D}\u{548C}\u{5927}\
+    \u{6B63}\u{660E}\u{6CBB}\u{682A}\u{5F0F}\u{4F1A}\u{793E}\u{0070}\u{0041}\u{006E}\u{0041}\u{03BC}\u{0041}\u{006D}\u{0041}\u{006B}\
+    \u{0041}\u{004B}\u{0042}\u{004D}\u{0042}\u{0047}\u{0042}\u{0063}\u{0061}\u{006C}\u{006B}\u{0063}\u{0061}\u{006C}\u{0070}\u{0046}\
+    \u{006E}\u{0046}\u{03BC}\u{0046}\u{03BC}\u{0067}\u{006D}\u{0067}\u{006B}\u{0067}\u{0048}\u{007A}\u{006B}\u{0048}\u{007A}\u{004D}\
+    \u{0048}\u{007A}\u{0047}\u{0048}\u{007A}\u{0054}\u{0048}\u{007A}\u{03BC}\u{006C}\u{006D}\u{006C}\u{0064}\u{006C}\u{006B}\u{006C}\
+    \u{0066}\u{006D}\u{006E}\u{006D}\u{03BC}\u{006D}\u{006D}\u{006D}\u{0063}\u{006D}\u{006B}\u{006D}\u{006D}\u{006D}\u{0032}\u{0063}\
+    \u{006D}\u{0032}\u{006D}\u{0032}\u{006B}\u{006D}\u{0032}\u{006D}\u{006D}\u{0033}\u{0063}\u{006D}\u{0033}\u{006D}\u{0033}\u{006B}\
+    \u{006D}\u{0033}\u{006D}\u{2215}\u{0073}\u{006D}\u{2215}\u{0073}\u{0032}\u{0050}\u{0061}\u{006B}\u{0050}\u{0061}\u{004D}\u{0050}\
+    \u{0061}\u{0047}\u{0050}\u{0061}\u{0072}\u{0061}\u{0064}\u{0072}\u{0061}\u{0064}\u{2215}\u{0073}\u{0072}\u{0061}\u{0064}\u{2215}\
+    \u{0073}\u{0032}\u{0070}\u{0073}\u{006E}\u{0073}\u{03BC}\u{0073}\u{006D}\u{0073}\u{0070}\u{0056}\u{006E}\u{0056}\u{03BC}\u{0056}\
+    \u{006D}\u{0056}\u{006B}\u{0056}\u{004D}\u{0056}\u{0070}\u{0057}\u{006E}\u{0057}\u{03BC}\u{0057}\u{006D}\u{0057}\u{006B}\u{0057}\
+    \u{004D}\u{0057}\u{006B}\u{03A9}\u{004D}\u{03A9}\u{0061}\u{002E}\u{006D}\u{002E}\u{0042}\u{0071}\u{0063}\u{0063}\u{0063}\u{0064}\
+    \u{0043}\u{2215}\u{006B}\u{0067}\u{0043}\u{006F}\u{002E}\u{0064}\u{0042}\u{0047}\u{0079}\u{0068}\u{0061}\u{0048}\u{0050}\u{0069}\
+    \u{006E}\u{004B}\u{004B}\u{004B}\u{004D}\u{006B}\u{0074}\u{006C}\u{006D}\u{006C}\u{006E}\u{006C}\u{006F}\u{0067}\u{006C}\u{0078}\
+    \u{006D}\u{0062}\u{006D}\u{0069}\u{006C}\u{006D}\u{006F}\u{006C}\u{0050}\u{0048}\u{0070}\u{002E}\u{006D}\u{002E}\u{0050}\u{0050}\
+    \u{004D}\u{0050}\u{0052}\u{0073}\u{0072}\u{0053}\u{0076}\u{0057}\u{0062}\u{0056}\u{2215}\u{006D}\u{0041}\u{2215}\u{006D}\u{0031}\
+    \u{65E5}\u{0032}\u{65E5}\u{0033}\u{65E5}\u{0034}\u{65E5}\u{0035}\u{65E5}\u{0036}\u{65E5}\u{0037}\u{65E5}\u{0038}\u{65E5}\u{0039}\
+    \u{65E5}\u{0031}\u{0030}\u{65E5}\u{0031}\u{0031}\u{65E5}\u{0031}\u{0032}\u{65E5}\u{0031}\u{0033}\u{65E5}\u{0031}\u{0034}\u{65E5}\
+    \u{0031}\u{0035}\u{65E5}\u{0031}\u{0036}\u{65E5}\u{0031}\u{0037}\u{65E5}\u{0031}\u{0038}\u{65E5}\u{0031}\u{0039}\u{65E5}\u{0032}\
+    \u{0030}\u{65E5}\u{0032}\u{0031}\u{65E5}\u{0032}\u{0032}\u{65E5}\u{0032}\u{0033}\u{65E5}\u{0032}\u{0034}\u{65E5}\u{0032}\u{0035}\
+    \u{65E5}\u{0032}\u{0036}\u{65E5}\u{0032}\u{0037}\u{65E5}\u{0032}\u{0038}\u{65E5}\u{0032}\u{0039}\u{65E5}\u{0033}\u{0030}\u{65E5}\
+    \u{0033}\u{0031}\u{65E5}\u{0067}\u{0061}\u{006C}\u{044A}\u{044C}\u{A76F}\u{0126}\u{0153}\u{A727}\u{AB37}\u{026B}\u{AB52}\u{028D}\
+    \u{0066}\u{0066}\u{0066}\u{0069}\u{0066}\u{006C}\u{0066}\u{0066}\u{0069}\u{0066}\u{0066}\u{006C}\u{0073}\u{0074}\u{0574}\u{0576}\
+    \u{0574}\u{0565}\u{0574}\u{056B}\u{057E}\u{0576}\u{0574}\u{056D}\u{05E2}\u{05D4}\u{05DB}\u{05DC}\u{05DD}\u{05E8}\u{05EA}\u{05D0}\
+    \u{05DC}\u{0671}\u{067B}\u{067E}\u{0680}\u{067A}\u{067F}\u{0679}\u{06A4}\u{06A6}\u{0684}\u{0683}\u{0686}\u{0687}\u{068D}\u{068C}\
+    \u{068E}\u{0688}\u{0698}\u{0691}\u{06A9}\u{06AF}\u{06B3}\u{06B1}\u{06BA}\u{06BB}\u{06C1}\u{06BE}\u{06D2}\u{06AD}\u{06C7}\u{06C6}\
+    \u{06C8}\u{06CB}\u{06C5}\u{06C9}\u{06D0}\u{0649}\u{064A}\u{0654}\u{0627}\u{064A}\u{0654}\u{06D5}\u{064A}\u{0654}\u{0648}\u{064A}\
+    \u{0654}\u{06C7}\u{064A}\u{0654}\u{06C6}\u{064A}\u{0654}\u{06C8}\u{064A}\u{0654}\u{06D0}\u{064A}\u{0654}\u{0649}\u{06CC}\u{064A}\
+    \u{0654}\u{062C}\u{064A}\u{0654}\u{062D}\u{064A}\u{0654}\u{0645}\u{064A}\u{0654}\u{064A}\u{0628}\u{062C}\u{0628}\u{062D}\u{0628}\
+    \u{062E}\u{0628}\u{0645}\u{0628}\u{0649}\u{0628}\u{064A}\u{062A}\u{062C}\u{062A}\u{062D}\u{062A}\u{062E}\u{062A}\u{0645}\u{062A}\
+    \u{0649}\u{062A}\u{064A}\u{062B}\u{062C}\u{062B}\u{0645}\u{062B}\u{0649}\u{062B}\u{064A}\u{062C}\u{062D}\u{062C}\u{0645}\u{062D}\
+    \u{062C}\u{062D}\u{0645}\u{062E}\u{062C}\u{062E}\u{062D}\u{062E}\u{0645}\u{0633}\u{062C}\u{0633}\u{062D}\u{0633}\u{062E}\u{0633}\
+    \u{0645}\u{0635}\u{062D}\u{0635}\u{0645}\u{0636}\u{062C}\u{0636}\u{062D}\u{0636}\u{062E}\u{0636}\u{0645}\u{0637}\u{062D}\u{0637}\
+    \u{0645}\u{0638}\u{0645}\u{0639}\u{062C}\u{0639}\u{0645}\u{063A}\u{062C}\u{063A}\u{0645}\u{0641}\u{062C}\u{0641}\u{062D}\u{0641}\
+    \u{062E}\u{0641}\u{0645}\u{0641}\u{0649}\u{0641}\u{064A}\u{0642}\u{062D}\u{0642}\u{0645}\u{0642}\u{0649}\u{0642}\u{064A}\u{0643}\
+    \u{0627}\u{0643}\u{062C}\u{0643}\u{062D}\u{0643}\u{062E}\u{0643}\u{0644}\u{0643}\u{0645}\u{0643}\u{0649}\u{0643}\u{064A}\u{0644}\
+    \u{062C}\u{0644}\u{062D}\u{0644}\u{062E}\u{0644}\u{0645}\u{0644}\u{0649}\u{0644}\u{064A}\u{0645}\u{062C}\u{0645}\u{062D}\u{0645}\
+    \u{062E}\u{0645}\u{0645}\u{0645}\u{0649}\u{0645}\u{064A}\u{0646}\u{062C}\u{0646}\u{062D}\u{0646}\u{062E}\u{0646}\u{0645}\u{0646}\
+    \u{0649}\u{0646}\u{064A}\u{0647}\u{062C}\u{0647}\u{0645}\u{0647}\u{0649}\u{0647}\u{064A}\u{064A}\u{062C}\u{064A}\u{062D}\u{064A}\
+    \u{062E}\u{064A}\u{0645}\u{064A}\u{0649}\u{064A}\u{064A}\u{0630}\u{0670}\u{0631}\u{0670}\u{0649}\u{0670}\u{0020}\u{064C}\u{0651}\
+    \u{0020}\u{064D}\u{0651}\u{0020}\u{064E}\u{0651}\u{0020}\u{064F}\u{0651}\u{0020}\u{0650}\u{0651}\u{0020}\u{0651}\u{0670}\u{064A}\
+    \u{0654}\u{0631}\u{064A}\u{0654}\u{0632}\u{064A}\u{0654}\u{0646}\u{0628}\u{0631}\u{0628}\u{0632}\u{0628}\u{0646}\u{062A}\u{0631}\
+    \u{062A}\u{0632}\u{062A}\u{0646}\u{062B}\u{0631}\u{062B}\u{0632}\u{062B}\u{0646}\u{0645}\u{0627}\u{0646}\u{0631}\u{0646}\u{0632}\
+    \u{0646}\u{0646}\u{064A}\u{0631}\u{064A}\u{0632}\u{064A}\u{0646}\u{064A}\u{0654}\u{062E}\u{064A}\u{0654}\u{0647}\u{0628}\u{0647}\
+    \u{062A}\u{0647}\u{0635}\u{062E}\u{0644}\u{0647}\u{0646}\u{0647}\u{0647}\u{0670}\u{064A}\u{0647}\u{062B}\u{0647}\u{0633}\u{0647}\
+    \u{0634}\u{0645}\u{0634}\u{0647}\u{0640}\u{064E}\u{0651}\u{0640}\u{064F}\u{0651}\u{0640}\u{0650}\u{0651}\u{0637}\u{0649}\u{0637}\
+    \u{064A}\u{0639}\u{0649}\u{0639}\u{064A}\u{063A}\u{0649}\u{063A}\u{064A}\u{0633}\u{0649}\u{0633}\u{064A}\u{0634}\u{0649}\u{0634}\
+    \u{064A}\u{062D}\u{0649}\u{062D}\u{064A}\u{062C}\u{0649}\u{062C}\u{064A}\u{062E}\u{0649}\u{062E}\u{064A}\u{0635}\u{0649}\u{0635}\
+    \u{064A}\u{0636}\u{0649}\u{0636}\u{064A}\u{0634}\u{062C}\u{0634}\u{062D}\u{0634}\u{062E}\u{0634}\u{0631}\u{0633}\u{0631}\u{0635}\
+    \u{0631}\u{0636}\u{0631}\u{0627}\u{064B}\u{062A}\u{062C}\u{0645}\u{062A}\u{062D}\u{062C}\u{062A}\u{062D}\u{0645}\u{062A}\u{062E}\
+    \u{0645}\u{062A}\u{0645}\u{062C}\u{062A}\u{0645}\u{062D}\u{062A}\u{0645}\u{062E}\u{062C}\u{0645}\u{062D}\u{062D}\u{0645}\u{064A}\
+    \u{062D}\u{0645}\u{0649}\u{0633}\u{062D}\u{062C}\u{0633}\u{062C}\u{062D}\u{0633}\u{062C}\u{0649}\u{0633}\u{0645}\u{062D}\u{0633}\
+    \u{0645}\u{062C}\u{0633}\u{0645}\u{0645}\u{0635}\u{062D}\u{062D}\u{0635}\u{0645}\u{0645}\u{0634}\u{062D}\u{0645}\u{0634}\u{062C}\
+    \u{064A}\u{0634}\u{0645}\u{062E}\u{0634}\u{0645}\u{0645}\u{0636}\u{062D}\u{0649}\u{0636}\u{062E}\u{0645}\u{0637}\u{0645}\u{062D}\
+    \u{0637}\u{0645}\u{0645}\u{0637}\u{0645}\u{064A}\u{0639}\u{062C}\u{0645}\u{0639}\u{0645}\u{0645}\u{0639}\u{0645}\u{0649}\u{063A}\
+    \u{0645}\u{0645}\u{063A}\u{0645}\u{064A}\u{063A}\u{0645}\u{0649}\u{0641}\u{062E}\u{0645}\u{0642}\u{0645}\u{062D}\u{0642}\u{0645}\
+    \u{0645}\u{0644}\u{062D}\u{0645}\u{0644}\u{062D}\u{064A}\u{0644}\u{062D}\u{0649}\u{0644}\u{062C}\u{062C}\u{0644}\u{062E}\u{0645}\
+    \u{0644}\u{0645}\u{062D}\u{0645}\u{062D}\u{062C}\u{0645}\u{062D}\u{0645}\u{0645}\u{062D}\u{064A}\u{0645}\u{062C}\u{062D}\u{0645}\
+    \u{062C}\u{0645}\u{0645}\u{062E}\u{062C}\u{0645}\u{062E}\u{0645}\u{0645}\u{062C}\u{062E}\u{0647}\u{0645}\u{062C}\u{0647}\u{0645}\
+    \u{0645}\u{0646}\u{062D}\u{0645}\u{0646}\u{062D}\u{0649}\u{0646}\u{062C}\u{0645}\u{0646}\u{062C}\u{0649}\u{0646}\u{0645}\u{064A}\
+    \u{0646}\u{0645}\u{0649}\u{064A}\u{0645}\u{0645}\u{0628}\u{062E}\u{064A}\u{062A}\u{062C}\u{064A}\u{062A}\u{062C}\u{0649}\u{062A}\
+    \u{062E}\u{064A}\u{062A}\u{062E}\u{0649}\u{062A}\u{0645}\u{064A}\u{062A}\u{0645}\u{0649}\u{062C}\u{0645}\u{064A}\u{062C}\u{062D}\
+    \u{0649}\u{062C}\u{0645}\u{0649}\u{0633}\u{062E}\u{0649}\u{0635}\u{062D}\u{064A}\u{0634}\u{062D}\u{064A}\u{0636}\u{062D}\u{064A}\
+    \u{0644}\u{062C}\u{064A}\u{0644}\u{0645}\u{064A}\u{064A}\u{062D}\u{064A}\u{064A}\u{062C}\u{064A}\u{064A}\u{0645}\u{064A}\u{0645}\
+    \u{0645}\u{064A}\u{0642}\u{0645}\u{064A}\u{0646}\u{062D}\u{064A}\u{0639}\u{0645}\u{064A}\u{0643}\u{0645}\u{064A}\u{0646}\u{062C}\
+    \u{062D}\u{0645}\u{062E}\u{064A}\u{0644}\u{062C}\u{0645}\u{0643}\u{0645}\u{0645}\u{062C}\u{062D}\u{064A}\u{062D}\u{062C}\u{064A}\
+    \u{0645}\u{062C}\u{064A}\u{0641}\u{0645}\u{064A}\u{0628}\u{062D}\u{064A}\u{0633}\u{062E}\u{064A}\u{0646}\u{062C}\u{064A}\u{0635}\
+    \u{0644}\u{06D2}\u{0642}\u{0644}\u{06D2}\u{0627}\u{0644}\u{0644}\u{0647}\u{0627}\u{0643}\u{0628}\u{0631}\u{0645}\u{062D}\u{0645}\
+    \u{062F}\u{0635}\u{0644}\u{0639}\u{0645}\u{0631}\u{0633}\u{0648}\u{0644}\u{0639}\u{0644}\u{064A}\u{0647}\u{0648}\u{0633}\u{0644}\
+    \u{0645}\u{0635}\u{0644}\u{0649}\u{0635}\u{0644}\u{0649}\u{0020}\u{0627}\u{0644}\u{0644}\u{0647}\u{0020}\u{0639}\u{0644}\u{064A}\
+    \u{0647}\u{0020}\u{0648}\u{0633}\u{0644}\u{0645}\u{062C}\u{0644}\u{0020}\u{062C}\u{0644}\u{0627}\u{0644}\u{0647}\u{0631}\u{06CC}\
+    \u{0627}\u{0644}\u{002C}\u{3001}\u{3002}\u{003A}\u{0021}\u{003F}\u{3016}\u{3017}\u{2014}\u{2013}\u{005F}\u{007B}\u{007D}\u{3014}\
+    \u{3015}\u{3010}\u{3011}\u{300A}\u{300B}\u{300C}\u{300D}\u{300E}\u{300F}\u{005B}\u{005D}\u{0023}\u{0026}\u{002A}\u{002D}\u{003C}\
+    \u{003E}\u{005C}\u{0024}\u{0025}\u{0040}\u{0020}\u{064B}\u{0640}\u{064B}\u{0020}\u{064C}\u{0020}\u{064D}\u{0020}\u{064E}\u{0640}\
+    \u{064E}\u{0020}\u{064F}\u{0640}\u{064F}\u{0020}\u{0650}\u{0640}\u{0650}\u{0020}\u{0651}\u{0640}\u{0651}\u{0020}\u{0652}\u{0640}\
+    \u{0652}\u{0621}\u{0627}\u{0628}\u{0629}\u{062A}\u{062B}\u{062C}\u{062D}\u{062E}\u{062F}\u{0630}\u{0631}\u{0632}\u{0633}\u{0634}\
+    \u{0635}\u{0636}\u{0637}\u{0638}\u{0639}\u{063A}\u{0641}\u{0642}\u{0643}\u{0644}\u{0645}\u{0646}\u{0647}\u{0648}\u{064A}\u{0644}\
+    \u{0627}\u{0653}\u{0644}\u{0627}\u{0654}\u{0644}\u{0627}\u{0655}\u{0644}\u{0627}\u{0022}\u{0027}\u{002F}\u{005E}\u{007C}\u{007E}\
+    \u{2985}\u{2986}\u{30FB}\u{30A1}\u{30A3}\u{30A5}\u{30A7}\u{30A9}\u{30E3}\u{30E5}\u{30E7}\u{30C3}\u{30FC}\u{30F3}\u{3099}\u{309A}\
+    \u{00A2}\u{00A3}\u{00AC}\u{00A6}\u{00A5}\u{20A9}\u{2502}\u{2190}\u{2191}\u{2192}\u{2193}\u{25A0}\u{25CB}\u{02D0}\u{02D1}\u{00E6}\
+    \u{0299}\u{0253}\u{02A3}\u{AB66}\u{02A5}\u{02A4}\u{0256}\u{0257}\u{1D91}\u{0258}\u{025E}\u{02A9}\u{0264}\u{0262}\u{0260}\u{029B}\
+    \u{029C}\u{0267}\u{0284}\u{02AA}\u{02AB}\u{026C}\u{1DF04}\u{A78E}\u{026E}\u{1DF05}\u{028E}\u{1DF06}\u{00F8}\u{0276}\u{0277}\u{027A}\
+    \u{1DF08}\u{027D}\u{027E}\u{0280}\u{02A8}\u{02A6}\u{AB67}\u{02A7}\u{0288}\u{2C71}\u{028F}\u{02A1}\u{02A2}\u{0298}\u{01C0}\u{01C1}\
+    \u{01C2}\u{1DF0A}\u{1DF1E}\u{0131}\u{0237}\u{0391}\u{0392}\u{0394}\u{0395}\u{0396}\u{0397}\u{0399}\u{039A}\u{039B}\u{039C}\u{039D}\
+    \u{039E}\u{039F}\u{03A1}\u{03A4}\u{03A6}\u{03A7}\u{03A8}\u{2207}\u{03B1}\u{03B6}\u{03B7}\u{03BB}\u{03BD}\u{03BE}\u{03BF}\u{03C3}\
+    \u{03C4}\u{03C5}\u{03C8}\u{03C9}\u{2202}\u{03DC}\u{03DD}\u{0430}\u{0431}\u{0432}\u{0433}\u{0434}\u{0435}\u{0436}\u{0437}\u{0438}\
+    \u{043A}\u{043B}\u{043C}\u{043E}\u{043F}\u{0440}\u{0441}\u{0442}\u{0443}\u{0444}\u{0445}\u{0446}\u{0447}\u{0448}\u{044B}\u{044D}\
+    \u{044E}\u{A689}\u{04D9}\u{0456}\u{0458}\u{04E9}\u{04AF}\u{04CF}\u{0491}\u{0455}\u{045F}\u{04AB}\u{A651}\u{04B1}\u{066E}\u{06A1}\
+    \u{066F}\u{0030}\u{002E}\u{0030}\u{002C}\u{0031}\u{002C}\u{0032}\u{002C}\u{0033}\u{002C}\u{0034}\u{002C}\u{0035}\u{002C}\u{0036}\
+    \u{002C}\u{0037}\u{002C}\u{0038}\u{002C}\u{0039}\u{002C}\u{0028}\u{0041}\u{0029}\u{0028}\u{0042}\u{0029}\u{0028}\u{0043}\u{0029}\
+    \u{0028}\u{0044}\u{0029}\u{0028}\u{0045}\u{0029}\u{0028}\u{0046}\u{0029}\u{0028}\u{0047}\u{0029}\u{0028}\u{0048}\u{0029}\u{0028}\
+    \u{0049}\u{0029}\u{0028}\u{004A}\u{0029}\u{0028}\u{004B}\u{0029}\u{0028}\u{004C}\u{0029}\u{0028}\u{004D}\u{0029}\u{0028}\u{004E}\
+    \u{0029}\u{0028}\u{004F}\u{0029}\u{0028}\u{0050}\u{0029}\u{0028}\u{0051}\u{0029}\u{0028}\u{0052}\u{0029}\u{0028}\u{0053}\u{0029}\
+    \u{0028}\u{0054}\u{0029}\u{0028}\u{0055}\u{0029}\u{0028}\u{0056}\u{0029}\u{0028}\u{0057}\u{0029}\u{0028}\u{0058}\u{0029}\u{0028}\
+    \u{0059}\u{0029}\u{0028}\u{005A}\u{0029}\u{3014}\u{0053}\u{3015}\u{0043}\u{0044}\u{0057}\u{005A}\u{0048}\u{0056}\u{0053}\u{0044}\
+    \u{0053}\u{0053}\u{0050}\u{0050}\u{0056}\u{0057}\u{0043}\u{004D}\u{0043}\u{004D}\u{0044}\u{004D}\u{0052}\u{0044}\u{004A}\u{307B}\
+    \u{304B}\u{30B3}\u{30B3}\u{5B57}\u{53CC}\u{89E3}\u{4EA4}\u{6620}\u{7121}\u{524D}\u{5F8C}\u{65B0}\u{521D}\u{7D42}\u{8CA9}\u{58F0}\
+    \u{5439}\u{6F14}\u{6295}\u{6355}\u{904A}\u{6307}\u{6253}\u{7981}\u{7A7A}\u{5408}\u{6E80}\u{7533}\u{55B6}\u{914D}\u{3014}\u{672C}\
+    \u{3015}\u{3014}\u{4E09}\u{3015}\u{3014}\u{4E8C}\u{3015}\u{3014}\u{5B89}\u{3015}\u{3014}\u{70B9}\u{3015}\u{3014}\u{6253}\u{3015}\
+    \u{3014}\u{76D7}\u{3015}\u{3014}\u{52DD}\u{3015}\u{3014}\u{6557}\u{3015}\u{5F97}\u{53EF}\
+";
+
+pub(super) static COMPOSITIONS: [(char, char, char); 961] = [
+    ('\u{003C}', '\u{0338}', '\u{226E}'),
+    ('\u{003D}', '\u{0338}', '\u{2260}'),
+    ('\u{003E}', '\u{0338}', '\u{226F}'),
+    ('\u{0041}', '\u{0300}', '\u{00C0}'),
+    ('\u{0041}', '\u{0301}', '\u{00C1}'),
+    ('\u{0041}', '\u{0302}', '\u{00C2}'),
+    ('\u{0041}', '\u{0303}', '\u{00C3}'),
+    ('\u{0041}', '\u{0304}', '\u{0100}'),
+    ('\u{0041}', '\u{0306}', '\u{0102}'),
+    ('\u{0041}', '\u{0307}', '\u{0226}'),
+    ('\u{0041}', '\u{0308}', '\u{00C4}'),
+    ('\u{0041}', '\u{0309}', '\u{1EA2}'),
+    ('\u{0041}', '\u{030A}', '\u{00C5}'),
+    ('\u{0041}', '\u{030C}', '\u{01CD}'),
+    ('\u{0041}', '\u{030F}', '\u{0200}'),
+    ('\u{0041}', '\u{0311}', '\u{0202}'),
+    ('\u{0041}', '\u{0323}', '\u{1EA0}'),
+    ('\u{0041}', '\u{0325}', '\u{1E00}'),
+    ('\u{0041}', '\u{0328}', '\u{0104}'),
+    ('\u{0042}', '\u{0307}', '\u{1E02}'),
+    ('\u{0042}', '\u{0323}', '\u{1E04}'),
+    ('\u{0042}', '\u{0331}', '\u{1E06}'),
+    ('\u{0043}', '\u{0301}', '\u{0106}'),
+    ('\u{0043}', '\u{0302}', '\u{0108}'),
+    ('\u{0043}', '\u{0307}', '\u{010A}'),
+    ('\u{0043}', '\u{030C}', '\u{010C}'),
+    ('\u{0043}', '\u{0327}', '\u{00C7}'),
+    ('\u{0044}', '\u{0307}', '\u{1E0A}'),
+    ('\u{0044}', '\u{030C}', '\u{010E}'),
+    ('\u{0044}', '\u{0323}', '\u{1E0C}'),
+    ('\u{0044}', '\u{0327}', '\u{1E10}'),
+    ('\u{0044}', '\u{032D}', '\u{1E12}'),
+    ('\u{0044}', '\u{0331}', '\u{1E0E}'),
+    ('\u{0045}', '\u{0300}', '\u{00C8}'),
+    ('\u{0045}', '\u{0301}', '\u{00C9}'),
+    ('\u{0045}', '\u{0302}', '\u{00CA}'),
+    ('\u{0045}', '\u{0303}', '\u{1EBC}'),
+    ('\u{0045}', '\u{0304}', '\u{0112}'),
+    ('\u{0045}', '\u{0306}', '\u{0114}'),
+    ('\u{0045}', '\u{0307}', '\u{0116}'),
+    ('\u{0045}', '\u{0308}', '\u{00CB}'),
+    ('\u{0045}', '\u{0309}', '\u{1EBA}'),
+    ('\u{0045}', '\u{030C}', '\u{011A}'),
+    ('\u{0045}', '\u{030F}', '\u{0204}'),
+    ('\u{0045}', '\u{0311}', '\u{0206}'),
+    ('\u{0045}', '\u{0323}', '\u{1EB8}'),
+    ('\u{0045}', '\u{0327}', '\u{0228}'),
+    ('\u{0045}', '\u{0328}', '\u{0118}'),
+    ('\u{0045}', '\u{032D}', '\u{1E18}'),
+    ('\u{0045}', '\u{0330}', '\u{1E1A}'),
+    ('\u{0046}', '\u{0307}', '\u{1E1E}'),
+    ('\u{0047}', '\u{0301}', '\u{01F4}'),
+    ('\u{0047}', '\u{0302}', '\u{011C}'),
+    ('\u{0047}', '\u{0304}', '\u{1E20}'),
+    ('\u{0047}', '\u{0306}', '\u{011E}'),
+    ('\u{0047}', '\u{0307}', '\u{0120}'),
+    ('\u{0047}', '\u{030C}', '\u{01E6}'),
+    ('\u{0047}', '\u{0327}', '\u{0122}'),
+    ('\u{0048}', '\u{0302}', '\u{0124}'),
+    ('\u{0048}', '\u{0307}', '\u{1E22}'),
+    ('\u{0048}', '\u{0308}', '\u{1E26}'),
+    ('\u{0048}', '\u{030C}', '\u{021E}'),
+    ('\u{0048}', '\u{0323}', '\u{1E24}'),
+    ('\u{0048}', '\u{0327}', '\u{1E28}'),
+    ('\u{0048}', '\u{032E}', '\u{1E2A}'),
+    ('\u{0049}', '\u{0300}', '\u{00CC}'),
+    ('\u{0049}', '\u{0301}', '\u{00CD}'),
+    ('\u{0049}', '\u{0302}', '\u{00CE}'),
+    ('\u{0049}', '\u{0303}', '\u{0128}'),
+    ('\u{0049}', '\u{0304}', '\u{012A}'),
+    ('\u{0049}', '\u{0306}', '\u{012C}'),
+    ('\u{0049}', '\u{0307}', '\u{0130}'),
+    ('\u{0049}', '\u{0308}', '\u{00CF}'),
+    ('\u{0049}', '\u{0309}', '\u{1EC8}'),
+    ('\u{0049}', '\u{030C}', '\u{01CF}'),
+    ('\u{0049}', '\u{030F}', '\u{0208}'),
+    ('\u{0049}', '\u{0311}', '\u{020A}'),
+    ('\u{0049}', '\u{0323}', '\u{1ECA}'),
+    ('\u{0049}', '\u{0328}', '\u{012E}'),
+    ('\u{0049}', '\u{0330}', '\u{1E2C}'),
+    ('\u{004A}', '\u{0302}', '\u{0134}'),
+    ('\u{004B}', '\u{0301}', '\u{1E30}'),
+    ('\u{004B}', '\u{030C}', '\u{01E8}'),
+    ('\u{004B}', '\u{0323}', '\u{1E32}'),
+    ('\u{004B}', '\u{0327}', '\u{0136}'),
+    ('\u{004B}', '\u{0331}', '\u{1E34}'),
+    ('\u{004C}', '\u{0301}', '\u{0139}'),
+    ('\u{004C}', '\u{030C}', '\u{013D}'),
+    ('\u{004C}', '\u{0323}', '\u{1E36}'),
+    ('\u{004C}', '\u{0327}', '\u{013B}'),
+    ('\u{004C}', '\u{032D}', '\u{1E3C}'),
+    ('\u{004C}', '\u{0331}', '\u{1E3A}'),
+    ('\u{004D}', '\u{0301}', '\u{1E3E}'),
+    ('\u{004D}', '\u{0307}', '\u{1E40}'),
+    ('\u{004D}', '\u{0323}', '\u{1E42}'),
+    ('\u{004E}', '\u{0300}', '\u{01F8}'),
+    ('\u{004E}', '\u{0301}', '\u{0143}'),
+    ('\u{004E}', '\u{0303}', '\u{00D1}'),
+    ('\u{004E}', '\u{0307}', '\u{1E44}'),
+    ('\u{004E}', '\u{030C}', '\u{0147}'),
+    ('\u{004E}', '\u{0323}', '\u{1E46}'),
+    ('\u{004E}', '\u{0327}', '\u{0145}'),
+    ('\u{004E}', '\u{032D}', '\u{1E4A}'),
+    ('\u{004E}', '\u{0331}', '\u{1E48}'),
+    ('\u{004F}', '\u{0300}', '\u{00D2}'),
+    ('\u{004F}', '\u{0301}', '\u{00D3}'),
+    ('\u{004F}', '\u{0302}', '\u{00D4}'),
+    ('\u{004F}', '\u{0303}', '\u{00D5}'),
+    ('\u{004F}', '\u{0304}', '\u{014C}'),
+    ('\u{004F}', '\u{0306}', '\u{014E}'),
+    ('\u{004F}', '\u{0307}', '\u{022E}'),
+    ('\u{004F}', '\u{0308}', '\u{00D6}'),
+    ('\u{004F}', '\u{0309}', '\u{1ECE}'),
+    ('\u{004F}', '\u{030B}', '\u{0150}'),
+    ('\u{004F}', '\u{030C}', '\u{01D1}'),
+    ('\u{004F}', '\u{030F}', '\u{020C}'),
+    ('\u{004F}', '\u{0311}', '\u{020E}'),
+    ('\u{004F}', '\u{031B}', '\u{01A0}'),
+    ('\u{004F}', '\u{0323}', '\u{1ECC}'),
+    ('\u{004F}', '\u{0328}', '\u{01EA}'),
+    ('\u{0050}', '\u{0301}', '\u{1E54}'),
+    ('\u{0050}', '\u{0307}', '\u{1E56}'),
+    ('\u{0052}', '\u{0301}', '\u{0154}'),
+    ('\u{0052}', '\u{0307}', '\u{1E58}'),
+    ('\u{0052}', '\u{030C}', '\u{0158}'),
+    ('\u{0052}', '\u{030F}', '\u{0210}'),
+    ('\u{0052}', '\u{0311}', '\u{0212}'),
+    ('\u{0052}', '\u{0323}', '\u{1E5A}'),
+    ('\u{0052}', '\u{0327}', '\u{0156}'),
+    ('\u{0052}', '\u{0331}', '\u{1E5E}'),
+    ('\u{0053}', '\u{0301}', '\u{015A}'),
+    ('\u{0053}', '\u{0302}', '\u{015C}'),
+    ('\u{0053}', '\u{0307}', '\u{1E60}'),
+    ('\u{0053}', '\u{030C}', '\u{0160}'),
+    ('\u{0053}', '\u{0323}', '\u{1E62}'),
+    ('\u{0053}', '\u{0326}', '\u{0218}'),
+    ('\u{0053}', '\u{0327}', '\u{015E}'),
+    ('\u{0054}', '\u{0307}', '\u{1E6A}'),
+    ('\u{0054}', '\u{030C}', '\u{0164}'),
+    ('\u{0054}', '\u{0323}', '\u{1E6C}'),
+    ('\u{0054}', '\u{0326}', '\u{021A}'),
+    ('\u{0054}', '\u{0327}', '\u{0162}'),
+    ('\u{0054}', '\u{032D}', '\u{1E70}'),
+    ('\u{0054}', '\u{0331}', '\u{1E6E}'),
+    ('\u{0055}', '\u{0300}', '\u{00D9}'),
+    ('\u{0055}', '\u{0301}', '\u{00DA}'),
+    ('\u{0055}', '\u{0302}', '\u{00DB}'),
+    ('\u{0055}', '\u{0303}', '\u{0168}'),
+    ('\u{0055}', '\u{0304}', '\u{016A}'),
+    ('\u{0055}', '\u{0306}', '\u{016C}'),
+    ('\u{0055}', '\u{0308}', '\u{00DC}'),
+    ('\u{0055}', '\u{0309}', '\u{1EE6}'),
+    ('\u{0055}', '\u{030A}', '\u{016E}'),
+    ('\u{0055}', '\u{030B}', '\u{0170}'),
+    ('\u{0055}', '\u{030C}', '\u{01D3}'),
+    ('\u{0055}', '\u{030F}', '\u{0214}'),
+    ('\u{0055}', '\u{0311}', '\u{0216}'),
+    ('\u{0055}', '\u{031B}', '\u{01AF}'),
+    ('\u{0055}', '\u{0323}', '\u{1EE4}'),
+    ('\u{0055}', '\u{0324}', '\u{1E72}'),
+    ('\u{0055}', '\u{0328}', '\u{0172}'),
+    ('\u{0055}', '\u{032D}', '\u{1E76}'),
+    ('\u{0055}', '\u{0330}', '\u{1E74}'),
+    ('\u{0056}', '\u{0303}', '\u{1E7C}'),
+    ('\u{0056}', '\u{0323}', '\u{1E7E}'),
+    ('\u{0057}', '\u{0300}', '\u{1E80}'),
+    ('\u{0057}', '\u{0301}', '\u{1E82}'),
+    ('\u{0057}', '\u{0302}', '\u{0174}'),
+    ('\u{0057}', '\u{0307}', '\u{1E86}'),
+    ('\u{0057}', '\u{0308}', '\u{1E84}'),
+    ('\u{0057}', '\u{0323}', '\u{1E88}'),
+    ('\u{0058}', '\u{0307}', '\u{1E8A}'),
+    ('\u{0058}', '\u{0308}', '\u{1E8C}'),
+    ('\u{0059}', '\u{0300}', '\u{1EF2}'),
+    ('\u{0059}', '\u{0301}', '\u{00DD}'),
+    ('\u{0059}', '\u{0302}', '\u{0176}'),
+    ('\u{0059}', '\u{0303}', '\u{1EF8}'),
+    ('\u{0059}', '\u{0304}', '\u{0232}'),
+    ('\u{0059}', '\u{0307}', '\u{1E8E}'),
+    ('\u{0059}', '\u{0308}', '\u{0178}'),
+    ('\u{0059}', '\u{0309}', '\u{1EF6}'),
+    ('\u{0059}', '\u{0323}', '\u{1EF4}'),
+    ('\u{005A}', '\u{0301}', '\u{0179}'),
+    ('\u{005A}', '\u{0302}', '\u{1E90}'),
+    ('\u{005A}', '\u{0307}', '\u{017B}'),
+    ('\u{005A}', '\u{030C}', '\u{017D}'),
+    ('\u{005A}', '\u{0323}', '\u{1E92}'),
+    ('\u{005A}', '\u{0331}', '\u{1E94}'),
+    ('\u{0061}', '\u{0300}', '\u{00E0}'),
+    ('\u{0061}', '\u{0301}', '\u{00E1}'),
+    ('\u{0061}', '\u{0302}', '\u{00E2}'),
+    ('\u{0061}', '\u{0303}', '\u{00E3}'),
+    ('\u{0061}', '\u{0304}', '\u{0101}'),
+    ('\u{0061}', '\u{0306}', '\u{0103}'),
+    ('\u{0061}', '\u{0307}', '\u{0227}'),
+    ('\u{0061}', '\u{0308}', '\u{00E4}'),
+    ('\u{0061}', '\u{0309}', '\u{1EA3}'),
+    ('\u{0061}', '\u{030A}', '\u{00E5}'),
+    ('\u{0061}', '\u{030C}', '\u{01CE}'),
+    ('\u{0061}', '\u{030F}', '\u{0201}'),
+    ('\u{0061}', '\u{0311}', '\u{0203}'),
+    ('\u{0061}', '\u{0323}', '\u{1EA1}'),
+    ('\u{0061}', '\u{0325}', '\u{1E01}'),
+    ('\u{0061}', '\u{0328}', '\u{0105}'),
+    ('\u{0062}', '\u{0307}', '\u{1E03}'),
+    ('\u{0062}', '\u{0323}', '\u{1E05}'),
+    ('\u{0062}', '\u{0331}', '\u{1E07}'),
+    ('\u{0063}', '\u{0301}', '\u{0107}'),
+    ('\u{0063}', '\u{0302}', '\u{0109}'),
+    ('\u{0063}', '\u{0307}', '\u{010B}'),
+    ('\u{0063}', '\u{030C}', '\u{010D}'),
+    ('\u{0063}', '\u{0327}', '\u{00E7}'),
+    ('\u{0064}', '\u{0307}', '\u{1E0B}'),
+    ('\u{0064}', '\u{030C}', '\u{010F}'),
+    ('\u{0064}', '\u{0323}', '\u{1E0D}'),
+    ('\u{0064}', '\u{0327}', '\u{1E11}'),
+    ('\u{0064}', '\u{032D}', '\u{1E13}'),
+    ('\u{0064}', '\u{0331}', '\u{1E0F}'),
+    ('\u{0065}', '\u{0300}', '\u{00E8}'),
+    ('\u{0065}', '\u{0301}', '\u{00E9}'),
+    ('\u{0065}', '\u{0302}', '\u{00EA}'),
+    ('\u{0065}', '\u{0303}', '\u{1EBD}'),
+    ('\u{0065}', '\u{0304}', '\u{0113}'),
+    ('\u{0065}', '\u{0306}', '\u{0115}'),
+    ('\u{0065}', '\u{0307}', '\u{0117}'),
+    ('\u{0065}', '\u{0308}', '\u{00EB}'),
+    ('\u{0065}', '\u{0309}', '\u{1EBB}'),
+    ('\u{0065}', '\u{030C}', '\u{011B}'),
+    ('\u{0065}', '\u{030F}', '\u{0205}'),
+    ('\u{0065}', '\u{0311}', '\u{0207}'),
+    ('\u{0065}', '\u{0323}', '\u{1EB9}'),
+    ('\u{0065}', '\u{0327}', '\u{0229}'),
+    ('\u{0065}', '\u{0328}', '\u{0119}'),
+    ('\u{0065}', '\u{032D}', '\u{1E19}'),
+    ('\u{0065}', '\u{0330}', '\u{1E1B}'),
+    ('\u{0066}', '\u{0307}', '\u{1E1F}'),
+    ('\u{0067}', '\u{0301}', '\u{01F5}'),
+    ('\u{0067}', '\u{0302}', '\u{011D}'),
+    ('\u{0067}', '\u{0304}', '\u{1E21}'),
+    ('\u{0067}', '\u{0306}', '\u{011F}'),
+    ('\u{0067}', '\u{0307}', '\u{0121}'),
+    ('\u{0067}', '\u{030C}', '\u{01E7}'),
+    ('\u{0067}', '\u{0327}', '\u{0123}'),
+    ('\u{0068}', '\u{0302}', '\u{0125}'),
+    ('\u{0068}', '\u{0307}', '\u{1E23}'),
+    ('\u{0068}', '\u{0308}', '\u{1E27}'),
+    ('\u{0068}', '\u{030C}', '\u{021F}'),
+    ('\u{0068}', '\u{0323}', '\u{1E25}'),
+    ('\u{0068}', '\u{0327}', '\u{1E29}'),
+    ('\u{0068}', '\u{032E}', '\u{1E2B}'),
+    ('\u{0068}', '\u{0331}', '\u{1E96}'),
+    ('\u{0069}', '\u{0300}', '\u{00EC}'),
+    ('\u{0069}', '\u{0301}', '\u{00ED}'),
+    ('\u{0069}', '\u{0302}', '\u{00EE}'),
+    ('\u{0069}', '\u{0303}', '\u{0129}'),
+    ('\u{0069}', '\u{0304}', '\u{012B}'),
+    ('\u{0069}', '\u{0306}', '\u{012D}'),
+    ('\u{0069}', '\u{0308}', '\u{00EF}'),
+    ('\u{0069}', '\u{0309}', '\u{1EC9}'),
+    ('\u{0069}', '\u{030C}', '\u{01D0}'),
+    ('\u{0069}', '\u{030F}', '\u{0209}'),
+    ('\u{0069}', '\u{0311}', '\u{020B}'),
+    ('\u{0069}', '\u{0323}', '\u{1ECB}'),
+    ('\u{0069}', '\u{0328}', '\u{012F}'),
+    ('\u{0069}', '\u{0330}', '\u{1E2D}'),
+    ('\u{006A}', '\u{0302}', '\u{0135}'),
+    ('\u{006A}', '\u{030C}', '\u{01F0}'),
+    ('\u{006B}', '\u{0301}', '\u{1E31}'),
+    ('\u{006B}', '\u{030C}', '\u{01E9}'),
+    ('\u{006B}', '\u{0323}', '\u{1E33}'),
+    ('\u{006B}', '\u{0327}', '\u{0137}'),
+    ('\u{006B}', '\u{0331}', '\u{1E35}'),
+    ('\u{006C}', '\u{0301}', '\u{013A}'),
+    ('\u{006C}', '\u{030C}', '\u{013E}'),
+    ('\u{006C}', '\u{0323}', '\u{1E37}'),
+    ('\u{006C}', '\u{0327}', '\u{013C}'),
+    ('\u{006C}', '\u{032D}', '\u{1E3D}'),
+    ('\u{006C}', '\u{0331}', '\u{1E3B}'),
+    ('\u{006D}', '\u{0301}', '\u{1E3F}'),
+    ('\u{006D}', '\u{0307}', '\u{1E41}'),
+    ('\u{006D}', '\u{0323}', '\u{1E43}'),
+    ('\u{006E}', '\u{0300}', '\u{01F9}'),
+    ('\u{006E}', '\u{0301}', '\u{0144}'),
+    ('\u{006E}', '\u{0303}', '\u{00F1}'),
+    ('\u{006E}', '\u{0307}', '\u{1E45}'),
+    ('\u{006E}', '\u{030C}', '\u{0148}'),
+    ('\u{006E}', '\u{0323}', '\u{1E47}'),
+    ('\u{006E}', '\u{0327}', '\u{0146}'),
+    ('\u{006E}', '\u{032D}', '\u{1E4B}'),
+    ('\u{006E}', '\u{0331}', '\u{1E49}'),
+    ('\u{006F}', '\u{0300}', '\u{00F2}'),
+    ('\u{006F}', '\u{0301}', '\u{00F3}'),
+    ('\u{006F}', '\u{0302}', '\u{00F4}'),
+    ('\u{006F}', '\u{0303}', '\u{00F5}'),
+    ('\u{006F}', '\u{0304}', '\u{014D}'),
+    ('\u{006F}', '\u{0306}', '\u{014F}'),
+    ('\u{006F}', '\u{0307}', '\u{022F}'),
+    ('\u{006F}', '\u{0308}', '\u{00F6}'),
+    ('\u{006F}', '\u{0309}', '\u{1ECF}'),
+    ('\u{006F}', '\u{030B}', '\u{0151}'),
+    ('\u{006F}', '\u{030C}', '\u{01D2}'),
+    ('\u{006F}', '\u{030F}', '\u{020D}'),
+    ('\u{006F}', '\u{0311}', '\u{020F}'),
+    ('\u{006F}', '\u{031B}', '\u{01A1}'),
+    ('\u{006F}', '\u{0323}', '\u{1ECD}'),
+    ('\u{006F}', '\u{0328}', '\u{01EB}'),
+    ('\u{0070}', '\u{0301}', '\u{1E55}'),
+    ('\u{0070}', '\u{0307}', '\u{1E57}'),
+    ('\u{0072}', '\u{0301}', '\u{0155}'),
+    ('\u{0072}', '\u{0307}', '\u{1E59}'),
+    ('\u{0072}', '\u{030C}', '\u{0159}'),
+    ('\u{0072}', '\u{030F}', '\u{0211}'),
+    ('\u{0072}', '\u{0311}', '\u{0213}'),
+    ('\u{0072}', '\u{0323}', '\u{1E5B}'),
+    ('\u{0072}', '\u{0327}', '\u{0157}'),
+    ('\u{0072}', '\u{0331}', '\u{1E5F}'),
+    ('\u{0073}', '\u{0301}', '\u{015B}'),
+    ('\u{0073}', '\u{0302}', '\u{015D}'),
+    ('\u{0073}', '\u{0307}', '\u{1E61}'),
+    ('\u{0073}', '\u{030C}', '\u{0161}'),
+    ('\u{0073}', '\u{0323}', '\u{1E63}'),
+    ('\u{0073}', '\u{0326}', '\u{0219}'),
+    ('\u{0073}', '\u{0327}', '\u{015F}'),
+    ('\u{0074}', '\u{0307}', '\u{1E6B}'),
+    ('\u{0074}', '\u{0308}', '\u{1E97}'),
+    ('\u{0074}', '\u{030C}', '\u{0165}'),
+    ('\u{0074}', '\u{0323}', '\u{1E6D}'),
+    ('\u{0074}', '\u{0326}', '\u{021B}'),
+    ('\u{0074}', '\u{0327}', '\u{0163}'),
+    ('\u{0074}', '\u{032D}', '\u{1E71}'),
+    ('\u{0074}', '\u{0331}', '\u{1E6F}'),
+    ('\u{0075}', '\u{0300}', '\u{00F9}'),
+    ('\u{0075}', '\u{0301}', '\u{00FA}'),
+    ('\u{0075}', '\u{0302}', '\u{00FB}'),
+    ('\u{0075}', '\u{0303}', '\u{0169}'),
+    ('\u{0075}', '\u{0304}', '\u{016B}'),
+    ('\u{0075}', '\u{0306}', '\u{016D}'),
+    ('\u{0075}', '\u{0308}', '\u{00FC}'),
+    ('\u{0075}', '\u{0309}', '\u{1EE7}'),
+    ('\u{0075}', '\u{030A}', '\u{016F}'),
+    ('\u{0075}', '\u{030B}', '\u{0171}'),
+    ('\u{0075}', '\u{030C}', '\u{01D4}'),
+    ('\u{0075}', '\u{030F}', '\u{0215}'),
+    ('\u{0075}', '\u{0311}', '\u{0217}'),
+    ('\u{0075}', '\u{031B}', '\u{01B0}'),
+    ('\u{0075}', '\u{0323}', '\u{1EE5}'),
+    ('\u{0075}', '\u{0324}', '\u{1E73}'),
+    ('\u{0075}', '\u{0328}', '\u{0173}'),
+    ('\u{0075}', '\u{032D}', '\u{1E77}'),
+    ('\u{0075}', '\u{0330}', '\u{1E75}'),
+    ('\u{0076}', '\u{0303}', '\u{1E7D}'),
+    ('\u{0076}', '\u{0323}', '\u{1E7F}'),
+    ('\u{0077}', '\u{0300}', '\u{1E81}'),
+    ('\u{0077}', '\u{0301}', '\u{1E83}'),
+    ('\u{0077}', '\u{0302}', '\u{0175}'),
+    ('\u{0077}', '\u{0307}', '\u{1E87}'),
+    ('\u{0077}', '\u{0308}', '\u{1E85}'),
+    ('\u{0077}', '\u{030A}', '\u{1E98}'),
+    ('\u{0077}', '\u{0323}', '\u{1E89}'),
+    ('\u{0078}', '\u{0307}', '\u{1E8B}'),
+    ('\u{0078}', '\u{0308}', '\u{1E8D}'),
+    ('\u{0079}', '\u{0300}', '\u{1EF3}'),
+    ('\u{0079}', '\u{0301}', '\u{00FD}'),
+    ('\u{0079}', '\u{0302}', '\u{0177}'),
+    ('\u{0079}', '\u{0303}', '\u{1EF9}'),
+    ('\u{0079}', '\u{0304}', '\u{0233}'),
+    ('\u{0079}', '\u{0307}', '\u{1E8F}'),
+    ('\u{0079}', '\u{0308}', '\u{00FF}'),
+    ('\u{0079}', '\u{0309}', '\u{1EF7}'),
+    ('\u{0079}', '\u{030A}', '\u{1E99}'),
+    ('\u{0079}', '\u{0323}', '\u{1EF5}'),
+    ('\u{007A}', '\u{0301}', '\u{017A}'),
+    ('\u{007A}', '\u{0302}', '\u{1E91}'),
+    ('\u{007A}', '\u{0307}', '\u{017C}'),
+    ('\u{007A}', '\u{030C}', '\u{017E}'),
+    ('\u{007A}', '\u{0323}', '\u{1E93}'),
+    ('\u{007A}', '\u{0331}', '\u{1E95}'),
+    ('\u{00A8}', '\u{0300}', '\u{1FED}'),
+    ('\u{00A8}', '\u{0301}', '\u{0385}'),
+    ('\u{00A8}', '\u{0342}', '\u{1FC1}'),
+    ('\u{00C2}', '\u{0300}', '\u{1EA6}'),
+    ('\u{00C2}', '\u{0301}', '\u{1EA4}'),
+    ('\u{00C2}', '\u{0303}', '\u{1EAA}'),
+    ('\u{00C2}', '\u{0309}', '\u{1EA8}'),
+    ('\u{00C4}', '\u{0304}', '\u{01DE}'),
+    ('\u{00C5}', '\u{0301}', '\u{01FA}'),
+    ('\u{00C6}', '\u{0301}', '\u{01FC}'),
+    ('\u{00C6}', '\u{0304}', '\u{01E2}'),
+    ('\u{00C7}', '\u{0301}', '\u{1E08}'),
+    ('\u{00CA}', '\u{0300}', '\u{1EC0}'),
+    ('\u{00CA}', '\u{0301}', '\u{1EBE}'),
+    ('\u{00CA}', '\u{0303}', '\u{1EC4}'),
+    ('\u{00CA}', '\u{0309}', '\u{1EC2}'),
+    ('\u{00CF}', '\u{0301}', '\u{1E2E}'),
+    ('\u{00D4}', '\u{0300}', '\u{1ED2}'),
+    ('\u{00D4}', '\u{0301}', '\u{1ED0}'),
+    ('\u{00D4}', '\u{0303}', '\u{1ED6}'),
+    ('\u{00D4}', '\u{0309}', '\u{1ED4}'),
+    ('\u{00D5}', '\u{0301}', '\u{1E4C}'),
+    ('\u{00D5}', '\u{0304}', '\u{022C}'),
+    ('\u{00D5}', '\u{0308}', '\u{1E4E}'),
+    ('\u{00D6}', '\u{0304}', '\u{022A}'),
+    ('\u{00D8}', '\u{0301}', '\u{01FE}'),
+    ('\u{00DC}', '\u{0300}', '\u{01DB}'),
+    ('\u{00DC}', '\u{0301}', '\u{01D7}'),
+    ('\u{00DC}', '\u{0304}', '\u{01D5}'),
+    ('\u{00DC}', '\u{030C}', '\u{01D9}'),
+    ('\u{00E2}', '\u{0300}', '\u{1EA7}'),
+    ('\u{00E2}', '\u{0301}', '\u{1EA5}'),
+    ('\u{00E2}', '\u{0303}', '\u{1EAB}'),
+    ('\u{00E2}', '\u{0309}', '\u{1EA9}'),
+    ('\u{00E4}', '\u{0304}', '\u{01DF}'),
+    ('\u{00E5}', '\u{0301}', '\u{01FB}'),
+    ('\u{00E6}', '\u{0301}', '\u{01FD}'),
+    ('\u{00E6}', '\u{0304}', '\u{01E3}'),
+    ('\u{00E7}', '\u{0301}', '\u{1E09}'),
+    ('\u{00EA}', '\u{0300}', '\u{1EC1}'),
+    ('\u{00EA}', '\u{0301}', '\u{1EBF}'),
+    ('\u{00EA}', '\u{0303}', '\u{1EC5}'),
+    ('\u{00EA}', '\u{0309}', '\u{1EC3}'),
+    ('\u{00EF}', '\u{0301}', '\u{1E2F}'),
+    ('\u{00F4}', '\u{0300}', '\u{1ED3}'),
+    ('\u{00F4}', '\u{0301}', '\u{1ED1}'),
+    ('\u{00F4}', '\u{0303}', '\u{1ED7}'),
+    ('\u{00F4}', '\u{0309}', '\u{1ED5}'),
+    ('\u{00F5}', '\u{0301}', '\u{1E4D}'),
+    ('\u{00F5}', '\u{0304}', '\u{022D}'),
+    ('\u{00F5}', '\u{0308}', '\u{1E4F}'),
+    ('\u{00F6}', '\u{0304}', '\u{022B}'),
+    ('\u{00F8}', '\u{0301}', '\u{01FF}'),
+    ('\u{00FC}', '\u{0300}', '\u{01DC}'),
+    ('\u{00FC}', '\u{0301}', '\u{01D8}'),
+    ('\u{00FC}', '\u{0304}', '\u{01D6}'),
+    ('\u{00FC}', '\u{030C}', '\u{01DA}'),
+    ('\u{0102}', '\u{0300}', '\u{1EB0}'),
+    ('\u{0102}', '\u{0301}', '\u{1EAE}'),
+    ('\u{0102}', '\u{0303}', '\u{1EB4}'),
+    ('\u{0102}', '\u{0309}', '\u{1EB2}'),
+    ('\u{0103}', '\u{0300}', '\u{1EB1}'),
+    ('\u{0103}', '\u{0301}', '\u{1EAF}'),
+    ('\u{0103}', '\u{0303}', '\u{1EB5}'),
+    ('\u{0103}', '\u{0309}', '\u{1EB3}'),
+    ('\u{0112}', '\u{0300}', '\u{1E14}'),
+    ('\u{0112}', '\u{0301}', '\u{1E16}'),
+    ('\u{0113}', '\u{0300}', '\u{1E15}'),
+    ('\u{0113}', '\u{0301}', '\u{1E17}'),
+    ('\u{014C}', '\u{0300}', '\u{1E50}'),
+    ('\u{014C}', '\u{0301}', '\u{1E52}'),
+    ('\u{014D}', '\u{0300}', '\u{1E51}'),
+    ('\u{014D}', '\u{0301}', '\u{1E53}'),
+    ('\u{015A}', '\u{0307}', '\u{1E64}'),
+    ('\u{015B}', '\u{0307}', '\u{1E65}'),
+    ('\u{0160}', '\u{0307}', '\u{1E66}'),
+    ('\u{0161}', '\u{0307}', '\u{1E67}'),
+    ('\u{0168}', '\u{0301}', '\u{1E78}'),
+    ('\u{0169}', '\u{0301}', '\u{1E79}'),
+    ('\u{016A}', '\u{0308}', '\u{1E7A}'),
+    ('\u{016B}', '\u{0308}', '\u{1E7B}'),
+    ('\u{017F}', '\u{0307}', '\u{1E9B}'),
+    ('\u{01A0}', '\u{0300}', '\u{1EDC}'),
+    ('\u{01A0}', '\u{0301}', '\u{1EDA}'),
+    ('\u{01A0}', '\u{0303}', '\u{1EE0}'),
+    ('\u{01A0}', '\u{0309}', '\u{1EDE}'),
+    ('\u{01A0}', '\u{0323}', '\u{1EE2}'),
+    ('\u{01A1}', '\u{0300}', '\u{1EDD}'),
+    ('\u{01A1}', '\u{0301}', '\u{1EDB}'),
+    ('\u{01A1}', '\u{0303}', '\u{1EE1}'),
+    ('\u{01A1}', '\u{0309}', '\u{1EDF}'),
+    ('\u{01A1}', '\u{0323}', '\u{1EE3}'),
+    ('\u{01AF}', '\u{0300}', '\u{1EEA}'),
+    ('\u{01AF}', '\u{0301}', '\u{1EE8}'),
+    ('\u{01AF}', '\u{0303}', '\u{1EEE}'),
+    ('\u{01AF}', '\u{0309}', '\u{1EEC}'),
+    ('\u{01AF}', '\u{0323}', '\u{1EF0}'),
+    ('\u{01B0}', '\u{0300}', '\u{1EEB}'),
+    ('\u{01B0}', '\u{0301}', '\u{1EE9}'),
+    ('\u{01B0}', '\u{0303}', '\u{1EEF}'),
+    ('\u{01B0}', '\u{0309}', '\u{1EED}'),
+    ('\u{01B0}', '\u{0323}', '\u{1EF1}'),
+    ('\u{01B7}', '\u{030C}', '\u{01EE}'),
+    ('\u{01EA}', '\u{0304}', '\u{01EC}'),
+    ('\u{01EB}', '\u{0304}', '\u{01ED}'),
+    ('\u{0226}', '\u{0304}', '\u{01E0}'),
+    ('\u{0227}', '\u{0304}', '\u{01E1}'),
+    ('\u{0228}', '\u{0306}', '\u{1E1C}'),
+    ('\u{0229}', '\u{0306}', '\u{1E1D}'),
+    ('\u{022E}', '\u{0304}', '\u{0230}'),
+    ('\u{022F}', '\u{0304}', '\u{0231}'),
+    ('\u{0292}', '\u{030C}', '\u{01EF}'),
+    ('\u{0391}', '\u{0300}', '\u{1FBA}'),
+    ('\u{0391}', '\u{0301}', '\u{0386}'),
+    ('\u{0391}', '\u{0304}', '\u{1FB9}'),
+    ('\u{0391}', '\u{0306}', '\u{1FB8}'),
+    ('\u{0391}', '\u{0313}', '\u{1F08}'),
+    ('\u{0391}', '\u{0314}', '\u{1F09}'),
+    ('\u{0391}', '\u{0345}', '\u{1FBC}'),
+    ('\u{0395}', '\u{0300}', '\u{1FC8}'),
+    ('\u{0395}', '\u{0301}', '\u{0388}'),
+    ('\u{0395}', '\u{0313}', '\u{1F18}'),
+    ('\u{0395}', '\u{0314}', '\u{1F19}'),
+    ('\u{0397}', '\u{0300}', '\u{1FCA}'),
+    ('\u{0397}', '\u{0301}', '\u{0389}'),
+    ('\u{0397}', '\u{0313}', '\u{1F28}'),
+    ('\u{0397}', '\u{0314}', '\u{1F29}'),
+    ('\u{0397}', '\u{0345}', '\u{1FCC}'),
+    ('\u{0399}', '\u{0300}', '\u{1FDA}'),
+    ('\u{0399}', '\u{0301}', '\u{038A}'),
+    ('\u{0399}', '\u{0304}', '\u{1FD9}'),
+    ('\u{0399}', '\u{0306}', '\u{1FD8}'),
+    ('\u{0399}', '\u{0308}', '\u{03AA}'),
+    ('\u{0399}', '\u{0313}', '\u{1F38}'),
+    ('\u{0399}', '\u{0314}', '\u{1F39}'),
+    ('\u{039F}', '\u{0300}', '\u{1FF8}'),
+    ('\u{039F}', '\u{0301}', '\u{038C}'),
+    ('\u{039F}', '\u{0313}', '\u{1F48}'),
+    ('\u{039F}', '\u{0314}', '\u{1F49}'),
+    ('\u{03A1}', '\u{0314}', '\u{1FEC}'),
+    ('\u{03A5}', '\u{0300}', '\u{1FEA}'),
+    ('\u{03A5}', '\u{0301}', '\u{038E}'),
+    ('\u{03A5}', '\u{0304}', '\u{1FE9}'),
+    ('\u{03A5}', '\u{0306}', '\u{1FE8}'),
+    ('\u{03A5}', '\u{0308}', '\u{03AB}'),
+    ('\u{03A5}', '\u{0314}', '\u{1F59}'),
+    ('\u{03A9}', '\u{0300}', '\u{1FFA}'),
+    ('\u{03A9}', '\u{0301}', '\u{038F}'),
+    ('\u{03A9}', '\u{0313}', '\u{1F68}'),
+    ('\u{03A9}', '\u{0314}', '\u{1F69}'),
+    ('\u{03A9}', '\u{0345}', '\u{1FFC}'),
+    ('\u{03AC}', '\u{0345}', '\u{1FB4}'),
+    ('\u{03AE}', '\u{0345}', '\u{1FC4}'),
+    ('\u{03B1}', '\u{0300}', '\u{1F70}'),
+    ('\u{03B1}', '\u{0301}', '\u{03AC}'),
+    ('\u{03B1}', '\u{0304}', '\u{1FB1}'),
+    ('\u{03B1}', '\u{0306}', '\u{1FB0}'),
+    ('\u{03B1}', '\u{0313}', '\u{1F00}'),
+    ('\u{03B1}', '\u{0314}', '\u{1F01}'),
+    ('\u{03B1}', '\u{0342}', '\u{1FB6}'),
+    ('\u{03B1}', '\u{0345}', '\u{1FB3}'),
+    ('\u{03B5}', '\u{0300}', '\u{1F72}'),
+    ('\u{03B5}', '\u{0301}', '\u{03AD}'),
+    ('\u{03B5}', '\u{0313}', '\u{1F10}'),
+    ('\u{03B5}', '\u{0314}', '\u{1F11}'),
+    ('\u{03B7}', '\u{0300}', '\u{1F74}'),
+    ('\u{03B7}', '\u{0301}', '\u{03AE}'),
+    ('\u{03B7}', '\u{0313}', '\u{1F20}'),
+    ('\u{03B7}', '\u{0314}', '\u{1F21}'),
+    ('\u{03B7}', '\u{0342}', '\u{1FC6}'),
+    ('\u{03B7}', '\u{0345}', '\u{1FC3}'),
+    ('\u{03B9}', '\u{0300}', '\u{1F76}'),
+    ('\u{03B9}', '\u{0301}', '\u{03AF}'),
+    ('\u{03B9}', '\u{0304}', '\u{1FD1}'),
+    ('\u{03B9}', '\u{0306}', '\u{1FD0}'),
+    ('\u{03B9}', '\u{0308}', '\u{03CA}'),
+    ('\u{03B9}', '\u{0313}', '\u{1F30}'),
+    ('\u{03B9}', '\u{0314}', '\u{1F31}'),
+    ('\u{03B9}', '\u{0342}', '\u{1FD6}'),
+    ('\u{03BF}', '\u{0300}', '\u{1F78}'),
+    ('\u{03BF}', '\u{0301}', '\u{03CC}'),
+    ('\u{03BF}', '\u{0313}', '\u{1F40}'),
+    ('\u{03BF}', '\u{0314}', '\u{1F41}'),
+    ('\u{03C1}', '\u{0313}', '\u{1FE4}'),
+    ('\u{03C1}', '\u{0314}', '\u{1FE5}'),
+    ('\u{03C5}', '\u{0300}', '\u{1F7A}'),
+    ('\u{03C5}', '\u{0301}', '\u{03CD}'),
+    ('\u{03C5}', '\u{0304}', '\u{1FE1}'),
+    ('\u{03C5}', '\u{0306}', '\u{1FE0}'),
+    ('\u{03C5}', '\u{0308}', '\u{03CB}'),
+    ('\u{03C5}', '\u{0313}', '\u{1F50}'),
+    ('\u{03C5}', '\u{0314}', '\u{1F51}'),
+    ('\u{03C5}', '\u{0342}', '\u{1FE6}'),
+    ('\u{03C9}', '\u{0300}', '\u{1F7C}'),
+    ('\u{03C9}', '\u{0301}', '\u{03CE}'),
+    ('\u{03C9}', '\u{0313}', '\u{1F60}'),
+    ('\u{03C9}', '\u{0314}', '\u{1F61}'),
+    ('\u{03C9}', '\u{0342}', '\u{1FF6}'),
+    ('\u{03C9}', '\u{0345}', '\u{1FF3}'),
+    ('\u{03CA}', '\u{0300}', '\u{1FD2}'),
+    ('\u{03CA}', '\u{0301}', '\u{0390}'),
+    ('\u{03CA}', '\u{0342}', '\u{1FD7}'),
+    ('\u{03CB}', '\u{0300}', '\u{1FE2}'),
+    ('\u{03CB}', '\u{0301}', '\u{03B0}'),
+    ('\u{03CB}', '\u{0342}', '\u{1FE7}'),
+    ('\u{03CE}', '\u{0345}', '\u{1FF4}'),
+    ('\u{03D2}', '\u{0301}', '\u{03D3}'),
+    ('\u{03D2}', '\u{0308}', '\u{03D4}'),
+    ('\u{0406}', '\u{0308}', '\u{0407}'),
+    ('\u{0410}', '\u{0306}', '\u{04D0}'),
+    ('\u{0410}', '\u{0308}', '\u{04D2}'),
+    ('\u{0413}', '\u{0301}', '\u{0403}'),
+    ('\u{0415}', '\u{0300}', '\u{0400}'),
+    ('\u{0415}', '\u{0306}', '\u{04D6}'),
+    ('\u{0415}', '\u{0308}', '\u{0401}'),
+    ('\u{0416}', '\u{0306}', '\u{04C1}'),
+    ('\u{0416}', '\u{0308}', '\u{04DC}'),
+    ('\u{0417}', '\u{0308}', '\u{04DE}'),
+    ('\u{0418}', '\u{0300}', '\u{040D}'),
+    ('\u{0418}', '\u{0304}', '\u{04E2}'),
+    ('\u{0418}', '\u{0306}', '\u{0419}'),
+    ('\u{0418}', '\u{0308}', '\u{04E4}'),
+    ('\u{041A}', '\u{0301}', '\u{040C}'),
+    ('\u{041E}', '\u{0308}', '\u{04E6}'),
+    ('\u{0423}', '\u{0304}', '\u{04EE}'),
+    ('\u{0423}', '\u{0306}', '\u{040E}'),
+    ('\u{0423}', '\u{0308}', '\u{04F0}'),
+    ('\u{0423}', '\u{030B}', '\u{04F2}'),
+    ('\u{0427}', '\u{0308}', '\u{04F4}'),
+    ('\u{042B}', '\u{0308}', '\u{04F8}'),
+    ('\u{042D}', '\u{0308}', '\u{04EC}'),
+    ('\u{0430}', '\u{0306}', '\u{04D1}'),
+    ('\u{0430}', '\u{0308}', '\u{04D3}'),
+    ('\u{0433}', '\u{0301}', '\u{0453}'),
+    ('\u{0435}', '\u{0300}', '\u{0450}'),
+    ('\u{0435}', '\u{0306}', '\u{04D7}'),
+    ('\u{0435}', '\u{0308}', '\u{0451}'),
+    ('\u{0436}', '\u{0306}', '\u{04C2}'),
+    ('\u{0436}', '\u{0308}', '\u{04DD}'),
+    ('\u{0437}', '\u{0308}', '\u{04DF}'),
+    ('\u{0438}', '\u{0300}', '\u{045D}'),
+    ('\u{0438}', '\u{0304}', '\u{04E3}'),
+    ('\u{0438}', '\u{0306}', '\u{0439}'),
+    ('\u{0438}', '\u{0308}', '\u{04E5}'),
+    ('\u{043A}', '\u{0301}', '\u{045C}'),
+    ('\u{043E}', '\u{0308}', '\u{04E7}'),
+    ('\u{0443}', '\u{0304}', '\u{04EF}'),
+    ('\u{0443}', '\u{0306}', '\u{045E}'),
+    ('\u{0443}', '\u{0308}', '\u{04F1}'),
+    ('\u{0443}', '\u{030B}', '\u{04F3}'),
+    ('\u{0447}', '\u{0308}', '\u{04F5}'),
+    ('\u{044B}', '\u{0308}', '\u{04F9}'),
+    ('\u{044D}', '\u{0308}', '\u{04ED}'),
+    ('\u{0456}', '\u{0308}', '\u{0457}'),
+    ('\u{0474}', '\u{030F}', '\u{0476}'),
+    ('\u{0475}', '\u{030F}', '\u{0477}'),
+    ('\u{04D8}', '\u{0308}', '\u{04DA}'),
+    ('\u{04D9}', '\u{0308}', '\u{04DB}'),
+    ('\u{04E8}', '\u{0308}', '\u{04EA}'),
+    ('\u{04E9}', '\u{0308}', '\u{04EB}'),
+    ('\u{0627}', '\u{0653}', '\u{0622}'),
+    ('\u{0627}', '\u{0654}', '\u{0623}'),
+    ('\u{0627}', '\u{0655}', '\u{0625}'),
+    ('\u{0648}', '\u{0654}', '\u{0624}'),
+    ('\u{064A}', '\u{0654}', '\u{0626}'),
+    ('\u{06C1}', '\u{0654}', '\u{06C2}'),
+    ('\u{06D2}', '\u{0654}', '\u{06D3}'),
+    ('\u{06D5}', '\u{0654}', '\u{06C0}'),
+    ('\u{0928}', '\u{093C}', '\u{0929}'),
+    ('\u{0930}', '\u{093C}', '\u{0931}'),
+    ('\u{0933}', '\u{093C}', '\u{0934}'),
+    ('\u{09C7}', '\u{09BE}', '\u{09CB}'),
+    ('\u{09C7}', '\u{09D7}', '\u{09CC}'),
+    ('\u{0B47}', '\u{0B3E}', '\u{0B4B}'),
+    ('\u{0B47}', '\u{0B56}', '\u{0B48}'),
+    ('\u{0B47}', '\u{0B57}', '\u{0B4C}'),
+    ('\u{0B92}', '\u{0BD7}', '\u{0B94}'),
+    ('\u{0BC6}', '\u{0BBE}', '\u{0BCA}'),
+    ('\u{0BC6}', '\u{0BD7}', '\u{0BCC}'),
+    ('\u{0BC7}', '\u{0BBE}', '\u{0BCB}'),
+    ('\u{0C46}', '\u{0C56}', '\u{0C48}'),
+    ('\u{0CBF}', '\u{0CD5}', '\u{0CC0}'),
+    ('\u{0CC6}', '\u{0CC2}', '\u{0CCA}'),
+    ('\u{0CC6}', '\u{0CD5}', '\u{0CC7}'),
+    ('\u{0CC6}', '\u{0CD6}', '\u{0CC8}'),
+    ('\u{0CCA}', '\u{0CD5}', '\u{0CCB}'),
+    ('\u{0D46}', '\u{0D3E}', '\u{0D4A}'),
+    ('\u{0D46}', '\u{0D57}', '\u{0D4C}'),
+    ('\u{0D47}', '\u{0D3E}', '\u{0D4B}'),
+    ('\u{0DD9}', '\u{0DCA}', '\u{0DDA}'),
+    ('\u{0DD9}', '\u{0DCF}', '\u{0DDC}'),
+    ('\u{0DD9}', '\u{0DDF}', '\u{0DDE}'),
+    ('\u{0DDC}', '\u{0DCA}', '\u{0DDD}'),
+    ('\u{1025}', '\u{102E}', '\u{1026}'),
+    ('\u{1B05}', '\u{1B35}', '\u{1B06}'),
+    ('\u{1B07}', '\u{1B35}', '\u{1B08}'),
+    ('\u{1B09}', '\u{1B35}', '\u{1B0A}'),
+    ('\u{1B0B}', '\u{1B35}', '\u{1B0C}'),
+    ('\u{1B0D}', '\u{1B35}', '\u{1B0E}'),
+    ('\u{1B11}', '\u{1B35}', '\u{1B12}'),
+    ('\u{1B3A}', '\u{1B35}', '\u{1B3B}'),
+    ('\u{1B3C}', '\u{1B35}', '\u{1B3D}'),
+    ('\u{1B3E}', '\u{1B35}', '\u{1B40}'),
+    ('\u{1B3F}', '\u{1B35}', '\u{1B41}'),
+    ('\u{1B42}', '\u{1B35}', '\u{1B43}'),
+    ('\u{1E36}', '\u{0304}', '\u{1E38}'),
+    ('\u{1E37}', '\u{0304}', '\u{1E39}'),
+    ('\u{1E5A}', '\u{0304}', '\u{1E5C}'),
+    ('\u{1E5B}', '\u{0304}', '\u{1E5D}'),
+    ('\u{1E62}', '\u{0307}', '\u{1E68}'),
+    ('\u{1E63}', '\u{0307}', '\u{1E69}'),
+    ('\u{1EA0}', '\u{0302}', '\u{1EAC}'),
+    ('\u{1EA0}', '\u{0306}', '\u{1EB6}'),
+    ('\u{1EA1}', '\u{0302}', '\u{1EAD}'),
+    ('\u{1EA1}', '\u{0306}', '\u{1EB7}'),
+    ('\u{1EB8}', '\u{0302}', '\u{1EC6}'),
+    ('\u{1EB9}', '\u{0302}', '\u{1EC7}'),
+    ('\u{1ECC}', '\u{0302}', '\u{1ED8}'),
+    ('\u{1ECD}', '\u{0302}', '\u{1ED9}'),
+    ('\u{1F00}', '\u{0300}', '\u{1F02}'),
+    ('\u{1F00}', '\u{0301}', '\u{1F04}'),
+    ('\u{1F00}', '\u{0342}', '\u{1F06}'),
+    ('\u{1F00}', '\u{0345}', '\u{1F80}'),
+    ('\u{1F01}', '\u{0300}', '\u{1F03}'),
+    ('\u{1F01}', '\u{0301}', '\u{1F05}'),
+    ('\u{1F01}', '\u{0342}', '\u{1F07}'),
+    ('\u{1F01}', '\u{0345}', '\u{1F81}'),
+    ('\u{1F02}', '\u{0345}', '\u{1F82}'),
+    ('\u{1F03}', '\u{0345}', '\u{1F83}'),
+    ('\u{1F04}', '\u{0345}', '\u{1F84}'),
+    ('\u{1F05}', '\u{0345}', '\u{1F85}'),
+    ('\u{1F06}', '\u{0345}', '\u{1F86}'),
+    ('\u{1F07}', '\u{0345}', '\u{1F87}'),
+    ('\u{1F08}', '\u{0300}', '\u{1F0A}'),
+    ('\u{1F08}', '\u{0301}', '\u{1F0C}'),
+    ('\u{1F08}', '\u{0342}', '\u{1F0E}'),
+    ('\u{1F08}', '\u{0345}', '\u{1F88}'),
+    ('\u{1F09}', '\u{0300}', '\u{1F0B}'),
+    ('\u{1F09}', '\u{0301}', '\u{1F0D}'),
+    ('\u{1F09}', '\u{0342}', '\u{1F0F}'),
+    ('\u{1F09}', '\u{0345}', '\u{1F89}'),
+    ('\u{1F0A}', '\u{0345}', '\u{1F8A}'),
+    ('\u{1F0B}', '\u{0345}', '\u{1F8B}'),
+    ('\u{1F0C}', '\u{0345}', '\u{1F8C}'),
+    ('\u{1F0D}', '\u{0345}', '\u{1F8D}'),
+    ('\u{1F0E}', '\u{0345}', '\u{1F8E}'),
+    ('\u{1F0F}', '\u{0345}', '\u{1F8F}'),
+    ('\u{1F10}', '\u{0300}', '\u{1F12}'),
+    ('\u{1F10}', '\u{0301}', '\u{1F14}'),
+    ('\u{1F11}', '\u{0300}', '\u{1F13}'),
+    ('\u{1F11}', '\u{0301}', '\u{1F15}'),
+    ('\u{1F18}', '\u{0300}', '\u{1F1A}'),
+    ('\u{1F18}', '\u{0301}', '\u{1F1C}'),
+    ('\u{1F19}', '\u{0300}', '\u{1F1B}'),
+    ('\u{1F19}', '\u{0301}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{0300}', '\u{1F22}'),
+    ('\u{1F20}', '\u{0301}', '\u{1F24}'),
+    ('\u{1F20}', '\u{0342}', '\u{1F26}'),
+    ('\u{1F20}', '\u{0345}', '\u{1F90}'),
+    ('\u{1F21}', '\u{0300}', '\u{1F23}'),
+    ('\u{1F21}', '\u{0301}', '\u{1F25}'),
+    ('\u{1F21}', '\u{0342}', '\u{1F27}'),
+    ('\u{1F21}', '\u{0345}', '\u{1F91}'),
+    ('\u{1F22}', '\u{0345}', '\u{1F92}'),
+    ('\u{1F23}', '\u{0345}', '\u{1F93}'),
+    ('\u{1F24}', '\u{0345}', '\u{1F94}'),
+    ('\u{1F25}', '\u{0345}', '\u{1F95}'),
+    ('\u{1F26}', '\u{0345}', '\u{1F96}'),
+    ('\u{1F27}', '\u{0345}', '\u{1F97}'),
+    ('\u{1F28}', '\u{0300}', '\u{1F2A}'),
+    ('\u{1F28}', '\u{0301}', '\u{1F2C}'),
+    ('\u{1F28}', '\u{0342}', '\u{1F2E}'),
+    ('\u{1F28}', '\u{0345}', '\u{1F98}'),
+    ('\u{1F29}', '\u{0300}', '\u{1F2B}'),
+    ('\u{1F29}', '\u{0301}', '\u{1F2D}'),
+    ('\u{1F29}', '\u{0342}', '\u{1F2F}'),
+    ('\u{1F29}', '\u{0345}', '\u{1F99}'),
+    ('\u{1F2A}', '\u{0345}', '\u{1F9A}'),
+    ('\u{1F2B}', '\u{0345}', '\u{1F9B}'),
+    ('\u{1F2C}', '\u{0345}', '\u{1F9C}'),
+    ('\u{1F2D}', '\u{0345}', '\u{1F9D}'),
+    ('\u{1F2E}', '\u{0345}', '\u{1F9E}'),
+    ('\u{1F2F}', '\u{0345}', '\u{1F9F}'),
+    ('\u{1F30}', '\u{0300}', '\u{1F32}'),
+    ('\u{1F30}', '\u{0301}', '\u{1F34}'),
+    ('\u{1F30}', '\u{0342}', '\u{1F36}'),
+    ('\u{1F31}', '\u{0300}', '\u{1F33}'),
+    ('\u{1F31}', '\u{0301}', '\u{1F35}'),
+    ('\u{1F31}', '\u{0342}', '\u{1F37}'),
+    ('\u{1F38}', '\u{0300}', '\u{1F3A}'),
+    ('\u{1F38}', '\u{0301}', '\u{1F3C}'),
+    ('\u{1F38}', '\u{0342}', '\u{1F3E}'),
+    ('\u{1F39}', '\u{0300}', '\u{1F3B}'),
+    ('\u{1F39}', '\u{0301}', '\u{1F3D}'),
+    ('\u{1F39}', '\u{0342}', '\u{1F3F}'),
+    ('\u{1F40}', '\u{0300}', '\u{1F42}'),
+    ('\u{1F40}', '\u{0301}', '\u{1F44}'),
+    ('\u{1F41}', '\u{0300}', '\u{1F43}'),
+    ('\u{1F41}', '\u{0301}', '\u{1F45}'),
+    ('\u{1F48}', '\u{0300}', '\u{1F4A}'),
+    ('\u{1F48}', '\u{0301}', '\u{1F4C}'),
+    ('\u{1F49}', '\u{0300}', '\u{1F4B}'),
+    ('\u{1F49}', '\u{0301}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{0300}', '\u{1F52}'),
+    ('\u{1F50}', '\u{0301}', '\u{1F54}'),
+    ('\u{1F50}', '\u{0342}', '\u{1F56}'),
+    ('\u{1F51}', '\u{0300}', '\u{1F53}'),
+    ('\u{1F51}', '\u{0301}', '\u{1F55}'),
+    ('\u{1F51}', '\u{0342}', '\u{1F57}'),
+    ('\u{1F59}', '\u{0300}', '\u{1F5B}'),
+    ('\u{1F59}', '\u{0301}', '\u{1F5D}'),
+    ('\u{1F59}', '\u{0342}', '\u{1F5F}'),
+    ('\u{1F60}', '\u{0300}', '\u{1F62}'),
+    ('\u{1F60}', '\u{0301}', '\u{1F64}'),
+    ('\u{1F60}', '\u{0342}', '\u{1F66}'),
+    ('\u{1F60}', '\u{0345}', '\u{1FA0}'),
+    ('\u{1F61}', '\u{0300}', '\u{1F63}'),
+    ('\u{1F61}', '\u{0301}', '\u{1F65}'),
+    ('\u{1F61}', '\u{0342}', '\u{1F67}'),
+    ('\u{1F61}', '\u{0345}', '\u{1FA1}'),
+    ('\u{1F62}', '\u{0345}', '\u{1FA2}'),
+    ('\u{1F63}', '\u{0345}', '\u{1FA3}'),
+    ('\u{1F64}', '\u{0345}', '\u{1FA4}'),
+    ('\u{1F65}', '\u{0345}', '\u{1FA5}'),
+    ('\u{1F66}', '\u{0345}', '\u{1FA6}'),
+    ('\u{1F67}', '\u{0345}', '\u{1FA7}'),
+    ('\u{1F68}', '\u{0300}', '\u{1F6A}'),
+    ('\u{1F68}', '\u{0301}', '\u{1F6C}'),
+    ('\u{1F68}', '\u{0342}', '\u{1F6E}'),
+    ('\u{1F68}', '\u{0345}', '\u{1FA8}'),
+    ('\u{1F69}', '\u{0300}', '\u{1F6B}'),
+    ('\u{1F69}', '\u{0301}', '\u{1F6D}'),
+    ('\u{1F69}', '\u{0342}', '\u{1F6F}'),
+    ('\u{1F69}', '\u{0345}', '\u{1FA9}'),
+    ('\u{1F6A}', '\u{0345}', '\u{1FAA}'),
+    ('\u{1F6B}', '\u{0345}', '\u{1FAB}'),
+    ('\u{1F6C}', '\u{0345}', '\u{1FAC}'),
+    ('\u{1F6D}', '\u{0345}', '\u{1FAD}'),
+    ('\u{1F6E}', '\u{0345}', '\u{1FAE}'),
+    ('\u{1F6F}', '\u{0345}', '\u{1FAF}'),
+    ('\u{1F70}', '\u{0345}', '\u{1FB2}'),
+    ('\u{1F74}', '\u{0345}', '\u{1FC2}'),
+    ('\u{1F7C}', '\u{0345}', '\u{1FF2}'),
+    ('\u{1FB6}', '\u{0345}', '\u{1FB7}'),
+    ('\u{1FBF}', '\u{0300}', '\u{1FCD}'),
+    ('\u{1FBF}', '\u{0301}', '\u{1FCE}'),
+    ('\u{1FBF}', '\u{0342}', '\u{1FCF}'),
+    ('\u{1FC6}', '\u{0345}', '\u{1FC7}'),
+    ('\u{1FF6}', '\u{0345}', '\u{1FF7}'),
+    ('\u{1FFE}', '\u{0300}', '\u{1FDD}'),
+    ('\u{1FFE}', '\u{0301}', '\u{1FDE}'),
+    ('\u{1FFE}', '\u{0342}', '\u{1FDF}'),
+    ('\u{2190}', '\u{0338}', '\u{219A}'),
+    ('\u{2192}', '\u{0338}', '\u{219B}'),
+    ('\u{2194}', '\u{0338}', '\u{21AE}'),
+    ('\u{21D0}', '\u{0338}', '\u{21CD}'),
+    ('\u{21D2}', '\u{0338}', '\u{21CF}'),
+    ('\u{21D4}', '\u{0338}', '\u{21CE}'),
+    ('\u{2203}', '\u{0338}', '\u{2204}'),
+    ('\u{2208}', '\u{0338}', '\u{2209}'),
+    ('\u{220B}', '\u{0338}', '\u{220C}'),
+    ('\u{2223}', '\u{0338}', '\u{2224}'),
+    ('\u{2225}', '\u{0338}', '\u{2226}'),
+    ('\u{223C}', '\u{0338}', '\u{2241}'),
+    ('\u{2243}', '\u{0338}', '\u{2244}'),
+    ('\u{2245}', '\u{0338}', '\u{2247}'),
+    ('\u{2248}', '\u{0338}', '\u{2249}'),
+    ('\u{224D}', '\u{0338}', '\u{226D}'),
+    ('\u{2261}', '\u{0338}', '\u{2262}'),
+    ('\u{2264}', '\u{0338}', '\u{2270}'),
+    ('\u{2265}', '\u{0338}', '\u{2271}'),
+    ('\u{2272}', '\u{0338}', '\u{2274}'),
+    ('\u{2273}', '\u{0338}', '\u{2275}'),
+    ('\u{2276}', '\u{0338}', '\u{2278}'),
+    ('\u{2277}', '\u{0338}', '\u{2279}'),
+    ('\u{227A}', '\u{0338}', '\u{2280}'),
+    ('\u{227B}', '\u{0338}', '\u{2281}'),
+    ('\u{227C}', '\u{0338}', '\u{22E0}'),
+    ('\u{227D}', '\u{0338}', '\u{22E1}'),
+    ('\u{2282}', '\u{0338}', '\u{2284}'),
+    ('\u{2283}', '\u{0338}', '\u{2285}'),
+    ('\u{2286}', '\u{0338}', '\u{2288}'),
+    ('\u{2287}', '\u{0338}', '\u{2289}'),
+    ('\u{2291}', '\u{0338}', '\u{22E2}'),
+    ('\u{2292}', '\u{0338}', '\u{22E3}'),
+    ('\u{22A2}', '\u{0338}', '\u{22AC}'),
+    ('\u{22A8}', '\u{0338}', '\u{22AD}'),
+    ('\u{22A9}', '\u{0338}', '\u{22AE}'),
+    ('\u{22AB}', '\u{0338}', '\u{22AF}'),
+    ('\u{22B2}', '\u{0338}', '\u{22EA}'),
+    ('\u{22B3}', '\u{0338}', '\u{22EB}'),
+    ('\u{22B4}', '\u{0338}', '\u{22EC}'),
+    ('\u{22B5}', '\u{0338}', '\u{22ED}'),
+    ('\u{3046}', '\u{3099}', '\u{3094}'),
+    ('\u{304B}', '\u{3099}', '\u{304C}'),
+    ('\u{304D}', '\u{3099}', '\u{304E}'),
+    ('\u{304F}', '\u{3099}', '\u{3050}'),
+    ('\u{3051}', '\u{3099}', '\u{3052}'),
+    ('\u{3053}', '\u{3099}', '\u{3054}'),
+    ('\u{3055}', '\u{3099}', '\u{3056}'),
+    ('\u{3057}', '\u{3099}', '\u{3058}'),
+    ('\u{3059}', '\u{3099}', '\u{305A}'),
+    ('\u{305B}', '\u{3099}', '\u{305C}'),
+    ('\u{305D}', '\u{3099}', '\u{305E}'),
+    ('\u{305F}', '\u{3099}', '\u{3060}'),
+    ('\u{3061}', '\u{3099}', '\u{3062}'),
+    ('\u{3064}', '\u{3099}', '\u{3065}'),
+    ('\u{3066}', '\u{3099}', '\u{3067}'),
+    ('\u{3068}', '\u{3099}', '\u{3069}'),
+    ('\u{306F}', '\u{3099}', '\u{3070}'),
+    ('\u{306F}', '\u{309A}', '\u{3071}'),
+    ('\u{3072}', '\u{3099}', '\u{3073}'),
+    ('\u{3072}', '\u{309A}', '\u{3074}'),
+    ('\u{3075}', '\u{3099}', '\u{3076}'),
+    ('\u{3075}', '\u{309A}', '\u{3077}'),
+    ('\u{3078}', '\u{3099}', '\u{3079}'),
+    ('\u{3078}', '\u{309A}', '\u{307A}'),
+    ('\u{307B}', '\u{3099}', '\u{307C}'),
+    ('\u{307B}', '\u{309A}', '\u{307D}'),
+    ('\u{309D}', '\u{3099}', '\u{309E}'),
+    ('\u{30A6}', '\u{3099}', '\u{30F4}'),
+    ('\u{30AB}', '\u{3099}', '\u{30AC}'),
+    ('\u{30AD}', '\u{3099}', '\u{30AE}'),
+    ('\u{30AF}', '\u{3099}', '\u{30B0}'),
+    ('\u{30B1}', '\u{3099}', '\u{30B2}'),
+    ('\u{30B3}', '\u{3099}', '\u{30B4}'),
+    ('\u{30B5}', '\u{3099}', '\u{30B6}'),
+    ('\u{30B7}', '\u{3099}', '\u{30B8}'),
+    ('\u{30B9}', '\u{3099}', '\u{30BA}'),
+    ('\u{30BB}', '\u{3099}', '\u{30BC}'),
+    ('\u{30BD}', '\u{3099}', '\u{30BE}'),
+    ('\u{30BF}', '\u{3099}', '\u{30C0}'),
+    ('\u{30C1}', '\u{3099}', '\u{30C2}'),
+    ('\u{30C4}', '\u{3099}', '\u{30C5}'),
+    ('\u{30C6}', '\u{3099}', '\u{30C7}'),
+    ('\u{30C8}', '\u{3099}', '\u{30C9}'),
+    ('\u{30CF}', '\u{3099}', '\u{30D0}'),
+    ('\u{30CF}', '\u{309A}', '\u{30D1}'),
+    ('\u{30D2}', '\u{3099}', '\u{30D3}'),
+    ('\u{30D2}', '\u{309A}', '\u{30D4}'),
+    ('\u{30D5}', '\u{3099}', '\u{30D6}'),
+    ('\u{30D5}', '\u{309A}', '\u{30D7}'),
+    ('\u{30D8}', '\u{3099}', '\u{30D9}'),
+    ('\u{30D8}', '\u{309A}', '\u{30DA}'),
+    ('\u{30DB}', '\u{3099}', '\u{30DC}'),
+    ('\u{30DB}', '\u{309A}', '\u{30DD}'),
+    ('\u{30EF}', '\u{3099}', '\u{30F7}'),
+    ('\u{30F0}', '\u{3099}', '\u{30F8}'),
+    ('\u{30F1}', '\u{3099}', '\u{30F9}'),
+    ('\u{30F2}', '\u{3099}', '\u{30FA}'),
+    ('\u{30FD}', '\u{3099}', '\u{30FE}'),
+    ('\u{105D2}', '\u{0307}', '\u{105C9}'),
+    ('\u{105DA}', '\u{0307}', '\u{105E4}'),
+    ('\u{11099}', '\u{110BA}', '\u{1109A}'),
+    ('\u{1109B}', '\u{110BA}', '\u{1109C}'),
+    ('\u{110A5}', '\u{110BA}', '\u{110AB}'),
+    ('\u{11131}', '\u{11127}', '\u{1112E}'),
+    ('\u{11132}', '\u{11127}', '\u{1112F}'),
+    ('\u{11347}', '\u{1133E}', '\u{1134B}'),
+    ('\u{11347}', '\u{11357}', '\u{1134C}'),
+    ('\u{11382}', '\u{113C9}', '\u{11383}'),
+    ('\u{11384}', '\u{113BB}', '\u{11385}'),
+    ('\u{1138B}', '\u{113C2}', '\u{1138E}'),
+    ('\u{11390}', '\u{113C9}', '\u{11391}'),
+    ('\u{113C2}', '\u{113B8}', '\u{113C7}'),
+    ('\u{113C2}', '\u{113C2}', '\u{113C5}'),
+    ('\u{113C2}', '\u{113C9}', '\u{113C8}'),
+    ('\u{114B9}', '\u{114B0}', '\u{114BC}'),
+    ('\u{114B9}', '\u{114BA}', '\u{114BB}'),
+    ('\u{114B9}', '\u{114BD}', '\u{114BE}'),
+    ('\u{115B8}', '\u{115AF}', '\u{115BA}'),
+    ('\u{115B9}', '\u{115AF}', '\u{115BB}'),
+    ('\u{11935}', '\u{11930}', '\u{11938}'),
+    ('\u{1611E}', '\u{1611E}', '\u{16121}'),
+    ('\u{1611E}', '\u{1611F}', '\u{16123}'),
+    ('\u{1611E}', '\u{16120}', '\u{16125}'),
+    ('\u{1611E}', '\u{16129}', '\u{16122}'),
+    ('\u{16121}', '\u{1611F}', '\u{16126}'),
+    ('\u{16121}', '\u{16120}', '\u{16128}'),
+    ('\u{16122}', '\u{1611F}', '\u{16127}'),
+    ('\u{16129}', '\u{1611F}', '\u{16124}'),
+    ('\u{16D63}', '\u{16D67}', '\u{16D69}'),
+    ('\u{16D67}', '\u{16D67}', '\u{16D68}'),
+    ('\u{16D69}', '\u{16D67}', '\u{16D6A}'),
+];
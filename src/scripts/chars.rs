@@ -1,300 +1,281 @@
-pub(crate) fn is_cyrillic(ch: char) -> bool {
-    matches!(ch,
-        '\u{0400}'..='\u{0484}'
-        | '\u{0487}'..='\u{052F}'
-        | '\u{2DE0}'..='\u{2DFF}'
-        | '\u{A640}'..='\u{A69D}'
-        | '\u{1D2B}'
-        | '\u{1D78}'
-        | '\u{A69F}'
-    )
-}
-
-// https://en.wikipedia.org/wiki/Latin_script_in_Unicode
-pub(crate) fn is_latin(ch: char) -> bool {
-    matches!(ch,
-        'a'..='z'
-        | 'A'..='Z'
-        | '\u{0080}'..='\u{00FF}'
-        | '\u{0100}'..='\u{017F}'
-        | '\u{0180}'..='\u{024F}'
-        | '\u{0250}'..='\u{02AF}'
-        | '\u{1D00}'..='\u{1D7F}'
-        | '\u{1D80}'..='\u{1DBF}'
-        | '\u{1E00}'..='\u{1EFF}'
-        | '\u{2100}'..='\u{214F}'
-        | '\u{2C60}'..='\u{2C7F}'
-        | '\u{A720}'..='\u{A7FF}'
-        | '\u{AB30}'..='\u{AB6F}'
-    )
-}
-
-// Based on https://en.wikipedia.org/wiki/Arabic_script_in_Unicode
-pub(crate) fn is_arabic(ch: char) -> bool {
-    matches!(ch,
-        '\u{0600}'..='\u{06FF}'
-        | '\u{0750}'..='\u{07FF}'
-        | '\u{08A0}'..='\u{08FF}'
-        | '\u{FB50}'..='\u{FDFF}'
-        | '\u{FE70}'..='\u{FEFF}'
-        | '\u{10E60}'..='\u{10E7F}'
-        | '\u{1EE00}'..='\u{1EEFF}'
-    )
-}
-
-// Based on https://en.wikipedia.org/wiki/Devanagari#Unicode
-pub(crate) fn is_devanagari(ch: char) -> bool {
-    matches!(ch, '\u{0900}'..='\u{097F}' | '\u{A8E0}'..='\u{A8FF}' | '\u{1CD0}'..='\u{1CFF}')
-}
-
-// Based on https://www.key-shortcut.com/en/writing-systems/ethiopian-script/
-pub(crate) fn is_ethiopic(ch: char) -> bool {
-    matches!(ch, '\u{1200}'..='\u{139F}' | '\u{2D80}'..='\u{2DDF}' | '\u{AB00}'..='\u{AB2F}')
-}
-
-// Based on https://en.wikipedia.org/wiki/Hebrew_(Unicode_block)
-pub(crate) fn is_hebrew(ch: char) -> bool {
-    matches!(ch, '\u{0590}'..='\u{05FF}')
-}
-
-pub(crate) fn is_georgian(ch: char) -> bool {
-    matches!(ch, '\u{10A0}'..='\u{10FF}')
-}
-
-pub(crate) fn is_mandarin(ch: char) -> bool {
-    matches!(ch,
-        '\u{2E80}'..='\u{2E99}'
-        | '\u{2E9B}'..='\u{2EF3}'
-        | '\u{2F00}'..='\u{2FD5}'
-        | '\u{3005}'
-        | '\u{3007}'
-        | '\u{3021}'..='\u{3029}'
-        | '\u{3038}'..='\u{303B}'
-        | '\u{3400}'..='\u{4DB5}'
-        | '\u{4E00}'..='\u{9FCC}'
-        | '\u{F900}'..='\u{FA6D}'
-        | '\u{FA70}'..='\u{FAD9}'
-    )
-}
-
-pub(crate) fn is_bengali(ch: char) -> bool {
-    matches!(ch, '\u{0980}'..='\u{09FF}')
-}
-
-pub(crate) fn is_hiragana(ch: char) -> bool {
-    matches!(ch, '\u{3040}'..='\u{309F}')
-}
-
-pub(crate) fn is_katakana(ch: char) -> bool {
-    matches!(ch, '\u{30A0}'..='\u{30FF}')
-}
-
-// Hangul is Korean Alphabet. Unicode ranges are taken from: https://en.wikipedia.org/wiki/Hangul
-pub(crate) fn is_hangul(ch: char) -> bool {
-    matches!(ch,
-        '\u{AC00}'..='\u{D7AF}'
-        | '\u{1100}'..='\u{11FF}'
-        | '\u{3130}'..='\u{318F}'
-        | '\u{3200}'..='\u{32FF}'
-        | '\u{A960}'..='\u{A97F}'
-        | '\u{D7B0}'..='\u{D7FF}'
-        | '\u{FF00}'..='\u{FFEF}'
-    )
-}
-
-// Taken from: https://en.wikipedia.org/wiki/Greek_and_Coptic
-pub(crate) fn is_greek(ch: char) -> bool {
-    matches!(ch, '\u{0370}'..='\u{03FF}')
-}
-
-// Based on: https://en.wikipedia.org/wiki/Kannada_(Unicode_block)
-pub(crate) fn is_kannada(ch: char) -> bool {
-    matches!(ch, '\u{0C80}'..='\u{0CFF}')
-}
-
-// Based on: https://en.wikipedia.org/wiki/Tamil_(Unicode_block)
-pub(crate) fn is_tamil(ch: char) -> bool {
-    matches!(ch, '\u{0B80}'..='\u{0BFF}')
-}
-
-// Based on: https://en.wikipedia.org/wiki/Thai_(Unicode_block)
-pub(crate) fn is_thai(ch: char) -> bool {
-    matches!(ch, '\u{0E00}'..='\u{0E7F}')
-}
-
-// Based on: https://en.wikipedia.org/wiki/Gujarati_(Unicode_block)
-pub(crate) fn is_gujarati(ch: char) -> bool {
-    matches!(ch, '\u{0A80}'..='\u{0AFF}')
-}
-
-// Gurmukhi is the script for Punjabi language.
-// Based on: https://en.wikipedia.org/wiki/Gurmukhi_(Unicode_block)
-pub(crate) fn is_gurmukhi(ch: char) -> bool {
-    matches!(ch, '\u{0A00}'..='\u{0A7F}')
-}
-
-pub(crate) fn is_telugu(ch: char) -> bool {
-    matches!(ch, '\u{0C00}'..='\u{0C7F}')
-}
-
-// Based on: https://en.wikipedia.org/wiki/Malayalam_(Unicode_block)
-pub(crate) fn is_malayalam(ch: char) -> bool {
-    matches!(ch, '\u{0D00}'..='\u{0D7F}')
-}
-
-// Based on: https://en.wikipedia.org/wiki/Malayalam_(Unicode_block)
-pub(crate) fn is_oriya(ch: char) -> bool {
-    matches!(ch, '\u{0B00}'..='\u{0B7F}')
-}
-
-// Based on: https://en.wikipedia.org/wiki/Myanmar_(Unicode_block)
-pub(crate) fn is_myanmar(ch: char) -> bool {
-    matches!(ch, '\u{1000}'..='\u{109F}')
-}
-
-// Based on: https://en.wikipedia.org/wiki/Sinhala_(Unicode_block)
-pub(crate) fn is_sinhala(ch: char) -> bool {
-    matches!(ch, '\u{0D80}'..='\u{0DFF}')
+//! Classification of characters by script, based on the Script property of Unicode.
+//!
+//! A character is looked up in a two-level table (see [tables](super::tables)): the upper bits of
+//! a code point select a block, the lower bits select the script within the block. Identical blocks
+//! (e.g. blocks of unassigned code points) are stored only once.
+//!
+//! Characters of the `Common` and `Inherited` scripts (combining marks, etc) are attributed to
+//! a script only if their Script_Extensions property names exactly one of the supported scripts
+//! (e.g. the Arabic tatweel `ـ`).
+//!
+//! Stop characters (spaces, punctuation, symbols, digits and control characters according to
+//! the General_Category property) have no script, even if they belong to one
+//! (e.g. Devanagari digits or the Armenian full stop `։`). The same tables tell whether
+//! a character is a stop character, see [is_stop_char](crate::utils::is_stop_char).
+
+use super::script::Script;
+use super::tables::{BLOCK_INDEXES, BLOCK_SHIFT, BLOCKS, STOP};
+
+const BLOCK_MASK: usize = (1 << BLOCK_SHIFT) - 1;
+
+#[inline]
+fn char_code(ch: char) -> u8 {
+    let code_point = ch as usize;
+    match BLOCK_INDEXES.get(code_point >> BLOCK_SHIFT) {
+        Some(&block) => BLOCKS[block as usize][code_point & BLOCK_MASK],
+        None => 0,
+    }
 }
 
-// Based on: https://en.wikipedia.org/wiki/Khmer_alphabet
-pub(crate) fn is_khmer(ch: char) -> bool {
-    matches!(ch, '\u{1780}'..='\u{17FF}' | '\u{19E0}'..='\u{19FF}')
+/// Script of the character, if it's one of the supported scripts.
+#[inline]
+pub(crate) fn char_script(ch: char) -> Option<Script> {
+    match char_code(ch) {
+        0 | STOP => None,
+        code => Some(Script::all()[code as usize - 1]),
+    }
 }
 
-// See:
-// * https://en.wikipedia.org/wiki/Armenian_alphabet
-// * https://www.unicode.org/charts/PDF/U0530.pdf
-// * https://www.unicode.org/charts/PDF/UFB00.pdf
-pub(crate) fn is_armenian(ch: char) -> bool {
-    matches!(ch, '\u{0530}'..='\u{058F}' | '\u{FB13}'..='\u{FB17}')
+/// Whether the character is a stop character, see the module documentation.
+#[inline]
+pub(crate) fn is_stop_char(ch: char) -> bool {
+    char_code(ch) == STOP
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[test]
-    fn test_is_latin() {
-        assert_eq!(is_latin('z'), true);
-        assert_eq!(is_latin('A'), true);
-        assert_eq!(is_latin('č'), true);
-        assert_eq!(is_latin('š'), true);
-        assert_eq!(is_latin('Ĵ'), true);
-
-        assert_eq!(is_latin('ж'), false);
-    }
-
-    #[test]
-    fn test_is_cyrillic() {
-        assert_eq!(is_cyrillic('а'), true);
-        assert_eq!(is_cyrillic('Я'), true);
-        assert_eq!(is_cyrillic('Ґ'), true);
-        assert_eq!(is_cyrillic('ї'), true);
-        assert_eq!(is_cyrillic('Ꙕ'), true);
-
-        assert_eq!(is_cyrillic('L'), false);
-    }
-
-    #[test]
-    fn test_is_ethiopic() {
-        assert_eq!(is_ethiopic('ፚ'), true);
-        assert_eq!(is_ethiopic('ᎀ'), true);
-
-        assert_eq!(is_ethiopic('а'), false);
-        assert_eq!(is_ethiopic('L'), false);
+    use core::fmt::Write;
+    use unicode_general_category::{GeneralCategory, get_general_category};
+    use unicode_script::{Script as UnicodeScript, UnicodeScript as _};
+
+    const TABLES_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/scripts/tables.rs");
+
+    fn to_script(script: UnicodeScript) -> Option<Script> {
+        let script = match script {
+            UnicodeScript::Arabic => Script::Arabic,
+            UnicodeScript::Armenian => Script::Armenian,
+            UnicodeScript::Bengali => Script::Bengali,
+            UnicodeScript::Cyrillic => Script::Cyrillic,
+            UnicodeScript::Devanagari => Script::Devanagari,
+            UnicodeScript::Ethiopic => Script::Ethiopic,
+            UnicodeScript::Georgian => Script::Georgian,
+            UnicodeScript::Greek => Script::Greek,
+            UnicodeScript::Gujarati => Script::Gujarati,
+            UnicodeScript::Gurmukhi => Script::Gurmukhi,
+            UnicodeScript::Hangul => Script::Hangul,
+            UnicodeScript::Hebrew => Script::Hebrew,
+            UnicodeScript::Hiragana => Script::Hiragana,
+            UnicodeScript::Kannada => Script::Kannada,
+            UnicodeScript::Katakana => Script::Katakana,
+            UnicodeScript::Khmer => Script::Khmer,
+            UnicodeScript::Latin => Script::Latin,
+            UnicodeScript::Malayalam => Script::Malayalam,
+            UnicodeScript::Han => Script::Mandarin,
+            UnicodeScript::Myanmar => Script::Myanmar,
+            UnicodeScript::Oriya => Script::Oriya,
+            UnicodeScript::Sinhala => Script::Sinhala,
+            UnicodeScript::Tamil => Script::Tamil,
+            UnicodeScript::Telugu => Script::Telugu,
+            UnicodeScript::Thai => Script::Thai,
+            _ => return None,
+        };
+        Some(script)
     }
 
-    #[test]
-    fn test_is_georgian() {
-        assert_eq!(is_georgian('რ'), true);
-        assert_eq!(is_georgian('ж'), false);
+    // Stop character according to the Unicode data, see the module documentation.
+    fn unicode_is_stop_char(ch: char) -> bool {
+        let category = get_general_category(ch);
+        category == GeneralCategory::Control
+            || matches!(
+                category.abbreviation().as_bytes()[0],
+                b'P' | b'S' | b'N' | b'Z'
+            )
     }
 
-    #[test]
-    fn test_is_bengali() {
-        assert_eq!(is_bengali('ই'), true);
-        assert_eq!(is_bengali('z'), false);
+    // Script according to the Unicode data, see the module documentation.
+    fn unicode_char_script(ch: char) -> Option<Script> {
+        if unicode_is_stop_char(ch) {
+            return None;
+        }
+        match ch.script() {
+            UnicodeScript::Common | UnicodeScript::Inherited => {
+                let extension = ch.script_extension();
+                if extension.is_common() || extension.is_inherited() {
+                    return None;
+                }
+                let mut scripts = extension.iter().filter_map(to_script);
+                match (scripts.next(), scripts.next()) {
+                    (Some(script), None) => Some(script),
+                    _ => None,
+                }
+            }
+            script => to_script(script),
+        }
     }
 
-    #[test]
-    fn test_is_katakana() {
-        assert_eq!(is_katakana('カ'), true);
-        assert_eq!(is_katakana('f'), false);
+    // Characters of a newer version of Unicode would get a script, but not a general category.
+    fn assert_unicode_data_version() {
+        assert_eq!(unicode_script::UNICODE_VERSION, crate::UNICODE_VERSION);
+        assert_eq!(
+            unicode_general_category::UNICODE_VERSION,
+            crate::UNICODE_VERSION
+        );
     }
 
-    #[test]
-    fn test_is_hiragana() {
-        assert_eq!(is_hiragana('ひ'), true);
-        assert_eq!(is_hiragana('a'), false);
-    }
-
-    #[test]
-    fn test_is_hangul() {
-        assert_eq!(is_hangul('ᄁ'), true);
-        assert_eq!(is_hangul('t'), false);
-    }
-
-    #[test]
-    fn test_is_greek() {
-        assert_eq!(is_greek('φ'), true);
-        assert_eq!(is_greek('ф'), false);
-    }
-
-    #[test]
-    fn test_is_kannada() {
-        assert_eq!(is_kannada('ಡ'), true);
-        assert_eq!(is_kannada('S'), false);
-    }
-
-    #[test]
-    fn test_is_tamil() {
-        assert_eq!(is_tamil('ஐ'), true);
-        assert_eq!(is_tamil('Ж'), false);
-    }
-
-    #[test]
-    fn test_is_thai() {
-        assert_eq!(is_thai('ก'), true);
-        assert_eq!(is_thai('๛'), true);
-        assert_eq!(is_thai('Ж'), false);
-    }
-
-    #[test]
-    fn test_is_gujarati() {
-        assert_eq!(is_gujarati('ઁ'), true);
-        assert_eq!(is_gujarati('૱'), true);
-        assert_eq!(is_gujarati('Ж'), false);
+    fn render_tables() -> String {
+        const SHIFT: u32 = 7;
+        let block_len = 1 << SHIFT;
+        assert_unicode_data_version();
+
+        let codes: Vec<u8> = (0..=char::MAX as u32)
+            .map(|code_point| {
+                let Some(ch) = char::from_u32(code_point) else {
+                    return 0;
+                };
+                if unicode_is_stop_char(ch) {
+                    return STOP;
+                }
+                unicode_char_script(ch).map_or(0, |script| script as u8 + 1)
+            })
+            .collect();
+        let last = codes.iter().rposition(|&code| code != 0).unwrap();
+
+        // Code points after the last block get no script and are not stop characters
+        let blocks_count = last / block_len + 1;
+        let mut blocks: Vec<&[u8]> = Vec::new();
+        let mut indexes = Vec::new();
+        for block in codes[..blocks_count * block_len].chunks(block_len) {
+            let index = match blocks.iter().position(|b| *b == block) {
+                Some(index) => index,
+                None => {
+                    blocks.push(block);
+                    blocks.len() - 1
+                }
+            };
+            indexes.push(index);
+        }
+        assert!(blocks.len() <= 256);
+
+        let mut out = String::new();
+        let (major, minor, update) = crate::UNICODE_VERSION;
+        writeln!(
+            out,
+            "// Generated from the Script, Script_Extensions and General_Category properties"
+        )
+        .unwrap();
+        writeln!(out, "// of Unicode {major}.{minor}.{update}.").unwrap();
+        writeln!(
+            out,
+            "// Do not edit it manually, see `test_tables_are_up_to_date` in src/scripts/chars.rs."
+        )
+        .unwrap();
+        writeln!(out, "//").unwrap();
+        writeln!(
+            out,
+            "// A script is encoded as its position in `Script::all()` + 1, 0 means no script."
+        )
+        .unwrap();
+        writeln!(out, "// Stop characters are encoded as `STOP`.").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "pub(super) const STOP: u8 = {STOP};").unwrap();
+        writeln!(out).unwrap();
+        writeln!(out, "pub(super) const BLOCK_SHIFT: u32 = {SHIFT};").unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "pub(super) static BLOCK_INDEXES: [u8; {}] = [",
+            indexes.len()
+        )
+        .unwrap();
+        for row in indexes.chunks(32) {
+            let row: Vec<String> = row.iter().map(|i| i.to_string()).collect();
+            writeln!(out, "    {},", row.join(", ")).unwrap();
+        }
+        writeln!(out, "];").unwrap();
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "pub(super) static BLOCKS: [[u8; {}]; {}] = [",
+            block_len,
+            blocks.len()
+        )
+        .unwrap();
+        for block in &blocks {
+            let block: Vec<String> = block.iter().map(|c| c.to_string()).collect();
+            writeln!(out, "    [{}],", block.join(", ")).unwrap();
+        }
+        writeln!(out, "];").unwrap();
+        out
     }
 
+    // Run with `WHATLANG_UPDATE_TABLES=1` to regenerate the tables, e.g. after an update of
+    // the unicode-script and unicode-general-category crates (and `UNICODE_VERSION`).
     #[test]
-    fn test_is_gurmukhi() {
-        assert_eq!(is_gurmukhi('ਁ'), true);
-        assert_eq!(is_gurmukhi('ੴ'), true);
-        assert_eq!(is_gurmukhi('Ж'), false);
+    fn test_tables_are_up_to_date() {
+        let tables = render_tables();
+        if std::env::var_os("WHATLANG_UPDATE_TABLES").is_some() {
+            std::fs::write(TABLES_PATH, &tables).unwrap();
+        }
+        let actual = std::fs::read_to_string(TABLES_PATH).unwrap();
+        assert!(actual == tables, "{TABLES_PATH} is outdated");
     }
 
     #[test]
-    fn test_is_telugu() {
-        assert_eq!(is_telugu('ఁ'), true);
-        assert_eq!(is_telugu('౿'), true);
-        assert_eq!(is_telugu('Ж'), false);
+    fn test_char_script_matches_unicode_data() {
+        assert_unicode_data_version();
+        for ch in char::MIN..=char::MAX {
+            assert_eq!(char_script(ch), unicode_char_script(ch), "{:?}", ch);
+            assert_eq!(is_stop_char(ch), unicode_is_stop_char(ch), "{:?}", ch);
+        }
     }
 
     #[test]
-    fn test_is_oriya() {
-        assert_eq!(is_oriya('ଐ'), true);
-        assert_eq!(is_oriya('୷'), true);
-        assert_eq!(is_oriya('౿'), false);
+    fn test_char_script() {
+        let examples = [
+            (Script::Latin, "zAčšĴªＡ"),
+            (Script::Cyrillic, "аЯҐїꙔ"),
+            (Script::Ethiopic, "ፚᎀ"),
+            (Script::Georgian, "რ"),
+            (Script::Bengali, "ই"),
+            (Script::Katakana, "カｶ"),
+            (Script::Hiragana, "ひ"),
+            (Script::Hangul, "ᄁ한"),
+            (Script::Greek, "φ"),
+            (Script::Kannada, "ಡ"),
+            (Script::Tamil, "ஐ"),
+            (Script::Thai, "กๅ"),
+            (Script::Gujarati, "ઁ"),
+            (Script::Gurmukhi, "ਁੴ"),
+            (Script::Telugu, "ఁ"),
+            (Script::Oriya, "ଐ"),
+            (Script::Armenian, "աԱՓև"),
+            (Script::Arabic, "بـ"),
+            (Script::Mandarin, "水々"),
+        ];
+        for (script, chars) in examples {
+            for ch in chars.chars() {
+                assert_eq!(char_script(ch), Some(script), "{:?}", ch);
+            }
+        }
+
+        // Common characters
+        for ch in " 1,«»©×ー\u{0300}".chars() {
+            assert_eq!(char_script(ch), None, "{:?}", ch);
+        }
     }
 
     #[test]
-    fn test_is_armenian() {
-        assert_eq!(is_armenian('რ'), false); // Georgian
-        assert_eq!(is_armenian('Ш'), false); // Cyrillic
-        assert_eq!(is_armenian('ա'), true);
-        assert_eq!(is_armenian('Ա'), true);
-        assert_eq!(is_armenian('Փ'), true);
-        assert_eq!(is_armenian('և'), true);
+    fn test_is_stop_char() {
+        // Punctuation, symbols, digits and spaces of any script
+        for ch in "«»„“¿—、。「」１２٣۴१੦๛૱౿୷։،😀\u{3000}\u{0085}".chars()
+        {
+            assert!(is_stop_char(ch), "{:?}", ch);
+            assert_eq!(char_script(ch), None, "{:?}", ch);
+        }
+        // Letters and marks
+        for ch in "éßжاअ水ー\u{0300}\u{0307}\u{200D}".chars() {
+            assert!(!is_stop_char(ch), "{:?}", ch);
+        }
     }
 }
@@ -1,10 +1,39 @@
-use std::cmp::Reverse;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
 
 use super::chars;
 use super::script::Script;
-use crate::utils::is_stop_char;
 
-type ScriptCounter = (Script, fn(char) -> bool, usize);
+const SCRIPTS_COUNT: usize = 25;
+
+// Order of scripts with the same number of characters in a text.
+const SCRIPTS_BY_PRIORITY: [Script; SCRIPTS_COUNT] = [
+    Script::Latin,
+    Script::Cyrillic,
+    Script::Arabic,
+    Script::Mandarin,
+    Script::Devanagari,
+    Script::Hebrew,
+    Script::Ethiopic,
+    Script::Georgian,
+    Script::Bengali,
+    Script::Hangul,
+    Script::Hiragana,
+    Script::Katakana,
+    Script::Greek,
+    Script::Kannada,
+    Script::Tamil,
+    Script::Thai,
+    Script::Gujarati,
+    Script::Gurmukhi,
+    Script::Telugu,
+    Script::Malayalam,
+    Script::Oriya,
+    Script::Myanmar,
+    Script::Sinhala,
+    Script::Khmer,
+    Script::Armenian,
+];
 
 /// Detect only a script by a given text.
 /// Works much faster than a complete detection with `detect`.
@@ -20,17 +49,12 @@ pub fn detect_script(text: &str) -> Option<Script> {
     raw_info.main_script()
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct RawScriptInfo {
     pub counters: Vec<(Script, usize)>,
 }
 
 impl RawScriptInfo {
-    fn new(mut counters: Vec<(Script, usize)>) -> Self {
-        counters.sort_unstable_by_key(|(_, score)| Reverse(*score));
-        Self { counters }
-    }
-
     pub(crate) fn main_script(&self) -> Option<Script> {
         // expect - is safe because self.counters is never expected to be empty
         // See raw_detect_script().
@@ -50,71 +74,51 @@ impl RawScriptInfo {
 }
 
 pub fn raw_detect_script(text: &str) -> RawScriptInfo {
-    let mut script_counters: [ScriptCounter; 25] = [
-        (Script::Latin, chars::is_latin, 0),
-        (Script::Cyrillic, chars::is_cyrillic, 0),
-        (Script::Arabic, chars::is_arabic, 0),
-        (Script::Mandarin, chars::is_mandarin, 0),
-        (Script::Devanagari, chars::is_devanagari, 0),
-        (Script::Hebrew, chars::is_hebrew, 0),
-        (Script::Ethiopic, chars::is_ethiopic, 0),
-        (Script::Georgian, chars::is_georgian, 0),
-        (Script::Bengali, chars::is_bengali, 0),
-        (Script::Hangul, chars::is_hangul, 0),
-        (Script::Hiragana, chars::is_hiragana, 0),
-        (Script::Katakana, chars::is_katakana, 0),
-        (Script::Greek, chars::is_greek, 0),
-        (Script::Kannada, chars::is_kannada, 0),
-        (Script::Tamil, chars::is_tamil, 0),
-        (Script::Thai, chars::is_thai, 0),
-        (Script::Gujarati, chars::is_gujarati, 0),
-        (Script::Gurmukhi, chars::is_gurmukhi, 0),
-        (Script::Telugu, chars::is_telugu, 0),
-        (Script::Malayalam, chars::is_malayalam, 0),
-        (Script::Oriya, chars::is_oriya, 0),
-        (Script::Myanmar, chars::is_myanmar, 0),
-        (Script::Sinhala, chars::is_sinhala, 0),
-        (Script::Khmer, chars::is_khmer, 0),
-        (Script::Armenian, chars::is_armenian, 0),
-    ];
+    let mut raw_info = RawScriptInfo::default();
+    raw_detect_script_into(text, &mut raw_info);
+    raw_info
+}
 
-    for ch in text.chars() {
-        if is_stop_char(ch) {
-            continue;
-        }
+/// Same as [raw_detect_script], but reusing the counters of `raw_info`.
+pub(crate) fn raw_detect_script_into(text: &str, raw_info: &mut RawScriptInfo) {
+    let counts = if text.is_ascii() {
+        count_ascii_scripts(text.as_bytes())
+    } else {
+        count_scripts(text)
+    };
+
+    let counters = &mut raw_info.counters;
+    counters.clear();
+    counters.extend(
+        SCRIPTS_BY_PRIORITY
+            .iter()
+            .map(|&script| (script, counts[script as usize])),
+    );
+    // Stable sort, so of scripts with the same count the one with higher priority goes first
+    counters.sort_by_key(|(_, count)| Reverse(*count));
+}
 
-        // For performance reasons, we need to mutate script_counters by calling
-        // `swap` function, it would not be possible to do using normal iterator.
-        for i in 0..script_counters.len() {
-            let found = {
-                let (_script, check_fn, ref mut count) = script_counters[i];
-                if check_fn(ch) {
-                    *count += 1;
-                    true
-                } else {
-                    false
-                }
-            };
-            // Have to let borrow of count fall out of scope before doing swapping, or we could
-            // do this above.
-            if found {
-                // If script was found, move it closer to the front.
-                // If the text contains largely 1 or 2 scripts, this will
-                // cause these scripts to be eventually checked first.
-                if i > 0 {
-                    script_counters.swap(i - 1, i);
-                }
-                break;
-            }
+// Number of characters of every script, indexed by the script.
+fn count_scripts(text: &str) -> [usize; SCRIPTS_COUNT] {
+    let mut counts = [0; SCRIPTS_COUNT];
+    for ch in text.chars() {
+        if let Some(script) = chars::char_script(ch) {
+            counts[script as usize] += 1;
         }
     }
+    counts
+}
 
-    let counters: Vec<(Script, usize)> = script_counters
+// Same as count_scripts() for ASCII text, where only letters have a script (Latin).
+// The loop is branch-free, so the compiler vectorizes it with the SIMD instructions of the target.
+fn count_ascii_scripts(bytes: &[u8]) -> [usize; SCRIPTS_COUNT] {
+    let letters: usize = bytes
         .iter()
-        .map(|&(script, _, count)| (script, count))
-        .collect();
-
-    RawScriptInfo::new(counters)
+        .map(|&b| usize::from((b | 0x20).wrapping_sub(b'a') < 26))
+        .sum();
+    let mut counts = [0; SCRIPTS_COUNT];
+    counts[Script::Latin as usize] = letters;
+    counts
 }
 
 #[cfg(test)]
@@ -169,4 +173,12 @@ mod tests {
             Some(Script::Latin)
         );
     }
+
+    #[test]
+    fn test_count_ascii_scripts_as_generic() {
+        let all_ascii: String = (0u8..128).map(char::from).collect();
+        for text in [all_ascii.as_str(), "", "Hello, World! 42", "[`@{~\x7f"] {
+            assert_eq!(count_ascii_scripts(text.as_bytes()), count_scripts(text));
+        }
+    }
 }
@@ -1,83 +1,202 @@
 use super::Script;
 use crate::Lang;
 
-const LATIN_LANGS: [Lang; 37] = [
+const LATIN_LANGS: &[Lang] = &[
+    #[cfg(feature = "lang-spa")]
     Lang::Spa,
+    #[cfg(feature = "lang-eng")]
     Lang::Eng,
+    #[cfg(feature = "lang-por")]
     Lang::Por,
+    #[cfg(feature = "lang-ind")]
     Lang::Ind,
+    #[cfg(feature = "lang-fra")]
     Lang::Fra,
+    #[cfg(feature = "lang-deu")]
     Lang::Deu,
+    #[cfg(feature = "lang-jav")]
     Lang::Jav,
+    #[cfg(feature = "lang-vie")]
     Lang::Vie,
+    #[cfg(feature = "lang-ita")]
     Lang::Ita,
+    #[cfg(feature = "lang-tur")]
     Lang::Tur,
+    #[cfg(feature = "lang-pol")]
     Lang::Pol,
+    #[cfg(feature = "lang-ron")]
     Lang::Ron,
+    #[cfg(feature = "lang-hrv")]
     Lang::Hrv,
+    #[cfg(feature = "lang-nld")]
     Lang::Nld,
+    #[cfg(feature = "lang-uzb")]
     Lang::Uzb,
+    #[cfg(feature = "lang-hun")]
     Lang::Hun,
+    #[cfg(feature = "lang-aze")]
     Lang::Aze,
+    #[cfg(feature = "lang-ces")]
     Lang::Ces,
+    #[cfg(feature = "lang-zul")]
     Lang::Zul,
+    #[cfg(feature = "lang-swe")]
     Lang::Swe,
+    #[cfg(feature = "lang-aka")]
     Lang::Aka,
+    #[cfg(feature = "lang-sna")]
     Lang::Sna,
+    #[cfg(feature = "lang-afr")]
     Lang::Afr,
+    #[cfg(feature = "lang-fin")]
     Lang::Fin,
+    #[cfg(feature = "lang-slk")]
     Lang::Slk,
+    #[cfg(feature = "lang-tgl")]
     Lang::Tgl,
+    #[cfg(feature = "lang-tuk")]
     Lang::Tuk,
+    #[cfg(feature = "lang-dan")]
     Lang::Dan,
+    #[cfg(feature = "lang-nob")]
     Lang::Nob,
+    #[cfg(feature = "lang-cat")]
     Lang::Cat,
+    #[cfg(feature = "lang-lit")]
     Lang::Lit,
+    #[cfg(feature = "lang-slv")]
     Lang::Slv,
+    #[cfg(feature = "lang-epo")]
     Lang::Epo,
+    #[cfg(feature = "lang-lav")]
     Lang::Lav,
+    #[cfg(feature = "lang-est")]
     Lang::Est,
+    #[cfg(feature = "lang-lat")]
     Lang::Lat,
+    #[cfg(feature = "lang-cym")]
     Lang::Cym,
 ];
-const CYRILLIC_LANGS: [Lang; 6] = [
+const CYRILLIC_LANGS: &[Lang] = &[
+    #[cfg(feature = "lang-rus")]
     Lang::Rus,
+    #[cfg(feature = "lang-ukr")]
     Lang::Ukr,
+    #[cfg(feature = "lang-srp")]
     Lang::Srp,
+    #[cfg(feature = "lang-bel")]
     Lang::Bel,
+    #[cfg(feature = "lang-bul")]
     Lang::Bul,
+    #[cfg(feature = "lang-mkd")]
     Lang::Mkd,
 ];
-const ARABIC_LANGS: [Lang; 3] = [Lang::Ara, Lang::Urd, Lang::Pes];
-const DEVANAGARI_LANGS: [Lang; 3] = [Lang::Hin, Lang::Mar, Lang::Nep];
-const HEBREW_LANGS: [Lang; 2] = [Lang::Heb, Lang::Yid];
+const ARABIC_LANGS: &[Lang] = &[
+    #[cfg(feature = "lang-ara")]
+    Lang::Ara,
+    #[cfg(feature = "lang-urd")]
+    Lang::Urd,
+    #[cfg(feature = "lang-pes")]
+    Lang::Pes,
+];
+const DEVANAGARI_LANGS: &[Lang] = &[
+    #[cfg(feature = "lang-hin")]
+    Lang::Hin,
+    #[cfg(feature = "lang-mar")]
+    Lang::Mar,
+    #[cfg(feature = "lang-nep")]
+    Lang::Nep,
+];
+const HEBREW_LANGS: &[Lang] = &[
+    #[cfg(feature = "lang-heb")]
+    Lang::Heb,
+    #[cfg(feature = "lang-yid")]
+    Lang::Yid,
+];
 
 pub fn script_langs(script: Script) -> &'static [Lang] {
     match script {
-        Script::Latin => &LATIN_LANGS,
-        Script::Cyrillic => &CYRILLIC_LANGS,
-        Script::Devanagari => &DEVANAGARI_LANGS,
-        Script::Hebrew => &HEBREW_LANGS,
-        Script::Arabic => &ARABIC_LANGS,
-        Script::Mandarin => &[Lang::Cmn],
-        Script::Bengali => &[Lang::Ben],
-        Script::Hangul => &[Lang::Kor],
-        Script::Georgian => &[Lang::Kat],
-        Script::Greek => &[Lang::Ell],
-        Script::Kannada => &[Lang::Kan],
-        Script::Tamil => &[Lang::Tam],
-        Script::Thai => &[Lang::Tha],
-        Script::Gujarati => &[Lang::Guj],
-        Script::Gurmukhi => &[Lang::Pan],
-        Script::Telugu => &[Lang::Tel],
-        Script::Malayalam => &[Lang::Mal],
-        Script::Oriya => &[Lang::Ori],
-        Script::Myanmar => &[Lang::Mya],
-        Script::Sinhala => &[Lang::Sin],
-        Script::Khmer => &[Lang::Khm],
-        Script::Ethiopic => &[Lang::Amh],
-        Script::Armenian => &[Lang::Hye],
-        Script::Katakana | Script::Hiragana => &[Lang::Jpn],
+        Script::Latin => LATIN_LANGS,
+        Script::Cyrillic => CYRILLIC_LANGS,
+        Script::Devanagari => DEVANAGARI_LANGS,
+        Script::Hebrew => HEBREW_LANGS,
+        Script::Arabic => ARABIC_LANGS,
+        Script::Mandarin => &[
+            #[cfg(feature = "lang-cmn")]
+            Lang::Cmn,
+        ],
+        Script::Bengali => &[
+            #[cfg(feature = "lang-ben")]
+            Lang::Ben,
+        ],
+        Script::Hangul => &[
+            #[cfg(feature = "lang-kor")]
+            Lang::Kor,
+        ],
+        Script::Georgian => &[
+            #[cfg(feature = "lang-kat")]
+            Lang::Kat,
+        ],
+        Script::Greek => &[
+            #[cfg(feature = "lang-ell")]
+            Lang::Ell,
+        ],
+        Script::Kannada => &[
+            #[cfg(feature = "lang-kan")]
+            Lang::Kan,
+        ],
+        Script::Tamil => &[
+            #[cfg(feature = "lang-tam")]
+            Lang::Tam,
+        ],
+        Script::Thai => &[
+            #[cfg(feature = "lang-tha")]
+            Lang::Tha,
+        ],
+        Script::Gujarati => &[
+            #[cfg(feature = "lang-guj")]
+            Lang::Guj,
+        ],
+        Script::Gurmukhi => &[
+            #[cfg(feature = "lang-pan")]
+            Lang::Pan,
+        ],
+        Script::Telugu => &[
+            #[cfg(feature = "lang-tel")]
+            Lang::Tel,
+        ],
+        Script::Malayalam => &[
+            #[cfg(feature = "lang-mal")]
+            Lang::Mal,
+        ],
+        Script::Oriya => &[
+            #[cfg(feature = "lang-ori")]
+            Lang::Ori,
+        ],
+        Script::Myanmar => &[
+            #[cfg(feature = "lang-mya")]
+            Lang::Mya,
+        ],
+        Script::Sinhala => &[
+            #[cfg(feature = "lang-sin")]
+            Lang::Sin,
+        ],
+        Script::Khmer => &[
+            #[cfg(feature = "lang-khm")]
+            Lang::Khm,
+        ],
+        Script::Ethiopic => &[
+            #[cfg(feature = "lang-amh")]
+            Lang::Amh,
+        ],
+        Script::Armenian => &[
+            #[cfg(feature = "lang-hye")]
+            Lang::Hye,
+        ],
+        Script::Katakana | Script::Hiragana => &[
+            #[cfg(feature = "lang-jpn")]
+            Lang::Jpn,
+        ],
     }
 }
 
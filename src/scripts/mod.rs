@@ -3,7 +3,12 @@ mod detect;
 pub(crate) mod grouping;
 mod lang_mapping;
 mod script;
+#[rustfmt::skip]
+mod tables;
 
+pub use self::detect::RawScriptInfo;
 pub use self::detect::detect_script;
-pub use self::detect::{RawScriptInfo, raw_detect_script};
+#[cfg(feature = "dev")]
+pub use self::detect::raw_detect_script;
+pub(crate) use self::detect::raw_detect_script_into;
 pub use self::script::Script;
@@ -1,5 +1,6 @@
-use std::fmt;
-use std::str::FromStr;
+use alloc::string::ToString;
+use core::fmt;
+use core::str::FromStr;
 
 use super::lang_mapping;
 use crate::Lang;
@@ -112,6 +113,8 @@ impl Script {
         }
     }
 
+    /// Languages written in the script, except the ones excluded by cargo features
+    /// (see [Lang::is_supported]).
     pub fn langs(&self) -> &[Lang] {
         lang_mapping::script_langs(*self)
     }
@@ -1,14 +1,11 @@
-use hashbrown::HashMap;
-
-use super::utils::{TrigramsWithPositions, get_trigrams_with_positions};
-use super::{ARABIC_LANGS, CYRILLIC_LANGS, DEVANAGARI_LANGS, HEBREW_LANGS, LATIN_LANGS};
-use super::{LangProfile, LangProfileList};
-use super::{MAX_TOTAL_DISTANCE, MAX_TRIGRAM_DISTANCE, Trigram};
+use super::index::{MAX_PROFILES, TrigramIndex, script_to_trigram_index};
+use super::utils::TrigramTable;
+use super::{MAX_TOTAL_DISTANCE, MAX_TRIGRAM_DISTANCE};
 use crate::Lang;
 use crate::core::{FilterList, Info, InternalQuery, Text, calculate_confidence};
-use crate::scripts::grouping::MultiLangScript;
+use alloc::vec::Vec;
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct RawOutcome {
     pub trigrams_count: usize,
     #[allow(dead_code)]
@@ -17,24 +14,24 @@ pub struct RawOutcome {
 }
 
 #[inline]
-pub fn detect(iquery: &InternalQuery) -> Option<Info> {
-    let raw_outcome = raw_detect(iquery);
+pub fn detect(iquery: &InternalQuery, outcome: &mut RawOutcome) -> Option<Info> {
+    let index = script_to_trigram_index(iquery.multi_lang_script);
     let RawOutcome {
         trigrams_count,
         scores,
         ..
-    } = raw_outcome;
+    } = calculate_scores_in_index(iquery.text, iquery.filter_list, index, outcome);
 
-    let mut raw_scores_iter = scores.into_iter();
+    let mut raw_scores_iter = scores.iter();
 
     let opt_lang_score1 = raw_scores_iter.next();
     let opt_lang_score2 = raw_scores_iter.next();
 
     // TODO: Logic is duplicated in alphabets. Consider refactoring
-    opt_lang_score1.map(|(lang1, score1)| {
+    opt_lang_score1.map(|&(lang1, score1)| {
         let script = iquery.multi_lang_script.to_script();
-        let confidence = if let Some((_, score2)) = opt_lang_score2 {
-            calculate_confidence(score1, score2, trigrams_count)
+        let confidence = if let Some(&(_, score2)) = opt_lang_score2 {
+            calculate_confidence(score1, score2, *trigrams_count)
         } else {
             1.0
         };
@@ -42,77 +39,101 @@ pub fn detect(iquery: &InternalQuery) -> Option<Info> {
     })
 }
 
+#[cfg(feature = "dev")]
 #[inline]
 pub fn raw_detect(iquery: &InternalQuery) -> RawOutcome {
-    let lang_profile_list = script_to_lang_profile_list(iquery.multi_lang_script);
-    calculate_scores_in_profiles(&iquery.text, iquery.filter_list, lang_profile_list)
+    let mut outcome = RawOutcome::default();
+    raw_detect_into(iquery, &mut outcome);
+    outcome
 }
 
-fn script_to_lang_profile_list(script: MultiLangScript) -> LangProfileList {
-    use MultiLangScript as MLS;
-    match script {
-        MLS::Latin => LATIN_LANGS,
-        MLS::Cyrillic => CYRILLIC_LANGS,
-        MLS::Arabic => ARABIC_LANGS,
-        MLS::Devanagari => DEVANAGARI_LANGS,
-        MLS::Hebrew => HEBREW_LANGS,
-    }
+/// Same as [raw_detect], but reusing the buffers of the outcome.
+#[inline]
+pub fn raw_detect_into<'a>(iquery: &InternalQuery, outcome: &'a mut RawOutcome) -> &'a RawOutcome {
+    let index = script_to_trigram_index(iquery.multi_lang_script);
+    calculate_scores_in_index(iquery.text, iquery.filter_list, index, outcome)
 }
 
 #[inline]
-fn calculate_scores_in_profiles(
+pub(super) fn calculate_scores_in_index<'a>(
     text: &Text,
     filter_list: &FilterList,
-    lang_profile_list: LangProfileList,
-) -> RawOutcome {
-    let mut lang_distances: Vec<(Lang, u32)> = vec![];
-
-    let TrigramsWithPositions {
-        trigram_positions, ..
-    } = get_trigrams_with_positions(&text.lowercase());
-    let unique_trigrams_count = trigram_positions.len();
-
-    for &(lang, lang_trigrams) in lang_profile_list {
-        if !filter_list.is_allowed(lang) {
-            continue;
-        }
-        let dist = calculate_distance(lang_trigrams, &trigram_positions);
-        lang_distances.push(((lang), dist));
-    }
+    index: &TrigramIndex,
+    outcome: &'a mut RawOutcome,
+) -> &'a RawOutcome {
+    let table = text.trigrams();
+    let unique_trigrams_count = table.len();
+    let distances = calculate_distances(index, &table);
+
+    let RawOutcome {
+        trigrams_count,
+        raw_distances: lang_distances,
+        scores,
+    } = outcome;
+    *trigrams_count = unique_trigrams_count;
+
+    lang_distances.clear();
+    lang_distances.extend(
+        index
+            .profiles()
+            .iter()
+            .zip(distances)
+            .filter(|((lang, _), _)| filter_list.is_allowed(*lang))
+            .map(|(&(lang, _), dist)| (lang, dist)),
+    );
 
     // Sort languages by distance
     lang_distances.sort_unstable_by_key(|(_, dist)| *dist);
 
     let max_dist = unique_trigrams_count as u32 * MAX_TRIGRAM_DISTANCE;
 
-    let raw_scores = lang_distances
-        .iter()
-        .map(|&(lang, distance)| (lang, distance_to_raw_score(distance, max_dist)))
-        .collect();
+    scores.clear();
+    scores.extend(
+        lang_distances
+            .iter()
+            .map(|&(lang, distance)| (lang, distance_to_raw_score(distance, max_dist))),
+    );
 
-    RawOutcome {
-        trigrams_count: unique_trigrams_count,
-        scores: raw_scores,
-        raw_distances: lang_distances,
-    }
+    outcome
 }
 
+/// Distances between the text and every profile of the index, in the order of the profile list.
 #[inline]
-fn calculate_distance(lang_trigrams: LangProfile, text_trigrams: &HashMap<Trigram, u32>) -> u32 {
-    let mut total_dist = 0u32;
-
-    for (i, &trigram) in lang_trigrams.iter().enumerate() {
-        let dist = match text_trigrams.get(&trigram) {
-            Some(&n) => (n as i32 - i as i32).unsigned_abs(),
-            None => MAX_TRIGRAM_DISTANCE,
-        };
-        total_dist += dist;
+fn calculate_distances(
+    index: &TrigramIndex,
+    text_trigrams: &TrigramTable,
+) -> impl Iterator<Item = u32> + use<> {
+    let profiles = index.profiles();
+
+    // Every trigram of a profile that is not found in the text gives MAX_TRIGRAM_DISTANCE,
+    // so the distance of a profile is `profile_len * MAX_TRIGRAM_DISTANCE - savings`,
+    // where every found trigram saves `MAX_TRIGRAM_DISTANCE - |position - rank|`.
+    let mut savings = [0i64; MAX_PROFILES];
+    for (trigram, position) in text_trigrams.trigram_positions() {
+        for (profile_position, rank) in index.get(trigram) {
+            let dist = (position as i64 - rank as i64).abs();
+            savings[profile_position] += MAX_TRIGRAM_DISTANCE as i64 - dist;
+        }
     }
 
-    let count = text_trigrams.len() as u32;
+    let text_trigrams_count = text_trigrams.len() as u32;
+    profiles
+        .iter()
+        .zip(savings)
+        .map(move |(&(_, profile_len), saving)| {
+            let total_dist = profile_len as i64 * MAX_TRIGRAM_DISTANCE as i64 - saving;
+            normalize_distance(total_dist as u32, text_trigrams_count)
+        })
+}
 
-    if MAX_TRIGRAM_DISTANCE > count {
-        let delta = MAX_TRIGRAM_DISTANCE - count;
+// Compensate the distance of the profile trigrams that can not be matched,
+// because the text is too short.
+#[inline]
+fn normalize_distance(total_dist: u32, text_trigrams_count: u32) -> u32 {
+    let mut total_dist = total_dist;
+
+    if MAX_TRIGRAM_DISTANCE > text_trigrams_count {
+        let delta = MAX_TRIGRAM_DISTANCE - text_trigrams_count;
         total_dist -= delta * MAX_TRIGRAM_DISTANCE;
     }
 
@@ -128,16 +149,68 @@ fn distance_to_raw_score(distance: u32, max_distance: u32) -> f64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{AlphabetMode, WeightCurve};
+    use crate::scripts::grouping::MultiLangScript;
+    use crate::trigrams::index::script_to_lang_profile_list;
+    use crate::trigrams::{LangProfile, PackedTrigram};
+    use hashbrown::HashMap;
+
+    // Old implementation, that looks up every trigram of a profile in the trigrams of a text.
+    fn naive_calculate_distance(
+        lang_trigrams: LangProfile,
+        text_trigrams: &HashMap<PackedTrigram, u32>,
+    ) -> u32 {
+        let mut total_dist = 0u32;
+
+        for (i, trigram) in lang_trigrams.iter().enumerate() {
+            let dist = match text_trigrams.get(&trigram.pack()) {
+                Some(&n) => (n as i32 - i as i32).unsigned_abs(),
+                None => MAX_TRIGRAM_DISTANCE,
+            };
+            total_dist += dist;
+        }
+
+        normalize_distance(total_dist, text_trigrams.len() as u32)
+    }
+
+    #[test]
+    fn test_calculate_distances_as_naive_implementation() {
+        let texts = [
+            "Die Ordnung muss für immer in diesem Codebase bleiben",
+            "Ĉu vi ne volas eklerni Esperanton? Bonvolu!",
+            "Та нічого, все нормально. А в тебе як?",
+            "a",
+            include_str!("../../tests/examples.json"),
+        ];
+        let mut table = TrigramTable::default();
+        for text in texts {
+            table.fill(&Text::new(text));
+            let trigram_positions: HashMap<_, _> = table.trigram_positions().collect();
+            for script in [MultiLangScript::Latin, MultiLangScript::Cyrillic] {
+                let index = script_to_trigram_index(script);
+                let distances = calculate_distances(index, &table);
+                let lang_profile_list = script_to_lang_profile_list(script);
+                for (&(lang, profile), distance) in lang_profile_list.iter().zip(distances) {
+                    let expected = naive_calculate_distance(profile, &trigram_positions);
+                    assert_eq!(distance, expected, "{lang:?}");
+                }
+            }
+        }
+    }
 
     #[test]
     fn test_when_german_is_given() {
         let text = "Die Ordnung muss für immer in diesem Codebase bleiben";
         let iq = InternalQuery {
-            text: Text::new(text),
+            text: &Text::new(text),
             filter_list: &FilterList::default(),
             multi_lang_script: MultiLangScript::Latin,
+            alphabet_weight_curve: WeightCurve::default(),
+            alphabet_mode: AlphabetMode::default(),
+            tolerate_foreign_words: false,
         };
-        let raw_outcome = raw_detect(&iq);
+        let mut outcome = RawOutcome::default();
+        let raw_outcome = raw_detect_into(&iq, &mut outcome);
 
         assert_eq!(raw_outcome.trigrams_count, 50);
 
@@ -0,0 +1,305 @@
+//! Inverted index of the trigram profiles.
+//!
+//! Instead of looking up every trigram of every profile in the trigrams of a text, every trigram
+//! of the text is looked up once in the index, which gives all the profiles containing the trigram
+//! and its rank in each of them. So distances to all the profiles are accumulated in one pass.
+//!
+//! The index is built from the profiles at compile time, see [trigram_index] macro.
+
+use super::{ARABIC_LANGS, CYRILLIC_LANGS, DEVANAGARI_LANGS, HEBREW_LANGS, LATIN_LANGS};
+use super::{LangProfileList, PackedTrigram};
+use crate::Lang;
+use crate::scripts::grouping::MultiLangScript;
+
+// A posting is a position of a profile within the profile list and the rank of a trigram in
+// the profile, packed into u16.
+const RANK_BITS: u32 = 9;
+const RANK_MASK: u16 = (1 << RANK_BITS) - 1;
+
+/// Maximum number of profiles in an index.
+pub const MAX_PROFILES: usize = 1 << (u16::BITS - RANK_BITS);
+
+/// Inverted index binding every trigram of the profiles of a script to the profiles which contain
+/// the trigram and the rank of the trigram in every of them.
+pub struct TrigramIndex {
+    // Languages of the profiles with the number of trigrams in every profile.
+    profiles: &'static [(Lang, usize)],
+    // Packed trigrams in ascending order.
+    keys: &'static [PackedTrigram],
+    // Postings of `keys[i]` are `postings[offsets[i]..offsets[i + 1]]`.
+    offsets: &'static [u16],
+    postings: &'static [u16],
+}
+
+impl TrigramIndex {
+    /// Languages of the profiles with the number of trigrams in every profile,
+    /// in the order of the profile list.
+    pub fn profiles(&self) -> &'static [(Lang, usize)] {
+        self.profiles
+    }
+
+    /// Positions of the profiles (within the profile list) which contain the trigram,
+    /// together with the rank of the trigram in the profile.
+    #[inline]
+    pub fn get(&self, trigram: PackedTrigram) -> impl Iterator<Item = (usize, u32)> + '_ {
+        let range = match self.keys.binary_search(&trigram) {
+            Ok(i) => self.offsets[i] as usize..self.offsets[i + 1] as usize,
+            Err(_) => 0..0,
+        };
+        self.postings[range].iter().map(|&posting| {
+            let position = (posting >> RANK_BITS) as usize;
+            let rank = (posting & RANK_MASK) as u32;
+            (position, rank)
+        })
+    }
+}
+
+pub fn script_to_trigram_index(script: MultiLangScript) -> &'static TrigramIndex {
+    use MultiLangScript as MLS;
+    match script {
+        MLS::Latin => &LATIN_INDEX,
+        MLS::Cyrillic => &CYRILLIC_INDEX,
+        MLS::Arabic => &ARABIC_INDEX,
+        MLS::Devanagari => &DEVANAGARI_INDEX,
+        MLS::Hebrew => &HEBREW_INDEX,
+    }
+}
+
+#[cfg(test)]
+pub fn script_to_lang_profile_list(script: MultiLangScript) -> LangProfileList {
+    use MultiLangScript as MLS;
+    match script {
+        MLS::Latin => LATIN_LANGS,
+        MLS::Cyrillic => CYRILLIC_LANGS,
+        MLS::Arabic => ARABIC_LANGS,
+        MLS::Devanagari => DEVANAGARI_LANGS,
+        MLS::Hebrew => HEBREW_LANGS,
+    }
+}
+
+/// Build a [TrigramIndex] of a profile list at compile time.
+macro_rules! trigram_index {
+    ($name:ident, $lang_profile_list:expr) => {
+        static $name: TrigramIndex = {
+            const ENTRIES_COUNT: usize = count_entries($lang_profile_list);
+            const ENTRIES: [u128; ENTRIES_COUNT] = sorted_entries($lang_profile_list);
+            const KEYS_COUNT: usize = count_keys(&ENTRIES);
+            static KEYS: [PackedTrigram; KEYS_COUNT] = build_keys(&ENTRIES);
+            static OFFSETS: [u16; KEYS_COUNT + 1] = build_offsets(&ENTRIES);
+            static POSTINGS: [u16; ENTRIES_COUNT] = build_postings(&ENTRIES);
+            static PROFILES: [(Lang, usize); $lang_profile_list.len()] =
+                build_profiles($lang_profile_list);
+            TrigramIndex {
+                profiles: &PROFILES,
+                keys: &KEYS,
+                offsets: &OFFSETS,
+                postings: &POSTINGS,
+            }
+        };
+    };
+}
+
+trigram_index!(LATIN_INDEX, LATIN_LANGS);
+trigram_index!(CYRILLIC_INDEX, CYRILLIC_LANGS);
+trigram_index!(ARABIC_INDEX, ARABIC_LANGS);
+trigram_index!(DEVANAGARI_INDEX, DEVANAGARI_LANGS);
+trigram_index!(HEBREW_INDEX, HEBREW_LANGS);
+
+const fn count_entries(lang_profile_list: LangProfileList) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < lang_profile_list.len() {
+        count += lang_profile_list[i].1.len();
+        i += 1;
+    }
+    assert!(
+        count <= u16::MAX as usize,
+        "Too many trigrams for u16 offsets"
+    );
+    count
+}
+
+// Every entry is a packed trigram followed by a posting, sorted.
+const fn sorted_entries<const N: usize>(lang_profile_list: LangProfileList) -> [u128; N] {
+    assert!(lang_profile_list.len() <= MAX_PROFILES);
+
+    let mut entries = [0u128; N];
+    let mut n = 0;
+    let mut position = 0;
+    while position < lang_profile_list.len() {
+        let profile = lang_profile_list[position].1;
+        let profile_len = profile.len();
+        assert!(profile_len <= RANK_MASK as usize + 1);
+        let mut rank = 0;
+        let mut offset = 0;
+        while rank < profile_len {
+            let (trigram, next_offset) = profile.decode_at(offset);
+            let posting = ((position as u16) << RANK_BITS) | rank as u16;
+            entries[n] = ((trigram.pack() as u128) << u16::BITS) | posting as u128;
+            n += 1;
+            rank += 1;
+            offset = next_offset;
+        }
+        position += 1;
+    }
+
+    heapsort(&mut entries);
+    entries
+}
+
+const fn build_profiles<const P: usize>(lang_profile_list: LangProfileList) -> [(Lang, usize); P] {
+    let mut profiles = [(Lang::Eng, 0); P];
+    let mut i = 0;
+    while i < P {
+        let (lang, profile) = lang_profile_list[i];
+        profiles[i] = (lang, profile.len());
+        i += 1;
+    }
+    profiles
+}
+
+const fn heapsort(entries: &mut [u128]) {
+    let len = entries.len();
+    let mut start = len / 2;
+    while start > 0 {
+        start -= 1;
+        sift_down(entries, start, len);
+    }
+    let mut end = len;
+    while end > 1 {
+        end -= 1;
+        let tmp = entries[0];
+        entries[0] = entries[end];
+        entries[end] = tmp;
+        sift_down(entries, 0, end);
+    }
+}
+
+const fn sift_down(entries: &mut [u128], mut root: usize, end: usize) {
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= end {
+            break;
+        }
+        if child + 1 < end && entries[child] < entries[child + 1] {
+            child += 1;
+        }
+        if entries[root] >= entries[child] {
+            break;
+        }
+        let tmp = entries[root];
+        entries[root] = entries[child];
+        entries[child] = tmp;
+        root = child;
+    }
+}
+
+const fn entry_key(entry: u128) -> PackedTrigram {
+    (entry >> u16::BITS) as PackedTrigram
+}
+
+const fn count_keys<const N: usize>(entries: &[u128; N]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < N {
+        if i == 0 || entry_key(entries[i]) != entry_key(entries[i - 1]) {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+const fn build_keys<const N: usize, const K: usize>(entries: &[u128; N]) -> [PackedTrigram; K] {
+    let mut keys = [0; K];
+    let mut k = 0;
+    let mut i = 0;
+    while i < N {
+        if i == 0 || entry_key(entries[i]) != entry_key(entries[i - 1]) {
+            keys[k] = entry_key(entries[i]);
+            k += 1;
+        }
+        i += 1;
+    }
+    keys
+}
+
+const fn build_offsets<const N: usize, const K1: usize>(entries: &[u128; N]) -> [u16; K1] {
+    let mut offsets = [0u16; K1];
+    let mut k = 0;
+    let mut i = 0;
+    while i < N {
+        if i == 0 || entry_key(entries[i]) != entry_key(entries[i - 1]) {
+            offsets[k] = i as u16;
+            k += 1;
+        }
+        i += 1;
+    }
+    offsets[k] = N as u16;
+    offsets
+}
+
+const fn build_postings<const N: usize>(entries: &[u128; N]) -> [u16; N] {
+    let mut postings = [0u16; N];
+    let mut i = 0;
+    while i < N {
+        postings[i] = entries[i] as u16;
+        i += 1;
+    }
+    postings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trigrams::Trigram;
+
+    #[test]
+    fn test_index_matches_profiles() {
+        for script in [
+            MultiLangScript::Latin,
+            MultiLangScript::Cyrillic,
+            MultiLangScript::Arabic,
+            MultiLangScript::Devanagari,
+            MultiLangScript::Hebrew,
+        ] {
+            let index = script_to_trigram_index(script);
+            let lang_profile_list = script_to_lang_profile_list(script);
+
+            let mut entries_count = 0;
+            for (position, &(lang, profile)) in lang_profile_list.iter().enumerate() {
+                assert_eq!(index.profiles()[position], (lang, profile.len()));
+                for (rank, trigram) in profile.iter().enumerate() {
+                    assert!(
+                        index
+                            .get(trigram.pack())
+                            .any(|posting| posting == (position, rank as u32))
+                    );
+                    entries_count += 1;
+                }
+            }
+            assert_eq!(index.postings.len(), entries_count);
+        }
+    }
+
+    #[test]
+    fn test_get_unknown_trigram() {
+        let index = script_to_trigram_index(MultiLangScript::Latin);
+        assert_eq!(index.get(Trigram('ж', 'ж', 'ж').pack()).count(), 0);
+    }
+
+    #[test]
+    fn test_pack_keeps_order() {
+        let trigrams = [
+            Trigram(' ', 'a', 'b'),
+            Trigram('a', ' ', 'z'),
+            Trigram('a', 'b', ' '),
+            Trigram('a', 'b', 'c'),
+            Trigram('ж', 'a', 'a'),
+        ];
+        for pair in trigrams.windows(2) {
+            assert!(pair[0] < pair[1]);
+            assert!(pair[0].pack() < pair[1].pack());
+        }
+    }
+}
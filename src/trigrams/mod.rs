@@ -1,14 +1,105 @@
 pub mod detection;
+mod index;
 mod profiles;
+mod scorer;
 pub mod utils;
 
 pub use profiles::*;
 
-pub use detection::{RawOutcome, detect, raw_detect};
+#[cfg(feature = "dev")]
+pub use detection::raw_detect;
+pub use detection::{RawOutcome, detect, raw_detect_into};
+pub use scorer::TrigramScorer;
 
 #[derive(Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Clone, Copy)]
 pub struct Trigram(pub(crate) char, pub(crate) char, pub(crate) char);
 
+/// Trigram packed into an integer, see [Trigram::pack].
+pub(crate) type PackedTrigram = u64;
+
+impl Trigram {
+    // Packed trigrams keep the order of the trigrams, since a char takes at most 21 bits.
+    #[inline]
+    pub(crate) const fn pack(self) -> PackedTrigram {
+        ((self.0 as u64) << 42) | ((self.1 as u64) << 21) | (self.2 as u64)
+    }
+}
+
+/// Trigrams of a language, the most common first. To keep the source and the binary small,
+/// trigrams are stored as UTF-8 text separated by `|` (the format of `misc/data.json`),
+/// which takes about 4 bytes per trigram instead of 12 bytes of a [Trigram].
+/// Profiles are decoded at compile time into the [index], only their lengths are kept
+/// in the binary.
+#[derive(Debug, Clone, Copy)]
+pub struct LangProfile(&'static str);
+
+impl LangProfile {
+    /// Number of trigrams.
+    pub const fn len(self) -> usize {
+        let bytes = self.0.as_bytes();
+        if bytes.is_empty() {
+            return 0;
+        }
+        let mut count = 1;
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == b'|' {
+                count += 1;
+            }
+            i += 1;
+        }
+        count
+    }
+
+    #[cfg(test)]
+    pub fn iter(self) -> impl Iterator<Item = Trigram> {
+        let mut offset = 0;
+        core::iter::from_fn(move || {
+            (offset < self.0.len()).then(|| {
+                let (trigram, next_offset) = self.decode_at(offset);
+                offset = next_offset;
+                trigram
+            })
+        })
+    }
+
+    /// Decode the trigram starting at the byte `offset`.
+    /// Returns the trigram and the offset of the next one.
+    pub(crate) const fn decode_at(self, offset: usize) -> (Trigram, usize) {
+        let bytes = self.0.as_bytes();
+        let (c1, offset) = decode_utf8_char(bytes, offset);
+        let (c2, offset) = decode_utf8_char(bytes, offset);
+        let (c3, offset) = decode_utf8_char(bytes, offset);
+        assert!(offset == bytes.len() || bytes[offset] == b'|');
+        (Trigram(c1, c2, c3), offset + 1)
+    }
+}
+
+// `str::chars()` is not available in const context.
+const fn decode_utf8_char(bytes: &[u8], i: usize) -> (char, usize) {
+    let b0 = bytes[i] as u32;
+    let (code, len) = if b0 < 0x80 {
+        (b0, 1)
+    } else if b0 < 0xE0 {
+        (((b0 & 0x1F) << 6) | (bytes[i + 1] as u32 & 0x3F), 2)
+    } else if b0 < 0xF0 {
+        let code = ((b0 & 0x0F) << 12)
+            | ((bytes[i + 1] as u32 & 0x3F) << 6)
+            | (bytes[i + 2] as u32 & 0x3F);
+        (code, 3)
+    } else {
+        let code = ((b0 & 0x07) << 18)
+            | ((bytes[i + 1] as u32 & 0x3F) << 12)
+            | ((bytes[i + 2] as u32 & 0x3F) << 6)
+            | (bytes[i + 3] as u32 & 0x3F);
+        (code, 4)
+    };
+    match char::from_u32(code) {
+        Some(ch) => (ch, i + len),
+        None => panic!("Invalid UTF-8"),
+    }
+}
+
 // Maximum distance(difference) for a trigram in a language profile and text profile.
 pub const MAX_TRIGRAM_DISTANCE: u32 = 300;
 
@@ -17,3 +108,44 @@ pub const MAX_TOTAL_DISTANCE: u32 = MAX_TRIGRAM_DISTANCE * MAX_TRIGRAM_DISTANCE;
 
 // Double MAX_TRIGRAM_DISTANCE
 pub const TEXT_TRIGRAMS_SIZE: usize = 600;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lang_profile_decoding() {
+        let profile = LangProfile(" de|ón |жы |🙂ab");
+        assert_eq!(profile.len(), 4);
+        let trigrams: Vec<Trigram> = profile.iter().collect();
+        assert_eq!(
+            trigrams,
+            [
+                Trigram(' ', 'd', 'e'),
+                Trigram('ó', 'n', ' '),
+                Trigram('ж', 'ы', ' '),
+                Trigram('🙂', 'a', 'b'),
+            ]
+        );
+
+        let empty = LangProfile("");
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty.iter().count(), 0);
+    }
+
+    #[test]
+    fn test_lang_profiles_are_decoded_as_data_json() {
+        for (_, profile) in LATIN_LANGS.iter().chain(CYRILLIC_LANGS).chain(ARABIC_LANGS) {
+            let expected: Vec<Trigram> = profile
+                .0
+                .split('|')
+                .map(|s| {
+                    let chars: Vec<char> = s.chars().collect();
+                    Trigram(chars[0], chars[1], chars[2])
+                })
+                .collect();
+            assert_eq!(profile.iter().collect::<Vec<_>>(), expected);
+            assert_eq!(profile.len(), expected.len());
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use super::RawOutcome;
+use super::detection::calculate_scores_in_index;
+use super::index::script_to_trigram_index;
+use crate::Script;
+use crate::core::{FilterList, Scorer, Scores, Text, with_thread_scratch};
+use crate::scripts::grouping::ScriptLangGroup;
+
+/// [Scorer] based on the trigram method: the distance between trigrams of the text and
+/// trigram profiles of the languages.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TrigramScorer;
+
+impl Scorer for TrigramScorer {
+    fn score(&self, text: &Text, script: Script, filter_list: &FilterList) -> Scores {
+        match script.to_lang_group() {
+            ScriptLangGroup::Multi(multi_lang_script) => {
+                let index = script_to_trigram_index(multi_lang_script);
+                with_thread_scratch(|scratch| {
+                    let outcome = &mut scratch.buffers.trigram;
+                    let RawOutcome {
+                        trigrams_count,
+                        scores,
+                        ..
+                    } = calculate_scores_in_index(text, filter_list, index, outcome);
+                    Scores::new(scores.clone(), *trigrams_count)
+                })
+            }
+            _ => Scores::for_one_lang_script(script, filter_list),
+        }
+    }
+}
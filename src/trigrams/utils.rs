@@ -1,109 +1,199 @@
-use hashbrown::HashMap;
-
 use super::TEXT_TRIGRAMS_SIZE;
-use super::Trigram;
-use crate::core::LowercaseText;
-use crate::utils::is_stop_char;
+use super::{PackedTrigram, Trigram};
+use crate::core::Text;
+use crate::utils::{is_ascii_stop_char, is_stop_char};
+use alloc::vec;
+use alloc::vec::Vec;
+
+const INITIAL_CAPACITY: usize = 1024;
 
-pub struct TrigramsWithPositions {
-    pub(crate) _total_trigrams: u32,
-    pub(crate) trigram_positions: HashMap<Trigram, u32>,
+// Marks an empty slot of the table. It's never a trigram, since '\0' is a stop char.
+const EMPTY: PackedTrigram = 0;
+
+/// Reusable table to count the trigrams of a text and rank them by number of occurrences.
+///
+/// The table keeps its memory between texts, so it allocates only when a text has more distinct
+/// trigrams than any text counted before.
+#[derive(Debug, Default)]
+pub struct TrigramTable {
+    // Open addressing with linear probing, the capacity is a power of two.
+    keys: Vec<PackedTrigram>,
+    counts: Vec<u32>,
+    // Indexes of the occupied slots, in order of insertion.
+    occupied: Vec<u32>,
+    // Occurrences and trigrams ranked in descending order, at most TEXT_TRIGRAMS_SIZE of them.
+    ranked: Vec<(u32, PackedTrigram)>,
+    total_trigrams: u32,
 }
 
-#[inline]
-pub fn get_trigrams_with_positions(text: &LowercaseText) -> TrigramsWithPositions {
-    let CountResult {
-        total_trigrams,
-        trigram_occurances,
-    } = count(text);
-    let trigram_positions = trigram_occurances_to_positions(trigram_occurances);
-    TrigramsWithPositions {
-        _total_trigrams: total_trigrams,
-        trigram_positions,
+impl TrigramTable {
+    /// Count the trigrams of the text, lowercasing it on the fly, and rank them.
+    pub fn fill(&mut self, text: &Text) {
+        if text.is_ascii() {
+            let bytes = text.normalized().bytes();
+            self.count(bytes.map(|b| ASCII_TRIGRAM_CHARS[b as usize & 0x7F] as char));
+        } else {
+            self.count(text.lowercase_chars().map(to_trigram_char));
+        }
+        self.rank();
     }
-}
 
-#[inline]
-#[allow(clippy::unnecessary_sort_by)]
-fn trigram_occurances_to_positions(
-    trigram_occurances: HashMap<Trigram, u32>,
-) -> HashMap<Trigram, u32> {
-    // Sort in descending order by number of occurrences and trigrams
-    let mut count_vec: Vec<_> = trigram_occurances
-        .into_iter()
-        .map(|(trigram, count)| (count, trigram))
-        .collect();
-    count_vec.sort_unstable_by(|a, b| b.cmp(a));
-
-    count_vec
-        .into_iter()
-        .take(TEXT_TRIGRAMS_SIZE) // we're interested only in the first 600 (2 * MAX_TRIGRAM_DISTANCE)
-        .enumerate()
-        .map(|(i, (_, trigram))| (trigram, i as u32))
-        .collect()
-}
+    /// Number of distinct trigrams taken into account (at most TEXT_TRIGRAMS_SIZE).
+    pub fn len(&self) -> usize {
+        self.ranked.len()
+    }
 
-struct CountResult {
-    total_trigrams: u32,
-    trigram_occurances: HashMap<Trigram, u32>,
-}
+    #[allow(dead_code)]
+    pub fn total_trigrams(&self) -> u32 {
+        self.total_trigrams
+    }
 
-#[inline]
-fn count(text: &LowercaseText) -> CountResult {
-    let hash_capacity = calculate_initial_hash_capacity(text);
-    let mut trigram_occurances: HashMap<Trigram, u32> = HashMap::with_capacity(hash_capacity);
-    let mut total_trigrams = 0;
-
-    // iterate through the string and count trigrams
-    let mut chars_iter = text
-        .chars()
-        .map(to_trigram_char)
-        //.flat_map(char::to_lowercase)
-        .chain(Some(' '));
-    let mut c1 = ' ';
-    // unwrap is safe, because we always chain a space character on the end of the iterator
-    let mut c2 = chars_iter.next().unwrap();
-    for cur_char in chars_iter {
-        let c3 = cur_char;
-        if !(c2 == ' ' && (c1 == ' ' || c3 == ' ')) {
-            let trigram = Trigram(c1, c2, c3);
-            let count = trigram_occurances.entry(trigram).or_insert(0);
-            *count += 1;
-            total_trigrams += 1;
+    /// The most frequent trigrams with their positions, the most frequent one is at position 0.
+    pub fn trigram_positions(&self) -> impl Iterator<Item = (PackedTrigram, u32)> + '_ {
+        self.ranked
+            .iter()
+            .enumerate()
+            .map(|(i, &(_, trigram))| (trigram, i as u32))
+    }
+
+    fn clear(&mut self) {
+        for &slot in &self.occupied {
+            self.keys[slot as usize] = EMPTY;
         }
-        c1 = c2;
-        c2 = c3;
+        self.occupied.clear();
+        self.ranked.clear();
+        self.total_trigrams = 0;
     }
 
-    CountResult {
-        total_trigrams,
-        trigram_occurances,
+    // Count trigrams of chars, which are already converted with to_trigram_char().
+    #[inline]
+    fn count(&mut self, chars: impl Iterator<Item = char>) {
+        self.clear();
+        if self.keys.is_empty() {
+            self.keys = vec![EMPTY; INITIAL_CAPACITY];
+            self.counts = vec![0; INITIAL_CAPACITY];
+        }
+
+        // iterate through the chars and count trigrams
+        let mut chars_iter = chars.chain(Some(' '));
+        let mut c1 = ' ';
+        // unwrap is safe, because we always chain a space character on the end of the iterator
+        let mut c2 = chars_iter.next().unwrap();
+        for cur_char in chars_iter {
+            let c3 = cur_char;
+            if !(c2 == ' ' && (c1 == ' ' || c3 == ' ')) {
+                self.insert(Trigram(c1, c2, c3).pack());
+                self.total_trigrams += 1;
+            }
+            c1 = c2;
+            c2 = c3;
+        }
     }
-}
 
-// Convert punctuations and digits to a space.
-#[inline]
-fn to_trigram_char(ch: char) -> char {
-    if is_stop_char(ch) { ' ' } else { ch }
+    #[inline]
+    fn insert(&mut self, trigram: PackedTrigram) {
+        let slot = self.find_slot(trigram);
+        if self.keys[slot] == EMPTY {
+            self.keys[slot] = trigram;
+            self.counts[slot] = 1;
+            self.occupied.push(slot as u32);
+            // Keep the load factor below 1/2
+            if self.occupied.len() * 2 > self.keys.len() {
+                self.grow();
+            }
+        } else {
+            self.counts[slot] += 1;
+        }
+    }
+
+    // Slot of the trigram, or the empty slot where it belongs.
+    #[inline]
+    fn find_slot(&self, trigram: PackedTrigram) -> usize {
+        let mask = self.keys.len() - 1;
+        let mut slot = hash(trigram) & mask;
+        while self.keys[slot] != EMPTY && self.keys[slot] != trigram {
+            slot = (slot + 1) & mask;
+        }
+        slot
+    }
+
+    fn grow(&mut self) {
+        let capacity = self.keys.len() * 2;
+        let keys = core::mem::replace(&mut self.keys, vec![EMPTY; capacity]);
+        let counts = core::mem::replace(&mut self.counts, vec![0; capacity]);
+        for occupied in self.occupied.iter_mut() {
+            let old_slot = *occupied as usize;
+            let slot = {
+                let mask = capacity - 1;
+                let mut slot = hash(keys[old_slot]) & mask;
+                while self.keys[slot] != EMPTY {
+                    slot = (slot + 1) & mask;
+                }
+                slot
+            };
+            self.keys[slot] = keys[old_slot];
+            self.counts[slot] = counts[old_slot];
+            *occupied = slot as u32;
+        }
+    }
+
+    // Sort in descending order by number of occurrences and trigrams,
+    // we're interested only in the first 600 (2 * MAX_TRIGRAM_DISTANCE)
+    #[inline]
+    fn rank(&mut self) {
+        let Self {
+            keys,
+            counts,
+            occupied,
+            ranked,
+            ..
+        } = self;
+        ranked.extend(
+            occupied
+                .iter()
+                .map(|&slot| (counts[slot as usize], keys[slot as usize])),
+        );
+
+        let descending = |a: &(u32, PackedTrigram), b: &(u32, PackedTrigram)| b.cmp(a);
+        if ranked.len() > TEXT_TRIGRAMS_SIZE {
+            ranked.select_nth_unstable_by(TEXT_TRIGRAMS_SIZE, descending);
+            ranked.truncate(TEXT_TRIGRAMS_SIZE);
+        }
+        ranked.sort_unstable_by(descending);
+    }
 }
 
-// In order to improve performance, define the initial capacity for trigrams hash map,
-// based on the size of the input text.
 #[inline]
-fn calculate_initial_hash_capacity(text: &str) -> usize {
-    const MAX_INITIAL_HASH_CAPACITY: usize = 2048;
+fn hash(trigram: PackedTrigram) -> usize {
+    // Fibonacci hashing: the upper half of the product is well mixed
+    (trigram.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 32) as usize
+}
 
-    let len = text.len();
-    if len > MAX_INITIAL_HASH_CAPACITY {
-        MAX_INITIAL_HASH_CAPACITY
-    } else {
-        len
+// to_trigram_char() of lowercase ASCII chars.
+static ASCII_TRIGRAM_CHARS: [u8; 128] = {
+    let mut chars = [0; 128];
+    let mut b = 0;
+    while b < 128 {
+        chars[b] = if is_ascii_stop_char(b as u8) {
+            b' '
+        } else {
+            (b as u8).to_ascii_lowercase()
+        };
+        b += 1;
     }
+    chars
+};
+
+// Convert punctuations, symbols and digits to a space.
+#[inline]
+fn to_trigram_char(ch: char) -> char {
+    if is_stop_char(ch) { ' ' } else { ch }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use hashbrown::HashMap;
 
     fn assert_valuable_trigram_chars(chars: &[char]) {
         for &ch in chars.iter() {
@@ -130,18 +220,34 @@ mod tests {
         assert_not_valuable_trigram_chars(&['[', ']', '^', '\\', '`']);
         // 0x7B - 0x7E
         assert_not_valuable_trigram_chars(&['[', '|', '{', '}', '~']);
+        // non-ASCII
+        assert_not_valuable_trigram_chars(&['«', '¿', '—', '、', '「', '１', '٣', '😀']);
+    }
+
+    #[test]
+    fn test_ascii_trigram_chars() {
+        for b in 0..128u8 {
+            let ch = (b as char).to_ascii_lowercase();
+            assert_eq!(ASCII_TRIGRAM_CHARS[b as usize] as char, to_trigram_char(ch));
+        }
+    }
+
+    fn occurrences(table: &TrigramTable) -> HashMap<PackedTrigram, u32> {
+        table
+            .occupied
+            .iter()
+            .map(|&slot| (table.keys[slot as usize], table.counts[slot as usize]))
+            .collect()
     }
 
     fn assert_count(text: &str, pairs: &[(&str, u32)]) {
-        let lowercase_text = LowercaseText::new(text);
-        let CountResult {
-            total_trigrams: _,
-            trigram_occurances,
-        } = count(&lowercase_text);
+        let mut table = TrigramTable::default();
+        table.fill(&Text::new(text));
+        let trigram_occurances = occurrences(&table);
         for &(trigram_str, expected_n) in pairs.iter() {
             let chars: Vec<char> = trigram_str.chars().collect();
             let trigram = Trigram(chars[0], chars[1], chars[2]);
-            let actual_n = trigram_occurances[&trigram];
+            let actual_n = trigram_occurances[&trigram.pack()];
             assert_eq!(
                 actual_n, expected_n,
                 "trigram '{:?}' expected to occur {} times, got {}",
@@ -172,15 +278,33 @@ mod tests {
     }
 
     #[test]
-    fn test_get_trigrams_with_positions() {
-        let lowercase_text = LowercaseText::new("xaaaaabbbb    d");
-        let TrigramsWithPositions {
-            _total_trigrams,
-            trigram_positions,
-        } = get_trigrams_with_positions(&lowercase_text);
-
-        assert_eq!(trigram_positions[&Trigram('a', 'a', 'a')], 0);
-        assert_eq!(trigram_positions[&Trigram('b', 'b', 'b')], 1);
-        assert_eq!(_total_trigrams, 11);
+    fn test_trigram_positions() {
+        let mut table = TrigramTable::default();
+        table.fill(&Text::new("xaaaaabbbb    d"));
+        let trigram_positions: HashMap<_, _> = table.trigram_positions().collect();
+
+        assert_eq!(trigram_positions[&Trigram('a', 'a', 'a').pack()], 0);
+        assert_eq!(trigram_positions[&Trigram('b', 'b', 'b').pack()], 1);
+        assert_eq!(table.total_trigrams(), 11);
+    }
+
+    #[test]
+    fn test_table_is_reused() {
+        let long_text = include_str!("../../tests/examples.json");
+        let mut table = TrigramTable::default();
+        table.fill(&Text::new(long_text));
+        let capacity = table.keys.len();
+        assert!(capacity > INITIAL_CAPACITY);
+        let first: Vec<_> = table.trigram_positions().collect();
+        assert_eq!(first.len(), TEXT_TRIGRAMS_SIZE);
+
+        table.fill(&Text::new("yes"));
+        assert_eq!(table.len(), 3);
+        assert_eq!(occurrences(&table).len(), 3);
+
+        table.fill(&Text::new(long_text));
+        let second: Vec<_> = table.trigram_positions().collect();
+        assert_eq!(first, second);
+        assert_eq!(table.keys.len(), capacity);
     }
 }
@@ -1,15 +1,57 @@
-// Is it space, punctuation or digit?
+use crate::scripts::chars;
+
+// Is it space, punctuation, symbol, digit or control character (general categories Z*, P*, S*,
+// N* and Cc of Unicode)?
 // Stop character is a character that does not give any value for script
 // or language detection.
 #[inline]
 pub fn is_stop_char(ch: char) -> bool {
-    matches!(ch, '\u{0000}'..='\u{0040}' | '\u{005B}'..='\u{0060}' | '\u{007B}'..='\u{007E}')
+    if ch.is_ascii() {
+        is_ascii_stop_char(ch as u8)
+    } else {
+        chars::is_stop_char(ch)
+    }
+}
+
+// Same as is_stop_char() for ASCII.
+#[inline]
+pub const fn is_ascii_stop_char(b: u8) -> bool {
+    matches!(b, 0x00..=0x40 | 0x5B..=0x60 | 0x7B..=0x7F)
+}
+
+// Same as `x.round() as usize` for non-negative numbers, since f64::round() requires std.
+// Rounds half away from zero as well.
+#[inline]
+pub fn round_to_usize(x: f64) -> usize {
+    let truncated = x as usize;
+    if x - truncated as f64 >= 0.5 {
+        truncated + 1
+    } else {
+        truncated
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_round_to_usize() {
+        for x in [
+            0.0,
+            0.4,
+            0.5,
+            0.49999999999999994,
+            1.5,
+            2.5,
+            2.4999,
+            1e9 + 0.5,
+            12345.678,
+        ] {
+            assert_eq!(round_to_usize(x), x.round() as usize, "{}", x);
+        }
+    }
+
     #[test]
     fn test_is_top_char() {
         // stop chars
@@ -27,5 +69,18 @@ mod tests {
         assert!(!is_stop_char('Z'));
         assert!(!is_stop_char('я'));
         assert!(!is_stop_char('А')); // cyrillic A
+
+        // non-ASCII stop chars
+        assert!(is_stop_char('«'));
+        assert!(is_stop_char('。'));
+        assert!(is_stop_char('١'));
+        assert!(!is_stop_char('é'));
+    }
+
+    #[test]
+    fn test_ascii_stop_chars_as_unicode_tables() {
+        for b in 0..128u8 {
+            assert_eq!(is_ascii_stop_char(b), chars::is_stop_char(b as char), "{b}");
+        }
     }
 }
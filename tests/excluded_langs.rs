@@ -0,0 +1,54 @@
+// Run with a reduced set of languages, e.g.:
+// cargo test --no-default-features --features std,lang-eng,lang-deu,lang-fra,lang-spa,lang-ita --test excluded_langs
+#![cfg(all(
+    feature = "lang-eng",
+    feature = "lang-deu",
+    not(feature = "lang-rus"),
+    not(feature = "lang-por"),
+    not(feature = "lang-cmn"),
+    not(feature = "lang-jpn")
+))]
+
+use whatlang::{Detector, Lang, Script, detect, detect_lang};
+
+#[test]
+fn test_lang_and_script_stay_complete() {
+    assert!(Lang::all().contains(&Lang::Rus));
+    assert!(!Lang::Rus.is_supported());
+    assert!(Lang::Eng.is_supported());
+
+    assert!(Script::Latin.langs().contains(&Lang::Eng));
+    assert!(!Script::Latin.langs().contains(&Lang::Por));
+    assert!(Script::Cyrillic.langs().is_empty());
+}
+
+#[test]
+fn test_excluded_langs_are_never_returned() {
+    assert_eq!(detect_lang("Мой дядя самых честных правил"), None);
+    assert_eq!(detect_lang("我能吞下玻璃而不伤身体。"), None);
+
+    let info = detect("Eu não sei se vou conseguir chegar a tempo amanhã").unwrap();
+    assert!(info.lang().is_supported());
+
+    let text = "Die Ordnung muss für immer in diesem Codebase bleiben";
+    assert_eq!(detect_lang(text), Some(Lang::Deu));
+}
+
+#[test]
+fn test_allowlist_with_excluded_lang() {
+    let err = Detector::try_with_allowlist(vec![Lang::Eng, Lang::Rus]).unwrap_err();
+    assert_eq!(err.lang(), Lang::Rus);
+    assert_eq!(
+        err.to_string(),
+        "Russian (rus) is not supported: whatlang is built without feature \"lang-rus\""
+    );
+
+    let detector = Detector::try_with_allowlist(vec![Lang::Eng, Lang::Deu]).unwrap();
+    assert_eq!(detector.detect_lang("Hello, how are you?"), Some(Lang::Eng));
+}
+
+#[test]
+#[should_panic(expected = "built without feature \"lang-por\"")]
+fn test_allowlist_with_excluded_lang_panics() {
+    Detector::with_allowlist(vec![Lang::Eng, Lang::Por]);
+}
@@ -13,3 +13,199 @@ fn test_fuzzing() {
 
     arbtest::builder().run(prop)
 }
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_fuzzing_markup() {
+    use ::arbitrary::{Arbitrary, Unstructured};
+    use whatlang::InputMode;
+
+    // Random strings rarely contain markup, so texts are built of its pieces
+    const PIECES: [&str; 24] = [
+        "<",
+        ">",
+        "</",
+        "/>",
+        "<!--",
+        "-->",
+        "<!",
+        "<?",
+        "&",
+        ";",
+        "&#",
+        "&#x",
+        "amp",
+        "=",
+        "\"",
+        "'",
+        "`",
+        "```",
+        "~~~",
+        "[",
+        "](",
+        ")",
+        "\n",
+        "Grüße lang=\"de\" script ",
+    ];
+
+    fn prop(u: &mut Unstructured) -> ::arbitrary::Result<()> {
+        let mode = InputMode::arbitrary(u)?;
+        let mut input = String::new();
+        for _ in 0..u.int_in_range(0..=40)? {
+            input.push_str(u.choose(&PIECES)?);
+        }
+        mode.extract(&input);
+        Ok(())
+    }
+
+    arbtest::builder().run(prop)
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_fuzzing_comments() {
+    use ::arbitrary::Unstructured;
+    use whatlang::{CommentExtractor, Syntax};
+
+    // Random strings rarely contain comments, so sources are built of their pieces
+    const PIECES: [&str; 22] = [
+        "//",
+        "///",
+        "//!",
+        "/*",
+        "*/",
+        "/**",
+        "#",
+        "#!",
+        "\"",
+        "'",
+        "`",
+        "\"\"\"",
+        "'''",
+        "r#\"",
+        "\"#",
+        "\\",
+        "\n",
+        " ",
+        "é",
+        "'a",
+        "x",
+        "Grüße parse_config ",
+    ];
+    const SYNTAXES: [Syntax; 4] = [Syntax::CFamily, Syntax::Rust, Syntax::Python, Syntax::Hash];
+
+    fn prop(u: &mut Unstructured) -> ::arbitrary::Result<()> {
+        let syntax = *u.choose(&SYNTAXES)?;
+        let mut source = String::new();
+        for _ in 0..u.int_in_range(0..=40)? {
+            source.push_str(u.choose(&PIECES)?);
+        }
+        for comment in CommentExtractor::new(syntax).extract(&source) {
+            assert!(source.get(comment.range()).is_some());
+        }
+        Ok(())
+    }
+
+    arbtest::builder().run(prop)
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_fuzzing_email() {
+    use ::arbitrary::Unstructured;
+    use whatlang::Email;
+
+    // Messages are built of pieces of headers, MIME parts and replies
+    const PIECES: [&str; 20] = [
+        "From: a@b.c\n",
+        "Content-Type: multipart/alternative; boundary=b\n",
+        "Content-Type: text/html\n",
+        "Content-Transfer-Encoding: base64\n",
+        "Content-Transfer-Encoding: quoted-printable\n",
+        "--b\n",
+        "--b--\n",
+        "\n",
+        "> ",
+        "On Monday Anna wrote:\n",
+        "-----Original Message-----\n",
+        "Von: Anna\nGesendet: Montag\n",
+        "-- \n",
+        "<blockquote>",
+        "=C3=",
+        "=\n",
+        "w6TDtg==",
+        "é",
+        "x",
+        "Grüße ",
+    ];
+
+    fn prop(u: &mut Unstructured) -> ::arbitrary::Result<()> {
+        let mut message = String::new();
+        for _ in 0..u.int_in_range(0..=40)? {
+            message.push_str(u.choose(&PIECES)?);
+        }
+        let email = Email::parse(&message);
+        assert!(email.content().len() + email.quoted().len() <= message.len() * 3 + 2);
+        Ok(())
+    }
+
+    arbtest::builder().run(prop)
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_fuzzing_catalogs() {
+    use ::arbitrary::Unstructured;
+    use whatlang::{Catalog, CatalogFormat, CatalogValidator, Lang};
+
+    // Catalogs are built of pieces of the formats and placeholders
+    const PIECES: [&str; 30] = [
+        "msgid ",
+        "msgstr ",
+        "msgstr[0] ",
+        "msgctxt ",
+        "\"",
+        "\\",
+        "\n",
+        "#",
+        "<xliff trgLang=\"de\">",
+        "<trans-unit id='a'>",
+        "<unit>",
+        "<source>",
+        "<target>",
+        "</target>",
+        "<ph>",
+        "<x/>",
+        "<!--",
+        "<![CDATA[",
+        "&amp;",
+        "&#",
+        "{",
+        "}",
+        ":",
+        ",",
+        "[",
+        "\\u",
+        "%1$s",
+        "{n, plural, one {# Datei} other {",
+        "é",
+        "Grüße ",
+    ];
+    const FORMATS: [CatalogFormat; 3] =
+        [CatalogFormat::Po, CatalogFormat::Xliff, CatalogFormat::Json];
+
+    fn prop(u: &mut Unstructured) -> ::arbitrary::Result<()> {
+        let format = *u.choose(&FORMATS)?;
+        let mut text = String::new();
+        for _ in 0..u.int_in_range(0..=40)? {
+            text.push_str(u.choose(&PIECES)?);
+        }
+        if let Ok(catalog) = Catalog::parse(&text, format) {
+            let validator = CatalogValidator::new().set_min_letters(0);
+            validator.validate(&catalog, Lang::Deu);
+        }
+        Ok(())
+    }
+
+    arbtest::builder().run(prop)
+}
@@ -0,0 +1,63 @@
+extern crate serde_json;
+extern crate whatlang;
+
+use whatlang::{AlphabetMode, Detector, DetectorScratch, Options};
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Counts allocations, so it's possible to verify that detection with a scratch does not allocate.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+// The only test in this file, so no other test allocates concurrently.
+#[test]
+fn test_detect_with_does_not_allocate_after_warmup() {
+    let example_data = include_str!("examples.json");
+    let examples: HashMap<String, String> = serde_json::from_str(example_data).unwrap();
+    let texts: Vec<&str> = examples.values().map(String::as_str).collect();
+
+    let detectors = [
+        Detector::new(),
+        Detector::with_options(Options::new().set_alphabet_mode(AlphabetMode::Frequency)),
+        Detector::with_options(Options::new().set_tolerate_foreign_words(true)),
+    ];
+
+    for detector in &detectors {
+        let mut scratch = DetectorScratch::new();
+        let expected: Vec<_> = texts.iter().map(|text| detector.detect(text)).collect();
+
+        // warmup
+        for text in &texts {
+            detector.detect_with(&mut scratch, text);
+        }
+
+        let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+        for (text, expected) in texts.iter().zip(&expected) {
+            assert_eq!(detector.detect_with(&mut scratch, text), *expected);
+        }
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+        assert_eq!(allocations, 0);
+    }
+}